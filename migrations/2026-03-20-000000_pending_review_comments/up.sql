@@ -0,0 +1,22 @@
+-- Pending inline review comments drafted locally.
+--
+-- Drafts are anchored to a diff line (path, line, side) at a specific commit
+-- and kept until they are submitted to GitHub as part of a review.
+
+CREATE TABLE pending_review_comments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    api_base TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    repo TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    commit_sha TEXT NOT NULL,
+    path TEXT NOT NULL,
+    line INTEGER NOT NULL CHECK(line > 0),
+    side TEXT NOT NULL CHECK(side IN ('LEFT', 'RIGHT')),
+    body TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_pending_review_comments_pull_request
+    ON pending_review_comments(api_base, owner, repo, pr_number);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS pr_review_cache_reviews;
+DROP TABLE IF EXISTS pr_review_cache;
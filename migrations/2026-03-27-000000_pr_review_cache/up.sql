@@ -0,0 +1,31 @@
+-- Cache top-level pull request reviews fetched via Octocrab.
+--
+-- Each pull request has one cache entry carrying the TTL window; the reviews
+-- returned by the last fetch are stored as child rows in API order so the
+-- full list can be replaced atomically on refetch.
+
+CREATE TABLE pr_review_cache (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    api_base TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    repo TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    fetched_at_unix INTEGER NOT NULL,
+    expires_at_unix INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(api_base, owner, repo, pr_number)
+);
+
+CREATE TABLE pr_review_cache_reviews (
+    cache_id INTEGER NOT NULL REFERENCES pr_review_cache(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    review_id INTEGER NOT NULL,
+    author TEXT,
+    state TEXT,
+    body TEXT,
+    submitted_at TEXT,
+    commit_sha TEXT,
+    html_url TEXT,
+    PRIMARY KEY (cache_id, position)
+);
@@ -0,0 +1,22 @@
+-- Cache raw comment listing pages together with their ETag validators.
+--
+-- Each row holds one page of a pull request comment listing exactly as
+-- GitHub returned it. Refreshes send the stored ETag in `If-None-Match`, and
+-- a 304 response reuses the stored body without spending rate-limit quota.
+
+CREATE TABLE comment_page_cache (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    api_base TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    repo TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    listing TEXT NOT NULL,
+    page INTEGER NOT NULL,
+    etag TEXT,
+    body TEXT NOT NULL,
+    has_next_page BOOLEAN NOT NULL,
+    fetched_at_unix INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(api_base, owner, repo, pr_number, listing, page)
+);
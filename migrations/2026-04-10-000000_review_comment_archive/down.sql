@@ -0,0 +1,31 @@
+CREATE TABLE review_comments_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    pull_request_id INTEGER NOT NULL REFERENCES pull_requests(id) ON DELETE CASCADE,
+    github_comment_id INTEGER NOT NULL,
+    body TEXT NOT NULL,
+    file_path TEXT,
+    line_number INTEGER,
+    original_line_number INTEGER,
+    diff_hunk TEXT,
+    resolution_status TEXT NOT NULL DEFAULT 'unresolved'
+        CHECK(resolution_status IN ('unresolved', 'resolved', 'won''t fix', 'outdated')),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(github_comment_id)
+);
+
+INSERT INTO review_comments_old (
+    id, pull_request_id, github_comment_id, body, file_path, line_number,
+    original_line_number, diff_hunk, resolution_status, created_at, updated_at
+)
+SELECT
+    id, pull_request_id, github_comment_id, COALESCE(body, ''), file_path,
+    line_number, original_line_number, diff_hunk, resolution_status, created_at,
+    updated_at
+FROM review_comments;
+
+DROP TABLE review_comments;
+ALTER TABLE review_comments_old RENAME TO review_comments;
+
+CREATE INDEX idx_review_comments_pr_status
+    ON review_comments(pull_request_id, resolution_status);
@@ -0,0 +1,47 @@
+-- Extend review_comments so stored rows can rebuild a full review comment.
+--
+-- The initial schema kept only the comment body and its diff anchor. Offline
+-- reading also needs the author, thread linkage, GitHub's own timestamps, and
+-- the review thread state loaded over GraphQL. `body` becomes nullable because
+-- GitHub returns `null` for some deleted or redacted comments.
+--
+-- SQLite cannot relax a NOT NULL constraint in place, so the table is rebuilt.
+
+CREATE TABLE review_comments_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    pull_request_id INTEGER NOT NULL REFERENCES pull_requests(id) ON DELETE CASCADE,
+    github_comment_id INTEGER NOT NULL,
+    body TEXT,
+    author TEXT,
+    file_path TEXT,
+    line_number INTEGER,
+    original_line_number INTEGER,
+    diff_hunk TEXT,
+    commit_sha TEXT,
+    in_reply_to_id INTEGER,
+    github_created_at TEXT,
+    github_updated_at TEXT,
+    resolution_status TEXT NOT NULL DEFAULT 'unresolved'
+        CHECK(resolution_status IN ('unresolved', 'resolved', 'won''t fix', 'outdated')),
+    thread_id TEXT,
+    thread_is_outdated BOOLEAN NOT NULL DEFAULT 0,
+    thread_resolved_by TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(github_comment_id)
+);
+
+INSERT INTO review_comments_new (
+    id, pull_request_id, github_comment_id, body, file_path, line_number,
+    original_line_number, diff_hunk, resolution_status, created_at, updated_at
+)
+SELECT
+    id, pull_request_id, github_comment_id, body, file_path, line_number,
+    original_line_number, diff_hunk, resolution_status, created_at, updated_at
+FROM review_comments;
+
+DROP TABLE review_comments;
+ALTER TABLE review_comments_new RENAME TO review_comments;
+
+CREATE INDEX idx_review_comments_pr_status
+    ON review_comments(pull_request_id, resolution_status);
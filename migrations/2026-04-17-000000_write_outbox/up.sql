@@ -0,0 +1,23 @@
+-- Durable outbox of review writes waiting to reach GitHub.
+--
+-- Each row is one idempotent write intent (for example a reply or a thread
+-- resolution) identified by a stable `intent_id`. The payload is the JSON
+-- form of the intent. Rows are removed once the write has been applied on
+-- GitHub or discarded by the user.
+
+CREATE TABLE write_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    intent_id TEXT NOT NULL UNIQUE,
+    api_base TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    repo TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    payload TEXT NOT NULL,
+    attempt_count INTEGER NOT NULL DEFAULT 0 CHECK(attempt_count >= 0),
+    last_error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_write_outbox_pull_request
+    ON write_outbox(api_base, owner, repo, pr_number);
@@ -0,0 +1,23 @@
+-- Cached cross-repository review queue.
+--
+-- Each row is one pull request gathered for the review queue dashboard,
+-- keyed by its `owner/repo` name and number. `reasons` holds the
+-- comma-separated reason keys. `last_seen_updated_at` records the pull
+-- request's `updated_at` when the user last opened it from the dashboard and
+-- survives refreshes, so later activity can be reported as unread.
+
+CREATE TABLE review_queue (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    repository TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    title TEXT,
+    author TEXT,
+    state TEXT,
+    html_url TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    reasons TEXT NOT NULL,
+    last_seen_updated_at TEXT,
+    fetched_at_unix INTEGER NOT NULL,
+    UNIQUE(repository, pr_number)
+);
@@ -0,0 +1,12 @@
+ALTER TABLE pr_metadata_cache DROP COLUMN changed_files;
+ALTER TABLE pr_metadata_cache DROP COLUMN deletions;
+ALTER TABLE pr_metadata_cache DROP COLUMN additions;
+ALTER TABLE pr_metadata_cache DROP COLUMN mergeable_state;
+ALTER TABLE pr_metadata_cache DROP COLUMN requested_reviewers;
+ALTER TABLE pr_metadata_cache DROP COLUMN labels;
+ALTER TABLE pr_metadata_cache DROP COLUMN head_sha;
+ALTER TABLE pr_metadata_cache DROP COLUMN head_ref;
+ALTER TABLE pr_metadata_cache DROP COLUMN base_sha;
+ALTER TABLE pr_metadata_cache DROP COLUMN base_ref;
+ALTER TABLE pr_metadata_cache DROP COLUMN draft;
+ALTER TABLE pr_metadata_cache DROP COLUMN body;
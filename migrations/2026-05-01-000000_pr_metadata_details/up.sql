@@ -0,0 +1,25 @@
+-- Extend pr_metadata_cache with the pull request details shown in the CLI
+-- summary and the review TUI header.
+--
+-- Labels and requested reviewers are stored as JSON arrays of strings. The
+-- base and head SHAs record the commits the branches pointed at when the
+-- metadata was fetched.
+--
+-- Existing rows lack the new details and would otherwise be served until
+-- they expire, or indefinitely while GitHub answers `304 Not Modified`, so
+-- they are dropped and refetched on next use.
+
+ALTER TABLE pr_metadata_cache ADD COLUMN body TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN draft BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE pr_metadata_cache ADD COLUMN base_ref TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN base_sha TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN head_ref TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN head_sha TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN labels TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE pr_metadata_cache ADD COLUMN requested_reviewers TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE pr_metadata_cache ADD COLUMN mergeable_state TEXT;
+ALTER TABLE pr_metadata_cache ADD COLUMN additions INTEGER;
+ALTER TABLE pr_metadata_cache ADD COLUMN deletions INTEGER;
+ALTER TABLE pr_metadata_cache ADD COLUMN changed_files INTEGER;
+
+DELETE FROM pr_metadata_cache;
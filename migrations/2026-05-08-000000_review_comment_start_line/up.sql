@@ -0,0 +1,7 @@
+-- Record where multi-line review comments start.
+--
+-- Suggested changes replace every line from `start_line_number` to
+-- `line_number`, so archived comments need the start line for suggestions
+-- to be applied offline. Single-line comments leave it `NULL`.
+
+ALTER TABLE review_comments ADD COLUMN start_line_number INTEGER;
@@ -0,0 +1,50 @@
+CREATE TABLE review_comments_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    pull_request_id INTEGER NOT NULL REFERENCES pull_requests(id) ON DELETE CASCADE,
+    github_comment_id INTEGER NOT NULL,
+    body TEXT,
+    author TEXT,
+    file_path TEXT,
+    line_number INTEGER,
+    original_line_number INTEGER,
+    diff_hunk TEXT,
+    commit_sha TEXT,
+    in_reply_to_id INTEGER,
+    github_created_at TEXT,
+    github_updated_at TEXT,
+    resolution_status TEXT NOT NULL DEFAULT 'unresolved'
+        CHECK(resolution_status IN ('unresolved', 'resolved', 'won''t fix', 'outdated')),
+    thread_id TEXT,
+    thread_is_outdated BOOLEAN NOT NULL DEFAULT 0,
+    thread_resolved_by TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    start_line_number INTEGER,
+    UNIQUE(github_comment_id)
+);
+
+INSERT OR IGNORE INTO review_comments_old (
+    id, pull_request_id, github_comment_id, body, author, file_path,
+    line_number, start_line_number, original_line_number, diff_hunk,
+    commit_sha, in_reply_to_id, github_created_at, github_updated_at,
+    resolution_status, thread_id, thread_is_outdated, thread_resolved_by,
+    created_at, updated_at
+)
+SELECT
+    id, pull_request_id, github_comment_id, body, author, file_path,
+    line_number, start_line_number, original_line_number, diff_hunk,
+    commit_sha, in_reply_to_id, github_created_at, github_updated_at,
+    resolution_status, thread_id, thread_is_outdated, thread_resolved_by,
+    created_at, updated_at
+FROM review_comments;
+
+DROP TABLE review_comments;
+ALTER TABLE review_comments_old RENAME TO review_comments;
+
+CREATE INDEX idx_review_comments_pr_status
+    ON review_comments(pull_request_id, resolution_status);
+
+DROP INDEX idx_repositories_host_owner_name;
+CREATE UNIQUE INDEX idx_repositories_owner_name
+    ON repositories(owner, name);
+ALTER TABLE repositories DROP COLUMN host;
@@ -0,0 +1,27 @@
+CREATE TABLE review_queue_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    repository TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    title TEXT,
+    author TEXT,
+    state TEXT,
+    html_url TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    reasons TEXT NOT NULL,
+    last_seen_updated_at TEXT,
+    fetched_at_unix INTEGER NOT NULL,
+    UNIQUE(repository, pr_number)
+);
+
+INSERT OR IGNORE INTO review_queue_old (
+    id, repository, pr_number, title, author, state, html_url, created_at,
+    updated_at, reasons, last_seen_updated_at, fetched_at_unix
+)
+SELECT
+    id, repository, pr_number, title, author, state, html_url, created_at,
+    updated_at, reasons, last_seen_updated_at, fetched_at_unix
+FROM review_queue;
+
+DROP TABLE review_queue;
+ALTER TABLE review_queue_old RENAME TO review_queue;
@@ -0,0 +1,42 @@
+-- Scope the cached review queue to the GitHub host it was gathered from.
+--
+-- The same owner/repo pair can exist on github.com and on a GitHub
+-- Enterprise host, so the queue key gains a `host` column. Existing rows
+-- take their host from `html_url`, which GitHub always reports as
+-- `https://{host}/...`.
+--
+-- SQLite cannot drop a table constraint in place, so review_queue is
+-- rebuilt.
+
+CREATE TABLE review_queue_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    host TEXT NOT NULL DEFAULT 'github.com',
+    repository TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    title TEXT,
+    author TEXT,
+    state TEXT,
+    html_url TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    reasons TEXT NOT NULL,
+    last_seen_updated_at TEXT,
+    fetched_at_unix INTEGER NOT NULL,
+    UNIQUE(host, repository, pr_number)
+);
+
+INSERT INTO review_queue_new (
+    id, repository, pr_number, title, author, state, html_url, created_at,
+    updated_at, reasons, last_seen_updated_at, fetched_at_unix
+)
+SELECT
+    id, repository, pr_number, title, author, state, html_url, created_at,
+    updated_at, reasons, last_seen_updated_at, fetched_at_unix
+FROM review_queue;
+
+UPDATE review_queue_new
+SET host = lower(substr(html_url, 9, instr(substr(html_url, 9), '/') - 1))
+WHERE html_url LIKE 'https://%/%';
+
+DROP TABLE review_queue;
+ALTER TABLE review_queue_new RENAME TO review_queue;
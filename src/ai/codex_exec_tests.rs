@@ -11,7 +11,7 @@ use tempfile::TempDir;
 
 use super::*;
 use crate::ai::codex_process::{build_command_spec, parse_progress_event};
-use crate::export::{ExportedComment, write_jsonl};
+use crate::export::{ExportedComment, ExportedCommentKind, write_jsonl};
 
 /// Result type used by Codex execution tests.
 type TestResult = Result<(), Box<dyn std::error::Error>>;
@@ -24,6 +24,7 @@ type ScriptFixture = Result<(TempDir, Utf8PathBuf), IntakeError>;
 fn sample_comment() -> ExportedComment {
     ExportedComment {
         id: 1,
+        kind: ExportedCommentKind::ReviewComment,
         author: Some("alice".to_owned()),
         file_path: Some("src/lib.rs".to_owned()),
         line_number: Some(42),
@@ -33,6 +34,10 @@ fn sample_comment() -> ExportedComment {
         commit_sha: Some("abc123".to_owned()),
         in_reply_to_id: None,
         created_at: Some("2026-02-12T10:00:00Z".to_owned()),
+        is_resolved: None,
+        is_outdated: None,
+        resolved_by: None,
+        review_state: None,
     }
 }
 
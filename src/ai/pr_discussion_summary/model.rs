@@ -7,7 +7,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::github::ReviewComment;
+use crate::github::{CiStatus, ReviewComment};
 use crate::verification::{CommentVerificationResult, GithubCommentId};
 
 /// Stable fallback label for comments without a file attachment.
@@ -129,6 +129,7 @@ pub struct PrDiscussionSummaryRequest {
     pr_title: Option<String>,
     review_comments: Vec<ReviewComment>,
     verification_results: HashMap<GithubCommentId, CommentVerificationResult>,
+    ci_status: Option<CiStatus>,
 }
 
 impl PrDiscussionSummaryRequest {
@@ -144,6 +145,7 @@ impl PrDiscussionSummaryRequest {
             pr_title,
             review_comments,
             verification_results: HashMap::new(),
+            ci_status: None,
         }
     }
 
@@ -157,6 +159,13 @@ impl PrDiscussionSummaryRequest {
         self
     }
 
+    /// Adds CI results for the head commit as optional prompt context.
+    #[must_use]
+    pub fn with_ci_status(mut self, ci_status: CiStatus) -> Self {
+        self.ci_status = Some(ci_status);
+        self
+    }
+
     /// Pull-request number included in the prompt context.
     #[must_use]
     pub const fn pr_number(&self) -> u64 {
@@ -182,6 +191,12 @@ impl PrDiscussionSummaryRequest {
     ) -> &HashMap<GithubCommentId, CommentVerificationResult> {
         &self.verification_results
     }
+
+    /// CI results for the head commit, if they were loaded.
+    #[must_use]
+    pub const fn ci_status(&self) -> Option<&CiStatus> {
+        self.ci_status.as_ref()
+    }
 }
 
 /// Structured PR-level summary grouped by file and severity.
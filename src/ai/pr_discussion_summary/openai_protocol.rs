@@ -7,7 +7,7 @@
 use reqwest::StatusCode;
 use serde::Serialize;
 
-use crate::github::IntakeError;
+use crate::github::{CiStatus, IntakeError};
 
 use super::super::model::DiscussionSeverity;
 use super::super::service::{ThreadSummaryDraft, ThreadSummaryProviderRequest};
@@ -62,13 +62,32 @@ struct ChatChoiceMessage {
 struct PromptPayload<'a> {
     pr_number: u64,
     pr_title: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ci: Option<PromptCiStatus<'a>>,
     threads: Vec<PromptThread<'a>>,
 }
 
+#[derive(Debug, Serialize)]
+struct PromptCiStatus<'a> {
+    head_sha: &'a str,
+    outcome: &'static str,
+    failing_checks: Vec<PromptFailingCheck<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct PromptFailingCheck<'a> {
+    name: &'a str,
+    state: Option<&'a str>,
+    summary: Option<&'a str>,
+}
+
 #[derive(Debug, Serialize)]
 struct PromptThread<'a> {
     root_comment_id: u64,
     file_path: &'a str,
+    is_resolved: Option<bool>,
+    is_outdated: Option<bool>,
+    resolved_by: Option<&'a str>,
     related_comment_ids: Vec<u64>,
     comments: Vec<PromptComment<'a>>,
 }
@@ -102,6 +121,8 @@ fn build_system_prompt() -> String {
         "Return strict JSON only with top-level key 'summaries'. ",
         "Each summary must include root_comment_id, severity, headline, and rationale. ",
         "Severity must be one of: high, medium, low. ",
+        "Threads with is_resolved true were closed on GitHub; rate them low unless still actionable. ",
+        "When ci lists failing checks, raise the severity of threads those failures corroborate. ",
         "Do not invent thread IDs. ",
         "Do not use markdown fences."
     )
@@ -133,12 +154,28 @@ pub fn build_prompt(request: &ThreadSummaryProviderRequest<'_>) -> Result<String
     let payload = PromptPayload {
         pr_number: request.pr_number,
         pr_title: request.pr_title,
+        ci: request.ci_status.map(prompt_ci_status),
         threads: request
             .threads
             .iter()
             .map(|thread| PromptThread {
                 root_comment_id: thread.root_comment.id,
                 file_path: thread.file_path.as_str(),
+                is_resolved: thread
+                    .root_comment
+                    .thread_state
+                    .as_ref()
+                    .map(|state| state.is_resolved),
+                is_outdated: thread
+                    .root_comment
+                    .thread_state
+                    .as_ref()
+                    .map(|state| state.is_outdated),
+                resolved_by: thread
+                    .root_comment
+                    .thread_state
+                    .as_ref()
+                    .and_then(|state| state.resolved_by.as_deref()),
                 related_comment_ids: thread
                     .related_comment_ids
                     .iter()
@@ -167,6 +204,22 @@ pub fn build_prompt(request: &ThreadSummaryProviderRequest<'_>) -> Result<String
     })
 }
 
+fn prompt_ci_status(ci_status: &CiStatus) -> PromptCiStatus<'_> {
+    PromptCiStatus {
+        head_sha: ci_status.head_sha.as_str(),
+        outcome: ci_status.outcome().label(),
+        failing_checks: ci_status
+            .failing_checks()
+            .into_iter()
+            .map(|check| PromptFailingCheck {
+                name: check.name.as_str(),
+                state: check.state.as_deref(),
+                summary: check.summary.as_deref(),
+            })
+            .collect(),
+    }
+}
+
 /// Extracts assistant text from either content representation.
 pub fn parse_content_value(content: &ChatContent) -> Option<String> {
     match content {
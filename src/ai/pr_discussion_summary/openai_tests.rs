@@ -67,6 +67,7 @@ fn build_prompt_serializes_thread_context() {
             pr_number: 42,
             pr_title: Some("Title"),
             threads: &crate::ai::pr_discussion_summary::threads::build_discussion_threads(&request),
+            ci_status: None,
         },
     )
     .expect("prompt should serialize");
@@ -74,3 +75,63 @@ fn build_prompt_serializes_thread_context() {
     assert!(prompt.contains("\"pr_number\": 42"));
     assert!(prompt.contains("\"root_comment_id\": 1"));
 }
+
+#[test]
+fn build_prompt_includes_github_thread_state() {
+    let request = PrDiscussionSummaryRequest::new(
+        42,
+        None,
+        vec![crate::github::ReviewComment {
+            thread_state: Some(crate::github::ReviewThreadState {
+                thread_id: "PRRT_1".to_owned(),
+                is_resolved: true,
+                is_outdated: true,
+                resolved_by: Some("carol".to_owned()),
+            }),
+            ..minimal_review(1, "body", "alice")
+        }],
+    );
+    let prompt = build_prompt(
+        &crate::ai::pr_discussion_summary::service::ThreadSummaryProviderRequest {
+            pr_number: 42,
+            pr_title: None,
+            threads: &crate::ai::pr_discussion_summary::threads::build_discussion_threads(&request),
+            ci_status: None,
+        },
+    )
+    .expect("prompt should serialize");
+
+    assert!(prompt.contains("\"is_resolved\": true"));
+    assert!(prompt.contains("\"is_outdated\": true"));
+    assert!(prompt.contains("\"resolved_by\": \"carol\""));
+}
+
+#[test]
+fn build_prompt_includes_failing_ci_checks() {
+    let request =
+        PrDiscussionSummaryRequest::new(42, None, vec![minimal_review(1, "body", "alice")]);
+    let ci_status = crate::github::CiStatus {
+        head_sha: "abc123".to_owned(),
+        checks: vec![crate::github::CiCheck {
+            name: "build".to_owned(),
+            kind: crate::github::CiCheckKind::CheckRun,
+            outcome: crate::github::CiOutcome::Failing,
+            state: Some("failure".to_owned()),
+            summary: Some("2 tests failed".to_owned()),
+            details_url: None,
+        }],
+    };
+    let prompt = build_prompt(
+        &crate::ai::pr_discussion_summary::service::ThreadSummaryProviderRequest {
+            pr_number: 42,
+            pr_title: None,
+            threads: &crate::ai::pr_discussion_summary::threads::build_discussion_threads(&request),
+            ci_status: Some(&ci_status),
+        },
+    )
+    .expect("prompt should serialize");
+
+    assert!(prompt.contains("\"outcome\": \"failing\""));
+    assert!(prompt.contains("\"name\": \"build\""));
+    assert!(prompt.contains("\"summary\": \"2 tests failed\""));
+}
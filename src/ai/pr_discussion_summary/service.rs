@@ -2,7 +2,7 @@
 
 use std::collections::{BTreeMap, HashMap};
 
-use crate::github::IntakeError;
+use crate::github::{CiStatus, IntakeError};
 use crate::verification::GithubCommentId;
 
 use super::model::{
@@ -36,6 +36,7 @@ pub(crate) struct ThreadSummaryProviderRequest<'a> {
     pub pr_number: u64,
     pub pr_title: Option<&'a str>,
     pub threads: &'a [DiscussionThread],
+    pub ci_status: Option<&'a CiStatus>,
 }
 
 /// AI-generated draft for one summarized thread.
@@ -70,6 +71,7 @@ pub(crate) fn summarize_with_provider(
         pr_number: request.pr_number(),
         pr_title: request.pr_title(),
         threads: threads.as_slice(),
+        ci_status: request.ci_status(),
     };
     let drafts = provider.summarize_threads(&provider_request)?;
     validate_drafts(threads.as_slice(), drafts.as_slice())?;
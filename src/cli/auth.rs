@@ -0,0 +1,207 @@
+//! CLI operation mode for managing the encrypted credential file.
+//!
+//! Secrets and passphrases are never taken from command-line arguments: the
+//! passphrase comes from `FRANKIE_CREDENTIAL_PASSPHRASE` or a terminal
+//! prompt, and secrets are read from standard input or prompted for without
+//! echo.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+use frankie::redaction::register_secret;
+use frankie::{AuthAction, CredentialStore, CredentialTarget, FrankieConfig, IntakeError};
+
+use super::output::io_error;
+
+/// Runs the requested action against the credential file.
+///
+/// `--auth-status` lists the stored secrets without their values,
+/// `--auth-login <host|ai>` stores a secret, and `--auth-logout <host|ai>`
+/// removes one.
+///
+/// # Errors
+///
+/// Returns an error if the credential file cannot be located, decrypted, or
+/// written, no passphrase or secret is available, or writing output fails.
+pub fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let path = config.credential_file_path()?;
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+
+    match config.auth_action() {
+        AuthAction::Status => status(config, &path, &mut writer),
+        AuthAction::Login(target) => {
+            let passphrase = passphrase(config, !path.exists())?;
+            let secret = read_secret(target)?;
+            login(&path, &passphrase, target, secret)?;
+            writeln!(writer, "Stored {target} in {}", path.display())
+                .map_err(|error| io_error(&error))
+        }
+        AuthAction::Logout(target) => logout(config, &path, target, &mut writer),
+    }
+}
+
+/// Looks up the stored token for `host` after prompting for the passphrase.
+///
+/// Used when no other source supplied a token and the passphrase is not in
+/// the environment. Returns `Ok(None)` without prompting when standard input
+/// is not a terminal or there is no credential file.
+///
+/// # Errors
+///
+/// Returns an error when the prompt fails or the credential file cannot be
+/// decrypted.
+pub(super) fn prompt_for_stored_token(
+    config: &FrankieConfig,
+    host: &str,
+) -> Result<Option<String>, IntakeError> {
+    if config.credential_passphrase.is_some() || !io::stdin().is_terminal() {
+        return Ok(None);
+    }
+    let Ok(path) = config.credential_file_path() else {
+        return Ok(None);
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let passphrase = prompt("Credential file passphrase: ")?;
+    register_secret(&passphrase);
+    let store = CredentialStore::load(&path, &passphrase)?;
+    let token = store
+        .get(CredentialTarget::GitHubHost(host))
+        .map(str::to_owned);
+    if let Some(value) = token.as_deref() {
+        register_secret(value);
+        // Report where the token came from (ignore write errors)
+        drop(writeln!(
+            io::stderr(),
+            "Using the GitHub token for {host} from the credential file ({})",
+            path.display()
+        ));
+    }
+    Ok(token)
+}
+
+fn status(config: &FrankieConfig, path: &Path, writer: &mut impl Write) -> Result<(), IntakeError> {
+    if !path.exists() {
+        return writeln!(writer, "No credential file at {}", path.display())
+            .map_err(|error| io_error(&error));
+    }
+
+    let store = CredentialStore::load(path, &passphrase(config, false)?)?;
+    let hosts = store.github_hosts().collect::<Vec<_>>();
+    let host_list = if hosts.is_empty() {
+        "none".to_owned()
+    } else {
+        hosts.join(", ")
+    };
+    let ai_api_key = if store.has_ai_api_key() {
+        "stored"
+    } else {
+        "not stored"
+    };
+    writeln!(
+        writer,
+        "Credential file: {}\nGitHub tokens: {host_list}\nAI API key: {ai_api_key}",
+        path.display()
+    )
+    .map_err(|error| io_error(&error))
+}
+
+fn login(
+    path: &Path,
+    passphrase: &str,
+    target: CredentialTarget<'_>,
+    secret: String,
+) -> Result<(), IntakeError> {
+    let mut store = CredentialStore::load(path, passphrase)?;
+    store.set(target, secret);
+    store.save(path, passphrase)
+}
+
+fn logout(
+    config: &FrankieConfig,
+    path: &Path,
+    target: CredentialTarget<'_>,
+    writer: &mut impl Write,
+) -> Result<(), IntakeError> {
+    if !path.exists() {
+        return writeln!(writer, "No credential file at {}", path.display())
+            .map_err(|error| io_error(&error));
+    }
+
+    let passphrase = passphrase(config, false)?;
+    let mut store = CredentialStore::load(path, &passphrase)?;
+    if !store.remove(target) {
+        return writeln!(
+            writer,
+            "Nothing to remove: {target} is not stored in {}",
+            path.display()
+        )
+        .map_err(|error| io_error(&error));
+    }
+    store.save(path, &passphrase)?;
+    writeln!(writer, "Removed {target} from {}", path.display()).map_err(|error| io_error(&error))
+}
+
+/// Returns the credential file passphrase from configuration or a prompt.
+///
+/// When `confirm` is set (a new file is about to be created), the prompted
+/// passphrase must be entered twice.
+fn passphrase(config: &FrankieConfig, confirm: bool) -> Result<String, IntakeError> {
+    if let Some(configured) = config.credential_passphrase.as_deref() {
+        return non_empty(configured.to_owned(), "the credential file passphrase");
+    }
+    if !io::stdin().is_terminal() {
+        return Err(IntakeError::Configuration {
+            message: concat!(
+                "set FRANKIE_CREDENTIAL_PASSPHRASE or run from a terminal ",
+                "to unlock the credential file"
+            )
+            .to_owned(),
+        });
+    }
+
+    let entered = non_empty(
+        prompt("Credential file passphrase: ")?,
+        "the credential file passphrase",
+    )?;
+    if confirm && prompt("Confirm passphrase: ")? != entered {
+        return Err(IntakeError::Configuration {
+            message: "the passphrases do not match".to_owned(),
+        });
+    }
+    Ok(entered)
+}
+
+/// Reads the secret to store for `target`.
+///
+/// Prompts without echo on a terminal; otherwise reads the first line of
+/// standard input.
+fn read_secret(target: CredentialTarget<'_>) -> Result<String, IntakeError> {
+    let secret = if io::stdin().is_terminal() {
+        prompt(&format!("Enter {target}: "))?
+    } else {
+        let mut line = String::new();
+        io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|error| io_error(&error))?;
+        line
+    };
+    non_empty(secret.trim().to_owned(), &target.to_string())
+}
+
+fn prompt(message: &str) -> Result<String, IntakeError> {
+    rpassword::prompt_password(message).map_err(|error| io_error(&error))
+}
+
+fn non_empty(value: String, description: &str) -> Result<String, IntakeError> {
+    if value.is_empty() {
+        return Err(IntakeError::Configuration {
+            message: format!("{description} must not be empty"),
+        });
+    }
+    Ok(value)
+}
@@ -0,0 +1,74 @@
+//! Rate-limit wait notices for command-line modes.
+//!
+//! The GitHub gateways wait out rate limits for up to an hour before
+//! retrying. The review TUI shows these waits as a countdown in its status
+//! bar; other modes announce each new wait on stderr instead, so a long
+//! pause is never silent.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use frankie::{PendingBackoff, pending_backoff};
+use tokio::task::JoinHandle;
+
+/// Interval between checks for a new backoff.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Writes a notice to stderr for every backoff started while it is alive.
+pub struct BackoffNotices(JoinHandle<()>);
+
+impl BackoffNotices {
+    /// Starts watching for backoffs on the current Tokio runtime.
+    pub fn start() -> Self {
+        Self(tokio::spawn(report_backoffs()))
+    }
+}
+
+impl Drop for BackoffNotices {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+async fn report_backoffs() {
+    let mut reported = None;
+    loop {
+        let current = pending_backoff();
+        if let Some(backoff) = current.filter(|backoff| Some(*backoff) != reported) {
+            // Notices are best-effort (ignore write errors)
+            drop(writeln!(io::stderr(), "{}", notice(&backoff)));
+        }
+        reported = current;
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Describes `backoff` the way the review TUI status bar does.
+fn notice(backoff: &PendingBackoff) -> String {
+    format!(
+        "GitHub {}: retrying in {}s (attempt {}/{})",
+        backoff.reason().label(),
+        backoff.seconds_remaining(),
+        backoff.attempt(),
+        backoff.max_attempts()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for rate-limit wait notices.
+
+    use frankie::{BackoffReason, PendingBackoff};
+
+    use super::notice;
+
+    #[test]
+    fn notice_names_the_reason_and_attempt() {
+        let backoff = PendingBackoff::new(BackoffReason::RateLimitReset, 1, 3, 0);
+
+        assert_eq!(
+            notice(&backoff),
+            "GitHub rate limit exhausted: retrying in 0s (attempt 1/3)"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! Review queue dashboard operation.
+//!
+//! Gathers the pull requests waiting on the user across repositories, ranks
+//! them, and prints them or, with `--tui`, opens them in a picker and then
+//! reviews the chosen pull request. With `--database-url` the queue is
+//! cached, which lets `--offline` show it and lets later runs report which
+//! pull requests changed since they were last opened.
+
+use std::io::{self, Write};
+
+use bubbletea_rs::Program;
+use chrono::{DateTime, Utc};
+use frankie::github::RepositoryGateway;
+use frankie::github::locator::api_base_for_host;
+use frankie::persistence::ReviewQueueCache;
+use frankie::tui::{PullRequestPicker, set_initial_terminal_size, set_pull_request_picker};
+use frankie::{
+    FrankieConfig, GitHubCredentials, IntakeError, OctocrabRepositoryGateway, PageInfo,
+    PaginatedPullRequests, ReviewQueueEntry, ReviewQueueService, rank_review_queue,
+};
+
+use super::output::io_error;
+use super::pull_request_context::{map_persistence_error, resolve_host_credentials};
+use super::review_tui;
+use super::search_pull_requests::locator_for;
+
+/// Shows the review queue.
+///
+/// # Errors
+///
+/// Returns [`IntakeError::MissingToken`] if no token is available,
+/// [`IntakeError::RateLimitExceeded`] if the search rate limit is exhausted,
+/// [`IntakeError::Configuration`] if the database has not been migrated, and
+/// [`IntakeError::Api`] if a search fails or the picker fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let api_base = api_base_for_host(config.search_host())?;
+    let build_gateway = |credentials: &GitHubCredentials| {
+        OctocrabRepositoryGateway::for_api_base(credentials, api_base.as_str())
+    };
+    let now = Utc::now();
+
+    if !config.tui {
+        let mut stdout = io::stdout().lock();
+        return run_with_gateway_builder(config, build_gateway, &mut stdout, now).await;
+    }
+
+    let cache = open_cache(config)?;
+    let entries = load_queue(config, cache.as_ref(), build_gateway, now).await?;
+    let Some(chosen) = pick(&entries, now).await? else {
+        return Ok(());
+    };
+    if let Some(queue_cache) = cache.as_ref() {
+        queue_cache
+            .mark_seen(&chosen.pull_request)
+            .map_err(|error| map_persistence_error("failed to update the review queue", &error))?;
+    }
+    review_tui::run_for_locator(config, locator_for(&chosen.pull_request)?).await
+}
+
+/// Gathers the review queue using a custom gateway builder and writes it.
+///
+/// This function is exposed for testing with mock gateways.
+pub async fn run_with_gateway_builder<G, F, W>(
+    config: &FrankieConfig,
+    build_gateway: F,
+    writer: &mut W,
+    now: DateTime<Utc>,
+) -> Result<(), IntakeError>
+where
+    G: RepositoryGateway,
+    F: FnOnce(&GitHubCredentials) -> Result<G, IntakeError>,
+    W: Write,
+{
+    let cache = open_cache(config)?;
+    let entries = load_queue(config, cache.as_ref(), build_gateway, now).await?;
+    write_queue(writer, &entries, now)
+}
+
+fn open_cache(config: &FrankieConfig) -> Result<Option<ReviewQueueCache>, IntakeError> {
+    config
+        .database_url
+        .as_deref()
+        .map(|database_url| ReviewQueueCache::new(database_url, config.search_host()))
+        .transpose()
+        .map_err(|error| map_persistence_error("failed to open the review queue cache", &error))
+}
+
+/// Returns the ranked queue, refreshed from GitHub unless offline.
+///
+/// A refreshed queue replaces the cached one, and cached entries supply
+/// when each pull request was last opened.
+async fn load_queue<G, F>(
+    config: &FrankieConfig,
+    cache: Option<&ReviewQueueCache>,
+    build_gateway: F,
+    now: DateTime<Utc>,
+) -> Result<Vec<ReviewQueueEntry>, IntakeError>
+where
+    G: RepositoryGateway,
+    F: FnOnce(&GitHubCredentials) -> Result<G, IntakeError>,
+{
+    let mut entries = if config.is_offline() {
+        Vec::new()
+    } else {
+        let host = config.search_host();
+        let api_base = api_base_for_host(host)?;
+        let credentials = resolve_host_credentials(config, host, api_base.as_str())?;
+        let gateway = build_gateway(&credentials)?;
+        ReviewQueueService::new(&gateway)
+            .fetch(&config.review_queue_request())
+            .await?
+    };
+
+    if let Some(queue_cache) = cache {
+        let read_error = |error| map_persistence_error("failed to read the review queue", &error);
+        if !config.is_offline() {
+            queue_cache
+                .replace(&entries, now.timestamp())
+                .map_err(read_error)?;
+        }
+        entries = queue_cache.load().map_err(read_error)?.entries;
+    }
+
+    rank_review_queue(&mut entries);
+    Ok(entries)
+}
+
+fn write_queue<W: Write>(
+    writer: &mut W,
+    entries: &[ReviewQueueEntry],
+    now: DateTime<Utc>,
+) -> Result<(), IntakeError> {
+    writeln!(writer, "Review queue ({} pull requests):", entries.len())
+        .map_err(|e| io_error(&e))?;
+    writeln!(writer).map_err(|e| io_error(&e))?;
+
+    for entry in entries {
+        let pr = &entry.pull_request;
+        let title = pr.title.as_deref().unwrap_or("(no title)");
+        let author = pr.author.as_deref().unwrap_or("unknown");
+        writeln!(
+            writer,
+            "  {} {title} (@{author}) - {}",
+            pr.reference(),
+            queue_note(entry, now)
+        )
+        .map_err(|e| io_error(&e))?;
+    }
+
+    Ok(())
+}
+
+/// Shows the queue in the picker and returns the chosen entry.
+async fn pick(
+    entries: &[ReviewQueueEntry],
+    now: DateTime<Utc>,
+) -> Result<Option<ReviewQueueEntry>, IntakeError> {
+    if let Ok((width, height)) = crossterm::terminal::size() {
+        let _ = set_initial_terminal_size(width, height);
+    }
+    let listing = PaginatedPullRequests {
+        items: entries
+            .iter()
+            .map(|entry| entry.pull_request.clone())
+            .collect(),
+        page_info: PageInfo::default(),
+        rate_limit: None,
+    };
+    let notes = entries.iter().map(|entry| queue_note(entry, now)).collect();
+    let _ =
+        set_pull_request_picker(PullRequestPicker::new("Review queue", listing).with_notes(notes));
+
+    let picker = async {
+        Program::<PullRequestPicker>::builder()
+            .alt_screen(true)
+            .build()?
+            .run()
+            .await
+    }
+    .await
+    .map_err(|error: bubbletea_rs::Error| IntakeError::Api {
+        message: format!("TUI error: {error}"),
+    })?;
+    io::stdout().flush().map_err(|error| io_error(&error))?;
+
+    Ok(picker.selected().and_then(|selected| {
+        entries
+            .iter()
+            .find(|entry| entry.pull_request == *selected)
+            .cloned()
+    }))
+}
+
+/// Describes why an entry is queued, such as
+/// `unread, review requested, idle 3d`.
+fn queue_note(entry: &ReviewQueueEntry, now: DateTime<Utc>) -> String {
+    let unread = entry.has_unread_activity().then(|| "unread".to_owned());
+    let reasons = entry.reasons.iter().map(ToString::to_string);
+    let idle = entry
+        .idle_for(now)
+        .map(|idle| format!("idle {}", format_idle(idle)));
+    unread
+        .into_iter()
+        .chain(reasons)
+        .chain(idle)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_idle(idle: chrono::Duration) -> String {
+    if idle.num_days() > 0 {
+        format!("{}d", idle.num_days())
+    } else if idle.num_hours() > 0 {
+        format!("{}h", idle.num_hours())
+    } else {
+        format!("{}m", idle.num_minutes().max(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `dashboard` module.
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, TimeZone, Utc};
+    use frankie::github::{PageInfo, RepositoryGateway};
+    use frankie::persistence::{ReviewQueueCache, migrate_database};
+    use frankie::telemetry::NoopTelemetrySink;
+    use frankie::{
+        FrankieConfig, IntakeError, ListPullRequestsParams, PaginatedPullRequests,
+        PullRequestSummary, RepositoryLocator, SearchPullRequestsParams,
+    };
+    use tempfile::TempDir;
+
+    use super::run_with_gateway_builder;
+
+    /// Answers the review-requested search with one pull request and the
+    /// commenter search with another.
+    struct QueueGateway;
+
+    #[async_trait]
+    impl RepositoryGateway for QueueGateway {
+        async fn list_pull_requests(
+            &self,
+            _locator: &RepositoryLocator,
+            _params: &ListPullRequestsParams,
+        ) -> Result<PaginatedPullRequests, IntakeError> {
+            Err(IntakeError::Api {
+                message: "unexpected listing request".to_owned(),
+            })
+        }
+
+        async fn search_pull_requests(
+            &self,
+            params: &SearchPullRequestsParams,
+        ) -> Result<PaginatedPullRequests, IntakeError> {
+            let items = if params.search_query().contains("review-requested:@me") {
+                vec![summary(
+                    "octo/app",
+                    42,
+                    "Add search",
+                    "2026-04-14T12:00:00Z",
+                )]
+            } else {
+                vec![summary(
+                    "octo/lib",
+                    7,
+                    "Fix flaky test",
+                    "2026-04-16T09:00:00Z",
+                )]
+            };
+            Ok(PaginatedPullRequests {
+                items,
+                page_info: PageInfo::default(),
+                rate_limit: None,
+            })
+        }
+
+        async fn is_organization(&self, _login: &str) -> Result<bool, IntakeError> {
+            Err(IntakeError::Api {
+                message: "unexpected account lookup".to_owned(),
+            })
+        }
+    }
+
+    fn summary(repository: &str, number: u64, title: &str, updated_at: &str) -> PullRequestSummary {
+        PullRequestSummary {
+            number,
+            repository: Some(repository.to_owned()),
+            title: Some(title.to_owned()),
+            state: Some("open".to_owned()),
+            author: Some("octocat".to_owned()),
+            updated_at: Some(updated_at.to_owned()),
+            html_url: Some(format!("https://github.com/{repository}/pull/{number}")),
+            ..PullRequestSummary::default()
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 4, 17, 12, 0, 0)
+            .single()
+            .expect("timestamp should be valid")
+    }
+
+    fn migrated_database() -> (TempDir, String) {
+        let temp_dir = TempDir::new().expect("temp dir should be created");
+        let database_url = temp_dir
+            .path()
+            .join("frankie.sqlite")
+            .to_string_lossy()
+            .to_string();
+        migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+        (temp_dir, database_url)
+    }
+
+    async fn render(config: &FrankieConfig) -> String {
+        let mut buffer = Vec::new();
+        run_with_gateway_builder(config, |_| Ok(QueueGateway), &mut buffer, now())
+            .await
+            .expect("dashboard should succeed");
+        String::from_utf8(buffer).expect("output should be valid UTF-8")
+    }
+
+    #[tokio::test]
+    async fn dashboard_ranks_requested_reviews_first_with_notes() {
+        let config = FrankieConfig {
+            token: Some("ghp_example".to_owned()),
+            dashboard: true,
+            ..Default::default()
+        };
+
+        let output = render(&config).await;
+
+        assert_eq!(
+            output,
+            concat!(
+                "Review queue (2 pull requests):\n",
+                "\n",
+                "  octo/app#42 Add search (@octocat) - unread, review requested, idle 3d\n",
+                "  octo/lib#7 Fix flaky test (@octocat) - unread, you commented, idle 1d\n",
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn dashboard_caches_the_queue_and_tracks_seen_pull_requests() {
+        let (_temp_dir, database_url) = migrated_database();
+        let online = FrankieConfig {
+            token: Some("ghp_example".to_owned()),
+            dashboard: true,
+            database_url: Some(database_url.clone()),
+            ..Default::default()
+        };
+        render(&online).await;
+        let cache = ReviewQueueCache::new(database_url.clone(), online.search_host())
+            .expect("cache should open");
+        cache
+            .mark_seen(&summary(
+                "octo/app",
+                42,
+                "Add search",
+                "2026-04-14T12:00:00Z",
+            ))
+            .expect("mark_seen should succeed");
+
+        let offline = FrankieConfig {
+            offline: true,
+            ..online
+        };
+        let output = render(&offline).await;
+
+        assert!(
+            output.contains(
+                "  octo/lib#7 Fix flaky test (@octocat) - unread, you commented, idle 1d\n  \
+                 octo/app#42 Add search (@octocat) - review requested, idle 3d"
+            ),
+            "seen pull request should rank last: {output}"
+        );
+    }
+
+    #[tokio::test]
+    async fn dashboard_limits_the_queue_to_configured_repositories() {
+        let config = FrankieConfig {
+            token: Some("ghp_example".to_owned()),
+            dashboard: true,
+            dashboard_repos: vec!["OCTO/lib".to_owned()],
+            ..Default::default()
+        };
+
+        let output = render(&config).await;
+
+        assert!(output.contains("octo/lib#7"), "missing queued PR: {output}");
+        assert!(!output.contains("octo/app#42"), "unexpected PR: {output}");
+    }
+}
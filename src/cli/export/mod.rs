@@ -5,5 +5,6 @@
 //! to allow sharing with integration tests.
 
 pub use frankie::{
-    ExportFormat, ExportedComment, sort_comments, write_jsonl, write_markdown, write_template,
+    ExportFormat, ExportedComment, sort_comments, timeline_comments, write_jsonl, write_markdown,
+    write_template,
 };
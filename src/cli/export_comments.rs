@@ -10,13 +10,16 @@ use camino::Utf8Path;
 use cap_std::ambient_authority;
 use cap_std::fs_utf8::Dir;
 
-use frankie::{
-    FrankieConfig, IntakeError, OctocrabReviewCommentGateway, PersonalAccessToken, PrUrl,
-    PullRequestLocator, ReviewCommentGateway,
+use frankie::{FrankieConfig, IntakeError, PrUrl, PullRequestLocator, build_timeline};
+
+use super::pull_request_context::{
+    fetch_pull_request_comments, fetch_pull_request_reviews, load_review_comments,
+    resolve_credentials,
 };
 
 use super::export::{
-    ExportFormat, ExportedComment, sort_comments, write_jsonl, write_markdown, write_template,
+    ExportFormat, ExportedComment, sort_comments, timeline_comments, write_jsonl, write_markdown,
+    write_template,
 };
 
 /// Parameters for export operations.
@@ -53,6 +56,8 @@ impl<'a> ExportParams<'a> {
 /// - The export format is invalid
 /// - The template file is missing when using template format
 /// - The GitHub API call fails
+/// - Offline mode is set and no comments are stored for the PR
+/// - Offline mode is set together with `--timeline`
 /// - Writing to the output fails
 pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let pr_url = config.require_pr_url()?;
@@ -62,15 +67,17 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let template_content = load_template_if_needed(config, export_format)?;
 
     let locator = PullRequestLocator::parse(pr_url)?;
-    let token = PersonalAccessToken::new(config.resolve_token()?)?;
-
-    // Fetch review comments
-    let gateway = OctocrabReviewCommentGateway::new(&token, locator.api_base().as_str())?;
-    let reviews = gateway.list_review_comments(&locator).await?;
 
-    // Convert and sort comments
-    let mut comments: Vec<ExportedComment> = reviews.iter().map(ExportedComment::from).collect();
-    sort_comments(&mut comments);
+    let comments = if config.timeline {
+        load_timeline_comments(config, &locator).await?
+    } else {
+        // Load review comments from GitHub, or from the local database offline
+        let reviews = load_review_comments(config, &locator).await?;
+        let mut comments: Vec<ExportedComment> =
+            reviews.iter().map(ExportedComment::from).collect();
+        sort_comments(&mut comments);
+        comments
+    };
 
     // Write to output
     let params = ExportParams::new(
@@ -82,6 +89,28 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     write_output(config, &params)
 }
 
+/// Loads the whole conversation timeline: issue comments, reviews, and
+/// review threads in chronological order.
+async fn load_timeline_comments(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<Vec<ExportedComment>, IntakeError> {
+    if config.is_offline() {
+        return Err(IntakeError::Configuration {
+            message: "--timeline is not available offline: issue comments are not stored locally"
+                .to_owned(),
+        });
+    }
+
+    let review_comments = load_review_comments(config, locator).await?;
+    let credentials = resolve_credentials(config, locator)?;
+    let issue_comments = fetch_pull_request_comments(config, locator, &credentials).await?;
+    let reviews = fetch_pull_request_reviews(config, locator, &credentials).await?;
+
+    let timeline = build_timeline(&issue_comments, &reviews, &review_comments);
+    Ok(timeline_comments(&timeline))
+}
+
 /// Parses the export format from configuration.
 fn parse_export_format(config: &FrankieConfig) -> Result<ExportFormat, IntakeError> {
     config
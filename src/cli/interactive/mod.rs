@@ -6,12 +6,13 @@ use std::path::Path;
 use frankie::github::RepositoryGateway;
 use frankie::local::{GitHubOrigin, LocalDiscoveryError, discover_repository};
 use frankie::{
-    FrankieConfig, IntakeError, OctocrabRepositoryGateway, PersonalAccessToken, RepositoryIntake,
+    FrankieConfig, GitHubCredentials, IntakeError, OctocrabRepositoryGateway, RepositoryIntake,
     RepositoryLocator,
 };
 
 use super::default_listing_params;
 use super::output::write_listing_summary;
+use super::pull_request_context::resolve_host_credentials;
 
 #[cfg(test)]
 mod tests;
@@ -33,7 +34,7 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
             run_discovered_repository_with_gateway_builder(
                 config,
                 local_repo.github_origin(),
-                OctocrabRepositoryGateway::for_token,
+                |credentials, locator| OctocrabRepositoryGateway::for_token(credentials, locator),
                 &mut stdout,
             )
             .await
@@ -53,7 +54,7 @@ pub async fn run_discovered_repository_with_gateway_builder<G, F, W>(
 ) -> Result<(), IntakeError>
 where
     G: RepositoryGateway,
-    F: FnOnce(&PersonalAccessToken, &RepositoryLocator) -> Result<G, IntakeError>,
+    F: FnOnce(&GitHubCredentials, &RepositoryLocator) -> Result<G, IntakeError>,
     W: Write,
 {
     let owner = github_origin.owner();
@@ -65,11 +66,11 @@ where
         "Discovered repository from local Git: {owner}/{repo}"
     ));
 
-    let token_value = config.resolve_token()?;
     let locator = RepositoryLocator::from_github_origin(github_origin)?;
-    let token = PersonalAccessToken::new(token_value)?;
+    let credentials =
+        resolve_host_credentials(config, locator.host(), locator.api_base().as_str())?;
 
-    let gateway = build_gateway(&token, &locator)?;
+    let gateway = build_gateway(&credentials, &locator)?;
     let intake = RepositoryIntake::new(&gateway);
 
     let result = intake
@@ -4,7 +4,9 @@ use std::sync::{Arc, Mutex};
 
 use frankie::github::PageInfo;
 use frankie::local::GitHubOrigin;
-use frankie::{FrankieConfig, IntakeError, PaginatedPullRequests, PullRequestState};
+use frankie::{
+    FrankieConfig, GitHubCredentials, IntakeError, PaginatedPullRequests, PullRequestState,
+};
 
 use super::super::run_discovered_repository_with_gateway_builder;
 use crate::cli::test_utils::CapturingGateway;
@@ -34,10 +36,12 @@ async fn extracts_owner_repo_from_github_origin_and_wires_gateway() {
     run_discovered_repository_with_gateway_builder(
         &config,
         &origin,
-        move |token, locator| {
-            assert_eq!(
-                token.value(),
-                "ghp_test_token",
+        move |credentials, locator| {
+            assert!(
+                matches!(
+                    credentials,
+                    GitHubCredentials::PersonalAccessToken(token) if token.value() == "ghp_test_token"
+                ),
                 "token should be passed to gateway builder"
             );
             assert_eq!(
@@ -108,7 +112,7 @@ async fn uses_enterprise_api_for_enterprise_origin() {
     run_discovered_repository_with_gateway_builder(
         &config,
         &origin,
-        move |_token, locator| {
+        move |_credentials, locator| {
             assert!(
                 locator
                     .api_base()
@@ -127,8 +131,15 @@ async fn uses_enterprise_api_for_enterprise_origin() {
 
 #[tokio::test]
 async fn propagates_token_error_when_missing() {
-    // Ensure GITHUB_TOKEN is not set (resolve_token falls back to env var)
-    let _guard = env_lock::lock_env([("GITHUB_TOKEN", None::<&str>)]);
+    // Ensure no ambient token is found: GITHUB_TOKEN is unset, the gh CLI
+    // configuration is missing, and git runs no credential helper
+    let _guard = env_lock::lock_env([
+        ("GITHUB_TOKEN", None),
+        ("GH_CONFIG_DIR", Some("/nonexistent/frankie-gh-config")),
+        ("GIT_CONFIG_GLOBAL", Some("/dev/null")),
+        ("GIT_CONFIG_NOSYSTEM", Some("1")),
+        ("GIT_CONFIG_COUNT", None),
+    ]);
 
     let config = FrankieConfig {
         token: None,
@@ -143,7 +154,7 @@ async fn propagates_token_error_when_missing() {
     let result = run_discovered_repository_with_gateway_builder(
         &config,
         &origin,
-        |_token, _locator| -> Result<CapturingGateway, IntakeError> {
+        |_credentials, _locator| -> Result<CapturingGateway, IntakeError> {
             panic!("gateway builder should not be called when token is missing")
         },
         &mut buffer,
@@ -178,7 +189,7 @@ async fn propagates_gateway_errors() {
     let result = run_discovered_repository_with_gateway_builder(
         &config,
         &origin,
-        |_token, _locator| Ok(gateway),
+        |_credentials, _locator| Ok(gateway),
         &mut buffer,
     )
     .await;
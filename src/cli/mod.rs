@@ -2,11 +2,18 @@
 //!
 //! This module contains the implementations for different operation modes:
 //! - [`ai_rewrite`]: Non-interactive AI draft expansion/rewording
+//! - [`auth`]: Manage the encrypted credential file
+//! - [`backoff_notice`]: Announce rate-limit waits on stderr
+//! - [`dashboard`]: Cross-repository review queue
 //! - [`export_comments`]: Export review comments in structured formats
 //! - [`interactive`]: Local repository discovery and listing
 //! - [`migrations`]: Database schema migrations
+//! - [`outbox`]: Inspect, retry, or discard queued review writes
+//! - [`post_reply`]: Post a threaded reply to a review comment
 //! - [`repository_listing`]: List PRs for a specified repository
+//! - [`resolve_threads`]: Resolve or unresolve review threads by comment ID
 //! - [`review_tui`]: Interactive TUI for reviewing PR comments
+//! - [`search_pull_requests`]: Search PRs with GitHub search qualifiers
 //! - [`single_pr`]: Load details for a single pull request
 //! - [`summarize_discussions`]: Generate AI summaries for PR discussions
 //! - [`verify_resolutions`]: Automated resolution verification for comments
@@ -17,14 +24,21 @@
 use frankie::{ListPullRequestsParams, PullRequestState};
 
 pub mod ai_rewrite;
+pub mod auth;
+pub mod backoff_notice;
+pub mod dashboard;
 pub mod export;
 pub mod export_comments;
 pub mod interactive;
 pub mod migrations;
+pub mod outbox;
 pub mod output;
+pub mod post_reply;
 mod pull_request_context;
 pub mod repository_listing;
+pub mod resolve_threads;
 pub mod review_tui;
+pub mod search_pull_requests;
 pub mod single_pr;
 pub mod summarize_discussions;
 pub mod verify_resolutions;
@@ -0,0 +1,296 @@
+//! CLI operation mode for inspecting, retrying, and discarding queued writes.
+
+use std::io::{self, Write};
+
+use frankie::persistence::{QueuedWrite, WriteOutbox};
+use frankie::{
+    FrankieConfig, IntakeError, OutboxAction, OutboxReplayReport, OutboxReplayService,
+    PullRequestLocator, ReplayOutcome,
+};
+
+use super::output::io_error;
+use super::pull_request_context::{
+    map_persistence_error, resolve_credentials, review_comment_gateway,
+};
+
+/// Runs the requested action against the write outbox.
+///
+/// `--outbox` lists every queued write, `--outbox-retry <id|all>` sends the
+/// selected writes to GitHub, and `--outbox-discard <id>` removes a write
+/// without sending it.
+///
+/// # Errors
+///
+/// Returns an error if the outbox cannot be read or updated, the intent ID is
+/// unknown, a retried write still fails, or writing output fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let outbox = open_outbox(config)?;
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+
+    match config.outbox_action() {
+        OutboxAction::List => {
+            let queued = outbox
+                .list()
+                .map_err(|error| map_persistence_error("list queued writes", &error))?;
+            write_queued_writes(&mut writer, &queued)
+        }
+        OutboxAction::Discard(intent_id) => {
+            discard(&outbox, intent_id)?;
+            writeln!(writer, "Discarded queued write {intent_id}").map_err(|error| io_error(&error))
+        }
+        OutboxAction::RetryAll => {
+            let queued = outbox
+                .list()
+                .map_err(|error| map_persistence_error("list queued writes", &error))?;
+            retry(config, &outbox, queued, &mut writer).await
+        }
+        OutboxAction::Retry(intent_id) => {
+            let write = find_queued_write(&outbox, intent_id)?;
+            retry(config, &outbox, vec![write], &mut writer).await
+        }
+    }
+}
+
+fn open_outbox(config: &FrankieConfig) -> Result<WriteOutbox, IntakeError> {
+    WriteOutbox::new(config.database_url.clone().unwrap_or_default())
+        .map_err(|error| map_persistence_error("open write outbox", &error))
+}
+
+fn find_queued_write(outbox: &WriteOutbox, intent_id: &str) -> Result<QueuedWrite, IntakeError> {
+    outbox
+        .get(intent_id)
+        .map_err(|error| map_persistence_error("load queued write", &error))?
+        .ok_or_else(|| unknown_intent(intent_id))
+}
+
+fn discard(outbox: &WriteOutbox, intent_id: &str) -> Result<(), IntakeError> {
+    let removed = outbox
+        .remove(intent_id)
+        .map_err(|error| map_persistence_error("discard queued write", &error))?;
+    if removed {
+        Ok(())
+    } else {
+        Err(unknown_intent(intent_id))
+    }
+}
+
+fn unknown_intent(intent_id: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: format!("no queued write with intent ID {intent_id}"),
+    }
+}
+
+/// Replays the `queued` writes pull request by pull request and reports each
+/// outcome.
+///
+/// Returns the first failure so the process exits unsuccessfully while
+/// writes remain queued.
+async fn retry<W: Write>(
+    config: &FrankieConfig,
+    outbox: &WriteOutbox,
+    queued: Vec<QueuedWrite>,
+    writer: &mut W,
+) -> Result<(), IntakeError> {
+    if queued.is_empty() {
+        return writeln!(writer, "No queued writes.").map_err(|error| io_error(&error));
+    }
+
+    let mut first_failure = None;
+    for (locator, batch) in group_by_pull_request(queued) {
+        let credentials = resolve_credentials(config, &locator)?;
+        let gateway = review_comment_gateway(config, &locator, &credentials)?;
+        let report = OutboxReplayService::new(&gateway, outbox.clone())
+            .replay_writes(&locator, &batch)
+            .await;
+        write_replay_report(writer, &report)?;
+        first_failure = first_failure.or_else(|| first_failed(report));
+    }
+
+    first_failure.map_or(Ok(()), Err)
+}
+
+/// Groups queued writes by pull request, preserving queue order within each
+/// group.
+fn group_by_pull_request(queued: Vec<QueuedWrite>) -> Vec<(PullRequestLocator, Vec<QueuedWrite>)> {
+    let mut groups: Vec<(PullRequestLocator, Vec<QueuedWrite>)> = Vec::new();
+    for write in queued {
+        match groups
+            .iter_mut()
+            .find(|(locator, _)| *locator == write.locator)
+        {
+            Some((_, batch)) => batch.push(write),
+            None => groups.push((write.locator.clone(), vec![write])),
+        }
+    }
+    groups
+}
+
+fn first_failed(report: OutboxReplayReport) -> Option<IntakeError> {
+    report
+        .outcomes
+        .into_iter()
+        .find_map(|(_, outcome)| match outcome {
+            ReplayOutcome::Failed(error) => Some(error),
+            _ => None,
+        })
+}
+
+fn write_queued_writes<W: Write>(
+    writer: &mut W,
+    queued: &[QueuedWrite],
+) -> Result<(), IntakeError> {
+    if queued.is_empty() {
+        return writeln!(writer, "No queued writes.").map_err(|error| io_error(&error));
+    }
+
+    for write in queued {
+        write_queued_write(writer, write).map_err(|error| io_error(&error))?;
+    }
+    Ok(())
+}
+
+fn write_queued_write<W: Write>(writer: &mut W, write: &QueuedWrite) -> io::Result<()> {
+    writeln!(
+        writer,
+        "{}  {}/{}#{}  {}",
+        write.intent_id,
+        write.locator.owner().as_str(),
+        write.locator.repository().as_str(),
+        write.locator.number().get(),
+        write.intent.summary()
+    )?;
+    write!(
+        writer,
+        "    queued {} UTC, {} failed attempt(s)",
+        write.queued_at, write.attempt_count
+    )?;
+    match write.last_error.as_deref() {
+        Some(error) => writeln!(writer, "; last error: {error}"),
+        None => writeln!(writer),
+    }
+}
+
+fn write_replay_report<W: Write>(
+    writer: &mut W,
+    report: &OutboxReplayReport,
+) -> Result<(), IntakeError> {
+    for (intent_id, outcome) in &report.outcomes {
+        let line = match outcome {
+            ReplayOutcome::Applied => format!("Sent {intent_id}"),
+            ReplayOutcome::AlreadyApplied => {
+                format!("Dropped {intent_id}: already applied on GitHub")
+            }
+            ReplayOutcome::Failed(error) => format!("Failed {intent_id}: {error}"),
+            ReplayOutcome::Deferred => format!("Deferred {intent_id}: GitHub is unreachable"),
+        };
+        writeln!(writer, "{line}").map_err(|error| io_error(&error))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `outbox` module.
+
+    use rstest::rstest;
+
+    use super::{group_by_pull_request, write_queued_writes, write_replay_report};
+    use frankie::persistence::QueuedWrite;
+    use frankie::{
+        IntakeError, OutboxReplayReport, PullRequestLocator, ReplayOutcome, WriteIntent,
+    };
+
+    fn queued(intent_id: &str, pr_url: &str, last_error: Option<&str>) -> QueuedWrite {
+        QueuedWrite {
+            intent_id: intent_id.to_owned(),
+            locator: PullRequestLocator::parse(pr_url).expect("locator should parse"),
+            intent: WriteIntent::ThreadResolution {
+                thread_id: "PRRT_1".to_owned(),
+                resolved: true,
+            },
+            attempt_count: u32::from(last_error.is_some()),
+            last_error: last_error.map(str::to_owned),
+            queued_at: "2026-04-17 09:30:00".to_owned(),
+        }
+    }
+
+    fn render(result: impl FnOnce(&mut Vec<u8>) -> Result<(), IntakeError>) -> String {
+        let mut output = Vec::new();
+        assert!(result(&mut output).is_ok());
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    #[rstest]
+    fn write_queued_writes_lists_pull_request_and_last_error() {
+        let writes = vec![queued(
+            "w1",
+            "https://github.com/owner/repo/pull/3",
+            Some("network error: timed out"),
+        )];
+
+        let output = render(|writer| write_queued_writes(writer, &writes));
+
+        assert_eq!(
+            output,
+            concat!(
+                "w1  owner/repo#3  resolve thread PRRT_1\n",
+                "    queued 2026-04-17 09:30:00 UTC, 1 failed attempt(s); ",
+                "last error: network error: timed out\n"
+            )
+        );
+    }
+
+    #[rstest]
+    fn write_queued_writes_reports_an_empty_outbox() {
+        let output = render(|writer| write_queued_writes(writer, &[]));
+
+        assert_eq!(output, "No queued writes.\n");
+    }
+
+    #[rstest]
+    fn write_replay_report_describes_each_outcome() {
+        let report = OutboxReplayReport {
+            outcomes: vec![
+                ("w1".to_owned(), ReplayOutcome::Applied),
+                ("w2".to_owned(), ReplayOutcome::AlreadyApplied),
+                (
+                    "w3".to_owned(),
+                    ReplayOutcome::Failed(IntakeError::Network {
+                        message: "reset".to_owned(),
+                    }),
+                ),
+                ("w4".to_owned(), ReplayOutcome::Deferred),
+            ],
+        };
+
+        let output = render(|writer| write_replay_report(writer, &report));
+
+        assert_eq!(
+            output,
+            concat!(
+                "Sent w1\n",
+                "Dropped w2: already applied on GitHub\n",
+                "Failed w3: network error talking to GitHub: reset\n",
+                "Deferred w4: GitHub is unreachable\n"
+            )
+        );
+    }
+
+    #[rstest]
+    fn group_by_pull_request_keeps_queue_order() {
+        let writes = vec![
+            queued("w1", "https://github.com/owner/repo/pull/1", None),
+            queued("w2", "https://github.com/owner/repo/pull/2", None),
+            queued("w3", "https://github.com/owner/repo/pull/1", None),
+        ];
+
+        let groups = group_by_pull_request(writes);
+
+        let ids: Vec<Vec<&str>> = groups
+            .iter()
+            .map(|(_, batch)| batch.iter().map(|write| write.intent_id.as_str()).collect())
+            .collect();
+        assert_eq!(ids, vec![vec!["w1", "w3"], vec!["w2"]]);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::io::{self, Write};
 
+use frankie::github::PullRequestMetadata;
 use frankie::{IntakeError, PaginatedPullRequests, PullRequestDetails};
 
 /// Writes a summary of pull request details to stdout.
@@ -30,34 +31,136 @@ pub fn write_pr_summary_to<W: Write>(
         .html_url
         .as_deref()
         .unwrap_or("no HTML URL provided");
-    let message = format!(
-        "Loaded PR #{} by {author}: {title}\nURL: {url}\nComments: {}",
-        details.metadata.number,
-        details.comments.len()
-    );
+    let mut lines = vec![
+        format!(
+            "Loaded PR #{} by {author}: {title}",
+            details.metadata.number
+        ),
+        format!("URL: {url}"),
+    ];
+    lines.extend(metadata_lines(&details.metadata));
+    lines.push(format!("Comments: {}", details.comments.len()));
+    if let Some(body) = details
+        .metadata
+        .body
+        .as_deref()
+        .filter(|body| !body.trim().is_empty())
+    {
+        lines.push("Description:".to_owned());
+        lines.extend(body.trim_end().lines().map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("  {line}")
+            }
+        }));
+    }
 
-    writeln!(writer, "{message}").map_err(|error| IntakeError::Io {
+    writeln!(writer, "{}", lines.join("\n")).map_err(|error| IntakeError::Io {
         message: error.to_string(),
     })
 }
 
+/// Formats the optional pull request details, one line each, skipping those
+/// GitHub did not report.
+fn metadata_lines(metadata: &PullRequestMetadata) -> Vec<String> {
+    let state = metadata.state.as_deref().map(|state| {
+        if metadata.draft {
+            format!("State: {state} (draft)")
+        } else {
+            format!("State: {state}")
+        }
+    });
+    let branches = metadata
+        .branch_summary()
+        .map(|branches| format!("Branches: {branches}"));
+    let commits = metadata
+        .head
+        .sha
+        .as_deref()
+        .zip(metadata.base.sha.as_deref())
+        .map(|(head, base)| format!("Commits: head {head}, base {base}"));
+    let labels =
+        (!metadata.labels.is_empty()).then(|| format!("Labels: {}", metadata.labels.join(", ")));
+    let reviewers = (!metadata.requested_reviewers.is_empty()).then(|| {
+        format!(
+            "Requested reviewers: {}",
+            metadata.requested_reviewers.join(", ")
+        )
+    });
+    let mergeable = metadata
+        .mergeable_state
+        .as_deref()
+        .map(|mergeable| format!("Mergeable: {mergeable}"));
+    let changes = metadata
+        .change_summary()
+        .map(|changes| format!("Changes: {changes}"));
+
+    [
+        state, branches, commits, labels, reviewers, mergeable, changes,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
 /// Writes a summary of paginated pull requests to the given writer.
 pub fn write_listing_summary<W: Write>(
     writer: &mut W,
     result: &PaginatedPullRequests,
     owner: &str,
     repo: &str,
+) -> Result<(), IntakeError> {
+    write_pull_requests(
+        writer,
+        &format!("Pull requests for {owner}/{repo}:"),
+        result,
+    )
+}
+
+/// Writes pull request search results to the given writer.
+///
+/// Each result is prefixed with its repository, and the search API's rate
+/// limit is reported when GitHub returned it.
+pub fn write_search_summary<W: Write>(
+    writer: &mut W,
+    result: &PaginatedPullRequests,
+    query: &str,
+) -> Result<(), IntakeError> {
+    write_pull_requests(
+        writer,
+        &format!("Pull requests matching `{query}`:"),
+        result,
+    )?;
+
+    if let Some(rate_limit) = result.rate_limit {
+        writeln!(
+            writer,
+            "Search rate limit: {} of {} requests remaining.",
+            rate_limit.remaining(),
+            rate_limit.limit()
+        )
+        .map_err(|e| io_error(&e))?;
+    }
+
+    Ok(())
+}
+
+fn write_pull_requests<W: Write>(
+    writer: &mut W,
+    heading: &str,
+    result: &PaginatedPullRequests,
 ) -> Result<(), IntakeError> {
     let page_info = &result.page_info;
 
-    writeln!(writer, "Pull requests for {owner}/{repo}:").map_err(|e| io_error(&e))?;
+    writeln!(writer, "{heading}").map_err(|e| io_error(&e))?;
     writeln!(writer).map_err(|e| io_error(&e))?;
 
     for pr in &result.items {
         let title = pr.title.as_deref().unwrap_or("(no title)");
         let author = pr.author.as_deref().unwrap_or("unknown");
         let state = pr.state.as_deref().unwrap_or("unknown");
-        writeln!(writer, "  #{} [{state}] {title} (@{author})", pr.number)
+        writeln!(writer, "  {} [{state}] {title} (@{author})", pr.reference())
             .map_err(|e| io_error(&e))?;
     }
 
@@ -89,10 +192,10 @@ pub(crate) fn io_error(error: &io::Error) -> IntakeError {
 mod tests {
     //! Unit tests for the `output` module.
 
-    use frankie::github::{PageInfo, PullRequestMetadata};
+    use frankie::github::{PageInfo, PullRequestBranch, PullRequestMetadata};
     use frankie::{PaginatedPullRequests, PullRequestDetails, PullRequestSummary, RateLimitInfo};
 
-    use super::{write_listing_summary, write_pr_summary_to};
+    use super::{write_listing_summary, write_pr_summary_to, write_search_summary};
 
     #[test]
     fn write_listing_summary_includes_items_and_pagination() {
@@ -109,6 +212,7 @@ mod tests {
                 author: Some("octocat".to_owned()),
                 created_at: None,
                 updated_at: None,
+                ..PullRequestSummary::default()
             }],
             page_info,
             rate_limit: Some(RateLimitInfo::new(5000, 4999, 1_700_000_000)),
@@ -157,6 +261,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_search_summary_names_repositories_and_rate_limit() {
+        let result = PaginatedPullRequests {
+            items: vec![PullRequestSummary {
+                number: 7,
+                repository: Some("octo/lib".to_owned()),
+                title: Some("Fix flaky test".to_owned()),
+                state: Some("open".to_owned()),
+                author: Some("octocat".to_owned()),
+                ..PullRequestSummary::default()
+            }],
+            page_info: PageInfo::builder(1, 50).total_pages(Some(1)).build(),
+            rate_limit: Some(RateLimitInfo::new(30, 29, 1_700_000_000)),
+        };
+
+        let mut buffer = Vec::new();
+        write_search_summary(&mut buffer, &result, "is:pr review-requested:@me")
+            .expect("should write search summary");
+
+        let output = String::from_utf8(buffer).expect("output should be valid UTF-8");
+        assert!(
+            output.contains("Pull requests matching `is:pr review-requested:@me`:"),
+            "missing header: {output}"
+        );
+        assert!(
+            output.contains("octo/lib#7 [open] Fix flaky test (@octocat)"),
+            "missing PR line: {output}"
+        );
+        assert!(
+            output.contains("Search rate limit: 29 of 30 requests remaining."),
+            "missing rate limit line: {output}"
+        );
+    }
+
     #[test]
     fn write_pr_summary_to_includes_pr_details() {
         let details = PullRequestDetails {
@@ -191,4 +329,58 @@ mod tests {
             "missing comment count: {output}"
         );
     }
+
+    #[test]
+    fn write_pr_summary_to_includes_branches_labels_and_changes() {
+        let details = PullRequestDetails {
+            metadata: PullRequestMetadata {
+                number: 42,
+                title: Some("Add widgets".to_owned()),
+                state: Some("open".to_owned()),
+                author: Some("octocat".to_owned()),
+                html_url: Some("https://github.com/octo/cat/pull/42".to_owned()),
+                body: Some("Widgets for everyone.\n\nCloses #7.".to_owned()),
+                draft: true,
+                base: PullRequestBranch {
+                    name: Some("main".to_owned()),
+                    sha: Some("aaa111".to_owned()),
+                },
+                head: PullRequestBranch {
+                    name: Some("feature/widgets".to_owned()),
+                    sha: Some("bbb222".to_owned()),
+                },
+                labels: vec!["enhancement".to_owned(), "ui".to_owned()],
+                requested_reviewers: vec!["hubot".to_owned()],
+                mergeable_state: Some("clean".to_owned()),
+                additions: Some(120),
+                deletions: Some(8),
+                changed_files: Some(4),
+            },
+            comments: vec![],
+        };
+
+        let mut buffer = Vec::new();
+        write_pr_summary_to(&mut buffer, &details).expect("should write PR summary");
+
+        let output = String::from_utf8(buffer).expect("output should be valid UTF-8");
+        assert_eq!(
+            output,
+            concat!(
+                "Loaded PR #42 by octocat: Add widgets\n",
+                "URL: https://github.com/octo/cat/pull/42\n",
+                "State: open (draft)\n",
+                "Branches: feature/widgets -> main\n",
+                "Commits: head bbb222, base aaa111\n",
+                "Labels: enhancement, ui\n",
+                "Requested reviewers: hubot\n",
+                "Mergeable: clean\n",
+                "Changes: +120 -8 in 4 files\n",
+                "Comments: 0\n",
+                "Description:\n",
+                "  Widgets for everyone.\n",
+                "\n",
+                "  Closes #7.\n",
+            )
+        );
+    }
 }
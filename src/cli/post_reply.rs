@@ -0,0 +1,79 @@
+//! CLI operation mode for posting a threaded review reply.
+
+use std::io::{self, Write};
+
+use frankie::{FrankieConfig, IntakeError, ReviewComment, ReviewCommentGateway};
+
+use super::output::io_error;
+use super::pull_request_context::{resolve_credentials, resolve_locator, review_comment_gateway};
+
+/// Posts `--reply-text` as a reply to the `--reply-to` review comment.
+///
+/// # Errors
+///
+/// Returns an error if configuration is missing, the parent comment does not
+/// exist on the pull request, GitHub rejects the reply, or writing output
+/// fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let (comment_id, body) = require_reply_arguments(config)?;
+    let locator = resolve_locator(config)?;
+    let credentials = resolve_credentials(config, &locator)?;
+    let gateway = review_comment_gateway(config, &locator, &credentials)?;
+    let reply = gateway
+        .reply_to_review_comment(&locator, comment_id, body)
+        .await?;
+
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    write_reply_confirmation(&mut writer, comment_id, &reply)
+}
+
+fn require_reply_arguments(config: &FrankieConfig) -> Result<(u64, &str), IntakeError> {
+    match (config.reply_to, config.reply_text.as_deref()) {
+        (Some(comment_id), Some(body)) => Ok((comment_id, body)),
+        _ => Err(IntakeError::Configuration {
+            message: "--reply-to and --reply-text must be provided together".to_owned(),
+        }),
+    }
+}
+
+fn write_reply_confirmation<W: Write>(
+    writer: &mut W,
+    parent_id: u64,
+    reply: &ReviewComment,
+) -> Result<(), IntakeError> {
+    writeln!(
+        writer,
+        "Posted reply {} to review comment {parent_id}",
+        reply.id
+    )
+    .map_err(|error| io_error(&error))
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `post_reply` module.
+
+    use rstest::rstest;
+
+    use super::write_reply_confirmation;
+    use frankie::ReviewComment;
+
+    #[rstest]
+    fn write_reply_confirmation_reports_both_ids() {
+        let reply = ReviewComment {
+            id: 10,
+            in_reply_to_id: Some(1),
+            ..ReviewComment::default()
+        };
+        let mut output = Vec::new();
+
+        let result = write_reply_confirmation(&mut output, 1, &reply);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            String::from_utf8_lossy(&output),
+            "Posted reply 10 to review comment 1\n"
+        );
+    }
+}
@@ -1,11 +1,18 @@
 //! Shared pull-request context helpers for CLI operation modes.
 
+use std::io::{self, Write};
 use std::path::Path;
 
-use frankie::github::PullRequestGateway;
+use frankie::github::{PullRequestComment, PullRequestGateway, PullRequestMetadata};
 use frankie::local::discover_repository;
+use frankie::persistence::{
+    CommentPageCache, PersistenceError, PullRequestMetadataCache, ReviewCommentArchive,
+};
 use frankie::{
-    FrankieConfig, IntakeError, OctocrabGateway, PersonalAccessToken, PullRequestLocator,
+    CiStatus, CiStatusGateway, FrankieConfig, GitHubCredentials, IntakeError,
+    OctocrabCachingGateway, OctocrabCiStatusGateway, OctocrabGateway, OctocrabReviewCommentGateway,
+    PersonalAccessToken, PullRequestLocator, PullRequestReview, ReviewComment,
+    ReviewCommentGateway,
 };
 
 /// Resolves a [`PullRequestLocator`] from CLI configuration.
@@ -29,6 +36,184 @@ pub(super) fn resolve_locator(config: &FrankieConfig) -> Result<PullRequestLocat
     PullRequestLocator::parse(config.require_pr_url()?)
 }
 
+/// Resolves the credentials for the host serving the pull request.
+///
+/// See [`resolve_host_credentials`].
+///
+/// # Errors
+///
+/// Returns an error when no credentials are configured for the host, the
+/// token is blank, or the GitHub App private key cannot be read.
+pub(super) fn resolve_credentials(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<GitHubCredentials, IntakeError> {
+    resolve_host_credentials(config, locator.host(), locator.api_base().as_str())
+}
+
+/// Resolves the credentials for a GitHub host.
+///
+/// A configured GitHub App installation wins over tokens; otherwise the token
+/// comes from [`FrankieConfig::resolve_token_source`]. When the token was
+/// discovered from the `gh` CLI or a git credential helper, a note naming the
+/// source (never the token) is written to stderr. When no source has a token,
+/// a terminal user is prompted for the credential file passphrase.
+///
+/// # Errors
+///
+/// Returns an error when no credentials are configured for the host, the
+/// token is blank, or the GitHub App private key cannot be read.
+pub(super) fn resolve_host_credentials(
+    config: &FrankieConfig,
+    host: &str,
+    api_base: &str,
+) -> Result<GitHubCredentials, IntakeError> {
+    if let Some(installation) = config.github_app_installation(api_base)? {
+        return Ok(installation.into());
+    }
+
+    let resolved = match config.resolve_token_source(host) {
+        Err(IntakeError::MissingToken) => {
+            let stored = super::auth::prompt_for_stored_token(config, host)?;
+            return stored
+                .ok_or(IntakeError::MissingToken)
+                .and_then(PersonalAccessToken::new)
+                .map(GitHubCredentials::from);
+        }
+        other => other?,
+    };
+    if resolved.source().is_discovered() {
+        // Report where the token came from (ignore write errors)
+        drop(writeln!(
+            io::stderr(),
+            "Using the GitHub token for {host} from {}",
+            resolved.source()
+        ));
+    }
+    PersonalAccessToken::new(resolved.into_value()).map(GitHubCredentials::from)
+}
+
+/// Builds the review comment gateway for a pull request.
+///
+/// When a database URL is configured, comment pages are cached with their
+/// `ETag`s and every fetched comment is archived for offline reading.
+///
+/// # Errors
+///
+/// Returns an error when the Octocrab client cannot be built.
+pub(super) fn review_comment_gateway(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<OctocrabReviewCommentGateway, IntakeError> {
+    let mut gateway = OctocrabReviewCommentGateway::for_token(credentials, locator)?;
+    let Some(database_url) = config.database_url.as_deref() else {
+        return Ok(gateway);
+    };
+    if let Ok(cache) = CommentPageCache::new(database_url.to_owned()) {
+        gateway = gateway.with_page_cache(cache);
+    }
+    if let Ok(archive) = ReviewCommentArchive::new(database_url.to_owned()) {
+        gateway = gateway.with_archive(archive);
+    }
+    Ok(gateway)
+}
+
+/// Loads the pull request's review comments.
+///
+/// In offline mode the comments come from the local archive; otherwise they
+/// are fetched from GitHub (and archived when a database is configured).
+///
+/// # Errors
+///
+/// Returns an error when the token is missing or invalid, the GitHub request
+/// fails, or offline comments cannot be loaded.
+pub(super) async fn load_review_comments(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<Vec<ReviewComment>, IntakeError> {
+    if config.is_offline() {
+        return load_archived_review_comments(config, locator);
+    }
+
+    let credentials = resolve_credentials(config, locator)?;
+    let gateway = review_comment_gateway(config, locator, &credentials)?;
+    gateway.list_review_comments(locator).await
+}
+
+/// Loads the review comments archived for the pull request.
+///
+/// # Errors
+///
+/// Returns [`IntakeError::Configuration`] when no database is configured, the
+/// schema is missing, or the pull request has never been fetched, and
+/// [`IntakeError::Io`] when the database cannot be read.
+pub(super) fn load_archived_review_comments(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<Vec<ReviewComment>, IntakeError> {
+    let database_url =
+        config
+            .database_url
+            .as_deref()
+            .ok_or_else(|| IntakeError::Configuration {
+                message: PersistenceError::MissingDatabaseUrl.to_string(),
+            })?;
+    let archived = ReviewCommentArchive::new(database_url.to_owned())
+        .and_then(|archive| archive.load_comments(locator))
+        .map_err(|error| map_persistence_error("load stored review comments", &error))?;
+
+    archived.ok_or_else(|| IntakeError::Configuration {
+        message: format!(
+            concat!(
+                "no review comments stored for {}/{}#{}; run Frankie once ",
+                "without --offline to populate the local database"
+            ),
+            locator.owner().as_str(),
+            locator.repository().as_str(),
+            locator.number().get()
+        ),
+    })
+}
+
+/// Reads the pull-request title from the local metadata cache, if present.
+///
+/// Used offline, where the title cannot be fetched from GitHub.
+pub(super) fn cached_pull_request_title(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Option<String> {
+    cached_pull_request_metadata(config, locator)?.title
+}
+
+/// Reads the pull-request metadata from the local metadata cache, if
+/// present, regardless of its expiry.
+pub(super) fn cached_pull_request_metadata(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Option<PullRequestMetadata> {
+    let cache = PullRequestMetadataCache::new(config.database_url.clone()?).ok()?;
+    Some(cache.get(locator).ok()??.metadata)
+}
+
+/// Maps a local database error onto an [`IntakeError`], prefixed with the
+/// `action` that failed.
+///
+/// Missing or uninitialised databases are configuration problems; anything
+/// else is reported as an I/O failure.
+pub(super) fn map_persistence_error(action: &str, error: &PersistenceError) -> IntakeError {
+    match error {
+        PersistenceError::MissingDatabaseUrl
+        | PersistenceError::BlankDatabaseUrl
+        | PersistenceError::SchemaNotInitialised => IntakeError::Configuration {
+            message: format!("{action}: {error}"),
+        },
+        _ => IntakeError::Io {
+            message: format!("{action}: {error}"),
+        },
+    }
+}
+
 /// Fetches the pull-request title for prompt context when it is available.
 ///
 /// # Errors
@@ -36,13 +221,101 @@ pub(super) fn resolve_locator(config: &FrankieConfig) -> Result<PullRequestLocat
 /// Returns an error when the metadata request fails.
 pub(super) async fn fetch_pull_request_title(
     locator: &PullRequestLocator,
-    token: &PersonalAccessToken,
+    credentials: &GitHubCredentials,
 ) -> Result<Option<String>, IntakeError> {
-    let gateway = OctocrabGateway::for_token(token, locator)?;
+    let gateway = OctocrabGateway::for_token(credentials, locator)?;
     let metadata = gateway.pull_request(locator).await?;
     Ok(metadata.title)
 }
 
+/// Fetches the pull-request metadata, using the `SQLite` cache when a
+/// database URL is configured so it is also available offline.
+///
+/// # Errors
+///
+/// Returns an error when the metadata request fails.
+pub(super) async fn fetch_pull_request_metadata(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<PullRequestMetadata, IntakeError> {
+    if let Some(database_url) = config.database_url.as_deref() {
+        let gateway = OctocrabCachingGateway::for_token(
+            credentials,
+            locator,
+            database_url,
+            config.pr_metadata_cache_ttl_seconds,
+        )?;
+        gateway.pull_request(locator).await
+    } else {
+        let gateway = OctocrabGateway::for_token(credentials, locator)?;
+        gateway.pull_request(locator).await
+    }
+}
+
+/// Fetches the top-level pull request reviews, using the `SQLite` cache when
+/// a database URL is configured.
+///
+/// # Errors
+///
+/// Returns an error when the reviews request fails.
+pub(super) async fn fetch_pull_request_reviews(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<Vec<PullRequestReview>, IntakeError> {
+    if let Some(database_url) = config.database_url.as_deref() {
+        let gateway = OctocrabCachingGateway::for_token(
+            credentials,
+            locator,
+            database_url,
+            config.pr_metadata_cache_ttl_seconds,
+        )?;
+        gateway.list_reviews(locator).await
+    } else {
+        let gateway = OctocrabGateway::for_token(credentials, locator)?;
+        gateway.list_reviews(locator).await
+    }
+}
+
+/// Fetches the pull request's issue comments, sending conditional requests
+/// through the `SQLite` page cache when a database URL is configured.
+///
+/// # Errors
+///
+/// Returns an error when the comments request fails.
+pub(super) async fn fetch_pull_request_comments(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<Vec<PullRequestComment>, IntakeError> {
+    if let Some(database_url) = config.database_url.as_deref() {
+        let gateway = OctocrabCachingGateway::for_token(
+            credentials,
+            locator,
+            database_url,
+            config.pr_metadata_cache_ttl_seconds,
+        )?;
+        gateway.pull_request_comments(locator).await
+    } else {
+        let gateway = OctocrabGateway::for_token(credentials, locator)?;
+        gateway.pull_request_comments(locator).await
+    }
+}
+
+/// Fetches check runs and commit statuses for the pull-request head.
+///
+/// # Errors
+///
+/// Returns an error when the pull request or its CI results cannot be loaded.
+pub(super) async fn fetch_ci_status(
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<CiStatus, IntakeError> {
+    let gateway = OctocrabCiStatusGateway::new(credentials, locator.api_base().as_str())?;
+    gateway.head_ci_status(locator).await
+}
+
 pub(super) fn resolve_from_identifier(
     identifier: &str,
     no_local_discovery: bool,
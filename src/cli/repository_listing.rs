@@ -4,12 +4,13 @@ use std::io::{self, Write};
 
 use frankie::github::RepositoryGateway;
 use frankie::{
-    FrankieConfig, IntakeError, OctocrabRepositoryGateway, PersonalAccessToken, RepositoryIntake,
+    FrankieConfig, GitHubCredentials, IntakeError, OctocrabRepositoryGateway, RepositoryIntake,
     RepositoryLocator,
 };
 
 use super::default_listing_params;
 use super::output::write_listing_summary;
+use super::pull_request_context::resolve_host_credentials;
 
 /// Lists pull requests for a repository.
 ///
@@ -19,7 +20,12 @@ use super::output::write_listing_summary;
 /// Returns [`IntakeError::GitHub`] if the API request fails.
 pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let mut stdout = io::stdout().lock();
-    run_with_gateway_builder(config, OctocrabRepositoryGateway::for_token, &mut stdout).await
+    run_with_gateway_builder(
+        config,
+        |credentials, locator| OctocrabRepositoryGateway::for_token(credentials, locator),
+        &mut stdout,
+    )
+    .await
 }
 
 /// Lists pull requests using a custom gateway builder.
@@ -32,16 +38,15 @@ pub async fn run_with_gateway_builder<G, F, W>(
 ) -> Result<(), IntakeError>
 where
     G: RepositoryGateway,
-    F: FnOnce(&PersonalAccessToken, &RepositoryLocator) -> Result<G, IntakeError>,
+    F: FnOnce(&GitHubCredentials, &RepositoryLocator) -> Result<G, IntakeError>,
     W: Write,
 {
     let (owner, repo) = config.require_repository_info()?;
-    let token_value = config.resolve_token()?;
-
     let locator = RepositoryLocator::from_owner_repo(owner, repo)?;
-    let token = PersonalAccessToken::new(token_value)?;
+    let credentials =
+        resolve_host_credentials(config, locator.host(), locator.api_base().as_str())?;
 
-    let gateway = build_gateway(&token, &locator)?;
+    let gateway = build_gateway(&credentials, &locator)?;
     let intake = RepositoryIntake::new(&gateway);
 
     let result = intake
@@ -57,7 +62,9 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use frankie::github::PageInfo;
-    use frankie::{FrankieConfig, IntakeError, PaginatedPullRequests, PullRequestState};
+    use frankie::{
+        FrankieConfig, GitHubCredentials, IntakeError, PaginatedPullRequests, PullRequestState,
+    };
 
     use super::run_with_gateway_builder;
     use crate::cli::test_utils::CapturingGateway;
@@ -84,10 +91,12 @@ mod tests {
         let mut buffer = Vec::new();
         run_with_gateway_builder(
             &config,
-            move |token, locator| {
-                assert_eq!(
-                    token.value(),
-                    "ghp_example",
+            move |credentials, locator| {
+                assert!(
+                    matches!(
+                        credentials,
+                        GitHubCredentials::PersonalAccessToken(token) if token.value() == "ghp_example"
+                    ),
                     "unexpected token passed to gateway builder"
                 );
                 assert_eq!(
@@ -138,7 +147,8 @@ mod tests {
 
         let mut buffer = Vec::new();
         let result =
-            run_with_gateway_builder(&config, |_token, _locator| Ok(gateway), &mut buffer).await;
+            run_with_gateway_builder(&config, |_credentials, _locator| Ok(gateway), &mut buffer)
+                .await;
 
         assert!(
             matches!(result, Err(IntakeError::InvalidPagination { .. })),
@@ -0,0 +1,201 @@
+//! CLI operation mode for resolving and unresolving review threads.
+
+use std::io::{self, Write};
+
+use frankie::{FrankieConfig, IntakeError, ReviewComment, ReviewCommentGateway, ReviewThreadState};
+
+use super::output::io_error;
+use super::pull_request_context::{resolve_credentials, resolve_locator, review_comment_gateway};
+
+/// A single thread resolution change requested on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ThreadChange {
+    comment_id: u64,
+    thread_id: String,
+    resolved: bool,
+}
+
+/// Resolves and unresolves the review threads containing the configured
+/// comment IDs.
+///
+/// Every comment ID is looked up before any thread is changed, so an unknown
+/// ID leaves all threads untouched.
+///
+/// # Errors
+///
+/// Returns an error if configuration is missing, a comment is not part of the
+/// pull request, thread state cannot be loaded, GitHub rejects a change, or
+/// writing output fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let (resolve, unresolve) = config.thread_resolution_targets()?;
+    let locator = resolve_locator(config)?;
+    let credentials = resolve_credentials(config, &locator)?;
+    let gateway = review_comment_gateway(config, &locator, &credentials)?;
+    let comments = gateway.list_review_comments(&locator).await?;
+    let changes = plan_thread_changes(&comments, &resolve, &unresolve)?;
+
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    for change in changes {
+        let state = gateway
+            .set_review_thread_resolution(change.thread_id.as_str(), change.resolved)
+            .await?;
+        write_thread_change(&mut writer, change.comment_id, &state)?;
+    }
+
+    Ok(())
+}
+
+/// Maps requested comment IDs onto thread changes, one per thread.
+fn plan_thread_changes(
+    comments: &[ReviewComment],
+    resolve: &[u64],
+    unresolve: &[u64],
+) -> Result<Vec<ThreadChange>, IntakeError> {
+    let requests = resolve
+        .iter()
+        .map(|id| (*id, true))
+        .chain(unresolve.iter().map(|id| (*id, false)));
+
+    let mut changes: Vec<ThreadChange> = Vec::new();
+    for (comment_id, resolved) in requests {
+        let thread_id = thread_id_for_comment(comments, comment_id)?;
+        if changes.iter().any(|change| change.thread_id == thread_id) {
+            continue;
+        }
+        changes.push(ThreadChange {
+            comment_id,
+            thread_id: thread_id.to_owned(),
+            resolved,
+        });
+    }
+
+    Ok(changes)
+}
+
+fn thread_id_for_comment(comments: &[ReviewComment], comment_id: u64) -> Result<&str, IntakeError> {
+    let comment = comments
+        .iter()
+        .find(|comment| comment.id == comment_id)
+        .ok_or_else(|| IntakeError::Configuration {
+            message: format!("review comment {comment_id} was not found on the pull request"),
+        })?;
+
+    comment
+        .thread_state
+        .as_ref()
+        .map(|state| state.thread_id.as_str())
+        .ok_or_else(|| IntakeError::Api {
+            message: format!(
+                "thread state for review comment {comment_id} could not be loaded from GitHub"
+            ),
+        })
+}
+
+fn write_thread_change<W: Write>(
+    writer: &mut W,
+    comment_id: u64,
+    state: &ReviewThreadState,
+) -> Result<(), IntakeError> {
+    let action = if state.is_resolved {
+        "Resolved"
+    } else {
+        "Unresolved"
+    };
+    writeln!(
+        writer,
+        "{action} thread {} (comment {comment_id})",
+        state.thread_id
+    )
+    .map_err(|error| io_error(&error))
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `resolve_threads` module.
+
+    use rstest::{fixture, rstest};
+
+    use super::{ThreadChange, plan_thread_changes, write_thread_change};
+    use frankie::{IntakeError, ReviewComment, ReviewThreadState};
+
+    fn comment(id: u64, thread_id: Option<&str>) -> ReviewComment {
+        ReviewComment {
+            id,
+            thread_state: thread_id.map(|value| ReviewThreadState {
+                thread_id: value.to_owned(),
+                ..ReviewThreadState::default()
+            }),
+            ..ReviewComment::default()
+        }
+    }
+
+    #[fixture]
+    fn comments() -> Vec<ReviewComment> {
+        vec![
+            comment(1, Some("PRRT_one")),
+            comment(2, Some("PRRT_one")),
+            comment(3, Some("PRRT_two")),
+            comment(4, None),
+        ]
+    }
+
+    #[rstest]
+    fn plan_collapses_comments_in_the_same_thread(comments: Vec<ReviewComment>) {
+        let changes = plan_thread_changes(&comments, &[1, 2], &[3]).expect("plan should succeed");
+
+        assert_eq!(
+            changes,
+            vec![
+                ThreadChange {
+                    comment_id: 1,
+                    thread_id: "PRRT_one".to_owned(),
+                    resolved: true,
+                },
+                ThreadChange {
+                    comment_id: 3,
+                    thread_id: "PRRT_two".to_owned(),
+                    resolved: false,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(99, "was not found")]
+    #[case(4, "could not be loaded")]
+    fn plan_rejects_unknown_targets(
+        comments: Vec<ReviewComment>,
+        #[case] comment_id: u64,
+        #[case] expected_fragment: &str,
+    ) {
+        let error =
+            plan_thread_changes(&comments, &[comment_id], &[]).expect_err("plan should fail");
+
+        assert!(
+            matches!(
+                &error,
+                IntakeError::Configuration { .. } | IntakeError::Api { .. }
+            ),
+            "unexpected error: {error:?}"
+        );
+        assert!(error.to_string().contains(expected_fragment));
+    }
+
+    #[rstest]
+    fn write_thread_change_reports_action() {
+        let state = ReviewThreadState {
+            thread_id: "PRRT_one".to_owned(),
+            is_resolved: true,
+            ..ReviewThreadState::default()
+        };
+        let mut output = Vec::new();
+
+        write_thread_change(&mut output, 1, &state).expect("write should succeed");
+
+        assert_eq!(
+            String::from_utf8_lossy(&output),
+            "Resolved thread PRRT_one (comment 1)\n"
+        );
+    }
+}
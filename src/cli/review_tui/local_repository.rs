@@ -0,0 +1,139 @@
+//! Local repository discovery for the review TUI.
+//!
+//! Time travel and suggested changes need a local checkout of the pull
+//! request's repository. These helpers find one and check that its origin
+//! matches the pull request before wiring up Git operations.
+
+use std::sync::Arc;
+
+use frankie::local::{CommitSha, GitHubOrigin, create_git_ops, discover_repository};
+use frankie::suggestion::Git2SuggestionApplier;
+use frankie::tui::{set_git_ops_context, set_suggestion_applier};
+use frankie::{FrankieConfig, PullRequestLocator};
+
+/// Attempts to set up Git operations for time-travel navigation and
+/// suggested changes.
+///
+/// Tries to discover or open a local repository matching the PR, then
+/// creates git ops and a suggestion applier and stores them in global state
+/// for `Model::init()`.
+///
+/// Verification and time travel target `pr_head_sha` when that commit has
+/// been fetched locally, and the local HEAD otherwise.
+///
+/// Returns `None` on success, or a failure reason string when discovery
+/// fails. Failures are non-fatal: the TUI launches without time-travel.
+pub(super) fn try_setup_git_ops(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    pr_head_sha: Option<&str>,
+) -> Option<String> {
+    let result = discover_repo_for_locator(config, locator);
+
+    match result {
+        Ok((repo_path, local_head_sha)) => match create_git_ops(&repo_path) {
+            Ok(git_ops) => {
+                let head_sha = pr_head_sha
+                    .filter(|sha| git_ops.commit_exists(&CommitSha::new((*sha).to_owned())))
+                    .map_or(local_head_sha, str::to_owned);
+                let _ = set_git_ops_context(git_ops, head_sha);
+                // A bare repository still supports time travel, just not
+                // applying suggestions.
+                if let Ok(applier) = Git2SuggestionApplier::open(&repo_path) {
+                    let _ = set_suggestion_applier(Arc::new(applier));
+                }
+                None
+            }
+            Err(e) => Some(format!(
+                "failed to open repository at {}: {e}",
+                repo_path.display()
+            )),
+        },
+        Err(reason) => Some(reason),
+    }
+}
+
+/// Discovers a local repository matching the PR's origin.
+///
+/// Uses `--repo-path` if configured, otherwise auto-discovers from the
+/// current directory. Validates that the discovered repository's origin
+/// matches the PR's owner and repository.
+///
+/// Returns the repository path and HEAD SHA on success.
+fn discover_repo_for_locator(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<(std::path::PathBuf, String), String> {
+    let discovery_path = choose_repo_discovery_path(config)?;
+    let local_repo = discover_repository(&discovery_path).map_err(|e| {
+        if config.repo_path.is_some() {
+            format!("--repo-path '{}': {e}", discovery_path.display())
+        } else {
+            format!("{e}")
+        }
+    })?;
+
+    // Validate the discovered repository matches the PR's origin
+    validate_repo_matches_locator(local_repo.github_origin(), locator)?;
+
+    // Get HEAD SHA for line mapping verification
+    let head_sha = local_repo.head_sha()?;
+
+    Ok((local_repo.workdir().to_path_buf(), head_sha))
+}
+
+/// Chooses the path to use for local repository discovery.
+///
+/// Returns the explicit `--repo-path` when provided, rejects discovery
+/// when `--no-local-discovery` is set, and falls back to the current
+/// directory.
+fn choose_repo_discovery_path(config: &FrankieConfig) -> Result<std::path::PathBuf, String> {
+    if let Some(ref repo_path) = config.repo_path {
+        return Ok(std::path::PathBuf::from(repo_path));
+    }
+
+    if config.no_local_discovery {
+        return Err("local repository discovery is disabled (--no-local-discovery)".to_owned());
+    }
+
+    Ok(std::path::PathBuf::from("."))
+}
+
+/// Validates that a discovered repository's origin matches the PR's
+/// host, owner, and repository.
+pub(super) fn validate_repo_matches_locator(
+    origin: &GitHubOrigin,
+    locator: &PullRequestLocator,
+) -> Result<(), String> {
+    let expected_host = locator.host();
+    let expected_owner = locator.owner().as_str();
+    let expected_repo = locator.repository().as_str();
+
+    if !origin.host().eq_ignore_ascii_case(expected_host) {
+        return Err(format!(
+            concat!(
+                "local repository host ({found_host}) does not match the PR ",
+                "host ({expected_host})"
+            ),
+            found_host = origin.host(),
+            expected_host = expected_host,
+        ));
+    }
+
+    if !origin.owner().eq_ignore_ascii_case(expected_owner)
+        || !origin.repository().eq_ignore_ascii_case(expected_repo)
+    {
+        return Err(format!(
+            concat!(
+                "local repository origin ({found_owner}/{found_repo}) does not ",
+                "match the PR repository ({expected_owner}/{expected_repo})"
+            ),
+            found_owner = origin.owner(),
+            found_repo = origin.repository(),
+            expected_owner = expected_owner,
+            expected_repo = expected_repo,
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,323 @@
+//! TUI mode for reviewing PR comments.
+//!
+//! This module provides the entry point for the interactive terminal user
+//! interface that allows users to navigate and filter review comments.
+
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bubbletea_rs::Program;
+
+use frankie::ai::{
+    OpenAiCommentRewriteConfig, OpenAiCommentRewriteService, OpenAiPrDiscussionSummaryConfig,
+    OpenAiPrDiscussionSummaryService,
+};
+use frankie::persistence::{
+    CommentPageCache, PendingReviewCommentStore, PullRequestReviewCache, ReviewCommentArchive,
+    ReviewCommentVerificationCache, SyncCheckpointStore, WriteOutbox,
+};
+use frankie::telemetry::StderrJsonlTelemetrySink;
+use frankie::tui::{
+    ReplyDraftConfig, ReplyDraftMaxLength, ReviewApp, ReviewCommentStream, ReviewStreamUpdate,
+    TimeTravelContext, set_authenticated_login, set_comment_page_cache,
+    set_comment_rewrite_service, set_commit_history_limit, set_initial_ci_status,
+    set_initial_error, set_initial_issue_comments, set_initial_pull_request_metadata,
+    set_initial_pull_request_reviews, set_initial_reviews, set_initial_terminal_size,
+    set_offline_context, set_pending_review_comment_store, set_pr_discussion_summary_service,
+    set_refresh_context, set_reply_draft_config, set_review_comment_archive,
+    set_review_comment_stream, set_review_comment_verification_cache, set_sync_checkpoint_store,
+    set_telemetry_sink, set_time_travel_context, set_write_outbox,
+};
+use frankie::{
+    FrankieConfig, GitHubCredentials, IntakeError, PullRequestLocator, ReviewComment,
+    ReviewCommentGateway,
+};
+
+use super::pull_request_context;
+
+mod local_repository;
+
+use local_repository::try_setup_git_ops;
+
+/// Runs the TUI mode for reviewing PR comments.
+///
+/// Resolves the PR locator, fetches reviews from GitHub (or loads them from
+/// the local database in offline mode), wires up time-travel when a local
+/// repository is available, and launches the interactive TUI.
+///
+/// # Errors
+///
+/// Returns an error if locator resolution, token validation, the GitHub
+/// API call, loading stored comments, or TUI initialisation fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    run_for_locator(config, resolve_locator(config)?).await
+}
+
+/// Runs the TUI mode for the pull request at `locator`.
+///
+/// Used by [`run`] and by the pull request picker once a pull request has
+/// been chosen.
+///
+/// # Errors
+///
+/// Returns an error if token validation, the GitHub API call, loading
+/// stored comments, or TUI initialisation fails.
+pub(super) async fn run_for_locator(
+    config: &FrankieConfig,
+    locator: PullRequestLocator,
+) -> Result<(), IntakeError> {
+    let (reviews, pr_head_sha) = if config.is_offline() {
+        load_offline_data(config, &locator)?
+    } else {
+        load_online_data(config, &locator).await?
+    };
+
+    let review_count = reviews.len();
+    if !set_initial_reviews(reviews) {
+        return Err(IntakeError::Api {
+            message: format!(
+                "TUI already initialised with reviews from a previous run. \
+                 Cannot proceed with {review_count} newly fetched review(s) as stale data may be displayed. \
+                 Restart the process to view fresh data."
+            ),
+        });
+    }
+
+    // Non-fatal: TUI launches without time-travel on failure.
+    let discovery_failure = try_setup_git_ops(config, &locator, pr_head_sha.as_deref());
+    let _ = set_time_travel_context(TimeTravelContext {
+        host: locator.host().to_owned(),
+        owner: locator.owner().as_str().to_owned(),
+        repo: locator.repository().as_str().to_owned(),
+        pr_number: locator.number().get(),
+        discovery_failure,
+    });
+
+    if let Some(database_url) = config.database_url.as_deref() {
+        set_local_stores(database_url);
+    }
+
+    let _ = set_commit_history_limit(config.commit_history_limit);
+
+    let reply_draft_config = ReplyDraftConfig::new(
+        ReplyDraftMaxLength::new(config.reply_max_length),
+        config.reply_templates.clone(),
+    );
+    let _ = set_reply_draft_config(reply_draft_config);
+    let rewrite_service = OpenAiCommentRewriteService::new(OpenAiCommentRewriteConfig::new(
+        config.ai_base_url.clone(),
+        config.ai_model.clone(),
+        config.resolve_ai_api_key(),
+        Duration::from_secs(config.ai_timeout_seconds),
+    ));
+    let _ = set_comment_rewrite_service(Arc::new(rewrite_service));
+    let summary_service =
+        OpenAiPrDiscussionSummaryService::new(OpenAiPrDiscussionSummaryConfig::new(
+            config.ai_base_url.clone(),
+            config.ai_model.clone(),
+            config.resolve_ai_api_key(),
+            Duration::from_secs(config.ai_timeout_seconds),
+        ));
+    let _ = set_pr_discussion_summary_service(Arc::new(summary_service));
+    let _ = set_telemetry_sink(Arc::new(StderrJsonlTelemetrySink));
+    run_tui().await.map_err(|error| IntakeError::Api {
+        message: format!("TUI error: {error}"),
+    })?;
+
+    Ok(())
+}
+
+/// Fetches review comments, reviews, conversation comments, CI status, and
+/// metadata from GitHub
+/// and stores the refresh context for background sync.
+///
+/// Returns the review comments and, when the metadata loaded, the pull
+/// request's head SHA.
+async fn load_online_data(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<(Vec<ReviewComment>, Option<String>), IntakeError> {
+    let credentials = pull_request_context::resolve_credentials(config, locator)?;
+
+    let gateway: Arc<dyn ReviewCommentGateway> = Arc::new(
+        pull_request_context::review_comment_gateway(config, locator, &credentials)?,
+    );
+    let reviews = load_first_review_page(Arc::clone(&gateway), locator).await?;
+    // Non-fatal: without a login, editing and deleting comments is not offered.
+    if let Ok(login) = gateway.authenticated_login().await {
+        let _ = set_authenticated_login(login);
+    }
+
+    load_initial_conversation(config, locator, &credentials).await;
+    load_initial_ci_status(locator, &credentials).await;
+
+    // Non-fatal: the header falls back to the plain title without metadata.
+    let metadata = pull_request_context::fetch_pull_request_metadata(config, locator, &credentials)
+        .await
+        .ok();
+    let pr_title = metadata.as_ref().and_then(|details| details.title.clone());
+    let pr_head_sha = metadata
+        .as_ref()
+        .and_then(|details| details.head.sha.clone());
+    if let Some(details) = metadata {
+        let _ = set_initial_pull_request_metadata(details);
+    }
+    let _ = set_refresh_context(locator.clone(), credentials, pr_title);
+    Ok((reviews, pr_head_sha))
+}
+
+/// Waits for the first page of review comments.
+///
+/// When more pages follow, the listing is left running and handed to the
+/// TUI, which merges the remaining pages as they arrive.
+async fn load_first_review_page(
+    gateway: Arc<dyn ReviewCommentGateway>,
+    locator: &PullRequestLocator,
+) -> Result<Vec<ReviewComment>, IntakeError> {
+    let mut stream = ReviewCommentStream::spawn(gateway, locator.clone());
+    match stream.next_update().await {
+        Some(ReviewStreamUpdate::Page(page)) => {
+            let _ = set_review_comment_stream(stream);
+            Ok(page)
+        }
+        Some(ReviewStreamUpdate::Finished(result)) => result,
+        None => Err(IntakeError::Api {
+            message: "review comment listing stopped unexpectedly".to_owned(),
+        }),
+    }
+}
+
+/// Fetches top-level reviews and conversation comments for the reviews and
+/// timeline views.
+///
+/// Non-fatal: when reviews cannot be loaded the TUI opens with an empty
+/// reviews view and the failure in the status bar, and the next sync retries.
+async fn load_initial_conversation(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) {
+    match pull_request_context::fetch_pull_request_reviews(config, locator, credentials).await {
+        Ok(reviews) => {
+            let _ = set_initial_pull_request_reviews(reviews);
+        }
+        Err(error) => {
+            let _ = set_initial_error(format!("Failed to load pull request reviews: {error}"));
+        }
+    }
+    // Non-fatal: the conversation timeline then shows reviews and threads only.
+    if let Ok(comments) =
+        pull_request_context::fetch_pull_request_comments(config, locator, credentials).await
+    {
+        let _ = set_initial_issue_comments(comments);
+    }
+}
+
+/// Loads review comments, cached reviews, and cached metadata from the
+/// local database without touching the network.
+///
+/// Returns the review comments and, when metadata is cached, the pull
+/// request's head SHA.
+fn load_offline_data(
+    config: &FrankieConfig,
+    locator: &PullRequestLocator,
+) -> Result<(Vec<ReviewComment>, Option<String>), IntakeError> {
+    let reviews = pull_request_context::load_archived_review_comments(config, locator)?;
+
+    let cached_reviews = config
+        .database_url
+        .as_deref()
+        .and_then(|database_url| PullRequestReviewCache::new(database_url).ok())
+        .and_then(|cache| cache.get(locator).ok().flatten());
+    if let Some(cached) = cached_reviews {
+        let _ = set_initial_pull_request_reviews(cached.reviews);
+    }
+
+    let metadata = pull_request_context::cached_pull_request_metadata(config, locator);
+    let pr_title = metadata.as_ref().and_then(|details| details.title.clone());
+    let pr_head_sha = metadata
+        .as_ref()
+        .and_then(|details| details.head.sha.clone());
+    if let Some(details) = metadata {
+        let _ = set_initial_pull_request_metadata(details);
+    }
+    let _ = set_offline_context(locator.clone(), pr_title);
+    Ok((reviews, pr_head_sha))
+}
+
+/// Hands the `SQLite`-backed caches and stores to the TUI.
+///
+/// The comment page cache and archive let background refreshes send
+/// conditional requests and keep the stored comments current; the write
+/// outbox queues replies and resolutions that cannot reach GitHub.
+fn set_local_stores(database_url: &str) {
+    if let Ok(cache) = CommentPageCache::new(database_url.to_owned()) {
+        let _ = set_comment_page_cache(Arc::new(cache));
+    }
+    if let Ok(archive) = ReviewCommentArchive::new(database_url.to_owned()) {
+        let _ = set_review_comment_archive(Arc::new(archive));
+    }
+    if let Ok(cache) = ReviewCommentVerificationCache::new(database_url.to_owned()) {
+        let _ = set_review_comment_verification_cache(Arc::new(cache));
+    }
+    if let Ok(store) = PendingReviewCommentStore::new(database_url.to_owned()) {
+        let _ = set_pending_review_comment_store(Arc::new(store));
+    }
+    if let Ok(store) = SyncCheckpointStore::new(database_url.to_owned()) {
+        let _ = set_sync_checkpoint_store(Arc::new(store));
+    }
+    if let Ok(outbox) = WriteOutbox::new(database_url.to_owned()) {
+        let _ = set_write_outbox(Arc::new(outbox));
+    }
+}
+
+/// Loads CI results for the pull request head into TUI storage.
+///
+/// Non-fatal: the header omits the CI badge when checks cannot be loaded.
+async fn load_initial_ci_status(locator: &PullRequestLocator, credentials: &GitHubCredentials) {
+    if let Ok(ci_status) = pull_request_context::fetch_ci_status(locator, credentials).await {
+        let _ = set_initial_ci_status(ci_status);
+    }
+}
+
+/// Resolves a [`PullRequestLocator`] from the configuration, preferring
+/// the positional `pr_identifier` and falling back to `--pr-url`.
+fn resolve_locator(config: &FrankieConfig) -> Result<PullRequestLocator, IntakeError> {
+    pull_request_context::resolve_locator(config)
+}
+
+/// Resolves a locator from a positional PR identifier (URL or bare number).
+///
+/// URL identifiers bypass local discovery. Bare PR numbers require
+/// discovery for owner/repo context; when `no_local_discovery` is `true`
+/// a bare number is rejected with a configuration error.
+#[cfg(test)]
+fn resolve_from_identifier(
+    identifier: &str,
+    no_local_discovery: bool,
+) -> Result<PullRequestLocator, IntakeError> {
+    pull_request_context::resolve_from_identifier(identifier, no_local_discovery, None)
+}
+
+/// Runs the bubbletea-rs program with the `ReviewApp` model.
+async fn run_tui() -> Result<(), bubbletea_rs::Error> {
+    // Seed initial terminal dimensions so first render uses the actual size.
+    if let Ok((width, height)) = crossterm::terminal::size() {
+        let _ = set_initial_terminal_size(width, height);
+    }
+
+    // Build and run the program using the builder pattern.
+    // ReviewApp::init() will retrieve data from module-level storage.
+    let program = Program::<ReviewApp>::builder().alt_screen(true).build()?;
+
+    program.run().await?;
+
+    // Ensure stdout is flushed
+    io::stdout().flush().ok();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -2,6 +2,9 @@
 
 use std::sync::Arc;
 
+use frankie::local::GitHubOrigin;
+
+use super::local_repository::validate_repo_matches_locator;
 use super::*;
 
 #[test]
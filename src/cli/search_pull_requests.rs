@@ -0,0 +1,326 @@
+//! Pull request search operation.
+//!
+//! Runs a GitHub search on the configured host with the configured
+//! qualifiers and either prints the first page of results or, with `--tui`,
+//! opens them in a picker that loads further pages on demand and then
+//! reviews the chosen pull request.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bubbletea_rs::Program;
+use frankie::github::RepositoryGateway;
+use frankie::github::locator::api_base_for_host;
+use frankie::tui::{
+    PullRequestPages, PullRequestPicker, set_initial_terminal_size, set_pull_request_picker,
+};
+use frankie::{
+    FrankieConfig, GitHubCredentials, IntakeError, OctocrabRepositoryGateway,
+    PaginatedPullRequests, PullRequestLocator, PullRequestSummary, RepositoryIntake,
+    SearchPullRequestsParams,
+};
+
+use super::output::{io_error, write_search_summary};
+use super::pull_request_context::resolve_host_credentials;
+use super::review_tui;
+
+/// Searches pull requests and prints or picks from the results.
+///
+/// # Errors
+///
+/// Returns [`IntakeError::MissingToken`] if no token is available,
+/// [`IntakeError::RateLimitExceeded`] if the search rate limit is exhausted,
+/// and [`IntakeError::Api`] if GitHub rejects the query or the picker fails.
+pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let api_base = api_base_for_host(config.search_host())?;
+    let build_gateway = |credentials: &GitHubCredentials| {
+        OctocrabRepositoryGateway::for_api_base(credentials, api_base.as_str())
+    };
+
+    if !config.tui {
+        let mut stdout = io::stdout().lock();
+        return run_with_gateway_builder(config, build_gateway, &mut stdout).await;
+    }
+
+    let (gateway, params) = connect(config, build_gateway).await?;
+    let first_page = RepositoryIntake::new(&gateway)
+        .search_pull_requests(&params)
+        .await?;
+    let query = params.search_query();
+    let pages = Arc::new(SearchPages { gateway, params });
+    let Some(chosen) = pick(query, first_page, pages).await? else {
+        return Ok(());
+    };
+    review_tui::run_for_locator(config, locator_for(&chosen)?).await
+}
+
+/// Searches pull requests using a custom gateway builder and writes the
+/// results.
+///
+/// This function is exposed for testing with mock gateways.
+pub async fn run_with_gateway_builder<G, F, W>(
+    config: &FrankieConfig,
+    build_gateway: F,
+    writer: &mut W,
+) -> Result<(), IntakeError>
+where
+    G: RepositoryGateway,
+    F: FnOnce(&GitHubCredentials) -> Result<G, IntakeError>,
+    W: Write,
+{
+    let (gateway, params) = connect(config, build_gateway).await?;
+    let result = RepositoryIntake::new(&gateway)
+        .search_pull_requests(&params)
+        .await?;
+    write_search_summary(writer, &result, &params.search_query())
+}
+
+/// Builds the gateway for the search host and the search parameters, with
+/// an `--owner` scope matched to the owner's account type.
+async fn connect<G, F>(
+    config: &FrankieConfig,
+    build_gateway: F,
+) -> Result<(G, SearchPullRequestsParams), IntakeError>
+where
+    G: RepositoryGateway,
+    F: FnOnce(&GitHubCredentials) -> Result<G, IntakeError>,
+{
+    let host = config.search_host();
+    let api_base = api_base_for_host(host)?;
+    let credentials = resolve_host_credentials(config, host, api_base.as_str())?;
+    let gateway = build_gateway(&credentials)?;
+    let params = RepositoryIntake::new(&gateway)
+        .resolve_search_scope(config.search_params())
+        .await?;
+    Ok((gateway, params))
+}
+
+/// Fetches further pages of a search for the picker.
+struct SearchPages {
+    gateway: OctocrabRepositoryGateway,
+    params: SearchPullRequestsParams,
+}
+
+impl fmt::Debug for SearchPages {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SearchPages")
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl PullRequestPages for SearchPages {
+    async fn fetch_page(&self, page: u32) -> Result<PaginatedPullRequests, IntakeError> {
+        let params = SearchPullRequestsParams {
+            page: Some(page),
+            ..self.params.clone()
+        };
+        RepositoryIntake::new(&self.gateway)
+            .search_pull_requests(&params)
+            .await
+    }
+}
+
+/// Shows the results in the picker and returns the chosen pull request.
+async fn pick(
+    query: String,
+    first_page: PaginatedPullRequests,
+    pages: Arc<SearchPages>,
+) -> Result<Option<PullRequestSummary>, IntakeError> {
+    if let Ok((width, height)) = crossterm::terminal::size() {
+        let _ = set_initial_terminal_size(width, height);
+    }
+    let _ = set_pull_request_picker(
+        PullRequestPicker::new(format!("Pull requests matching `{query}`"), first_page)
+            .with_more_pages(pages),
+    );
+
+    let picker = async {
+        Program::<PullRequestPicker>::builder()
+            .alt_screen(true)
+            .build()?
+            .run()
+            .await
+    }
+    .await
+    .map_err(|error: bubbletea_rs::Error| IntakeError::Api {
+        message: format!("TUI error: {error}"),
+    })?;
+    io::stdout().flush().map_err(|error| io_error(&error))?;
+
+    Ok(picker.selected().cloned())
+}
+
+/// Builds the locator for a chosen search result from its web URL.
+pub(super) fn locator_for(summary: &PullRequestSummary) -> Result<PullRequestLocator, IntakeError> {
+    let url = summary
+        .html_url
+        .as_deref()
+        .ok_or_else(|| IntakeError::Api {
+            message: format!("search result {} has no URL", summary.reference()),
+        })?;
+    PullRequestLocator::parse(url)
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `search_pull_requests` module.
+
+    use std::sync::{Arc, Mutex};
+
+    use frankie::github::PageInfo;
+    use frankie::{
+        FrankieConfig, IntakeError, PaginatedPullRequests, PullRequestSummary, RateLimitInfo,
+        SearchPullRequestsParams, SearchScope,
+    };
+    use rstest::rstest;
+
+    use super::{locator_for, run_with_gateway_builder};
+    use crate::cli::test_utils::CapturingSearchGateway;
+
+    fn search_config(owner: Option<&str>, repo: Option<&str>) -> FrankieConfig {
+        FrankieConfig {
+            token: Some("ghp_example".to_owned()),
+            owner: owner.map(str::to_owned),
+            repo: repo.map(str::to_owned),
+            search: Some("review-requested:@me".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn gateway(
+        response: Result<PaginatedPullRequests, IntakeError>,
+    ) -> (
+        CapturingSearchGateway,
+        Arc<Mutex<Option<SearchPullRequestsParams>>>,
+    ) {
+        let captured = Arc::new(Mutex::new(None));
+        let gateway = CapturingSearchGateway {
+            captured: Arc::clone(&captured),
+            response: Arc::new(Mutex::new(Some(response))),
+            user_accounts: vec!["octocat".to_owned()],
+        };
+        (gateway, captured)
+    }
+
+    #[rstest]
+    #[case::all(None, None, SearchScope::All)]
+    #[case::organization(Some("octo"), None, SearchScope::Organization("octo".to_owned()))]
+    #[case::user(Some("octocat"), None, SearchScope::User("octocat".to_owned()))]
+    #[case::repository(
+        Some("octo"),
+        Some("app"),
+        SearchScope::Repository { owner: "octo".to_owned(), repo: "app".to_owned() }
+    )]
+    #[tokio::test]
+    async fn search_scopes_to_the_configured_owner_and_repository(
+        #[case] owner: Option<&str>,
+        #[case] repo: Option<&str>,
+        #[case] expected: SearchScope,
+    ) {
+        let (gateway, captured) = gateway(Ok(PaginatedPullRequests {
+            items: vec![],
+            page_info: PageInfo::default(),
+            rate_limit: None,
+        }));
+
+        let mut buffer = Vec::new();
+        run_with_gateway_builder(&search_config(owner, repo), |_| Ok(gateway), &mut buffer)
+            .await
+            .expect("search should succeed");
+
+        let params = captured
+            .lock()
+            .expect("captured mutex should be available")
+            .clone()
+            .expect("gateway should have been called");
+        assert_eq!(params.scope, expected);
+        assert_eq!(params.query, "review-requested:@me");
+        assert_eq!(params.per_page, Some(50));
+    }
+
+    #[tokio::test]
+    async fn search_writes_results_with_their_repositories() {
+        let (gateway, _) = gateway(Ok(PaginatedPullRequests {
+            items: vec![PullRequestSummary {
+                number: 42,
+                repository: Some("octo/app".to_owned()),
+                title: Some("Add search".to_owned()),
+                state: Some("open".to_owned()),
+                author: Some("octocat".to_owned()),
+                ..PullRequestSummary::default()
+            }],
+            page_info: PageInfo::builder(1, 50).total_pages(Some(1)).build(),
+            rate_limit: Some(RateLimitInfo::new(30, 12, 1_700_000_000)),
+        }));
+
+        let mut buffer = Vec::new();
+        run_with_gateway_builder(&search_config(None, None), |_| Ok(gateway), &mut buffer)
+            .await
+            .expect("search should succeed");
+
+        let output = String::from_utf8(buffer).expect("output should be valid UTF-8");
+        assert!(
+            output.contains("Pull requests matching `is:pr review-requested:@me`:"),
+            "missing header: {output}"
+        );
+        assert!(
+            output.contains("octo/app#42 [open] Add search (@octocat)"),
+            "missing result: {output}"
+        );
+        assert!(
+            output.contains("12 of 30 requests remaining"),
+            "missing rate limit: {output}"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_propagates_query_errors() {
+        let (gateway, _) = gateway(Err(IntakeError::Api {
+            message: "Validation Failed".to_owned(),
+        }));
+
+        let mut buffer = Vec::new();
+        let result =
+            run_with_gateway_builder(&search_config(None, None), |_| Ok(gateway), &mut buffer)
+                .await;
+
+        assert!(
+            matches!(result, Err(IntakeError::Api { ref message }) if message == "Validation Failed"),
+            "unexpected result: {result:?}"
+        );
+    }
+
+    #[rstest]
+    fn locator_for_uses_the_result_url() {
+        let summary = PullRequestSummary {
+            number: 7,
+            repository: Some("octo/lib".to_owned()),
+            html_url: Some("https://github.com/octo/lib/pull/7".to_owned()),
+            ..PullRequestSummary::default()
+        };
+
+        let locator = locator_for(&summary).expect("locator should parse");
+
+        assert_eq!(locator.owner().as_str(), "octo");
+        assert_eq!(locator.repository().as_str(), "lib");
+        assert_eq!(locator.number().get(), 7);
+    }
+
+    #[rstest]
+    fn locator_for_rejects_results_without_a_url() {
+        let summary = PullRequestSummary {
+            number: 7,
+            ..PullRequestSummary::default()
+        };
+
+        assert!(matches!(
+            locator_for(&summary),
+            Err(IntakeError::Api { .. })
+        ));
+    }
+}
@@ -1,11 +1,12 @@
 //! Single pull request loading operation.
 
 use frankie::{
-    FrankieConfig, IntakeError, OctocrabCachingGateway, OctocrabGateway, PersonalAccessToken,
-    PullRequestIntake, PullRequestLocator,
+    FrankieConfig, IntakeError, OctocrabCachingGateway, OctocrabGateway, PullRequestIntake,
+    PullRequestLocator,
 };
 
 use super::output::write_pr_summary;
+use super::pull_request_context::resolve_host_credentials;
 
 /// Loads a single pull request by URL.
 ///
@@ -15,14 +16,13 @@ use super::output::write_pr_summary;
 /// Returns [`IntakeError::GitHub`] if the API request fails.
 pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let pr_url = config.require_pr_url()?;
-    let token_value = config.resolve_token()?;
-
     let locator = PullRequestLocator::parse(pr_url)?;
-    let token = PersonalAccessToken::new(token_value)?;
+    let credentials =
+        resolve_host_credentials(config, locator.host(), locator.api_base().as_str())?;
 
     let details = if let Some(database_url) = config.database_url.as_deref() {
         let gateway = OctocrabCachingGateway::for_token(
-            &token,
+            &credentials,
             &locator,
             database_url,
             config.pr_metadata_cache_ttl_seconds,
@@ -30,7 +30,7 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
         let intake = PullRequestIntake::new(&gateway);
         intake.load(&locator).await?
     } else {
-        let gateway = OctocrabGateway::for_token(&token, &locator)?;
+        let gateway = OctocrabGateway::for_token(&credentials, &locator)?;
         let intake = PullRequestIntake::new(&gateway);
         intake.load(&locator).await?
     };
@@ -7,13 +7,13 @@ use frankie::ai::{
     FrankieDeepLink, OpenAiPrDiscussionSummaryConfig, OpenAiPrDiscussionSummaryService,
     PrDiscussionSummary, PrDiscussionSummaryRequest, PrDiscussionSummaryService,
 };
-use frankie::{
-    FrankieConfig, IntakeError, OctocrabReviewCommentGateway, PersonalAccessToken,
-    ReviewCommentGateway,
-};
+use frankie::{CiStatus, FrankieConfig, GitHubCredentials, IntakeError, PullRequestLocator};
 
 use super::output::io_error;
-use super::pull_request_context::{fetch_pull_request_title, resolve_locator};
+use super::pull_request_context::{
+    cached_pull_request_title, fetch_ci_status, fetch_pull_request_title, load_review_comments,
+    resolve_credentials, resolve_locator,
+};
 
 /// Generates and prints a PR-level discussion summary.
 ///
@@ -23,15 +23,18 @@ use super::pull_request_context::{fetch_pull_request_title, resolve_locator};
 /// be loaded, summary generation fails, or writing output fails.
 pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let locator = resolve_locator(config)?;
-    let token = PersonalAccessToken::new(config.resolve_token()?)?;
-    let gateway = OctocrabReviewCommentGateway::new(&token, locator.api_base().as_str())?;
-    let review_comments = gateway.list_review_comments(&locator).await?;
-    let pr_title = fetch_pull_request_title(&locator, &token)
-        .await
-        .ok()
-        .flatten();
-    let request =
+    let review_comments = load_review_comments(config, &locator).await?;
+    let (pr_title, ci_status) = if config.is_offline() {
+        (cached_pull_request_title(config, &locator), None)
+    } else {
+        let credentials = resolve_credentials(config, &locator)?;
+        fetch_pull_request_context(&locator, &credentials).await
+    };
+    let mut request =
         PrDiscussionSummaryRequest::new(locator.number().get(), pr_title, review_comments);
+    if let Some(status) = ci_status {
+        request = request.with_ci_status(status);
+    }
     let service = build_summary_service(config);
     let stdout = io::stdout();
     let mut writer = stdout.lock();
@@ -40,6 +43,21 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     write_summary(&mut writer, locator.number().get(), &summary)
 }
 
+/// Fetches the pull-request title and CI status for prompt context.
+///
+/// Both are optional: failures leave the corresponding value unset.
+async fn fetch_pull_request_context(
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> (Option<String>, Option<CiStatus>) {
+    let pr_title = fetch_pull_request_title(locator, credentials)
+        .await
+        .ok()
+        .flatten();
+    let ci_status = fetch_ci_status(locator, credentials).await.ok();
+    (pr_title, ci_status)
+}
+
 fn build_summary_service(config: &FrankieConfig) -> OpenAiPrDiscussionSummaryService {
     let service_config = OpenAiPrDiscussionSummaryConfig::new(
         config.ai_base_url.clone(),
@@ -4,7 +4,10 @@ use std::sync::{Arc, Mutex, PoisonError};
 
 use async_trait::async_trait;
 use frankie::github::RepositoryGateway;
-use frankie::{IntakeError, ListPullRequestsParams, PaginatedPullRequests, RepositoryLocator};
+use frankie::{
+    IntakeError, ListPullRequestsParams, PaginatedPullRequests, RepositoryLocator,
+    SearchPullRequestsParams,
+};
 
 /// A mock gateway that captures its inputs and returns a preconfigured response.
 #[derive(Clone)]
@@ -39,4 +42,68 @@ impl RepositoryGateway for CapturingGateway {
                 })
             })
     }
+
+    async fn search_pull_requests(
+        &self,
+        _params: &SearchPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError> {
+        Err(IntakeError::Api {
+            message: "unexpected search request".to_owned(),
+        })
+    }
+
+    async fn is_organization(&self, _login: &str) -> Result<bool, IntakeError> {
+        Err(IntakeError::Api {
+            message: "unexpected account lookup".to_owned(),
+        })
+    }
+}
+
+/// A mock gateway that captures search parameters and returns a
+/// preconfigured response.
+#[derive(Clone)]
+pub struct CapturingSearchGateway {
+    /// Captured params from the last search.
+    pub captured: Arc<Mutex<Option<SearchPullRequestsParams>>>,
+    /// Response to return (consumed on first call).
+    pub response: Arc<Mutex<Option<Result<PaginatedPullRequests, IntakeError>>>>,
+    /// Logins of user accounts; every other login is an organization.
+    pub user_accounts: Vec<String>,
+}
+
+#[async_trait]
+impl RepositoryGateway for CapturingSearchGateway {
+    async fn list_pull_requests(
+        &self,
+        _locator: &RepositoryLocator,
+        _params: &ListPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError> {
+        Err(IntakeError::Api {
+            message: "unexpected listing request".to_owned(),
+        })
+    }
+
+    async fn search_pull_requests(
+        &self,
+        params: &SearchPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError> {
+        self.captured
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(params.clone());
+
+        self.response
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .unwrap_or_else(|| {
+                Err(IntakeError::Api {
+                    message: "mock response was already consumed".to_owned(),
+                })
+            })
+    }
+
+    async fn is_organization(&self, login: &str) -> Result<bool, IntakeError> {
+        Ok(!self.user_accounts.iter().any(|user| user == login))
+    }
 }
@@ -1,22 +1,24 @@
 //! CLI operation mode for automated resolution verification.
 //!
 //! This mode loads pull request review comments from GitHub and verifies each
-//! comment against the local repository `HEAD` by replaying diffs and checking
+//! comment against the pull request head commit, or the local repository
+//! `HEAD` when that commit has not been fetched, by replaying diffs and checking
 //! deterministic conditions. Verification results are persisted in the local
 //! `SQLite` cache for reuse across sessions.
 
 use std::io::{self, Write};
 
-use frankie::local::{GitHubOrigin, create_git_ops, discover_repository};
+use frankie::local::{CommitSha, GitHubOrigin, create_git_ops, discover_repository};
 use frankie::persistence::ReviewCommentVerificationCache;
 use frankie::time::unix_now;
 use frankie::verification::{DiffReplayResolutionVerifier, ResolutionVerificationService};
 use frankie::{
-    FrankieConfig, IntakeError, OctocrabReviewCommentGateway, PersonalAccessToken,
-    PullRequestLocator, ReviewComment, ReviewCommentGateway,
+    FrankieConfig, IntakeError, PullRequestLocator, ReviewComment, ReviewCommentGateway,
 };
 
-use super::pull_request_context::resolve_locator;
+use super::pull_request_context::{
+    fetch_pull_request_metadata, resolve_credentials, resolve_locator, review_comment_gateway,
+};
 
 /// Verifies review comments for a pull request and persists results.
 ///
@@ -26,7 +28,7 @@ use super::pull_request_context::resolve_locator;
 /// local repository discovery fails, or cache persistence fails.
 pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
     let locator = resolve_locator(config)?;
-    let token = PersonalAccessToken::new(config.resolve_token()?)?;
+    let credentials = resolve_credentials(config, &locator)?;
 
     let database_url =
         config
@@ -42,11 +44,12 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
         }
     })?;
 
-    let (repo_path, head_sha) = discover_repo_for_locator(config, &locator).map_err(|message| {
-        IntakeError::Configuration {
-            message: format!("failed to discover local repository: {message}"),
-        }
-    })?;
+    let (repo_path, local_head_sha) =
+        discover_repo_for_locator(config, &locator).map_err(|message| {
+            IntakeError::Configuration {
+                message: format!("failed to discover local repository: {message}"),
+            }
+        })?;
     let git_ops = create_git_ops(&repo_path).map_err(|error| IntakeError::Configuration {
         message: format!(
             "failed to open git repository at {}: {error}",
@@ -54,7 +57,15 @@ pub async fn run(config: &FrankieConfig) -> Result<(), IntakeError> {
         ),
     })?;
 
-    let gateway = OctocrabReviewCommentGateway::new(&token, locator.api_base().as_str())?;
+    // Non-fatal: without metadata, comments are verified against local HEAD.
+    let head_sha = fetch_pull_request_metadata(config, &locator, &credentials)
+        .await
+        .ok()
+        .and_then(|metadata| metadata.head.sha)
+        .filter(|sha| git_ops.commit_exists(&CommitSha::new(sha.clone())))
+        .unwrap_or(local_head_sha);
+
+    let gateway = review_comment_gateway(config, &locator, &credentials)?;
     let reviews = gateway.list_review_comments(&locator).await?;
 
     let verifier = DiffReplayResolutionVerifier::new(git_ops);
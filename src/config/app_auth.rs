@@ -0,0 +1,55 @@
+//! GitHub App installation settings for configuration validation and lookup.
+//!
+//! `app_id`, `app_installation_id`, and `app_private_key_path` together let
+//! Frankie authenticate as a GitHub App installation, for CI bots that
+//! cannot use a personal access token.
+
+use std::path::Path;
+
+use crate::config::FrankieConfig;
+use crate::github::credentials::GitHubAppInstallation;
+use crate::github::error::IntakeError;
+
+/// Builds the configured GitHub App installation, if any.
+///
+/// Installation tokens are requested from `api_base`. Returns `Ok(None)` when
+/// no GitHub App settings are configured.
+pub(crate) fn app_installation(
+    config: &FrankieConfig,
+    api_base: &str,
+) -> Result<Option<GitHubAppInstallation>, IntakeError> {
+    let (Some(app_id), Some(installation_id), Some(private_key_path)) = (
+        config.app_id,
+        config.app_installation_id,
+        config.app_private_key_path.as_deref(),
+    ) else {
+        return Ok(None);
+    };
+    GitHubAppInstallation::from_private_key_file(
+        app_id,
+        installation_id,
+        Path::new(private_key_path),
+        api_base,
+    )
+    .map(Some)
+}
+
+/// Checks that the GitHub App settings are given together or not at all.
+pub(crate) fn validate_app_auth(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let provided = [
+        config.app_id.is_some(),
+        config.app_installation_id.is_some(),
+        config.app_private_key_path.is_some(),
+    ];
+    if provided.iter().all(|&set| set) || provided.iter().all(|&set| !set) {
+        return Ok(());
+    }
+
+    Err(IntakeError::Configuration {
+        message: concat!(
+            "--app-id, --app-installation-id, and --app-private-key-path ",
+            "must be provided together"
+        )
+        .to_owned(),
+    })
+}
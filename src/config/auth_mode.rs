@@ -0,0 +1,92 @@
+//! Credential-file management mode helpers for configuration validation.
+
+use crate::config::FrankieConfig;
+use crate::config::credential_store::CredentialTarget;
+use crate::github::error::IntakeError;
+
+/// Action requested on the credential file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthAction<'a> {
+    /// List the stored secrets without their values.
+    Status,
+    /// Store a secret for the target.
+    Login(CredentialTarget<'a>),
+    /// Remove the secret for the target.
+    Logout(CredentialTarget<'a>),
+}
+
+/// Returns whether one of the `--auth-*` flags is set.
+#[must_use]
+pub(crate) const fn is_auth_mode(config: &FrankieConfig) -> bool {
+    config.auth_status || config.auth_login.is_some() || config.auth_logout.is_some()
+}
+
+/// Returns the requested credential-file action.
+///
+/// Login takes precedence over logout; [`validate_auth_mode`] rejects
+/// configurations that set more than one action.
+#[must_use]
+pub(crate) fn auth_action(config: &FrankieConfig) -> AuthAction<'_> {
+    match (config.auth_login.as_deref(), config.auth_logout.as_deref()) {
+        (Some(target), _) => AuthAction::Login(CredentialTarget::parse(target)),
+        (None, Some(target)) => AuthAction::Logout(CredentialTarget::parse(target)),
+        (None, None) => AuthAction::Status,
+    }
+}
+
+/// Checks that auth mode has a single action with a non-empty target and
+/// no conflicting flags.
+pub(crate) fn validate_auth_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if !is_auth_mode(config) {
+        return Ok(());
+    }
+
+    let actions = [
+        config.auth_status,
+        config.auth_login.is_some(),
+        config.auth_logout.is_some(),
+    ];
+    if actions.iter().filter(|&&set| set).count() > 1 {
+        return Err(configuration_error(
+            "--auth-login, --auth-logout, and --auth-status cannot be combined",
+        ));
+    }
+
+    if matches!(
+        auth_action(config),
+        AuthAction::Login(CredentialTarget::GitHubHost(""))
+            | AuthAction::Logout(CredentialTarget::GitHubHost(""))
+    ) {
+        return Err(configuration_error(
+            "--auth-login and --auth-logout require a GitHub host or `ai`",
+        ));
+    }
+
+    validate_auth_compatibility(config)
+}
+
+fn validate_auth_compatibility(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let conflicting_flag = if config.pr_url.is_some() || config.pr_identifier.is_some() {
+        Some("a pull request")
+    } else if config.owner.is_some() || config.repo.is_some() {
+        Some("--owner/--repo")
+    } else if super::outbox_mode::is_outbox_mode(config) {
+        Some("--outbox")
+    } else if config.tui {
+        Some("--tui")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(configuration_error(&format!(
+            "--auth-login, --auth-logout, and --auth-status cannot be combined with {flag}"
+        )))
+    })
+}
+
+fn configuration_error(message: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: message.to_owned(),
+    }
+}
@@ -0,0 +1,155 @@
+//! Passphrase-based encryption for the credential file.
+//!
+//! The passphrase is stretched with PBKDF2-HMAC-SHA256 and a random salt, and
+//! the serialized store is sealed with ChaCha20-Poly1305 under a random nonce.
+//! The KDF parameters, salt, and nonce are stored alongside the ciphertext in
+//! a small JSON envelope; the envelope header is authenticated as associated
+//! data, so tampering with any field makes decryption fail.
+
+use std::num::NonZeroU32;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::aead::{Aad, CHACHA20_POLY1305, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+/// Envelope format version written by this module.
+const FORMAT_VERSION: u32 = 1;
+const KDF_NAME: &str = "pbkdf2-hmac-sha256";
+const CIPHER_NAME: &str = "chacha20-poly1305";
+/// PBKDF2 iteration count, following current OWASP guidance for SHA-256.
+const KDF_ITERATIONS: u32 = 600_000;
+/// Iteration counts accepted when opening a file.
+///
+/// The count is read from the envelope before it can be authenticated, so a
+/// corrupt or tampered file must not be able to stall key derivation.
+const ACCEPTED_KDF_ITERATIONS: std::ops::RangeInclusive<u32> = 100_000..=10_000_000;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// Why a credential file could not be sealed or opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum CipherError {
+    /// The envelope is not valid JSON or uses an unsupported format.
+    Malformed(String),
+    /// Authentication failed: wrong passphrase or a modified file.
+    Rejected,
+    /// The system random number generator failed.
+    Random,
+}
+
+/// On-disk JSON envelope holding the encrypted store.
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Envelope {
+    /// Associated data binding the envelope header to the ciphertext.
+    fn associated_data(&self) -> String {
+        format!(
+            "frankie-credentials:v{}:{}:{}:{}:{}",
+            self.version, self.kdf, self.iterations, self.salt, self.cipher
+        )
+    }
+}
+
+/// Encrypts `plaintext` under `passphrase` and returns the JSON envelope.
+pub(super) fn seal(plaintext: &[u8], passphrase: &str) -> Result<String, CipherError> {
+    let random = SystemRandom::new();
+    let mut salt = [0_u8; SALT_LEN];
+    let mut nonce = [0_u8; NONCE_LEN];
+    random.fill(&mut salt).map_err(|_| CipherError::Random)?;
+    random.fill(&mut nonce).map_err(|_| CipherError::Random)?;
+
+    let mut envelope = Envelope {
+        version: FORMAT_VERSION,
+        kdf: KDF_NAME.to_owned(),
+        iterations: KDF_ITERATIONS,
+        salt: STANDARD.encode(salt),
+        cipher: CIPHER_NAME.to_owned(),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: String::new(),
+    };
+    let key = derive_key(passphrase, &salt, KDF_ITERATIONS)?;
+    let mut sealed = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(envelope.associated_data().as_bytes()),
+        &mut sealed,
+    )
+    .map_err(|_| CipherError::Rejected)?;
+    envelope.ciphertext = STANDARD.encode(sealed);
+
+    serde_json::to_string_pretty(&envelope)
+        .map_err(|error| CipherError::Malformed(error.to_string()))
+}
+
+/// Decrypts a JSON envelope produced by [`seal`].
+pub(super) fn open(contents: &str, passphrase: &str) -> Result<Vec<u8>, CipherError> {
+    let envelope: Envelope = serde_json::from_str(contents)
+        .map_err(|error| CipherError::Malformed(error.to_string()))?;
+    if envelope.version != FORMAT_VERSION
+        || envelope.kdf != KDF_NAME
+        || envelope.cipher != CIPHER_NAME
+    {
+        return Err(CipherError::Malformed(format!(
+            "unsupported format version {} ({}, {})",
+            envelope.version, envelope.kdf, envelope.cipher
+        )));
+    }
+
+    if !ACCEPTED_KDF_ITERATIONS.contains(&envelope.iterations) {
+        return Err(CipherError::Malformed(format!(
+            "iteration count {} is outside {}..={}",
+            envelope.iterations,
+            ACCEPTED_KDF_ITERATIONS.start(),
+            ACCEPTED_KDF_ITERATIONS.end()
+        )));
+    }
+
+    let salt = decode_field(&envelope.salt, "salt")?;
+    let nonce_bytes: [u8; NONCE_LEN] = decode_field(&envelope.nonce, "nonce")?
+        .try_into()
+        .map_err(|_| CipherError::Malformed("nonce has the wrong length".to_owned()))?;
+    let mut sealed = decode_field(&envelope.ciphertext, "ciphertext")?;
+
+    let key = derive_key(passphrase, &salt, envelope.iterations)?;
+    let plaintext = key
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce_bytes),
+            Aad::from(envelope.associated_data().as_bytes()),
+            &mut sealed,
+        )
+        .map_err(|_| CipherError::Rejected)?;
+    Ok(plaintext.to_vec())
+}
+
+fn decode_field(value: &str, field: &str) -> Result<Vec<u8>, CipherError> {
+    STANDARD
+        .decode(value)
+        .map_err(|error| CipherError::Malformed(format!("{field} is not valid base64: {error}")))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey, CipherError> {
+    let rounds = NonZeroU32::new(iterations)
+        .ok_or_else(|| CipherError::Malformed("iteration count must be positive".to_owned()))?;
+    let mut key_bytes = [0_u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        rounds,
+        salt,
+        passphrase.as_bytes(),
+        &mut key_bytes,
+    );
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &key_bytes).map_err(|_| CipherError::Rejected)?;
+    Ok(LessSafeKey::new(key))
+}
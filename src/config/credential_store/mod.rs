@@ -0,0 +1,294 @@
+//! Passphrase-encrypted storage for GitHub tokens and the AI API key.
+//!
+//! The credential file keeps secrets out of plaintext configuration files
+//! and environment variables. It is written by `frankie --auth-login` and read
+//! by the token resolution chain when `FRANKIE_CREDENTIAL_PASSPHRASE` is set.
+//! The file is replaced atomically and, on Unix, created readable by its
+//! owner only.
+
+mod cipher;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use self::cipher::CipherError;
+use super::host_tokens::strip_port;
+use super::{FrankieConfig, ResolvedToken, TokenSource};
+use crate::github::error::IntakeError;
+
+/// Target name that selects the AI API key instead of a GitHub host.
+pub const AI_API_KEY_TARGET: &str = "ai";
+
+/// A secret stored in the credential file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialTarget<'a> {
+    /// The GitHub token for a host such as `github.com`.
+    GitHubHost(&'a str),
+    /// The API key used for AI rewrite and summary requests.
+    AiApiKey,
+}
+
+impl<'a> CredentialTarget<'a> {
+    /// Parses a `--auth-login`/`--auth-logout` target.
+    ///
+    /// [`AI_API_KEY_TARGET`] selects the AI API key; anything else is a
+    /// GitHub host.
+    #[must_use]
+    pub fn parse(target: &'a str) -> Self {
+        let trimmed = target.trim();
+        if trimmed.eq_ignore_ascii_case(AI_API_KEY_TARGET) {
+            Self::AiApiKey
+        } else {
+            Self::GitHubHost(trimmed)
+        }
+    }
+}
+
+impl fmt::Display for CredentialTarget<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GitHubHost(host) => write!(formatter, "the GitHub token for {host}"),
+            Self::AiApiKey => formatter.write_str("the AI API key"),
+        }
+    }
+}
+
+/// Decrypted contents of the credential file.
+///
+/// The `Debug` output lists which secrets are stored but never their values.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialStore {
+    #[serde(default)]
+    github_tokens: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ai_api_key: Option<String>,
+}
+
+impl CredentialStore {
+    /// Reads and decrypts the credential file at `path`.
+    ///
+    /// A missing file yields an empty store.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Io`] when the file cannot be read, and
+    /// [`IntakeError::Configuration`] when the passphrase is wrong or the
+    /// file is corrupted.
+    pub fn load(path: &Path, passphrase: &str) -> Result<Self, IntakeError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path).map_err(|error| IntakeError::Io {
+            message: format!("failed to read credential file {}: {error}", path.display()),
+        })?;
+        let plaintext =
+            cipher::open(&contents, passphrase).map_err(|error| cipher_error(path, &error))?;
+        serde_json::from_slice(&plaintext).map_err(|error| IntakeError::Configuration {
+            message: format!(
+                "credential file {} holds invalid data: {error}",
+                path.display()
+            ),
+        })
+    }
+
+    /// Encrypts the store under `passphrase` and writes it to `path`.
+    ///
+    /// Parent directories are created when needed, and the previous file is
+    /// replaced only once the new one is fully written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Io`] when the file cannot be written and
+    /// [`IntakeError::Configuration`] when encryption fails.
+    pub fn save(&self, path: &Path, passphrase: &str) -> Result<(), IntakeError> {
+        let plaintext = serde_json::to_vec(self).map_err(|error| IntakeError::Configuration {
+            message: format!("failed to serialize credentials: {error}"),
+        })?;
+        let sealed =
+            cipher::seal(&plaintext, passphrase).map_err(|error| cipher_error(path, &error))?;
+        write_private_file(path, sealed.as_bytes()).map_err(|error| IntakeError::Io {
+            message: format!(
+                "failed to write credential file {}: {error}",
+                path.display()
+            ),
+        })
+    }
+
+    /// Returns the stored secret for `target`.
+    #[must_use]
+    pub fn get(&self, target: CredentialTarget<'_>) -> Option<&str> {
+        match target {
+            CredentialTarget::GitHubHost(host) => {
+                self.github_tokens.get(&host_key(host)).map(String::as_str)
+            }
+            CredentialTarget::AiApiKey => self.ai_api_key.as_deref(),
+        }
+    }
+
+    /// Stores `secret` for `target`, replacing any previous value.
+    pub fn set(&mut self, target: CredentialTarget<'_>, secret: String) {
+        match target {
+            CredentialTarget::GitHubHost(host) => {
+                self.github_tokens.insert(host_key(host), secret);
+            }
+            CredentialTarget::AiApiKey => self.ai_api_key = Some(secret),
+        }
+    }
+
+    /// Removes the secret for `target`, returning whether one was stored.
+    pub fn remove(&mut self, target: CredentialTarget<'_>) -> bool {
+        match target {
+            CredentialTarget::GitHubHost(host) => {
+                self.github_tokens.remove(&host_key(host)).is_some()
+            }
+            CredentialTarget::AiApiKey => self.ai_api_key.take().is_some(),
+        }
+    }
+
+    /// Returns the hosts with a stored GitHub token, in sorted order.
+    pub fn github_hosts(&self) -> impl Iterator<Item = &str> {
+        self.github_tokens.keys().map(String::as_str)
+    }
+
+    /// Returns whether an AI API key is stored.
+    #[must_use]
+    pub const fn has_ai_api_key(&self) -> bool {
+        self.ai_api_key.is_some()
+    }
+}
+
+impl fmt::Debug for CredentialStore {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("CredentialStore")
+            .field(
+                "github_hosts",
+                &self.github_tokens.keys().collect::<Vec<_>>(),
+            )
+            .field("has_ai_api_key", &self.has_ai_api_key())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Looks up the stored token for `host`.
+///
+/// Returns `Ok(None)` without touching the file when no passphrase is
+/// configured or the file does not exist.
+pub(crate) fn stored_token(
+    config: &FrankieConfig,
+    host: &str,
+) -> Result<Option<ResolvedToken>, IntakeError> {
+    let Some(passphrase) = config.credential_passphrase.as_deref() else {
+        return Ok(None);
+    };
+    let path = config.credential_file_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let store = CredentialStore::load(&path, passphrase)?;
+    Ok(store
+        .get(CredentialTarget::GitHubHost(host))
+        .map(|token| ResolvedToken::new(token.to_owned(), TokenSource::CredentialFile { path })))
+}
+
+/// Looks up the stored AI API key, logging a warning when the credential
+/// file cannot be read.
+pub(crate) fn stored_ai_api_key(config: &FrankieConfig) -> Option<String> {
+    let passphrase = config.credential_passphrase.as_deref()?;
+    let path = config.credential_file_path().ok()?;
+    if !path.exists() {
+        return None;
+    }
+    match CredentialStore::load(&path, passphrase) {
+        Ok(store) => store.get(CredentialTarget::AiApiKey).map(str::to_owned),
+        Err(error) => {
+            warn_unreadable_store(&error);
+            None
+        }
+    }
+}
+
+fn warn_unreadable_store(error: &IntakeError) {
+    tracing::warn!("skipping the credential file for the AI API key: {error}");
+}
+
+/// Returns the default credential file location.
+///
+/// Uses `${XDG_CONFIG_HOME}/frankie/credentials.enc` when `XDG_CONFIG_HOME`
+/// is set, else `${HOME}/.config/frankie/credentials.enc`.
+///
+/// # Errors
+///
+/// Returns [`IntakeError::Configuration`] when neither `XDG_CONFIG_HOME`
+/// nor `HOME` is available.
+pub fn default_credential_file() -> Result<PathBuf, IntakeError> {
+    let xdg = std::env::var("XDG_CONFIG_HOME").ok();
+    let home = std::env::var("HOME").ok();
+    resolve_credential_file(xdg.as_deref(), home.as_deref())
+}
+
+pub(crate) fn resolve_credential_file(
+    xdg_config_home: Option<&str>,
+    home: Option<&str>,
+) -> Result<PathBuf, IntakeError> {
+    let config_dir = xdg_config_home
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            home.filter(|value| !value.is_empty())
+                .map(|value| Path::new(value).join(".config"))
+        })
+        .ok_or_else(|| IntakeError::Configuration {
+            message: concat!(
+                "cannot locate the credential file: set --credential-file, ",
+                "XDG_CONFIG_HOME, or HOME"
+            )
+            .to_owned(),
+        })?;
+    Ok(config_dir.join("frankie").join("credentials.enc"))
+}
+
+/// Normalizes a host for use as a store key.
+fn host_key(host: &str) -> String {
+    strip_port(host.trim()).to_ascii_lowercase()
+}
+
+fn cipher_error(path: &Path, error: &CipherError) -> IntakeError {
+    let message = match error {
+        CipherError::Rejected => format!(
+            "could not decrypt credential file {}: wrong passphrase or the file was modified",
+            path.display()
+        ),
+        CipherError::Malformed(detail) => {
+            format!("credential file {} is malformed: {detail}", path.display())
+        }
+        CipherError::Random => "the system random number generator failed".to_owned(),
+    };
+    IntakeError::Configuration { message }
+}
+
+/// Writes `contents` to a uniquely named temporary sibling of `path`, then
+/// renames it into place.
+///
+/// The temporary file is created with mode `0600` on Unix, and its unique
+/// name keeps concurrent writers from clobbering each other's partial file.
+fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let directory = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(directory)?;
+
+    let mut temporary = tempfile::NamedTempFile::new_in(directory)?;
+    temporary.write_all(contents)?;
+    temporary.as_file().sync_all()?;
+    temporary
+        .persist(path)
+        .map(drop)
+        .map_err(|error| error.error)
+}
@@ -0,0 +1,72 @@
+//! Review queue dashboard mode helpers for configuration validation.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+use crate::github::review_queue::ReviewQueueRequest;
+
+/// Returns whether `--dashboard` is set.
+#[must_use]
+pub(crate) const fn is_dashboard_mode(config: &FrankieConfig) -> bool {
+    config.dashboard
+}
+
+/// Returns the review queue request for `--dashboard-repos` and `--search`.
+#[must_use]
+pub(crate) fn review_queue_request(config: &FrankieConfig) -> ReviewQueueRequest {
+    ReviewQueueRequest {
+        repositories: config
+            .dashboard_repos
+            .iter()
+            .map(|name| name.trim().to_owned())
+            .collect(),
+        search: config.search.clone(),
+    }
+}
+
+/// Checks that dashboard repositories are `owner/repo` names and that
+/// dashboard mode has no conflicting flags.
+pub(crate) fn validate_dashboard_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if let Some(invalid) = config
+        .dashboard_repos
+        .iter()
+        .find(|name| !is_repository_name(name.trim()))
+    {
+        return Err(configuration_error(&format!(
+            "--dashboard-repos entries must be `owner/repo`, got `{invalid}`"
+        )));
+    }
+    if !is_dashboard_mode(config) {
+        return Ok(());
+    }
+
+    let conflicting_flag = if config.pr_url.is_some() || config.pr_identifier.is_some() {
+        Some("a pull request")
+    } else if config.owner.is_some() || config.repo.is_some() {
+        Some("--owner or --repo; list repositories in --dashboard-repos")
+    } else if config.export.is_some() {
+        Some("--export")
+    } else if super::outbox_mode::is_outbox_mode(config) {
+        Some("--outbox")
+    } else if super::auth_mode::is_auth_mode(config) {
+        Some("--auth-login, --auth-logout, or --auth-status")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(configuration_error(&format!(
+            "--dashboard cannot be combined with {flag}"
+        )))
+    })
+}
+
+fn is_repository_name(name: &str) -> bool {
+    name.split_once('/')
+        .is_some_and(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+}
+
+fn configuration_error(message: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: message.to_owned(),
+    }
+}
@@ -0,0 +1,78 @@
+//! Per-host token helpers for configuration validation and lookup.
+//!
+//! `host_tokens` entries take the form `host=token`, so pull requests on a
+//! GitHub Enterprise server can use a different token from github.com.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+
+/// Returns the token configured for `host`, if any.
+///
+/// Hosts are compared case-insensitively. Entries are matched on the host
+/// alone, so a port in the entry or the pull request URL is ignored.
+#[must_use]
+pub(crate) fn token_for_host<'a>(config: &'a FrankieConfig, host: &str) -> Option<&'a str> {
+    config
+        .host_tokens
+        .iter()
+        .filter_map(|entry| parse_entry(entry))
+        .find(|(entry_host, _)| strip_port(entry_host).eq_ignore_ascii_case(strip_port(host)))
+        .map(|(_, token)| token)
+}
+
+/// Returns the token of every well-formed `host_tokens` entry.
+pub(super) fn configured_tokens(config: &FrankieConfig) -> impl Iterator<Item = &str> {
+    config
+        .host_tokens
+        .iter()
+        .filter_map(|entry| parse_entry(entry))
+        .map(|(_, token)| token)
+}
+
+/// Checks that every `host_tokens` entry is a `host=token` pair.
+///
+/// Error messages identify entries by position so that tokens are never
+/// echoed back.
+pub(crate) fn validate_host_tokens(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let malformed = config
+        .host_tokens
+        .iter()
+        .position(|entry| parse_entry(entry).is_none());
+
+    malformed.map_or(Ok(()), |index| {
+        Err(IntakeError::Configuration {
+            message: format!(
+                "--host-tokens entry {} must have the form host=token, for example ghe.example.com=ghp_...",
+                index.saturating_add(1)
+            ),
+        })
+    })
+}
+
+/// Splits `host=token`, rejecting empty parts and hosts given as URLs.
+fn parse_entry(entry: &str) -> Option<(&str, &str)> {
+    let (host, token) = entry.split_once('=')?;
+    let trimmed_host = host.trim();
+    let trimmed_token = token.trim();
+    let is_bare_host = !trimmed_host.is_empty() && !trimmed_host.contains('/');
+    (is_bare_host && !trimmed_token.is_empty()).then_some((trimmed_host, trimmed_token))
+}
+
+/// Returns whether `host` is github.com, ignoring case and any port.
+pub(super) fn is_github_com(host: &str) -> bool {
+    strip_port(host).eq_ignore_ascii_case("github.com")
+}
+
+/// Removes a trailing `:port` from a host, leaving IPv6 literals intact.
+pub(super) fn strip_port(host: &str) -> &str {
+    match host.rsplit_once(':') {
+        Some((name, port))
+            if !name.is_empty()
+                && !name.ends_with(':')
+                && port.chars().all(|character| character.is_ascii_digit()) =>
+        {
+            name
+        }
+        _ => host,
+    }
+}
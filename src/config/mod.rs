@@ -11,99 +11,103 @@
 //! 3. **Environment variables** – `FRANKIE_*` or legacy `GITHUB_TOKEN`
 //! 4. **Command-line arguments** – `--pr-url`/`-u`, `--token`/`-t`, etc.
 
-use std::env;
 use std::ffi::OsString;
 
 use ortho_config::OrthoConfig;
 
 use crate::github::error::IntakeError;
-
+use crate::github::gateway::SearchPullRequestsParams;
+use crate::github::review_queue::ReviewQueueRequest;
+
+mod app_auth;
+mod auth_mode;
+mod credential_store;
+mod dashboard_mode;
+mod host_tokens;
 mod model;
+mod offline_mode;
+mod operation_mode;
+mod outbox_mode;
+mod reply_mode;
+mod search_mode;
 mod summarize_mode;
-
+mod thread_resolution_mode;
+mod token_discovery;
+mod token_resolution;
+mod validation;
+
+pub use auth_mode::AuthAction;
+pub use credential_store::{
+    AI_API_KEY_TARGET, CredentialStore, CredentialTarget, default_credential_file,
+};
 pub(crate) use model::DEFAULT_REPLY_MAX_LENGTH;
 pub use model::{DEFAULT_COMMIT_HISTORY_LIMIT, FrankieConfig};
-
-/// Operation mode determined by CLI arguments.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum OperationMode {
-    /// Load a single PR by URL.
-    SinglePullRequest,
-    /// List PRs for a repository.
-    RepositoryListing,
-    /// Interactive repository discovery (future).
-    Interactive,
-    /// Interactive TUI for reviewing PR comments.
-    ReviewTui,
-    /// Export review comments in structured format.
-    ExportComments,
-    /// AI-powered draft rewrite mode.
-    AiRewrite,
-    /// Verify comment resolutions against local git state.
-    VerifyResolutions,
-    /// Generate an AI summary for PR-level discussions.
-    SummarizeDiscussions,
-}
+pub use operation_mode::OperationMode;
+pub use outbox_mode::OutboxAction;
+pub use token_discovery::{ResolvedToken, TokenSource};
 
 impl FrankieConfig {
-    /// CLI flags that consume the following argument as their value.
+    /// Returns whether the command-line `flag`, such as `--pr-url` or `-u`,
+    /// consumes the following argument as its value.
     ///
-    /// Boolean flags (`--tui`, `--migrate-db`, `--no-local-discovery`) are
-    /// omitted because they do not consume a trailing value. Update this
-    /// list whenever a value-bearing flag is added to or removed from the
-    /// struct fields above.
-    pub const VALUE_FLAGS: &[&str] = &[
-        "--pr-url",
-        "-u",
-        "--token",
-        "-t",
-        "--owner",
-        "-o",
-        "--repo",
-        "-r",
-        "--database-url",
-        "--pr-metadata-cache-ttl-seconds",
-        "--export",
-        "-e",
-        "--output",
-        "--template",
-        "--repo-path",
-        "--reply-max-length",
-        "--reply-templates",
-        "--ai-rewrite-mode",
-        "--ai-rewrite-text",
-        "--ai-base-url",
-        "--ai-model",
-        "--ai-api-key",
-        "--ai-timeout-seconds",
-        "--commit-history-limit",
-        "--config-path",
-    ];
+    /// Boolean flags (`--tui`, `--migrate-db`, `--no-local-discovery`) do
+    /// not consume a trailing value, and neither does `--flag=value`.
+    #[must_use]
+    pub fn is_value_flag(flag: &str) -> bool {
+        model::VALUE_FLAGS
+            .iter()
+            .any(|value_flag| value_flag == flag)
+    }
 
-    /// Resolves the token from configuration or the legacy `GITHUB_TOKEN`
-    /// environment variable.
+    /// Returns the action requested on the credential file.
     ///
-    /// For backward compatibility, if no token is provided via `FRANKIE_TOKEN`,
-    /// the CLI, or a configuration file, this method falls back to reading
-    /// `GITHUB_TOKEN` from the environment.
+    /// Only meaningful in [`OperationMode::Auth`]; returns
+    /// [`AuthAction::Status`] when no login or logout target is set.
+    #[must_use]
+    pub fn auth_action(&self) -> AuthAction<'_> {
+        auth_mode::auth_action(self)
+    }
+
+    /// Returns whether review comments are read from the local database
+    /// instead of GitHub.
+    #[must_use]
+    pub const fn is_offline(&self) -> bool {
+        offline_mode::is_offline_mode(self)
+    }
+
+    /// Returns the search parameters for `--search`.
     ///
-    /// # Errors
+    /// The search is restricted to the `--owner`/`--repo` repository when
+    /// both are set, to the `--owner` organization when only it is set, and
+    /// otherwise covers every repository the token can see.
+    #[must_use]
+    pub fn search_params(&self) -> SearchPullRequestsParams {
+        search_mode::search_params(self)
+    }
+
+    /// Returns the GitHub host `--search` runs against: `search_host`, or
+    /// `github.com` when it is unset.
+    #[must_use]
+    pub fn search_host(&self) -> &str {
+        search_mode::search_host(self)
+    }
+
+    /// Returns which pull requests the review queue dashboard gathers.
     ///
-    /// Returns [`IntakeError::MissingToken`] when no token source provides a
-    /// value.
-    pub fn resolve_token(&self) -> Result<String, IntakeError> {
-        self.token
-            .clone()
-            .or_else(|| env::var("GITHUB_TOKEN").ok())
-            .ok_or(IntakeError::MissingToken)
+    /// The queue is limited to `--dashboard-repos` when any are set, and
+    /// `--search` adds its results to the queue.
+    #[must_use]
+    pub fn review_queue_request(&self) -> ReviewQueueRequest {
+        dashboard_mode::review_queue_request(self)
     }
 
-    /// Resolves the AI API key from configuration or `OPENAI_API_KEY`.
+    /// Returns the action requested on the write outbox.
+    ///
+    /// Only meaningful in [`OperationMode::Outbox`]; returns
+    /// [`OutboxAction::List`] when no retry or discard target is set.
     #[must_use]
-    pub fn resolve_ai_api_key(&self) -> Option<String> {
-        self.ai_api_key
-            .clone()
-            .or_else(|| env::var("OPENAI_API_KEY").ok())
+    pub fn outbox_action(&self) -> OutboxAction<'_> {
+        outbox_mode::outbox_action(self)
     }
 
     /// Returns the pull request URL or an error if missing.
@@ -117,99 +121,6 @@ impl FrankieConfig {
             .ok_or(IntakeError::MissingPullRequestUrl)
     }
 
-    const fn has_pr_url(&self) -> bool {
-        self.pr_url.is_some()
-    }
-
-    const fn has_repo(&self) -> bool {
-        self.owner.is_some() && self.repo.is_some()
-    }
-
-    const fn should_export_comments(&self) -> bool {
-        self.export.is_some()
-    }
-
-    fn non_empty_trimmed(value: Option<&str>) -> bool {
-        value.is_some_and(|text| !text.trim().is_empty())
-    }
-
-    fn rewrite_mode_present(&self) -> bool {
-        Self::non_empty_trimmed(self.ai_rewrite_mode.as_deref())
-    }
-
-    fn rewrite_text_present(&self) -> bool {
-        Self::non_empty_trimmed(self.ai_rewrite_text.as_deref())
-    }
-
-    fn should_ai_rewrite(&self) -> bool {
-        self.rewrite_mode_present() || self.rewrite_text_present()
-    }
-
-    const fn is_verify_resolutions_mode(&self) -> bool {
-        self.verify_resolutions
-    }
-
-    const fn is_summarize_discussions_mode(&self) -> bool {
-        summarize_mode::is_summarize_discussions_mode(self)
-    }
-
-    fn is_ai_rewrite_mode(&self) -> bool {
-        self.should_ai_rewrite()
-    }
-
-    const fn is_export_comments_mode(&self) -> bool {
-        self.export.is_some()
-    }
-
-    const fn is_review_tui_mode(&self) -> bool {
-        self.has_pr_identifier() || (self.tui && self.has_pr_url())
-    }
-
-    const fn is_single_pull_request_mode(&self) -> bool {
-        self.has_pr_url()
-    }
-
-    const fn is_repository_listing_mode(&self) -> bool {
-        self.has_repo()
-    }
-
-    const fn has_pr_identifier(&self) -> bool {
-        self.pr_identifier.is_some()
-    }
-
-    fn resolve_operation_mode(&self) -> OperationMode {
-        if self.is_verify_resolutions_mode() {
-            OperationMode::VerifyResolutions
-        } else if self.is_summarize_discussions_mode() {
-            OperationMode::SummarizeDiscussions
-        } else if self.is_ai_rewrite_mode() {
-            OperationMode::AiRewrite
-        } else if self.is_export_comments_mode() {
-            OperationMode::ExportComments
-        } else if self.is_review_tui_mode() {
-            OperationMode::ReviewTui
-        } else if self.is_single_pull_request_mode() {
-            OperationMode::SinglePullRequest
-        } else if self.is_repository_listing_mode() {
-            OperationMode::RepositoryListing
-        } else {
-            OperationMode::Interactive
-        }
-    }
-
-    /// Determines the operation mode based on provided configuration.
-    ///
-    /// Returns `AiRewrite` if AI rewrite fields are set, `ExportComments` if
-    /// export format is set (PR URL validation is deferred to
-    /// `export_comments::run`), `ReviewTui` if a positional PR identifier is
-    /// present or TUI mode is enabled with a PR URL, `SinglePullRequest` if a
-    /// PR URL is provided without TUI or export, `RepositoryListing` if both
-    /// owner and repo are provided, or `Interactive` otherwise.
-    #[must_use]
-    pub fn operation_mode(&self) -> OperationMode {
-        self.resolve_operation_mode()
-    }
-
     /// Sets the positional PR identifier extracted from raw CLI arguments.
     pub fn set_pr_identifier(&mut self, value: String) {
         self.pr_identifier = Some(value);
@@ -242,8 +153,10 @@ impl FrankieConfig {
     /// Returns an error when argument parsing fails or configuration files
     /// cannot be loaded.
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let args: Vec<OsString> = std::env::args_os().collect();
         let mut config =
             <Self as OrthoConfig>::load().map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        config.token_from_command_line = passes_token_flag(&args);
         config.normalize();
         Ok(config)
     }
@@ -261,89 +174,31 @@ impl FrankieConfig {
     pub fn load_from_iter(
         iter: impl IntoIterator<Item = impl Into<OsString> + Clone>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut config = <Self as OrthoConfig>::load_from_iter(iter)
+        let args: Vec<OsString> = iter.into_iter().map(Into::into).collect();
+        let mut config = <Self as OrthoConfig>::load_from_iter(args.clone())
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        config.token_from_command_line = passes_token_flag(&args);
         config.normalize();
         Ok(config)
     }
 
-    /// Validates that the configuration is internally consistent.
-    ///
-    /// Checks that:
-    /// - Positional PR identifier and `--pr-url` are not both provided
-    /// - AI rewrite mode and text are both present when either is specified
-    /// - Verify resolutions mode has compatible configuration
-    /// - Summary mode has compatible configuration
+    /// Returns the comment IDs whose threads should be resolved and
+    /// unresolved, in that order.
     ///
     /// # Errors
     ///
-    /// Returns [`IntakeError::Configuration`] when:
-    /// - Both `pr_identifier` and `pr_url` are provided (mutually exclusive)
-    /// - AI rewrite mode is specified without text, or vice versa
-    /// - Verify resolutions mode is incompatible with current configuration
-    /// - Summary mode is incompatible with current configuration
-    pub fn validate(&self) -> Result<(), IntakeError> {
-        self.validate_pr_identifier_exclusivity()?;
-        self.validate_ai_rewrite_completeness()?;
-        self.validate_verify_resolutions_compatibility()?;
-        self.validate_summary_mode_compatibility()?;
-        Ok(())
-    }
-
-    fn validate_pr_identifier_exclusivity(&self) -> Result<(), IntakeError> {
-        if self.has_pr_identifier() && self.has_pr_url() {
-            return Err(IntakeError::Configuration {
-                message: concat!(
-                    "positional PR identifier and --pr-url are mutually ",
-                    "exclusive; provide one or the other"
-                )
-                .to_owned(),
-            });
-        }
-
-        Ok(())
-    }
-
-    fn validate_summary_mode_compatibility(&self) -> Result<(), IntakeError> {
-        summarize_mode::validate_summary_mode_compatibility(self)
-    }
-
-    fn validate_ai_rewrite_completeness(&self) -> Result<(), IntakeError> {
-        let mode_present = self.rewrite_mode_present();
-        let text_present = self.rewrite_text_present();
-        let mode_without_text = mode_present && !text_present;
-        let text_without_mode = !mode_present && text_present;
-        if mode_without_text || text_without_mode {
-            return Err(IntakeError::Configuration {
-                message: concat!(
-                    "--ai-rewrite-mode and --ai-rewrite-text must be provided ",
-                    "together"
-                )
-                .to_owned(),
-            });
-        }
-
-        Ok(())
-    }
-
-    fn validate_verify_resolutions_compatibility(&self) -> Result<(), IntakeError> {
-        if self.verify_resolutions && self.should_ai_rewrite() {
-            return Err(IntakeError::Configuration {
-                message: concat!(
-                    "--verify-resolutions cannot be combined with AI rewrite ",
-                    "flags; remove --ai-rewrite-mode/--ai-rewrite-text"
-                )
-                .to_owned(),
-            });
-        }
-
-        if self.verify_resolutions && self.should_export_comments() {
-            return Err(IntakeError::Configuration {
-                message: "--verify-resolutions cannot be combined with --export".to_owned(),
-            });
-        }
-
-        Ok(())
+    /// Returns [`IntakeError::Configuration`] when either list contains a
+    /// value that is not a comment ID.
+    pub fn thread_resolution_targets(&self) -> Result<(Vec<u64>, Vec<u64>), IntakeError> {
+        let resolve = thread_resolution_mode::parse_comment_ids(
+            "--resolve-threads",
+            self.resolve_threads.as_deref(),
+        )?;
+        let unresolve = thread_resolution_mode::parse_comment_ids(
+            "--unresolve-threads",
+            self.unresolve_threads.as_deref(),
+        )?;
+        Ok((resolve, unresolve))
     }
 
     /// Returns owner and repo if both are configured.
@@ -364,5 +219,69 @@ impl FrankieConfig {
     }
 }
 
+/// Returns whether the command-line `args` pass a token with `--token` or
+/// `-t`.
+///
+/// Matches `--token`, `--token=value`, `-t`, and `-tvalue`, including `-t`
+/// at the end of a cluster of boolean short flags such as `-Tt`. The values
+/// of other flags are skipped, so `--reply-text -t` does not count, and
+/// nothing after `--` is treated as a flag.
+fn passes_token_flag(args: &[OsString]) -> bool {
+    let mut remaining = args.iter().skip(1).map(|arg| arg.to_string_lossy());
+    while let Some(arg) = remaining.next() {
+        let consumes_next = if arg == "--" {
+            return false;
+        } else if let Some(long) = arg.strip_prefix("--") {
+            if long == "token" || long.starts_with("token=") {
+                return true;
+            }
+            FrankieConfig::is_value_flag(&arg)
+        } else if let Some(shorts) = arg.strip_prefix('-') {
+            match short_cluster_token(shorts) {
+                ShortCluster::Token => return true,
+                ShortCluster::ConsumesNext => true,
+                ShortCluster::SelfContained => false,
+            }
+        } else {
+            false
+        };
+        if consumes_next {
+            remaining.next();
+        }
+    }
+    false
+}
+
+/// How a cluster of short flags, such as `Tt` in `-Tt`, uses its arguments.
+enum ShortCluster {
+    /// The cluster sets the token.
+    Token,
+    /// The cluster ends with a value-taking flag whose value is the next
+    /// argument.
+    ConsumesNext,
+    /// The cluster needs no further argument.
+    SelfContained,
+}
+
+/// Reads a short flag cluster the way clap does: boolean flags may be
+/// combined, and the first value-taking flag takes the rest of the cluster,
+/// or else the next argument, as its value.
+fn short_cluster_token(shorts: &str) -> ShortCluster {
+    let mut cluster = shorts.chars();
+    while let Some(short) = cluster.next() {
+        if short == 't' {
+            return ShortCluster::Token;
+        }
+        if FrankieConfig::is_value_flag(&format!("-{short}")) {
+            return if cluster.as_str().is_empty() {
+                ShortCluster::ConsumesNext
+            } else {
+                ShortCluster::SelfContained
+            };
+        }
+    }
+    ShortCluster::SelfContained
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,8 @@
 //! Definition of [`FrankieConfig`], its defaults, and default constants.
 
+use std::sync::LazyLock;
+
+use clap::CommandFactory;
 use ortho_config::OrthoConfig;
 use serde::{Deserialize, Serialize};
 
@@ -9,18 +12,30 @@ use serde::{Deserialize, Serialize};
 ///
 /// - `FRANKIE_PR_URL` or `--pr-url`: Pull request URL
 /// - `FRANKIE_TOKEN`, `GITHUB_TOKEN`, or `--token`: Authentication token
+/// - `FRANKIE_HOST_TOKENS` or `--host-tokens`: Tokens for specific hosts
+/// - `FRANKIE_APP_ID` or `--app-id`: GitHub App ID
+/// - `FRANKIE_APP_INSTALLATION_ID` or `--app-installation-id`: GitHub App
+///   installation ID
+/// - `FRANKIE_APP_PRIVATE_KEY_PATH` or `--app-private-key-path`: GitHub App
+///   private key file
 /// - `FRANKIE_OWNER` or `--owner`: Repository owner
 /// - `FRANKIE_REPO` or `--repo`: Repository name
 /// - `FRANKIE_DATABASE_URL` or `--database-url`: Local `SQLite` database path
 /// - `FRANKIE_TEMPLATE` or `--template`: Template file path for custom export
 /// - `FRANKIE_REPLY_MAX_LENGTH` or `--reply-max-length`: Max reply length
 /// - `FRANKIE_REPLY_TEMPLATES` or `--reply-templates`: Reply templates
+/// - `FRANKIE_REPLY_TO` or `--reply-to`: Review comment ID to reply to
+/// - `FRANKIE_REPLY_TEXT` or `--reply-text`: Reply body to post
+/// - `FRANKIE_RESOLVE_THREADS` or `--resolve-threads`: Comment IDs to resolve
+/// - `FRANKIE_UNRESOLVE_THREADS` or `--unresolve-threads`: Comment IDs to
+///   unresolve
 /// - `FRANKIE_AI_REWRITE_MODE` or `--ai-rewrite-mode`: Rewrite mode
 /// - `FRANKIE_AI_REWRITE_TEXT` or `--ai-rewrite-text`: Source draft text
 /// - `FRANKIE_AI_BASE_URL` or `--ai-base-url`: AI API base URL
 /// - `FRANKIE_AI_MODEL` or `--ai-model`: AI model identifier
 /// - `FRANKIE_AI_API_KEY`, `OPENAI_API_KEY`, or `--ai-api-key`: AI API key
 /// - `FRANKIE_AI_TIMEOUT_SECONDS` or `--ai-timeout-seconds`: Request timeout
+/// - `FRANKIE_SEARCH_HOST` or `--search-host`: GitHub host to search
 /// - `--verify-resolutions`: Verify resolutions
 ///
 /// # Example
@@ -66,6 +81,20 @@ pub struct FrankieConfig {
     #[ortho_config(cli_short = 't')]
     pub token: Option<String>,
 
+    /// Tokens for specific GitHub hosts, as `host=token` entries.
+    ///
+    /// Pull requests and repositories on a listed host, such as a GitHub
+    /// Enterprise server, use its token instead of `token`. Hosts are matched
+    /// case-insensitively and without a port.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--host-tokens '<json-array>'`
+    /// - Environment: `FRANKIE_HOST_TOKENS`
+    /// - Config file:
+    ///   `host_tokens = ["ghe.example.com=ghp_..."]`
+    #[ortho_config()]
+    pub host_tokens: Vec<String>,
+
     /// Repository owner (e.g., "octocat").
     ///
     /// Can be provided via:
@@ -109,6 +138,22 @@ pub struct FrankieConfig {
     #[ortho_config()]
     pub migrate_db: bool,
 
+    /// Reads review comments from the local database instead of GitHub.
+    ///
+    /// Every review comment Frankie fetches while `database_url` is set is
+    /// stored locally. Offline mode opens the review TUI, `--export`, and
+    /// `--summarize-discussions` from those stored comments without making
+    /// GitHub requests, so no token is needed. Requires `database_url`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--offline`
+    /// - Config file: `offline = true`
+    ///
+    /// Note: Environment variable `FRANKIE_OFFLINE` is not supported because
+    /// `ortho_config` does not load boolean values from the environment.
+    #[ortho_config()]
+    pub offline: bool,
+
     /// TTL for cached pull request metadata, in seconds.
     ///
     /// When `database_url` is set, Frankie can cache pull request metadata in
@@ -220,6 +265,22 @@ pub struct FrankieConfig {
     #[ortho_config()]
     pub template: Option<String>,
 
+    /// Exports the pull request's whole conversation timeline.
+    ///
+    /// When set together with `--export`, issue comments and reviews are
+    /// exported alongside the review comments, in chronological order,
+    /// rather than review comments alone sorted by location. Not available
+    /// offline, because issue comments are not stored locally.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--timeline`
+    /// - Config file: `timeline = true`
+    ///
+    /// Note: Environment variable `FRANKIE_TIMELINE` is not supported because
+    /// `ortho_config` does not load boolean values from the environment.
+    #[ortho_config()]
+    pub timeline: bool,
+
     /// Local repository path for time-travel features (`--repo-path`,
     /// `FRANKIE_REPO_PATH`). Overrides auto-discovery from the current
     /// working directory.
@@ -251,6 +312,81 @@ pub struct FrankieConfig {
     #[ortho_config()]
     pub reply_templates: Vec<String>,
 
+    /// Review comment ID to reply to in non-interactive reply mode.
+    ///
+    /// When set together with `reply_text`, Frankie posts the text as a
+    /// threaded reply to this comment and exits.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--reply-to <COMMENT_ID>`
+    /// - Environment: `FRANKIE_REPLY_TO`
+    #[ortho_config()]
+    pub reply_to: Option<u64>,
+
+    /// Reply body posted in non-interactive reply mode.
+    ///
+    /// Must be non-empty and no longer than `reply_max_length` characters.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--reply-text <TEXT>`
+    /// - Environment: `FRANKIE_REPLY_TEXT`
+    #[ortho_config()]
+    pub reply_text: Option<String>,
+
+    /// Comma-separated review comment IDs whose threads should be resolved.
+    ///
+    /// Any comment in a thread identifies that thread; Frankie resolves it
+    /// through GitHub's GraphQL API and exits.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--resolve-threads <IDS>`
+    /// - Environment: `FRANKIE_RESOLVE_THREADS`
+    #[ortho_config()]
+    pub resolve_threads: Option<String>,
+
+    /// Comma-separated review comment IDs whose threads should be unresolved.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--unresolve-threads <IDS>`
+    /// - Environment: `FRANKIE_UNRESOLVE_THREADS`
+    #[ortho_config()]
+    pub unresolve_threads: Option<String>,
+
+    /// Lists the review writes queued in the local outbox.
+    ///
+    /// Replies and thread resolutions that could not reach GitHub are queued
+    /// in the database at `database_url`. Requires `database_url`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--outbox`
+    /// - Config file: `outbox = true`
+    ///
+    /// Note: Environment variable `FRANKIE_OUTBOX` is not supported because
+    /// `ortho_config` does not load boolean values from the environment.
+    #[ortho_config()]
+    pub outbox: bool,
+
+    /// Intent ID of a queued write to send again, or `all`.
+    ///
+    /// Writes already reflected on GitHub are removed without being sent
+    /// twice. Requires `database_url`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--outbox-retry <INTENT_ID|all>`
+    /// - Environment: `FRANKIE_OUTBOX_RETRY`
+    #[ortho_config()]
+    pub outbox_retry: Option<String>,
+
+    /// Intent ID of a queued write to remove without sending it.
+    ///
+    /// Requires `database_url`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--outbox-discard <INTENT_ID>`
+    /// - Environment: `FRANKIE_OUTBOX_DISCARD`
+    #[ortho_config()]
+    pub outbox_discard: Option<String>,
+
     /// Rewrite mode for non-interactive AI draft rewriting.
     ///
     /// Valid values are `expand` and `reword`.
@@ -299,11 +435,153 @@ pub struct FrankieConfig {
     #[ortho_config()]
     pub commit_history_limit: usize,
 
+    /// GitHub App ID used to authenticate as an app installation.
+    ///
+    /// When `app_id`, `app_installation_id`, and `app_private_key_path` are
+    /// all set, Frankie authenticates as the installation instead of using
+    /// `token` or `host_tokens`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--app-id <ID>`
+    /// - Environment: `FRANKIE_APP_ID`
+    /// - Config file: `app_id = 12345`
+    #[ortho_config()]
+    pub app_id: Option<u64>,
+
+    /// GitHub App installation ID to request installation tokens for.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--app-installation-id <ID>`
+    /// - Environment: `FRANKIE_APP_INSTALLATION_ID`
+    /// - Config file: `app_installation_id = 67890`
+    #[ortho_config(cli_short = 'j')]
+    pub app_installation_id: Option<u64>,
+
+    /// Path to the GitHub App's PEM-encoded private key.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--app-private-key-path <PATH>`
+    /// - Environment: `FRANKIE_APP_PRIVATE_KEY_PATH`
+    /// - Config file: `app_private_key_path = "..."`
+    #[ortho_config()]
+    pub app_private_key_path: Option<String>,
+
+    /// Path of the passphrase-encrypted credential file.
+    ///
+    /// Defaults to `${XDG_CONFIG_HOME}/frankie/credentials.enc`, falling back
+    /// to `~/.config/frankie/credentials.enc`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--credential-file <PATH>`
+    /// - Environment: `FRANKIE_CREDENTIAL_FILE`
+    /// - Config file: `credential_file = "..."`
+    #[ortho_config()]
+    pub credential_file: Option<String>,
+
+    /// Passphrase that unlocks the credential file.
+    ///
+    /// Not accepted on the command line, where it would be visible to other
+    /// processes. When unset, the credential file is only read by
+    /// `--auth-*` commands and by prompts on an interactive terminal.
+    ///
+    /// Can be provided via:
+    /// - Environment: `FRANKIE_CREDENTIAL_PASSPHRASE`
+    #[ortho_config(skip_cli)]
+    pub credential_passphrase: Option<String>,
+
+    /// Stores a secret in the credential file.
+    ///
+    /// The target is a GitHub host such as `github.com`, or `ai` for the AI
+    /// API key. The secret is read from standard input, or prompted for
+    /// without echo on a terminal.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--auth-login <HOST|ai>`
+    #[ortho_config()]
+    pub auth_login: Option<String>,
+
+    /// Removes a secret from the credential file.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--auth-logout <HOST|ai>`
+    #[ortho_config()]
+    pub auth_logout: Option<String>,
+
+    /// Lists the secrets stored in the credential file without their values.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--auth-status`
+    #[ortho_config(cli_short = 'Z')]
+    pub auth_status: bool,
+
+    /// GitHub search qualifiers selecting pull requests to list.
+    ///
+    /// Accepts the qualifiers of GitHub's issue search, such as
+    /// `review-requested:@me`, `author:octocat`, `label:bug`, `is:draft`,
+    /// and `updated:>2026-01-01`. `--owner` alone restricts the search to an
+    /// organization and `--owner` with `--repo` to one repository. With
+    /// `--tui`, the results open in a picker.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--search <QUALIFIERS>`
+    /// - Environment: `FRANKIE_SEARCH`
+    /// - Config file: `search = "review-requested:@me"`
+    #[ortho_config(cli_short = 'Q')]
+    pub search: Option<String>,
+
+    /// GitHub host searched by `--search` and the review queue dashboard,
+    /// such as `ghe.example.com`. Defaults to `github.com`.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--search-host <HOST>` or `-Y <HOST>`
+    /// - Environment: `FRANKIE_SEARCH_HOST`
+    /// - Config file: `search_host = "ghe.example.com"`
+    #[ortho_config(cli_short = 'Y')]
+    pub search_host: Option<String>,
+
+    /// Shows the review queue dashboard.
+    ///
+    /// Gathers open pull requests where the user's review is requested or
+    /// where they have commented, plus any `--search` results, and ranks
+    /// them by unread activity and staleness. With `--tui`, the queue opens
+    /// in a picker. With `database_url`, the queue is cached and unread
+    /// activity is tracked between runs.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--dashboard`
+    /// - Config file: `dashboard = true`
+    ///
+    /// Note: Environment variable `FRANKIE_DASHBOARD` is not supported
+    /// because `ortho_config` does not load boolean values from the
+    /// environment.
+    #[ortho_config(cli_short = 'W')]
+    pub dashboard: bool,
+
+    /// Repositories the review queue dashboard is limited to, as
+    /// `owner/repo` entries. Empty includes every repository.
+    ///
+    /// Can be provided via:
+    /// - CLI: `--dashboard-repos '<json-array>'`
+    /// - Environment: `FRANKIE_DASHBOARD_REPOS`
+    /// - Config file: `dashboard_repos = ["octo/app", "octo/lib"]`
+    #[ortho_config(cli_short = 'J')]
+    pub dashboard_repos: Vec<String>,
+
     /// Positional PR identifier (bare number or full URL) extracted from
     /// command-line arguments before ortho-config processes the remaining
     /// flags. When set, the TUI is launched without requiring `-T`.
     #[serde(skip)]
     pub pr_identifier: Option<String>,
+
+    /// Whether `token` was passed with `--token` or `-t` rather than read
+    /// from `FRANKIE_TOKEN` or a configuration file.
+    ///
+    /// Set by [`FrankieConfig::load`] and [`FrankieConfig::load_from_iter`]
+    /// from the raw command-line arguments, so an explicit `--token` can take
+    /// precedence over every discovered token.
+    #[ortho_config(skip_cli)]
+    #[serde(skip)]
+    pub token_from_command_line: bool,
 }
 
 const DEFAULT_PR_METADATA_CACHE_TTL_SECONDS: u64 = 86_400;
@@ -320,10 +598,12 @@ impl Default for FrankieConfig {
         Self {
             pr_url: None,
             token: None,
+            host_tokens: Vec::new(),
             owner: None,
             repo: None,
             database_url: None,
             migrate_db: false,
+            offline: false,
             pr_metadata_cache_ttl_seconds: DEFAULT_PR_METADATA_CACHE_TTL_SECONDS,
             no_local_discovery: false,
             tui: false,
@@ -332,9 +612,17 @@ impl Default for FrankieConfig {
             summarize_discussions: false,
             output: None,
             template: None,
+            timeline: false,
             repo_path: None,
             reply_max_length: DEFAULT_REPLY_MAX_LENGTH,
             reply_templates: crate::reply_template::default_reply_templates(),
+            reply_to: None,
+            reply_text: None,
+            resolve_threads: None,
+            unresolve_threads: None,
+            outbox: false,
+            outbox_retry: None,
+            outbox_discard: None,
             ai_rewrite_mode: None,
             ai_rewrite_text: None,
             ai_base_url: DEFAULT_AI_BASE_URL.to_owned(),
@@ -342,7 +630,44 @@ impl Default for FrankieConfig {
             ai_api_key: None,
             ai_timeout_seconds: DEFAULT_AI_TIMEOUT_SECONDS,
             commit_history_limit: DEFAULT_COMMIT_HISTORY_LIMIT,
+            app_id: None,
+            app_installation_id: None,
+            app_private_key_path: None,
+            credential_file: None,
+            credential_passphrase: None,
+            auth_login: None,
+            auth_logout: None,
+            auth_status: false,
+            search: None,
+            search_host: None,
+            dashboard: false,
+            dashboard_repos: Vec::new(),
             pr_identifier: None,
+            token_from_command_line: false,
         }
     }
 }
+
+/// Short and long command-line flags that consume a value, such as `-u`
+/// and `--pr-url`.
+///
+/// Derived from the generated CLI parser, so flags and auto-assigned short
+/// aliases never fall out of step with the struct fields.
+pub(super) static VALUE_FLAGS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let mut command = cli_command();
+    command.build();
+    command
+        .get_arguments()
+        .filter(|arg| arg.get_num_args().is_some_and(|range| range.takes_values()))
+        .flat_map(|arg| {
+            let short = arg.get_short().map(|short| format!("-{short}"));
+            let long = arg.get_long().map(|long| format!("--{long}"));
+            short.into_iter().chain(long)
+        })
+        .collect()
+});
+
+/// Returns the clap command that parses [`FrankieConfig`]'s flags.
+pub(super) fn cli_command() -> clap::Command {
+    __FrankieConfigCli::command()
+}
@@ -0,0 +1,55 @@
+//! Offline-mode helpers for configuration validation.
+
+use crate::config::{FrankieConfig, OperationMode};
+use crate::github::error::IntakeError;
+
+/// Returns whether offline mode is enabled.
+#[must_use]
+pub(crate) const fn is_offline_mode(config: &FrankieConfig) -> bool {
+    config.offline
+}
+
+/// Checks that offline mode has a database to read from and is only used
+/// with modes that can run from stored review comments.
+pub(crate) fn validate_offline_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if !is_offline_mode(config) {
+        return Ok(());
+    }
+
+    let has_database_url = config
+        .database_url
+        .as_deref()
+        .is_some_and(|url| !url.trim().is_empty());
+    if !has_database_url {
+        return Err(IntakeError::Configuration {
+            message: concat!(
+                "--offline reads review comments from the local database; ",
+                "provide --database-url"
+            )
+            .to_owned(),
+        });
+    }
+
+    if supports_offline(config.operation_mode()) {
+        return Ok(());
+    }
+
+    Err(IntakeError::Configuration {
+        message: concat!(
+            "--offline only supports the review TUI, --export, ",
+            "--summarize-discussions, --outbox, and --dashboard"
+        )
+        .to_owned(),
+    })
+}
+
+const fn supports_offline(mode: OperationMode) -> bool {
+    matches!(
+        mode,
+        OperationMode::ReviewTui
+            | OperationMode::ExportComments
+            | OperationMode::SummarizeDiscussions
+            | OperationMode::Outbox
+            | OperationMode::Dashboard
+    )
+}
@@ -0,0 +1,179 @@
+//! Operation mode detection from the configured flags.
+
+use super::{
+    auth_mode, dashboard_mode, outbox_mode, reply_mode, search_mode, summarize_mode,
+    thread_resolution_mode,
+};
+use crate::config::FrankieConfig;
+
+/// Operation mode determined by CLI arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationMode {
+    /// Load a single PR by URL.
+    SinglePullRequest,
+    /// List PRs for a repository.
+    RepositoryListing,
+    /// Interactive repository discovery (future).
+    Interactive,
+    /// Interactive TUI for reviewing PR comments.
+    ReviewTui,
+    /// Export review comments in structured format.
+    ExportComments,
+    /// AI-powered draft rewrite mode.
+    AiRewrite,
+    /// Verify comment resolutions against local git state.
+    VerifyResolutions,
+    /// Generate an AI summary for PR-level discussions.
+    SummarizeDiscussions,
+    /// Post a threaded reply to a review comment.
+    PostReply,
+    /// Resolve or unresolve review threads by comment ID.
+    ResolveThreads,
+    /// Inspect, retry, or discard queued review writes.
+    Outbox,
+    /// Manage the secrets stored in the encrypted credential file.
+    Auth,
+    /// Search pull requests with GitHub search qualifiers.
+    SearchPullRequests,
+    /// Show the cross-repository review queue.
+    Dashboard,
+}
+
+impl FrankieConfig {
+    pub(super) const fn has_pr_url(&self) -> bool {
+        self.pr_url.is_some()
+    }
+
+    const fn has_repo(&self) -> bool {
+        self.owner.is_some() && self.repo.is_some()
+    }
+
+    pub(super) const fn should_export_comments(&self) -> bool {
+        self.export.is_some()
+    }
+
+    fn non_empty_trimmed(value: Option<&str>) -> bool {
+        value.is_some_and(|text| !text.trim().is_empty())
+    }
+
+    pub(super) fn rewrite_mode_present(&self) -> bool {
+        Self::non_empty_trimmed(self.ai_rewrite_mode.as_deref())
+    }
+
+    pub(super) fn rewrite_text_present(&self) -> bool {
+        Self::non_empty_trimmed(self.ai_rewrite_text.as_deref())
+    }
+
+    pub(super) fn should_ai_rewrite(&self) -> bool {
+        self.rewrite_mode_present() || self.rewrite_text_present()
+    }
+
+    const fn is_verify_resolutions_mode(&self) -> bool {
+        self.verify_resolutions
+    }
+
+    const fn is_summarize_discussions_mode(&self) -> bool {
+        summarize_mode::is_summarize_discussions_mode(self)
+    }
+
+    const fn is_post_reply_mode(&self) -> bool {
+        reply_mode::is_post_reply_mode(self)
+    }
+
+    const fn is_thread_resolution_mode(&self) -> bool {
+        thread_resolution_mode::is_thread_resolution_mode(self)
+    }
+
+    fn is_ai_rewrite_mode(&self) -> bool {
+        self.should_ai_rewrite()
+    }
+
+    const fn is_export_comments_mode(&self) -> bool {
+        self.export.is_some()
+    }
+
+    const fn is_review_tui_mode(&self) -> bool {
+        self.has_pr_identifier() || (self.tui && self.has_pr_url())
+    }
+
+    const fn is_single_pull_request_mode(&self) -> bool {
+        self.has_pr_url()
+    }
+
+    const fn is_repository_listing_mode(&self) -> bool {
+        self.has_repo()
+    }
+
+    pub(super) const fn has_pr_identifier(&self) -> bool {
+        self.pr_identifier.is_some()
+    }
+
+    /// Returns the mode for one-shot actions on a pull request's reviews.
+    ///
+    /// These modes take precedence over export, TUI, and listing modes.
+    const fn review_action_mode(&self) -> Option<OperationMode> {
+        if self.is_verify_resolutions_mode() {
+            Some(OperationMode::VerifyResolutions)
+        } else if self.is_summarize_discussions_mode() {
+            Some(OperationMode::SummarizeDiscussions)
+        } else if self.is_post_reply_mode() {
+            Some(OperationMode::PostReply)
+        } else if self.is_thread_resolution_mode() {
+            Some(OperationMode::ResolveThreads)
+        } else {
+            None
+        }
+    }
+
+    fn resolve_operation_mode(&self) -> OperationMode {
+        if auth_mode::is_auth_mode(self) {
+            return OperationMode::Auth;
+        }
+        if outbox_mode::is_outbox_mode(self) {
+            return OperationMode::Outbox;
+        }
+        if dashboard_mode::is_dashboard_mode(self) {
+            return OperationMode::Dashboard;
+        }
+        if search_mode::is_search_mode(self) {
+            return OperationMode::SearchPullRequests;
+        }
+        self.review_action_mode()
+            .unwrap_or_else(|| self.fallback_operation_mode())
+    }
+
+    /// Returns the mode used when no one-shot review action is configured.
+    fn fallback_operation_mode(&self) -> OperationMode {
+        if self.is_ai_rewrite_mode() {
+            OperationMode::AiRewrite
+        } else if self.is_export_comments_mode() {
+            OperationMode::ExportComments
+        } else if self.is_review_tui_mode() {
+            OperationMode::ReviewTui
+        } else if self.is_single_pull_request_mode() {
+            OperationMode::SinglePullRequest
+        } else if self.is_repository_listing_mode() {
+            OperationMode::RepositoryListing
+        } else {
+            OperationMode::Interactive
+        }
+    }
+
+    /// Determines the operation mode based on provided configuration.
+    ///
+    /// Returns `Auth` if an `--auth-*` flag is set, `Outbox` if an outbox
+    /// flag is set, `Dashboard` if `--dashboard` is set,
+    /// `SearchPullRequests` if `--search` is set, `PostReply`
+    /// if a reply target is set, `ResolveThreads` if
+    /// thread resolution targets are set, `AiRewrite` if AI rewrite fields
+    /// are set, `ExportComments` if export format is set (PR URL validation
+    /// is deferred to `export_comments::run`), `ReviewTui` if a positional PR
+    /// identifier is present or TUI mode is enabled with a PR URL,
+    /// `SinglePullRequest` if a PR URL is provided without TUI or export,
+    /// `RepositoryListing` if both owner and repo are provided, or
+    /// `Interactive` otherwise.
+    #[must_use]
+    pub fn operation_mode(&self) -> OperationMode {
+        self.resolve_operation_mode()
+    }
+}
@@ -0,0 +1,114 @@
+//! Write-outbox mode helpers for configuration validation.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+
+/// Value of `--outbox-retry` that replays every queued write.
+const RETRY_ALL: &str = "all";
+
+/// Action requested on the write outbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxAction<'a> {
+    /// List the queued writes.
+    List,
+    /// Send every queued write again.
+    RetryAll,
+    /// Send the queued write with this intent ID again.
+    Retry(&'a str),
+    /// Remove the queued write with this intent ID without sending it.
+    Discard(&'a str),
+}
+
+/// Returns whether one of the outbox flags is set.
+#[must_use]
+pub(crate) const fn is_outbox_mode(config: &FrankieConfig) -> bool {
+    config.outbox || config.outbox_retry.is_some() || config.outbox_discard.is_some()
+}
+
+/// Returns the requested outbox action.
+///
+/// Retrying takes precedence over discarding; [`validate_outbox_mode`]
+/// rejects configurations that set both.
+#[must_use]
+pub(crate) fn outbox_action(config: &FrankieConfig) -> OutboxAction<'_> {
+    match (
+        config.outbox_retry.as_deref().map(str::trim),
+        config.outbox_discard.as_deref().map(str::trim),
+    ) {
+        (Some(target), _) if target.eq_ignore_ascii_case(RETRY_ALL) => OutboxAction::RetryAll,
+        (Some(intent_id), _) => OutboxAction::Retry(intent_id),
+        (None, Some(intent_id)) => OutboxAction::Discard(intent_id),
+        (None, None) => OutboxAction::List,
+    }
+}
+
+/// Checks that outbox mode has a database, a single action with a non-empty
+/// target, and no conflicting flags.
+pub(crate) fn validate_outbox_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if !is_outbox_mode(config) {
+        return Ok(());
+    }
+
+    let has_database_url = config
+        .database_url
+        .as_deref()
+        .is_some_and(|url| !url.trim().is_empty());
+    if !has_database_url {
+        return Err(configuration_error(
+            "the write outbox is stored in the local database; provide --database-url",
+        ));
+    }
+
+    if config.outbox_retry.is_some() && config.outbox_discard.is_some() {
+        return Err(configuration_error(
+            "--outbox-retry and --outbox-discard cannot be combined",
+        ));
+    }
+
+    if matches!(
+        outbox_action(config),
+        OutboxAction::Retry("") | OutboxAction::Discard("")
+    ) {
+        return Err(configuration_error(
+            "--outbox-retry and --outbox-discard require an intent ID",
+        ));
+    }
+
+    if config.offline && config.outbox_retry.is_some() {
+        return Err(configuration_error(
+            "--outbox-retry sends writes to GitHub and cannot be combined with --offline",
+        ));
+    }
+
+    validate_outbox_compatibility(config)
+}
+
+fn validate_outbox_compatibility(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let conflicting_flag = if config.verify_resolutions {
+        Some("--verify-resolutions")
+    } else if config.summarize_discussions {
+        Some("--summarize-discussions")
+    } else if config.reply_to.is_some() || config.reply_text.is_some() {
+        Some("--reply-to/--reply-text")
+    } else if config.resolve_threads.is_some() || config.unresolve_threads.is_some() {
+        Some("--resolve-threads/--unresolve-threads")
+    } else if config.export.is_some() {
+        Some("--export")
+    } else if config.tui {
+        Some("--tui")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(configuration_error(&format!(
+            "--outbox, --outbox-retry, and --outbox-discard cannot be combined with {flag}"
+        )))
+    })
+}
+
+fn configuration_error(message: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: message.to_owned(),
+    }
+}
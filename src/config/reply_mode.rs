@@ -0,0 +1,73 @@
+//! Reply-mode helpers for configuration validation and mode detection.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+
+/// Returns whether non-interactive reply mode is enabled.
+#[must_use]
+pub(crate) const fn is_post_reply_mode(config: &FrankieConfig) -> bool {
+    config.reply_to.is_some()
+}
+
+pub(crate) fn validate_post_reply_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    validate_reply_completeness(config)?;
+    if !is_post_reply_mode(config) {
+        return Ok(());
+    }
+
+    validate_reply_compatibility(config)?;
+    validate_reply_text(config)
+}
+
+fn validate_reply_completeness(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if config.reply_to.is_some() != config.reply_text.is_some() {
+        return Err(IntakeError::Configuration {
+            message: "--reply-to and --reply-text must be provided together".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_reply_compatibility(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let conflicting_flag = if config.verify_resolutions {
+        Some("--verify-resolutions")
+    } else if config.summarize_discussions {
+        Some("--summarize-discussions")
+    } else if config.should_ai_rewrite() {
+        Some("AI rewrite flags")
+    } else if config.export.is_some() {
+        Some("--export")
+    } else if config.tui {
+        Some("--tui")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(IntakeError::Configuration {
+            message: format!("--reply-to cannot be combined with {flag}"),
+        })
+    })
+}
+
+fn validate_reply_text(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let text = config.reply_text.as_deref().unwrap_or_default();
+    if text.trim().is_empty() {
+        return Err(IntakeError::Configuration {
+            message: "--reply-text must not be empty".to_owned(),
+        });
+    }
+
+    let char_count = text.chars().count();
+    if char_count > config.reply_max_length {
+        return Err(IntakeError::Configuration {
+            message: format!(
+                "--reply-text is {char_count} characters, exceeding the configured limit of {}",
+                config.reply_max_length
+            ),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+//! Pull request search mode helpers for configuration validation.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+use crate::github::gateway::{SearchPullRequestsParams, SearchScope};
+use crate::github::locator::api_base_for_host;
+
+/// Returns whether `--search` is set.
+#[must_use]
+pub(crate) const fn is_search_mode(config: &FrankieConfig) -> bool {
+    config.search.is_some()
+}
+
+/// Returns the search parameters for `--search`, scoped by `--owner` and
+/// `--repo`.
+#[must_use]
+pub(crate) fn search_params(config: &FrankieConfig) -> SearchPullRequestsParams {
+    let scope = match (config.owner.as_deref(), config.repo.as_deref()) {
+        (Some(owner), Some(repo)) => SearchScope::Repository {
+            owner: owner.to_owned(),
+            repo: repo.to_owned(),
+        },
+        (Some(owner), None) => SearchScope::Organization(owner.to_owned()),
+        (None, _) => SearchScope::All,
+    };
+    SearchPullRequestsParams {
+        per_page: Some(50),
+        ..SearchPullRequestsParams::new(config.search.as_deref().unwrap_or_default(), scope)
+    }
+}
+
+/// Host searched when `search_host` is unset.
+const DEFAULT_SEARCH_HOST: &str = "github.com";
+
+/// Returns `search_host`, or github.com when it is unset or blank.
+#[must_use]
+pub(crate) fn search_host(config: &FrankieConfig) -> &str {
+    config
+        .search_host
+        .as_deref()
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .unwrap_or(DEFAULT_SEARCH_HOST)
+}
+
+/// Checks that `search_host` is a host name and that search mode has
+/// qualifiers, a usable scope, and no conflicting flags.
+pub(crate) fn validate_search_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if api_base_for_host(search_host(config)).is_err() {
+        return Err(configuration_error(
+            "--search-host must be a host name such as ghe.example.com, without a scheme or path",
+        ));
+    }
+    let Some(query) = config.search.as_deref() else {
+        return Ok(());
+    };
+
+    if query.trim().is_empty() {
+        return Err(configuration_error(
+            "--search requires search qualifiers, such as `review-requested:@me`",
+        ));
+    }
+    if config.repo.is_some() && config.owner.is_none() {
+        return Err(configuration_error(
+            "--search with --repo also requires --owner",
+        ));
+    }
+
+    let conflicting_flag = if config.pr_url.is_some() || config.pr_identifier.is_some() {
+        Some("a pull request")
+    } else if config.export.is_some() {
+        Some("--export")
+    } else if super::outbox_mode::is_outbox_mode(config) {
+        Some("--outbox")
+    } else if super::auth_mode::is_auth_mode(config) {
+        Some("--auth-login, --auth-logout, or --auth-status")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(configuration_error(&format!(
+            "--search cannot be combined with {flag}"
+        )))
+    })
+}
+
+fn configuration_error(message: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: message.to_owned(),
+    }
+}
@@ -0,0 +1,93 @@
+//! Tests for GitHub App installation credential resolution and validation.
+
+use rstest::rstest;
+
+use crate::FrankieConfig;
+use crate::github::credentials::GitHubCredentials;
+use crate::github::error::IntakeError;
+
+const TEST_KEY_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/github_app_test_key.pem"
+);
+
+fn config_with_app(
+    app_id: Option<u64>,
+    installation_id: Option<u64>,
+    key_path: Option<&str>,
+) -> FrankieConfig {
+    FrankieConfig {
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        token: Some("default-token".to_owned()),
+        app_id,
+        app_installation_id: installation_id,
+        app_private_key_path: key_path.map(str::to_owned),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+fn resolve_credentials_prefers_the_app_installation() {
+    let config = config_with_app(Some(1), Some(2), Some(TEST_KEY_PATH));
+
+    let credentials = config.resolve_credentials("github.com", "https://api.github.com");
+
+    match credentials {
+        Ok(GitHubCredentials::AppInstallation(installation)) => {
+            assert_eq!(installation.app_id(), 1);
+            assert_eq!(installation.installation_id(), 2);
+        }
+        other => panic!("expected app installation credentials, got {other:?}"),
+    }
+}
+
+#[rstest]
+fn resolve_credentials_falls_back_to_the_token() {
+    let config = config_with_app(None, None, None);
+
+    let credentials = config.resolve_credentials("github.com", "https://api.github.com");
+
+    assert!(matches!(
+        credentials,
+        Ok(GitHubCredentials::PersonalAccessToken(token)) if token.value() == "default-token"
+    ));
+}
+
+#[rstest]
+fn resolve_credentials_reports_an_unreadable_private_key() {
+    let config = config_with_app(Some(1), Some(2), Some("/nonexistent/frankie-app.pem"));
+
+    match config.resolve_credentials("github.com", "https://api.github.com") {
+        Err(IntakeError::Configuration { message }) => {
+            assert!(
+                message.contains("/nonexistent/frankie-app.pem"),
+                "{message}"
+            );
+        }
+        other => panic!("expected Configuration error, got {other:?}"),
+    }
+}
+
+#[rstest]
+#[case::all_settings(Some(1), Some(2), Some("key.pem"), true)]
+#[case::no_settings(None, None, None, true)]
+#[case::missing_key(Some(1), Some(2), None, false)]
+#[case::missing_installation(Some(1), None, Some("key.pem"), false)]
+#[case::installation_only(None, Some(2), None, false)]
+fn validate_requires_app_settings_together(
+    #[case] app_id: Option<u64>,
+    #[case] installation_id: Option<u64>,
+    #[case] key_path: Option<&str>,
+    #[case] valid: bool,
+) {
+    let config = config_with_app(app_id, installation_id, key_path);
+
+    match config.validate() {
+        Ok(()) => assert!(valid, "expected a Configuration error"),
+        Err(IntakeError::Configuration { message }) => {
+            assert!(!valid, "unexpected error: {message}");
+            assert!(message.contains("--app-installation-id"), "{message}");
+        }
+        Err(other) => panic!("expected Configuration error, got {other:?}"),
+    }
+}
@@ -0,0 +1,244 @@
+//! Tests for the encrypted credential file and its place in token
+//! resolution.
+
+use std::path::{Path, PathBuf};
+
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::helpers::lock_env_without_ambient_tokens;
+use crate::FrankieConfig;
+use crate::config::credential_store::resolve_credential_file;
+use crate::config::{CredentialStore, CredentialTarget, TokenSource};
+use crate::github::error::IntakeError;
+
+const PASSPHRASE: &str = "correct horse battery staple";
+
+/// Writes a credential file holding a token for `ghe.example.com:8443` and
+/// an AI API key, and returns its path.
+fn saved_store(dir: &TempDir) -> PathBuf {
+    let path = dir.path().join("nested").join("credentials.enc");
+    let mut store = CredentialStore::default();
+    store.set(
+        CredentialTarget::GitHubHost("GHE.example.com:8443"),
+        "ghp_stored".to_owned(),
+    );
+    store.set(CredentialTarget::AiApiKey, "sk-stored".to_owned());
+    store
+        .save(&path, PASSPHRASE)
+        .expect("credential file should be written");
+    path
+}
+
+fn config_for(path: &Path, passphrase: Option<&str>) -> FrankieConfig {
+    FrankieConfig {
+        credential_file: Some(path.display().to_string()),
+        credential_passphrase: passphrase.map(str::to_owned),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+fn saved_store_round_trips_without_exposing_secrets_on_disk() {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+
+    let contents = std::fs::read_to_string(&path).expect("credential file should be readable");
+    let loaded = CredentialStore::load(&path, PASSPHRASE).expect("store should decrypt");
+
+    assert!(!contents.contains("ghp_stored"), "file: {contents}");
+    assert!(!contents.contains("ghe.example.com"), "file: {contents}");
+    assert_eq!(
+        loaded.get(CredentialTarget::GitHubHost("ghe.example.com")),
+        Some("ghp_stored")
+    );
+    assert_eq!(loaded.get(CredentialTarget::AiApiKey), Some("sk-stored"));
+}
+
+#[cfg(unix)]
+#[rstest]
+fn saved_store_is_readable_by_its_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+
+    let mode = std::fs::metadata(&path)
+        .expect("credential file should exist")
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[rstest]
+fn saving_leaves_no_temporary_file_behind() {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let store = CredentialStore::load(&path, PASSPHRASE).expect("credential file should load");
+    store
+        .save(&path, PASSPHRASE)
+        .expect("credential file should be rewritten");
+
+    let entries = std::fs::read_dir(path.parent().expect("file should have a parent"))
+        .expect("directory should be readable")
+        .map(|entry| entry.expect("entry should be readable").file_name())
+        .collect::<Vec<_>>();
+    assert_eq!(entries, ["credentials.enc"]);
+}
+
+#[rstest]
+#[case::wrong_passphrase(None)]
+#[case::modified_file(Some(("\"iterations\": 600000", "\"iterations\": 600001")))]
+fn load_rejects_wrong_passphrase_and_modified_files(#[case] edit: Option<(&str, &str)>) {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let passphrase = if let Some((from, to)) = edit {
+        let contents = std::fs::read_to_string(&path).expect("credential file should be readable");
+        std::fs::write(&path, contents.replace(from, to)).expect("file should be rewritten");
+        PASSPHRASE
+    } else {
+        "wrong passphrase"
+    };
+
+    match CredentialStore::load(&path, passphrase) {
+        Err(IntakeError::Configuration { message }) => {
+            assert!(message.contains("could not decrypt"), "message: {message}");
+        }
+        other => panic!("expected a decryption error, got {other:?}"),
+    }
+}
+
+#[rstest]
+#[case::too_few("\"iterations\": 1,")]
+#[case::too_many("\"iterations\": 4000000000,")]
+fn load_rejects_out_of_range_iteration_counts(#[case] iterations: &str) {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let contents = std::fs::read_to_string(&path).expect("credential file should be readable");
+    std::fs::write(
+        &path,
+        contents.replace("\"iterations\": 600000,", iterations),
+    )
+    .expect("file should be rewritten");
+
+    match CredentialStore::load(&path, PASSPHRASE) {
+        Err(IntakeError::Configuration { message }) => {
+            assert!(message.contains("iteration count"), "message: {message}");
+        }
+        other => panic!("expected a malformed file error, got {other:?}"),
+    }
+}
+
+#[rstest]
+fn load_returns_an_empty_store_for_a_missing_file() {
+    let dir = TempDir::new().expect("temp dir should be created");
+
+    let store = CredentialStore::load(&dir.path().join("missing.enc"), PASSPHRASE)
+        .expect("missing file should load as empty");
+
+    assert_eq!(store, CredentialStore::default());
+}
+
+#[rstest]
+fn store_debug_lists_hosts_without_secrets() {
+    let mut store = CredentialStore::default();
+    store.set(
+        CredentialTarget::GitHubHost("github.com"),
+        "ghp_secret".to_owned(),
+    );
+    store.set(CredentialTarget::AiApiKey, "sk-secret".to_owned());
+
+    let debug = format!("{store:?}");
+
+    assert!(debug.contains("github.com"), "debug: {debug}");
+    assert!(!debug.contains("secret"), "debug: {debug}");
+}
+
+#[rstest]
+fn store_remove_reports_whether_a_secret_was_stored() {
+    let mut store = CredentialStore::default();
+    store.set(
+        CredentialTarget::GitHubHost("github.com"),
+        "ghp_secret".to_owned(),
+    );
+
+    assert!(store.remove(CredentialTarget::GitHubHost("GitHub.com")));
+    assert!(!store.remove(CredentialTarget::GitHubHost("github.com")));
+    assert!(!store.remove(CredentialTarget::AiApiKey));
+}
+
+#[rstest]
+#[case::xdg(Some("/xdg"), Some("/home/me"), "/xdg/frankie/credentials.enc")]
+#[case::home(None, Some("/home/me"), "/home/me/.config/frankie/credentials.enc")]
+#[case::empty_xdg(Some(""), Some("/home/me"), "/home/me/.config/frankie/credentials.enc")]
+fn resolve_credential_file_follows_xdg_layout(
+    #[case] xdg: Option<&str>,
+    #[case] home: Option<&str>,
+    #[case] expected: &str,
+) {
+    assert_eq!(
+        resolve_credential_file(xdg, home).ok(),
+        Some(PathBuf::from(expected))
+    );
+}
+
+#[rstest]
+fn resolve_token_source_reads_the_credential_file_with_a_passphrase() {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let _guard = env_lock::lock_env([("GITHUB_TOKEN", None::<&str>)]);
+
+    let resolved = config_for(&path, Some(PASSPHRASE))
+        .resolve_token_source("ghe.example.com")
+        .expect("stored token should resolve");
+
+    assert_eq!(resolved.value(), "ghp_stored");
+    assert_eq!(resolved.source(), &TokenSource::CredentialFile { path });
+}
+
+#[rstest]
+#[case::enterprise_host_uses_the_stored_token("ghe.example.com", "ghp_stored")]
+#[case::github_com_uses_github_token_env("github.com", "ghp_env")]
+fn resolve_token_source_orders_the_credential_file_by_host(
+    #[case] host: &str,
+    #[case] expected: &str,
+) {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let _guard = env_lock::lock_env([("GITHUB_TOKEN", Some("ghp_env"))]);
+
+    assert_eq!(
+        config_for(&path, Some(PASSPHRASE))
+            .resolve_token_for_host(host)
+            .ok()
+            .as_deref(),
+        Some(expected)
+    );
+}
+
+#[rstest]
+fn resolve_token_source_skips_the_credential_file_without_a_passphrase() {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = dir.path().join("unreadable.enc");
+    std::fs::write(&path, "not an envelope").expect("file should be written");
+    let _guard = lock_env_without_ambient_tokens();
+
+    assert!(matches!(
+        config_for(&path, None).resolve_token_source("github.com"),
+        Err(IntakeError::MissingToken)
+    ));
+}
+
+#[rstest]
+fn resolve_ai_api_key_falls_back_to_the_credential_file() {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let path = saved_store(&dir);
+    let _guard = env_lock::lock_env([("OPENAI_API_KEY", None::<&str>)]);
+
+    assert_eq!(
+        config_for(&path, Some(PASSPHRASE))
+            .resolve_ai_api_key()
+            .as_deref(),
+        Some("sk-stored")
+    );
+}
@@ -3,7 +3,9 @@
 
 use rstest::rstest;
 
+use super::helpers::lock_env_without_ambient_tokens;
 use crate::FrankieConfig;
+use crate::config::model::cli_command;
 
 #[rstest]
 fn resolve_token_returns_value_when_present() {
@@ -37,8 +39,8 @@ fn require_pr_url_returns_value_when_present() {
 
 #[rstest]
 fn resolve_token_returns_error_when_none() {
-    // Lock and clear GITHUB_TOKEN to ensure test isolation
-    let _guard = env_lock::lock_env([("GITHUB_TOKEN", None::<&str>)]);
+    // Lock and clear every ambient token source to ensure test isolation
+    let _guard = lock_env_without_ambient_tokens();
     let config = FrankieConfig::default();
 
     let result = config.resolve_token();
@@ -157,8 +159,8 @@ fn repo_path_can_be_set() {
 #[rstest]
 fn value_flags_includes_repo_path() {
     assert!(
-        FrankieConfig::VALUE_FLAGS.contains(&"--repo-path"),
-        "VALUE_FLAGS should include --repo-path"
+        FrankieConfig::is_value_flag("--repo-path"),
+        "--repo-path should take a value"
     );
 }
 
@@ -167,8 +169,8 @@ fn value_flags_includes_repo_path() {
 #[case("--reply-templates")]
 fn value_flags_include_reply_drafting_flags(#[case] flag: &str) {
     assert!(
-        FrankieConfig::VALUE_FLAGS.contains(&flag),
-        "VALUE_FLAGS should include {flag}"
+        FrankieConfig::is_value_flag(flag),
+        "{flag} should take a value"
     );
 }
 
@@ -181,8 +183,44 @@ fn value_flags_include_reply_drafting_flags(#[case] flag: &str) {
 #[case("--ai-timeout-seconds")]
 fn value_flags_include_ai_rewrite_flags(#[case] flag: &str) {
     assert!(
-        FrankieConfig::VALUE_FLAGS.contains(&flag),
-        "VALUE_FLAGS should include {flag}"
+        FrankieConfig::is_value_flag(flag),
+        "{flag} should take a value"
+    );
+}
+
+#[rstest]
+fn value_flags_include_every_value_taking_argument() {
+    let mut command = cli_command();
+    command.build();
+    let flags: Vec<String> = command
+        .get_arguments()
+        .filter(|arg| arg.get_action().takes_values())
+        .flat_map(|arg| {
+            let short = arg.get_short().map(|short| format!("-{short}"));
+            let long = arg.get_long().map(|long| format!("--{long}"));
+            short.into_iter().chain(long)
+        })
+        .collect();
+
+    assert!(flags.iter().any(|flag| flag == "-S"), "flags: {flags:?}");
+    for flag in &flags {
+        assert!(
+            FrankieConfig::is_value_flag(flag),
+            "{flag} should take a value"
+        );
+    }
+}
+
+#[rstest]
+#[case("--tui")]
+#[case("-T")]
+#[case("--migrate-db")]
+#[case("--token=abc")]
+#[case("--help")]
+fn value_flags_exclude_self_contained_flags(#[case] flag: &str) {
+    assert!(
+        !FrankieConfig::is_value_flag(flag),
+        "{flag} should not take a value"
     );
 }
 
@@ -26,3 +26,17 @@ pub fn build_config_from_layers(layers: &[(&str, Value)]) -> FrankieConfig {
 
     FrankieConfig::merge_from_layers(composer.layers()).expect("merge should succeed")
 }
+
+/// Locks the environment with every token source outside [`FrankieConfig`]
+/// disabled: `GITHUB_TOKEN` is cleared, the `gh` CLI configuration points at
+/// a missing directory, and git reads no global, system, or environment
+/// configuration (so no credential helper runs).
+pub fn lock_env_without_ambient_tokens() -> env_lock::EnvGuard<'static> {
+    env_lock::lock_env([
+        ("GITHUB_TOKEN", None),
+        ("GH_CONFIG_DIR", Some("/nonexistent/frankie-gh-config")),
+        ("GIT_CONFIG_GLOBAL", Some("/dev/null")),
+        ("GIT_CONFIG_NOSYSTEM", Some("1")),
+        ("GIT_CONFIG_COUNT", None),
+    ])
+}
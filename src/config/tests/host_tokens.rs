@@ -0,0 +1,87 @@
+//! Tests for per-host token resolution and validation.
+
+use rstest::rstest;
+
+use super::helpers::lock_env_without_ambient_tokens;
+use crate::FrankieConfig;
+use crate::github::error::IntakeError;
+use crate::redaction::redact_secrets;
+
+fn config_with_host_tokens(entries: &[&str]) -> FrankieConfig {
+    FrankieConfig {
+        token: Some("default-token".to_owned()),
+        host_tokens: entries.iter().map(|entry| (*entry).to_owned()).collect(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case::exact_host("ghe.example.com", "ghe-token")]
+#[case::case_insensitive("GHE.Example.com", "ghe-token")]
+#[case::host_with_port("ghe.example.com:8443", "ghe-token")]
+#[case::github_com_entry("github.com", "public-token")]
+#[case::unlisted_host("other.example.com", "default-token")]
+fn resolve_token_for_host_prefers_host_entries(#[case] host: &str, #[case] expected: &str) {
+    let config = config_with_host_tokens(&[
+        "ghe.example.com:8443 = ghe-token",
+        "github.com=public-token",
+    ]);
+
+    assert_eq!(
+        config.resolve_token_for_host(host).ok().as_deref(),
+        Some(expected)
+    );
+}
+
+#[rstest]
+fn resolve_token_for_host_reports_missing_token() {
+    let config = FrankieConfig {
+        host_tokens: vec!["ghe.example.com=ghe-token".to_owned()],
+        ..Default::default()
+    };
+    let _guard = lock_env_without_ambient_tokens();
+
+    assert!(matches!(
+        config.resolve_token_for_host("github.com"),
+        Err(IntakeError::MissingToken)
+    ));
+}
+
+#[rstest]
+fn resolved_enterprise_tokens_are_redacted_by_value() {
+    let token = "0123456789abcdef0123456789abcdef01234567";
+    let config = config_with_host_tokens(&[&format!("ghe.example.com={token}")]);
+    let line = format!("request to ghe.example.com with {token} failed");
+
+    assert_eq!(
+        config
+            .resolve_token_for_host("ghe.example.com")
+            .ok()
+            .as_deref(),
+        Some(token)
+    );
+    assert_eq!(
+        redact_secrets(&line),
+        "request to ghe.example.com with [REDACTED] failed"
+    );
+}
+
+#[rstest]
+#[case::missing_separator(&["ghe.example.com"], 1)]
+#[case::empty_token(&["github.com=ok", "ghe.example.com= "], 2)]
+#[case::empty_host(&["=secret"], 1)]
+#[case::url_host(&["https://ghe.example.com=secret"], 1)]
+fn validate_rejects_malformed_host_tokens(#[case] entries: &[&str], #[case] position: usize) {
+    let config = FrankieConfig {
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..config_with_host_tokens(entries)
+    };
+
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            assert!(message.contains(&format!("entry {position}")), "{message}");
+            assert!(!message.contains("secret"), "tokens must not be echoed");
+        }
+        other => panic!("expected Configuration error, got {other:?}"),
+    }
+}
@@ -5,17 +5,26 @@
 //! - `precedence`: Layer precedence tests
 //! - `operation_mode`: Operation mode determination tests
 //! - `field_resolution`: Token, PR URL, and repository info resolution tests
+//! - `host_tokens`: Per-host token resolution and validation tests
+//! - `app_auth`: GitHub App installation credential tests
+//! - `token_discovery`: `gh` CLI and git credential helper token discovery
+//!   tests
+//! - `credential_store`: Encrypted credential file and stored token tests
 //! - `ttl_loading`: `pr_metadata_cache_ttl_seconds` loading tests
 //! - `commit_history_limit`: `commit_history_limit` loading tests
 //! - `local_discovery_config`: `no_local_discovery` configuration tests
 //! - `validation`: Configuration consistency validation tests
 
+mod app_auth;
 mod commit_history_limit;
+mod credential_store;
 mod field_resolution;
 mod helpers;
+mod host_tokens;
 mod local_discovery_config;
 mod operation_mode;
 mod precedence;
 mod reply_drafting;
+mod token_discovery;
 mod ttl_loading;
 mod validation;
@@ -3,7 +3,9 @@
 use rstest::rstest;
 
 use crate::FrankieConfig;
-use crate::config::OperationMode;
+use crate::config::{AuthAction, CredentialTarget, OperationMode, OutboxAction};
+use crate::github::gateway::SearchScope;
+use crate::github::review_queue::ReviewQueueRequest;
 
 #[rstest]
 fn operation_mode_single_pr_when_pr_url_present() {
@@ -167,6 +169,39 @@ fn summarize_discussions_takes_precedence_over_export() {
     );
 }
 
+#[rstest]
+fn reply_to_selects_post_reply_mode() {
+    let config = FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("Thanks".to_owned()),
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        config.operation_mode(),
+        OperationMode::PostReply,
+        "reply target should take precedence over single PR mode"
+    );
+}
+
+#[rstest]
+#[case(Some("12"), None)]
+#[case(None, Some("12"))]
+fn thread_resolution_flags_select_resolve_threads_mode(
+    #[case] resolve_threads: Option<&str>,
+    #[case] unresolve_threads: Option<&str>,
+) {
+    let config = FrankieConfig {
+        resolve_threads: resolve_threads.map(str::to_owned),
+        unresolve_threads: unresolve_threads.map(str::to_owned),
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..Default::default()
+    };
+
+    assert_eq!(config.operation_mode(), OperationMode::ResolveThreads);
+}
+
 #[rstest]
 fn pr_identifier_url_triggers_review_tui() {
     let config = FrankieConfig {
@@ -180,3 +215,111 @@ fn pr_identifier_url_triggers_review_tui() {
         "URL identifier should trigger ReviewTui"
     );
 }
+
+#[rstest]
+#[case::list(
+    FrankieConfig { outbox: true, ..Default::default() },
+    OutboxAction::List
+)]
+#[case::retry_all(
+    FrankieConfig { outbox_retry: Some("ALL".to_owned()), ..Default::default() },
+    OutboxAction::RetryAll
+)]
+#[case::retry_one(
+    FrankieConfig { outbox_retry: Some(" w1a2b ".to_owned()), ..Default::default() },
+    OutboxAction::Retry("w1a2b")
+)]
+#[case::discard(
+    FrankieConfig { outbox_discard: Some("w1a2b".to_owned()), ..Default::default() },
+    OutboxAction::Discard("w1a2b")
+)]
+fn outbox_flags_select_outbox_mode(#[case] config: FrankieConfig, #[case] action: OutboxAction) {
+    let config_with_pr = FrankieConfig {
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..config
+    };
+
+    assert_eq!(config_with_pr.operation_mode(), OperationMode::Outbox);
+    assert_eq!(config_with_pr.outbox_action(), action);
+}
+
+#[rstest]
+#[case::status(
+    FrankieConfig { auth_status: true, ..Default::default() },
+    AuthAction::Status
+)]
+#[case::login_host(
+    FrankieConfig { auth_login: Some(" ghe.example.com ".to_owned()), ..Default::default() },
+    AuthAction::Login(CredentialTarget::GitHubHost("ghe.example.com"))
+)]
+#[case::login_ai(
+    FrankieConfig { auth_login: Some("AI".to_owned()), ..Default::default() },
+    AuthAction::Login(CredentialTarget::AiApiKey)
+)]
+#[case::logout(
+    FrankieConfig { auth_logout: Some("github.com".to_owned()), ..Default::default() },
+    AuthAction::Logout(CredentialTarget::GitHubHost("github.com"))
+)]
+fn auth_flags_select_auth_mode(#[case] config: FrankieConfig, #[case] action: AuthAction) {
+    assert_eq!(config.operation_mode(), OperationMode::Auth);
+    assert_eq!(config.auth_action(), action);
+}
+
+#[rstest]
+#[case::all(None, None, SearchScope::All)]
+#[case::organization(Some("octo"), None, SearchScope::Organization("octo".to_owned()))]
+#[case::repository(
+    Some("octo"),
+    Some("app"),
+    SearchScope::Repository { owner: "octo".to_owned(), repo: "app".to_owned() }
+)]
+fn search_flag_selects_search_mode_scoped_by_owner_and_repo(
+    #[case] owner: Option<&str>,
+    #[case] repo: Option<&str>,
+    #[case] scope: SearchScope,
+) {
+    let config = FrankieConfig {
+        search: Some("review-requested:@me".to_owned()),
+        owner: owner.map(str::to_owned),
+        repo: repo.map(str::to_owned),
+        ..Default::default()
+    };
+
+    let params = config.search_params();
+
+    assert_eq!(config.operation_mode(), OperationMode::SearchPullRequests);
+    assert_eq!(params.scope, scope);
+    assert_eq!(params.query, "review-requested:@me");
+}
+
+#[rstest]
+#[case::unset(None, "github.com")]
+#[case::blank(Some(" "), "github.com")]
+#[case::enterprise(Some(" ghe.example.com "), "ghe.example.com")]
+fn search_host_defaults_to_github_com(#[case] search_host: Option<&str>, #[case] expected: &str) {
+    let config = FrankieConfig {
+        search_host: search_host.map(str::to_owned),
+        ..Default::default()
+    };
+
+    assert_eq!(config.search_host(), expected);
+}
+
+#[rstest]
+fn dashboard_flag_selects_dashboard_mode_with_trimmed_repositories() {
+    let config = FrankieConfig {
+        dashboard: true,
+        dashboard_repos: vec![" octo/app ".to_owned(), "octo/lib".to_owned()],
+        search: Some("label:urgent".to_owned()),
+        ..Default::default()
+    };
+
+    assert_eq!(config.operation_mode(), OperationMode::Dashboard);
+    assert_eq!(
+        config.review_queue_request(),
+        ReviewQueueRequest {
+            repositories: vec!["octo/app".to_owned(), "octo/lib".to_owned()],
+            search: Some("label:urgent".to_owned()),
+        }
+    );
+}
@@ -0,0 +1,305 @@
+//! Tests for token discovery from the `gh` CLI and git credential helpers.
+
+use std::ffi::OsString;
+use std::path::Path;
+
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::FrankieConfig;
+use crate::config::token_discovery::{password_from_credential_output, token_from_gh_hosts};
+use crate::config::{ResolvedToken, TokenSource, passes_token_flag};
+use crate::github::error::IntakeError;
+
+const GH_HOSTS: &str = "\
+github.com:
+    user: octocat
+    git_protocol: https
+    users:
+        octocat:
+            oauth_token: gho_multi_account
+ghe.example.com:
+    oauth_token: gho_enterprise
+    user: octocat
+";
+
+const CREDENTIAL_HELPER: &str = "!f() { echo username=x-access-token; echo password=ghp_git; }; f";
+
+fn gh_config_dir() -> TempDir {
+    let dir = TempDir::new().expect("temp dir should be created");
+    std::fs::write(dir.path().join("hosts.yml"), GH_HOSTS).expect("hosts.yml should be written");
+    dir
+}
+
+/// Locks the environment with `gh_dir` as the `gh` configuration and, when
+/// `helper` is set, a git credential helper configured through the
+/// environment.
+fn lock_discovery_env<'a>(gh_dir: &'a Path, helper: Option<&'a str>) -> env_lock::EnvGuard<'a> {
+    let gh_dir_str = gh_dir.to_str().expect("temp dir should be UTF-8");
+    env_lock::lock_env([
+        ("GITHUB_TOKEN", None),
+        ("GH_CONFIG_DIR", Some(gh_dir_str)),
+        ("GIT_CONFIG_GLOBAL", Some("/dev/null")),
+        ("GIT_CONFIG_NOSYSTEM", Some("1")),
+        ("GIT_CONFIG_COUNT", helper.map(|_| "1")),
+        ("GIT_CONFIG_KEY_0", helper.map(|_| "credential.helper")),
+        ("GIT_CONFIG_VALUE_0", helper),
+    ])
+}
+
+#[rstest]
+#[case::active_user_entry("github.com", Some("gho_multi_account"))]
+#[case::host_level_token("ghe.example.com", Some("gho_enterprise"))]
+#[case::case_insensitive_with_port("GHE.Example.com:8443", Some("gho_enterprise"))]
+#[case::unlisted_host("other.example.com", None)]
+fn token_from_gh_hosts_matches_the_host(#[case] host: &str, #[case] expected: Option<&str>) {
+    assert_eq!(token_from_gh_hosts(GH_HOSTS, host).as_deref(), expected);
+}
+
+#[rstest]
+#[case::malformed_yaml("github.com: [unclosed")]
+#[case::blank_token("github.com:\n    oauth_token: '  '\n")]
+#[case::not_a_mapping("- github.com\n")]
+fn token_from_gh_hosts_ignores_unusable_documents(#[case] contents: &str) {
+    assert_eq!(token_from_gh_hosts(contents, "github.com"), None);
+}
+
+#[rstest]
+#[case::password("protocol=https\nhost=github.com\npassword=ghp_git\n", Some("ghp_git"))]
+#[case::empty_password("password=\n", None)]
+#[case::no_password("protocol=https\nhost=github.com\n", None)]
+fn password_from_credential_output_reads_the_password(
+    #[case] output: &str,
+    #[case] expected: Option<&str>,
+) {
+    assert_eq!(password_from_credential_output(output).as_deref(), expected);
+}
+
+#[rstest]
+fn resolve_token_source_reads_the_gh_cli_configuration() {
+    let gh_dir = gh_config_dir();
+    let _guard = lock_discovery_env(gh_dir.path(), None);
+
+    let resolved = FrankieConfig::default()
+        .resolve_token_source("ghe.example.com")
+        .expect("gh token should be discovered");
+
+    assert_eq!(resolved.value(), "gho_enterprise");
+    assert_eq!(
+        resolved.source(),
+        &TokenSource::GhCli {
+            path: gh_dir.path().join("hosts.yml"),
+        }
+    );
+}
+
+#[rstest]
+fn resolve_token_source_falls_back_to_git_credential_helpers() {
+    let gh_dir = TempDir::new().expect("temp dir should be created");
+    let _guard = lock_discovery_env(gh_dir.path(), Some(CREDENTIAL_HELPER));
+
+    let resolved = FrankieConfig::default()
+        .resolve_token_source("ghe.example.com")
+        .expect("git credential should be discovered");
+
+    assert_eq!(resolved.value(), "ghp_git");
+    assert_eq!(resolved.source(), &TokenSource::GitCredential);
+}
+
+#[rstest]
+fn resolve_token_source_prefers_gh_over_git_credentials() {
+    let gh_dir = gh_config_dir();
+    let _guard = lock_discovery_env(gh_dir.path(), Some(CREDENTIAL_HELPER));
+
+    let resolved = FrankieConfig::default().resolve_token_source("github.com");
+
+    assert_eq!(
+        resolved.ok().map(ResolvedToken::into_value).as_deref(),
+        Some("gho_multi_account")
+    );
+}
+
+#[rstest]
+#[case::host_entry(
+    "ghe.example.com",
+    (Some("ghe.example.com=ghp_host"), None),
+    "ghp_host",
+    TokenSource::HostTokens
+)]
+#[case::configured(
+    "github.com",
+    (None, Some("ghp_configured")),
+    "ghp_configured",
+    TokenSource::Configured
+)]
+fn resolve_token_source_prefers_configured_tokens(
+    #[case] host: &str,
+    #[case] (host_token, token): (Option<&str>, Option<&str>),
+    #[case] expected: &str,
+    #[case] expected_source: TokenSource,
+) {
+    let gh_dir = gh_config_dir();
+    let _guard = lock_discovery_env(gh_dir.path(), Some(CREDENTIAL_HELPER));
+    let config = FrankieConfig {
+        token: token.map(str::to_owned),
+        host_tokens: host_token.map(str::to_owned).into_iter().collect(),
+        ..Default::default()
+    };
+
+    let resolved = config
+        .resolve_token_source(host)
+        .expect("configured token should resolve");
+
+    assert_eq!(resolved.value(), expected);
+    assert_eq!(resolved.source(), &expected_source);
+}
+
+#[rstest]
+fn enterprise_hosts_prefer_host_specific_tokens_over_github_token_env() {
+    let gh_dir = gh_config_dir();
+    let _guard = env_lock::lock_env([
+        ("GITHUB_TOKEN", Some("ghp_env")),
+        ("GH_CONFIG_DIR", gh_dir.path().to_str()),
+    ]);
+
+    let resolved = FrankieConfig::default()
+        .resolve_token_source("ghe.example.com")
+        .expect("gh token should be discovered");
+
+    assert_eq!(resolved.value(), "gho_enterprise");
+}
+
+#[rstest]
+fn enterprise_hosts_prefer_host_specific_tokens_over_configured_ones() {
+    let gh_dir = gh_config_dir();
+    let _guard = lock_discovery_env(gh_dir.path(), Some(CREDENTIAL_HELPER));
+    let config = FrankieConfig {
+        token: Some("ghp_configured".to_owned()),
+        ..Default::default()
+    };
+
+    let resolved = config
+        .resolve_token_source("ghe.example.com")
+        .expect("gh token should be discovered");
+
+    assert_eq!(resolved.value(), "gho_enterprise");
+}
+
+#[rstest]
+fn enterprise_hosts_fall_back_to_configured_tokens() {
+    let gh_dir = TempDir::new().expect("temp dir should be created");
+    let _guard = lock_discovery_env(gh_dir.path(), None);
+    let config = FrankieConfig {
+        token: Some("ghp_configured".to_owned()),
+        ..Default::default()
+    };
+
+    let resolved = config
+        .resolve_token_source("ghe.example.com")
+        .expect("configured token should resolve");
+
+    assert_eq!(resolved.value(), "ghp_configured");
+    assert_eq!(resolved.source(), &TokenSource::Configured);
+}
+
+#[rstest]
+#[case::separate_value(&["--token", "ghp_cli"])]
+#[case::inline_value(&["--token=ghp_cli"])]
+#[case::short_flag(&["-t", "ghp_cli"])]
+#[case::attached_short_value(&["-tghp_cli"])]
+fn command_line_token_wins_for_enterprise_hosts(#[case] cli_args: &[&str]) {
+    let gh_dir = gh_config_dir();
+    let home = TempDir::new().expect("temp dir should be created");
+    let home_str = home.path().to_str().expect("temp dir should be UTF-8");
+    let _guard = env_lock::lock_env([
+        ("HOME", Some(home_str)),
+        ("XDG_CONFIG_HOME", Some(home_str)),
+        ("FRANKIE_TOKEN", Some("ghp_frankie_env")),
+        ("GITHUB_TOKEN", Some("ghp_env")),
+        ("GH_CONFIG_DIR", gh_dir.path().to_str()),
+        ("GIT_CONFIG_GLOBAL", Some("/dev/null")),
+        ("GIT_CONFIG_NOSYSTEM", Some("1")),
+        ("GIT_CONFIG_COUNT", Some("1")),
+        ("GIT_CONFIG_KEY_0", Some("credential.helper")),
+        ("GIT_CONFIG_VALUE_0", Some(CREDENTIAL_HELPER)),
+    ]);
+    let args = std::iter::once("frankie").chain(cli_args.iter().copied());
+    let config = FrankieConfig::load_from_iter(args).expect("config should load");
+
+    let resolved = config
+        .resolve_token_source("ghe.example.com")
+        .expect("command-line token should resolve");
+
+    assert_eq!(resolved.value(), "ghp_cli");
+    assert_eq!(resolved.source(), &TokenSource::CommandLine);
+}
+
+#[rstest]
+#[case::separate_value(&["--token", "ghp_cli"], true)]
+#[case::inline_value(&["--token=ghp_cli"], true)]
+#[case::short_flag(&["-t", "ghp_cli"], true)]
+#[case::attached_short_value(&["-tghp_cli"], true)]
+#[case::after_boolean_short_flags(&["-Tt", "ghp_cli"], true)]
+#[case::value_of_another_flag(&["--reply-text", "-t"], false)]
+#[case::attached_value_of_another_flag(&["-y-t"], false)]
+#[case::short_value_of_another_flag(&["-y", "--token"], false)]
+#[case::similar_long_flag(&["--tokens"], false)]
+#[case::after_separator(&["--", "-t"], false)]
+#[case::absent(&["--tui"], false)]
+fn passes_token_flag_matches_only_the_token_flag(
+    #[case] cli_args: &[&str],
+    #[case] expected: bool,
+) {
+    let args = std::iter::once("frankie")
+        .chain(cli_args.iter().copied())
+        .map(OsString::from)
+        .collect::<Vec<_>>();
+
+    assert_eq!(passes_token_flag(&args), expected);
+}
+
+#[rstest]
+fn resolve_token_source_prefers_github_token_env_over_discovery() {
+    let gh_dir = gh_config_dir();
+    let _guard = env_lock::lock_env([
+        ("GITHUB_TOKEN", Some("ghp_env")),
+        ("GH_CONFIG_DIR", gh_dir.path().to_str()),
+    ]);
+
+    let resolved = FrankieConfig::default()
+        .resolve_token_source("github.com")
+        .expect("GITHUB_TOKEN should resolve");
+
+    assert_eq!(resolved.value(), "ghp_env");
+    assert_eq!(resolved.source(), &TokenSource::GitHubTokenEnv);
+}
+
+#[rstest]
+fn resolve_token_source_reports_missing_token_when_nothing_is_found() {
+    let gh_dir = TempDir::new().expect("temp dir should be created");
+    let _guard = lock_discovery_env(gh_dir.path(), None);
+
+    assert!(matches!(
+        FrankieConfig::default().resolve_token_source("github.com"),
+        Err(IntakeError::MissingToken)
+    ));
+}
+
+#[rstest]
+fn resolved_token_debug_and_source_never_show_the_token() {
+    let gh_dir = gh_config_dir();
+    let _guard = lock_discovery_env(gh_dir.path(), None);
+
+    let resolved = FrankieConfig::default()
+        .resolve_token_source("github.com")
+        .expect("gh token should be discovered");
+
+    let debug = format!("{resolved:?}");
+    let source = resolved.source().to_string();
+    assert!(!debug.contains("gho_multi_account"), "debug: {debug}");
+    assert!(debug.contains("<redacted>"), "debug: {debug}");
+    assert!(
+        source.starts_with("the gh CLI configuration ("),
+        "source: {source}"
+    );
+}
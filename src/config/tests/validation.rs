@@ -133,3 +133,421 @@ fn rejects_incompatible_summary_mode_configuration(
         other => Err(format!("expected Configuration error, got {other:?}").into()),
     }
 }
+
+#[rstest]
+#[case(
+    FrankieConfig {
+        reply_to: Some(7),
+        ..Default::default()
+    },
+    "must be provided together"
+)]
+#[case(
+    FrankieConfig {
+        reply_text: Some("Thanks".to_owned()),
+        ..Default::default()
+    },
+    "must be provided together"
+)]
+#[case(
+    FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("   ".to_owned()),
+        ..Default::default()
+    },
+    "must not be empty"
+)]
+#[case(
+    FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("abcdef".to_owned()),
+        reply_max_length: 5,
+        ..Default::default()
+    },
+    "exceeding the configured limit of 5"
+)]
+#[case(
+    FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("Thanks".to_owned()),
+        export: Some("markdown".to_owned()),
+        ..Default::default()
+    },
+    "--export"
+)]
+#[case(
+    FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("Thanks".to_owned()),
+        tui: true,
+        ..Default::default()
+    },
+    "--tui"
+)]
+fn rejects_invalid_post_reply_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let result = config.validate();
+
+    match result {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+fn accepts_complete_post_reply_configuration() {
+    let config = FrankieConfig {
+        reply_to: Some(7),
+        reply_text: Some("Thanks, fixed.".to_owned()),
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_ok());
+}
+
+#[rstest]
+#[case(
+    FrankieConfig {
+        resolve_threads: Some("12,abc".to_owned()),
+        ..Default::default()
+    },
+    "expects comment IDs, got 'abc'"
+)]
+#[case(
+    FrankieConfig {
+        unresolve_threads: Some(" , ".to_owned()),
+        ..Default::default()
+    },
+    "requires at least one comment ID"
+)]
+#[case(
+    FrankieConfig {
+        resolve_threads: Some("12, 13".to_owned()),
+        unresolve_threads: Some("13".to_owned()),
+        ..Default::default()
+    },
+    "comment 13 appears in both"
+)]
+#[case(
+    FrankieConfig {
+        resolve_threads: Some("12".to_owned()),
+        export: Some("markdown".to_owned()),
+        ..Default::default()
+    },
+    "--export"
+)]
+#[case(
+    FrankieConfig {
+        resolve_threads: Some("12".to_owned()),
+        reply_to: Some(12),
+        reply_text: Some("Done".to_owned()),
+        ..Default::default()
+    },
+    "--reply-to"
+)]
+fn rejects_invalid_thread_resolution_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let result = config.validate();
+
+    match result {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+fn thread_resolution_targets_parse_both_lists() {
+    let config = FrankieConfig {
+        resolve_threads: Some("12, 13,".to_owned()),
+        unresolve_threads: Some("14".to_owned()),
+        ..Default::default()
+    };
+
+    assert!(config.validate().is_ok());
+    assert_eq!(
+        config
+            .thread_resolution_targets()
+            .expect("targets should parse"),
+        (vec![12, 13], vec![14])
+    );
+}
+
+#[rstest]
+#[case::tui(FrankieConfig { pr_identifier: Some("https://github.com/o/r/pull/1".to_owned()), ..Default::default() })]
+#[case::export(FrankieConfig { export: Some("jsonl".to_owned()), ..Default::default() })]
+#[case::summary(FrankieConfig { summarize_discussions: true, ..Default::default() })]
+#[case::dashboard(FrankieConfig { dashboard: true, ..Default::default() })]
+fn offline_mode_accepts_modes_that_read_stored_comments(#[case] config: FrankieConfig) {
+    let offline_config = FrankieConfig {
+        offline: true,
+        database_url: Some("frankie.sqlite".to_owned()),
+        ..config
+    };
+
+    assert!(offline_config.validate().is_ok());
+    assert!(offline_config.is_offline());
+}
+
+#[rstest]
+#[case(
+    FrankieConfig {
+        offline: true,
+        export: Some("jsonl".to_owned()),
+        ..Default::default()
+    },
+    "--database-url"
+)]
+#[case(
+    FrankieConfig {
+        offline: true,
+        database_url: Some("frankie.sqlite".to_owned()),
+        reply_to: Some(12),
+        reply_text: Some("Done".to_owned()),
+        ..Default::default()
+    },
+    "only supports"
+)]
+#[case(
+    FrankieConfig {
+        offline: true,
+        database_url: Some("frankie.sqlite".to_owned()),
+        owner: Some("o".to_owned()),
+        repo: Some("r".to_owned()),
+        ..Default::default()
+    },
+    "only supports"
+)]
+fn rejects_invalid_offline_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+#[case::list(FrankieConfig { outbox: true, ..Default::default() })]
+#[case::offline_list(FrankieConfig { outbox: true, offline: true, ..Default::default() })]
+#[case::retry(FrankieConfig { outbox_retry: Some("all".to_owned()), ..Default::default() })]
+fn outbox_mode_accepts_a_single_action(#[case] config: FrankieConfig) {
+    let with_database = FrankieConfig {
+        database_url: Some("frankie.sqlite".to_owned()),
+        ..config
+    };
+
+    assert!(with_database.validate().is_ok());
+}
+
+#[rstest]
+#[case(
+    FrankieConfig { outbox: true, ..Default::default() },
+    "--database-url"
+)]
+#[case(
+    FrankieConfig {
+        database_url: Some("frankie.sqlite".to_owned()),
+        outbox_retry: Some("w1".to_owned()),
+        outbox_discard: Some("w2".to_owned()),
+        ..Default::default()
+    },
+    "cannot be combined"
+)]
+#[case(
+    FrankieConfig {
+        database_url: Some("frankie.sqlite".to_owned()),
+        outbox_discard: Some("  ".to_owned()),
+        ..Default::default()
+    },
+    "require an intent ID"
+)]
+#[case(
+    FrankieConfig {
+        database_url: Some("frankie.sqlite".to_owned()),
+        offline: true,
+        outbox_retry: Some("all".to_owned()),
+        ..Default::default()
+    },
+    "--offline"
+)]
+#[case(
+    FrankieConfig {
+        database_url: Some("frankie.sqlite".to_owned()),
+        outbox: true,
+        export: Some("jsonl".to_owned()),
+        ..Default::default()
+    },
+    "--export"
+)]
+fn rejects_invalid_outbox_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+#[case(
+    FrankieConfig {
+        auth_status: true,
+        auth_login: Some("github.com".to_owned()),
+        ..Default::default()
+    },
+    "cannot be combined"
+)]
+#[case(
+    FrankieConfig { auth_logout: Some("  ".to_owned()), ..Default::default() },
+    "require a GitHub host"
+)]
+#[case(
+    FrankieConfig {
+        auth_login: Some("github.com".to_owned()),
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..Default::default()
+    },
+    "a pull request"
+)]
+#[case(
+    FrankieConfig {
+        auth_status: true,
+        database_url: Some("frankie.sqlite".to_owned()),
+        outbox: true,
+        ..Default::default()
+    },
+    "--outbox"
+)]
+fn rejects_invalid_auth_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+#[case(
+    FrankieConfig { search: Some("  ".to_owned()), ..Default::default() },
+    "requires search qualifiers"
+)]
+#[case(
+    FrankieConfig {
+        search: Some("is:draft".to_owned()),
+        repo: Some("app".to_owned()),
+        ..Default::default()
+    },
+    "also requires --owner"
+)]
+#[case(
+    FrankieConfig {
+        search: Some("is:draft".to_owned()),
+        pr_url: Some("https://github.com/owner/repo/pull/1".to_owned()),
+        ..Default::default()
+    },
+    "a pull request"
+)]
+#[case(
+    FrankieConfig {
+        search: Some("is:draft".to_owned()),
+        export: Some("jsonl".to_owned()),
+        ..Default::default()
+    },
+    "--export"
+)]
+#[case(
+    FrankieConfig {
+        search: Some("is:draft".to_owned()),
+        search_host: Some("https://ghe.example.com".to_owned()),
+        ..Default::default()
+    },
+    "--search-host must be a host name"
+)]
+fn rejects_invalid_search_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
+
+#[rstest]
+#[case(
+    FrankieConfig {
+        dashboard: true,
+        dashboard_repos: vec!["octo".to_owned()],
+        ..Default::default()
+    },
+    "entries must be `owner/repo`, got `octo`"
+)]
+#[case(
+    FrankieConfig {
+        dashboard: true,
+        owner: Some("octo".to_owned()),
+        ..Default::default()
+    },
+    "--dashboard-repos"
+)]
+#[case(
+    FrankieConfig {
+        dashboard: true,
+        pr_identifier: Some("42".to_owned()),
+        ..Default::default()
+    },
+    "a pull request"
+)]
+fn rejects_invalid_dashboard_configuration(
+    #[case] config: FrankieConfig,
+    #[case] expected_fragment: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.validate() {
+        Err(IntakeError::Configuration { message }) => {
+            if !message.contains(expected_fragment) {
+                return Err(format!("expected '{message}' to mention {expected_fragment}").into());
+            }
+            Ok(())
+        }
+        other => Err(format!("expected Configuration error, got {other:?}").into()),
+    }
+}
@@ -0,0 +1,87 @@
+//! Thread-resolution mode helpers for configuration validation and parsing.
+
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+
+/// Returns whether non-interactive thread resolution mode is enabled.
+#[must_use]
+pub(crate) const fn is_thread_resolution_mode(config: &FrankieConfig) -> bool {
+    config.resolve_threads.is_some() || config.unresolve_threads.is_some()
+}
+
+/// Parses a comma-separated list of review comment IDs.
+///
+/// Whitespace around entries is ignored. Returns an empty list when `value`
+/// is `None`.
+pub(crate) fn parse_comment_ids(flag: &str, value: Option<&str>) -> Result<Vec<u64>, IntakeError> {
+    let Some(raw) = value else {
+        return Ok(Vec::new());
+    };
+
+    let ids = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .parse::<u64>()
+                .map_err(|_| IntakeError::Configuration {
+                    message: format!("{flag} expects comment IDs, got '{entry}'"),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if ids.is_empty() {
+        return Err(IntakeError::Configuration {
+            message: format!("{flag} requires at least one comment ID"),
+        });
+    }
+
+    Ok(ids)
+}
+
+pub(crate) fn validate_thread_resolution_mode(config: &FrankieConfig) -> Result<(), IntakeError> {
+    if !is_thread_resolution_mode(config) {
+        return Ok(());
+    }
+
+    validate_thread_resolution_compatibility(config)?;
+    let resolve = parse_comment_ids("--resolve-threads", config.resolve_threads.as_deref())?;
+    let unresolve = parse_comment_ids("--unresolve-threads", config.unresolve_threads.as_deref())?;
+
+    if let Some(conflict) = resolve.iter().find(|id| unresolve.contains(id)) {
+        return Err(IntakeError::Configuration {
+            message: format!(
+                "comment {conflict} appears in both --resolve-threads and --unresolve-threads"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_thread_resolution_compatibility(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let conflicting_flag = if config.verify_resolutions {
+        Some("--verify-resolutions")
+    } else if config.summarize_discussions {
+        Some("--summarize-discussions")
+    } else if config.reply_to.is_some() || config.reply_text.is_some() {
+        Some("--reply-to/--reply-text")
+    } else if config.should_ai_rewrite() {
+        Some("AI rewrite flags")
+    } else if config.export.is_some() {
+        Some("--export")
+    } else if config.tui {
+        Some("--tui")
+    } else {
+        None
+    };
+
+    conflicting_flag.map_or(Ok(()), |flag| {
+        Err(IntakeError::Configuration {
+            message: format!(
+                "--resolve-threads/--unresolve-threads cannot be combined with {flag}"
+            ),
+        })
+    })
+}
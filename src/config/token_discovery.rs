@@ -0,0 +1,206 @@
+//! Token discovery from the `gh` CLI configuration and git credential
+//! helpers.
+//!
+//! When no token is configured for Frankie itself, the token resolution
+//! chain falls back to credentials the developer already has: the
+//! `oauth_token` stored for the host in the `gh` CLI's `hosts.yml`, then
+//! whatever `git credential fill` returns for the host. Discovered tokens are
+//! tagged with their [`TokenSource`] so callers can say where a token came
+//! from without printing it.
+
+use std::env;
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use yaml_rust2::YamlLoader;
+
+use super::host_tokens::strip_port;
+
+/// Where a resolved token came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// A `host_tokens` entry for the host.
+    HostTokens,
+    /// The `--token` command-line flag.
+    CommandLine,
+    /// `FRANKIE_TOKEN` or `token` in a configuration file.
+    Configured,
+    /// The legacy `GITHUB_TOKEN` environment variable.
+    GitHubTokenEnv,
+    /// The encrypted credential file at `path`.
+    CredentialFile {
+        /// Path of the credential file that held the token.
+        path: PathBuf,
+    },
+    /// The `gh` CLI's `hosts.yml` at `path`.
+    GhCli {
+        /// Path of the `hosts.yml` file that held the token.
+        path: PathBuf,
+    },
+    /// The password returned by `git credential fill`.
+    GitCredential,
+}
+
+impl TokenSource {
+    /// Returns whether the token was discovered rather than configured for
+    /// Frankie.
+    #[must_use]
+    pub const fn is_discovered(&self) -> bool {
+        matches!(self, Self::GhCli { .. } | Self::GitCredential)
+    }
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HostTokens => formatter.write_str("a --host-tokens entry"),
+            Self::CommandLine => formatter.write_str("--token"),
+            Self::Configured => formatter.write_str("FRANKIE_TOKEN or the configuration file"),
+            Self::GitHubTokenEnv => formatter.write_str("the GITHUB_TOKEN environment variable"),
+            Self::CredentialFile { path } => {
+                write!(formatter, "the credential file ({})", path.display())
+            }
+            Self::GhCli { path } => {
+                write!(formatter, "the gh CLI configuration ({})", path.display())
+            }
+            Self::GitCredential => formatter.write_str("git credential fill"),
+        }
+    }
+}
+
+/// A token together with the source that supplied it.
+///
+/// The `Debug` output redacts the token value.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResolvedToken {
+    value: String,
+    source: TokenSource,
+}
+
+impl ResolvedToken {
+    pub(crate) const fn new(value: String, source: TokenSource) -> Self {
+        Self { value, source }
+    }
+
+    /// Borrow the token value.
+    #[must_use]
+    pub const fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Returns the source that supplied the token.
+    #[must_use]
+    pub const fn source(&self) -> &TokenSource {
+        &self.source
+    }
+
+    /// Consumes the resolved token and returns its value.
+    #[must_use]
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+impl fmt::Debug for ResolvedToken {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ResolvedToken")
+            .field("value", &"<redacted>")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// Looks up the token the `gh` CLI stores for `host`.
+pub(crate) fn gh_cli_token(host: &str) -> Option<ResolvedToken> {
+    let path = gh_hosts_path()?;
+    let contents = std::fs::read_to_string(&path).ok()?;
+    let token = token_from_gh_hosts(&contents, host)?;
+    Some(ResolvedToken::new(token, TokenSource::GhCli { path }))
+}
+
+/// Returns the location of the `gh` CLI's `hosts.yml`.
+///
+/// Follows `gh`'s own lookup: `GH_CONFIG_DIR`, then `XDG_CONFIG_HOME/gh`,
+/// then `%AppData%/GitHub CLI` on Windows, then `~/.config/gh`.
+fn gh_hosts_path() -> Option<PathBuf> {
+    let config_dir = non_empty_var("GH_CONFIG_DIR")
+        .map(PathBuf::from)
+        .or_else(|| non_empty_var("XDG_CONFIG_HOME").map(|dir| Path::new(&dir).join("gh")))
+        .or_else(|| {
+            cfg!(windows)
+                .then(|| non_empty_var("AppData"))
+                .flatten()
+                .map(|dir| Path::new(&dir).join("GitHub CLI"))
+        })
+        .or_else(|| non_empty_var("HOME").map(|home| Path::new(&home).join(".config/gh")))?;
+    Some(config_dir.join("hosts.yml"))
+}
+
+fn non_empty_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.is_empty())
+}
+
+/// Extracts the `oauth_token` for `host` from a `hosts.yml` document.
+///
+/// The host-level `oauth_token` wins; otherwise the active user's entry under
+/// `users` is used. Hosts are matched case-insensitively and without a port.
+pub(super) fn token_from_gh_hosts(contents: &str, host: &str) -> Option<String> {
+    let documents = YamlLoader::load_from_str(contents).ok()?;
+    let hosts = documents.first()?.as_hash()?;
+    let entry = hosts.iter().find_map(|(key, value)| {
+        key.as_str()
+            .filter(|name| strip_port(name).eq_ignore_ascii_case(strip_port(host)))
+            .map(|_| value)
+    })?;
+
+    let active_user_token = || {
+        let user = entry["user"].as_str()?;
+        entry["users"][user]["oauth_token"].as_str()
+    };
+    entry["oauth_token"]
+        .as_str()
+        .or_else(active_user_token)
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_owned)
+}
+
+/// Asks git's credential helpers for a password for `https://{host}`.
+///
+/// Terminal prompts are disabled, so hosts without a stored credential
+/// return `None` instead of blocking.
+pub(crate) fn git_credential_token(host: &str) -> Option<ResolvedToken> {
+    let mut child = Command::new("git")
+        .args(["credential", "fill"])
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env("GCM_INTERACTIVE", "never")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let request = format!("protocol=https\nhost={host}\n\n");
+    let wrote = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(request.as_bytes()).is_ok());
+    let output = child.wait_with_output().ok()?;
+    if !wrote || !output.status.success() {
+        return None;
+    }
+
+    let password = password_from_credential_output(&String::from_utf8_lossy(&output.stdout))?;
+    Some(ResolvedToken::new(password, TokenSource::GitCredential))
+}
+
+/// Reads the `password=` attribute from `git credential fill` output.
+pub(super) fn password_from_credential_output(output: &str) -> Option<String> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .filter(|password| !password.is_empty())
+        .map(str::to_owned)
+}
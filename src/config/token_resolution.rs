@@ -0,0 +1,230 @@
+//! Token and secret resolution for GitHub hosts and the AI service.
+
+use std::env;
+use std::path::PathBuf;
+
+use super::{ResolvedToken, TokenSource, app_auth, credential_store, host_tokens, token_discovery};
+use crate::config::{FrankieConfig, default_credential_file};
+use crate::github::credentials::{GitHubAppInstallation, GitHubCredentials};
+use crate::github::error::IntakeError;
+use crate::github::locator::PersonalAccessToken;
+use crate::redaction::register_secret;
+
+impl FrankieConfig {
+    /// Resolves the token for `github.com`.
+    ///
+    /// Equivalent to [`Self::resolve_token_for_host`] with `github.com`, so
+    /// the configured token, the legacy `GITHUB_TOKEN` environment variable,
+    /// the `gh` CLI configuration, and git credential helpers are tried in
+    /// turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::MissingToken`] when no token source provides a
+    /// value.
+    pub fn resolve_token(&self) -> Result<String, IntakeError> {
+        self.resolve_token_for_host("github.com")
+    }
+
+    /// Resolves the token for a GitHub host.
+    ///
+    /// Returns the token value from [`Self::resolve_token_source`]. `host` is
+    /// the web host of the pull request or repository, such as `github.com`
+    /// or `ghe.example.com`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::MissingToken`] when no token source provides a
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::FrankieConfig;
+    ///
+    /// let config = FrankieConfig {
+    ///     token: Some("ghp_public".to_owned()),
+    ///     host_tokens: vec!["ghe.example.com=ghp_enterprise".to_owned()],
+    ///     ..FrankieConfig::default()
+    /// };
+    /// assert_eq!(
+    ///     config.resolve_token_for_host("GHE.example.com").ok().as_deref(),
+    ///     Some("ghp_enterprise")
+    /// );
+    /// assert_eq!(
+    ///     config.resolve_token_for_host("github.com").ok().as_deref(),
+    ///     Some("ghp_public")
+    /// );
+    /// ```
+    pub fn resolve_token_for_host(&self, host: &str) -> Result<String, IntakeError> {
+        self.resolve_token_source(host)
+            .map(ResolvedToken::into_value)
+    }
+
+    /// Resolves the token for a GitHub host along with the source that
+    /// supplied it.
+    ///
+    /// Sources are tried in order:
+    ///
+    /// 1. `--token` on the command line;
+    /// 2. a matching `host_tokens` entry;
+    /// 3. `FRANKIE_TOKEN` or `token` in a configuration file;
+    /// 4. the legacy `GITHUB_TOKEN` environment variable;
+    /// 5. the token for `host` in the encrypted credential file, when
+    ///    `credential_passphrase` is set;
+    /// 6. the `oauth_token` for `host` in the `gh` CLI's `hosts.yml`;
+    /// 7. the password `git credential fill` returns for `https://{host}`.
+    ///
+    /// Hosts other than `github.com` try the host-specific sources (5, 6,
+    /// and 7) before `FRANKIE_TOKEN`, the configuration file `token`, and
+    /// `GITHUB_TOKEN`, which usually hold a github.com token that a GitHub
+    /// Enterprise server would reject.
+    ///
+    /// The returned [`ResolvedToken`] redacts its value in `Debug` output, so
+    /// callers can report the [`TokenSource`] without exposing the token, and
+    /// the value is registered with [`register_secret`] so logs and
+    /// transcripts mask it too.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::MissingToken`] when no token source provides a
+    /// value, and [`IntakeError::Configuration`] when the credential file
+    /// cannot be decrypted.
+    pub fn resolve_token_source(&self, host: &str) -> Result<ResolvedToken, IntakeError> {
+        self.find_token_source(host)
+            .inspect(|token| register_secret(token.value()))
+    }
+
+    /// Finds the token for `host` in the order described by
+    /// [`Self::resolve_token_source`].
+    fn find_token_source(&self, host: &str) -> Result<ResolvedToken, IntakeError> {
+        if let Some(token) = self.token.as_ref().filter(|_| self.token_from_command_line) {
+            return Ok(ResolvedToken::new(token.clone(), TokenSource::CommandLine));
+        }
+        if let Some(host_token) = host_tokens::token_for_host(self, host) {
+            return Ok(ResolvedToken::new(
+                host_token.to_owned(),
+                TokenSource::HostTokens,
+            ));
+        }
+        if host_tokens::is_github_com(host) {
+            if let Some(token) = self.host_agnostic_token() {
+                return Ok(token);
+            }
+            return self
+                .host_specific_token(host)?
+                .ok_or(IntakeError::MissingToken);
+        }
+        self.host_specific_token(host)?
+            .or_else(|| self.host_agnostic_token())
+            .ok_or(IntakeError::MissingToken)
+    }
+
+    /// Returns the token from `FRANKIE_TOKEN`, the configuration file, or
+    /// `GITHUB_TOKEN`, which apply to every host.
+    fn host_agnostic_token(&self) -> Option<ResolvedToken> {
+        self.token
+            .clone()
+            .map(|token| ResolvedToken::new(token, TokenSource::Configured))
+            .or_else(|| {
+                env::var("GITHUB_TOKEN")
+                    .ok()
+                    .map(|token| ResolvedToken::new(token, TokenSource::GitHubTokenEnv))
+            })
+    }
+
+    /// Returns the token stored for `host` in the credential file, the `gh`
+    /// CLI configuration, or a git credential helper.
+    fn host_specific_token(&self, host: &str) -> Result<Option<ResolvedToken>, IntakeError> {
+        if let Some(stored) = credential_store::stored_token(self, host)? {
+            return Ok(Some(stored));
+        }
+        Ok(token_discovery::gh_cli_token(host)
+            .or_else(|| token_discovery::git_credential_token(host)))
+    }
+
+    /// Returns the path of the encrypted credential file.
+    ///
+    /// Uses `credential_file` when set, else [`default_credential_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when no path is configured and
+    /// neither `XDG_CONFIG_HOME` nor `HOME` is available.
+    pub fn credential_file_path(&self) -> Result<PathBuf, IntakeError> {
+        self.credential_file
+            .as_deref()
+            .map_or_else(default_credential_file, |path| Ok(PathBuf::from(path)))
+    }
+
+    /// Returns the configured GitHub App installation, if any.
+    ///
+    /// Installation tokens are requested from `api_base`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the GitHub App private key
+    /// cannot be read.
+    pub fn github_app_installation(
+        &self,
+        api_base: &str,
+    ) -> Result<Option<GitHubAppInstallation>, IntakeError> {
+        app_auth::app_installation(self, api_base)
+    }
+
+    /// Resolves the credentials for a GitHub host.
+    ///
+    /// A configured GitHub App installation is used for every host, with
+    /// installation tokens requested from `api_base`. Otherwise this resolves
+    /// a personal access token via [`Self::resolve_token_for_host`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::MissingToken`] when no credentials are
+    /// configured, and [`IntakeError::Configuration`] when the GitHub App
+    /// private key cannot be read.
+    pub fn resolve_credentials(
+        &self,
+        host: &str,
+        api_base: &str,
+    ) -> Result<GitHubCredentials, IntakeError> {
+        if let Some(installation) = self.github_app_installation(api_base)? {
+            return Ok(installation.into());
+        }
+        PersonalAccessToken::new(self.resolve_token_for_host(host)?).map(GitHubCredentials::from)
+    }
+
+    /// Resolves the AI API key from configuration, `OPENAI_API_KEY`, or the
+    /// credential file.
+    ///
+    /// The credential file is only read when `credential_passphrase` is set;
+    /// a file that cannot be decrypted is skipped with a warning. The key is
+    /// registered with [`register_secret`].
+    #[must_use]
+    pub fn resolve_ai_api_key(&self) -> Option<String> {
+        self.ai_api_key
+            .clone()
+            .or_else(|| env::var("OPENAI_API_KEY").ok())
+            .or_else(|| credential_store::stored_ai_api_key(self))
+            .inspect(|key| register_secret(key))
+    }
+
+    /// Registers every secret set in the configuration with the redactor.
+    ///
+    /// Covers the configured token, `host_tokens` values, the AI API key,
+    /// and the credential passphrase. Secrets discovered later (from the
+    /// `gh` CLI, git, or the credential file) are registered when they are
+    /// resolved.
+    pub fn register_secrets(&self) {
+        let configured = [
+            self.token.as_deref(),
+            self.ai_api_key.as_deref(),
+            self.credential_passphrase.as_deref(),
+        ];
+        configured
+            .into_iter()
+            .flatten()
+            .chain(host_tokens::configured_tokens(self))
+            .for_each(register_secret);
+    }
+}
@@ -0,0 +1,124 @@
+//! Validation of the loaded configuration.
+
+use super::{
+    app_auth, auth_mode, dashboard_mode, host_tokens, offline_mode, outbox_mode, reply_mode,
+    search_mode, summarize_mode, thread_resolution_mode,
+};
+use crate::config::FrankieConfig;
+use crate::github::error::IntakeError;
+
+impl FrankieConfig {
+    /// Validates that the configuration is internally consistent.
+    ///
+    /// Checks that:
+    /// - Positional PR identifier and `--pr-url` are not both provided
+    /// - Every `--host-tokens` entry is a `host=token` pair
+    /// - GitHub App settings are given together, or not at all
+    /// - AI rewrite mode and text are both present when either is specified
+    /// - Verify resolutions mode has compatible configuration
+    /// - Summary mode has compatible configuration
+    /// - Reply mode has both a target and text, and compatible configuration
+    /// - Thread resolution targets are valid comment IDs
+    /// - Offline mode has a database and a mode that can run offline
+    /// - Outbox mode has a database and a single, non-conflicting action
+    /// - Auth mode has a single action with a target and no conflicting flags
+    /// - Dashboard repositories are `owner/repo` names and dashboard mode
+    ///   has no conflicting flags
+    /// - Search mode has qualifiers, a usable scope, and no conflicting flags
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when:
+    /// - Both `pr_identifier` and `pr_url` are provided (mutually exclusive)
+    /// - A `--host-tokens` entry is not of the form `host=token`
+    /// - Only some of `--app-id`, `--app-installation-id`, and
+    ///   `--app-private-key-path` are provided
+    /// - AI rewrite mode is specified without text, or vice versa
+    /// - Verify resolutions mode is incompatible with current configuration
+    /// - Summary mode is incompatible with current configuration
+    /// - Reply mode is incomplete or incompatible with current configuration
+    /// - Thread resolution targets are malformed, overlap, or conflict with
+    ///   other modes
+    /// - Offline mode lacks `--database-url` or is used with a mode that
+    ///   needs GitHub
+    /// - Outbox mode lacks `--database-url`, combines retry and discard, or
+    ///   conflicts with other modes
+    /// - Auth mode combines actions, lacks a target, or conflicts with other
+    ///   modes
+    /// - A `--dashboard-repos` entry is not of the form `owner/repo`, or
+    ///   dashboard mode conflicts with other modes
+    /// - Search mode has blank qualifiers, `--repo` without `--owner`, or
+    ///   conflicts with other modes
+    pub fn validate(&self) -> Result<(), IntakeError> {
+        self.validate_pr_identifier_exclusivity()?;
+        host_tokens::validate_host_tokens(self)?;
+        app_auth::validate_app_auth(self)?;
+        self.validate_ai_rewrite_completeness()?;
+        self.validate_verify_resolutions_compatibility()?;
+        self.validate_summary_mode_compatibility()?;
+        reply_mode::validate_post_reply_mode(self)?;
+        thread_resolution_mode::validate_thread_resolution_mode(self)?;
+        offline_mode::validate_offline_mode(self)?;
+        outbox_mode::validate_outbox_mode(self)?;
+        auth_mode::validate_auth_mode(self)?;
+        dashboard_mode::validate_dashboard_mode(self)?;
+        search_mode::validate_search_mode(self)?;
+        Ok(())
+    }
+
+    fn validate_pr_identifier_exclusivity(&self) -> Result<(), IntakeError> {
+        if self.has_pr_identifier() && self.has_pr_url() {
+            return Err(IntakeError::Configuration {
+                message: concat!(
+                    "positional PR identifier and --pr-url are mutually ",
+                    "exclusive; provide one or the other"
+                )
+                .to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_summary_mode_compatibility(&self) -> Result<(), IntakeError> {
+        summarize_mode::validate_summary_mode_compatibility(self)
+    }
+
+    fn validate_ai_rewrite_completeness(&self) -> Result<(), IntakeError> {
+        let mode_present = self.rewrite_mode_present();
+        let text_present = self.rewrite_text_present();
+        let mode_without_text = mode_present && !text_present;
+        let text_without_mode = !mode_present && text_present;
+        if mode_without_text || text_without_mode {
+            return Err(IntakeError::Configuration {
+                message: concat!(
+                    "--ai-rewrite-mode and --ai-rewrite-text must be provided ",
+                    "together"
+                )
+                .to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_verify_resolutions_compatibility(&self) -> Result<(), IntakeError> {
+        if self.verify_resolutions && self.should_ai_rewrite() {
+            return Err(IntakeError::Configuration {
+                message: concat!(
+                    "--verify-resolutions cannot be combined with AI rewrite ",
+                    "flags; remove --ai-rewrite-mode/--ai-rewrite-text"
+                )
+                .to_owned(),
+            });
+        }
+
+        if self.verify_resolutions && self.should_export_comments() {
+            return Err(IntakeError::Configuration {
+                message: "--verify-resolutions cannot be combined with --export".to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+}
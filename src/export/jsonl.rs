@@ -8,6 +8,8 @@ use std::io::Write;
 use crate::github::IntakeError;
 
 use super::model::ExportedComment;
+#[cfg(test)]
+use super::model::ExportedCommentKind;
 
 /// Writes comments in JSONL format to the given writer.
 ///
@@ -82,6 +84,7 @@ impl CommentBuilder {
     fn build(self) -> ExportedComment {
         ExportedComment {
             id: self.id,
+            kind: ExportedCommentKind::ReviewComment,
             author: self.author,
             file_path: self.file_path,
             line_number: self.line_number,
@@ -91,6 +94,10 @@ impl CommentBuilder {
             commit_sha: None,
             in_reply_to_id: None,
             created_at: None,
+            is_resolved: None,
+            is_outdated: None,
+            resolved_by: None,
+            review_state: None,
         }
     }
 }
@@ -139,6 +146,7 @@ mod tests {
         let parsed: serde_json::Value =
             serde_json::from_str(first_line).expect("should be valid JSON");
         assert_json_field_eq(&parsed, "id", 123_u64);
+        assert_json_field_eq(&parsed, "kind", "review_comment");
         assert_json_field_eq(&parsed, "author", "alice");
         assert_json_field_eq(&parsed, "file_path", "src/lib.rs");
         assert_json_field_eq(&parsed, "line_number", 42_u64);
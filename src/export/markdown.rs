@@ -9,7 +9,7 @@ use camino::Utf8Path;
 
 use crate::github::IntakeError;
 
-use super::model::ExportedComment;
+use super::model::{ExportedComment, ExportedCommentKind};
 
 /// Writes comments in Markdown format to the given writer.
 ///
@@ -72,33 +72,67 @@ fn write_comment_section<W: Write>(
     Ok(())
 }
 
-/// Writes the file location heading.
+/// Writes the file location heading, or the entry kind for comments that
+/// are not attached to the diff.
 fn write_location_heading<W: Write>(
     writer: &mut W,
     comment: &ExportedComment,
 ) -> Result<(), IntakeError> {
-    let location = match (&comment.file_path, comment.line_number) {
-        (Some(path), Some(line)) => format!("{path}:{line}"),
-        (Some(path), None) => path.clone(),
-        (None, Some(line)) => format!("(unknown file):{line}"),
-        (None, None) => "(unknown location)".to_owned(),
+    let location = match comment.kind {
+        ExportedCommentKind::ReviewComment => diff_location(comment),
+        ExportedCommentKind::IssueComment => "Conversation comment".to_owned(),
+        ExportedCommentKind::Review => comment
+            .review_state
+            .as_deref()
+            .map_or_else(|| "Review".to_owned(), |state| format!("Review ({state})")),
     };
     writeln!(writer, "## {location}").map_err(|e| io_error(&e))?;
     writeln!(writer).map_err(|e| io_error(&e))?;
     Ok(())
 }
 
-/// Writes comment metadata (author and timestamp).
+/// Formats a review comment's `path:line` location.
+fn diff_location(comment: &ExportedComment) -> String {
+    match (&comment.file_path, comment.line_number) {
+        (Some(path), Some(line)) => format!("{path}:{line}"),
+        (Some(path), None) => path.clone(),
+        (None, Some(line)) => format!("(unknown file):{line}"),
+        (None, None) => "(unknown location)".to_owned(),
+    }
+}
+
+/// Writes comment metadata (author, timestamp, and thread status).
 fn write_metadata<W: Write>(writer: &mut W, comment: &ExportedComment) -> Result<(), IntakeError> {
     if let Some(author) = &comment.author {
-        writeln!(writer, "**Reviewer:** {author}").map_err(|e| io_error(&e))?;
+        let label = match comment.kind {
+            ExportedCommentKind::IssueComment => "Author",
+            ExportedCommentKind::ReviewComment | ExportedCommentKind::Review => "Reviewer",
+        };
+        writeln!(writer, "**{label}:** {author}").map_err(|e| io_error(&e))?;
     }
     if let Some(created_at) = &comment.created_at {
         writeln!(writer, "**Created:** {created_at}").map_err(|e| io_error(&e))?;
     }
+    if let Some(status) = thread_status_label(comment) {
+        writeln!(writer, "**Status:** {status}").map_err(|e| io_error(&e))?;
+    }
     Ok(())
 }
 
+/// Formats the thread resolution status, if known.
+fn thread_status_label(comment: &ExportedComment) -> Option<String> {
+    let is_resolved = comment.is_resolved?;
+    let mut label = match (is_resolved, comment.resolved_by.as_deref()) {
+        (true, Some(resolver)) => format!("Resolved by {resolver}"),
+        (true, None) => "Resolved".to_owned(),
+        (false, _) => "Unresolved".to_owned(),
+    };
+    if comment.is_outdated == Some(true) {
+        label.push_str(" (outdated)");
+    }
+    Some(label)
+}
+
 /// Writes a fenced code block with language hint from file extension.
 ///
 /// Uses a fence length that exceeds any backtick runs in the diff hunk to
@@ -6,6 +6,7 @@
 use rstest::rstest;
 
 use super::*;
+use crate::export::ExportedCommentKind;
 use crate::export::test_helpers::{
     CommentBuilder, PrUrl, assert_contains, assert_not_contains, test_data,
 };
@@ -75,6 +76,29 @@ fn writes_comment_with_all_fields() -> TestResult {
     Ok(())
 }
 
+#[rstest]
+#[case::resolved(CommentBuilder::new(1).resolved_by("carol"), "**Status:** Resolved by carol")]
+#[case::unresolved_outdated(
+    CommentBuilder::new(1).thread_state(false, true),
+    "**Status:** Unresolved (outdated)"
+)]
+fn writes_thread_status_when_known(
+    #[case] builder: CommentBuilder,
+    #[case] expected: &str,
+) -> TestResult {
+    assert_single_comment_output_contains(builder.build(), expected)
+}
+
+#[rstest]
+fn omits_thread_status_when_unknown() -> TestResult {
+    let comments = vec![CommentBuilder::new(1).build()];
+
+    let output = write_markdown_to_string(&comments, test_data::DEFAULT_PR_URL)?;
+
+    assert_not_contains(&output, "**Status:**")?;
+    Ok(())
+}
+
 #[rstest]
 fn handles_missing_file_path() -> TestResult {
     let comment = CommentBuilder::new(1)
@@ -170,3 +194,27 @@ fn multiple_comments_have_separators() -> TestResult {
     }
     Ok(())
 }
+
+#[rstest]
+#[case::issue_comment(
+    CommentBuilder::new(1).kind(ExportedCommentKind::IssueComment).author("bot"),
+    &["## Conversation comment", "**Author:** bot"]
+)]
+#[case::review(
+    CommentBuilder::new(2).review_state("APPROVED").author("alice"),
+    &["## Review (APPROVED)", "**Reviewer:** alice"]
+)]
+fn conversation_items_are_headed_by_kind(
+    #[case] builder: CommentBuilder,
+    #[case] expected_fragments: &[&str],
+) -> TestResult {
+    let comments = vec![builder.build()];
+
+    let output = write_markdown_to_string(&comments, test_data::DEFAULT_PR_URL)?;
+
+    for fragment in expected_fragments {
+        assert_contains(&output, fragment)?;
+    }
+    assert_not_contains(&output, "(unknown location)")?;
+    Ok(())
+}
@@ -15,6 +15,9 @@
 //! Comments are exported in stable order: by file path (alphabetical), then
 //! line number (ascending), then comment ID (ascending). Comments with missing
 //! file paths or line numbers are sorted last.
+//!
+//! Timeline exports instead keep the pull request's conversation in
+//! chronological order, mixing issue comments, reviews, and review threads.
 
 mod jsonl;
 mod markdown;
@@ -23,9 +26,11 @@ mod ordering;
 mod template;
 #[doc(hidden)]
 pub mod test_helpers;
+mod timeline;
 
 pub use jsonl::write_jsonl;
 pub use markdown::write_markdown;
-pub use model::{ExportFormat, ExportedComment, PrUrl};
+pub use model::{ExportFormat, ExportedComment, ExportedCommentKind, PrUrl};
 pub use ordering::sort_comments;
 pub use template::write_template;
+pub use timeline::timeline_comments;
@@ -8,7 +8,7 @@ use std::str::FromStr;
 
 use serde::Serialize;
 
-use crate::github::{IntakeError, ReviewComment};
+use crate::github::{IntakeError, PullRequestComment, PullRequestReview, ReviewComment};
 
 /// A newtype wrapper for pull request URLs.
 ///
@@ -31,15 +31,42 @@ impl<'a> PrUrl<'a> {
     }
 }
 
+/// The kind of conversation item an [`ExportedComment`] was built from.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportedCommentKind {
+    /// Inline review comment on the diff.
+    ReviewComment,
+    /// Top-level discussion comment on the pull request.
+    IssueComment,
+    /// Review verdict and summary body.
+    Review,
+}
+
+impl ExportedCommentKind {
+    /// Returns the serialized name, such as `review_comment`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ReviewComment => "review_comment",
+            Self::IssueComment => "issue_comment",
+            Self::Review => "review",
+        }
+    }
+}
+
 /// A review comment prepared for export with all relevant metadata.
 ///
 /// This structure is designed for serialization and includes only the fields
 /// needed for structured export. It is constructed from a [`ReviewComment`]
-/// via the [`From`] trait implementation.
+/// via the [`From`] trait implementation; timeline exports also build it
+/// from issue comments and reviews.
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct ExportedComment {
     /// Comment identifier.
     pub id: u64,
+    /// Kind of conversation item the comment was built from.
+    pub kind: ExportedCommentKind,
     /// Author login.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
@@ -67,12 +94,25 @@ pub struct ExportedComment {
     /// Creation timestamp (ISO 8601 format).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
+    /// Whether the comment's thread is resolved on GitHub, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_resolved: Option<bool>,
+    /// Whether the comment's thread is outdated on GitHub, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_outdated: Option<bool>,
+    /// Login of the user who resolved the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_by: Option<String>,
+    /// Review verdict (e.g. `APPROVED`); set for reviews only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_state: Option<String>,
 }
 
 impl From<&ReviewComment> for ExportedComment {
     fn from(comment: &ReviewComment) -> Self {
         Self {
             id: comment.id,
+            kind: ExportedCommentKind::ReviewComment,
             author: comment.author.clone(),
             file_path: comment.file_path.clone(),
             line_number: comment.line_number,
@@ -82,8 +122,75 @@ impl From<&ReviewComment> for ExportedComment {
             commit_sha: comment.commit_sha.clone(),
             in_reply_to_id: comment.in_reply_to_id,
             created_at: comment.created_at.clone(),
+            is_resolved: comment.thread_state.as_ref().map(|state| state.is_resolved),
+            is_outdated: comment.thread_state.as_ref().map(|state| state.is_outdated),
+            resolved_by: comment
+                .thread_state
+                .as_ref()
+                .and_then(|state| state.resolved_by.clone()),
+            review_state: None,
+        }
+    }
+}
+
+impl From<&PullRequestComment> for ExportedComment {
+    fn from(comment: &PullRequestComment) -> Self {
+        Self::conversation_item(
+            comment.id,
+            ExportedCommentKind::IssueComment,
+            comment.author.clone(),
+            comment.body.clone(),
+        )
+        .with_created_at(comment.created_at.clone())
+    }
+}
+
+impl From<&PullRequestReview> for ExportedComment {
+    fn from(review: &PullRequestReview) -> Self {
+        let mut exported = Self::conversation_item(
+            review.id,
+            ExportedCommentKind::Review,
+            review.author.clone(),
+            review.body.clone(),
+        )
+        .with_created_at(review.submitted_at.clone());
+        exported.commit_sha.clone_from(&review.commit_sha);
+        exported.review_state.clone_from(&review.state);
+        exported
+    }
+}
+
+impl ExportedComment {
+    /// Builds an entry that is not attached to the diff.
+    const fn conversation_item(
+        id: u64,
+        kind: ExportedCommentKind,
+        author: Option<String>,
+        body: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            kind,
+            author,
+            file_path: None,
+            line_number: None,
+            original_line_number: None,
+            body,
+            diff_hunk: None,
+            commit_sha: None,
+            in_reply_to_id: None,
+            created_at: None,
+            is_resolved: None,
+            is_outdated: None,
+            resolved_by: None,
+            review_state: None,
         }
     }
+
+    fn with_created_at(mut self, created_at: Option<String>) -> Self {
+        self.created_at = created_at;
+        self
+    }
 }
 
 /// Supported export formats.
@@ -140,12 +247,21 @@ mod tests {
             author: Some("alice".to_owned()),
             file_path: Some("src/lib.rs".to_owned()),
             line_number: Some(42),
+            side: None,
             original_line_number: Some(40),
             diff_hunk: Some("@@ -40,3 +40,5 @@".to_owned()),
             commit_sha: Some("abc123".to_owned()),
             in_reply_to_id: Some(100),
             created_at: Some("2025-01-15T10:00:00Z".to_owned()),
             updated_at: Some("2025-01-15T11:00:00Z".to_owned()),
+            thread_state: Some(crate::github::ReviewThreadState {
+                thread_id: "PRRT_1".to_owned(),
+                is_resolved: true,
+                is_outdated: false,
+                resolved_by: Some("bob".to_owned()),
+            }),
+            start_line_number: None,
+            reactions: Vec::new(),
         };
 
         let exported = ExportedComment::from(&comment);
@@ -160,6 +276,9 @@ mod tests {
         assert_eq!(exported.commit_sha.as_deref(), Some("abc123"));
         assert_eq!(exported.in_reply_to_id, Some(100));
         assert_eq!(exported.created_at.as_deref(), Some("2025-01-15T10:00:00Z"));
+        assert_eq!(exported.is_resolved, Some(true));
+        assert_eq!(exported.is_outdated, Some(false));
+        assert_eq!(exported.resolved_by.as_deref(), Some("bob"));
     }
 
     #[rstest]
@@ -176,6 +295,8 @@ mod tests {
         assert!(exported.author.is_none());
         assert!(exported.file_path.is_none());
         assert!(exported.line_number.is_none());
+        assert!(exported.is_resolved.is_none());
+        assert!(exported.resolved_by.is_none());
     }
 
     #[rstest]
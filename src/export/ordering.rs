@@ -53,10 +53,12 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::export::model::ExportedCommentKind;
 
     fn make_comment(id: u64, file_path: Option<&str>, line_number: Option<u32>) -> ExportedComment {
         ExportedComment {
             id,
+            kind: ExportedCommentKind::ReviewComment,
             file_path: file_path.map(String::from),
             line_number,
             author: None,
@@ -66,6 +68,10 @@ mod tests {
             commit_sha: None,
             in_reply_to_id: None,
             created_at: None,
+            is_resolved: None,
+            is_outdated: None,
+            resolved_by: None,
+            review_state: None,
         }
     }
 
@@ -20,6 +20,7 @@
 //!
 //! **Comment-level** (inside `{% for c in comments %}`):
 //! - `c.id` — comment ID
+//! - `c.kind` — `review_comment`, `issue_comment`, or `review`
 //! - `c.file` — file path
 //! - `c.line` — line number
 //! - `c.reviewer` — comment author
@@ -29,6 +30,10 @@
 //! - `c.commit` — commit secure hash algorithm (SHA)
 //! - `c.timestamp` — creation timestamp
 //! - `c.reply_to` — parent comment ID
+//! - `c.resolution` — "resolved", "unresolved", or empty when unknown
+//! - `c.outdated` — "true", "false", or empty when unknown
+//! - `c.resolved_by` — login of the user who resolved the thread
+//! - `c.state` — review verdict such as "APPROVED", empty for comments
 
 use std::io::Write;
 
@@ -48,6 +53,8 @@ use super::ExportedComment;
 struct TemplateComment {
     /// Comment identifier.
     id: u64,
+    /// Kind of conversation item, such as `review_comment`.
+    kind: &'static str,
     /// File path (empty string if not present).
     file: String,
     /// Line number (empty string if not present).
@@ -66,12 +73,21 @@ struct TemplateComment {
     timestamp: String,
     /// Parent comment ID if this is a reply (empty string if not present).
     reply_to: String,
+    /// Thread resolution: "resolved", "unresolved", or empty when unknown.
+    resolution: &'static str,
+    /// Whether the thread is outdated (empty string if unknown).
+    outdated: String,
+    /// Login of the user who resolved the thread (empty string if not present).
+    resolved_by: String,
+    /// Review verdict (empty string for comments).
+    state: String,
 }
 
 impl From<&ExportedComment> for TemplateComment {
     fn from(comment: &ExportedComment) -> Self {
         Self {
             id: comment.id,
+            kind: comment.kind.as_str(),
             file: comment.file_path.clone().unwrap_or_default(),
             line: comment
                 .line_number
@@ -89,6 +105,16 @@ impl From<&ExportedComment> for TemplateComment {
             reply_to: comment
                 .in_reply_to_id
                 .map_or_else(String::new, |id| id.to_string()),
+            resolution: match comment.is_resolved {
+                Some(true) => "resolved",
+                Some(false) => "unresolved",
+                None => "",
+            },
+            outdated: comment
+                .is_outdated
+                .map_or_else(String::new, |outdated| outdated.to_string()),
+            resolved_by: comment.resolved_by.clone().unwrap_or_default(),
+            state: comment.review_state.clone().unwrap_or_default(),
         }
     }
 }
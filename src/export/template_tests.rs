@@ -28,6 +28,11 @@ fn render_template(
 #[case::timestamp("timestamp", "2025-01-15T10:30:00Z", |b: CommentBuilder| b.created_at("2025-01-15T10:30:00Z"))]
 #[case::id("id", "1", |b: CommentBuilder| b)]
 #[case::reply_to("reply_to", "999", |b: CommentBuilder| b.in_reply_to_id(999))]
+#[case::resolution("resolution", "unresolved", |b: CommentBuilder| b.thread_state(false, false))]
+#[case::outdated("outdated", "true", |b: CommentBuilder| b.thread_state(false, true))]
+#[case::resolved_by("resolved_by", "carol", |b: CommentBuilder| b.resolved_by("carol"))]
+#[case::kind("kind", "review_comment", |b: CommentBuilder| b)]
+#[case::state("state", "APPROVED", |b: CommentBuilder| b.review_state("APPROVED"))]
 fn substitutes_placeholder(
     #[case] field: &str,
     #[case] expected: &str,
@@ -5,7 +5,7 @@
 
 use std::fmt;
 
-use super::ExportedComment;
+use super::{ExportedComment, ExportedCommentKind};
 
 // Re-export PrUrl from model for test convenience.
 pub use super::PrUrl;
@@ -56,6 +56,7 @@ pub mod test_data {
 #[must_use]
 pub struct CommentBuilder {
     id: u64,
+    kind: ExportedCommentKind,
     author: Option<String>,
     file_path: Option<String>,
     line_number: Option<u32>,
@@ -65,6 +66,10 @@ pub struct CommentBuilder {
     commit_sha: Option<String>,
     in_reply_to_id: Option<u64>,
     created_at: Option<String>,
+    is_resolved: Option<bool>,
+    is_outdated: Option<bool>,
+    resolved_by: Option<String>,
+    review_state: Option<String>,
 }
 
 impl CommentBuilder {
@@ -72,6 +77,7 @@ impl CommentBuilder {
     pub const fn new(id: u64) -> Self {
         Self {
             id,
+            kind: ExportedCommentKind::ReviewComment,
             author: None,
             file_path: None,
             line_number: None,
@@ -81,9 +87,26 @@ impl CommentBuilder {
             commit_sha: None,
             in_reply_to_id: None,
             created_at: None,
+            is_resolved: None,
+            is_outdated: None,
+            resolved_by: None,
+            review_state: None,
         }
     }
 
+    /// Sets the kind of conversation item the comment was built from.
+    pub const fn kind(mut self, kind: ExportedCommentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Marks the comment as a review with the given verdict.
+    pub fn review_state(mut self, state: &str) -> Self {
+        self.kind = ExportedCommentKind::Review;
+        self.review_state = Some(state.to_owned());
+        self
+    }
+
     /// Sets the comment author.
     pub fn author(mut self, author: &str) -> Self {
         self.author = Some(author.to_owned());
@@ -138,6 +161,20 @@ impl CommentBuilder {
         self
     }
 
+    /// Marks the comment's thread as resolved by the given user.
+    pub fn resolved_by(mut self, resolved_by: &str) -> Self {
+        self.is_resolved = Some(true);
+        self.resolved_by = Some(resolved_by.to_owned());
+        self
+    }
+
+    /// Sets whether the comment's thread is resolved and outdated.
+    pub const fn thread_state(mut self, is_resolved: bool, is_outdated: bool) -> Self {
+        self.is_resolved = Some(is_resolved);
+        self.is_outdated = Some(is_outdated);
+        self
+    }
+
     /// Sets the author to [`test_data::SAMPLE_AUTHOR`].
     pub fn with_sample_author(self) -> Self {
         self.author(test_data::SAMPLE_AUTHOR)
@@ -168,6 +205,7 @@ impl CommentBuilder {
     pub fn build(self) -> ExportedComment {
         ExportedComment {
             id: self.id,
+            kind: self.kind,
             author: self.author,
             file_path: self.file_path,
             line_number: self.line_number,
@@ -177,6 +215,10 @@ impl CommentBuilder {
             commit_sha: self.commit_sha,
             in_reply_to_id: self.in_reply_to_id,
             created_at: self.created_at,
+            is_resolved: self.is_resolved,
+            is_outdated: self.is_outdated,
+            resolved_by: self.resolved_by,
+            review_state: self.review_state,
         }
     }
 }
@@ -0,0 +1,88 @@
+//! Conversation timeline flattening for export.
+//!
+//! Timeline exports keep the chronological order of
+//! [`build_timeline`](crate::github::build_timeline) instead of sorting by
+//! location. Each review thread is written as its root followed by its
+//! replies, so a thread reads top to bottom.
+
+use crate::github::TimelineEntry;
+
+use super::model::ExportedComment;
+
+/// Converts timeline entries into export records in timeline order.
+#[must_use]
+pub fn timeline_comments(entries: &[TimelineEntry<'_>]) -> Vec<ExportedComment> {
+    let mut comments = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match entry {
+            TimelineEntry::IssueComment(comment) => comments.push(ExportedComment::from(*comment)),
+            TimelineEntry::Review(review) => comments.push(ExportedComment::from(*review)),
+            TimelineEntry::ReviewThread(thread) => {
+                comments.extend(thread.comments().map(ExportedComment::from));
+            }
+        }
+    }
+    comments
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `timeline` module.
+
+    use rstest::rstest;
+
+    use super::timeline_comments;
+    use crate::export::ExportedCommentKind;
+    use crate::github::{PullRequestComment, PullRequestReview, ReviewComment, build_timeline};
+
+    #[rstest]
+    fn timeline_comments_flatten_threads_in_timeline_order() {
+        let issue_comments = vec![PullRequestComment {
+            id: 1,
+            body: Some("CI report".to_owned()),
+            author: Some("bot".to_owned()),
+            created_at: Some("2025-01-01T09:00:00Z".to_owned()),
+            html_url: None,
+        }];
+        let reviews = vec![PullRequestReview {
+            id: 2,
+            author: Some("alice".to_owned()),
+            state: Some("APPROVED".to_owned()),
+            submitted_at: Some("2025-01-03T09:00:00Z".to_owned()),
+            ..PullRequestReview::default()
+        }];
+        let review_comments = vec![
+            ReviewComment {
+                id: 10,
+                created_at: Some("2025-01-02T09:00:00Z".to_owned()),
+                ..ReviewComment::default()
+            },
+            ReviewComment {
+                id: 11,
+                in_reply_to_id: Some(10),
+                created_at: Some("2025-01-04T09:00:00Z".to_owned()),
+                ..ReviewComment::default()
+            },
+        ];
+        let timeline = build_timeline(&issue_comments, &reviews, &review_comments);
+
+        let exported = timeline_comments(&timeline);
+
+        let summary: Vec<(ExportedCommentKind, u64)> = exported
+            .iter()
+            .map(|comment| (comment.kind, comment.id))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ExportedCommentKind::IssueComment, 1),
+                (ExportedCommentKind::ReviewComment, 10),
+                (ExportedCommentKind::ReviewComment, 11),
+                (ExportedCommentKind::Review, 2),
+            ]
+        );
+        let review = exported.last().expect("review should be exported");
+        assert_eq!(review.review_state.as_deref(), Some("APPROVED"));
+        assert_eq!(review.created_at.as_deref(), Some("2025-01-03T09:00:00Z"));
+    }
+}
@@ -0,0 +1,259 @@
+//! CI check runs and commit statuses for a pull request head.
+//!
+//! GitHub reports CI results through two APIs: check runs (GitHub Actions and
+//! other check apps) and the older commit statuses. [`CiStatus`] folds both
+//! into one list of [`CiCheck`] entries so callers can show a single badge
+//! and drill into the failing checks without caring where each came from.
+
+use std::fmt;
+
+/// Simplified outcome of a check or of the head commit as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CiOutcome {
+    /// Every check that reported has passed or was skipped.
+    Passing,
+    /// At least one check failed, errored, timed out, or was cancelled.
+    Failing,
+    /// At least one check is queued or still running.
+    Pending,
+    /// No checks have been reported for the commit.
+    NoChecks,
+}
+
+impl CiOutcome {
+    /// Returns a short lowercase label for the outcome.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Passing => "passing",
+            Self::Failing => "failing",
+            Self::Pending => "pending",
+            Self::NoChecks => "no checks",
+        }
+    }
+
+    /// Maps a check run's `status` and `conclusion` to an outcome.
+    ///
+    /// Runs that have not completed are pending. Completed runs pass when
+    /// they succeeded, were neutral, or were skipped; every other conclusion
+    /// counts as a failure.
+    #[must_use]
+    pub fn from_check_run(status: Option<&str>, conclusion: Option<&str>) -> Self {
+        if status.is_some_and(|value| value != "completed") {
+            return Self::Pending;
+        }
+        match conclusion {
+            Some("success" | "neutral" | "skipped") => Self::Passing,
+            Some(_) => Self::Failing,
+            None => Self::Pending,
+        }
+    }
+
+    /// Maps a commit status `state` to an outcome.
+    #[must_use]
+    pub fn from_commit_status(state: Option<&str>) -> Self {
+        match state {
+            Some("success") => Self::Passing,
+            Some("failure" | "error") => Self::Failing,
+            _ => Self::Pending,
+        }
+    }
+}
+
+impl fmt::Display for CiOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// API a [`CiCheck`] was reported through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiCheckKind {
+    /// A check run from the Checks API.
+    CheckRun,
+    /// A commit status context from the Statuses API.
+    CommitStatus,
+}
+
+/// One CI result reported against the head commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiCheck {
+    /// Check run name or commit status context.
+    pub name: String,
+    /// API the result was reported through.
+    pub kind: CiCheckKind,
+    /// Simplified outcome of the check.
+    pub outcome: CiOutcome,
+    /// Raw GitHub conclusion, status, or state (e.g. `failure`, `timed_out`).
+    pub state: Option<String>,
+    /// Output title and summary for check runs, or the status description.
+    pub summary: Option<String>,
+    /// Link to the check details.
+    pub details_url: Option<String>,
+}
+
+/// CI results for a pull request head commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiStatus {
+    /// SHA of the commit the checks ran against.
+    pub head_sha: String,
+    /// Check runs followed by commit statuses, in API order.
+    pub checks: Vec<CiCheck>,
+}
+
+impl CiStatus {
+    /// Returns the combined outcome of every check.
+    ///
+    /// Any failure makes the commit fail; otherwise any pending check makes
+    /// it pending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::github::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+    ///
+    /// let check = |outcome| CiCheck {
+    ///     name: "build".to_owned(),
+    ///     kind: CiCheckKind::CheckRun,
+    ///     outcome,
+    ///     state: None,
+    ///     summary: None,
+    ///     details_url: None,
+    /// };
+    /// let status = CiStatus {
+    ///     head_sha: "abc123".to_owned(),
+    ///     checks: vec![check(CiOutcome::Passing), check(CiOutcome::Pending)],
+    /// };
+    ///
+    /// assert_eq!(status.outcome(), CiOutcome::Pending);
+    /// ```
+    #[must_use]
+    pub fn outcome(&self) -> CiOutcome {
+        let has = |outcome| self.checks.iter().any(|check| check.outcome == outcome);
+        if self.checks.is_empty() {
+            CiOutcome::NoChecks
+        } else if has(CiOutcome::Failing) {
+            CiOutcome::Failing
+        } else if has(CiOutcome::Pending) {
+            CiOutcome::Pending
+        } else {
+            CiOutcome::Passing
+        }
+    }
+
+    /// Returns the number of checks with the given outcome.
+    #[must_use]
+    pub fn count(&self, outcome: CiOutcome) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.outcome == outcome)
+            .count()
+    }
+
+    /// Returns the checks that failed, in API order.
+    #[must_use]
+    pub fn failing_checks(&self) -> Vec<&CiCheck> {
+        self.checks
+            .iter()
+            .filter(|check| check.outcome == CiOutcome::Failing)
+            .collect()
+    }
+
+    /// Returns a compact badge such as `CI: 2/5 failing`.
+    #[must_use]
+    pub fn badge(&self) -> String {
+        let total = self.checks.len();
+        match self.outcome() {
+            CiOutcome::NoChecks => "CI: no checks".to_owned(),
+            CiOutcome::Passing => format!("CI: {total}/{total} passing"),
+            outcome => format!("CI: {}/{total} {outcome}", self.count(outcome)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for CI status aggregation.
+
+    use rstest::rstest;
+
+    use super::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+
+    fn check(name: &str, outcome: CiOutcome) -> CiCheck {
+        CiCheck {
+            name: name.to_owned(),
+            kind: CiCheckKind::CheckRun,
+            outcome,
+            state: None,
+            summary: None,
+            details_url: None,
+        }
+    }
+
+    fn status(outcomes: &[CiOutcome]) -> CiStatus {
+        CiStatus {
+            head_sha: "abc123".to_owned(),
+            checks: outcomes
+                .iter()
+                .enumerate()
+                .map(|(index, outcome)| check(&format!("check-{index}"), *outcome))
+                .collect(),
+        }
+    }
+
+    #[rstest]
+    #[case(Some("completed"), Some("success"), CiOutcome::Passing)]
+    #[case(Some("completed"), Some("skipped"), CiOutcome::Passing)]
+    #[case(Some("completed"), Some("timed_out"), CiOutcome::Failing)]
+    #[case(Some("completed"), Some("action_required"), CiOutcome::Failing)]
+    #[case(Some("in_progress"), None, CiOutcome::Pending)]
+    #[case(Some("queued"), Some("success"), CiOutcome::Pending)]
+    fn check_run_outcomes(
+        #[case] run_status: Option<&str>,
+        #[case] conclusion: Option<&str>,
+        #[case] expected: CiOutcome,
+    ) {
+        assert_eq!(CiOutcome::from_check_run(run_status, conclusion), expected);
+    }
+
+    #[rstest]
+    #[case(Some("success"), CiOutcome::Passing)]
+    #[case(Some("error"), CiOutcome::Failing)]
+    #[case(Some("pending"), CiOutcome::Pending)]
+    fn commit_status_outcomes(#[case] state: Option<&str>, #[case] expected: CiOutcome) {
+        assert_eq!(CiOutcome::from_commit_status(state), expected);
+    }
+
+    #[rstest]
+    #[case::none(&[], CiOutcome::NoChecks, "CI: no checks")]
+    #[case::passing(&[CiOutcome::Passing, CiOutcome::Passing], CiOutcome::Passing, "CI: 2/2 passing")]
+    #[case::pending(&[CiOutcome::Passing, CiOutcome::Pending], CiOutcome::Pending, "CI: 1/2 pending")]
+    #[case::failing(
+        &[CiOutcome::Failing, CiOutcome::Pending, CiOutcome::Passing],
+        CiOutcome::Failing,
+        "CI: 1/3 failing"
+    )]
+    fn aggregate_outcome_and_badge(
+        #[case] outcomes: &[CiOutcome],
+        #[case] expected: CiOutcome,
+        #[case] badge: &str,
+    ) {
+        let ci = status(outcomes);
+
+        assert_eq!(ci.outcome(), expected);
+        assert_eq!(ci.badge(), badge);
+    }
+
+    #[rstest]
+    fn failing_checks_keep_api_order() {
+        let ci = status(&[CiOutcome::Failing, CiOutcome::Passing, CiOutcome::Failing]);
+
+        let names: Vec<_> = ci
+            .failing_checks()
+            .iter()
+            .map(|failing| failing.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["check-0", "check-2"]);
+    }
+}
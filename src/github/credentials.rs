@@ -0,0 +1,222 @@
+//! Credentials used to authenticate GitHub API requests.
+//!
+//! Gateways accept either a [`PersonalAccessToken`] or a
+//! [`GitHubAppInstallation`]. An installation authenticates as a GitHub App:
+//! it signs a short-lived JWT with the app's private key, exchanges it for an
+//! installation access token, and caches that token until shortly before it
+//! expires. Clones share the cache, so every gateway built from the same
+//! installation reuses one token.
+
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use http::Uri;
+use jsonwebtoken::EncodingKey;
+use octocrab::Octocrab;
+use octocrab::models::{AppId, InstallationId};
+use octocrab::service::middleware::retry::RetryConfig;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use super::error::IntakeError;
+use super::locator::PersonalAccessToken;
+
+/// How long before expiry a cached installation token is replaced.
+const REFRESH_MARGIN: TimeDelta = TimeDelta::seconds(60);
+
+/// Credentials a gateway authenticates with.
+#[derive(Debug, Clone)]
+pub enum GitHubCredentials {
+    /// A personal access token sent with every request.
+    PersonalAccessToken(PersonalAccessToken),
+    /// A GitHub App installation whose token is minted on demand.
+    AppInstallation(GitHubAppInstallation),
+}
+
+impl From<PersonalAccessToken> for GitHubCredentials {
+    fn from(token: PersonalAccessToken) -> Self {
+        Self::PersonalAccessToken(token)
+    }
+}
+
+impl From<&PersonalAccessToken> for GitHubCredentials {
+    fn from(token: &PersonalAccessToken) -> Self {
+        Self::PersonalAccessToken(token.clone())
+    }
+}
+
+impl From<GitHubAppInstallation> for GitHubCredentials {
+    fn from(installation: GitHubAppInstallation) -> Self {
+        Self::AppInstallation(installation)
+    }
+}
+
+impl From<&GitHubAppInstallation> for GitHubCredentials {
+    fn from(installation: &GitHubAppInstallation) -> Self {
+        Self::AppInstallation(installation.clone())
+    }
+}
+
+impl From<&Self> for GitHubCredentials {
+    fn from(credentials: &Self) -> Self {
+        credentials.clone()
+    }
+}
+
+/// GitHub App installation credentials with a shared token cache.
+///
+/// The installation token is requested from
+/// `{api_base}/app/installations/{installation_id}/access_tokens` the first
+/// time a gateway needs it, reused until one minute before it expires, and
+/// requested again after GitHub rejects it.
+#[derive(Clone)]
+pub struct GitHubAppInstallation {
+    inner: Arc<InstallationState>,
+}
+
+struct InstallationState {
+    app_id: AppId,
+    installation_id: InstallationId,
+    key: EncodingKey,
+    api_base: Uri,
+    cached: Mutex<Option<InstallationToken>>,
+}
+
+/// Installation access token returned by GitHub.
+#[derive(Debug, Clone, Deserialize)]
+struct InstallationToken {
+    token: String,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl InstallationToken {
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| expires_at - now > REFRESH_MARGIN)
+    }
+}
+
+impl GitHubAppInstallation {
+    /// Creates installation credentials from a PEM-encoded RSA private key.
+    ///
+    /// `api_base` is the REST API base of the GitHub host the app is
+    /// installed on, such as `https://api.github.com`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the key is not a valid RSA
+    /// PEM key and [`IntakeError::InvalidUrl`] when `api_base` cannot be
+    /// parsed.
+    pub fn new(
+        app_id: u64,
+        installation_id: u64,
+        private_key_pem: &[u8],
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let key = EncodingKey::from_rsa_pem(private_key_pem).map_err(|error| {
+            IntakeError::Configuration {
+                message: format!("GitHub App private key is not a valid RSA PEM key: {error}"),
+            }
+        })?;
+        let base_uri = api_base
+            .parse::<Uri>()
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(InstallationState {
+                app_id: AppId(app_id),
+                installation_id: InstallationId(installation_id),
+                key,
+                api_base: base_uri,
+                cached: Mutex::new(None),
+            }),
+        })
+    }
+
+    /// Creates installation credentials from a private key file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the file cannot be read or
+    /// does not hold a valid RSA PEM key, and [`IntakeError::InvalidUrl`] when
+    /// `api_base` cannot be parsed.
+    pub fn from_private_key_file(
+        app_id: u64,
+        installation_id: u64,
+        private_key_path: &Path,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let pem = std::fs::read(private_key_path).map_err(|error| IntakeError::Configuration {
+            message: format!(
+                "failed to read GitHub App private key {}: {error}",
+                private_key_path.display()
+            ),
+        })?;
+        Self::new(app_id, installation_id, &pem, api_base)
+    }
+
+    /// Returns the GitHub App ID.
+    #[must_use]
+    pub fn app_id(&self) -> u64 {
+        self.inner.app_id.0
+    }
+
+    /// Returns the installation ID.
+    #[must_use]
+    pub fn installation_id(&self) -> u64 {
+        self.inner.installation_id.0
+    }
+
+    /// Returns a valid installation token, requesting a new one when the
+    /// cached token is missing or about to expire.
+    ///
+    /// The cache stays locked while a token is requested, so concurrent
+    /// requests wait for one exchange instead of starting their own.
+    pub(crate) async fn installation_token(&self) -> Result<String, octocrab::Error> {
+        let mut cached = self.inner.cached.lock().await;
+        if let Some(token) = cached.as_ref().filter(|token| token.is_fresh(Utc::now())) {
+            return Ok(token.token.clone());
+        }
+
+        let minted = self.inner.request_installation_token().await?;
+        let token = minted.token.clone();
+        *cached = Some(minted);
+        Ok(token)
+    }
+
+    /// Drops the cached token so the next request mints a new one.
+    ///
+    /// Only `rejected` is dropped: when another request has already replaced
+    /// it with a fresh token, the fresh token is kept.
+    pub(crate) async fn invalidate(&self, rejected: &str) {
+        let mut cached = self.inner.cached.lock().await;
+        if cached.as_ref().is_some_and(|token| token.token == rejected) {
+            *cached = None;
+        }
+    }
+}
+
+impl InstallationState {
+    /// Exchanges a freshly signed app JWT for an installation token.
+    async fn request_installation_token(&self) -> Result<InstallationToken, octocrab::Error> {
+        let app_client = Octocrab::builder()
+            .add_retry_config(RetryConfig::None)
+            .app(self.app_id, self.key.clone())
+            .base_uri(self.api_base.clone())?
+            .build()?;
+        let route = format!("/app/installations/{}/access_tokens", self.installation_id);
+        app_client.post(route, None::<&()>).await
+    }
+}
+
+impl fmt::Debug for GitHubAppInstallation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("GitHubAppInstallation")
+            .field("app_id", &self.app_id())
+            .field("installation_id", &self.installation_id())
+            .field("api_base", &self.inner.api_base)
+            .finish_non_exhaustive()
+    }
+}
@@ -92,3 +92,14 @@ pub enum IntakeError {
         message: String,
     },
 }
+
+impl IntakeError {
+    /// Returns `true` when the failure is likely to clear on its own, such as
+    /// a dropped connection or an exhausted rate limit.
+    ///
+    /// Writes that fail this way are worth queueing and retrying later.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Network { .. } | Self::RateLimitExceeded { .. })
+    }
+}
@@ -1,20 +1,25 @@
-//! Octocrab gateway that caches pull request metadata in `SQLite`.
+//! Octocrab gateway that caches pull request metadata and reviews in `SQLite`.
 
 use async_trait::async_trait;
 use http::header::{ETAG, LAST_MODIFIED};
 use http::{StatusCode, Uri};
 use octocrab::Octocrab;
 
+use crate::github::credentials::GitHubCredentials;
 use crate::github::error::IntakeError;
-use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
-use crate::github::models::{ApiPullRequest, PullRequestComment, PullRequestMetadata};
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{
+    ApiComment, ApiPullRequest, ApiPullRequestReview, PullRequestComment, PullRequestMetadata,
+    PullRequestReview,
+};
 use crate::persistence::{
-    CachedPullRequestMetadata, PullRequestMetadataCache, PullRequestMetadataCacheWrite,
+    CachedPullRequestMetadata, CommentListing, CommentPageCache, PullRequestMetadataCache,
+    PullRequestMetadataCacheWrite, PullRequestReviewCache,
 };
 
 use super::PullRequestGateway;
 use super::client::build_octocrab_client;
-use super::comments::fetch_pull_request_comments;
+use super::comment_pages::CachedCommentListing;
 use super::error_mapping::{map_http_error, map_octocrab_error, map_persistence_error};
 use super::http_utils::{build_conditional_headers, extract_github_message, header_to_string};
 
@@ -32,16 +37,20 @@ struct ModifiedPullRequest {
 
 /// Octocrab-backed gateway that caches pull request metadata in `SQLite`.
 ///
-/// Only the metadata call is cached; comment listing currently always calls the
-/// GitHub API.
+/// Metadata is cached with a TTL. Review and issue comment pages are stored
+/// with their `ETag`s and revalidated on every listing, so unchanged pages
+/// cost no rate-limit quota; the latest reviews are also kept in the review
+/// cache for offline use.
 pub struct OctocrabCachingGateway {
     client: Octocrab,
     cache: PullRequestMetadataCache,
+    review_cache: PullRequestReviewCache,
+    comment_page_cache: CommentPageCache,
     ttl_seconds: u64,
 }
 
 impl OctocrabCachingGateway {
-    /// Builds a caching gateway for the given token, pull request locator, and
+    /// Builds a caching gateway for the given credentials, pull request locator, and
     /// database URL.
     ///
     /// # Errors
@@ -49,17 +58,23 @@ impl OctocrabCachingGateway {
     /// Returns [`IntakeError`] when the Octocrab client cannot be constructed
     /// or when the database URL is invalid.
     pub fn for_token(
-        token: &PersonalAccessToken,
+        credentials: impl Into<GitHubCredentials>,
         locator: &PullRequestLocator,
         database_url: &str,
         ttl_seconds: u64,
     ) -> Result<Self, IntakeError> {
-        let octocrab = build_octocrab_client(token, locator.api_base().as_str())?;
+        let octocrab = build_octocrab_client(&credentials.into(), locator.api_base().as_str())?;
         let cache = PullRequestMetadataCache::new(database_url.to_owned())
             .map_err(|error| map_persistence_error("initialise cache", &error))?;
+        let review_cache = PullRequestReviewCache::new(database_url.to_owned())
+            .map_err(|error| map_persistence_error("initialise cache", &error))?;
+        let comment_page_cache = CommentPageCache::new(database_url.to_owned())
+            .map_err(|error| map_persistence_error("initialise cache", &error))?;
         Ok(Self {
             client: octocrab,
             cache,
+            review_cache,
+            comment_page_cache,
             ttl_seconds,
         })
     }
@@ -135,7 +150,7 @@ impl OctocrabCachingGateway {
                     })?;
 
                 Ok(FetchResult::Modified {
-                    metadata: api.into(),
+                    metadata: Box::new(api.into()),
                     validators,
                 })
             }
@@ -159,7 +174,7 @@ impl OctocrabCachingGateway {
 enum FetchResult {
     NotModified,
     Modified {
-        metadata: PullRequestMetadata,
+        metadata: Box<PullRequestMetadata>,
         validators: ResponseValidators,
     },
 }
@@ -203,7 +218,7 @@ impl PullRequestGateway for OctocrabCachingGateway {
             } => self.store_and_return(
                 locator,
                 ModifiedPullRequest {
-                    metadata,
+                    metadata: *metadata,
                     validators,
                 },
                 now,
@@ -215,6 +230,41 @@ impl PullRequestGateway for OctocrabCachingGateway {
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Vec<PullRequestComment>, IntakeError> {
-        fetch_pull_request_comments(&self.client, locator).await
+        let listing = CachedCommentListing {
+            client: &self.client,
+            cache: &self.comment_page_cache,
+            locator,
+            listing: CommentListing::IssueComments,
+            path: locator.comments_path(),
+        };
+        let comments: Vec<ApiComment> = listing.fetch_all().await?;
+        Ok(comments.into_iter().map(ApiComment::into).collect())
+    }
+
+    async fn list_reviews(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<PullRequestReview>, IntakeError> {
+        let listing = CachedCommentListing {
+            client: &self.client,
+            cache: &self.comment_page_cache,
+            locator,
+            listing: CommentListing::Reviews,
+            path: locator.reviews_path(),
+        };
+        let api_reviews: Vec<ApiPullRequestReview> = listing.fetch_all().await?;
+        let reviews: Vec<PullRequestReview> = api_reviews
+            .into_iter()
+            .map(ApiPullRequestReview::into)
+            .collect();
+        let now = PullRequestMetadataCache::now_unix_seconds();
+        self.review_cache
+            .replace(locator, &reviews, self.expiry_window(now))
+            .map_err(|error| map_persistence_error("write review cache", &error))?;
+        Ok(reviews)
     }
 }
+
+#[cfg(test)]
+#[path = "caching_tests.rs"]
+mod tests;
@@ -0,0 +1,370 @@
+//! Tests for the caching pull request gateway.
+
+type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::OctocrabCachingGateway;
+use crate::github::gateway::PullRequestGateway;
+use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
+use crate::github::models::{PullRequestBranch, PullRequestMetadata};
+use crate::persistence::migrate_database;
+use crate::telemetry::NoopTelemetrySink;
+
+const PULL_REQUEST_PATH: &str = "/api/v3/repos/owner/repo/pulls/42";
+const REVIEWS_PATH: &str = "/api/v3/repos/owner/repo/pulls/42/reviews";
+const ISSUE_COMMENTS_PATH: &str = "/api/v3/repos/owner/repo/issues/42/comments";
+
+struct CachingGatewayFixture {
+    runtime: Runtime,
+    server: MockServer,
+    locator: PullRequestLocator,
+    token: PersonalAccessToken,
+    database_url: String,
+    _temp_dir: TempDir,
+}
+
+impl CachingGatewayFixture {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn gateway(&self, ttl_seconds: u64) -> FixtureResult<OctocrabCachingGateway> {
+        let _guard = self.runtime.enter();
+        Ok(OctocrabCachingGateway::for_token(
+            &self.token,
+            &self.locator,
+            &self.database_url,
+            ttl_seconds,
+        )?)
+    }
+}
+
+#[fixture]
+fn gateway_fixture() -> FixtureResult<CachingGatewayFixture> {
+    let temp_dir = TempDir::new()?;
+    let database_url = temp_dir
+        .path()
+        .join("frankie.sqlite")
+        .to_string_lossy()
+        .to_string();
+    migrate_database(&database_url, &NoopTelemetrySink)?;
+    let runtime = Runtime::new()?;
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
+    Ok(CachingGatewayFixture {
+        runtime,
+        server,
+        locator,
+        token: PersonalAccessToken::new("valid-token")?,
+        database_url,
+        _temp_dir: temp_dir,
+    })
+}
+
+fn review_json(id: u64, state: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "user": { "login": "reviewer" },
+        "state": state,
+        "body": format!("Review {id}"),
+        "submitted_at": "2025-01-01T00:00:00Z",
+        "commit_id": "abc123"
+    })
+}
+
+fn mount_review_pages(fixture: &CachingGatewayFixture, expected_calls: u64) {
+    let next_url = format!("{}{REVIEWS_PATH}?page=2", fixture.server.uri());
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(REVIEWS_PATH))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([review_json(2, "APPROVED")])),
+            )
+            .expect(expected_calls)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(REVIEWS_PATH))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([review_json(1, "COMMENTED")]))
+                    .insert_header("Link", format!("<{next_url}>; rel=\"next\"")),
+            )
+            .expect(expected_calls)
+            .mount(&fixture.server),
+    );
+}
+
+/// A listing whose pages carry `ETag`s and answer a matching
+/// `If-None-Match` with `304 Not Modified`, once each.
+struct EtagListing {
+    path: &'static str,
+    page_count: u32,
+}
+
+impl EtagListing {
+    fn mount(&self, fixture: &CachingGatewayFixture, item: impl Fn(u32) -> serde_json::Value) {
+        for page in 1..=self.page_count {
+            self.mount_page(fixture, page, &item(page));
+        }
+    }
+
+    fn mount_page(&self, fixture: &CachingGatewayFixture, page: u32, item: &serde_json::Value) {
+        let page_value = page.to_string();
+        let etag = format!("\"page-{page}\"");
+        fixture.block_on(
+            Mock::given(method("GET"))
+                .and(path(self.path))
+                .and(query_param("page", page_value.as_str()))
+                .and(header("If-None-Match", etag.as_str()))
+                .respond_with(ResponseTemplate::new(304))
+                .expect(1)
+                .mount(&fixture.server),
+        );
+
+        let mut response = ResponseTemplate::new(200)
+            .insert_header("ETag", etag.as_str())
+            .set_body_json(serde_json::json!([item]));
+        if page < self.page_count {
+            let next_url = format!("{}{}?page={}", fixture.server.uri(), self.path, page + 1);
+            response = response.insert_header("Link", format!("<{next_url}>; rel=\"next\""));
+        }
+        fixture.block_on(
+            Mock::given(method("GET"))
+                .and(path(self.path))
+                .and(query_param("page", page_value.as_str()))
+                .respond_with(response)
+                .expect(1)
+                .mount(&fixture.server),
+        );
+    }
+}
+
+#[rstest]
+fn pull_request_details_survive_the_cache(gateway_fixture: FixtureResult<CachingGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(PULL_REQUEST_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "number": 42,
+                "title": "Add widgets",
+                "state": "open",
+                "html_url": "https://github.com/owner/repo/pull/42",
+                "user": { "login": "octocat" },
+                "body": "Widgets for everyone.",
+                "draft": true,
+                "base": { "ref": "main", "sha": "aaa111" },
+                "head": { "ref": "feature/widgets", "sha": "bbb222" },
+                "labels": [{ "name": "enhancement" }],
+                "requested_reviewers": [{ "login": "hubot" }],
+                "requested_teams": [{ "slug": "core" }],
+                "mergeable_state": "blocked",
+                "additions": 120,
+                "deletions": 8,
+                "changed_files": 4
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    let expected = PullRequestMetadata {
+        number: 42,
+        title: Some("Add widgets".to_owned()),
+        state: Some("open".to_owned()),
+        html_url: Some("https://github.com/owner/repo/pull/42".to_owned()),
+        author: Some("octocat".to_owned()),
+        body: Some("Widgets for everyone.".to_owned()),
+        draft: true,
+        base: PullRequestBranch {
+            name: Some("main".to_owned()),
+            sha: Some("aaa111".to_owned()),
+        },
+        head: PullRequestBranch {
+            name: Some("feature/widgets".to_owned()),
+            sha: Some("bbb222".to_owned()),
+        },
+        labels: vec!["enhancement".to_owned()],
+        requested_reviewers: vec!["hubot".to_owned(), "core".to_owned()],
+        mergeable_state: Some("blocked".to_owned()),
+        additions: Some(120),
+        deletions: Some(8),
+        changed_files: Some(4),
+    };
+
+    let fetched = fixture
+        .block_on(
+            fixture
+                .gateway(86_400)
+                .expect("gateway should build")
+                .pull_request(&fixture.locator),
+        )
+        .expect("pull request should load");
+    let cached = fixture
+        .block_on(
+            fixture
+                .gateway(86_400)
+                .expect("gateway should build")
+                .pull_request(&fixture.locator),
+        )
+        .expect("cached pull request should load");
+
+    assert_eq!(fetched, expected);
+    assert_eq!(cached, expected);
+}
+
+#[rstest]
+fn list_reviews_follows_pagination(gateway_fixture: FixtureResult<CachingGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    mount_review_pages(&fixture, 1);
+    let gateway = fixture.gateway(86_400).expect("gateway should build");
+
+    let reviews = fixture
+        .block_on(gateway.list_reviews(&fixture.locator))
+        .expect("reviews should load");
+
+    let states: Vec<_> = reviews
+        .iter()
+        .map(|review| (review.id, review.state.as_deref()))
+        .collect();
+    assert_eq!(states, vec![(1, Some("COMMENTED")), (2, Some("APPROVED"))]);
+    assert_eq!(
+        reviews.first().and_then(|review| review.author.as_deref()),
+        Some("reviewer")
+    );
+}
+
+#[rstest]
+fn unchanged_review_pages_are_served_from_cache(
+    gateway_fixture: FixtureResult<CachingGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let listing = EtagListing {
+        path: REVIEWS_PATH,
+        page_count: 2,
+    };
+    listing.mount(&fixture, |page| review_json(u64::from(page), "COMMENTED"));
+    let first = fixture
+        .block_on(
+            fixture
+                .gateway(86_400)
+                .expect("gateway should build")
+                .list_reviews(&fixture.locator),
+        )
+        .expect("reviews should load");
+
+    let second = fixture
+        .block_on(
+            fixture
+                .gateway(86_400)
+                .expect("gateway should build")
+                .list_reviews(&fixture.locator),
+        )
+        .expect("revalidated reviews should load");
+
+    assert_eq!(first, second);
+    let ids: Vec<_> = second.iter().map(|review| review.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[rstest]
+fn reviews_without_etags_are_refetched(gateway_fixture: FixtureResult<CachingGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    mount_review_pages(&fixture, 2);
+    let gateway = fixture.gateway(86_400).expect("gateway should build");
+
+    for _ in 0..2 {
+        let reviews = fixture
+            .block_on(gateway.list_reviews(&fixture.locator))
+            .expect("reviews should load");
+        assert_eq!(reviews.len(), 2);
+    }
+}
+
+#[rstest]
+fn unchanged_comment_pages_are_served_from_cache(
+    gateway_fixture: FixtureResult<CachingGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let listing = EtagListing {
+        path: ISSUE_COMMENTS_PATH,
+        page_count: 2,
+    };
+    listing.mount(&fixture, |page| {
+        serde_json::json!({
+            "id": page,
+            "body": format!("Comment {page}"),
+            "user": { "login": "alice" }
+        })
+    });
+    let gateway = fixture.gateway(86_400).expect("gateway should build");
+
+    let first = fixture
+        .block_on(gateway.pull_request_comments(&fixture.locator))
+        .expect("comments should load");
+    let second = fixture
+        .block_on(gateway.pull_request_comments(&fixture.locator))
+        .expect("revalidated comments should load");
+
+    assert_eq!(first, second);
+    let ids: Vec<_> = second.iter().map(|comment| comment.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+}
+
+/// Answers each comment page with one comment numbered after the page,
+/// linking to the next page until `page_count`.
+struct NumberedCommentPages {
+    next_url: String,
+    page_count: u32,
+}
+
+impl wiremock::Respond for NumberedCommentPages {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        let page = request
+            .url
+            .query_pairs()
+            .find(|(name, _)| name == "page")
+            .and_then(|(_, value)| value.parse::<u32>().ok())
+            .unwrap_or(1);
+        let response = ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            { "id": page, "body": format!("Comment {page}"), "user": { "login": "alice" } }
+        ]));
+        if page < self.page_count {
+            let link = format!("<{}?page={}>; rel=\"next\"", self.next_url, page + 1);
+            response.insert_header("Link", link)
+        } else {
+            response
+        }
+    }
+}
+
+#[rstest]
+fn long_comment_listings_are_not_truncated(gateway_fixture: FixtureResult<CachingGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let page_count = 120;
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(ISSUE_COMMENTS_PATH))
+            .respond_with(NumberedCommentPages {
+                next_url: format!("{}{ISSUE_COMMENTS_PATH}", fixture.server.uri()),
+                page_count,
+            })
+            .expect(u64::from(page_count))
+            .mount(&fixture.server),
+    );
+    let gateway = fixture.gateway(86_400).expect("gateway should build");
+
+    let comments = fixture
+        .block_on(gateway.pull_request_comments(&fixture.locator))
+        .expect("comments should load");
+
+    let ids: Vec<_> = comments.iter().map(|comment| comment.id).collect();
+    assert_eq!(ids, (1..=u64::from(page_count)).collect::<Vec<_>>());
+}
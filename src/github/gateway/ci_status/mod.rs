@@ -0,0 +1,209 @@
+//! Gateway for loading CI check runs and commit statuses.
+
+use async_trait::async_trait;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+
+use crate::github::ci_status::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+use crate::github::credentials::GitHubCredentials;
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+
+use super::CiStatusGateway;
+use super::client::build_octocrab_client;
+use super::error_mapping::map_octocrab_error;
+
+/// Page size requested from the check runs and statuses endpoints.
+const PER_PAGE: usize = 100;
+
+/// Upper bound on check run pages fetched for one commit.
+const MAX_CHECK_RUN_PAGES: u32 = 10;
+
+/// Gateway for loading CI results through Octocrab.
+pub struct OctocrabCiStatusGateway {
+    client: Octocrab,
+}
+
+impl OctocrabCiStatusGateway {
+    /// Creates a new gateway for the given credentials and API base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Octocrab client cannot be built.
+    pub fn new(
+        credentials: impl Into<GitHubCredentials>,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let client = build_octocrab_client(&credentials.into(), api_base)?;
+        Ok(Self { client })
+    }
+
+    async fn fetch_check_runs(
+        &self,
+        locator: &PullRequestLocator,
+        commit_sha: &str,
+    ) -> Result<Vec<CiCheck>, IntakeError> {
+        let path = locator.check_runs_path(commit_sha);
+        let mut checks = Vec::new();
+        for page in 1..=MAX_CHECK_RUN_PAGES {
+            let list = self
+                .client
+                .get::<ApiCheckRunList, _, _>(
+                    &path,
+                    Some(&PageQuery {
+                        per_page: PER_PAGE,
+                        page,
+                    }),
+                )
+                .await
+                .map_err(|error| map_octocrab_error("check runs", &error))?;
+            let received = list.check_runs.len();
+            checks.extend(list.check_runs.into_iter().map(CiCheck::from));
+
+            let fetched = u64::try_from(checks.len()).unwrap_or(u64::MAX);
+            if received < PER_PAGE || fetched >= list.total_count {
+                break;
+            }
+        }
+        Ok(checks)
+    }
+
+    async fn fetch_commit_statuses(
+        &self,
+        locator: &PullRequestLocator,
+        commit_sha: &str,
+    ) -> Result<Vec<CiCheck>, IntakeError> {
+        self.client
+            .get::<ApiCombinedStatus, _, _>(
+                locator.combined_status_path(commit_sha),
+                Some(&PageQuery {
+                    per_page: PER_PAGE,
+                    page: 1,
+                }),
+            )
+            .await
+            .map(|combined| combined.statuses.into_iter().map(CiCheck::from).collect())
+            .map_err(|error| map_octocrab_error("commit status", &error))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PageQuery {
+    per_page: usize,
+    page: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiPullRequestHead {
+    head: ApiCommitRef,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCommitRef {
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCheckRunList {
+    #[serde(default)]
+    total_count: u64,
+    #[serde(default)]
+    check_runs: Vec<ApiCheckRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCheckRun {
+    name: String,
+    status: Option<String>,
+    conclusion: Option<String>,
+    details_url: Option<String>,
+    html_url: Option<String>,
+    output: Option<ApiCheckRunOutput>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCheckRunOutput {
+    title: Option<String>,
+    summary: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCombinedStatus {
+    #[serde(default)]
+    statuses: Vec<ApiCommitStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiCommitStatus {
+    context: String,
+    state: Option<String>,
+    description: Option<String>,
+    target_url: Option<String>,
+}
+
+impl From<ApiCheckRun> for CiCheck {
+    fn from(value: ApiCheckRun) -> Self {
+        let outcome =
+            CiOutcome::from_check_run(value.status.as_deref(), value.conclusion.as_deref());
+        let summary = value.output.and_then(|output| {
+            let parts: Vec<_> = [output.title, output.summary]
+                .into_iter()
+                .flatten()
+                .map(|part| part.trim().to_owned())
+                .filter(|part| !part.is_empty())
+                .collect();
+            (!parts.is_empty()).then(|| parts.join("\n"))
+        });
+        Self {
+            name: value.name,
+            kind: CiCheckKind::CheckRun,
+            outcome,
+            state: value.conclusion.or(value.status),
+            summary,
+            details_url: value.details_url.or(value.html_url),
+        }
+    }
+}
+
+impl From<ApiCommitStatus> for CiCheck {
+    fn from(value: ApiCommitStatus) -> Self {
+        Self {
+            name: value.context,
+            kind: CiCheckKind::CommitStatus,
+            outcome: CiOutcome::from_commit_status(value.state.as_deref()),
+            state: value.state,
+            summary: value
+                .description
+                .filter(|description| !description.trim().is_empty()),
+            details_url: value.target_url,
+        }
+    }
+}
+
+#[async_trait]
+impl CiStatusGateway for OctocrabCiStatusGateway {
+    async fn head_ci_status(&self, locator: &PullRequestLocator) -> Result<CiStatus, IntakeError> {
+        let pull_request = self
+            .client
+            .get::<ApiPullRequestHead, _, _>(locator.pull_request_path(), None::<&()>)
+            .await
+            .map_err(|error| map_octocrab_error("pull request head", &error))?;
+        self.commit_ci_status(locator, &pull_request.head.sha).await
+    }
+
+    async fn commit_ci_status(
+        &self,
+        locator: &PullRequestLocator,
+        commit_sha: &str,
+    ) -> Result<CiStatus, IntakeError> {
+        let mut checks = self.fetch_check_runs(locator, commit_sha).await?;
+        checks.extend(self.fetch_commit_statuses(locator, commit_sha).await?);
+        Ok(CiStatus {
+            head_sha: commit_sha.to_owned(),
+            checks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
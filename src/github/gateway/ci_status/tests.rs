@@ -0,0 +1,189 @@
+//! Tests for the CI status gateway.
+
+type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+use rstest::{fixture, rstest};
+use tokio::runtime::Runtime;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::OctocrabCiStatusGateway;
+use crate::github::ci_status::{CiCheckKind, CiOutcome};
+use crate::github::error::IntakeError;
+use crate::github::gateway::CiStatusGateway;
+use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
+
+const HEAD_SHA: &str = "abc123";
+const CHECK_RUNS_PATH: &str = "/api/v3/repos/owner/repo/commits/abc123/check-runs";
+const STATUS_PATH: &str = "/api/v3/repos/owner/repo/commits/abc123/status";
+
+struct CiGatewayFixture {
+    runtime: Runtime,
+    server: MockServer,
+    locator: PullRequestLocator,
+    gateway: OctocrabCiStatusGateway,
+}
+
+impl CiGatewayFixture {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn mount_json(&self, mock_path: &str, body: serde_json::Value) {
+        self.block_on(
+            Mock::given(method("GET"))
+                .and(path(mock_path))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&self.server),
+        );
+    }
+}
+
+#[fixture]
+fn gateway_fixture() -> FixtureResult<CiGatewayFixture> {
+    let token = PersonalAccessToken::new("valid-token")?;
+    let runtime = Runtime::new()?;
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
+    let _guard = runtime.enter();
+    let gateway = OctocrabCiStatusGateway::new(&token, &format!("{}/api/v3", server.uri()))?;
+    Ok(CiGatewayFixture {
+        runtime,
+        server,
+        locator,
+        gateway,
+    })
+}
+
+#[rstest]
+fn head_ci_status_merges_check_runs_and_statuses(gateway_fixture: FixtureResult<CiGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.mount_json(
+        "/api/v3/repos/owner/repo/pulls/42",
+        serde_json::json!({ "number": 42, "head": { "sha": HEAD_SHA } }),
+    );
+    fixture.mount_json(
+        CHECK_RUNS_PATH,
+        serde_json::json!({
+            "total_count": 2,
+            "check_runs": [
+                {
+                    "name": "build",
+                    "status": "completed",
+                    "conclusion": "failure",
+                    "details_url": "https://ci.example/build",
+                    "output": { "title": "2 tests failed", "summary": "see logs" }
+                },
+                { "name": "lint", "status": "in_progress", "conclusion": null }
+            ]
+        }),
+    );
+    fixture.mount_json(
+        STATUS_PATH,
+        serde_json::json!({
+            "state": "success",
+            "statuses": [{
+                "context": "deploy/preview",
+                "state": "success",
+                "description": "Preview ready",
+                "target_url": "https://preview.example"
+            }]
+        }),
+    );
+
+    let status = fixture
+        .block_on(fixture.gateway.head_ci_status(&fixture.locator))
+        .expect("CI status should load");
+
+    assert_eq!(status.head_sha, HEAD_SHA);
+    assert_eq!(status.outcome(), CiOutcome::Failing);
+    let summary: Vec<_> = status
+        .checks
+        .iter()
+        .map(|check| (check.name.as_str(), check.kind, check.outcome))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("build", CiCheckKind::CheckRun, CiOutcome::Failing),
+            ("lint", CiCheckKind::CheckRun, CiOutcome::Pending),
+            (
+                "deploy/preview",
+                CiCheckKind::CommitStatus,
+                CiOutcome::Passing
+            ),
+        ]
+    );
+    let failing = status.failing_checks();
+    assert_eq!(
+        failing.first().and_then(|check| check.summary.as_deref()),
+        Some("2 tests failed\nsee logs")
+    );
+}
+
+#[rstest]
+fn commit_ci_status_follows_check_run_pages(gateway_fixture: FixtureResult<CiGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let first_page: Vec<_> = (0..100)
+        .map(|index| {
+            serde_json::json!({
+                "name": format!("job-{index}"),
+                "status": "completed",
+                "conclusion": "success"
+            })
+        })
+        .collect();
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(CHECK_RUNS_PATH))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 101,
+                "check_runs": [{ "name": "job-100", "status": "queued" }]
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(CHECK_RUNS_PATH))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 101,
+                "check_runs": first_page
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.mount_json(STATUS_PATH, serde_json::json!({ "statuses": [] }));
+
+    let status = fixture
+        .block_on(fixture.gateway.commit_ci_status(&fixture.locator, HEAD_SHA))
+        .expect("CI status should load");
+
+    assert_eq!(status.checks.len(), 101);
+    assert_eq!(status.badge(), "CI: 1/101 pending");
+}
+
+#[rstest]
+fn missing_pull_request_surfaces_api_error(gateway_fixture: FixtureResult<CiGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "message": "Not Found" })),
+            )
+            .mount(&fixture.server),
+    );
+
+    let error = fixture
+        .block_on(fixture.gateway.head_ci_status(&fixture.locator))
+        .expect_err("missing pull request should fail");
+
+    assert!(
+        matches!(&error, IntakeError::Api { message } if message.contains("pull request head")),
+        "unexpected error: {error:?}"
+    );
+}
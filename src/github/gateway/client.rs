@@ -1,36 +1,81 @@
 //! Octocrab client construction helpers for gateway implementations.
 
-use http::Uri;
-use octocrab::Octocrab;
+use bytes::Bytes;
+use http::{Request, Response, Uri};
+use http_body_util::combinators::BoxBody;
+use octocrab::service::middleware::retry::RetryConfig;
+use octocrab::{AuthState, OctoBody, Octocrab, OctocrabBuilder};
+use tower_service::Service;
 
+use crate::github::credentials::GitHubCredentials;
 use crate::github::error::IntakeError;
-use crate::github::locator::PersonalAccessToken;
 
 use super::error_mapping::map_octocrab_error;
+use super::installation_auth::InstallationAuthService;
+use super::retry::{BackoffPolicy, RateLimitRetryService};
 
-/// Builds an Octocrab client for the given token and API base URL.
+/// Builds an Octocrab client for the given credentials and API base URL.
 ///
 /// This helper consolidates the shared logic for parsing the base URI and
-/// constructing an authenticated Octocrab client.
+/// constructing an authenticated Octocrab client. Requests are sent through
+/// the shared rate-limit retry layer, which replaces Octocrab's built-in
+/// retries. GitHub App installations add their token through an
+/// [`InstallationAuthService`] beneath the retry layer, so retried requests
+/// pick up a refreshed token.
 ///
 /// # Errors
 ///
 /// Returns `IntakeError::InvalidUrl` when the base URI cannot be parsed or
 /// `IntakeError::Api` when Octocrab fails to construct a client.
 pub(super) fn build_octocrab_client(
-    token: &PersonalAccessToken,
+    credentials: &GitHubCredentials,
     api_base: &str,
 ) -> Result<Octocrab, IntakeError> {
     let base_uri: Uri = api_base
         .parse::<Uri>()
         .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
 
-    Octocrab::builder()
-        .personal_token(token.as_ref())
+    let unauthenticated = Octocrab::builder().add_retry_config(RetryConfig::None);
+    let builder = match credentials {
+        GitHubCredentials::PersonalAccessToken(token) => {
+            unauthenticated.personal_token(token.as_ref())
+        }
+        GitHubCredentials::AppInstallation(_) => unauthenticated,
+    };
+    let base = builder
         .base_uri(base_uri)
         .map_err(|error| IntakeError::Api {
             message: format!("build client failed: {error}"),
         })?
         .build()
-        .map_err(|error| map_octocrab_error("build client", &error))
+        .map_err(|error| map_octocrab_error("build client", &error))?;
+
+    let inner = match credentials {
+        GitHubCredentials::PersonalAccessToken(_) => base,
+        GitHubCredentials::AppInstallation(installation) => {
+            wrap_service(InstallationAuthService::new(base, installation.clone()))
+        }
+    };
+    Ok(wrap_service(RateLimitRetryService::new(
+        inner,
+        BackoffPolicy::DEFAULT,
+    )))
+}
+
+/// Wraps a request layer in an Octocrab client that adds no auth of its own.
+fn wrap_service<S>(service: S) -> Octocrab
+where
+    S: Service<
+            Request<OctoBody>,
+            Response = Response<BoxBody<Bytes, octocrab::Error>>,
+            Error = octocrab::Error,
+        > + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    OctocrabBuilder::new_empty()
+        .with_service(service)
+        .with_auth(AuthState::None)
+        .build()
+        .unwrap_or_else(|never| match never {})
 }
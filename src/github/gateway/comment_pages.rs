@@ -0,0 +1,276 @@
+//! Conditional, cache-backed paging for comment listings.
+//!
+//! Each page is requested with the `ETag` stored by the previous fetch. A
+//! `304 Not Modified` response reuses the cached body and does not count
+//! against the GitHub rate limit, so refreshing an unchanged listing costs
+//! one free request per page. When the first page names the last one, the
+//! remaining pages are requested concurrently.
+
+use http::header::{ETAG, HeaderMap, IF_NONE_MATCH, LINK};
+use http::{StatusCode, Uri};
+use octocrab::Octocrab;
+use serde::de::DeserializeOwned;
+
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::rate_limit::RateLimitInfo;
+use crate::persistence::{
+    CachedCommentPage, CommentListing, CommentPageCache, CommentPageCacheWrite,
+    PullRequestMetadataCache,
+};
+
+use super::error_mapping::{map_http_error, map_octocrab_error, map_persistence_error};
+use super::http_utils::{extract_github_message, header_to_string, rate_limit_from_headers};
+use super::page_fetch::{fetch_pages_in_order, last_page_from_link, page_concurrency};
+
+/// Page size requested from comment listing endpoints.
+const PER_PAGE: u32 = 100;
+
+/// A comment listing fetched through the page cache.
+pub(super) struct CachedCommentListing<'a> {
+    /// Client used for the conditional requests.
+    pub client: &'a Octocrab,
+    /// Cache holding page bodies and their `ETag`s.
+    pub cache: &'a CommentPageCache,
+    /// Pull request the listing belongs to.
+    pub locator: &'a PullRequestLocator,
+    /// Listing identifier used as part of the cache key.
+    pub listing: CommentListing,
+    /// API path of the listing, with any query parameters other than
+    /// `per_page` and `page`.
+    pub path: String,
+}
+
+/// A page returned by [`CachedCommentListing::fetch_page`].
+struct FetchedPage {
+    /// Page body and paging state, as stored in the cache.
+    cached: CachedCommentPage,
+    /// Number of the listing's last page, when the response named it.
+    last_page: Option<u32>,
+    /// Rate-limit budget reported with the response.
+    rate_limit: Option<RateLimitInfo>,
+}
+
+impl CachedCommentListing<'_> {
+    /// Fetches every page of the listing, reusing unchanged cached pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] when a request fails, GitHub answers with an
+    /// error status, a page cannot be decoded, or the cache cannot be read
+    /// or written.
+    pub(super) async fn fetch_all<T: DeserializeOwned>(&self) -> Result<Vec<T>, IntakeError> {
+        self.fetch_all_with_pages(|_: &[T]| {}).await
+    }
+
+    /// Fetches every page of the listing like [`Self::fetch_all`], passing
+    /// each page's items to `on_page` in page order as they arrive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] for the same failures as [`Self::fetch_all`].
+    pub(super) async fn fetch_all_with_pages<T: DeserializeOwned>(
+        &self,
+        mut on_page: impl FnMut(&[T]) + Send,
+    ) -> Result<Vec<T>, IntakeError> {
+        let mut items = Vec::new();
+        let mut collect = |page: u32, fetched: &CachedCommentPage| {
+            let page_items: Vec<T> = self.decode_page(page, fetched)?;
+            on_page(&page_items);
+            items.extend(page_items);
+            Ok::<_, IntakeError>(fetched.has_next_page)
+        };
+
+        let first = self.fetch_page(1).await?;
+        let mut has_next_page = collect(1, &first.cached)?;
+        let mut page = 1;
+        if has_next_page && let Some(last_page) = first.last_page.filter(|&last| last > 1) {
+            let concurrency = page_concurrency(first.rate_limit.as_ref(), last_page - 1);
+            fetch_pages_in_order(
+                2..=last_page,
+                concurrency,
+                |number| self.fetch_page(number),
+                |number, fetched| {
+                    has_next_page = collect(number, &fetched.cached)?;
+                    Ok(())
+                },
+            )
+            .await?;
+            page = last_page;
+        }
+
+        // Without a last page, or when the listing grew meanwhile, walk on.
+        while has_next_page {
+            page += 1;
+            has_next_page = collect(page, &self.fetch_page(page).await?.cached)?;
+        }
+
+        self.cache
+            .prune_pages_after(self.locator, self.listing, page)
+            .map_err(|error| map_persistence_error("prune comment page cache", &error))?;
+        Ok(items)
+    }
+
+    /// Fetches a resource that is not paginated, such as the pull request
+    /// itself, reusing the cached body when it is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] for the same failures as [`Self::fetch_all`].
+    pub(super) async fn fetch_one<T: DeserializeOwned>(&self) -> Result<T, IntakeError> {
+        let uri = self
+            .path
+            .parse::<Uri>()
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+        let fetched = self.fetch(1, uri).await?;
+        serde_json::from_str(&fetched.cached.body).map_err(|error| IntakeError::Api {
+            message: format!("{} deserialisation failed: {error}", self.label()),
+        })
+    }
+
+    fn decode_page<T: DeserializeOwned>(
+        &self,
+        page: u32,
+        fetched: &CachedCommentPage,
+    ) -> Result<Vec<T>, IntakeError> {
+        serde_json::from_str(&fetched.body).map_err(|error| IntakeError::Api {
+            message: format!(
+                "{} page {page} deserialisation failed: {error}",
+                self.label()
+            ),
+        })
+    }
+
+    async fn fetch_page(&self, page: u32) -> Result<FetchedPage, IntakeError> {
+        let separator = if self.path.contains('?') { '&' } else { '?' };
+        let uri = format!("{}{separator}per_page={PER_PAGE}&page={page}", self.path)
+            .parse::<Uri>()
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+        self.fetch(page, uri).await
+    }
+
+    /// Requests `uri` conditionally and stores the result as page `page`.
+    async fn fetch(&self, page: u32, uri: Uri) -> Result<FetchedPage, IntakeError> {
+        let stored = self
+            .cache
+            .get_page(self.locator, self.listing, page)
+            .map_err(|error| map_persistence_error("read comment page cache", &error))?;
+
+        let response = self
+            .client
+            ._get_with_headers(uri, stored.as_ref().and_then(if_none_match))
+            .await
+            .map_err(|error| map_octocrab_error(self.label(), &error))?;
+
+        let last_page = header_to_string(response.headers().get(LINK))
+            .as_deref()
+            .and_then(last_page_from_link);
+        let rate_limit = rate_limit_from_headers(response.headers());
+        let cached = match (response.status(), stored) {
+            (StatusCode::NOT_MODIFIED, Some(entry)) => entry,
+            (StatusCode::NOT_MODIFIED, None) => {
+                return Err(IntakeError::Api {
+                    message: format!("unexpected 304 for uncached {} page {page}", self.label()),
+                });
+            }
+            (StatusCode::OK, _) => {
+                let etag = header_to_string(response.headers().get(ETAG));
+                let has_next_page = header_to_string(response.headers().get(LINK))
+                    .is_some_and(|link| link.contains("rel=\"next\""));
+                let body = self
+                    .client
+                    .body_to_string(response)
+                    .await
+                    .map_err(|error| IntakeError::Api {
+                        message: format!("{} response decode failed: {error}", self.label()),
+                    })?;
+                self.store_page(page, (etag, body), has_next_page)?
+            }
+            (status, _) => {
+                let body = self
+                    .client
+                    .body_to_string(response)
+                    .await
+                    .unwrap_or_else(|_| String::new());
+                return Err(map_listing_error(
+                    self.label(),
+                    status,
+                    extract_github_message(&body),
+                ));
+            }
+        };
+        Ok(FetchedPage {
+            cached,
+            last_page,
+            rate_limit,
+        })
+    }
+
+    fn store_page(
+        &self,
+        page: u32,
+        (etag, body): (Option<String>, String),
+        has_next_page: bool,
+    ) -> Result<CachedCommentPage, IntakeError> {
+        let fetched_at_unix = PullRequestMetadataCache::now_unix_seconds();
+        self.cache
+            .upsert_page(
+                self.locator,
+                &CommentPageCacheWrite {
+                    listing: self.listing,
+                    page,
+                    etag: etag.as_deref(),
+                    body: &body,
+                    has_next_page,
+                    fetched_at_unix,
+                },
+            )
+            .map_err(|error| map_persistence_error("write comment page cache", &error))?;
+        Ok(CachedCommentPage {
+            etag,
+            body,
+            has_next_page,
+            fetched_at_unix,
+        })
+    }
+
+    const fn label(&self) -> &'static str {
+        match self.listing {
+            CommentListing::ReviewComments | CommentListing::ReviewCommentsSince => {
+                "review comments"
+            }
+            CommentListing::IssueComments => "issue comments",
+            CommentListing::Reviews => "pull request reviews",
+            CommentListing::PullRequest => "pull request",
+        }
+    }
+}
+
+fn if_none_match(cached: &CachedCommentPage) -> Option<HeaderMap> {
+    let value = cached.etag.as_deref()?.parse().ok()?;
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, value);
+    Some(headers)
+}
+
+/// Maps an error status, keeping rate limits distinguishable for callers.
+pub(super) fn map_listing_error(
+    operation: &str,
+    status: StatusCode,
+    maybe_message: Option<String>,
+) -> IntakeError {
+    let is_rate_limited = matches!(
+        status,
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+    ) && maybe_message
+        .as_deref()
+        .is_some_and(|message| message.to_lowercase().contains("rate limit"));
+
+    match maybe_message {
+        Some(message) if is_rate_limited => IntakeError::RateLimitExceeded {
+            rate_limit: None,
+            message: format!("{operation} failed: {message}"),
+        },
+        other => map_http_error(operation, status, other),
+    }
+}
@@ -1,10 +1,12 @@
-//! Shared comment fetching helpers for GitHub gateways.
+//! Shared comment and review fetching helpers for GitHub gateways.
 
 use octocrab::{Octocrab, Page};
 
 use crate::github::error::IntakeError;
 use crate::github::locator::PullRequestLocator;
-use crate::github::models::{ApiComment, PullRequestComment};
+use crate::github::models::{
+    ApiComment, ApiPullRequestReview, PullRequestComment, PullRequestReview,
+};
 
 use super::error_mapping::map_octocrab_error;
 
@@ -23,3 +25,24 @@ pub(super) async fn fetch_pull_request_comments(
         .map(|comments| comments.into_iter().map(ApiComment::into).collect())
         .map_err(|error| map_octocrab_error("issue comments", &error))
 }
+
+pub(super) async fn fetch_pull_request_reviews(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+) -> Result<Vec<PullRequestReview>, IntakeError> {
+    let page = client
+        .get::<Page<ApiPullRequestReview>, _, _>(locator.reviews_path(), None::<&()>)
+        .await
+        .map_err(|error| map_octocrab_error("pull request reviews", &error))?;
+
+    client
+        .all_pages(page)
+        .await
+        .map(|reviews| {
+            reviews
+                .into_iter()
+                .map(ApiPullRequestReview::into)
+                .collect()
+        })
+        .map_err(|error| map_octocrab_error("pull request reviews", &error))
+}
@@ -0,0 +1,137 @@
+//! Gateway for loading pull request changed files and diffs.
+
+use async_trait::async_trait;
+use http::header::{ACCEPT, HeaderMap, HeaderValue};
+use http::{StatusCode, Uri};
+use octocrab::{Octocrab, Page};
+use serde::Deserialize;
+
+use crate::github::credentials::GitHubCredentials;
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::pull_request_diff::{ChangedFile, ChangedFileStatus, PullRequestDiff};
+
+use super::PullRequestDiffGateway;
+use super::client::build_octocrab_client;
+use super::error_mapping::{map_http_error, map_octocrab_error};
+use super::http_utils::extract_github_message;
+
+/// Media type GitHub uses to return a pull request as a unified diff.
+const DIFF_MEDIA_TYPE: &str = "application/vnd.github.v3.diff";
+
+/// Gateway for loading PR changed files and diffs through Octocrab.
+pub struct OctocrabPullRequestDiffGateway {
+    client: Octocrab,
+}
+
+impl OctocrabPullRequestDiffGateway {
+    /// Creates a new gateway for the given credentials and API base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Octocrab client cannot be built.
+    pub fn new(
+        credentials: impl Into<GitHubCredentials>,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let client = build_octocrab_client(&credentials.into(), api_base)?;
+        Ok(Self { client })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiChangedFile {
+    filename: String,
+    previous_filename: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    additions: u64,
+    #[serde(default)]
+    deletions: u64,
+    patch: Option<String>,
+    sha: Option<String>,
+}
+
+impl From<ApiChangedFile> for ChangedFile {
+    fn from(value: ApiChangedFile) -> Self {
+        Self {
+            path: value.filename,
+            previous_path: value.previous_filename,
+            status: value
+                .status
+                .as_deref()
+                .and_then(ChangedFileStatus::from_api_str)
+                .unwrap_or_default(),
+            additions: value.additions,
+            deletions: value.deletions,
+            patch: value.patch,
+            blob_sha: value.sha,
+        }
+    }
+}
+
+#[async_trait]
+impl PullRequestDiffGateway for OctocrabPullRequestDiffGateway {
+    async fn list_changed_files(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<ChangedFile>, IntakeError> {
+        let page = self
+            .client
+            .get::<Page<ApiChangedFile>, _, _>(locator.files_path(), None::<&()>)
+            .await
+            .map_err(|error| map_octocrab_error("changed files", &error))?;
+
+        self.client
+            .all_pages(page)
+            .await
+            .map(|files| files.into_iter().map(ChangedFile::from).collect())
+            .map_err(|error| map_octocrab_error("changed files", &error))
+    }
+
+    async fn unified_diff(&self, locator: &PullRequestLocator) -> Result<String, IntakeError> {
+        let uri: Uri = locator
+            .pull_request_path()
+            .parse::<Uri>()
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(DIFF_MEDIA_TYPE));
+
+        let response = self
+            .client
+            ._get_with_headers(uri, Some(headers))
+            .await
+            .map_err(|error| map_octocrab_error("pull request diff", &error))?;
+        let status = response.status();
+        let body = self.client.body_to_string(response).await;
+
+        match (status, body) {
+            (StatusCode::OK, Ok(diff)) => Ok(diff),
+            (StatusCode::OK, Err(error)) => Err(IntakeError::Api {
+                message: format!("pull request diff response decode failed: {error}"),
+            }),
+            (failure, maybe_body) => Err(map_http_error(
+                "pull request diff",
+                failure,
+                maybe_body
+                    .ok()
+                    .and_then(|text| extract_github_message(&text)),
+            )),
+        }
+    }
+
+    async fn pull_request_diff(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<PullRequestDiff, IntakeError> {
+        let files = self.list_changed_files(locator).await?;
+        let unified_diff = self.unified_diff(locator).await?;
+        Ok(PullRequestDiff {
+            files,
+            unified_diff,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
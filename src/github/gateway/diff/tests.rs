@@ -0,0 +1,168 @@
+//! Tests for the pull request diff gateway.
+
+type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+use rstest::{fixture, rstest};
+use tokio::runtime::Runtime;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::OctocrabPullRequestDiffGateway;
+use crate::github::error::IntakeError;
+use crate::github::gateway::PullRequestDiffGateway;
+use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
+use crate::github::pull_request_diff::ChangedFileStatus;
+
+const FILES_PATH: &str = "/api/v3/repos/owner/repo/pulls/42/files";
+const PULL_PATH: &str = "/api/v3/repos/owner/repo/pulls/42";
+const UNIFIED_DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1 @@\n-old\n+new\n";
+
+struct DiffGatewayFixture {
+    runtime: Runtime,
+    server: MockServer,
+    locator: PullRequestLocator,
+    gateway: OctocrabPullRequestDiffGateway,
+}
+
+impl DiffGatewayFixture {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn mount_file_pages(&self) {
+        let next_url = format!("{}{FILES_PATH}?page=2", self.server.uri());
+        self.block_on(
+            Mock::given(method("GET"))
+                .and(path(FILES_PATH))
+                .and(query_param("page", "2"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                        "filename": "docs/new.md",
+                        "previous_filename": "docs/old.md",
+                        "status": "renamed",
+                        "additions": 0,
+                        "deletions": 0
+                    }])),
+                )
+                .mount(&self.server),
+        );
+        self.block_on(
+            Mock::given(method("GET"))
+                .and(path(FILES_PATH))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!([{
+                            "filename": "src/lib.rs",
+                            "status": "modified",
+                            "additions": 1,
+                            "deletions": 1,
+                            "patch": "@@ -1 +1 @@\n-old\n+new",
+                            "sha": "blob123"
+                        }]))
+                        .insert_header("Link", format!("<{next_url}>; rel=\"next\"")),
+                )
+                .mount(&self.server),
+        );
+    }
+
+    fn mount_unified_diff(&self, template: ResponseTemplate) {
+        self.block_on(
+            Mock::given(method("GET"))
+                .and(path(PULL_PATH))
+                .and(header("accept", "application/vnd.github.v3.diff"))
+                .respond_with(template)
+                .mount(&self.server),
+        );
+    }
+}
+
+#[fixture]
+fn gateway_fixture() -> FixtureResult<DiffGatewayFixture> {
+    let token = PersonalAccessToken::new("valid-token")?;
+    let runtime = Runtime::new()?;
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
+    let _guard = runtime.enter();
+    let gateway = OctocrabPullRequestDiffGateway::new(&token, &format!("{}/api/v3", server.uri()))?;
+    Ok(DiffGatewayFixture {
+        runtime,
+        server,
+        locator,
+        gateway,
+    })
+}
+
+#[rstest]
+fn list_changed_files_follows_pagination(gateway_fixture: FixtureResult<DiffGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.mount_file_pages();
+
+    let files = fixture
+        .block_on(fixture.gateway.list_changed_files(&fixture.locator))
+        .expect("changed files should load");
+
+    let summary: Vec<_> = files
+        .iter()
+        .map(|file| {
+            (
+                file.path.as_str(),
+                file.status,
+                file.previous_path.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("src/lib.rs", ChangedFileStatus::Modified, None),
+            (
+                "docs/new.md",
+                ChangedFileStatus::Renamed,
+                Some("docs/old.md")
+            ),
+        ]
+    );
+    assert_eq!(
+        files.first().and_then(|file| file.blob_sha.as_deref()),
+        Some("blob123")
+    );
+    assert_eq!(files.get(1).and_then(|file| file.patch.as_deref()), None);
+}
+
+#[rstest]
+fn pull_request_diff_combines_files_and_unified_diff(
+    gateway_fixture: FixtureResult<DiffGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.mount_file_pages();
+    fixture.mount_unified_diff(ResponseTemplate::new(200).set_body_string(UNIFIED_DIFF));
+
+    let diff = fixture
+        .block_on(fixture.gateway.pull_request_diff(&fixture.locator))
+        .expect("diff should load");
+
+    assert_eq!(diff.unified_diff, UNIFIED_DIFF);
+    assert_eq!(diff.files.len(), 2);
+    assert_eq!(diff.total_additions(), 1);
+    assert_eq!(
+        diff.file("src/lib.rs").map(|file| file.hunks().len()),
+        Some(1)
+    );
+}
+
+#[rstest]
+fn unified_diff_surfaces_github_errors(gateway_fixture: FixtureResult<DiffGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.mount_unified_diff(ResponseTemplate::new(406).set_body_json(serde_json::json!({
+        "message": "Sorry, the diff exceeded the maximum number of files (300)."
+    })));
+
+    let error = fixture
+        .block_on(fixture.gateway.unified_diff(&fixture.locator))
+        .expect_err("oversized diff should fail");
+
+    assert!(
+        matches!(&error, IntakeError::Api { message } if message.contains("maximum number of files")),
+        "unexpected error: {error:?}"
+    );
+}
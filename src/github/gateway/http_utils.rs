@@ -2,6 +2,7 @@
 
 use http::header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH};
 
+use crate::github::rate_limit::RateLimitInfo;
 use crate::persistence::CachedPullRequestMetadata;
 
 pub(super) fn build_conditional_headers(cached: &CachedPullRequestMetadata) -> Option<HeaderMap> {
@@ -32,6 +33,19 @@ pub(super) fn header_to_string(header_value: Option<&HeaderValue>) -> Option<Str
         .map(ToOwned::to_owned)
 }
 
+/// Reads the `X-RateLimit-*` headers of a response.
+pub(super) fn rate_limit_from_headers(headers: &HeaderMap) -> Option<RateLimitInfo> {
+    let header =
+        |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+    let limit = u32::try_from(header("x-ratelimit-limit")?).ok()?;
+    let remaining = u32::try_from(header("x-ratelimit-remaining")?).ok()?;
+    Some(RateLimitInfo::new(
+        limit,
+        remaining,
+        header("x-ratelimit-reset")?,
+    ))
+}
+
 pub(super) fn extract_github_message(body: &str) -> Option<String> {
     let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
         return None;
@@ -0,0 +1,87 @@
+//! Request layer that authenticates gateway clients as a GitHub App
+//! installation.
+//!
+//! [`InstallationAuthService`] sits between the rate-limit retry layer and an
+//! unauthenticated Octocrab client. Each request is sent with the current
+//! installation token. A `401` response drops the cached token and the
+//! request is sent once more with a freshly minted one, so a token revoked
+//! before its recorded expiry costs one extra round trip rather than a
+//! failed request.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::header::AUTHORIZATION;
+use http::{HeaderValue, Request, Response, StatusCode};
+use http_body_util::combinators::BoxBody;
+use octocrab::{OctoBody, Octocrab};
+use tower_service::Service;
+
+use crate::github::credentials::GitHubAppInstallation;
+
+use super::retry::clone_request;
+
+/// Result of one request through the installation auth layer.
+type ServiceResult = Result<Response<BoxBody<Bytes, octocrab::Error>>, octocrab::Error>;
+
+/// Octocrab service that adds an installation token to every request.
+#[derive(Clone)]
+pub(super) struct InstallationAuthService {
+    inner: Octocrab,
+    installation: GitHubAppInstallation,
+}
+
+impl InstallationAuthService {
+    /// Creates an auth layer over the unauthenticated `inner` client.
+    pub(super) const fn new(inner: Octocrab, installation: GitHubAppInstallation) -> Self {
+        Self {
+            inner,
+            installation,
+        }
+    }
+
+    async fn send(self, request: Request<OctoBody>) -> ServiceResult {
+        let retry = clone_request(&request);
+        let response = self.send_with_token(request).await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+        self.send_with_token(retry).await
+    }
+
+    /// Sends `request` with the current installation token, dropping the
+    /// token when GitHub rejects it.
+    async fn send_with_token(&self, mut request: Request<OctoBody>) -> ServiceResult {
+        let token = self.installation.installation_token().await?;
+        let mut header = HeaderValue::from_str(&format!("Bearer {token}")).map_err(|source| {
+            octocrab::Error::InvalidHeaderValue {
+                source,
+                backtrace: std::backtrace::Backtrace::capture(),
+            }
+        })?;
+        header.set_sensitive(true);
+        request.headers_mut().insert(AUTHORIZATION, header);
+
+        let response = self.inner.execute(request).await?;
+        if response.status() == StatusCode::UNAUTHORIZED {
+            self.installation.invalidate(&token).await;
+        }
+        Ok(response)
+    }
+}
+
+impl Service<Request<OctoBody>> for InstallationAuthService {
+    type Response = Response<BoxBody<Bytes, octocrab::Error>>;
+    type Error = octocrab::Error;
+    type Future = Pin<Box<dyn Future<Output = ServiceResult> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<OctoBody>) -> Self::Future {
+        Box::pin(self.clone().send(request))
+    }
+}
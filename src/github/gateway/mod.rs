@@ -5,27 +5,50 @@
 //! implementations handle real HTTP requests.
 
 mod caching;
+mod ci_status;
 mod client;
+mod comment_pages;
 mod comments;
+mod diff;
 mod error_mapping;
 mod http_utils;
+mod installation_auth;
+mod page_fetch;
 mod pull_request;
 mod repository;
+mod retry;
 mod review_comments;
+mod reviews;
 
 pub use caching::OctocrabCachingGateway;
+pub use ci_status::OctocrabCiStatusGateway;
+pub use diff::OctocrabPullRequestDiffGateway;
 pub use pull_request::OctocrabGateway;
 pub use repository::{
     ListPullRequestsParams, OctocrabRepositoryGateway, PaginatedPullRequests, PullRequestState,
+    SearchPullRequestsParams, SearchScope,
 };
 pub use review_comments::OctocrabReviewCommentGateway;
+pub(crate) use review_comments::{apply_thread_states, log_thread_state_failure};
+pub use reviews::OctocrabPullRequestReviewGateway;
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
 
+use crate::github::ci_status::CiStatus;
 use crate::github::error::IntakeError;
 use crate::github::locator::PullRequestLocator;
-use crate::github::models::{PullRequestComment, PullRequestMetadata, ReviewComment};
+use crate::github::models::{
+    PullRequestComment, PullRequestMetadata, PullRequestReview, ReactionContent, ReactionToggle,
+    ReviewComment, ReviewThreadState,
+};
+use crate::github::pull_request_diff::{ChangedFile, PullRequestDiff};
 use crate::github::repository_locator::RepositoryLocator;
+use crate::github::review_submission::{ReviewSubmission, SubmittedReview};
+
+/// Sending half of a channel receiving review comments page by page.
+pub type ReviewCommentPageSender = tokio::sync::mpsc::UnboundedSender<Vec<ReviewComment>>;
 
 /// Gateway that can load pull request data.
 #[cfg_attr(test, mockall::automock)]
@@ -42,6 +65,12 @@ pub trait PullRequestGateway: Send + Sync {
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Vec<PullRequestComment>, IntakeError>;
+
+    /// Fetch all top-level reviews submitted on the pull request.
+    async fn list_reviews(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<PullRequestReview>, IntakeError>;
 }
 
 /// Gateway for repository-level operations.
@@ -54,9 +83,21 @@ pub trait RepositoryGateway: Send + Sync {
         locator: &RepositoryLocator,
         params: &ListPullRequestsParams,
     ) -> Result<PaginatedPullRequests, IntakeError>;
+
+    /// Search pull requests with GitHub search qualifiers such as
+    /// `review-requested:@me` or `label:bug`, with pagination.
+    async fn search_pull_requests(
+        &self,
+        params: &SearchPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError>;
+
+    /// Returns whether the account `login` is an organization rather than a
+    /// user.
+    async fn is_organization(&self, login: &str) -> Result<bool, IntakeError>;
 }
 
-/// Gateway for fetching PR review comments.
+/// Gateway for fetching, replying to, editing, deleting, reacting to, and
+/// resolving PR review comments.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait ReviewCommentGateway: Send + Sync {
@@ -65,4 +106,154 @@ pub trait ReviewCommentGateway: Send + Sync {
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Vec<ReviewComment>, IntakeError>;
+
+    /// Fetch all review comments like [`Self::list_review_comments`],
+    /// sending each page to `pages` as soon as it arrives.
+    ///
+    /// Pages are sent in listing order and carry no thread state; the
+    /// returned listing is complete and does. Sends to a closed channel are
+    /// ignored, and the sender is dropped once the listing ends.
+    async fn stream_review_comments(
+        &self,
+        locator: &PullRequestLocator,
+        pages: ReviewCommentPageSender,
+    ) -> Result<Vec<ReviewComment>, IntakeError>;
+
+    /// Post a reply into the review thread containing `comment_id`.
+    ///
+    /// Returns the newly created review comment.
+    async fn reply_to_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<ReviewComment, IntakeError>;
+
+    /// Replace the body of the review comment `comment_id`.
+    ///
+    /// Returns the updated review comment. GitHub only allows authors to
+    /// edit their own comments.
+    async fn edit_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<ReviewComment, IntakeError>;
+
+    /// Delete the review comment `comment_id`.
+    ///
+    /// GitHub only allows authors to delete their own comments.
+    async fn delete_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+    ) -> Result<(), IntakeError>;
+
+    /// Fetch the login of the authenticated user, which identifies the
+    /// review comments they may edit or delete.
+    ///
+    /// Fails for GitHub App installation credentials, which do not act as a
+    /// user.
+    async fn authenticated_login(&self) -> Result<String, IntakeError>;
+
+    /// Fetch review comments created or edited at or after `since`.
+    ///
+    /// `since` is an ISO 8601 timestamp. Comments are returned without
+    /// thread state; [`Self::review_thread_states`] supplies it.
+    async fn review_comments_updated_since(
+        &self,
+        locator: &PullRequestLocator,
+        since: &str,
+    ) -> Result<Vec<ReviewComment>, IntakeError>;
+
+    /// Fetch the number of review comments currently on the pull request.
+    async fn review_comment_count(&self, locator: &PullRequestLocator) -> Result<u64, IntakeError>;
+
+    /// Fetch review thread state keyed by the ID of each thread's root
+    /// comment.
+    async fn review_thread_states(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<HashMap<u64, ReviewThreadState>, IntakeError>;
+
+    /// Mark the review thread identified by `thread_id` as resolved or
+    /// unresolved.
+    ///
+    /// `thread_id` is the GraphQL node identifier from
+    /// [`ReviewThreadState::thread_id`]. Returns the updated thread state.
+    async fn set_review_thread_resolution(
+        &self,
+        thread_id: &str,
+        resolved: bool,
+    ) -> Result<ReviewThreadState, IntakeError>;
+
+    /// Add the authenticated user's `content` reaction to the review comment
+    /// `comment_id`, or remove it when they had already reacted with it.
+    ///
+    /// Returns whether the reaction was added or removed.
+    async fn toggle_review_comment_reaction(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        content: ReactionContent,
+    ) -> Result<ReactionToggle, IntakeError>;
+
+    /// Returns whether the authenticated user has reacted to the review
+    /// comment `comment_id` with `content`.
+    async fn has_reacted_to_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        content: ReactionContent,
+    ) -> Result<bool, IntakeError>;
+}
+
+/// Gateway for submitting pull request reviews.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PullRequestReviewGateway: Send + Sync {
+    /// Submit a complete review with its inline comments.
+    ///
+    /// Implementations validate the submission before sending it.
+    async fn submit_review(
+        &self,
+        locator: &PullRequestLocator,
+        submission: &ReviewSubmission,
+    ) -> Result<SubmittedReview, IntakeError>;
+}
+
+/// Gateway for loading the files and diff of a whole pull request.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait PullRequestDiffGateway: Send + Sync {
+    /// List every file changed by the pull request, following pagination.
+    async fn list_changed_files(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<ChangedFile>, IntakeError>;
+
+    /// Fetch the unified diff for the whole pull request.
+    async fn unified_diff(&self, locator: &PullRequestLocator) -> Result<String, IntakeError>;
+
+    /// Fetch the changed files together with the unified diff.
+    async fn pull_request_diff(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<PullRequestDiff, IntakeError>;
+}
+
+/// Gateway for loading CI results for a pull request.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CiStatusGateway: Send + Sync {
+    /// Fetch check runs and commit statuses for the pull request head commit.
+    async fn head_ci_status(&self, locator: &PullRequestLocator) -> Result<CiStatus, IntakeError>;
+
+    /// Fetch check runs and commit statuses for a specific commit in the
+    /// pull request's repository.
+    async fn commit_ci_status(
+        &self,
+        locator: &PullRequestLocator,
+        commit_sha: &str,
+    ) -> Result<CiStatus, IntakeError>;
 }
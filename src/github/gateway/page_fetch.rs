@@ -0,0 +1,186 @@
+//! Concurrent fetching of numbered listing pages.
+//!
+//! GitHub's `Link` header names the last page of a listing, so once the
+//! first page has arrived the remaining pages can be requested together
+//! rather than one after another. Parallelism is bounded, and drops to one
+//! request at a time when the rate-limit budget cannot absorb a burst; the
+//! retry layer then waits out the limit for each request in turn.
+
+use std::future::Future;
+use std::ops::RangeInclusive;
+
+use futures_util::{StreamExt, stream};
+
+use crate::github::rate_limit::RateLimitInfo;
+
+/// Most pages requested at once.
+const MAX_CONCURRENT_PAGES: usize = 4;
+
+/// Requests kept spare in the rate-limit budget before fetching
+/// concurrently, so other callers are not starved by one large listing.
+const RATE_LIMIT_RESERVE: u32 = 100;
+
+/// Returns how many of the `pages` still to fetch may be requested at once.
+///
+/// An unknown budget is treated as sufficient; the retry layer still waits
+/// out any limit that is hit.
+pub(super) fn page_concurrency(rate_limit: Option<&RateLimitInfo>, pages: u32) -> usize {
+    let within_budget =
+        rate_limit.is_none_or(|info| info.remaining() >= pages.saturating_add(RATE_LIMIT_RESERVE));
+    if !within_budget {
+        return 1;
+    }
+    usize::try_from(pages).map_or(MAX_CONCURRENT_PAGES, |count| {
+        count.clamp(1, MAX_CONCURRENT_PAGES)
+    })
+}
+
+/// Reads the page number of the `rel="last"` entry in a `Link` header.
+pub(super) fn last_page_from_link(link: &str) -> Option<u32> {
+    let entry = link
+        .split(',')
+        .find(|entry| entry.contains("rel=\"last\""))?;
+    let (_, after_open) = entry.split_once('<')?;
+    let (target, _) = after_open.split_once('>')?;
+    let (_, query) = target.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("page="))?
+        .parse()
+        .ok()
+}
+
+/// Fetches `pages` with at most `concurrency` requests in flight, handing
+/// each page to `on_page` in page order.
+///
+/// Stops at the first error from either callback; requests still in flight
+/// are dropped.
+pub(super) async fn fetch_pages_in_order<P, E, F, Fut>(
+    pages: RangeInclusive<u32>,
+    concurrency: usize,
+    mut fetch: F,
+    mut on_page: impl FnMut(u32, P) -> Result<(), E>,
+) -> Result<(), E>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<P, E>>,
+{
+    let mut fetched = stream::iter(pages)
+        .map(|page| {
+            let request = fetch(page);
+            async move { (page, request.await) }
+        })
+        .buffered(concurrency.max(1));
+
+    while let Some((page, result)) = fetched.next().await {
+        on_page(page, result?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `page_fetch` module.
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rstest::rstest;
+
+    use super::{fetch_pages_in_order, last_page_from_link, page_concurrency};
+    use crate::github::rate_limit::RateLimitInfo;
+
+    #[rstest]
+    #[case::unknown_budget(None, 10, 4)]
+    #[case::few_pages(None, 2, 2)]
+    #[case::ample_budget(Some(5000), 10, 4)]
+    #[case::low_budget(Some(50), 10, 1)]
+    #[case::budget_within_reserve(Some(105), 10, 1)]
+    fn concurrency_respects_rate_limit(
+        #[case] remaining: Option<u32>,
+        #[case] pages: u32,
+        #[case] expected: usize,
+    ) {
+        let rate_limit = remaining.map(|count| RateLimitInfo::new(5000, count, 1_700_000_000));
+
+        assert_eq!(page_concurrency(rate_limit.as_ref(), pages), expected);
+    }
+
+    #[rstest]
+    #[case::last_present(
+        "<https://api.github.com/repos/o/r/pulls/1/comments?per_page=100&page=2>; rel=\"next\", \
+         <https://api.github.com/repos/o/r/pulls/1/comments?per_page=100&page=14>; rel=\"last\"",
+        Some(14)
+    )]
+    #[case::last_missing(
+        "<https://api.github.com/repos/o/r/pulls/1/comments?page=2>; rel=\"next\"",
+        None
+    )]
+    #[case::malformed("rel=\"last\"", None)]
+    fn last_page_is_read_from_link_header(#[case] link: &str, #[case] expected: Option<u32>) {
+        assert_eq!(last_page_from_link(link), expected);
+    }
+
+    /// Fetches `page`, taking longer for even pages so later pages can
+    /// finish first.
+    async fn tracked_fetch(
+        page: u32,
+        in_flight: &AtomicUsize,
+        peak: &AtomicUsize,
+    ) -> Result<u32, String> {
+        let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        peak.fetch_max(now, Ordering::SeqCst);
+        let yields = if page.is_multiple_of(2) { 2 } else { 1 };
+        for _ in 0..yields {
+            tokio::task::yield_now().await;
+        }
+        in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(page * 10)
+    }
+
+    async fn failing_fetch(page: u32) -> Result<u32, String> {
+        if page == 3 {
+            Err(format!("page {page} failed"))
+        } else {
+            Ok(page)
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_are_delivered_in_order_with_bounded_parallelism() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let mut delivered = Vec::new();
+
+        let result = fetch_pages_in_order(
+            2..=9,
+            3,
+            |page| tracked_fetch(page, &in_flight, &peak),
+            |page, items| {
+                delivered.push((page, items));
+                Ok(())
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            delivered,
+            (2..=9).map(|page| (page, page * 10)).collect::<Vec<_>>()
+        );
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn first_failure_stops_fetching() {
+        let mut delivered = Vec::new();
+
+        let result = fetch_pages_in_order(1..=5, 2, failing_fetch, |page, _| {
+            delivered.push(page);
+            Ok(())
+        })
+        .await;
+
+        assert_eq!(result, Err("page 3 failed".to_owned()));
+        assert_eq!(delivered, vec![1, 2]);
+    }
+}
@@ -3,40 +3,77 @@
 use async_trait::async_trait;
 use octocrab::Octocrab;
 
+use crate::github::credentials::GitHubCredentials;
 use crate::github::error::IntakeError;
-use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
-use crate::github::models::{ApiPullRequest, PullRequestComment, PullRequestMetadata};
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{
+    ApiComment, ApiPullRequest, ApiPullRequestReview, PullRequestComment, PullRequestMetadata,
+    PullRequestReview,
+};
+use crate::persistence::{CommentListing, CommentPageCache};
 
 use super::PullRequestGateway;
 use super::client::build_octocrab_client;
-use super::comments::fetch_pull_request_comments;
+use super::comment_pages::CachedCommentListing;
+use super::comments::{fetch_pull_request_comments, fetch_pull_request_reviews};
 use super::error_mapping::map_octocrab_error;
 
 /// Octocrab-backed gateway.
 pub struct OctocrabGateway {
     client: Octocrab,
+    page_cache: Option<CommentPageCache>,
 }
 
 impl OctocrabGateway {
     /// Creates a new gateway from an Octocrab client.
     #[must_use]
     pub const fn new(client: Octocrab) -> Self {
-        Self { client }
+        Self {
+            client,
+            page_cache: None,
+        }
     }
 
-    /// Builds an Octocrab client for the given token and pull request locator.
+    /// Builds an Octocrab client for the given credentials and pull request locator.
     ///
     /// # Errors
     ///
     /// Returns `IntakeError::InvalidUrl` when the base URI cannot be parsed or
     /// `IntakeError::Api` when Octocrab fails to construct a client.
     pub fn for_token(
-        token: &PersonalAccessToken,
+        credentials: impl Into<GitHubCredentials>,
         locator: &PullRequestLocator,
     ) -> Result<Self, IntakeError> {
-        let octocrab = build_octocrab_client(token, locator.api_base().as_str())?;
+        let octocrab = build_octocrab_client(&credentials.into(), locator.api_base().as_str())?;
         Ok(Self::new(octocrab))
     }
+
+    /// Caches review and conversation comment pages and their `ETag`s in
+    /// `cache`.
+    ///
+    /// Later listings send `If-None-Match` for each cached page, so an
+    /// unchanged listing is revalidated without spending rate-limit quota.
+    #[must_use]
+    pub fn with_page_cache(mut self, cache: CommentPageCache) -> Self {
+        self.page_cache = Some(cache);
+        self
+    }
+
+    /// Returns the cached listing `listing` at `path`, if a cache is set.
+    fn cached_listing<'a>(
+        &'a self,
+        locator: &'a PullRequestLocator,
+        listing: CommentListing,
+        path: String,
+    ) -> Option<CachedCommentListing<'a>> {
+        self.page_cache.as_ref().map(|cache| CachedCommentListing {
+            client: &self.client,
+            cache,
+            locator,
+            listing,
+            path,
+        })
+    }
 }
 
 #[async_trait]
@@ -56,6 +93,30 @@ impl PullRequestGateway for OctocrabGateway {
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Vec<PullRequestComment>, IntakeError> {
-        fetch_pull_request_comments(&self.client, locator).await
+        let path = locator.comments_path();
+        match self.cached_listing(locator, CommentListing::IssueComments, path) {
+            Some(listing) => {
+                let comments: Vec<ApiComment> = listing.fetch_all().await?;
+                Ok(comments.into_iter().map(ApiComment::into).collect())
+            }
+            None => fetch_pull_request_comments(&self.client, locator).await,
+        }
+    }
+
+    async fn list_reviews(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<PullRequestReview>, IntakeError> {
+        let path = locator.reviews_path();
+        match self.cached_listing(locator, CommentListing::Reviews, path) {
+            Some(listing) => {
+                let reviews: Vec<ApiPullRequestReview> = listing.fetch_all().await?;
+                Ok(reviews
+                    .into_iter()
+                    .map(ApiPullRequestReview::into)
+                    .collect())
+            }
+            None => fetch_pull_request_reviews(&self.client, locator).await,
+        }
     }
 }
@@ -1,12 +1,13 @@
-//! Repository-level gateway for listing pull requests.
+//! Repository-level gateway for listing and searching pull requests.
 //!
 //! This module contains the Octocrab-backed repository gateway and its tests.
 
 use async_trait::async_trait;
 use octocrab::{Octocrab, Page};
+use serde::Deserialize;
 
+use crate::github::credentials::GitHubCredentials;
 use crate::github::error::IntakeError;
-use crate::github::locator::PersonalAccessToken;
 use crate::github::models::{ApiPullRequestSummary, PullRequestSummary};
 use crate::github::pagination::PageInfo;
 use crate::github::rate_limit::RateLimitInfo;
@@ -16,9 +17,13 @@ use super::RepositoryGateway;
 use super::client::build_octocrab_client;
 use super::error_mapping::{is_rate_limit_error, map_octocrab_error};
 
+mod search;
 mod types;
 
-pub use types::{ListPullRequestsParams, PaginatedPullRequests, PullRequestState};
+pub use types::{
+    ListPullRequestsParams, PaginatedPullRequests, PullRequestState, SearchPullRequestsParams,
+    SearchScope,
+};
 
 /// Octocrab-backed repository gateway.
 pub struct OctocrabRepositoryGateway {
@@ -32,17 +37,32 @@ impl OctocrabRepositoryGateway {
         Self { client }
     }
 
-    /// Builds an Octocrab client for the given token and repository locator.
+    /// Builds an Octocrab client for the given credentials and repository locator.
     ///
     /// # Errors
     ///
     /// Returns `IntakeError::InvalidUrl` when the base URI cannot be parsed or
     /// `IntakeError::Api` when Octocrab fails to construct a client.
     pub fn for_token(
-        token: &PersonalAccessToken,
+        credentials: impl Into<GitHubCredentials>,
         locator: &RepositoryLocator,
     ) -> Result<Self, IntakeError> {
-        let octocrab = build_octocrab_client(token, locator.api_base().as_str())?;
+        Self::for_api_base(credentials, locator.api_base().as_str())
+    }
+
+    /// Builds an Octocrab client for the given credentials and API base.
+    ///
+    /// Used for searches that are not tied to a single repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntakeError::InvalidUrl` when the base URI cannot be parsed or
+    /// `IntakeError::Api` when Octocrab fails to construct a client.
+    pub fn for_api_base(
+        credentials: impl Into<GitHubCredentials>,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let octocrab = build_octocrab_client(&credentials.into(), api_base)?;
         Ok(Self::new(octocrab))
     }
 }
@@ -105,6 +125,33 @@ impl RepositoryGateway for OctocrabRepositoryGateway {
             rate_limit: None, // Rate limit headers not directly accessible via octocrab.
         })
     }
+
+    async fn search_pull_requests(
+        &self,
+        params: &SearchPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError> {
+        search::search_pull_requests(&self.client, params).await
+    }
+
+    async fn is_organization(&self, login: &str) -> Result<bool, IntakeError> {
+        let path = format!(
+            "/users/{}",
+            url::form_urlencoded::byte_serialize(login.as_bytes()).collect::<String>()
+        );
+        match self.client.get::<ApiAccount, _, _>(path, None::<&()>).await {
+            Ok(account) => Ok(account.kind.eq_ignore_ascii_case("Organization")),
+            Err(error) => Err(self
+                .map_octocrab_error_with_rate_limit("account lookup", &error)
+                .await),
+        }
+    }
+}
+
+/// Account resource fields needed to tell organizations from users.
+#[derive(Debug, Deserialize)]
+struct ApiAccount {
+    #[serde(rename = "type")]
+    kind: String,
 }
 
 impl OctocrabRepositoryGateway {
@@ -0,0 +1,173 @@
+//! Pull request search through the GitHub issue search API.
+//!
+//! Search qualifiers are passed through unchanged; `is:pr` and the scope
+//! qualifier are added by [`SearchPullRequestsParams::search_query`]. The
+//! request is sent without Octocrab's typed search builder so the response
+//! headers, which carry the search API's own rate limit, stay available.
+
+use http::header::LINK;
+use http::{StatusCode, Uri};
+use octocrab::Octocrab;
+
+use crate::github::error::IntakeError;
+use crate::github::models::{ApiSearchResults, PullRequestSummary};
+use crate::github::pagination::PageInfo;
+
+use super::super::comment_pages::map_listing_error;
+use super::super::error_mapping::map_octocrab_error;
+use super::super::http_utils::{header_to_string, rate_limit_from_headers};
+use super::types::{PaginatedPullRequests, SearchPullRequestsParams};
+
+const OPERATION: &str = "search pull requests";
+
+/// GitHub returns at most this many results for one search query.
+const MAX_SEARCH_RESULTS: u64 = 1000;
+
+/// Runs one page of a pull request search.
+pub(super) async fn search_pull_requests(
+    client: &Octocrab,
+    params: &SearchPullRequestsParams,
+) -> Result<PaginatedPullRequests, IntakeError> {
+    let page = params.page.unwrap_or(1);
+    let per_page = params.per_page.unwrap_or(30);
+    super::validate_pagination_params(page, per_page)?;
+
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("q", &params.search_query())
+        .append_pair("sort", "updated")
+        .append_pair("order", "desc")
+        .append_pair("page", &page.to_string())
+        .append_pair("per_page", &per_page.to_string())
+        .finish();
+    let uri = format!("/search/issues?{query}")
+        .parse::<Uri>()
+        .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+
+    let response = client
+        ._get_with_headers(uri, None)
+        .await
+        .map_err(|error| map_octocrab_error(OPERATION, &error))?;
+    let status = response.status();
+    let rate_limit = rate_limit_from_headers(response.headers());
+    let link = header_to_string(response.headers().get(LINK)).unwrap_or_default();
+    let body = client
+        .body_to_string(response)
+        .await
+        .map_err(|error| IntakeError::Api {
+            message: format!("{OPERATION} response decode failed: {error}"),
+        })?;
+
+    if status != StatusCode::OK {
+        return Err(search_error(status, &body));
+    }
+
+    let results: ApiSearchResults =
+        serde_json::from_str(&body).map_err(|error| IntakeError::Api {
+            message: format!("{OPERATION} deserialisation failed: {error}"),
+        })?;
+    let page_info = PageInfo::builder(page, per_page)
+        .total_pages(total_pages(results.total_count, per_page))
+        .has_next(link.contains("rel=\"next\""))
+        .has_prev(link.contains("rel=\"prev\""))
+        .build();
+
+    Ok(PaginatedPullRequests {
+        items: results
+            .items
+            .into_iter()
+            .map(PullRequestSummary::from)
+            .collect(),
+        page_info,
+        rate_limit,
+    })
+}
+
+/// Returns the number of reachable result pages.
+///
+/// GitHub stops serving search results after [`MAX_SEARCH_RESULTS`], so
+/// `total_count` can promise more pages than can be fetched.
+fn total_pages(total_count: u64, per_page: u8) -> Option<u32> {
+    let reachable = total_count.min(MAX_SEARCH_RESULTS);
+    u32::try_from(reachable.div_ceil(u64::from(per_page)).max(1)).ok()
+}
+
+/// Maps an error response, including GitHub's explanation of an invalid
+/// query (for example an unknown user in `author:`).
+fn search_error(status: StatusCode, body: &str) -> IntakeError {
+    let value = serde_json::from_str::<serde_json::Value>(body).ok();
+    let message = value
+        .as_ref()
+        .and_then(|json| json.get("message"))
+        .and_then(serde_json::Value::as_str);
+    let details = value
+        .as_ref()
+        .and_then(|json| json.get("errors"))
+        .and_then(serde_json::Value::as_array)
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|error| error.get("message")?.as_str())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+        .filter(|details| !details.is_empty());
+
+    let combined = match (message, details) {
+        (Some(summary), Some(reasons)) => Some(format!("{summary}: {reasons}")),
+        (summary, reasons) => reasons.or_else(|| summary.map(str::to_owned)),
+    };
+    map_listing_error(OPERATION, status, combined)
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `search` module.
+
+    use http::StatusCode;
+    use rstest::rstest;
+
+    use super::{search_error, total_pages};
+    use crate::github::error::IntakeError;
+
+    #[rstest]
+    #[case::empty(0, 30, Some(1))]
+    #[case::partial_page(31, 30, Some(2))]
+    #[case::capped(5000, 100, Some(10))]
+    fn total_pages_respects_the_search_result_cap(
+        #[case] total_count: u64,
+        #[case] per_page: u8,
+        #[case] expected: Option<u32>,
+    ) {
+        assert_eq!(total_pages(total_count, per_page), expected);
+    }
+
+    #[rstest]
+    fn search_error_includes_validation_details() {
+        let body = r#"{"message":"Validation Failed","errors":[{"message":"The listed users cannot be searched"}]}"#;
+
+        let error = search_error(StatusCode::UNPROCESSABLE_ENTITY, body);
+
+        assert_eq!(
+            error,
+            IntakeError::Api {
+                message: concat!(
+                    "search pull requests failed with status 422 Unprocessable Entity: ",
+                    "Validation Failed: The listed users cannot be searched"
+                )
+                .to_owned(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn search_error_reports_rate_limits() {
+        let body = r#"{"message":"API rate limit exceeded for user ID 1."}"#;
+
+        let error = search_error(StatusCode::FORBIDDEN, body);
+
+        assert!(
+            matches!(error, IntakeError::RateLimitExceeded { .. }),
+            "unexpected error: {error:?}"
+        );
+    }
+}
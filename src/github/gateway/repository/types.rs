@@ -49,6 +49,90 @@ impl Default for ListPullRequestsParams {
     }
 }
 
+/// Where a pull request search looks.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SearchScope {
+    /// Every repository the credentials can see.
+    #[default]
+    All,
+    /// Repositories owned by an organization.
+    Organization(String),
+    /// Repositories owned by a user account.
+    User(String),
+    /// A single repository.
+    Repository {
+        /// Repository owner.
+        owner: String,
+        /// Repository name.
+        repo: String,
+    },
+}
+
+impl SearchScope {
+    /// Returns the search qualifier restricting results to this scope.
+    #[must_use]
+    pub fn qualifier(&self) -> Option<String> {
+        match self {
+            Self::All => None,
+            Self::Organization(org) => Some(format!("org:{org}")),
+            Self::User(user) => Some(format!("user:{user}")),
+            Self::Repository { owner, repo } => Some(format!("repo:{owner}/{repo}")),
+        }
+    }
+}
+
+/// Parameters for searching pull requests with GitHub search qualifiers.
+///
+/// # Example
+///
+/// ```
+/// use frankie::{SearchPullRequestsParams, SearchScope};
+///
+/// let params = SearchPullRequestsParams::new(
+///     "review-requested:@me is:draft",
+///     SearchScope::Organization("octo".to_owned()),
+/// );
+/// assert_eq!(params.search_query(), "is:pr org:octo review-requested:@me is:draft");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPullRequestsParams {
+    /// Search qualifiers and terms, such as `author:octocat label:bug`.
+    pub query: String,
+    /// Repository or organization the search is restricted to.
+    pub scope: SearchScope,
+    /// Page number to fetch (1-based). Defaults to 1.
+    pub page: Option<u32>,
+    /// Items per page (max 100). Defaults to 30.
+    pub per_page: Option<u8>,
+}
+
+impl SearchPullRequestsParams {
+    /// Creates parameters for the first page of results.
+    #[must_use]
+    pub fn new(query: impl Into<String>, scope: SearchScope) -> Self {
+        Self {
+            query: query.into(),
+            scope,
+            page: Some(1),
+            per_page: Some(30),
+        }
+    }
+
+    /// Returns the full `q` parameter sent to the search API.
+    ///
+    /// Always includes `is:pr` so issues are excluded, followed by the scope
+    /// qualifier and the caller's query.
+    #[must_use]
+    pub fn search_query(&self) -> String {
+        ["is:pr".to_owned()]
+            .into_iter()
+            .chain(self.scope.qualifier())
+            .chain(Some(self.query.trim().to_owned()).filter(|query| !query.is_empty()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 /// Paginated pull request listing result.
 #[derive(Debug, Clone)]
 pub struct PaginatedPullRequests {
@@ -58,7 +142,8 @@ pub struct PaginatedPullRequests {
     pub page_info: PageInfo,
     /// Rate limit information when available.
     ///
-    /// This is currently always `None` for successful responses because
+    /// Search results carry the search API's rate limit, read from the
+    /// response headers. Repository listings leave this `None` because
     /// Octocrab does not expose rate limit headers on normal requests. Rate
     /// limit errors are instead mapped to `IntakeError::RateLimitExceeded`
     /// (with optional rate limit data when it can be fetched).
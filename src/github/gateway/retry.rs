@@ -0,0 +1,316 @@
+//! Shared retry layer that waits out GitHub rate limits.
+//!
+//! Every Octocrab gateway client sends its requests through
+//! [`RateLimitRetryService`]. Rate-limited responses (`403` or `429`) are
+//! retried after the wait GitHub asks for via `Retry-After` or
+//! `X-RateLimit-Reset`, or after a jittered exponential backoff for
+//! secondary rate limits that carry no hint. While a retry is waiting it is
+//! published through [`crate::github::rate_limit::pending_backoff`].
+//!
+//! Transport failures and server errors are retried immediately, matching
+//! the behaviour of Octocrab's default retry policy that this layer replaces,
+//! but only for idempotent methods: a `POST` that failed this way may still
+//! have created a comment or applied a mutation on GitHub.
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::BuildHasher;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{HeaderMap, Request, Response, StatusCode};
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full};
+use octocrab::{OctoBody, Octocrab};
+use tower_service::Service;
+
+use crate::github::rate_limit::{BackoffReason, PENDING_BACKOFF, PendingBackoff, unix_now_ms};
+
+/// Response body type produced by Octocrab services.
+type ResponseBody = BoxBody<Bytes, octocrab::Error>;
+
+/// Result of one request through the retry layer.
+type ServiceResult = Result<Response<ResponseBody>, octocrab::Error>;
+
+/// Retry limits applied by [`RateLimitRetryService`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct BackoffPolicy {
+    /// Maximum retries for one request.
+    pub max_retries: u32,
+    /// First delay for secondary rate limits without a reset hint.
+    pub base_delay: Duration,
+    /// Cap on the exponential delay, before jitter is applied.
+    pub max_delay: Duration,
+    /// Longest wait requested by GitHub that is honoured before giving up.
+    pub max_wait: Duration,
+}
+
+impl BackoffPolicy {
+    /// Policy used by every gateway client.
+    ///
+    /// GitHub asks clients hitting a secondary rate limit without a reset
+    /// hint to wait at least one minute and back off exponentially. Waits
+    /// longer than ten minutes fail the request rather than blocking a
+    /// command for up to an hour until a primary limit resets.
+    #[cfg(not(test))]
+    pub(super) const DEFAULT: Self = Self {
+        max_retries: 3,
+        base_delay: Duration::from_secs(60),
+        max_delay: Duration::from_secs(600),
+        max_wait: Duration::from_secs(600),
+    };
+
+    /// Unit-test policy with millisecond delays, so gateway tests that mock
+    /// rate-limit errors stay fast.
+    #[cfg(test)]
+    pub(super) const DEFAULT: Self = Self {
+        max_retries: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        max_wait: Duration::from_secs(600),
+    };
+
+    /// Decides how long to wait before retrying a rate-limited response.
+    ///
+    /// `attempt` is the 0-based number of retries already made and
+    /// `mentions_rate_limit` reports whether the body names a rate limit.
+    /// Returns `None` when the response should be passed to the caller.
+    fn rate_limit_delay(
+        &self,
+        (status, headers): (StatusCode, &HeaderMap),
+        mentions_rate_limit: bool,
+        attempt: u32,
+    ) -> Option<(Duration, BackoffReason)> {
+        if !is_rate_limit_status(status) {
+            return None;
+        }
+
+        let (delay, reason) = if let Some(seconds) = header_u64(headers, "retry-after") {
+            (Duration::from_secs(seconds), BackoffReason::RetryAfter)
+        } else if let Some(reset_at) = exhausted_reset_at(headers) {
+            let now = Duration::from_millis(unix_now_ms()).as_secs();
+            let wait = reset_at.saturating_sub(now).saturating_add(1);
+            (Duration::from_secs(wait), BackoffReason::RateLimitReset)
+        } else if status == StatusCode::TOO_MANY_REQUESTS || mentions_rate_limit {
+            (
+                self.jittered_delay(attempt, random_u64()),
+                BackoffReason::SecondaryRateLimit,
+            )
+        } else {
+            return None;
+        };
+
+        (delay <= self.max_wait).then_some((delay, reason))
+    }
+
+    /// Returns the exponential delay for `attempt` with equal jitter.
+    ///
+    /// The delay doubles from `base_delay` up to `max_delay`; the result is
+    /// drawn uniformly from the upper half of that window using `random`.
+    fn jittered_delay(&self, attempt: u32, random: u64) -> Duration {
+        let factor = 1_u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        let window = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        let half = window / 2;
+        let half_ms = u64::try_from(half.as_millis()).unwrap_or(u64::MAX);
+        let scaled = u128::from(random) * u128::from(half_ms.saturating_add(1));
+        let jitter_ms = u64::try_from(scaled >> 64).unwrap_or(half_ms);
+        half.saturating_add(Duration::from_millis(jitter_ms))
+    }
+}
+
+/// What to do with a response received by the retry layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RetryDecision {
+    /// Hand the response to the caller.
+    Return,
+    /// Retry straight away, as for server errors on idempotent requests.
+    Immediately,
+    /// Wait out a rate limit before retrying.
+    After(Duration, BackoffReason),
+}
+
+impl From<Option<(Duration, BackoffReason)>> for RetryDecision {
+    fn from(delay: Option<(Duration, BackoffReason)>) -> Self {
+        delay.map_or(Self::Return, |(wait, reason)| Self::After(wait, reason))
+    }
+}
+
+/// Octocrab service that retries rate-limited and failed requests.
+///
+/// Wraps a fully configured [`Octocrab`] client, so base URI resolution,
+/// authentication, and default headers are applied on every attempt.
+#[derive(Clone)]
+pub(super) struct RateLimitRetryService {
+    inner: Octocrab,
+    policy: BackoffPolicy,
+}
+
+impl RateLimitRetryService {
+    /// Creates a retry layer over `inner`.
+    pub(super) const fn new(inner: Octocrab, policy: BackoffPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    async fn send(self, mut request: Request<OctoBody>) -> ServiceResult {
+        let idempotent = request.method().is_idempotent();
+        for attempt in 0..self.policy.max_retries {
+            let retry_request = clone_request(&request);
+            let received = match self.inner.execute(request).await {
+                Err(error) if idempotent && is_transient_error(&error) => {
+                    request = retry_request;
+                    continue;
+                }
+                result => result?,
+            };
+            let (response, decision) = self.inspect(received, attempt).await?;
+            match decision {
+                RetryDecision::Return => return Ok(response),
+                RetryDecision::Immediately if !idempotent => return Ok(response),
+                RetryDecision::Immediately => {}
+                RetryDecision::After(delay, reason) => {
+                    self.wait_out(delay, reason, attempt).await;
+                }
+            }
+            request = retry_request;
+        }
+
+        self.inner.execute(request).await
+    }
+
+    /// Decides whether `response` should be retried.
+    ///
+    /// Bodies are only buffered for `403` responses without a reset hint, to
+    /// tell secondary rate limits apart from permission failures.
+    async fn inspect(
+        &self,
+        response: Response<ResponseBody>,
+        attempt: u32,
+    ) -> Result<(Response<ResponseBody>, RetryDecision), octocrab::Error> {
+        let status = response.status();
+        if status.is_server_error() {
+            return Ok((response, RetryDecision::Immediately));
+        }
+        if status != StatusCode::FORBIDDEN || has_reset_hint(response.headers()) {
+            let delay = self
+                .policy
+                .rate_limit_delay((status, response.headers()), false, attempt);
+            return Ok((response, RetryDecision::from(delay)));
+        }
+
+        let (parts, body) = response.into_parts();
+        let bytes = body.collect().await?.to_bytes();
+        let mentions_rate_limit = String::from_utf8_lossy(&bytes)
+            .to_lowercase()
+            .contains("rate limit");
+        let delay =
+            self.policy
+                .rate_limit_delay((status, &parts.headers), mentions_rate_limit, attempt);
+        let buffered = Full::new(bytes).map_err(|never| match never {}).boxed();
+        Ok((
+            Response::from_parts(parts, buffered),
+            RetryDecision::from(delay),
+        ))
+    }
+
+    async fn wait_out(&self, delay: Duration, reason: BackoffReason, attempt: u32) {
+        let delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let backoff = PendingBackoff::new(
+            reason,
+            attempt.saturating_add(1),
+            self.policy.max_retries,
+            unix_now_ms().saturating_add(delay_ms),
+        );
+        let _guard = BackoffGuard::begin(backoff);
+        tokio::time::sleep(delay).await;
+    }
+}
+
+impl Service<Request<OctoBody>> for RateLimitRetryService {
+    type Response = Response<ResponseBody>;
+    type Error = octocrab::Error;
+    type Future = Pin<Box<dyn Future<Output = ServiceResult> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<OctoBody>) -> Self::Future {
+        Box::pin(self.clone().send(request))
+    }
+}
+
+/// Publishes a pending backoff for as long as the guard is alive.
+///
+/// Clearing on drop keeps the published state accurate when a waiting
+/// request is cancelled.
+struct BackoffGuard(PendingBackoff);
+
+impl BackoffGuard {
+    fn begin(backoff: PendingBackoff) -> Self {
+        PENDING_BACKOFF.begin(backoff);
+        Self(backoff)
+    }
+}
+
+impl Drop for BackoffGuard {
+    fn drop(&mut self) {
+        PENDING_BACKOFF.end(self.0);
+    }
+}
+
+const fn is_rate_limit_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+    )
+}
+
+const fn is_transient_error(error: &octocrab::Error) -> bool {
+    matches!(
+        error,
+        octocrab::Error::Http { .. }
+            | octocrab::Error::Hyper { .. }
+            | octocrab::Error::Service { .. }
+    )
+}
+
+fn has_reset_hint(headers: &HeaderMap) -> bool {
+    headers.contains_key("retry-after") || exhausted_reset_at(headers).is_some()
+}
+
+/// Returns the reset time when `X-RateLimit-Remaining` reports exhaustion.
+fn exhausted_reset_at(headers: &HeaderMap) -> Option<u64> {
+    (header_u64(headers, "x-ratelimit-remaining")? == 0)
+        .then(|| header_u64(headers, "x-ratelimit-reset"))
+        .flatten()
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Returns a random value for jitter without an extra dependency.
+///
+/// Each `RandomState` is seeded with fresh per-process randomness.
+fn random_u64() -> u64 {
+    RandomState::new().hash_one(unix_now_ms())
+}
+
+/// Copies a request so it can be sent again.
+///
+/// Octocrab buffers every body it builds, so only empty bodies fail to
+/// clone; those are replaced with a fresh empty body.
+pub(super) fn clone_request(request: &Request<OctoBody>) -> Request<OctoBody> {
+    let mut clone = Request::new(request.body().try_clone().unwrap_or_else(OctoBody::empty));
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+#[cfg(test)]
+#[path = "retry_tests.rs"]
+mod tests;
@@ -0,0 +1,219 @@
+//! Unit tests for the rate-limit retry policy.
+
+use std::time::Duration;
+
+use http::{HeaderMap, HeaderValue, StatusCode};
+use rstest::rstest;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::BackoffPolicy;
+use crate::github::gateway::client::build_octocrab_client;
+use crate::github::locator::PersonalAccessToken;
+use crate::github::rate_limit::{BackoffReason, unix_now_ms};
+
+const REPO_PATH: &str = "/api/v3/repos/owner/repo";
+
+/// Production-shaped policy for checking delay calculations.
+const POLICY: BackoffPolicy = BackoffPolicy {
+    max_retries: 3,
+    base_delay: Duration::from_secs(60),
+    max_delay: Duration::from_secs(600),
+    max_wait: Duration::from_secs(600),
+};
+
+/// Mounts `first` for one request, then a successful repository body.
+async fn mount_then_succeed(server: &MockServer, first: ResponseTemplate) {
+    Mock::given(method("GET"))
+        .and(path(REPO_PATH))
+        .respond_with(first)
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(REPO_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "name": "repo" })),
+        )
+        .mount(server)
+        .await;
+}
+
+fn client_for(server: &MockServer) -> octocrab::Octocrab {
+    let token = PersonalAccessToken::new("valid-token").expect("token should be valid");
+    build_octocrab_client(&token.into(), &format!("{}/api/v3", server.uri()))
+        .expect("client should build")
+}
+
+async fn get_repo(server: &MockServer) -> Result<serde_json::Value, octocrab::Error> {
+    client_for(server)
+        .get(REPO_PATH.trim_start_matches("/api/v3"), None::<&()>)
+        .await
+}
+
+fn headers(pairs: &[(&'static str, String)]) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, raw) in pairs {
+        if let Ok(value) = HeaderValue::from_str(raw) {
+            map.insert(*name, value);
+        }
+    }
+    map
+}
+
+#[rstest]
+fn retry_after_header_sets_the_wait() {
+    let delay = POLICY.rate_limit_delay(
+        (
+            StatusCode::FORBIDDEN,
+            &headers(&[("retry-after", "30".to_owned())]),
+        ),
+        false,
+        0,
+    );
+
+    assert_eq!(
+        delay,
+        Some((Duration::from_secs(30), BackoffReason::RetryAfter))
+    );
+}
+
+#[rstest]
+fn exhausted_primary_limit_waits_for_reset() {
+    let now = Duration::from_millis(unix_now_ms()).as_secs();
+    let delay = POLICY.rate_limit_delay(
+        (
+            StatusCode::FORBIDDEN,
+            &headers(&[
+                ("x-ratelimit-remaining", "0".to_owned()),
+                ("x-ratelimit-reset", (now + 20).to_string()),
+            ]),
+        ),
+        false,
+        0,
+    );
+
+    let (wait, reason) = delay.expect("exhausted limit should back off");
+    assert_eq!(reason, BackoffReason::RateLimitReset);
+    assert!((20..=21).contains(&wait.as_secs()), "wait was {wait:?}");
+}
+
+#[rstest]
+#[case::plain_forbidden(StatusCode::FORBIDDEN, false, false)]
+#[case::secondary_forbidden(StatusCode::FORBIDDEN, true, true)]
+#[case::too_many_requests(StatusCode::TOO_MANY_REQUESTS, false, true)]
+#[case::not_found(StatusCode::NOT_FOUND, true, false)]
+fn only_rate_limits_are_retried(
+    #[case] status: StatusCode,
+    #[case] mentions_rate_limit: bool,
+    #[case] retried: bool,
+) {
+    let delay = POLICY.rate_limit_delay((status, &HeaderMap::new()), mentions_rate_limit, 0);
+
+    assert_eq!(delay.is_some(), retried);
+}
+
+#[rstest]
+fn waits_beyond_the_limit_are_not_honoured() {
+    let delay = POLICY.rate_limit_delay(
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers(&[("retry-after", "900".to_owned())]),
+        ),
+        false,
+        0,
+    );
+
+    assert_eq!(delay, None);
+}
+
+#[rstest]
+#[case::first(0, 0, Duration::from_secs(30))]
+#[case::first_max_jitter(0, u64::MAX, Duration::from_secs(60))]
+#[case::second(1, 0, Duration::from_secs(60))]
+#[case::capped(10, 0, Duration::from_secs(300))]
+fn jittered_delay_doubles_within_the_cap(
+    #[case] attempt: u32,
+    #[case] random: u64,
+    #[case] expected: Duration,
+) {
+    let delay = POLICY.jittered_delay(attempt, random);
+
+    let difference = delay.abs_diff(expected);
+    assert!(
+        difference < Duration::from_millis(1),
+        "{delay:?} != {expected:?}"
+    );
+}
+
+#[tokio::test]
+async fn client_retries_after_retry_after_response() {
+    let server = MockServer::start().await;
+    mount_then_succeed(
+        &server,
+        ResponseTemplate::new(429)
+            .insert_header("retry-after", "0")
+            .set_body_json(serde_json::json!({ "message": "slow down" })),
+    )
+    .await;
+
+    let repo = get_repo(&server).await.expect("retry should succeed");
+
+    assert_eq!(repo.get("name"), Some(&serde_json::json!("repo")));
+}
+
+#[tokio::test]
+async fn client_returns_plain_forbidden_without_retrying() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(REPO_PATH))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_json(serde_json::json!({ "message": "Resource not accessible" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = get_repo(&server)
+        .await
+        .expect_err("forbidden should not be retried");
+
+    assert!(
+        matches!(&error, octocrab::Error::GitHub { source, .. }
+            if source.message == "Resource not accessible"),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn client_retries_server_errors_for_idempotent_requests() {
+    let server = MockServer::start().await;
+    mount_then_succeed(&server, ResponseTemplate::new(502)).await;
+
+    let repo = get_repo(&server).await.expect("retry should succeed");
+
+    assert_eq!(repo.get("name"), Some(&serde_json::json!("repo")));
+}
+
+#[tokio::test]
+async fn client_does_not_retry_server_errors_for_posts() {
+    let server = MockServer::start().await;
+    let replies_path = format!("{REPO_PATH}/pulls/1/comments/7/replies");
+    Mock::given(method("POST"))
+        .and(path(replies_path.as_str()))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result: Result<serde_json::Value, octocrab::Error> = client_for(&server)
+        .post(
+            replies_path.trim_start_matches("/api/v3"),
+            Some(&serde_json::json!({ "body": "Thanks" })),
+        )
+        .await;
+
+    assert!(result.is_err(), "a failed POST should not be retried");
+}
@@ -0,0 +1,64 @@
+//! Editing and deleting review comments.
+//!
+//! Both operations target the comment resource directly rather than the
+//! pull request, so they work for replies and thread roots alike. Deletion
+//! uses a raw request because GitHub answers with an empty `204 No Content`
+//! body that cannot be deserialised.
+
+use http::{StatusCode, Uri};
+use octocrab::Octocrab;
+
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ApiReviewComment, ReviewComment};
+
+use super::super::comment_pages::map_listing_error;
+use super::super::error_mapping::map_octocrab_error;
+use super::super::http_utils::extract_github_message;
+
+const EDIT_OPERATION: &str = "edit review comment";
+const DELETE_OPERATION: &str = "delete review comment";
+
+/// Replaces the body of the review comment `comment_id`.
+pub(super) async fn edit_comment(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+    comment_id: u64,
+    body: &str,
+) -> Result<ReviewComment, IntakeError> {
+    let payload = serde_json::json!({ "body": body });
+    client
+        .patch::<ApiReviewComment, _, _>(locator.review_comment_path(comment_id), Some(&payload))
+        .await
+        .map(Into::into)
+        .map_err(|error| map_octocrab_error(EDIT_OPERATION, &error))
+}
+
+/// Deletes the review comment `comment_id`.
+pub(super) async fn delete_comment(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+    comment_id: u64,
+) -> Result<(), IntakeError> {
+    let uri = locator
+        .review_comment_path(comment_id)
+        .parse::<Uri>()
+        .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+    let response = client
+        ._delete(uri, None::<&()>)
+        .await
+        .map_err(|error| map_octocrab_error(DELETE_OPERATION, &error))?;
+    let status = response.status();
+    if status == StatusCode::NO_CONTENT {
+        return Ok(());
+    }
+    let body = client
+        .body_to_string(response)
+        .await
+        .unwrap_or_else(|_| String::new());
+    Err(map_listing_error(
+        DELETE_OPERATION,
+        status,
+        extract_github_message(&body),
+    ))
+}
@@ -0,0 +1,245 @@
+//! Listing review comments page by page.
+//!
+//! Full listings fetch the first page, then the remaining pages
+//! concurrently once the first names the last one, falling back to
+//! following `next` links. Incremental listings request only comments
+//! updated since a checkpoint. With a page cache attached, every page is
+//! requested conditionally instead.
+
+use octocrab::Page;
+use serde::{Deserialize, Serialize};
+
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ApiReviewComment, ReviewComment};
+use crate::persistence::CommentListing;
+
+use super::super::ReviewCommentPageSender;
+use super::super::comment_pages::CachedCommentListing;
+use super::super::page_fetch::{fetch_pages_in_order, page_concurrency};
+use super::OctocrabReviewCommentGateway;
+
+impl OctocrabReviewCommentGateway {
+    /// Fetches every page of review comments from the REST API.
+    pub(super) async fn fetch_rest_review_comments(
+        &self,
+        locator: &PullRequestLocator,
+        pages: Option<&ReviewCommentPageSender>,
+    ) -> Result<Vec<ReviewComment>, IntakeError> {
+        let comments: Vec<ApiReviewComment> = if let Some(cache) = self.page_cache.as_ref() {
+            let listing = CachedCommentListing {
+                client: &self.client,
+                cache,
+                locator,
+                listing: CommentListing::ReviewComments,
+                path: locator.review_comments_path(),
+            };
+            listing
+                .fetch_all_with_pages(|page| send_page(pages, page))
+                .await?
+        } else {
+            match self.fetch_uncached_review_comments(locator, pages).await {
+                Ok(comments) => comments,
+                Err(error) => {
+                    return Err(self
+                        .map_octocrab_error_with_rate_limit("review comments", &error)
+                        .await);
+                }
+            }
+        };
+        Ok(comments.into_iter().map(Into::into).collect())
+    }
+
+    /// Fetches every page of review comments without the page cache.
+    ///
+    /// When the first page names the last one, the pages in between are
+    /// fetched concurrently within the budget reported by the rate limit
+    /// endpoint. Otherwise, or if the listing grew meanwhile, the `next`
+    /// links are followed one page at a time.
+    async fn fetch_uncached_review_comments(
+        &self,
+        locator: &PullRequestLocator,
+        pages: Option<&ReviewCommentPageSender>,
+    ) -> Result<Vec<ApiReviewComment>, octocrab::Error> {
+        let path = locator.review_comments_path();
+        let mut first: Page<ApiReviewComment> =
+            self.client.get(&path, Some(&PageQuery::new(1))).await?;
+        let mut comments = first.take_items();
+        send_page(pages, &comments);
+        let Some(last_page) = first.number_of_pages().filter(|&last| last > 1) else {
+            return self.follow_next_pages(first, comments, pages).await;
+        };
+
+        let rate_limit = self.fetch_rate_limit_info().await;
+        let mut trailing = None;
+        fetch_pages_in_order(
+            2..=last_page,
+            page_concurrency(rate_limit.as_ref(), last_page - 1),
+            |number| {
+                let (client, page_path) = (&self.client, &path);
+                async move {
+                    client
+                        .get::<Page<ApiReviewComment>, _, _>(
+                            page_path,
+                            Some(&PageQuery::new(number)),
+                        )
+                        .await
+                }
+            },
+            |_, mut page| {
+                let items = page.take_items();
+                send_page(pages, &items);
+                comments.extend(items);
+                trailing = Some(page);
+                Ok(())
+            },
+        )
+        .await?;
+
+        match trailing {
+            Some(page) => self.follow_next_pages(page, comments, pages).await,
+            None => Ok(comments),
+        }
+    }
+
+    /// Appends the pages after `page`, whose items were already taken, by
+    /// following its `next` links.
+    async fn follow_next_pages(
+        &self,
+        page: Page<ApiReviewComment>,
+        mut comments: Vec<ApiReviewComment>,
+        pages: Option<&ReviewCommentPageSender>,
+    ) -> Result<Vec<ApiReviewComment>, octocrab::Error> {
+        if page.next.is_none() {
+            return Ok(comments);
+        }
+        let rest = self.client.all_pages(page).await?;
+        send_page(pages, &rest);
+        comments.extend(rest);
+        Ok(comments)
+    }
+
+    /// Fetches every page of review comments updated at or after `since`.
+    ///
+    /// With a page cache attached the pages are requested conditionally, so
+    /// a sync where nothing changed costs no rate-limit quota.
+    pub(super) async fn fetch_review_comments_since(
+        &self,
+        locator: &PullRequestLocator,
+        since: &str,
+    ) -> Result<Vec<ReviewComment>, IntakeError> {
+        if let Some(cache) = self.page_cache.as_ref() {
+            let listing = CachedCommentListing {
+                client: &self.client,
+                cache,
+                locator,
+                listing: CommentListing::ReviewCommentsSince,
+                path: format!(
+                    "{}?since={}&sort=updated&direction=asc",
+                    locator.review_comments_path(),
+                    url::form_urlencoded::byte_serialize(since.as_bytes()).collect::<String>()
+                ),
+            };
+            let comments: Vec<ApiReviewComment> = listing.fetch_all().await?;
+            return Ok(comments.into_iter().map(Into::into).collect());
+        }
+
+        let query = SinceQuery {
+            since,
+            sort: "updated",
+            direction: "asc",
+            per_page: 100,
+        };
+        let page: Page<ApiReviewComment> = match self
+            .client
+            .get(locator.review_comments_path(), Some(&query))
+            .await
+        {
+            Ok(page) => page,
+            Err(error) => {
+                return Err(self
+                    .map_octocrab_error_with_rate_limit("review comments", &error)
+                    .await);
+            }
+        };
+
+        match self.client.all_pages(page).await {
+            Ok(comments) => Ok(comments.into_iter().map(Into::into).collect()),
+            Err(error) => Err(self
+                .map_octocrab_error_with_rate_limit("review comments", &error)
+                .await),
+        }
+    }
+
+    /// Reads the review comment count from the pull request resource.
+    ///
+    /// With a page cache attached the resource is requested conditionally.
+    pub(super) async fn fetch_review_comment_count(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<u64, IntakeError> {
+        if let Some(cache) = self.page_cache.as_ref() {
+            let resource = CachedCommentListing {
+                client: &self.client,
+                cache,
+                locator,
+                listing: CommentListing::PullRequest,
+                path: locator.pull_request_path(),
+            };
+            let pull_request: ApiReviewCommentCount = resource.fetch_one().await?;
+            return Ok(pull_request.review_comments);
+        }
+
+        match self
+            .client
+            .get::<ApiReviewCommentCount, _, _>(locator.pull_request_path(), None::<&()>)
+            .await
+        {
+            Ok(pull_request) => Ok(pull_request.review_comments),
+            Err(error) => Err(self
+                .map_octocrab_error_with_rate_limit("review comment count", &error)
+                .await),
+        }
+    }
+}
+
+/// Query parameters selecting one page of a listing.
+#[derive(Debug, Serialize)]
+struct PageQuery {
+    per_page: u8,
+    page: u32,
+}
+
+impl PageQuery {
+    const fn new(page: u32) -> Self {
+        Self {
+            per_page: 100,
+            page,
+        }
+    }
+}
+
+/// Query parameters for listing review comments updated since a timestamp.
+#[derive(Debug, Serialize)]
+struct SinceQuery<'a> {
+    since: &'a str,
+    sort: &'static str,
+    direction: &'static str,
+    per_page: u8,
+}
+
+/// Pull request fields needed to count review comments.
+#[derive(Debug, Deserialize)]
+struct ApiReviewCommentCount {
+    #[serde(default)]
+    review_comments: u64,
+}
+
+/// Sends a page of comments to `pages`, if a receiver is still listening.
+fn send_page(pages: Option<&ReviewCommentPageSender>, page: &[ApiReviewComment]) {
+    if let Some(sender) = pages
+        && !sender.is_closed()
+    {
+        drop(sender.send(page.iter().cloned().map(Into::into).collect()));
+    }
+}
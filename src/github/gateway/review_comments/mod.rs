@@ -1,42 +1,131 @@
-//! Gateway for fetching PR review comments.
+//! Gateway for fetching, replying to, editing, deleting, reacting to, and
+//! resolving PR review comments.
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
-use octocrab::{Octocrab, Page};
+use octocrab::Octocrab;
+use serde::Deserialize;
 
+use crate::github::credentials::GitHubCredentials;
 use crate::github::error::IntakeError;
-use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
-use crate::github::models::{ApiReviewComment, ReviewComment};
+use crate::github::locator::{PullRequestLocator, graphql_base_for_api_base};
+use crate::github::models::{
+    ApiReviewComment, ReactionContent, ReactionToggle, ReviewComment, ReviewThreadState,
+};
 use crate::github::rate_limit::RateLimitInfo;
+use crate::persistence::{CommentPageCache, PersistenceError, ReviewCommentArchive};
 
-use super::ReviewCommentGateway;
 use super::client::build_octocrab_client;
 use super::error_mapping::{is_rate_limit_error, map_octocrab_error};
+use super::{ReviewCommentGateway, ReviewCommentPageSender};
+
+mod edits;
+mod listing;
+mod reactions;
+mod threads;
+
+pub(crate) use threads::{apply_thread_states, log_thread_state_failure};
 
 /// Gateway for loading PR review comments through Octocrab.
+///
+/// Review comments are fetched from the REST API and then annotated with
+/// thread resolution state loaded from the GraphQL API. Once the first page
+/// reveals how many there are, the remaining pages are fetched
+/// concurrently. With a page cache
+/// attached, REST pages are requested conditionally and unchanged pages are
+/// served from `SQLite`. With an archive attached, every comment fetched or
+/// posted is also written to the `review_comments` table for offline reading.
 pub struct OctocrabReviewCommentGateway {
     client: Octocrab,
+    graphql_client: Octocrab,
+    page_cache: Option<CommentPageCache>,
+    archive: Option<ReviewCommentArchive>,
 }
 
 impl OctocrabReviewCommentGateway {
-    /// Creates a new gateway for the given token and API base URL.
+    /// Creates a new gateway for the given credentials and API base URL.
     ///
     /// # Arguments
     ///
-    /// * `token` - Personal access token for authentication.
+    /// * `credentials` - Personal access token or GitHub App installation.
     /// * `api_base` - Base URL for the GitHub API (e.g. `https://api.github.com`).
     ///
     /// # Errors
     ///
     /// Returns an error if the Octocrab client cannot be built.
-    pub fn new(token: &PersonalAccessToken, api_base: &str) -> Result<Self, IntakeError> {
-        let client = build_octocrab_client(token, api_base)?;
-        Ok(Self { client })
+    pub fn new(
+        credentials: impl Into<GitHubCredentials>,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let resolved = credentials.into();
+        let client = build_octocrab_client(&resolved, api_base)?;
+        let graphql_client =
+            build_octocrab_client(&resolved, &graphql_base_for_api_base(api_base))?;
+        Ok(Self {
+            client,
+            graphql_client,
+            page_cache: None,
+            archive: None,
+        })
+    }
+
+    /// Creates a gateway for the host serving the pull request.
+    ///
+    /// REST and GraphQL endpoints are both derived from the locator, so pull
+    /// requests on GitHub Enterprise hosts need no hand-written API URLs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Octocrab client cannot be built.
+    pub fn for_token(
+        credentials: impl Into<GitHubCredentials>,
+        locator: &PullRequestLocator,
+    ) -> Result<Self, IntakeError> {
+        Self::new(credentials, locator.api_base().as_str())
+    }
+
+    /// Caches review comment pages and their `ETag`s in `cache`.
+    ///
+    /// Later listings send `If-None-Match` for each cached page, so a refresh
+    /// where nothing changed is answered with `304 Not Modified` responses
+    /// that do not count against the rate limit.
+    #[must_use]
+    pub fn with_page_cache(mut self, cache: CommentPageCache) -> Self {
+        self.page_cache = Some(cache);
+        self
+    }
+
+    /// Writes every fetched or posted review comment to `archive`.
+    ///
+    /// Full listings replace the archived comments for the pull request, so
+    /// deleted comments disappear from the archive too. Archive writes are
+    /// best-effort and never fail the GitHub call.
+    #[must_use]
+    pub fn with_archive(mut self, archive: ReviewCommentArchive) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    /// Runs `write` against the archive, logging rather than returning
+    /// failures.
+    fn archive_best_effort(
+        &self,
+        write: impl FnOnce(&ReviewCommentArchive) -> Result<(), PersistenceError>,
+    ) {
+        if let Some(archive) = self.archive.as_ref()
+            && let Err(error) = write(archive)
+        {
+            log_archive_failure(&error);
+        }
     }
 
     /// Fetches all review comments for a pull request.
     ///
     /// This method automatically handles pagination, fetching all pages of
     /// comments from the GitHub API and combining them into a single vector.
+    /// When `pages` is given, each page is also sent to it as it arrives.
+    /// Each comment is then annotated with its thread's resolution state.
     ///
     /// # Errors
     ///
@@ -50,30 +139,82 @@ impl OctocrabReviewCommentGateway {
     ///   [`IntakeError::RateLimitExceeded`] with optional rate limit information
     ///   including reset time.
     /// - **Pagination failures**: Errors encountered while fetching subsequent pages
-    ///   of results via [`Octocrab::all_pages`].
+    ///   of results.
     /// - **Deserialization errors**: Malformed JSON responses or unexpected response
     ///   structure from the GitHub API.
     async fn fetch_review_comments(
         &self,
         locator: &PullRequestLocator,
+        pages: Option<&ReviewCommentPageSender>,
     ) -> Result<Vec<ReviewComment>, IntakeError> {
-        let page: Page<ApiReviewComment> = match self
+        let mut comments = self.fetch_rest_review_comments(locator, pages).await?;
+        self.attach_thread_states(locator, &mut comments).await;
+        self.archive_best_effort(|archive| archive.replace_comments(locator, &comments));
+        Ok(comments)
+    }
+
+    /// Reads the authenticated user's login from the `/user` resource.
+    async fn fetch_authenticated_login(&self) -> Result<String, IntakeError> {
+        match self
             .client
-            .get(locator.review_comments_path(), None::<&()>)
+            .get::<ApiAuthenticatedUser, _, _>("/user", None::<&()>)
             .await
         {
-            Ok(page) => page,
-            Err(error) => {
-                return Err(self
-                    .map_octocrab_error_with_rate_limit("review comments", &error)
-                    .await);
-            }
-        };
+            Ok(user) => Ok(user.login),
+            Err(error) => Err(self
+                .map_octocrab_error_with_rate_limit("authenticated user", &error)
+                .await),
+        }
+    }
+
+    /// Annotates comments with thread resolution state from GraphQL.
+    ///
+    /// Thread state is best-effort: when the GraphQL query fails (for example
+    /// because the token lacks GraphQL access) the comments are returned
+    /// without it and callers fall back to reply-based heuristics.
+    async fn attach_thread_states(
+        &self,
+        locator: &PullRequestLocator,
+        comments: &mut [ReviewComment],
+    ) {
+        if comments.is_empty() {
+            return;
+        }
 
-        match self.client.all_pages(page).await {
-            Ok(comments) => Ok(comments.into_iter().map(Into::into).collect()),
+        match threads::fetch_review_thread_states(&self.graphql_client, locator).await {
+            Ok(states) => apply_thread_states(comments, &states),
+            Err(error) => log_thread_state_failure(&error),
+        }
+    }
+
+    /// Posts a reply into the thread containing `comment_id`.
+    ///
+    /// Uses the `/pulls/{number}/comments/{comment_id}/replies` endpoint,
+    /// which attaches the reply to the same diff position as its parent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] for the same transport, authentication, and
+    /// rate-limit failures as [`Self::fetch_review_comments`], and when the
+    /// parent comment does not exist (GitHub responds with 404).
+    async fn post_review_reply(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<ReviewComment, IntakeError> {
+        let payload = serde_json::json!({ "body": body });
+        match self
+            .client
+            .post::<_, ApiReviewComment>(
+                locator.review_comment_replies_path(comment_id),
+                Some(&payload),
+            )
+            .await
+        {
+            Ok(comment) => Ok(comment.into()),
             Err(error) => Err(self
-                .map_octocrab_error_with_rate_limit("review comments", &error)
+                .map_octocrab_error_with_rate_limit("post review reply", &error)
                 .await),
         }
     }
@@ -124,13 +265,121 @@ impl OctocrabReviewCommentGateway {
     }
 }
 
+/// User resource fields needed to identify the authenticated user.
+#[derive(Debug, Deserialize)]
+struct ApiAuthenticatedUser {
+    login: String,
+}
+
+fn log_archive_failure(error: &PersistenceError) {
+    tracing::warn!("review comments not archived: {error}");
+}
+
 #[async_trait]
 impl ReviewCommentGateway for OctocrabReviewCommentGateway {
     async fn list_review_comments(
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Vec<ReviewComment>, IntakeError> {
-        self.fetch_review_comments(locator).await
+        self.fetch_review_comments(locator, None).await
+    }
+
+    async fn stream_review_comments(
+        &self,
+        locator: &PullRequestLocator,
+        pages: ReviewCommentPageSender,
+    ) -> Result<Vec<ReviewComment>, IntakeError> {
+        self.fetch_review_comments(locator, Some(&pages)).await
+    }
+
+    async fn reply_to_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<ReviewComment, IntakeError> {
+        let reply = self.post_review_reply(locator, comment_id, body).await?;
+        self.archive_best_effort(|archive| {
+            archive.upsert_comments(locator, std::slice::from_ref(&reply))
+        });
+        Ok(reply)
+    }
+
+    async fn edit_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<ReviewComment, IntakeError> {
+        let edited = edits::edit_comment(&self.client, locator, comment_id, body).await?;
+        self.archive_best_effort(|archive| {
+            archive.upsert_comments(locator, std::slice::from_ref(&edited))
+        });
+        Ok(edited)
+    }
+
+    async fn delete_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+    ) -> Result<(), IntakeError> {
+        edits::delete_comment(&self.client, locator, comment_id).await?;
+        self.archive_best_effort(|archive| archive.delete_comment(locator, comment_id));
+        Ok(())
+    }
+
+    async fn authenticated_login(&self) -> Result<String, IntakeError> {
+        self.fetch_authenticated_login().await
+    }
+
+    async fn review_comments_updated_since(
+        &self,
+        locator: &PullRequestLocator,
+        since: &str,
+    ) -> Result<Vec<ReviewComment>, IntakeError> {
+        let comments = self.fetch_review_comments_since(locator, since).await?;
+        self.archive_best_effort(|archive| archive.upsert_comments(locator, &comments));
+        Ok(comments)
+    }
+
+    async fn review_comment_count(&self, locator: &PullRequestLocator) -> Result<u64, IntakeError> {
+        self.fetch_review_comment_count(locator).await
+    }
+
+    async fn review_thread_states(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<HashMap<u64, ReviewThreadState>, IntakeError> {
+        let states = threads::fetch_review_thread_states(&self.graphql_client, locator).await?;
+        self.archive_best_effort(|archive| archive.update_thread_states(locator, &states));
+        Ok(states)
+    }
+
+    async fn set_review_thread_resolution(
+        &self,
+        thread_id: &str,
+        resolved: bool,
+    ) -> Result<ReviewThreadState, IntakeError> {
+        threads::set_review_thread_resolution(&self.graphql_client, thread_id, resolved).await
+    }
+
+    async fn toggle_review_comment_reaction(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        content: ReactionContent,
+    ) -> Result<ReactionToggle, IntakeError> {
+        reactions::toggle_reaction(&self.client, locator, comment_id, content).await
+    }
+
+    async fn has_reacted_to_review_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+        content: ReactionContent,
+    ) -> Result<bool, IntakeError> {
+        let login = self.fetch_authenticated_login().await?;
+        reactions::has_reacted(&self.client, locator, (comment_id, content), &login).await
     }
 }
 
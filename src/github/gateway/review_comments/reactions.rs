@@ -0,0 +1,146 @@
+//! Adding and removing emoji reactions on review comments.
+//!
+//! The reactions API answers a create request with `201 Created` for a new
+//! reaction and `200 OK` when the authenticated user already left the same
+//! reaction. A toggle therefore always creates first and, on `200`, deletes
+//! the existing reaction by its identifier. Raw requests are used so the
+//! status code stays visible.
+//!
+//! Replaying a queued reaction instead asks whether the authenticated user's
+//! reaction already exists, by listing the comment's reactions of that kind.
+
+use http::{StatusCode, Uri};
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ReactionContent, ReactionToggle};
+
+use super::super::comment_pages::map_listing_error;
+use super::super::error_mapping::map_octocrab_error;
+use super::super::http_utils::extract_github_message;
+
+const OPERATION: &str = "toggle review comment reaction";
+const LIST_OPERATION: &str = "list review comment reactions";
+
+/// Page size used when listing a comment's reactions.
+const REACTIONS_PER_PAGE: usize = 100;
+
+/// Reaction resource fields needed to delete a reaction.
+#[derive(Debug, Deserialize)]
+struct ApiReaction {
+    id: u64,
+}
+
+/// Reaction resource fields needed to find a user's reaction.
+#[derive(Debug, Deserialize)]
+struct ApiUserReaction {
+    user: Option<ApiReactionUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiReactionUser {
+    login: String,
+}
+
+/// Adds `content` to the comment, or removes it when the authenticated user
+/// had already reacted with it.
+pub(super) async fn toggle_reaction(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+    comment_id: u64,
+    content: ReactionContent,
+) -> Result<ReactionToggle, IntakeError> {
+    let path = locator.review_comment_reactions_path(comment_id);
+    let payload = serde_json::json!({ "content": content.api_name() });
+    let response = client
+        ._post(parse_uri(&path)?, Some(&payload))
+        .await
+        .map_err(|error| map_octocrab_error(OPERATION, &error))?;
+    let status = response.status();
+    let body = client
+        .body_to_string(response)
+        .await
+        .map_err(|error| IntakeError::Api {
+            message: format!("{OPERATION} response decode failed: {error}"),
+        })?;
+
+    match status {
+        StatusCode::CREATED => Ok(ReactionToggle::Added),
+        StatusCode::OK => {
+            let existing: ApiReaction =
+                serde_json::from_str(&body).map_err(|error| IntakeError::Api {
+                    message: format!("{OPERATION} deserialisation failed: {error}"),
+                })?;
+            delete_reaction(client, &format!("{path}/{}", existing.id)).await?;
+            Ok(ReactionToggle::Removed)
+        }
+        other => Err(map_listing_error(
+            OPERATION,
+            other,
+            extract_github_message(&body),
+        )),
+    }
+}
+
+/// Returns whether `login` has reacted to the comment with `content`.
+pub(super) async fn has_reacted(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+    (comment_id, content): (u64, ReactionContent),
+    login: &str,
+) -> Result<bool, IntakeError> {
+    let path = locator.review_comment_reactions_path(comment_id);
+    let encoded_content =
+        url::form_urlencoded::byte_serialize(content.api_name().as_bytes()).collect::<String>();
+    for page in 1..=u32::MAX {
+        let reactions: Vec<ApiUserReaction> = client
+            .get(
+                format!(
+                    "{path}?content={encoded_content}&per_page={REACTIONS_PER_PAGE}&page={page}"
+                ),
+                None::<&()>,
+            )
+            .await
+            .map_err(|error| map_octocrab_error(LIST_OPERATION, &error))?;
+        let found = reactions.iter().any(|reaction| {
+            reaction
+                .user
+                .as_ref()
+                .is_some_and(|user| user.login.eq_ignore_ascii_case(login))
+        });
+        if found {
+            return Ok(true);
+        }
+        if reactions.len() < REACTIONS_PER_PAGE {
+            break;
+        }
+    }
+    Ok(false)
+}
+
+async fn delete_reaction(client: &Octocrab, path: &str) -> Result<(), IntakeError> {
+    let response = client
+        ._delete(parse_uri(path)?, None::<&()>)
+        .await
+        .map_err(|error| map_octocrab_error(OPERATION, &error))?;
+    let status = response.status();
+    if status == StatusCode::NO_CONTENT {
+        return Ok(());
+    }
+    let body = client
+        .body_to_string(response)
+        .await
+        .unwrap_or_else(|_| String::new());
+    Err(map_listing_error(
+        OPERATION,
+        status,
+        extract_github_message(&body),
+    ))
+}
+
+fn parse_uri(path: &str) -> Result<Uri, IntakeError> {
+    path.parse::<Uri>()
+        .map_err(|error| IntakeError::InvalidUrl(error.to_string()))
+}
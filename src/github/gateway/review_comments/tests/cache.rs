@@ -0,0 +1,150 @@
+//! Tests for revalidating listed pages against the comment page cache.
+
+use rstest::rstest;
+use tempfile::TempDir;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use super::{
+    BlocksOnRuntime, FixtureResult, OctocrabReviewCommentGateway, PersonalAccessToken,
+    ReviewCommentGatewayFixture, gateway_fixture, token,
+};
+use crate::github::gateway::ReviewCommentGateway;
+use crate::persistence::{CommentPageCache, migrate_database};
+use crate::telemetry::NoopTelemetrySink;
+
+/// Builds a gateway for `fixture` with a page cache in a fresh database.
+///
+/// The returned directory holds the database and must outlive the gateway.
+fn cached_gateway(
+    fixture: &ReviewCommentGatewayFixture,
+    token: FixtureResult<PersonalAccessToken>,
+) -> (OctocrabReviewCommentGateway, TempDir) {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let db_path = temp_dir.path().join("frankie.sqlite");
+    let database_url = db_path.to_string_lossy();
+    migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+    let cache = CommentPageCache::new(database_url.as_ref()).expect("cache should be created");
+    let _guard = fixture.runtime.enter();
+    let gateway = OctocrabReviewCommentGateway::new(
+        token.expect("token should be valid"),
+        &format!("{}/api/v3", fixture.server.uri()),
+    )
+    .expect("gateway should build")
+    .with_page_cache(cache);
+    (gateway, temp_dir)
+}
+
+/// Mounts `resource` answering `If-None-Match: etag` with a 304 and other
+/// requests with `body`, once each.
+fn mount_conditional(
+    fixture: &ReviewCommentGatewayFixture,
+    resource: &str,
+    etag: &str,
+    body: &serde_json::Value,
+) {
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(resource))
+            .and(header("If-None-Match", etag))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(resource))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", etag)
+                    .set_body_json(body),
+            )
+            .expect(1)
+            .mount(&fixture.server),
+    );
+}
+
+#[rstest]
+fn cached_sync_requests_are_conditional(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+    token: FixtureResult<PersonalAccessToken>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let (gateway, _temp_dir) = cached_gateway(&fixture, token);
+    mount_conditional(
+        &fixture,
+        "/api/v3/repos/owner/repo/pulls/42",
+        "\"pr\"",
+        &serde_json::json!({ "review_comments": 1 }),
+    );
+    mount_conditional(
+        &fixture,
+        "/api/v3/repos/owner/repo/pulls/42/comments",
+        "\"since\"",
+        &serde_json::json!([{ "id": 7, "body": "Edited" }]),
+    );
+
+    for _ in 0..2 {
+        let count = fixture
+            .block_on(gateway.review_comment_count(&fixture.locator))
+            .expect("count should load");
+        let changed = fixture
+            .block_on(
+                gateway.review_comments_updated_since(&fixture.locator, "2025-01-01T00:00:00Z"),
+            )
+            .expect("changed comments should load");
+
+        assert_eq!(count, 1);
+        assert_eq!(
+            changed.iter().map(|comment| comment.id).collect::<Vec<_>>(),
+            vec![7]
+        );
+    }
+}
+
+#[rstest]
+fn cached_listing_revalidates_pages_with_etag(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+    token: FixtureResult<PersonalAccessToken>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let (gateway, _temp_dir) = cached_gateway(&fixture, token);
+
+    let comments_path = "/api/v3/repos/owner/repo/pulls/42/comments";
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(comments_path))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(comments_path))
+            .and(query_param("page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!([{ "id": 7, "body": "Cached" }])),
+            )
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let first = fixture
+        .block_on(gateway.list_review_comments(&fixture.locator))
+        .expect("first listing should succeed");
+    let second = fixture
+        .block_on(gateway.list_review_comments(&fixture.locator))
+        .expect("revalidated listing should succeed");
+
+    assert_eq!(first, second);
+    assert_eq!(
+        second
+            .iter()
+            .map(|comment| (comment.id, comment.body.as_deref()))
+            .collect::<Vec<_>>(),
+        vec![(7, Some("Cached"))]
+    );
+}
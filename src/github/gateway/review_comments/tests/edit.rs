@@ -0,0 +1,85 @@
+//! Tests for editing and deleting review comments.
+
+use rstest::rstest;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use super::{BlocksOnRuntime, FixtureResult, ReviewCommentGatewayFixture, gateway_fixture};
+use crate::github::gateway::ReviewCommentGateway;
+
+#[rstest]
+fn edit_review_comment_patches_body(gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("PATCH"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/10"))
+            .and(body_partial_json(serde_json::json!({
+                "body": "Fixed in the latest push."
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 10,
+                "body": "Fixed in the latest push.",
+                "user": { "login": "octocat" },
+                "in_reply_to_id": 1,
+                "updated_at": "2025-01-04T00:00:00Z"
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let edited = fixture
+        .block_on(fixture.gateway.edit_review_comment(
+            &fixture.locator,
+            10,
+            "Fixed in the latest push.",
+        ))
+        .expect("comment should be edited");
+
+    assert_eq!(edited.id, 10);
+    assert_eq!(edited.body.as_deref(), Some("Fixed in the latest push."));
+    assert_eq!(edited.updated_at.as_deref(), Some("2025-01-04T00:00:00Z"));
+}
+
+#[rstest]
+fn delete_review_comment_accepts_no_content(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("DELETE"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/10"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    fixture
+        .block_on(fixture.gateway.delete_review_comment(&fixture.locator, 10))
+        .expect("comment should be deleted");
+}
+
+#[rstest]
+fn delete_review_comment_maps_forbidden(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("DELETE"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/10"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Must have admin rights to Repository."
+            })))
+            .mount(&fixture.server),
+    );
+
+    let error = fixture
+        .block_on(fixture.gateway.delete_review_comment(&fixture.locator, 10))
+        .expect_err("delete should fail");
+
+    let message = error.to_string();
+    assert!(
+        message.contains("delete review comment"),
+        "unexpected: {message}"
+    );
+    assert!(message.contains("admin rights"), "unexpected: {message}");
+}
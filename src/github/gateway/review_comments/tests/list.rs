@@ -1,63 +1,15 @@
-//! Tests for the review comments gateway.
+//! Tests for listing review comments and streaming their pages.
 
-type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+use rstest::rstest;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
 
-use rstest::{fixture, rstest};
-use tokio::runtime::Runtime;
-use wiremock::matchers::{method, path};
-use wiremock::{Mock, MockServer, ResponseTemplate};
-
-use super::OctocrabReviewCommentGateway;
+use super::{
+    BlocksOnRuntime, EXPECTED_RATE_LIMIT_RESET_AT, FixtureResult, ReviewCommentGatewayFixture,
+    gateway_fixture,
+};
 use crate::github::error::IntakeError;
 use crate::github::gateway::ReviewCommentGateway;
-use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
-
-const EXPECTED_RATE_LIMIT_RESET_AT: u64 = 1_700_000_000;
-
-trait BlocksOnRuntime {
-    fn runtime(&self) -> &Runtime;
-
-    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
-        self.runtime().block_on(future)
-    }
-}
-
-struct ReviewCommentGatewayFixture {
-    runtime: Runtime,
-    server: MockServer,
-    locator: PullRequestLocator,
-    gateway: OctocrabReviewCommentGateway,
-}
-
-impl BlocksOnRuntime for ReviewCommentGatewayFixture {
-    fn runtime(&self) -> &Runtime {
-        &self.runtime
-    }
-}
-
-#[fixture]
-fn token() -> FixtureResult<PersonalAccessToken> {
-    Ok(PersonalAccessToken::new("valid-token")?)
-}
-
-#[fixture]
-fn gateway_fixture(
-    token: FixtureResult<PersonalAccessToken>,
-) -> FixtureResult<ReviewCommentGatewayFixture> {
-    let token_value = token?;
-    let runtime = Runtime::new()?;
-    let server = runtime.block_on(MockServer::start());
-    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
-    let _guard = runtime.enter();
-    let gateway =
-        OctocrabReviewCommentGateway::new(&token_value, &format!("{}/api/v3", server.uri()))?;
-    Ok(ReviewCommentGatewayFixture {
-        runtime,
-        server,
-        locator,
-        gateway,
-    })
-}
 
 #[rstest]
 fn list_review_comments_returns_comments(
@@ -250,3 +202,64 @@ fn list_review_comments_returns_empty_list(
 
     assert!(result.is_empty(), "expected empty list");
 }
+
+/// Mounts page `number` of three, holding the single comment `number`.
+fn mount_numbered_page(fixture: &ReviewCommentGatewayFixture, number: u64) {
+    let comments_path = "/api/v3/repos/owner/repo/pulls/42/comments";
+    let page_url = |page: u64| {
+        format!(
+            "<{}{comments_path}?per_page=100&page={page}>",
+            fixture.server.uri()
+        )
+    };
+    let link = if number < 3 {
+        format!(
+            "{}; rel=\"next\", {}; rel=\"last\"",
+            page_url(number + 1),
+            page_url(3)
+        )
+    } else {
+        format!("{}; rel=\"first\"", page_url(1))
+    };
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path(comments_path))
+            .and(query_param("page", number.to_string()))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", link.as_str())
+                    .set_body_json(serde_json::json!([{ "id": number, "body": "Page" }])),
+            )
+            .expect(1)
+            .mount(&fixture.server),
+    );
+}
+
+#[rstest]
+fn stream_review_comments_sends_each_page_in_order(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    for number in 1..=3 {
+        mount_numbered_page(&fixture, number);
+    }
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    let result = fixture
+        .block_on(
+            fixture
+                .gateway
+                .stream_review_comments(&fixture.locator, sender),
+        )
+        .expect("listing should succeed");
+
+    let mut pages = Vec::new();
+    while let Ok(page) = receiver.try_recv() {
+        pages.push(page.iter().map(|comment| comment.id).collect::<Vec<_>>());
+    }
+    assert_eq!(pages, vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(
+        result.iter().map(|comment| comment.id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+}
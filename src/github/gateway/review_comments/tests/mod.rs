@@ -0,0 +1,89 @@
+//! Tests for the review comments gateway.
+
+mod cache;
+mod edit;
+mod list;
+mod reactions;
+mod reply;
+mod threads;
+
+type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+use rstest::{fixture, rstest};
+use tokio::runtime::Runtime;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::OctocrabReviewCommentGateway;
+use crate::github::gateway::ReviewCommentGateway;
+use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
+
+const EXPECTED_RATE_LIMIT_RESET_AT: u64 = 1_700_000_000;
+
+trait BlocksOnRuntime {
+    fn runtime(&self) -> &Runtime;
+
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime().block_on(future)
+    }
+}
+
+struct ReviewCommentGatewayFixture {
+    runtime: Runtime,
+    server: MockServer,
+    locator: PullRequestLocator,
+    gateway: OctocrabReviewCommentGateway,
+}
+
+impl BlocksOnRuntime for ReviewCommentGatewayFixture {
+    fn runtime(&self) -> &Runtime {
+        &self.runtime
+    }
+}
+
+#[fixture]
+fn token() -> FixtureResult<PersonalAccessToken> {
+    Ok(PersonalAccessToken::new("valid-token")?)
+}
+
+#[fixture]
+fn gateway_fixture(
+    token: FixtureResult<PersonalAccessToken>,
+) -> FixtureResult<ReviewCommentGatewayFixture> {
+    let token_value = token?;
+    let runtime = Runtime::new()?;
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
+    let _guard = runtime.enter();
+    let gateway =
+        OctocrabReviewCommentGateway::new(&token_value, &format!("{}/api/v3", server.uri()))?;
+    Ok(ReviewCommentGatewayFixture {
+        runtime,
+        server,
+        locator,
+        gateway,
+    })
+}
+
+#[rstest]
+fn authenticated_login_reads_user_resource(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/v3/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "login": "octocat",
+                "id": 1
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let login = fixture
+        .block_on(fixture.gateway.authenticated_login())
+        .expect("login should load");
+
+    assert_eq!(login, "octocat");
+}
@@ -0,0 +1,188 @@
+//! Tests for reading and toggling review comment reactions.
+
+use rstest::rstest;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+use super::{BlocksOnRuntime, FixtureResult, ReviewCommentGatewayFixture, gateway_fixture};
+use crate::github::gateway::ReviewCommentGateway;
+use crate::github::models::{ReactionContent, ReactionCount, ReactionToggle};
+
+#[rstest]
+fn list_review_comments_reads_reaction_counts(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+        "id": 1,
+        "body": "nit: rename",
+        "user": { "login": "alice" },
+        "reactions": { "total_count": 3, "+1": 2, "eyes": 1, "confused": 0 }
+    }]));
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42/comments"))
+            .respond_with(response)
+            .mount(&fixture.server),
+    );
+
+    let comments = fixture
+        .block_on(fixture.gateway.list_review_comments(&fixture.locator))
+        .expect("request should succeed");
+
+    let first = comments.first().expect("should have a comment");
+    assert_eq!(
+        first.reactions,
+        vec![
+            ReactionCount {
+                content: ReactionContent::ThumbsUp,
+                count: 2
+            },
+            ReactionCount {
+                content: ReactionContent::Eyes,
+                count: 1
+            },
+        ]
+    );
+}
+
+#[rstest]
+fn toggle_review_comment_reaction_adds_new_reaction(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/7/reactions"))
+            .and(body_partial_json(serde_json::json!({ "content": "eyes" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": 500,
+                "content": "eyes"
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("DELETE"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(0)
+            .mount(&fixture.server),
+    );
+
+    let toggle = fixture
+        .block_on(fixture.gateway.toggle_review_comment_reaction(
+            &fixture.locator,
+            7,
+            ReactionContent::Eyes,
+        ))
+        .expect("reaction should be added");
+
+    assert_eq!(toggle, ReactionToggle::Added);
+}
+
+#[rstest]
+fn toggle_review_comment_reaction_removes_existing_reaction(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/7/reactions"))
+            .and(body_partial_json(serde_json::json!({ "content": "+1" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 500,
+                "content": "+1"
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    fixture.block_on(
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/api/v3/repos/owner/repo/pulls/comments/7/reactions/500",
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let toggle = fixture
+        .block_on(fixture.gateway.toggle_review_comment_reaction(
+            &fixture.locator,
+            7,
+            ReactionContent::ThumbsUp,
+        ))
+        .expect("reaction should be removed");
+
+    assert_eq!(toggle, ReactionToggle::Removed);
+}
+
+#[rstest]
+#[case::found_on_a_later_page("octocat", true)]
+#[case::left_by_someone_else("hubot", false)]
+fn has_reacted_checks_every_page_for_the_viewer(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+    #[case] viewer: &str,
+    #[case] expected: bool,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/v3/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "login": viewer
+            })))
+            .mount(&fixture.server),
+    );
+    let reaction =
+        |login: &str| serde_json::json!({ "id": 1, "content": "+1", "user": { "login": login } });
+    let first_page: Vec<_> = (0..100).map(|_| reaction("someone")).collect();
+    for (page, reactions) in [("1", first_page), ("2", vec![reaction("octocat")])] {
+        fixture.block_on(
+            Mock::given(method("GET"))
+                .and(path("/api/v3/repos/owner/repo/pulls/comments/7/reactions"))
+                .and(query_param("content", "+1"))
+                .and(query_param("page", page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(reactions))
+                .expect(1)
+                .mount(&fixture.server),
+        );
+    }
+
+    let has_reacted = fixture
+        .block_on(fixture.gateway.has_reacted_to_review_comment(
+            &fixture.locator,
+            7,
+            ReactionContent::ThumbsUp,
+        ))
+        .expect("reactions should be listed");
+
+    assert_eq!(has_reacted, expected);
+}
+
+#[rstest]
+fn toggle_review_comment_reaction_maps_missing_comment(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/comments/99/reactions"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found"
+            })))
+            .mount(&fixture.server),
+    );
+
+    let error = fixture
+        .block_on(fixture.gateway.toggle_review_comment_reaction(
+            &fixture.locator,
+            99,
+            ReactionContent::Hooray,
+        ))
+        .expect_err("reaction should fail");
+
+    let message = error.to_string();
+    assert!(message.contains("reaction"), "unexpected: {message}");
+    assert!(message.contains("Not Found"), "unexpected: {message}");
+}
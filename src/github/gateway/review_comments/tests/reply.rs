@@ -0,0 +1,86 @@
+//! Tests for replying to review comments.
+
+use rstest::rstest;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use super::{BlocksOnRuntime, FixtureResult, ReviewCommentGatewayFixture, gateway_fixture};
+use crate::github::error::IntakeError;
+use crate::github::gateway::ReviewCommentGateway;
+
+#[rstest]
+fn reply_to_review_comment_posts_reply(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let response = ResponseTemplate::new(201).set_body_json(serde_json::json!({
+        "id": 10,
+        "body": "Fixed in the latest push",
+        "user": { "login": "octocat" },
+        "path": "src/main.rs",
+        "line": 10,
+        "commit_id": "abc123",
+        "in_reply_to_id": 1,
+        "created_at": "2025-01-03T00:00:00Z"
+    }));
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42/comments/1/replies"))
+            .and(body_partial_json(serde_json::json!({
+                "body": "Fixed in the latest push"
+            })))
+            .respond_with(response)
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let reply = fixture
+        .block_on(fixture.gateway.reply_to_review_comment(
+            &fixture.locator,
+            1,
+            "Fixed in the latest push",
+        ))
+        .expect("reply should be posted");
+
+    assert_eq!(reply.id, 10);
+    assert_eq!(reply.in_reply_to_id, Some(1));
+    assert_eq!(reply.author.as_deref(), Some("octocat"));
+    assert_eq!(reply.body.as_deref(), Some("Fixed in the latest push"));
+}
+
+#[rstest]
+fn reply_to_review_comment_maps_missing_parent(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path(
+                "/api/v3/repos/owner/repo/pulls/42/comments/99/replies",
+            ))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest"
+            })))
+            .mount(&fixture.server),
+    );
+
+    let error = fixture
+        .block_on(
+            fixture
+                .gateway
+                .reply_to_review_comment(&fixture.locator, 99, "hello"),
+        )
+        .expect_err("reply should fail");
+
+    match error {
+        IntakeError::Api { message } => {
+            assert!(
+                message.contains("post review reply"),
+                "unexpected: {message}"
+            );
+            assert!(message.contains("Not Found"), "unexpected: {message}");
+        }
+        other => panic!("expected Api error, got {other:?}"),
+    }
+}
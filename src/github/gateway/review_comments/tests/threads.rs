@@ -0,0 +1,204 @@
+//! Tests for review thread state and resolution.
+
+use rstest::rstest;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+use super::{BlocksOnRuntime, FixtureResult, ReviewCommentGatewayFixture, gateway_fixture};
+use crate::github::gateway::ReviewCommentGateway;
+
+fn mount_review_comment_pair(fixture: &ReviewCommentGatewayFixture) {
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!([
+        { "id": 1, "body": "Root", "in_reply_to_id": null },
+        { "id": 2, "body": "Reply", "in_reply_to_id": 1 },
+        { "id": 3, "body": "Other root", "in_reply_to_id": null }
+    ]));
+    fixture.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42/comments"))
+            .respond_with(response)
+            .mount(&fixture.server),
+    );
+}
+
+#[rstest]
+fn list_review_comments_attaches_thread_state(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    mount_review_comment_pair(&fixture);
+
+    let threads_response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "data": {
+            "repository": {
+                "pullRequest": {
+                    "reviewThreads": {
+                        "pageInfo": { "hasNextPage": false, "endCursor": null },
+                        "nodes": [
+                            {
+                                "id": "PRRT_resolved",
+                                "isResolved": true,
+                                "isOutdated": false,
+                                "resolvedBy": { "login": "carol" },
+                                "comments": { "nodes": [{ "databaseId": 1 }] }
+                            },
+                            {
+                                "id": "PRRT_open",
+                                "isResolved": false,
+                                "isOutdated": true,
+                                "resolvedBy": null,
+                                "comments": { "nodes": [{ "databaseId": 3 }] }
+                            }
+                        ]
+                    }
+                }
+            }
+        }
+    }));
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "owner": "owner", "repo": "repo", "number": 42 }
+            })))
+            .respond_with(threads_response)
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let result = fixture
+        .block_on(fixture.gateway.list_review_comments(&fixture.locator))
+        .expect("request should succeed");
+
+    let states: Vec<_> = result
+        .iter()
+        .map(|comment| {
+            comment.thread_state.as_ref().map(|state| {
+                (
+                    state.thread_id.as_str(),
+                    state.is_resolved,
+                    state.is_outdated,
+                )
+            })
+        })
+        .collect();
+    assert_eq!(
+        states,
+        vec![
+            Some(("PRRT_resolved", true, false)),
+            Some(("PRRT_resolved", true, false)),
+            Some(("PRRT_open", false, true)),
+        ]
+    );
+    let root = result.first().expect("should have root comment");
+    assert_eq!(
+        root.thread_state
+            .as_ref()
+            .and_then(|state| state.resolved_by.as_deref()),
+        Some("carol")
+    );
+}
+
+#[rstest]
+fn list_review_comments_tolerates_thread_state_failure(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    mount_review_comment_pair(&fixture);
+
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{ "message": "Resource not accessible by integration" }]
+            })))
+            .mount(&fixture.server),
+    );
+
+    let result = fixture
+        .block_on(fixture.gateway.list_review_comments(&fixture.locator))
+        .expect("comments should load without thread state");
+
+    assert_eq!(result.len(), 3);
+    assert!(
+        result.iter().all(|comment| comment.thread_state.is_none()),
+        "thread state should be absent when GraphQL fails"
+    );
+}
+
+#[rstest]
+#[case(true, "resolveReviewThread", Some("carol"))]
+#[case(false, "unresolveReviewThread", None)]
+fn set_review_thread_resolution_runs_mutation(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+    #[case] resolved: bool,
+    #[case] mutation_field: &str,
+    #[case] resolved_by: Option<&str>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "data": {
+            mutation_field: {
+                "thread": {
+                    "id": "PRRT_thread",
+                    "isResolved": resolved,
+                    "isOutdated": false,
+                    "resolvedBy": resolved_by.map(|login| serde_json::json!({ "login": login }))
+                }
+            }
+        }
+    }));
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .and(body_partial_json(serde_json::json!({
+                "variables": { "threadId": "PRRT_thread" }
+            })))
+            .respond_with(response)
+            .expect(1)
+            .mount(&fixture.server),
+    );
+
+    let state = fixture
+        .block_on(
+            fixture
+                .gateway
+                .set_review_thread_resolution("PRRT_thread", resolved),
+        )
+        .expect("mutation should succeed");
+
+    assert_eq!(state.thread_id, "PRRT_thread");
+    assert_eq!(state.is_resolved, resolved);
+    assert_eq!(state.resolved_by.as_deref(), resolved_by);
+}
+
+#[rstest]
+fn set_review_thread_resolution_reports_graphql_errors(
+    gateway_fixture: FixtureResult<ReviewCommentGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "resolveReviewThread": null },
+                "errors": [{ "message": "Could not resolve to a node with the global id" }]
+            })))
+            .mount(&fixture.server),
+    );
+
+    let error = fixture
+        .block_on(
+            fixture
+                .gateway
+                .set_review_thread_resolution("PRRT_missing", true),
+        )
+        .expect_err("mutation should fail");
+
+    let message = error.to_string();
+    assert!(
+        message.contains("resolve review thread"),
+        "unexpected: {message}"
+    );
+}
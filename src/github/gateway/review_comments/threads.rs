@@ -0,0 +1,248 @@
+//! GraphQL loading of review thread resolution state.
+//!
+//! The REST review comments endpoint does not expose whether a conversation
+//! has been resolved or has become outdated. This module queries the
+//! `reviewThreads` connection and indexes each thread's state by the
+//! database identifier of its root comment. It also wraps the
+//! `resolveReviewThread` and `unresolveReviewThread` mutations.
+
+use std::collections::HashMap;
+
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+
+use super::super::error_mapping::map_octocrab_error;
+
+const REVIEW_THREADS_QUERY: &str = "\
+query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id
+          isResolved
+          isOutdated
+          resolvedBy { login }
+          comments(first: 1) { nodes { databaseId } }
+        }
+      }
+    }
+  }
+}";
+
+const RESOLVE_REVIEW_THREAD_MUTATION: &str = "\
+mutation($threadId: ID!) {
+  resolveReviewThread(input: { threadId: $threadId }) {
+    thread { id isResolved isOutdated resolvedBy { login } }
+  }
+}";
+
+const UNRESOLVE_REVIEW_THREAD_MUTATION: &str = "\
+mutation($threadId: ID!) {
+  unresolveReviewThread(input: { threadId: $threadId }) {
+    thread { id isResolved isOutdated resolvedBy { login } }
+  }
+}";
+
+#[derive(Debug, Deserialize)]
+struct ReviewThreadsData {
+    repository: Option<RepositoryNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryNode {
+    pull_request: Option<PullRequestNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestNode {
+    review_threads: ReviewThreadConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadConnection {
+    page_info: ConnectionPageInfo,
+    #[serde(default)]
+    nodes: Vec<Option<ReviewThreadNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConnectionPageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadNode {
+    id: String,
+    is_resolved: bool,
+    is_outdated: bool,
+    resolved_by: Option<ActorNode>,
+    #[serde(default)]
+    comments: ThreadCommentConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct ThreadResolutionData {
+    #[serde(rename = "resolveReviewThread", alias = "unresolveReviewThread")]
+    payload: Option<ThreadResolutionPayload>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ThreadResolutionPayload {
+    thread: Option<ReviewThreadNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActorNode {
+    login: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ThreadCommentConnection {
+    #[serde(default)]
+    nodes: Vec<Option<ThreadCommentNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreadCommentNode {
+    database_id: Option<u64>,
+}
+
+impl ReviewThreadNode {
+    fn root_comment_id(&self) -> Option<u64> {
+        self.comments
+            .nodes
+            .iter()
+            .flatten()
+            .find_map(|comment| comment.database_id)
+    }
+}
+
+impl From<ReviewThreadNode> for ReviewThreadState {
+    fn from(value: ReviewThreadNode) -> Self {
+        Self {
+            thread_id: value.id,
+            is_resolved: value.is_resolved,
+            is_outdated: value.is_outdated,
+            resolved_by: value.resolved_by.and_then(|actor| actor.login),
+        }
+    }
+}
+
+/// Fetches every review thread for the pull request, keyed by root comment ID.
+///
+/// # Errors
+///
+/// Returns [`IntakeError`] when the GraphQL request fails, the response
+/// cannot be decoded, or the repository or pull request is not found.
+pub(super) async fn fetch_review_thread_states(
+    client: &Octocrab,
+    locator: &PullRequestLocator,
+) -> Result<HashMap<u64, ReviewThreadState>, IntakeError> {
+    let mut states = HashMap::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let payload = serde_json::json!({
+            "query": REVIEW_THREADS_QUERY,
+            "variables": {
+                "owner": locator.owner().as_str(),
+                "repo": locator.repository().as_str(),
+                "number": locator.number().get(),
+                "cursor": cursor,
+            },
+        });
+        let data: ReviewThreadsData = client
+            .graphql(&payload)
+            .await
+            .map_err(|error| map_octocrab_error("review threads", &error))?;
+        let connection = data
+            .repository
+            .and_then(|repository| repository.pull_request)
+            .map(|pull_request| pull_request.review_threads)
+            .ok_or_else(|| IntakeError::Api {
+                message: "review threads failed: pull request not found".to_owned(),
+            })?;
+
+        for thread in connection.nodes.into_iter().flatten() {
+            if let Some(root_id) = thread.root_comment_id() {
+                states.insert(root_id, thread.into());
+            }
+        }
+
+        match connection.page_info {
+            ConnectionPageInfo {
+                has_next_page: true,
+                end_cursor: Some(next),
+            } => cursor = Some(next),
+            _ => return Ok(states),
+        }
+    }
+}
+
+/// Marks a review thread as resolved or unresolved.
+///
+/// Returns the thread state reported by GitHub after the mutation.
+///
+/// # Errors
+///
+/// Returns [`IntakeError`] when the GraphQL request fails, the token lacks
+/// permission to resolve the thread, or the thread does not exist.
+pub(super) async fn set_review_thread_resolution(
+    client: &Octocrab,
+    thread_id: &str,
+    resolved: bool,
+) -> Result<ReviewThreadState, IntakeError> {
+    let (operation, mutation) = if resolved {
+        ("resolve review thread", RESOLVE_REVIEW_THREAD_MUTATION)
+    } else {
+        ("unresolve review thread", UNRESOLVE_REVIEW_THREAD_MUTATION)
+    };
+    let payload = serde_json::json!({
+        "query": mutation,
+        "variables": { "threadId": thread_id },
+    });
+    let data: ThreadResolutionData = client
+        .graphql(&payload)
+        .await
+        .map_err(|error| map_octocrab_error(operation, &error))?;
+
+    data.payload
+        .and_then(|result| result.thread)
+        .map(ReviewThreadState::from)
+        .ok_or_else(|| IntakeError::Api {
+            message: format!("{operation} failed: review thread {thread_id} not found"),
+        })
+}
+
+/// Attaches thread state to each comment by root comment ID.
+///
+/// Replies are matched through `in_reply_to_id`, which GitHub always points
+/// at the thread's root comment.
+pub(crate) fn apply_thread_states(
+    comments: &mut [ReviewComment],
+    states: &HashMap<u64, ReviewThreadState>,
+) {
+    for comment in comments {
+        let root_id = comment.in_reply_to_id.unwrap_or(comment.id);
+        comment.thread_state = states.get(&root_id).cloned();
+    }
+}
+
+/// Logs that thread state could not be loaded, which callers tolerate by
+/// keeping comments without it.
+pub(crate) fn log_thread_state_failure(error: &IntakeError) {
+    tracing::warn!("review thread state unavailable: {error}");
+}
@@ -0,0 +1,70 @@
+//! Gateway for submitting pull request reviews.
+
+use async_trait::async_trait;
+use octocrab::Octocrab;
+use serde::Deserialize;
+
+use crate::github::credentials::GitHubCredentials;
+use crate::github::error::IntakeError;
+use crate::github::locator::PullRequestLocator;
+use crate::github::review_submission::{ReviewSubmission, SubmittedReview};
+
+use super::PullRequestReviewGateway;
+use super::client::build_octocrab_client;
+use super::error_mapping::map_octocrab_error;
+
+/// Gateway for submitting PR reviews through Octocrab.
+pub struct OctocrabPullRequestReviewGateway {
+    client: Octocrab,
+}
+
+impl OctocrabPullRequestReviewGateway {
+    /// Creates a new gateway for the given credentials and API base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Octocrab client cannot be built.
+    pub fn new(
+        credentials: impl Into<GitHubCredentials>,
+        api_base: &str,
+    ) -> Result<Self, IntakeError> {
+        let client = build_octocrab_client(&credentials.into(), api_base)?;
+        Ok(Self { client })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiReview {
+    id: u64,
+    state: Option<String>,
+    html_url: Option<String>,
+}
+
+impl From<ApiReview> for SubmittedReview {
+    fn from(value: ApiReview) -> Self {
+        Self {
+            id: value.id,
+            state: value.state,
+            html_url: value.html_url,
+        }
+    }
+}
+
+#[async_trait]
+impl PullRequestReviewGateway for OctocrabPullRequestReviewGateway {
+    async fn submit_review(
+        &self,
+        locator: &PullRequestLocator,
+        submission: &ReviewSubmission,
+    ) -> Result<SubmittedReview, IntakeError> {
+        submission.validate()?;
+        self.client
+            .post::<_, ApiReview>(locator.reviews_path(), Some(&submission.to_payload()))
+            .await
+            .map(SubmittedReview::from)
+            .map_err(|error| map_octocrab_error("submit review", &error))
+    }
+}
+
+#[cfg(test)]
+mod tests;
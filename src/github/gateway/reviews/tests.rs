@@ -0,0 +1,123 @@
+//! Tests for the pull request review gateway.
+
+type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+use rstest::{fixture, rstest};
+use tokio::runtime::Runtime;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::OctocrabPullRequestReviewGateway;
+use crate::github::error::IntakeError;
+use crate::github::gateway::PullRequestReviewGateway;
+use crate::github::locator::{PersonalAccessToken, PullRequestLocator};
+use crate::github::review_submission::{PendingReviewComment, ReviewEvent, ReviewSubmission};
+
+struct ReviewGatewayFixture {
+    runtime: Runtime,
+    server: MockServer,
+    locator: PullRequestLocator,
+    gateway: OctocrabPullRequestReviewGateway,
+}
+
+impl ReviewGatewayFixture {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+#[fixture]
+fn gateway_fixture() -> FixtureResult<ReviewGatewayFixture> {
+    let token = PersonalAccessToken::new("valid-token")?;
+    let runtime = Runtime::new()?;
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/owner/repo/pull/42", server.uri()))?;
+    let _guard = runtime.enter();
+    let gateway =
+        OctocrabPullRequestReviewGateway::new(&token, &format!("{}/api/v3", server.uri()))?;
+    Ok(ReviewGatewayFixture {
+        runtime,
+        server,
+        locator,
+        gateway,
+    })
+}
+
+#[rstest]
+fn submit_review_posts_event_and_inline_comments(
+    gateway_fixture: FixtureResult<ReviewGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42/reviews"))
+            .and(body_json(serde_json::json!({
+                "body": "Looks good",
+                "event": "APPROVE",
+                "comments": [{
+                    "path": "src/main.rs",
+                    "line": 12,
+                    "side": "RIGHT",
+                    "body": "Nice cleanup"
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 80,
+                "state": "APPROVED",
+                "html_url": "https://github.com/owner/repo/pull/42#pullrequestreview-80"
+            })))
+            .expect(1)
+            .mount(&fixture.server),
+    );
+    let submission = ReviewSubmission::new(ReviewEvent::Approve, "Looks good")
+        .with_comment(PendingReviewComment::new("src/main.rs", 12, "Nice cleanup"));
+
+    let review = fixture
+        .block_on(fixture.gateway.submit_review(&fixture.locator, &submission))
+        .expect("review should be submitted");
+
+    assert_eq!(review.id, 80);
+    assert_eq!(review.state.as_deref(), Some("APPROVED"));
+}
+
+#[rstest]
+fn submit_review_rejects_invalid_submission_without_request(
+    gateway_fixture: FixtureResult<ReviewGatewayFixture>,
+) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&fixture.server),
+    );
+    let submission = ReviewSubmission::new(ReviewEvent::RequestChanges, "");
+
+    let error = fixture
+        .block_on(fixture.gateway.submit_review(&fixture.locator, &submission))
+        .expect_err("submission should be rejected");
+
+    assert!(matches!(error, IntakeError::Configuration { .. }));
+}
+
+#[rstest]
+fn submit_review_maps_validation_failures(gateway_fixture: FixtureResult<ReviewGatewayFixture>) {
+    let fixture = gateway_fixture.expect("fixture should succeed");
+    fixture.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/owner/repo/pulls/42/reviews"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "message": "Unprocessable Entity",
+                "errors": ["Can not approve your own pull request"]
+            })))
+            .mount(&fixture.server),
+    );
+    let submission = ReviewSubmission::new(ReviewEvent::Approve, "");
+
+    let error = fixture
+        .block_on(fixture.gateway.submit_review(&fixture.locator, &submission))
+        .expect_err("GitHub should reject the review");
+
+    let message = error.to_string();
+    assert!(message.contains("submit review"), "unexpected: {message}");
+}
@@ -0,0 +1,101 @@
+//! API base derivation for GitHub and GitHub Enterprise hosts.
+//!
+//! GitHub.com serves its REST API from `api.github.com`, while Enterprise
+//! hosts serve it from `/api/v3` on the web host itself.
+
+use url::Url;
+
+use super::IntakeError;
+
+/// Derives the GitHub API base URL from a host string.
+pub(crate) fn derive_api_base_from_host(
+    scheme: &str,
+    host: &str,
+    port: Option<u16>,
+) -> Result<Url, IntakeError> {
+    if host.eq_ignore_ascii_case("github.com") {
+        Url::parse("https://api.github.com")
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))
+    } else {
+        let authority = if host.contains(':') {
+            format!("[{host}]")
+        } else {
+            host.to_owned()
+        };
+        let mut api_url = Url::parse(&format!("{scheme}://{authority}"))
+            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+
+        api_url
+            .set_port(port)
+            .map_err(|()| IntakeError::InvalidUrl("invalid port".to_owned()))?;
+        api_url.set_path("api/v3");
+        Ok(api_url)
+    }
+}
+
+/// Derives the GraphQL base URL from a REST API base URL.
+///
+/// Octocrab posts GraphQL queries to `/graphql` relative to its base URI.
+/// GitHub.com serves both APIs from `https://api.github.com`, whereas GitHub
+/// Enterprise serves REST from `/api/v3` and GraphQL from `/api/graphql`, so
+/// the `/v3` suffix is dropped for Enterprise hosts.
+pub(crate) fn graphql_base_for_api_base(api_base: &str) -> String {
+    let trimmed = api_base.trim_end_matches('/');
+    trimmed
+        .strip_suffix("/api/v3")
+        .map_or_else(|| trimmed.to_owned(), |host| format!("{host}/api"))
+}
+
+/// Returns the web host an API base URL serves.
+///
+/// Maps `api.github.com` to `github.com`; Enterprise API bases share their
+/// host with the web interface.
+pub(crate) fn web_host_for_api_base(api_base: &Url) -> &str {
+    api_base.host_str().map_or("github.com", |host| {
+        if host.eq_ignore_ascii_case("api.github.com") {
+            "github.com"
+        } else {
+            host
+        }
+    })
+}
+
+/// Returns the REST API base URL for a GitHub web host.
+///
+/// `host` may carry a port, such as `ghe.example.com:8443`. Enterprise hosts
+/// are reached over HTTPS.
+///
+/// # Errors
+///
+/// Returns [`IntakeError::InvalidUrl`] when `host` is not a valid host.
+///
+/// # Example
+///
+/// ```
+/// use frankie::github::locator::api_base_for_host;
+///
+/// let github = api_base_for_host("github.com").expect("host should be valid");
+/// assert_eq!(github.as_str(), "https://api.github.com/");
+///
+/// let enterprise = api_base_for_host("ghe.example.com").expect("host should be valid");
+/// assert_eq!(enterprise.as_str(), "https://ghe.example.com/api/v3");
+/// ```
+pub fn api_base_for_host(host: &str) -> Result<Url, IntakeError> {
+    let parsed = Url::parse(&format!("https://{}/", host.trim()))
+        .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
+    if parsed.path() != "/" || parsed.query().is_some() || !parsed.username().is_empty() {
+        return Err(IntakeError::InvalidUrl(format!(
+            "`{host}` is not a GitHub host"
+        )));
+    }
+    derive_api_base(&parsed)
+}
+
+/// Derives the GitHub API base URL from a parsed URL.
+pub(crate) fn derive_api_base(parsed: &Url) -> Result<Url, IntakeError> {
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| IntakeError::InvalidUrl("URL must include a host".to_owned()))?;
+
+    derive_api_base_from_host(parsed.scheme(), host, parsed.port())
+}
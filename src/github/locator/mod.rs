@@ -5,6 +5,13 @@ use url::Url;
 use super::error::IntakeError;
 use crate::local::GitHubOrigin;
 
+mod hosts;
+
+pub use hosts::api_base_for_host;
+pub(crate) use hosts::{
+    derive_api_base, derive_api_base_from_host, graphql_base_for_api_base, web_host_for_api_base,
+};
+
 /// Repository owner wrapper to avoid stringly typed parameters.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RepositoryOwner(String);
@@ -93,41 +100,6 @@ impl AsRef<str> for PersonalAccessToken {
     }
 }
 
-/// Derives the GitHub API base URL from a host string.
-pub(crate) fn derive_api_base_from_host(
-    scheme: &str,
-    host: &str,
-    port: Option<u16>,
-) -> Result<Url, IntakeError> {
-    if host.eq_ignore_ascii_case("github.com") {
-        Url::parse("https://api.github.com")
-            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))
-    } else {
-        let authority = if host.contains(':') {
-            format!("[{host}]")
-        } else {
-            host.to_owned()
-        };
-        let mut api_url = Url::parse(&format!("{scheme}://{authority}"))
-            .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?;
-
-        api_url
-            .set_port(port)
-            .map_err(|()| IntakeError::InvalidUrl("invalid port".to_owned()))?;
-        api_url.set_path("api/v3");
-        Ok(api_url)
-    }
-}
-
-/// Derives the GitHub API base URL from a parsed URL.
-pub(crate) fn derive_api_base(parsed: &Url) -> Result<Url, IntakeError> {
-    let host = parsed
-        .host_str()
-        .ok_or_else(|| IntakeError::InvalidUrl("URL must include a host".to_owned()))?;
-
-    derive_api_base_from_host(parsed.scheme(), host, parsed.port())
-}
-
 /// Parsed owner, repository, and API base extracted from a GitHub URL.
 ///
 /// Shared helper that centralises the URL → segments → validated newtypes
@@ -246,12 +218,49 @@ impl PullRequestLocator {
         Self::parse(&origin.pull_request_url(number))
     }
 
+    /// Rebuilds a locator from its stored parts.
+    ///
+    /// Used when reading locators back from the local database.
+    pub(crate) fn from_parts(
+        api_base: &str,
+        owner: &str,
+        repository: &str,
+        number: u64,
+    ) -> Result<Self, IntakeError> {
+        Ok(Self {
+            api_base: Url::parse(api_base)
+                .map_err(|error| IntakeError::InvalidUrl(error.to_string()))?,
+            owner: RepositoryOwner::new(owner)?,
+            repository: RepositoryName::new(repository)?,
+            number: PullRequestNumber::new(number)?,
+        })
+    }
+
     /// API base URL derived from the pull request host.
     #[must_use]
     pub const fn api_base(&self) -> &Url {
         &self.api_base
     }
 
+    /// GraphQL base URL derived from the pull request host.
+    ///
+    /// Octocrab appends `/graphql`, so this is `https://api.github.com` for
+    /// GitHub.com and `https://<host>[:<port>]/api` for GitHub Enterprise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::PullRequestLocator;
+    ///
+    /// let locator = PullRequestLocator::parse("https://ghe.example.com:8443/corp/app/pull/7")
+    ///     .expect("should parse enterprise URL");
+    /// assert_eq!(locator.graphql_base(), "https://ghe.example.com:8443/api");
+    /// ```
+    #[must_use]
+    pub fn graphql_base(&self) -> String {
+        graphql_base_for_api_base(self.api_base.as_str())
+    }
+
     /// Repository owner.
     #[must_use]
     pub const fn owner(&self) -> &RepositoryOwner {
@@ -276,13 +285,7 @@ impl PullRequestLocator {
     /// host for GitHub Enterprise installations.
     #[must_use]
     pub fn host(&self) -> &str {
-        self.api_base.host_str().map_or("github.com", |h| {
-            if h.eq_ignore_ascii_case("api.github.com") {
-                "github.com"
-            } else {
-                h
-            }
-        })
+        web_host_for_api_base(&self.api_base)
     }
 
     pub(crate) fn pull_request_path(&self) -> String {
@@ -312,4 +315,70 @@ impl PullRequestLocator {
             self.number.get()
         )
     }
+
+    /// Returns the API path for replying within a review comment thread.
+    pub(crate) fn review_comment_replies_path(&self, comment_id: u64) -> String {
+        format!(
+            "/repos/{}/{}/pulls/{}/comments/{comment_id}/replies",
+            self.owner.as_str(),
+            self.repository.as_str(),
+            self.number.get()
+        )
+    }
+
+    /// Returns the API path for a single review comment.
+    pub(crate) fn review_comment_path(&self, comment_id: u64) -> String {
+        format!(
+            "/repos/{}/{}/pulls/comments/{comment_id}",
+            self.owner.as_str(),
+            self.repository.as_str()
+        )
+    }
+
+    /// Returns the API path for the reactions on a review comment.
+    pub(crate) fn review_comment_reactions_path(&self, comment_id: u64) -> String {
+        format!(
+            "/repos/{}/{}/pulls/comments/{comment_id}/reactions",
+            self.owner.as_str(),
+            self.repository.as_str()
+        )
+    }
+
+    /// Returns the API path for listing the files changed by the pull request.
+    pub(crate) fn files_path(&self) -> String {
+        format!(
+            "/repos/{}/{}/pulls/{}/files",
+            self.owner.as_str(),
+            self.repository.as_str(),
+            self.number.get()
+        )
+    }
+
+    /// Returns the API path for listing check runs on a commit.
+    pub(crate) fn check_runs_path(&self, commit_sha: &str) -> String {
+        format!(
+            "/repos/{}/{}/commits/{commit_sha}/check-runs",
+            self.owner.as_str(),
+            self.repository.as_str()
+        )
+    }
+
+    /// Returns the API path for the combined commit status of a commit.
+    pub(crate) fn combined_status_path(&self, commit_sha: &str) -> String {
+        format!(
+            "/repos/{}/{}/commits/{commit_sha}/status",
+            self.owner.as_str(),
+            self.repository.as_str()
+        )
+    }
+
+    /// Returns the API path for creating and listing pull request reviews.
+    pub(crate) fn reviews_path(&self) -> String {
+        format!(
+            "/repos/{}/{}/pulls/{}/reviews",
+            self.owner.as_str(),
+            self.repository.as_str(),
+            self.number.get()
+        )
+    }
 }
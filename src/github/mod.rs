@@ -1,40 +1,67 @@
 //! GitHub pull request intake and token validation.
 //!
-//! This module wraps Octocrab to parse pull request URLs, validate personal
-//! access tokens, and retrieve pull request metadata alongside discussion
-//! comments. Errors are mapped into user-friendly variants so that callers can
+//! This module wraps Octocrab to parse pull request URLs, authenticate with
+//! personal access tokens or GitHub App installations, and retrieve pull
+//! request metadata alongside discussion comments. Errors are mapped into user-friendly variants so that callers can
 //! surface precise failures without exposing Octocrab internals.
 
+pub mod ci_status;
+pub mod credentials;
 pub mod error;
 pub mod gateway;
 pub mod intake;
 pub mod locator;
 pub mod models;
+pub mod outbox;
 pub mod pagination;
+pub mod pull_request_diff;
 pub mod rate_limit;
 pub mod repository_intake;
 pub mod repository_locator;
+pub mod review_queue;
+pub mod review_submission;
+pub mod review_sync;
 
+pub use ci_status::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+pub use credentials::{GitHubAppInstallation, GitHubCredentials};
 pub use error::IntakeError;
 pub use gateway::{
-    ListPullRequestsParams, OctocrabCachingGateway, OctocrabGateway, OctocrabRepositoryGateway,
-    OctocrabReviewCommentGateway, PaginatedPullRequests, PullRequestGateway, PullRequestState,
-    RepositoryGateway, ReviewCommentGateway,
+    CiStatusGateway, ListPullRequestsParams, OctocrabCachingGateway, OctocrabCiStatusGateway,
+    OctocrabGateway, OctocrabPullRequestDiffGateway, OctocrabPullRequestReviewGateway,
+    OctocrabRepositoryGateway, OctocrabReviewCommentGateway, PaginatedPullRequests,
+    PullRequestDiffGateway, PullRequestGateway, PullRequestReviewGateway, PullRequestState,
+    RepositoryGateway, ReviewCommentGateway, ReviewCommentPageSender, SearchPullRequestsParams,
+    SearchScope,
 };
 pub use intake::PullRequestIntake;
 pub use locator::{
     PersonalAccessToken, PullRequestLocator, PullRequestNumber, RepositoryName, RepositoryOwner,
 };
 pub use models::{
-    PullRequestComment, PullRequestDetails, PullRequestMetadata, PullRequestSummary, ReviewComment,
+    PullRequestBranch, PullRequestComment, PullRequestDetails, PullRequestMetadata,
+    PullRequestReview, PullRequestSummary, ReactionContent, ReactionCount, ReactionToggle,
+    ReviewComment, ReviewThreadState, TimelineEntry, TimelineEntryKind, TimelineThread,
+    build_timeline,
 };
+pub use outbox::{OutboxReplayReport, OutboxReplayService, ReplayOutcome, WriteIntent};
 pub use pagination::PageInfo;
-pub use rate_limit::RateLimitInfo;
+pub use pull_request_diff::{ChangedFile, ChangedFileStatus, PullRequestDiff};
+pub use rate_limit::{BackoffReason, PendingBackoff, RateLimitInfo, pending_backoff};
 pub use repository_intake::RepositoryIntake;
 pub use repository_locator::RepositoryLocator;
+pub use review_queue::{
+    ReviewQueueEntry, ReviewQueueReason, ReviewQueueRequest, ReviewQueueService, rank_review_queue,
+};
+pub use review_submission::{
+    DiffSide, PendingReviewComment, ReviewEvent, ReviewSubmission, SubmittedReview,
+};
+pub use review_sync::{ReviewSyncCheckpoint, ReviewSyncDelta, ReviewSyncService};
 
 #[cfg(test)]
-pub use gateway::{MockPullRequestGateway, MockRepositoryGateway, MockReviewCommentGateway};
+pub use gateway::{
+    MockCiStatusGateway, MockPullRequestDiffGateway, MockPullRequestGateway,
+    MockPullRequestReviewGateway, MockRepositoryGateway, MockReviewCommentGateway,
+};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,207 @@
+//! Pull request metadata and issue comments.
+
+use serde::Deserialize;
+
+use super::ApiUser;
+
+/// Pull request metadata used by the CLI and the review TUI.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestMetadata {
+    /// Pull request number.
+    pub number: u64,
+    /// Title of the pull request.
+    pub title: Option<String>,
+    /// State (e.g. open, closed).
+    pub state: Option<String>,
+    /// HTML URL for displaying to a user.
+    pub html_url: Option<String>,
+    /// Author login if present.
+    pub author: Option<String>,
+    /// Description of the pull request.
+    pub body: Option<String>,
+    /// Whether the pull request is a draft.
+    pub draft: bool,
+    /// Branch the pull request merges into.
+    pub base: PullRequestBranch,
+    /// Branch the pull request merges from.
+    pub head: PullRequestBranch,
+    /// Label names in API order.
+    pub labels: Vec<String>,
+    /// Logins of requested reviewers, followed by the slugs of requested
+    /// teams.
+    pub requested_reviewers: Vec<String>,
+    /// GitHub's mergeability verdict (e.g. `clean`, `dirty`, `blocked`);
+    /// `None` until GitHub has computed it.
+    pub mergeable_state: Option<String>,
+    /// Lines added across the pull request.
+    pub additions: Option<u64>,
+    /// Lines deleted across the pull request.
+    pub deletions: Option<u64>,
+    /// Number of files changed.
+    pub changed_files: Option<u64>,
+}
+
+/// One side of a pull request: a branch name and the commit it points at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestBranch {
+    /// Branch name (e.g. `main`).
+    pub name: Option<String>,
+    /// Commit SHA the branch pointed at when the metadata was fetched.
+    pub sha: Option<String>,
+}
+
+impl PullRequestMetadata {
+    /// Returns the line and file counts as `+12 -3 in 2 files`, or `None`
+    /// when GitHub did not report them.
+    #[must_use]
+    pub fn change_summary(&self) -> Option<String> {
+        let additions = self.additions?;
+        let deletions = self.deletions?;
+        let files = self.changed_files?;
+        let noun = if files == 1 { "file" } else { "files" };
+        Some(format!("+{additions} -{deletions} in {files} {noun}"))
+    }
+
+    /// Returns the branches as `head -> base`, or `None` when either name
+    /// is missing.
+    #[must_use]
+    pub fn branch_summary(&self) -> Option<String> {
+        Some(format!(
+            "{} -> {}",
+            self.head.name.as_deref()?,
+            self.base.name.as_deref()?
+        ))
+    }
+}
+
+/// Pull request issue comment details.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestComment {
+    /// Comment identifier.
+    pub id: u64,
+    /// Comment body.
+    pub body: Option<String>,
+    /// Author login.
+    pub author: Option<String>,
+    /// Creation timestamp (ISO 8601 format).
+    pub created_at: Option<String>,
+    /// HTML URL for displaying to a user.
+    pub html_url: Option<String>,
+}
+
+/// Combined pull request details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestDetails {
+    /// PR metadata.
+    pub metadata: PullRequestMetadata,
+    /// All issue comments attached to the PR.
+    pub comments: Vec<PullRequestComment>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiPullRequest {
+    pub(super) number: u64,
+    pub(super) title: Option<String>,
+    pub(super) state: Option<String>,
+    pub(super) html_url: Option<String>,
+    pub(super) user: Option<ApiUser>,
+    #[serde(default)]
+    pub(super) body: Option<String>,
+    #[serde(default)]
+    pub(super) draft: Option<bool>,
+    #[serde(default)]
+    pub(super) base: Option<ApiBranchRef>,
+    #[serde(default)]
+    pub(super) head: Option<ApiBranchRef>,
+    #[serde(default)]
+    pub(super) labels: Vec<ApiLabel>,
+    #[serde(default)]
+    pub(super) requested_reviewers: Vec<ApiUser>,
+    #[serde(default)]
+    pub(super) requested_teams: Vec<ApiTeam>,
+    #[serde(default)]
+    pub(super) mergeable_state: Option<String>,
+    #[serde(default)]
+    pub(super) additions: Option<u64>,
+    #[serde(default)]
+    pub(super) deletions: Option<u64>,
+    #[serde(default)]
+    pub(super) changed_files: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiBranchRef {
+    #[serde(rename = "ref")]
+    pub(super) name: Option<String>,
+    pub(super) sha: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiLabel {
+    pub(super) name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiTeam {
+    pub(super) slug: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiComment {
+    pub(super) id: u64,
+    pub(super) body: Option<String>,
+    pub(super) user: Option<ApiUser>,
+    #[serde(default)]
+    pub(super) created_at: Option<String>,
+    #[serde(default)]
+    pub(super) html_url: Option<String>,
+}
+
+impl From<ApiPullRequest> for PullRequestMetadata {
+    fn from(value: ApiPullRequest) -> Self {
+        let requested_reviewers = value
+            .requested_reviewers
+            .into_iter()
+            .filter_map(|user| user.login)
+            .chain(value.requested_teams.into_iter().map(|team| team.slug))
+            .collect();
+        Self {
+            number: value.number,
+            title: value.title,
+            state: value.state,
+            html_url: value.html_url,
+            author: value.user.and_then(|user| user.login),
+            body: value.body,
+            draft: value.draft.unwrap_or(false),
+            base: value.base.map(PullRequestBranch::from).unwrap_or_default(),
+            head: value.head.map(PullRequestBranch::from).unwrap_or_default(),
+            labels: value.labels.into_iter().map(|label| label.name).collect(),
+            requested_reviewers,
+            mergeable_state: value.mergeable_state,
+            additions: value.additions,
+            deletions: value.deletions,
+            changed_files: value.changed_files,
+        }
+    }
+}
+
+impl From<ApiBranchRef> for PullRequestBranch {
+    fn from(value: ApiBranchRef) -> Self {
+        Self {
+            name: value.name,
+            sha: value.sha,
+        }
+    }
+}
+
+impl From<ApiComment> for PullRequestComment {
+    fn from(value: ApiComment) -> Self {
+        Self {
+            id: value.id,
+            body: value.body,
+            author: value.user.and_then(|user| user.login),
+            created_at: value.created_at,
+            html_url: value.html_url,
+        }
+    }
+}
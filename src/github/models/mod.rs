@@ -6,43 +6,29 @@
 
 use serde::Deserialize;
 
+use crate::github::review_submission::DiffSide;
+
+mod metadata;
+mod reactions;
+mod reviews;
+mod summary;
 #[cfg(feature = "test-support")]
 pub mod test_support;
+mod timeline;
 
-/// Minimal pull request metadata used by the CLI.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub struct PullRequestMetadata {
-    /// Pull request number.
-    pub number: u64,
-    /// Title of the pull request.
-    pub title: Option<String>,
-    /// State (e.g. open, closed).
-    pub state: Option<String>,
-    /// HTML URL for displaying to a user.
-    pub html_url: Option<String>,
-    /// Author login if present.
-    pub author: Option<String>,
-}
-
-/// Pull request issue comment details.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PullRequestComment {
-    /// Comment identifier.
-    pub id: u64,
-    /// Comment body.
-    pub body: Option<String>,
-    /// Author login.
-    pub author: Option<String>,
-}
-
-/// Combined pull request details.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PullRequestDetails {
-    /// PR metadata.
-    pub metadata: PullRequestMetadata,
-    /// All issue comments attached to the PR.
-    pub comments: Vec<PullRequestComment>,
-}
+pub(crate) use metadata::{ApiComment, ApiPullRequest};
+pub use metadata::{
+    PullRequestBranch, PullRequestComment, PullRequestDetails, PullRequestMetadata,
+};
+use reactions::ApiReactionRollup;
+pub use reactions::{
+    ReactionContent, ReactionCount, ReactionToggle, apply_reaction_toggle, format_reactions,
+};
+pub(crate) use reviews::ApiPullRequestReview;
+pub use reviews::PullRequestReview;
+pub use summary::PullRequestSummary;
+pub(crate) use summary::{ApiPullRequestSummary, ApiSearchResults};
+pub use timeline::{TimelineEntry, TimelineEntryKind, TimelineThread, build_timeline};
 
 /// Pull request review comment (distinct from issue comments).
 ///
@@ -60,6 +46,15 @@ pub struct ReviewComment {
     pub file_path: Option<String>,
     /// Line number in the diff the comment refers to.
     pub line_number: Option<u32>,
+    /// Diff side `line_number` refers to.
+    ///
+    /// `None` when GitHub did not report it, as for comments loaded from
+    /// older archives.
+    pub side: Option<DiffSide>,
+    /// First line of a multi-line comment's range, ending at `line_number`.
+    ///
+    /// `None` for comments on a single line.
+    pub start_line_number: Option<u32>,
     /// Original line number before any changes.
     pub original_line_number: Option<u32>,
     /// Diff hunk context for this comment.
@@ -72,42 +67,30 @@ pub struct ReviewComment {
     pub created_at: Option<String>,
     /// Last update timestamp (ISO 8601 format).
     pub updated_at: Option<String>,
+    /// Resolution state of the review thread this comment belongs to.
+    ///
+    /// `None` when thread data has not been loaded from GitHub, in which
+    /// case callers fall back to reply-based heuristics.
+    pub thread_state: Option<ReviewThreadState>,
+    /// Non-zero emoji reaction counts in display order.
+    pub reactions: Vec<ReactionCount>,
 }
 
-/// Lightweight pull request summary for listing views.
+/// Resolution and outdated state of a review thread.
 ///
-/// Contains only the fields needed for PR listing, reducing payload size
-/// compared to full `PullRequestMetadata`.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PullRequestSummary {
-    /// Pull request number.
-    pub number: u64,
-    /// Title of the pull request.
-    pub title: Option<String>,
-    /// State (e.g. open, closed).
-    pub state: Option<String>,
-    /// Author login if present.
-    pub author: Option<String>,
-    /// Creation timestamp (ISO 8601 format).
-    pub created_at: Option<String>,
-    /// Last update timestamp (ISO 8601 format).
-    pub updated_at: Option<String>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-pub(super) struct ApiPullRequest {
-    pub(super) number: u64,
-    pub(super) title: Option<String>,
-    pub(super) state: Option<String>,
-    pub(super) html_url: Option<String>,
-    pub(super) user: Option<ApiUser>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-pub(super) struct ApiComment {
-    pub(super) id: u64,
-    pub(super) body: Option<String>,
-    pub(super) user: Option<ApiUser>,
+/// Review threads are a GraphQL-only concept on GitHub, so this state is
+/// loaded separately from the REST review comments and attached to every
+/// comment in the thread.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewThreadState {
+    /// GraphQL node identifier of the thread.
+    pub thread_id: String,
+    /// Whether the conversation has been marked as resolved.
+    pub is_resolved: bool,
+    /// Whether the thread's diff position no longer applies to the head.
+    pub is_outdated: bool,
+    /// Login of the user who resolved the thread, if resolved.
+    pub resolved_by: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -115,17 +98,6 @@ pub(super) struct ApiUser {
     pub(super) login: Option<String>,
 }
 
-/// API response type for PR listing.
-#[derive(Debug, Clone, Deserialize)]
-pub(super) struct ApiPullRequestSummary {
-    pub(super) number: u64,
-    pub(super) title: Option<String>,
-    pub(super) state: Option<String>,
-    pub(super) user: Option<ApiUser>,
-    pub(super) created_at: Option<String>,
-    pub(super) updated_at: Option<String>,
-}
-
 /// API response type for PR review comments.
 #[derive(Debug, Clone, Deserialize)]
 pub(super) struct ApiReviewComment {
@@ -134,47 +106,18 @@ pub(super) struct ApiReviewComment {
     pub(super) user: Option<ApiUser>,
     pub(super) path: Option<String>,
     pub(super) line: Option<u32>,
+    #[serde(default)]
+    pub(super) side: Option<String>,
+    #[serde(default)]
+    pub(super) start_line: Option<u32>,
     pub(super) original_line: Option<u32>,
     pub(super) diff_hunk: Option<String>,
     pub(super) commit_id: Option<String>,
     pub(super) in_reply_to_id: Option<u64>,
     pub(super) created_at: Option<String>,
     pub(super) updated_at: Option<String>,
-}
-
-impl From<ApiPullRequest> for PullRequestMetadata {
-    fn from(value: ApiPullRequest) -> Self {
-        Self {
-            number: value.number,
-            title: value.title,
-            state: value.state,
-            html_url: value.html_url,
-            author: value.user.and_then(|user| user.login),
-        }
-    }
-}
-
-impl From<ApiComment> for PullRequestComment {
-    fn from(value: ApiComment) -> Self {
-        Self {
-            id: value.id,
-            body: value.body,
-            author: value.user.and_then(|user| user.login),
-        }
-    }
-}
-
-impl From<ApiPullRequestSummary> for PullRequestSummary {
-    fn from(value: ApiPullRequestSummary) -> Self {
-        Self {
-            number: value.number,
-            title: value.title,
-            state: value.state,
-            author: value.user.and_then(|user| user.login),
-            created_at: value.created_at,
-            updated_at: value.updated_at,
-        }
-    }
+    #[serde(default)]
+    pub(super) reactions: Option<ApiReactionRollup>,
 }
 
 impl From<ApiReviewComment> for ReviewComment {
@@ -185,12 +128,16 @@ impl From<ApiReviewComment> for ReviewComment {
             author: value.user.and_then(|user| user.login),
             file_path: value.path,
             line_number: value.line,
+            side: value.side.as_deref().and_then(DiffSide::from_api_str),
+            start_line_number: value.start_line,
             original_line_number: value.original_line,
             diff_hunk: value.diff_hunk,
             commit_sha: value.commit_id,
             in_reply_to_id: value.in_reply_to_id,
             created_at: value.created_at,
             updated_at: value.updated_at,
+            thread_state: None,
+            reactions: value.reactions.map(Into::into).unwrap_or_default(),
         }
     }
 }
@@ -202,53 +149,7 @@ mod tests {
     use rstest::{fixture, rstest};
     use serde_json::json;
 
-    use super::{ApiPullRequestSummary, ApiReviewComment, ApiUser, PullRequestSummary};
-
-    #[test]
-    fn api_pull_request_summary_deserializes_from_json() {
-        let value = json!({
-            "number": 123,
-            "title": "Add tests",
-            "state": "open",
-            "user": { "login": "octocat" },
-            "created_at": "2025-01-01T00:00:00Z",
-            "updated_at": "2025-01-02T00:00:00Z"
-        });
-
-        let api: ApiPullRequestSummary =
-            serde_json::from_value(value).expect("ApiPullRequestSummary should deserialize");
-        assert_eq!(api.number, 123);
-        assert_eq!(api.title.as_deref(), Some("Add tests"));
-        assert_eq!(api.state.as_deref(), Some("open"));
-        assert_eq!(
-            api.user.as_ref().and_then(|user| user.login.as_deref()),
-            Some("octocat")
-        );
-        assert_eq!(api.created_at.as_deref(), Some("2025-01-01T00:00:00Z"));
-        assert_eq!(api.updated_at.as_deref(), Some("2025-01-02T00:00:00Z"));
-    }
-
-    #[test]
-    fn api_pull_request_summary_converts_into_pull_request_summary() {
-        let api = ApiPullRequestSummary {
-            number: 42,
-            title: Some("Ship it".to_owned()),
-            state: Some("closed".to_owned()),
-            user: Some(ApiUser {
-                login: Some("alice".to_owned()),
-            }),
-            created_at: None,
-            updated_at: Some("2025-01-03T00:00:00Z".to_owned()),
-        };
-
-        let summary: PullRequestSummary = api.into();
-        assert_eq!(summary.number, 42);
-        assert_eq!(summary.title.as_deref(), Some("Ship it"));
-        assert_eq!(summary.state.as_deref(), Some("closed"));
-        assert_eq!(summary.author.as_deref(), Some("alice"));
-        assert_eq!(summary.created_at, None);
-        assert_eq!(summary.updated_at.as_deref(), Some("2025-01-03T00:00:00Z"));
-    }
+    use super::{ApiReviewComment, DiffSide, ReviewComment};
 
     #[fixture]
     fn sample_api_review_comment() -> Result<ApiReviewComment, serde_json::Error> {
@@ -258,6 +159,7 @@ mod tests {
             "user": { "login": "reviewer" },
             "path": "src/main.rs",
             "line": 42,
+            "side": "LEFT",
             "original_line": 40,
             "diff_hunk": "@@ -38,6 +38,8 @@\n+    let x = 1;",
             "commit_id": "abc123",
@@ -293,6 +195,15 @@ mod tests {
         assert_eq!(sample_api_review_comment.original_line, Some(40));
     }
 
+    #[rstest]
+    fn review_comment_conversion_reads_diff_side(
+        #[from(sample_api_review_comment)] sample_res: Result<ApiReviewComment, serde_json::Error>,
+    ) {
+        let comment = ReviewComment::from(sample_res.expect("ApiReviewComment should deserialise"));
+
+        assert_eq!(comment.side, Some(DiffSide::Left));
+    }
+
     #[rstest]
     fn api_review_comment_deserialises_metadata_fields(
         #[from(sample_api_review_comment)] sample_res: Result<ApiReviewComment, serde_json::Error>,
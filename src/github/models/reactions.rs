@@ -0,0 +1,310 @@
+//! Emoji reactions on review comments.
+//!
+//! GitHub embeds a reaction rollup in every review comment, with one count
+//! per reaction kind. [`ReactionContent`] names the eight kinds GitHub
+//! supports and [`ReactionCount`] keeps the non-zero counts in a stable
+//! order.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An emoji reaction kind supported by GitHub.
+///
+/// Variants are declared, and therefore ordered, in GitHub's display order.
+/// They serialise as their [`Self::api_name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionContent {
+    /// 👍
+    #[serde(rename = "+1")]
+    ThumbsUp,
+    /// 👎
+    #[serde(rename = "-1")]
+    ThumbsDown,
+    /// 😄
+    Laugh,
+    /// 🎉
+    Hooray,
+    /// 😕
+    Confused,
+    /// ❤️
+    Heart,
+    /// 🚀
+    Rocket,
+    /// 👀
+    Eyes,
+}
+
+impl ReactionContent {
+    /// Every reaction kind, in GitHub's display order.
+    pub const ALL: [Self; 8] = [
+        Self::ThumbsUp,
+        Self::ThumbsDown,
+        Self::Laugh,
+        Self::Hooray,
+        Self::Confused,
+        Self::Heart,
+        Self::Rocket,
+        Self::Eyes,
+    ];
+
+    /// Returns the value the reactions API uses, such as `+1` or `eyes`.
+    #[must_use]
+    pub const fn api_name(self) -> &'static str {
+        match self {
+            Self::ThumbsUp => "+1",
+            Self::ThumbsDown => "-1",
+            Self::Laugh => "laugh",
+            Self::Confused => "confused",
+            Self::Heart => "heart",
+            Self::Hooray => "hooray",
+            Self::Rocket => "rocket",
+            Self::Eyes => "eyes",
+        }
+    }
+
+    /// Parses a value produced by [`Self::api_name`].
+    #[must_use]
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|content| content.api_name() == name)
+    }
+
+    /// Returns the emoji shown for the reaction.
+    #[must_use]
+    pub const fn emoji(self) -> &'static str {
+        match self {
+            Self::ThumbsUp => "👍",
+            Self::ThumbsDown => "👎",
+            Self::Laugh => "😄",
+            Self::Confused => "😕",
+            Self::Heart => "❤️",
+            Self::Hooray => "🎉",
+            Self::Rocket => "🚀",
+            Self::Eyes => "👀",
+        }
+    }
+}
+
+impl fmt::Display for ReactionContent {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.emoji())
+    }
+}
+
+/// How many users left one kind of reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReactionCount {
+    /// The reaction kind.
+    pub content: ReactionContent,
+    /// Number of users who reacted; never zero.
+    pub count: u64,
+}
+
+/// Whether toggling a reaction added or removed it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionToggle {
+    /// The authenticated user had not reacted, so the reaction was added.
+    Added,
+    /// The authenticated user had already reacted, so the reaction was
+    /// removed.
+    Removed,
+}
+
+/// Applies a toggle outcome to `reactions`, keeping the display order and
+/// dropping counts that reach zero.
+pub fn apply_reaction_toggle(
+    reactions: &mut Vec<ReactionCount>,
+    content: ReactionContent,
+    toggle: ReactionToggle,
+) {
+    let existing = reactions
+        .iter_mut()
+        .find(|reaction| reaction.content == content);
+    match (existing, toggle) {
+        (Some(reaction), ReactionToggle::Added) => reaction.count += 1,
+        (Some(reaction), ReactionToggle::Removed) => {
+            reaction.count = reaction.count.saturating_sub(1);
+        }
+        (None, ReactionToggle::Added) => reactions.push(ReactionCount { content, count: 1 }),
+        (None, ReactionToggle::Removed) => {}
+    }
+    reactions.retain(|reaction| reaction.count > 0);
+    reactions.sort_by_key(|reaction| reaction.content);
+}
+
+/// Formats counts as `👍 2  👀 1`, or `None` when there are none.
+#[must_use]
+pub fn format_reactions(reactions: &[ReactionCount]) -> Option<String> {
+    if reactions.is_empty() {
+        return None;
+    }
+    Some(
+        reactions
+            .iter()
+            .map(|reaction| format!("{} {}", reaction.content.emoji(), reaction.count))
+            .collect::<Vec<_>>()
+            .join("  "),
+    )
+}
+
+/// API response type for the reaction rollup embedded in comments.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct ApiReactionRollup {
+    #[serde(rename = "+1", default)]
+    thumbs_up: u64,
+    #[serde(rename = "-1", default)]
+    thumbs_down: u64,
+    #[serde(default)]
+    laugh: u64,
+    #[serde(default)]
+    confused: u64,
+    #[serde(default)]
+    heart: u64,
+    #[serde(default)]
+    hooray: u64,
+    #[serde(default)]
+    rocket: u64,
+    #[serde(default)]
+    eyes: u64,
+}
+
+impl ApiReactionRollup {
+    const fn count(&self, content: ReactionContent) -> u64 {
+        match content {
+            ReactionContent::ThumbsUp => self.thumbs_up,
+            ReactionContent::ThumbsDown => self.thumbs_down,
+            ReactionContent::Laugh => self.laugh,
+            ReactionContent::Confused => self.confused,
+            ReactionContent::Heart => self.heart,
+            ReactionContent::Hooray => self.hooray,
+            ReactionContent::Rocket => self.rocket,
+            ReactionContent::Eyes => self.eyes,
+        }
+    }
+}
+
+impl From<ApiReactionRollup> for Vec<ReactionCount> {
+    fn from(value: ApiReactionRollup) -> Self {
+        ReactionContent::ALL
+            .into_iter()
+            .map(|content| ReactionCount {
+                content,
+                count: value.count(content),
+            })
+            .filter(|reaction| reaction.count > 0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `reactions` module.
+
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{
+        ApiReactionRollup, ReactionContent, ReactionCount, ReactionToggle, apply_reaction_toggle,
+        format_reactions,
+    };
+
+    #[rstest]
+    fn rollup_keeps_non_zero_counts_in_display_order() {
+        let rollup: ApiReactionRollup = serde_json::from_value(json!({
+            "url": "https://api.github.com/repos/o/r/pulls/comments/1/reactions",
+            "total_count": 4,
+            "+1": 2,
+            "-1": 0,
+            "eyes": 1,
+            "hooray": 1
+        }))
+        .expect("rollup should deserialize");
+
+        let counts: Vec<ReactionCount> = rollup.into();
+
+        assert_eq!(
+            counts,
+            vec![
+                ReactionCount {
+                    content: ReactionContent::ThumbsUp,
+                    count: 2
+                },
+                ReactionCount {
+                    content: ReactionContent::Hooray,
+                    count: 1
+                },
+                ReactionCount {
+                    content: ReactionContent::Eyes,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            format_reactions(&counts).as_deref(),
+            Some("👍 2  🎉 1  👀 1")
+        );
+    }
+
+    #[rstest]
+    #[case::add_new(vec![], ReactionToggle::Added, vec![(ReactionContent::Eyes, 1)])]
+    #[case::add_existing(
+        vec![(ReactionContent::Eyes, 1)],
+        ReactionToggle::Added,
+        vec![(ReactionContent::Eyes, 2)]
+    )]
+    #[case::remove_last(vec![(ReactionContent::Eyes, 1)], ReactionToggle::Removed, vec![])]
+    #[case::remove_missing(vec![], ReactionToggle::Removed, vec![])]
+    fn toggles_update_counts(
+        #[case] initial: Vec<(ReactionContent, u64)>,
+        #[case] toggle: ReactionToggle,
+        #[case] expected: Vec<(ReactionContent, u64)>,
+    ) {
+        let to_counts = |pairs: Vec<(ReactionContent, u64)>| {
+            pairs
+                .into_iter()
+                .map(|(content, count)| ReactionCount { content, count })
+                .collect::<Vec<_>>()
+        };
+        let mut reactions = to_counts(initial);
+
+        apply_reaction_toggle(&mut reactions, ReactionContent::Eyes, toggle);
+
+        assert_eq!(reactions, to_counts(expected));
+    }
+
+    #[rstest]
+    fn added_reactions_keep_display_order() {
+        let mut reactions = vec![ReactionCount {
+            content: ReactionContent::Eyes,
+            count: 1,
+        }];
+
+        apply_reaction_toggle(
+            &mut reactions,
+            ReactionContent::ThumbsUp,
+            ReactionToggle::Added,
+        );
+
+        let order = reactions
+            .iter()
+            .map(|reaction| reaction.content)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![ReactionContent::ThumbsUp, ReactionContent::Eyes]
+        );
+    }
+
+    #[rstest]
+    fn api_names_round_trip() {
+        for content in ReactionContent::ALL {
+            assert_eq!(
+                ReactionContent::from_api_name(content.api_name()),
+                Some(content)
+            );
+        }
+    }
+}
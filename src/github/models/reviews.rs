@@ -0,0 +1,101 @@
+//! Top-level pull request reviews.
+
+use serde::Deserialize;
+
+use super::ApiUser;
+
+/// Top-level pull request review with its verdict and summary body.
+///
+/// Reviews group the inline review comments submitted together and carry the
+/// reviewer's overall verdict, which is not visible from the comments alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestReview {
+    /// Review identifier.
+    pub id: u64,
+    /// Reviewer login.
+    pub author: Option<String>,
+    /// Review state (e.g. `APPROVED`, `CHANGES_REQUESTED`, `COMMENTED`).
+    pub state: Option<String>,
+    /// Review summary body.
+    pub body: Option<String>,
+    /// Submission timestamp (ISO 8601 format); absent for pending reviews.
+    pub submitted_at: Option<String>,
+    /// Commit SHA the review was submitted against.
+    pub commit_sha: Option<String>,
+    /// HTML URL for displaying to a user.
+    pub html_url: Option<String>,
+}
+
+/// API response type for top-level PR reviews.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiPullRequestReview {
+    pub(super) id: u64,
+    pub(super) user: Option<ApiUser>,
+    pub(super) state: Option<String>,
+    pub(super) body: Option<String>,
+    pub(super) submitted_at: Option<String>,
+    pub(super) commit_id: Option<String>,
+    pub(super) html_url: Option<String>,
+}
+
+impl From<ApiPullRequestReview> for PullRequestReview {
+    fn from(value: ApiPullRequestReview) -> Self {
+        Self {
+            id: value.id,
+            author: value.user.and_then(|user| user.login),
+            state: value.state,
+            body: value.body.filter(|body| !body.is_empty()),
+            submitted_at: value.submitted_at,
+            commit_sha: value.commit_id,
+            html_url: value.html_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `reviews` module.
+
+    use serde_json::json;
+
+    use super::{ApiPullRequestReview, PullRequestReview};
+
+    #[test]
+    fn api_pull_request_review_converts_into_pull_request_review() {
+        let value = json!({
+            "id": 80,
+            "user": { "login": "reviewer" },
+            "state": "CHANGES_REQUESTED",
+            "body": "Please split this change.",
+            "submitted_at": "2025-01-04T00:00:00Z",
+            "commit_id": "abc123",
+            "html_url": "https://github.com/owner/repo/pull/42#pullrequestreview-80"
+        });
+
+        let api: ApiPullRequestReview =
+            serde_json::from_value(value).expect("ApiPullRequestReview should deserialise");
+        let review: PullRequestReview = api.into();
+
+        assert_eq!(review.id, 80);
+        assert_eq!(review.author.as_deref(), Some("reviewer"));
+        assert_eq!(review.state.as_deref(), Some("CHANGES_REQUESTED"));
+        assert_eq!(review.body.as_deref(), Some("Please split this change."));
+        assert_eq!(review.submitted_at.as_deref(), Some("2025-01-04T00:00:00Z"));
+        assert_eq!(review.commit_sha.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn api_pull_request_review_treats_empty_body_as_absent() {
+        let api: ApiPullRequestReview = serde_json::from_value(json!({
+            "id": 81,
+            "state": "APPROVED",
+            "body": ""
+        }))
+        .expect("ApiPullRequestReview should deserialise");
+
+        let review: PullRequestReview = api.into();
+
+        assert!(review.body.is_none());
+        assert!(review.author.is_none());
+    }
+}
@@ -0,0 +1,210 @@
+//! Lightweight pull request summaries for listing and search views.
+
+use serde::Deserialize;
+
+use super::ApiUser;
+
+/// Lightweight pull request summary for listing views.
+///
+/// Contains only the fields needed for PR listing, reducing payload size
+/// compared to full `PullRequestMetadata`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestSummary {
+    /// Pull request number.
+    pub number: u64,
+    /// Repository as `owner/repo`, set for search results that can span
+    /// several repositories.
+    pub repository: Option<String>,
+    /// Title of the pull request.
+    pub title: Option<String>,
+    /// State (e.g. open, closed).
+    pub state: Option<String>,
+    /// Author login if present.
+    pub author: Option<String>,
+    /// Creation timestamp (ISO 8601 format).
+    pub created_at: Option<String>,
+    /// Last update timestamp (ISO 8601 format).
+    pub updated_at: Option<String>,
+    /// HTML URL for displaying to a user.
+    pub html_url: Option<String>,
+}
+
+impl PullRequestSummary {
+    /// Returns a short reference such as `octo/repo#42`, or `#42` when the
+    /// repository is not known.
+    #[must_use]
+    pub fn reference(&self) -> String {
+        format!(
+            "{}#{}",
+            self.repository.as_deref().unwrap_or_default(),
+            self.number
+        )
+    }
+}
+
+/// API response type for PR listing.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiPullRequestSummary {
+    pub(super) number: u64,
+    pub(super) title: Option<String>,
+    pub(super) state: Option<String>,
+    pub(super) user: Option<ApiUser>,
+    pub(super) created_at: Option<String>,
+    pub(super) updated_at: Option<String>,
+    #[serde(default)]
+    pub(super) html_url: Option<String>,
+}
+
+/// API response type for issue search results.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiSearchResults {
+    pub(crate) total_count: u64,
+    #[serde(default)]
+    pub(crate) items: Vec<ApiSearchIssue>,
+}
+
+/// API response type for one issue search hit.
+///
+/// Pull requests are returned in issue form: the repository is only known
+/// from `repository_url`.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ApiSearchIssue {
+    pub(super) number: u64,
+    pub(super) title: Option<String>,
+    pub(super) state: Option<String>,
+    pub(super) user: Option<ApiUser>,
+    pub(super) created_at: Option<String>,
+    pub(super) updated_at: Option<String>,
+    pub(super) html_url: Option<String>,
+    pub(super) repository_url: Option<String>,
+}
+
+impl From<ApiPullRequestSummary> for PullRequestSummary {
+    fn from(value: ApiPullRequestSummary) -> Self {
+        Self {
+            number: value.number,
+            title: value.title,
+            state: value.state,
+            repository: None,
+            author: value.user.and_then(|user| user.login),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            html_url: value.html_url,
+        }
+    }
+}
+
+impl From<ApiSearchIssue> for PullRequestSummary {
+    fn from(value: ApiSearchIssue) -> Self {
+        Self {
+            number: value.number,
+            title: value.title,
+            state: value.state,
+            repository: value
+                .repository_url
+                .as_deref()
+                .and_then(repository_full_name),
+            author: value.user.and_then(|user| user.login),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            html_url: value.html_url,
+        }
+    }
+}
+
+/// Returns `owner/repo` from an API repository URL ending in
+/// `/repos/<owner>/<repo>`.
+fn repository_full_name(repository_url: &str) -> Option<String> {
+    let mut segments = repository_url.trim_end_matches('/').rsplit('/');
+    let repo = segments.next().filter(|segment| !segment.is_empty())?;
+    let owner = segments.next().filter(|segment| !segment.is_empty())?;
+    (segments.next() == Some("repos")).then(|| format!("{owner}/{repo}"))
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `summary` module.
+
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{ApiPullRequestSummary, ApiSearchIssue, ApiUser, PullRequestSummary};
+
+    #[test]
+    fn api_pull_request_summary_deserializes_from_json() {
+        let value = json!({
+            "number": 123,
+            "title": "Add tests",
+            "state": "open",
+            "user": { "login": "octocat" },
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-02T00:00:00Z"
+        });
+
+        let api: ApiPullRequestSummary =
+            serde_json::from_value(value).expect("ApiPullRequestSummary should deserialize");
+        assert_eq!(api.number, 123);
+        assert_eq!(api.title.as_deref(), Some("Add tests"));
+        assert_eq!(api.state.as_deref(), Some("open"));
+        assert_eq!(
+            api.user.as_ref().and_then(|user| user.login.as_deref()),
+            Some("octocat")
+        );
+        assert_eq!(api.created_at.as_deref(), Some("2025-01-01T00:00:00Z"));
+        assert_eq!(api.updated_at.as_deref(), Some("2025-01-02T00:00:00Z"));
+    }
+
+    #[test]
+    fn api_pull_request_summary_converts_into_pull_request_summary() {
+        let api = ApiPullRequestSummary {
+            number: 42,
+            title: Some("Ship it".to_owned()),
+            state: Some("closed".to_owned()),
+            user: Some(ApiUser {
+                login: Some("alice".to_owned()),
+            }),
+            created_at: None,
+            updated_at: Some("2025-01-03T00:00:00Z".to_owned()),
+            html_url: Some("https://github.com/octo/repo/pull/42".to_owned()),
+        };
+
+        let summary: PullRequestSummary = api.into();
+        assert_eq!(summary.number, 42);
+        assert_eq!(summary.title.as_deref(), Some("Ship it"));
+        assert_eq!(summary.state.as_deref(), Some("closed"));
+        assert_eq!(summary.author.as_deref(), Some("alice"));
+        assert_eq!(summary.created_at, None);
+        assert_eq!(summary.updated_at.as_deref(), Some("2025-01-03T00:00:00Z"));
+        assert_eq!(
+            summary.html_url.as_deref(),
+            Some("https://github.com/octo/repo/pull/42")
+        );
+        assert_eq!(summary.repository, None);
+    }
+
+    #[rstest]
+    #[case::github_com("https://api.github.com/repos/octo/repo", Some("octo/repo"))]
+    #[case::enterprise("https://ghe.example.com/api/v3/repos/corp/app", Some("corp/app"))]
+    #[case::not_a_repository("https://api.github.com/users/octo", None)]
+    fn api_search_issue_converts_with_repository_name(
+        #[case] repository_url: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let api: ApiSearchIssue = serde_json::from_value(json!({
+            "number": 7,
+            "title": "Fix flaky test",
+            "state": "open",
+            "user": { "login": "octocat" },
+            "html_url": "https://github.com/octo/repo/pull/7",
+            "repository_url": repository_url,
+            "pull_request": { "url": "https://api.github.com/repos/octo/repo/pulls/7" }
+        }))
+        .expect("ApiSearchIssue should deserialize");
+
+        let summary = PullRequestSummary::from(api);
+
+        assert_eq!(summary.number, 7);
+        assert_eq!(summary.repository.as_deref(), expected);
+        assert_eq!(summary.author.as_deref(), Some("octocat"));
+    }
+}
@@ -0,0 +1,376 @@
+//! Unified conversation timeline for a pull request.
+//!
+//! GitHub keeps a pull request's conversation in three places: issue
+//! comments for top-level discussion, reviews for verdicts and summary
+//! bodies, and review comments grouped into threads on the diff.
+//! [`build_timeline`] merges them into one chronological stream of
+//! [`TimelineEntry`] values.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use super::{PullRequestComment, PullRequestReview, ReviewComment};
+
+/// The kind of item a [`TimelineEntry`] holds.
+///
+/// Variants are declared, and therefore ordered, in the order entries with
+/// the same timestamp appear in the timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimelineEntryKind {
+    /// Top-level discussion comment on the pull request.
+    IssueComment,
+    /// Submitted review with a verdict or summary body.
+    Review,
+    /// Review comment thread attached to the diff.
+    ReviewThread,
+}
+
+impl TimelineEntryKind {
+    /// Returns the short badge shown next to entries of this kind.
+    #[must_use]
+    pub const fn badge(self) -> &'static str {
+        match self {
+            Self::IssueComment => "comment",
+            Self::Review => "review",
+            Self::ReviewThread => "thread",
+        }
+    }
+}
+
+/// A review comment thread: the root comment and its replies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineThread<'a> {
+    /// Comment that started the thread.
+    pub root: &'a ReviewComment,
+    /// Replies to the root, oldest first.
+    pub replies: Vec<&'a ReviewComment>,
+}
+
+impl<'a> TimelineThread<'a> {
+    /// Returns the root followed by its replies.
+    pub fn comments(&self) -> impl Iterator<Item = &'a ReviewComment> + '_ {
+        std::iter::once(self.root).chain(self.replies.iter().copied())
+    }
+}
+
+/// One item in a pull request's conversation timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimelineEntry<'a> {
+    /// Top-level discussion comment.
+    IssueComment(&'a PullRequestComment),
+    /// Submitted review.
+    Review(&'a PullRequestReview),
+    /// Review comment thread, placed at the time its root was written.
+    ReviewThread(TimelineThread<'a>),
+}
+
+impl<'a> TimelineEntry<'a> {
+    /// Returns the kind of item this entry holds.
+    #[must_use]
+    pub const fn kind(&self) -> TimelineEntryKind {
+        match self {
+            Self::IssueComment(_) => TimelineEntryKind::IssueComment,
+            Self::Review(_) => TimelineEntryKind::Review,
+            Self::ReviewThread(_) => TimelineEntryKind::ReviewThread,
+        }
+    }
+
+    /// Returns the identifier of the comment, review, or thread root.
+    #[must_use]
+    pub const fn id(&self) -> u64 {
+        match self {
+            Self::IssueComment(comment) => comment.id,
+            Self::Review(review) => review.id,
+            Self::ReviewThread(thread) => thread.root.id,
+        }
+    }
+
+    /// Returns the login of the entry's author, if known.
+    #[must_use]
+    pub fn author(&self) -> Option<&'a str> {
+        match self {
+            Self::IssueComment(comment) => comment.author.as_deref(),
+            Self::Review(review) => review.author.as_deref(),
+            Self::ReviewThread(thread) => thread.root.author.as_deref(),
+        }
+    }
+
+    /// Returns the body of the comment, review summary, or thread root.
+    #[must_use]
+    pub fn body(&self) -> Option<&'a str> {
+        match self {
+            Self::IssueComment(comment) => comment.body.as_deref(),
+            Self::Review(review) => review.body.as_deref(),
+            Self::ReviewThread(thread) => thread.root.body.as_deref(),
+        }
+    }
+
+    /// Returns when the entry was posted (ISO 8601 format), if known.
+    ///
+    /// Reviews use their submission time.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<&'a str> {
+        match self {
+            Self::IssueComment(comment) => comment.created_at.as_deref(),
+            Self::Review(review) => review.submitted_at.as_deref(),
+            Self::ReviewThread(thread) => thread.root.created_at.as_deref(),
+        }
+    }
+}
+
+/// Merges a pull request's conversation into one chronological timeline.
+///
+/// Review comments are grouped into threads by following `in_reply_to_id`
+/// to the root; replies whose parent is missing start their own thread.
+/// Pending reviews and reviews that only carry inline comments (a
+/// `COMMENTED` verdict with an empty body) are left out, as their comments
+/// already appear as threads.
+///
+/// Entries are ordered by timestamp, oldest first, with undated entries
+/// last. GitHub timestamps are UTC and fixed-width, so they are compared
+/// as text. Ties are broken by kind and then by identifier.
+#[must_use]
+pub fn build_timeline<'a>(
+    issue_comments: &'a [PullRequestComment],
+    reviews: &'a [PullRequestReview],
+    review_comments: &'a [ReviewComment],
+) -> Vec<TimelineEntry<'a>> {
+    let mut entries: Vec<TimelineEntry<'a>> = issue_comments
+        .iter()
+        .map(TimelineEntry::IssueComment)
+        .chain(
+            reviews
+                .iter()
+                .filter(|review| is_timeline_review(review))
+                .map(TimelineEntry::Review),
+        )
+        .chain(
+            group_threads(review_comments)
+                .into_iter()
+                .map(TimelineEntry::ReviewThread),
+        )
+        .collect();
+    entries.sort_by(compare_entries);
+    entries
+}
+
+/// Returns whether a review says something its inline comments do not.
+fn is_timeline_review(review: &PullRequestReview) -> bool {
+    match review.state.as_deref() {
+        Some("PENDING") => false,
+        Some("COMMENTED") => review
+            .body
+            .as_deref()
+            .is_some_and(|body| !body.trim().is_empty()),
+        _ => true,
+    }
+}
+
+/// Groups review comments into threads keyed by their root.
+fn group_threads(review_comments: &[ReviewComment]) -> Vec<TimelineThread<'_>> {
+    let by_id: BTreeMap<u64, &ReviewComment> = review_comments
+        .iter()
+        .map(|comment| (comment.id, comment))
+        .collect();
+
+    let mut threads: BTreeMap<u64, TimelineThread<'_>> = BTreeMap::new();
+    let mut replies: Vec<(u64, &ReviewComment)> = Vec::new();
+    for comment in review_comments {
+        let root_id = thread_root_id(comment, &by_id);
+        if root_id == comment.id {
+            threads.insert(
+                root_id,
+                TimelineThread {
+                    root: comment,
+                    replies: Vec::new(),
+                },
+            );
+        } else {
+            replies.push((root_id, comment));
+        }
+    }
+
+    for (root_id, reply) in replies {
+        if let Some(thread) = threads.get_mut(&root_id) {
+            thread.replies.push(reply);
+        }
+    }
+
+    threads
+        .into_values()
+        .map(|mut thread| {
+            thread
+                .replies
+                .sort_by_key(|reply| (reply.created_at.as_deref(), reply.id));
+            thread
+        })
+        .collect()
+}
+
+/// Follows `in_reply_to_id` to the oldest comment present in `by_id`.
+fn thread_root_id(comment: &ReviewComment, by_id: &BTreeMap<u64, &ReviewComment>) -> u64 {
+    let mut root_id = comment.id;
+    // Bounded by the number of comments so a malformed reply cycle cannot
+    // loop forever.
+    for _ in 0..by_id.len() {
+        let Some(parent_id) = by_id
+            .get(&root_id)
+            .and_then(|current| current.in_reply_to_id)
+            .filter(|parent_id| by_id.contains_key(parent_id))
+        else {
+            break;
+        };
+        root_id = parent_id;
+    }
+    root_id
+}
+
+fn compare_entries(left: &TimelineEntry<'_>, right: &TimelineEntry<'_>) -> Ordering {
+    let timestamps = match (left.timestamp(), right.timestamp()) {
+        (Some(left_time), Some(right_time)) => left_time.cmp(right_time),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+    timestamps
+        .then_with(|| left.kind().cmp(&right.kind()))
+        .then_with(|| left.id().cmp(&right.id()))
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `timeline` module.
+
+    use rstest::rstest;
+
+    use super::{TimelineEntry, TimelineEntryKind, build_timeline};
+    use crate::github::models::{PullRequestComment, PullRequestReview, ReviewComment};
+
+    fn issue_comment(id: u64, created_at: &str) -> PullRequestComment {
+        PullRequestComment {
+            id,
+            body: Some(format!("issue comment {id}")),
+            author: Some("bot".to_owned()),
+            created_at: Some(created_at.to_owned()),
+            html_url: None,
+        }
+    }
+
+    fn review(id: u64, state: &str, body: Option<&str>, submitted_at: &str) -> PullRequestReview {
+        PullRequestReview {
+            id,
+            author: Some("alice".to_owned()),
+            state: Some(state.to_owned()),
+            body: body.map(str::to_owned),
+            submitted_at: Some(submitted_at.to_owned()),
+            ..PullRequestReview::default()
+        }
+    }
+
+    fn review_comment(id: u64, in_reply_to_id: Option<u64>, created_at: &str) -> ReviewComment {
+        ReviewComment {
+            id,
+            in_reply_to_id,
+            created_at: Some(created_at.to_owned()),
+            ..ReviewComment::default()
+        }
+    }
+
+    fn kinds_and_ids(entries: &[TimelineEntry<'_>]) -> Vec<(TimelineEntryKind, u64)> {
+        entries
+            .iter()
+            .map(|entry| (entry.kind(), entry.id()))
+            .collect()
+    }
+
+    #[rstest]
+    fn timeline_orders_all_sources_chronologically() {
+        let issue_comments = vec![
+            issue_comment(1, "2025-01-01T09:00:00Z"),
+            issue_comment(2, "2025-01-03T09:00:00Z"),
+        ];
+        let reviews = vec![review(
+            10,
+            "APPROVED",
+            Some("Ship it"),
+            "2025-01-04T09:00:00Z",
+        )];
+        let review_comments = vec![review_comment(20, None, "2025-01-02T09:00:00Z")];
+
+        let timeline = build_timeline(&issue_comments, &reviews, &review_comments);
+
+        assert_eq!(
+            kinds_and_ids(&timeline),
+            vec![
+                (TimelineEntryKind::IssueComment, 1),
+                (TimelineEntryKind::ReviewThread, 20),
+                (TimelineEntryKind::IssueComment, 2),
+                (TimelineEntryKind::Review, 10),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn timeline_groups_replies_under_their_root() {
+        let review_comments = vec![
+            review_comment(3, Some(2), "2025-01-01T12:00:00Z"),
+            review_comment(1, None, "2025-01-01T09:00:00Z"),
+            review_comment(2, Some(1), "2025-01-01T10:00:00Z"),
+            review_comment(4, Some(99), "2025-01-01T11:00:00Z"),
+        ];
+
+        let timeline = build_timeline(&[], &[], &review_comments);
+
+        assert_eq!(
+            kinds_and_ids(&timeline),
+            vec![
+                (TimelineEntryKind::ReviewThread, 1),
+                (TimelineEntryKind::ReviewThread, 4),
+            ]
+        );
+        let Some(TimelineEntry::ReviewThread(thread)) = timeline.first() else {
+            panic!("expected a thread first");
+        };
+        let ids: Vec<u64> = thread.comments().map(|comment| comment.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[rstest]
+    #[case::approval("APPROVED", None, true)]
+    #[case::change_request("CHANGES_REQUESTED", None, true)]
+    #[case::comment_with_body("COMMENTED", Some("Overall looks fine"), true)]
+    #[case::comment_without_body("COMMENTED", Some("  "), false)]
+    #[case::pending("PENDING", Some("Draft"), false)]
+    fn timeline_keeps_reviews_with_something_to_say(
+        #[case] state: &str,
+        #[case] body: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let reviews = vec![review(1, state, body, "2025-01-01T09:00:00Z")];
+
+        let timeline = build_timeline(&[], &reviews, &[]);
+
+        assert_eq!(!timeline.is_empty(), expected);
+    }
+
+    #[rstest]
+    fn timeline_places_undated_entries_last() {
+        let issue_comments = vec![
+            PullRequestComment {
+                created_at: None,
+                ..issue_comment(1, "")
+            },
+            issue_comment(2, "2025-01-01T09:00:00Z"),
+        ];
+
+        let timeline = build_timeline(&issue_comments, &[], &[]);
+
+        assert_eq!(
+            kinds_and_ids(&timeline),
+            vec![
+                (TimelineEntryKind::IssueComment, 2),
+                (TimelineEntryKind::IssueComment, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,368 @@
+//! Durable review write intents and their replay.
+//!
+//! Replies, thread resolutions, and reactions that cannot reach GitHub are
+//! queued as [`WriteIntent`]s in the local [`WriteOutbox`]. [`OutboxReplayService`]
+//! drains the queue once GitHub is reachable again. Before sending a write
+//! it checks whether GitHub already reflects it, so a write that landed just
+//! before a crash or restart is not applied twice.
+
+use serde::{Deserialize, Serialize};
+
+use crate::persistence::{PersistenceError, QueuedWrite, WriteOutbox};
+
+use super::error::IntakeError;
+use super::gateway::ReviewCommentGateway;
+use super::locator::PullRequestLocator;
+use super::models::ReactionContent;
+
+mod remote;
+
+use remote::{RemoteSnapshot, is_own_reply_since, same_body_replies};
+
+/// Maximum characters of a reply body shown in a write summary.
+const SUMMARY_BODY_CHARS: usize = 48;
+
+/// A review write waiting to be applied on GitHub.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WriteIntent {
+    /// Post a reply into the review thread containing a comment.
+    Reply {
+        /// ID of the review comment being replied to.
+        comment_id: u64,
+        /// Reply body.
+        body: String,
+    },
+    /// Resolve or unresolve a review thread.
+    ThreadResolution {
+        /// GraphQL node ID of the review thread.
+        thread_id: String,
+        /// Whether the thread should end up resolved.
+        resolved: bool,
+    },
+    /// Add or remove the authenticated user's reaction on a review comment.
+    Reaction {
+        /// ID of the review comment reacted to.
+        comment_id: u64,
+        /// Reaction kind.
+        content: ReactionContent,
+        /// Whether the reaction should end up present.
+        added: bool,
+    },
+}
+
+impl WriteIntent {
+    /// Returns a one-line description of the write for listings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::github::WriteIntent;
+    ///
+    /// let intent = WriteIntent::Reply {
+    ///     comment_id: 7,
+    ///     body: "Fixed in the latest push".to_owned(),
+    /// };
+    /// assert_eq!(intent.summary(), "reply to #7: Fixed in the latest push");
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Reply { comment_id, body } => {
+                let first_line = body.lines().next().unwrap_or_default();
+                let mut preview: String = first_line.chars().take(SUMMARY_BODY_CHARS).collect();
+                if preview.len() < body.trim_end().len() {
+                    preview.push('…');
+                }
+                format!("reply to #{comment_id}: {preview}")
+            }
+            Self::ThreadResolution {
+                thread_id,
+                resolved,
+            } => {
+                let action = if *resolved { "resolve" } else { "unresolve" };
+                format!("{action} thread {thread_id}")
+            }
+            Self::Reaction {
+                comment_id,
+                content,
+                added,
+            } => {
+                if *added {
+                    format!("add {content} to #{comment_id}")
+                } else {
+                    format!("remove {content} from #{comment_id}")
+                }
+            }
+        }
+    }
+
+    /// Returns `true` when queueing `self` makes `earlier` pointless.
+    ///
+    /// Only the latest requested state matters, so a thread resolution
+    /// supersedes earlier ones for the same thread, and a reaction earlier
+    /// ones of the same kind on the same comment.
+    #[must_use]
+    pub fn supersedes(&self, earlier: &Self) -> bool {
+        match (self, earlier) {
+            (
+                Self::ThreadResolution { thread_id, .. },
+                Self::ThreadResolution {
+                    thread_id: earlier_thread_id,
+                    ..
+                },
+            ) => thread_id == earlier_thread_id,
+            (
+                Self::Reaction {
+                    comment_id,
+                    content,
+                    ..
+                },
+                Self::Reaction {
+                    comment_id: earlier_comment_id,
+                    content: earlier_content,
+                    ..
+                },
+            ) => comment_id == earlier_comment_id && content == earlier_content,
+            _ => false,
+        }
+    }
+}
+
+/// What happened to a queued write during replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayOutcome {
+    /// The write was sent to GitHub and removed from the outbox.
+    Applied,
+    /// GitHub already reflected the write, so it was removed without being
+    /// sent again.
+    AlreadyApplied,
+    /// Sending failed; the write stays queued with the error recorded.
+    Failed(IntakeError),
+    /// Not attempted because an earlier write hit a transient failure.
+    Deferred,
+}
+
+impl ReplayOutcome {
+    /// Returns `true` when the write left the outbox.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        matches!(self, Self::Applied | Self::AlreadyApplied)
+    }
+}
+
+/// Result of replaying queued writes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboxReplayReport {
+    /// Outcome of each write by intent ID, in queue order.
+    pub outcomes: Vec<(String, ReplayOutcome)>,
+}
+
+impl OutboxReplayReport {
+    /// Number of writes that left the outbox.
+    #[must_use]
+    pub fn completed_count(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_complete())
+            .count()
+    }
+
+    /// Number of writes still queued after the replay.
+    #[must_use]
+    pub fn remaining_count(&self) -> usize {
+        self.outcomes.len() - self.completed_count()
+    }
+}
+
+/// Replays queued writes through a [`ReviewCommentGateway`].
+pub struct OutboxReplayService<'client, Gateway>
+where
+    Gateway: ReviewCommentGateway,
+{
+    gateway: &'client Gateway,
+    outbox: WriteOutbox,
+}
+
+impl<'client, Gateway> OutboxReplayService<'client, Gateway>
+where
+    Gateway: ReviewCommentGateway,
+{
+    /// Creates a replay service draining `outbox` through `gateway`.
+    #[must_use]
+    pub const fn new(gateway: &'client Gateway, outbox: WriteOutbox) -> Self {
+        Self { gateway, outbox }
+    }
+
+    /// Replays every write queued for the pull request, in queue order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the queue cannot be read. Failures
+    /// of individual writes are reported in the [`OutboxReplayReport`].
+    pub async fn replay(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<OutboxReplayReport, PersistenceError> {
+        let queued = self.outbox.list_for_pull_request(locator)?;
+        Ok(self.replay_writes(locator, &queued).await)
+    }
+
+    /// Replays the given writes for the pull request, in order.
+    ///
+    /// Writes already reflected on GitHub are removed without being sent.
+    /// After a transient failure (a network error or exhausted rate limit)
+    /// the remaining writes are deferred rather than attempted.
+    pub async fn replay_writes(
+        &self,
+        locator: &PullRequestLocator,
+        writes: &[QueuedWrite],
+    ) -> OutboxReplayReport {
+        let mut remote = RemoteSnapshot::new(locator);
+        let mut report = OutboxReplayReport::default();
+        let mut offline = false;
+        for write in writes {
+            let outcome = if offline {
+                ReplayOutcome::Deferred
+            } else {
+                self.replay_one(write, &mut remote).await
+            };
+            self.record_outcome(&write.intent_id, &outcome);
+            offline =
+                matches!(&outcome, ReplayOutcome::Failed(error) if error.is_transient()) || offline;
+            report.outcomes.push((write.intent_id.clone(), outcome));
+        }
+        report
+    }
+
+    async fn replay_one(
+        &self,
+        write: &QueuedWrite,
+        remote: &mut RemoteSnapshot<'_>,
+    ) -> ReplayOutcome {
+        let result = match &write.intent {
+            WriteIntent::Reply { comment_id, body } => {
+                self.replay_reply(remote, (*comment_id, body), &write.queued_at)
+                    .await
+            }
+            WriteIntent::ThreadResolution {
+                thread_id,
+                resolved,
+            } => {
+                self.replay_thread_resolution(remote, thread_id, *resolved)
+                    .await
+            }
+            WriteIntent::Reaction {
+                comment_id,
+                content,
+                added,
+            } => {
+                self.replay_reaction(remote.locator, (*comment_id, *content), *added)
+                    .await
+            }
+        };
+        match result {
+            Ok(true) => ReplayOutcome::Applied,
+            Ok(false) => ReplayOutcome::AlreadyApplied,
+            Err(error) => ReplayOutcome::Failed(error),
+        }
+    }
+
+    /// Posts the reply unless the authenticated user already posted an
+    /// identical one to the thread since it was queued.
+    ///
+    /// Returns whether the reply was sent.
+    async fn replay_reply(
+        &self,
+        remote: &mut RemoteSnapshot<'_>,
+        (comment_id, body): (u64, &str),
+        queued_at: &str,
+    ) -> Result<bool, IntakeError> {
+        let candidates = same_body_replies(remote.comments(self.gateway).await?, comment_id, body);
+        if !candidates.is_empty()
+            && let Some(login) = remote.login(self.gateway).await?
+            && candidates
+                .iter()
+                .any(|reply| is_own_reply_since(reply, login, queued_at))
+        {
+            return Ok(false);
+        }
+        let reply = self
+            .gateway
+            .reply_to_review_comment(remote.locator, comment_id, body)
+            .await?;
+        remote.record_reply(reply);
+        Ok(true)
+    }
+
+    /// Sets the thread resolution unless the thread is already in that
+    /// state.
+    ///
+    /// Returns whether the change was sent.
+    async fn replay_thread_resolution(
+        &self,
+        remote: &mut RemoteSnapshot<'_>,
+        thread_id: &str,
+        resolved: bool,
+    ) -> Result<bool, IntakeError> {
+        let already_applied = remote
+            .thread_states(self.gateway)
+            .await?
+            .values()
+            .any(|state| state.thread_id == thread_id && state.is_resolved == resolved);
+        if already_applied {
+            return Ok(false);
+        }
+        let updated = self
+            .gateway
+            .set_review_thread_resolution(thread_id, resolved)
+            .await?;
+        remote.record_thread_state(&updated);
+        Ok(true)
+    }
+
+    /// Adds or removes the reaction unless the authenticated user's reaction
+    /// is already in the requested state.
+    ///
+    /// Returns whether the change was sent.
+    async fn replay_reaction(
+        &self,
+        locator: &PullRequestLocator,
+        (comment_id, content): (u64, ReactionContent),
+        added: bool,
+    ) -> Result<bool, IntakeError> {
+        let has_reacted = self
+            .gateway
+            .has_reacted_to_review_comment(locator, comment_id, content)
+            .await?;
+        if has_reacted == added {
+            return Ok(false);
+        }
+        self.gateway
+            .toggle_review_comment_reaction(locator, comment_id, content)
+            .await?;
+        Ok(true)
+    }
+
+    fn record_outcome(&self, intent_id: &str, outcome: &ReplayOutcome) {
+        let result = match outcome {
+            ReplayOutcome::Applied | ReplayOutcome::AlreadyApplied => {
+                self.outbox.remove(intent_id).map(drop)
+            }
+            ReplayOutcome::Failed(error) => {
+                self.outbox.record_failure(intent_id, &error.to_string())
+            }
+            ReplayOutcome::Deferred => Ok(()),
+        };
+        if let Err(error) = result {
+            log_outbox_failure(intent_id, &error);
+        }
+    }
+}
+
+fn log_outbox_failure(intent_id: &str, error: &PersistenceError) {
+    tracing::warn!("outbox entry {intent_id} not updated: {error}");
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,149 @@
+//! GitHub state consulted during replay to detect writes already applied.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+
+use crate::github::error::IntakeError;
+use crate::github::gateway::ReviewCommentGateway;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+
+/// How long before a reply was queued a matching reply may have been posted
+/// and still count as the queued one.
+///
+/// Replies are queued after a failed send, and a send can reach GitHub even
+/// when its response is lost, so the posted reply can predate the queue
+/// entry by a few seconds. The margin also absorbs clock skew.
+const QUEUED_REPLY_GRACE: TimeDelta = TimeDelta::seconds(60);
+
+/// Format of [`QueuedWrite::queued_at`](crate::persistence::QueuedWrite::queued_at).
+const QUEUED_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// GitHub state for one pull request, fetched at most once per replay for
+/// duplicate detection.
+#[derive(Debug)]
+pub(super) struct RemoteSnapshot<'locator> {
+    pub(super) locator: &'locator PullRequestLocator,
+    comments: Option<Vec<ReviewComment>>,
+    thread_states: Option<HashMap<u64, ReviewThreadState>>,
+    login: Option<String>,
+    /// Whether `login` has been looked up; it stays `None` afterwards for
+    /// credentials that do not act as a user.
+    login_fetched: bool,
+}
+
+impl<'locator> RemoteSnapshot<'locator> {
+    pub(super) const fn new(locator: &'locator PullRequestLocator) -> Self {
+        Self {
+            locator,
+            comments: None,
+            thread_states: None,
+            login: None,
+            login_fetched: false,
+        }
+    }
+
+    pub(super) async fn comments<Gateway: ReviewCommentGateway>(
+        &mut self,
+        gateway: &Gateway,
+    ) -> Result<&[ReviewComment], IntakeError> {
+        let comments = match self.comments.take() {
+            Some(comments) => comments,
+            None => gateway.list_review_comments(self.locator).await?,
+        };
+        Ok(self.comments.insert(comments))
+    }
+
+    pub(super) async fn thread_states<Gateway: ReviewCommentGateway>(
+        &mut self,
+        gateway: &Gateway,
+    ) -> Result<&HashMap<u64, ReviewThreadState>, IntakeError> {
+        let states = match self.thread_states.take() {
+            Some(states) => states,
+            None => gateway.review_thread_states(self.locator).await?,
+        };
+        Ok(self.thread_states.insert(states))
+    }
+
+    /// Returns the authenticated login, or `None` for credentials that do
+    /// not act as a user, such as GitHub App installations.
+    pub(super) async fn login<Gateway: ReviewCommentGateway>(
+        &mut self,
+        gateway: &Gateway,
+    ) -> Result<Option<&str>, IntakeError> {
+        if !self.login_fetched {
+            self.login = match gateway.authenticated_login().await {
+                Ok(login) => Some(login),
+                Err(error) if error.is_transient() => return Err(error),
+                Err(_) => None,
+            };
+            self.login_fetched = true;
+        }
+        Ok(self.login.as_deref())
+    }
+
+    /// Adds a reply posted during replay to the known comments.
+    pub(super) fn record_reply(&mut self, reply: ReviewComment) {
+        self.comments.get_or_insert_with(Vec::new).push(reply);
+    }
+
+    pub(super) fn record_thread_state(&mut self, updated: &ReviewThreadState) {
+        self.thread_states
+            .iter_mut()
+            .flat_map(HashMap::values_mut)
+            .filter(|state| state.thread_id == updated.thread_id)
+            .for_each(|state| state.clone_from(updated));
+    }
+}
+
+/// Returns the replies in the thread containing `comment_id` whose body
+/// matches `body`.
+///
+/// GitHub attaches replies to the thread's root comment, so replies to
+/// either the comment itself or its root are returned.
+pub(super) fn same_body_replies(
+    comments: &[ReviewComment],
+    comment_id: u64,
+    body: &str,
+) -> Vec<ReviewComment> {
+    let root_id = comments
+        .iter()
+        .find(|comment| comment.id == comment_id)
+        .and_then(|comment| comment.in_reply_to_id)
+        .unwrap_or(comment_id);
+    comments
+        .iter()
+        .filter(|comment| {
+            comment
+                .in_reply_to_id
+                .is_some_and(|parent_id| parent_id == comment_id || parent_id == root_id)
+                && comment.body.as_deref().map(str::trim) == Some(body.trim())
+        })
+        .cloned()
+        .collect()
+}
+
+/// Returns `true` when `reply` was posted by `login` no earlier than
+/// [`QUEUED_REPLY_GRACE`] before the write was queued at `queued_at`.
+///
+/// Replies with unknown authors or timestamps never match, so an unrelated
+/// reply cannot swallow the queued one.
+pub(super) fn is_own_reply_since(reply: &ReviewComment, login: &str, queued_at: &str) -> bool {
+    let is_own = reply
+        .author
+        .as_deref()
+        .is_some_and(|author| author.eq_ignore_ascii_case(login));
+    let created_time = reply
+        .created_at
+        .as_deref()
+        .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        .map(|timestamp| timestamp.with_timezone(&Utc));
+    let queued_time = NaiveDateTime::parse_from_str(queued_at, QUEUED_AT_FORMAT)
+        .ok()
+        .map(|timestamp| timestamp.and_utc());
+    match (created_time, queued_time) {
+        (Some(created), Some(queued)) => is_own && created >= queued - QUEUED_REPLY_GRACE,
+        _ => false,
+    }
+}
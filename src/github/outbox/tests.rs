@@ -0,0 +1,345 @@
+//! Tests for write intent replay.
+
+use std::collections::HashMap;
+
+use chrono::{SecondsFormat, Utc};
+use mockall::predicate::eq;
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::{OutboxReplayService, ReplayOutcome, WriteIntent};
+use crate::github::error::IntakeError;
+use crate::github::gateway::MockReviewCommentGateway;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ReactionContent, ReactionToggle, ReviewComment, ReviewThreadState};
+use crate::persistence::{WriteOutbox, migrate_database};
+use crate::telemetry::NoopTelemetrySink;
+
+fn locator() -> PullRequestLocator {
+    PullRequestLocator::parse("https://github.com/owner/repo/pull/42")
+        .expect("locator should parse")
+}
+
+fn comment(id: u64, in_reply_to_id: Option<u64>, body: &str) -> ReviewComment {
+    ReviewComment {
+        id,
+        in_reply_to_id,
+        body: Some(body.to_owned()),
+        ..Default::default()
+    }
+}
+
+/// A reply posted by `author` at `created_at`.
+fn authored_reply(id: u64, author: &str, created_at: &str, body: &str) -> ReviewComment {
+    ReviewComment {
+        author: Some(author.to_owned()),
+        created_at: Some(created_at.to_owned()),
+        ..comment(id, Some(1), body)
+    }
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn thread_state(resolved: bool) -> ReviewThreadState {
+    ReviewThreadState {
+        thread_id: "PRRT_1".to_owned(),
+        is_resolved: resolved,
+        is_outdated: false,
+        resolved_by: None,
+    }
+}
+
+#[fixture]
+fn outbox() -> (TempDir, WriteOutbox) {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let db_path = temp_dir.path().join("frankie.sqlite");
+    let database_url = db_path.to_string_lossy().to_string();
+    migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+    let outbox = WriteOutbox::new(database_url).expect("outbox should be created");
+    (temp_dir, outbox)
+}
+
+fn enqueue(outbox: &WriteOutbox, intent: &WriteIntent) -> String {
+    outbox
+        .enqueue(&locator(), intent)
+        .expect("enqueue should succeed")
+        .intent_id
+}
+
+#[rstest]
+#[case::short_reply(
+    WriteIntent::Reply { comment_id: 3, body: "Done".to_owned() },
+    "reply to #3: Done"
+)]
+#[case::multi_line_reply(
+    WriteIntent::Reply { comment_id: 3, body: "Done\nSee the new test".to_owned() },
+    "reply to #3: Done…"
+)]
+#[case::unresolve(
+    WriteIntent::ThreadResolution { thread_id: "PRRT_9".to_owned(), resolved: false },
+    "unresolve thread PRRT_9"
+)]
+#[case::add_reaction(
+    WriteIntent::Reaction { comment_id: 3, content: ReactionContent::Heart, added: true },
+    "add ❤️ to #3"
+)]
+#[case::remove_reaction(
+    WriteIntent::Reaction { comment_id: 3, content: ReactionContent::Eyes, added: false },
+    "remove 👀 from #3"
+)]
+fn summary_describes_the_write(#[case] intent: WriteIntent, #[case] expected: &str) {
+    assert_eq!(intent.summary(), expected);
+}
+
+#[rstest]
+#[tokio::test]
+async fn replay_applies_queued_writes_and_empties_the_outbox(outbox: (TempDir, WriteOutbox)) {
+    let (_temp_dir, store) = outbox;
+    let reply_id = enqueue(
+        &store,
+        &WriteIntent::Reply {
+            comment_id: 1,
+            body: "Fixed".to_owned(),
+        },
+    );
+    let resolve_id = enqueue(
+        &store,
+        &WriteIntent::ThreadResolution {
+            thread_id: "PRRT_1".to_owned(),
+            resolved: true,
+        },
+    );
+
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_list_review_comments()
+        .times(1)
+        .returning(|_| Ok(vec![comment(1, None, "Please fix")]));
+    gateway
+        .expect_reply_to_review_comment()
+        .withf(|_, comment_id, body| *comment_id == 1 && body == "Fixed")
+        .times(1)
+        .returning(|_, _, _| Ok(comment(2, Some(1), "Fixed")));
+    gateway
+        .expect_review_thread_states()
+        .times(1)
+        .returning(|_| Ok(HashMap::from([(1, thread_state(false))])));
+    gateway
+        .expect_set_review_thread_resolution()
+        .with(eq("PRRT_1"), eq(true))
+        .times(1)
+        .returning(|_, _| Ok(thread_state(true)));
+
+    let report = OutboxReplayService::new(&gateway, store.clone())
+        .replay(&locator())
+        .await
+        .expect("replay should succeed");
+
+    assert_eq!(
+        report.outcomes,
+        vec![
+            (reply_id, ReplayOutcome::Applied),
+            (resolve_id, ReplayOutcome::Applied),
+        ]
+    );
+    assert!(store.list().expect("list should succeed").is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn replay_skips_writes_already_on_github(outbox: (TempDir, WriteOutbox)) {
+    let (_temp_dir, store) = outbox;
+    let reply_id = enqueue(
+        &store,
+        &WriteIntent::Reply {
+            comment_id: 2,
+            body: "Fixed".to_owned(),
+        },
+    );
+    let resolve_id = enqueue(
+        &store,
+        &WriteIntent::ThreadResolution {
+            thread_id: "PRRT_1".to_owned(),
+            resolved: true,
+        },
+    );
+
+    // The reply landed before a restart; GitHub attached it to the root.
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway.expect_list_review_comments().returning(|_| {
+        Ok(vec![
+            comment(1, None, "Please fix"),
+            comment(2, Some(1), "Which part?"),
+            authored_reply(3, "octocat", &now(), "Fixed\n"),
+        ])
+    });
+    gateway
+        .expect_authenticated_login()
+        .times(1)
+        .returning(|| Ok("octocat".to_owned()));
+    gateway.expect_reply_to_review_comment().never();
+    gateway
+        .expect_review_thread_states()
+        .returning(|_| Ok(HashMap::from([(1, thread_state(true))])));
+    gateway.expect_set_review_thread_resolution().never();
+
+    let report = OutboxReplayService::new(&gateway, store.clone())
+        .replay(&locator())
+        .await
+        .expect("replay should succeed");
+
+    assert_eq!(
+        report.outcomes,
+        vec![
+            (reply_id, ReplayOutcome::AlreadyApplied),
+            (resolve_id, ReplayOutcome::AlreadyApplied),
+        ]
+    );
+    assert!(store.list().expect("list should succeed").is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn transient_failure_defers_the_rest_of_the_queue(outbox: (TempDir, WriteOutbox)) {
+    let (_temp_dir, store) = outbox;
+    let reply_id = enqueue(
+        &store,
+        &WriteIntent::Reply {
+            comment_id: 1,
+            body: "Fixed".to_owned(),
+        },
+    );
+    let resolve_id = enqueue(
+        &store,
+        &WriteIntent::ThreadResolution {
+            thread_id: "PRRT_1".to_owned(),
+            resolved: true,
+        },
+    );
+    let network_error = IntakeError::Network {
+        message: "connection reset".to_owned(),
+    };
+
+    let mut gateway = MockReviewCommentGateway::new();
+    let listing_error = network_error.clone();
+    gateway
+        .expect_list_review_comments()
+        .times(1)
+        .returning(move |_| Err(listing_error.clone()));
+    gateway.expect_review_thread_states().never();
+
+    let report = OutboxReplayService::new(&gateway, store.clone())
+        .replay(&locator())
+        .await
+        .expect("replay should succeed");
+
+    assert_eq!(
+        report.outcomes,
+        vec![
+            (
+                reply_id.clone(),
+                ReplayOutcome::Failed(network_error.clone())
+            ),
+            (resolve_id.clone(), ReplayOutcome::Deferred),
+        ]
+    );
+    assert_eq!(report.remaining_count(), 2);
+
+    let reply = store
+        .get(&reply_id)
+        .expect("get should succeed")
+        .expect("reply should stay queued");
+    assert_eq!(reply.attempt_count, 1);
+    assert_eq!(reply.last_error, Some(network_error.to_string()));
+    let resolve = store
+        .get(&resolve_id)
+        .expect("get should succeed")
+        .expect("resolution should stay queued");
+    assert_eq!(resolve.attempt_count, 0);
+}
+
+#[rstest]
+#[case::other_author(authored_reply(3, "hubot", &now(), "Fixed"))]
+#[case::older_reply(authored_reply(3, "octocat", "2020-01-01T00:00:00Z", "Fixed"))]
+#[tokio::test]
+async fn matching_replies_by_others_or_from_before_queueing_are_not_duplicates(
+    outbox: (TempDir, WriteOutbox),
+    #[case] existing: ReviewComment,
+) {
+    let (_temp_dir, store) = outbox;
+    let reply_id = enqueue(
+        &store,
+        &WriteIntent::Reply {
+            comment_id: 1,
+            body: "Fixed".to_owned(),
+        },
+    );
+
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_list_review_comments()
+        .returning(move |_| Ok(vec![comment(1, None, "Please fix"), existing.clone()]));
+    gateway
+        .expect_authenticated_login()
+        .returning(|| Ok("octocat".to_owned()));
+    gateway
+        .expect_reply_to_review_comment()
+        .times(1)
+        .returning(|_, _, _| Ok(comment(4, Some(1), "Fixed")));
+
+    let report = OutboxReplayService::new(&gateway, store.clone())
+        .replay(&locator())
+        .await
+        .expect("replay should succeed");
+
+    assert_eq!(report.outcomes, vec![(reply_id, ReplayOutcome::Applied)]);
+}
+
+#[rstest]
+#[case::add_missing(true, false, ReplayOutcome::Applied)]
+#[case::add_existing(true, true, ReplayOutcome::AlreadyApplied)]
+#[case::remove_existing(false, true, ReplayOutcome::Applied)]
+#[case::remove_missing(false, false, ReplayOutcome::AlreadyApplied)]
+#[tokio::test]
+async fn reactions_are_sent_only_when_the_viewer_state_differs(
+    outbox: (TempDir, WriteOutbox),
+    #[case] added: bool,
+    #[case] has_reacted: bool,
+    #[case] expected: ReplayOutcome,
+) {
+    let (_temp_dir, store) = outbox;
+    let intent_id = enqueue(
+        &store,
+        &WriteIntent::Reaction {
+            comment_id: 7,
+            content: ReactionContent::Rocket,
+            added,
+        },
+    );
+
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_has_reacted_to_review_comment()
+        .withf(|_, comment_id, content| *comment_id == 7 && *content == ReactionContent::Rocket)
+        .times(1)
+        .returning(move |_, _, _| Ok(has_reacted));
+    let toggle = if added {
+        ReactionToggle::Added
+    } else {
+        ReactionToggle::Removed
+    };
+    gateway
+        .expect_toggle_review_comment_reaction()
+        .times(usize::from(expected == ReplayOutcome::Applied))
+        .returning(move |_, _, _| Ok(toggle));
+
+    let report = OutboxReplayService::new(&gateway, store.clone())
+        .replay(&locator())
+        .await
+        .expect("replay should succeed");
+
+    assert_eq!(report.outcomes, vec![(intent_id, expected)]);
+    assert!(store.list().expect("list should succeed").is_empty());
+}
@@ -0,0 +1,223 @@
+//! Pull request changed files and diff types.
+//!
+//! Review comments only carry the `diff_hunk` around the commented line, so
+//! files without comments are otherwise invisible. [`ChangedFile`] describes
+//! one file touched by the pull request, including its patch, and
+//! [`PullRequestDiff`] bundles every changed file with the unified diff for
+//! the whole pull request so the TUI, exports, and AI prompts can share a
+//! single model.
+
+use std::fmt;
+
+/// How a file was changed by the pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChangedFileStatus {
+    /// The file was created.
+    Added,
+    /// The file was deleted.
+    Removed,
+    /// The file contents were modified.
+    #[default]
+    Modified,
+    /// The file was moved, possibly with content changes.
+    Renamed,
+    /// The file was copied from another path.
+    Copied,
+    /// The file mode or type changed.
+    Changed,
+    /// The file is listed without content changes.
+    Unchanged,
+}
+
+impl ChangedFileStatus {
+    /// Returns the GitHub API value for this status.
+    #[must_use]
+    pub const fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Modified => "modified",
+            Self::Renamed => "renamed",
+            Self::Copied => "copied",
+            Self::Changed => "changed",
+            Self::Unchanged => "unchanged",
+        }
+    }
+
+    /// Parses a GitHub API status value.
+    #[must_use]
+    pub fn from_api_str(value: &str) -> Option<Self> {
+        match value {
+            "added" => Some(Self::Added),
+            "removed" => Some(Self::Removed),
+            "modified" => Some(Self::Modified),
+            "renamed" => Some(Self::Renamed),
+            "copied" => Some(Self::Copied),
+            "changed" => Some(Self::Changed),
+            "unchanged" => Some(Self::Unchanged),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ChangedFileStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_api_str())
+    }
+}
+
+/// A file touched by a pull request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// Repository-relative path in the head commit.
+    pub path: String,
+    /// Path before a rename or copy, if any.
+    pub previous_path: Option<String>,
+    /// How the file was changed.
+    pub status: ChangedFileStatus,
+    /// Number of added lines.
+    pub additions: u64,
+    /// Number of deleted lines.
+    pub deletions: u64,
+    /// Unified diff hunks for the file.
+    ///
+    /// GitHub omits the patch for binary files and for very large diffs.
+    pub patch: Option<String>,
+    /// Blob SHA of the file in the head commit.
+    pub blob_sha: Option<String>,
+}
+
+impl ChangedFile {
+    /// Splits the patch into hunks, each starting with its `@@` header.
+    ///
+    /// Every returned hunk has the same shape as a review comment's
+    /// `diff_hunk`, so it can be rendered by the existing diff views.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::github::ChangedFile;
+    ///
+    /// let file = ChangedFile {
+    ///     path: "src/lib.rs".to_owned(),
+    ///     patch: Some("@@ -1 +1 @@\n-old\n+new\n@@ -9 +9 @@\n+added".to_owned()),
+    ///     ..ChangedFile::default()
+    /// };
+    ///
+    /// assert_eq!(file.hunks(), vec!["@@ -1 +1 @@\n-old\n+new", "@@ -9 +9 @@\n+added"]);
+    /// ```
+    #[must_use]
+    pub fn hunks(&self) -> Vec<&str> {
+        let Some(patch) = self.patch.as_deref() else {
+            return Vec::new();
+        };
+
+        let mut starts: Vec<usize> = patch
+            .match_indices("\n@@ ")
+            .map(|(index, _)| index + 1)
+            .collect();
+        if patch.starts_with("@@ ") {
+            starts.insert(0, 0);
+        }
+
+        starts
+            .iter()
+            .enumerate()
+            .map(|(position, &start)| {
+                let end = starts.get(position + 1).map_or(patch.len(), |&next| next);
+                patch
+                    .get(start..end)
+                    .unwrap_or_default()
+                    .trim_end_matches('\n')
+            })
+            .collect()
+    }
+}
+
+/// Changed files and unified diff for a whole pull request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestDiff {
+    /// Changed files in the order GitHub lists them.
+    pub files: Vec<ChangedFile>,
+    /// Unified diff of the pull request, as produced by `git diff`.
+    pub unified_diff: String,
+}
+
+impl PullRequestDiff {
+    /// Returns the changed file at `path`, if the pull request touches it.
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<&ChangedFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    /// Returns the total number of added lines across all files.
+    #[must_use]
+    pub fn total_additions(&self) -> u64 {
+        self.files.iter().map(|file| file.additions).sum()
+    }
+
+    /// Returns the total number of deleted lines across all files.
+    #[must_use]
+    pub fn total_deletions(&self) -> u64 {
+        self.files.iter().map(|file| file.deletions).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for pull request diff types.
+
+    use rstest::rstest;
+
+    use super::{ChangedFile, ChangedFileStatus, PullRequestDiff};
+
+    fn file(path: &str, additions: u64, deletions: u64) -> ChangedFile {
+        ChangedFile {
+            path: path.to_owned(),
+            additions,
+            deletions,
+            ..ChangedFile::default()
+        }
+    }
+
+    #[rstest]
+    #[case(ChangedFileStatus::Added)]
+    #[case(ChangedFileStatus::Removed)]
+    #[case(ChangedFileStatus::Modified)]
+    #[case(ChangedFileStatus::Renamed)]
+    #[case(ChangedFileStatus::Copied)]
+    #[case(ChangedFileStatus::Changed)]
+    #[case(ChangedFileStatus::Unchanged)]
+    fn status_round_trips_through_api_value(#[case] status: ChangedFileStatus) {
+        assert_eq!(
+            ChangedFileStatus::from_api_str(status.as_api_str()),
+            Some(status)
+        );
+    }
+
+    #[rstest]
+    #[case::no_patch(None, Vec::new())]
+    #[case::single_hunk(Some("@@ -1 +1 @@\n-a\n+b\n"), vec!["@@ -1 +1 @@\n-a\n+b"])]
+    #[case::leading_text(Some("junk\n@@ -2 +2 @@\n x"), vec!["@@ -2 +2 @@\n x"])]
+    fn hunks_split_patch_on_headers(#[case] patch: Option<&str>, #[case] expected: Vec<&str>) {
+        let changed = ChangedFile {
+            patch: patch.map(str::to_owned),
+            ..file("src/lib.rs", 1, 1)
+        };
+
+        assert_eq!(changed.hunks(), expected);
+    }
+
+    #[rstest]
+    fn diff_totals_and_lookup_cover_all_files() {
+        let diff = PullRequestDiff {
+            files: vec![file("src/lib.rs", 3, 1), file("README.md", 2, 0)],
+            unified_diff: String::new(),
+        };
+
+        assert_eq!(diff.total_additions(), 5);
+        assert_eq!(diff.total_deletions(), 1);
+        assert_eq!(diff.file("README.md").map(|found| found.additions), Some(2));
+        assert!(diff.file("missing.rs").is_none());
+    }
+}
@@ -3,7 +3,12 @@
 //! This module provides the `RateLimitInfo` type for capturing rate limit
 //! headers returned by the GitHub API. Rate limit information helps callers
 //! implement backoff strategies and avoid exhausting their API quota.
+//!
+//! The Octocrab gateways retry rate-limited requests automatically. While a
+//! retry is waiting, [`pending_backoff`] reports the wait so hosts such as the
+//! TUI can show a countdown instead of an error.
 
+use std::sync::{Mutex, PoisonError};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Rate limit information extracted from GitHub API response headers.
@@ -81,13 +86,167 @@ impl RateLimitInfo {
     }
 }
 
+/// Why a GitHub request is waiting before it is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackoffReason {
+    /// GitHub asked the client to wait via a `Retry-After` header.
+    RetryAfter,
+    /// The primary rate limit is exhausted until `X-RateLimit-Reset`.
+    RateLimitReset,
+    /// A secondary rate limit was hit without a reset hint.
+    SecondaryRateLimit,
+}
+
+impl BackoffReason {
+    /// Returns a short human-readable label for the reason.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::RetryAfter => "retry requested",
+            Self::RateLimitReset => "rate limit exhausted",
+            Self::SecondaryRateLimit => "secondary rate limit",
+        }
+    }
+}
+
+/// A rate-limited GitHub request that is waiting before its next attempt.
+///
+/// # Example
+///
+/// ```
+/// use frankie::github::rate_limit::{BackoffReason, PendingBackoff};
+///
+/// let backoff = PendingBackoff::new(BackoffReason::RetryAfter, 1, 4, 0);
+/// assert_eq!(backoff.attempt(), 1);
+/// assert_eq!(backoff.seconds_remaining(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingBackoff {
+    reason: BackoffReason,
+    attempt: u32,
+    max_attempts: u32,
+    resume_at_unix_ms: u64,
+}
+
+impl PendingBackoff {
+    /// Creates a pending backoff for retry `attempt` of `max_attempts`.
+    #[must_use]
+    pub const fn new(
+        reason: BackoffReason,
+        attempt: u32,
+        max_attempts: u32,
+        resume_at_unix_ms: u64,
+    ) -> Self {
+        Self {
+            reason,
+            attempt,
+            max_attempts,
+            resume_at_unix_ms,
+        }
+    }
+
+    /// Returns why the request is waiting.
+    #[must_use]
+    pub const fn reason(&self) -> BackoffReason {
+        self.reason
+    }
+
+    /// Returns the 1-based number of the retry being waited for.
+    #[must_use]
+    pub const fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the maximum number of retries for the request.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the Unix timestamp, in milliseconds, when the retry is sent.
+    #[must_use]
+    pub const fn resume_at_unix_ms(&self) -> u64 {
+        self.resume_at_unix_ms
+    }
+
+    /// Returns the whole seconds left before the retry, rounded up.
+    ///
+    /// Returns 0 once the resume time has passed.
+    #[must_use]
+    pub fn seconds_remaining(&self) -> u64 {
+        self.resume_at_unix_ms
+            .saturating_sub(unix_now_ms())
+            .div_ceil(1000)
+    }
+}
+
+/// Backoffs being waited out by gateway requests.
+///
+/// Every active backoff is kept, once per request waiting on it, so ending
+/// one wait still reports the others.
+#[derive(Debug, Default)]
+pub(crate) struct BackoffSlot(Mutex<Vec<PendingBackoff>>);
+
+impl BackoffSlot {
+    const fn new() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+
+    /// Returns the active backoff that ends last.
+    fn current(&self) -> Option<PendingBackoff> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .max_by_key(|backoff| backoff.resume_at_unix_ms)
+            .copied()
+    }
+
+    /// Publishes a backoff that a request is about to wait out.
+    pub(crate) fn begin(&self, backoff: PendingBackoff) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(backoff);
+    }
+
+    /// Clears one instance of `backoff` once its wait is over.
+    pub(crate) fn end(&self, backoff: PendingBackoff) {
+        let mut pending = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = pending.iter().position(|active| *active == backoff) {
+            pending.swap_remove(index);
+        }
+    }
+}
+
+/// Backoffs currently being waited out by gateway requests.
+pub(crate) static PENDING_BACKOFF: BackoffSlot = BackoffSlot::new();
+
+/// Returns the rate-limit backoff a GitHub request is currently waiting out.
+///
+/// When several requests are backing off at once, the one that ends last is
+/// reported. Returns `None` when no request is waiting.
+#[must_use]
+pub fn pending_backoff() -> Option<PendingBackoff> {
+    PENDING_BACKOFF.current()
+}
+
+/// Returns the current Unix time in milliseconds, or 0 if unavailable.
+pub(crate) fn unix_now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
 #[cfg(test)]
 mod tests {
     //! Unit tests for the `rate_limit` module.
 
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use super::RateLimitInfo;
+    use super::{BackoffReason, BackoffSlot, PendingBackoff, RateLimitInfo, unix_now_ms};
 
     #[test]
     fn seconds_until_reset_returns_zero_when_reset_has_passed() {
@@ -109,4 +268,46 @@ mod tests {
             "expected 1..=60 seconds until reset, got {seconds}"
         );
     }
+
+    #[test]
+    fn pending_backoff_reports_seconds_remaining_rounded_up() {
+        let backoff =
+            PendingBackoff::new(BackoffReason::RateLimitReset, 2, 4, unix_now_ms() + 1_500);
+
+        assert_eq!(backoff.seconds_remaining(), 2);
+        assert_eq!(backoff.reason().label(), "rate limit exhausted");
+    }
+
+    #[test]
+    fn current_backoff_is_the_one_that_ends_last() {
+        let later = PendingBackoff::new(BackoffReason::RetryAfter, 1, 4, 2);
+        let sooner = PendingBackoff::new(BackoffReason::SecondaryRateLimit, 1, 4, 1);
+
+        let slot = BackoffSlot::default();
+
+        slot.begin(later);
+        slot.begin(sooner);
+        assert_eq!(slot.current(), Some(later));
+
+        slot.end(later);
+        assert_eq!(slot.current(), Some(sooner));
+
+        slot.end(sooner);
+        assert_eq!(slot.current(), None);
+    }
+
+    #[test]
+    fn identical_backoffs_are_counted_separately() {
+        let backoff = PendingBackoff::new(BackoffReason::RateLimitReset, 1, 4, 1);
+
+        let slot = BackoffSlot::default();
+
+        slot.begin(backoff);
+        slot.begin(backoff);
+        slot.end(backoff);
+        assert_eq!(slot.current(), Some(backoff));
+
+        slot.end(backoff);
+        assert_eq!(slot.current(), None);
+    }
 }
@@ -2,10 +2,13 @@
 //!
 //! This module provides the `RepositoryIntake` facade that aggregates
 //! repository-level operations. It wraps the `RepositoryGateway` trait
-//! to provide a simplified interface for listing pull requests.
+//! to provide a simplified interface for listing and searching pull requests.
 
 use super::error::IntakeError;
-use super::gateway::{ListPullRequestsParams, PaginatedPullRequests, RepositoryGateway};
+use super::gateway::{
+    ListPullRequestsParams, PaginatedPullRequests, RepositoryGateway, SearchPullRequestsParams,
+    SearchScope,
+};
 use super::repository_locator::RepositoryLocator;
 
 /// Aggregates repository-level operations using a gateway.
@@ -53,4 +56,46 @@ where
     ) -> Result<PaginatedPullRequests, IntakeError> {
         self.client.list_pull_requests(locator, params).await
     }
+
+    /// Search pull requests with GitHub search qualifiers.
+    ///
+    /// The result carries the search API's rate limit when GitHub reports
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query is rejected by GitHub or the request
+    /// fails due to authentication, network issues, or rate limiting.
+    pub async fn search_pull_requests(
+        &self,
+        params: &SearchPullRequestsParams,
+    ) -> Result<PaginatedPullRequests, IntakeError> {
+        self.client.search_pull_requests(params).await
+    }
+
+    /// Returns `params` with an organization scope replaced by a user scope
+    /// when the owner is a user account.
+    ///
+    /// GitHub's `org:` qualifier matches nothing for user accounts, which
+    /// need `user:` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner cannot be looked up, for example
+    /// because the account does not exist.
+    pub async fn resolve_search_scope(
+        &self,
+        params: SearchPullRequestsParams,
+    ) -> Result<SearchPullRequestsParams, IntakeError> {
+        let SearchScope::Organization(owner) = &params.scope else {
+            return Ok(params);
+        };
+        if self.client.is_organization(owner).await? {
+            return Ok(params);
+        }
+        Ok(SearchPullRequestsParams {
+            scope: SearchScope::User(owner.clone()),
+            ..params
+        })
+    }
 }
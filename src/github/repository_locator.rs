@@ -7,7 +7,11 @@
 use url::Url;
 
 use super::error::IntakeError;
-use super::locator::{RepositoryName, RepositoryOwner, parse_owner_repo_and_api};
+use super::locator::{
+    RepositoryName, RepositoryOwner, derive_api_base_from_host, parse_owner_repo_and_api,
+    web_host_for_api_base,
+};
+use crate::local::GitHubOrigin;
 
 /// Parsed repository URL with derived API base.
 ///
@@ -78,6 +82,15 @@ impl RepositoryLocator {
         &self.api_base
     }
 
+    /// Web host derived from the API base URL.
+    ///
+    /// Returns `"github.com"` for standard GitHub repositories and the API
+    /// base host for GitHub Enterprise installations.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        web_host_for_api_base(&self.api_base)
+    }
+
     /// Repository owner.
     #[must_use]
     pub const fn owner(&self) -> &RepositoryOwner {
@@ -102,7 +115,8 @@ impl RepositoryLocator {
     /// Creates a repository locator from a discovered GitHub origin.
     ///
     /// For standard `github.com` origins, uses the public API base. For GitHub
-    /// Enterprise origins, derives the API base from the host.
+    /// Enterprise origins, derives `https://<host>[:<port>]/api/v3` from the
+    /// discovered host and port.
     ///
     /// # Errors
     ///
@@ -124,24 +138,15 @@ impl RepositoryLocator {
     /// assert_eq!(locator.owner().as_str(), "octo");
     /// assert_eq!(locator.repository().as_str(), "cat");
     /// ```
-    pub fn from_github_origin(origin: &crate::local::GitHubOrigin) -> Result<Self, IntakeError> {
-        match origin {
-            crate::local::GitHubOrigin::GitHubCom { owner, repository } => {
-                Self::from_owner_repo(owner, repository)
-            }
-            crate::local::GitHubOrigin::Enterprise {
-                host,
-                port,
-                owner,
-                repository,
-            } => {
-                // Build a URL to parse and derive API base, preserving port
-                let url = port.map_or_else(
-                    || format!("https://{host}/{owner}/{repository}"),
-                    |p| format!("https://{host}:{p}/{owner}/{repository}"),
-                );
-                Self::parse(&url)
-            }
-        }
+    pub fn from_github_origin(origin: &GitHubOrigin) -> Result<Self, IntakeError> {
+        let owner = RepositoryOwner::new(origin.owner())?;
+        let repository = RepositoryName::new(origin.repository())?;
+        let api_base = derive_api_base_from_host("https", origin.host(), origin.port())?;
+
+        Ok(Self {
+            api_base,
+            owner,
+            repository,
+        })
     }
 }
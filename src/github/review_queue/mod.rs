@@ -0,0 +1,298 @@
+//! Cross-repository review queue.
+//!
+//! [`ReviewQueueService`] gathers the open pull requests that need the
+//! authenticated user's attention: those where their review is requested,
+//! those they have commented on, and, optionally, those matching an extra
+//! search. Results from the separate searches are merged per pull request,
+//! restricted to the configured repositories, and ranked by
+//! [`rank_review_queue`] so that unread activity comes first and, within
+//! that, the pull requests that have waited longest.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use super::error::IntakeError;
+use super::gateway::{RepositoryGateway, SearchPullRequestsParams, SearchScope};
+use super::models::PullRequestSummary;
+
+/// Results requested from each search; GitHub's maximum page size.
+const QUEUE_PAGE_SIZE: u8 = 100;
+
+/// Longest `q` parameter GitHub's search API accepts.
+const MAX_SEARCH_QUERY_LENGTH: usize = 256;
+
+/// Qualifier the gateway prepends to every pull request search.
+const PULL_REQUEST_QUALIFIER: &str = "is:pr ";
+
+/// Why a pull request is in the review queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReviewQueueReason {
+    /// The user's review is requested.
+    ReviewRequested,
+    /// The user has commented, so replies may be waiting.
+    Commented,
+    /// The pull request matches the configured extra search.
+    Matched,
+}
+
+impl ReviewQueueReason {
+    /// Returns the stable key used to store the reason.
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::ReviewRequested => "review_requested",
+            Self::Commented => "commented",
+            Self::Matched => "matched",
+        }
+    }
+
+    /// Parses a key produced by [`Self::key`].
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        [Self::ReviewRequested, Self::Commented, Self::Matched]
+            .into_iter()
+            .find(|reason| reason.key() == key)
+    }
+
+    const fn qualifiers(self) -> &'static str {
+        match self {
+            Self::ReviewRequested => "is:open review-requested:@me",
+            Self::Commented => "is:open commenter:@me",
+            Self::Matched => "",
+        }
+    }
+}
+
+impl fmt::Display for ReviewQueueReason {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::ReviewRequested => "review requested",
+            Self::Commented => "you commented",
+            Self::Matched => "matches search",
+        })
+    }
+}
+
+/// A pull request in the review queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewQueueEntry {
+    /// The pull request, including its repository.
+    pub pull_request: PullRequestSummary,
+    /// Why the pull request is queued, sorted and without duplicates.
+    pub reasons: Vec<ReviewQueueReason>,
+    /// The pull request's `updated_at` when the user last opened it from
+    /// the queue, if ever.
+    pub last_seen_updated_at: Option<String>,
+}
+
+impl ReviewQueueEntry {
+    /// Returns `true` when the pull request changed since the user last
+    /// opened it, or has never been opened.
+    #[must_use]
+    pub fn has_unread_activity(&self) -> bool {
+        match (
+            self.last_seen_updated_at.as_deref(),
+            self.pull_request.updated_at.as_deref(),
+        ) {
+            (Some(seen), Some(updated)) => updated > seen,
+            (Some(_), None) => false,
+            (None, _) => true,
+        }
+    }
+
+    /// Returns how long the pull request has gone without an update, as of
+    /// `now`.
+    ///
+    /// Returns `None` when `updated_at` is missing or not RFC 3339.
+    #[must_use]
+    pub fn idle_for(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        let updated = self.pull_request.updated_at.as_deref()?;
+        let updated_at = DateTime::parse_from_rfc3339(updated).ok()?;
+        Some(now.signed_duration_since(updated_at))
+    }
+}
+
+/// Which pull requests the review queue gathers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewQueueRequest {
+    /// `owner/repo` names to keep; empty keeps every repository.
+    pub repositories: Vec<String>,
+    /// Extra search qualifiers whose results are also queued.
+    pub search: Option<String>,
+}
+
+impl ReviewQueueRequest {
+    fn includes(&self, pull_request: &PullRequestSummary) -> bool {
+        if self.repositories.is_empty() {
+            return true;
+        }
+        pull_request.repository.as_deref().is_some_and(|name| {
+            self.repositories
+                .iter()
+                .any(|wanted| wanted.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Returns the queries for each reason, limited to the configured
+    /// repositories so that results elsewhere cannot crowd them out of the
+    /// first page.
+    fn searches(&self) -> Vec<(ReviewQueueReason, String)> {
+        let mut searches = [
+            ReviewQueueReason::ReviewRequested,
+            ReviewQueueReason::Commented,
+        ]
+        .into_iter()
+        .map(|reason| (reason, reason.qualifiers().to_owned()))
+        .collect::<Vec<_>>();
+        if let Some(query) = self
+            .search
+            .as_deref()
+            .filter(|query| !query.trim().is_empty())
+        {
+            searches.push((ReviewQueueReason::Matched, query.trim().to_owned()));
+        }
+        searches
+            .into_iter()
+            .flat_map(|(reason, query)| {
+                self.with_repositories(&query)
+                    .into_iter()
+                    .map(move |limited| (reason, limited))
+            })
+            .collect()
+    }
+
+    /// Appends a `repo:` qualifier per configured repository to `query`.
+    ///
+    /// GitHub rejects queries longer than [`MAX_SEARCH_QUERY_LENGTH`], so
+    /// the repositories are spread over as many copies of `query` as needed.
+    fn with_repositories(&self, query: &str) -> Vec<String> {
+        let budget = MAX_SEARCH_QUERY_LENGTH - PULL_REQUEST_QUALIFIER.len();
+        let mut queries = Vec::new();
+        let mut current = query.to_owned();
+        for name in &self.repositories {
+            let qualifier = format!(" repo:{name}");
+            if current.len() > query.len() && current.len() + qualifier.len() > budget {
+                queries.push(std::mem::replace(&mut current, query.to_owned()));
+            }
+            current.push_str(&qualifier);
+        }
+        queries.push(current);
+        queries
+    }
+}
+
+/// Gathers the review queue through a [`RepositoryGateway`].
+pub struct ReviewQueueService<'client, Gateway>
+where
+    Gateway: RepositoryGateway,
+{
+    gateway: &'client Gateway,
+}
+
+impl<'client, Gateway> ReviewQueueService<'client, Gateway>
+where
+    Gateway: RepositoryGateway,
+{
+    /// Creates a service searching through `gateway`.
+    #[must_use]
+    pub const fn new(gateway: &'client Gateway) -> Self {
+        Self { gateway }
+    }
+
+    /// Runs the searches for each reason and merges the results.
+    ///
+    /// Every page of each search is fetched, most recently updated first,
+    /// up to the 1,000 results GitHub's search serves. Entries are returned
+    /// unranked and without `last_seen_updated_at`; see
+    /// [`rank_review_queue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first search error, such as a rejected query or an
+    /// exhausted search rate limit.
+    pub async fn fetch(
+        &self,
+        request: &ReviewQueueRequest,
+    ) -> Result<Vec<ReviewQueueEntry>, IntakeError> {
+        let mut merged: BTreeMap<String, ReviewQueueEntry> = BTreeMap::new();
+        for (reason, query) in request.searches() {
+            for pull_request in self
+                .search_every_page(query)
+                .await?
+                .into_iter()
+                .filter(|pull_request| request.includes(pull_request))
+            {
+                add_reason(&mut merged, pull_request, reason);
+            }
+        }
+        Ok(merged.into_values().collect())
+    }
+
+    /// Returns the results of `query`, following next-page links until
+    /// GitHub reports no further page.
+    async fn search_every_page(
+        &self,
+        query: String,
+    ) -> Result<Vec<PullRequestSummary>, IntakeError> {
+        let mut params = SearchPullRequestsParams {
+            per_page: Some(QUEUE_PAGE_SIZE),
+            ..SearchPullRequestsParams::new(query, SearchScope::All)
+        };
+        let mut pull_requests = Vec::new();
+        loop {
+            let results = self.gateway.search_pull_requests(&params).await?;
+            pull_requests.extend(results.items);
+            if !results.page_info.has_next() {
+                return Ok(pull_requests);
+            }
+            params.page = Some(results.page_info.current_page().saturating_add(1));
+        }
+    }
+}
+
+fn add_reason(
+    merged: &mut BTreeMap<String, ReviewQueueEntry>,
+    pull_request: PullRequestSummary,
+    reason: ReviewQueueReason,
+) {
+    let entry = merged
+        .entry(pull_request.reference().to_ascii_lowercase())
+        .or_insert_with(|| ReviewQueueEntry {
+            pull_request,
+            reasons: Vec::new(),
+            last_seen_updated_at: None,
+        });
+    if !entry.reasons.contains(&reason) {
+        entry.reasons.push(reason);
+        entry.reasons.sort_unstable();
+    }
+}
+
+/// Orders the queue so the most urgent pull requests come first.
+///
+/// Pull requests with unread activity come before those already seen, and
+/// requested reviews before the rest. Within each group the pull request
+/// idle the longest (oldest `updated_at`) comes first.
+pub fn rank_review_queue(entries: &mut [ReviewQueueEntry]) {
+    entries.sort_by(|left, right| {
+        right
+            .has_unread_activity()
+            .cmp(&left.has_unread_activity())
+            .then_with(|| left.reasons.first().cmp(&right.reasons.first()))
+            .then_with(|| {
+                left.pull_request
+                    .updated_at
+                    .cmp(&right.pull_request.updated_at)
+            })
+            .then_with(|| {
+                left.pull_request
+                    .reference()
+                    .cmp(&right.pull_request.reference())
+            })
+    });
+}
+
+#[cfg(test)]
+mod tests;
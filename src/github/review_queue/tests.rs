@@ -0,0 +1,308 @@
+//! Tests for review queue gathering and ranking.
+
+use chrono::{TimeZone, Utc};
+use rstest::rstest;
+
+use super::{
+    ReviewQueueEntry, ReviewQueueReason, ReviewQueueRequest, ReviewQueueService, rank_review_queue,
+};
+use crate::github::error::IntakeError;
+use crate::github::gateway::{
+    MockRepositoryGateway, PaginatedPullRequests, SearchPullRequestsParams, SearchScope,
+};
+use crate::github::models::PullRequestSummary;
+use crate::github::pagination::PageInfo;
+
+fn summary(repository: &str, number: u64, updated_at: &str) -> PullRequestSummary {
+    PullRequestSummary {
+        number,
+        repository: Some(repository.to_owned()),
+        title: Some(format!("Change {number}")),
+        updated_at: Some(updated_at.to_owned()),
+        ..PullRequestSummary::default()
+    }
+}
+
+fn entry(
+    pull_request: PullRequestSummary,
+    reasons: &[ReviewQueueReason],
+    last_seen_updated_at: Option<&str>,
+) -> ReviewQueueEntry {
+    ReviewQueueEntry {
+        pull_request,
+        reasons: reasons.to_vec(),
+        last_seen_updated_at: last_seen_updated_at.map(str::to_owned),
+    }
+}
+
+fn listing(items: Vec<PullRequestSummary>) -> PaginatedPullRequests {
+    PaginatedPullRequests {
+        items,
+        page_info: PageInfo::default(),
+        rate_limit: None,
+    }
+}
+
+/// Answers each search with the results registered for its qualifiers.
+fn gateway(responses: Vec<(&'static str, Vec<PullRequestSummary>)>) -> MockRepositoryGateway {
+    let mut gateway = MockRepositoryGateway::new();
+    gateway
+        .expect_search_pull_requests()
+        .returning(move |params| {
+            let query = params.search_query();
+            let items = responses
+                .iter()
+                .find(|(qualifier, _)| query.contains(qualifier))
+                .map_or_else(Vec::new, |(_, items)| items.clone());
+            Ok(listing(items))
+        });
+    gateway
+}
+
+#[tokio::test]
+async fn fetch_merges_reasons_and_keeps_configured_repositories() {
+    let gateway = gateway(vec![
+        (
+            "review-requested:@me",
+            vec![
+                summary("octo/app", 1, "2026-04-10T00:00:00Z"),
+                summary("other/lib", 2, "2026-04-11T00:00:00Z"),
+            ],
+        ),
+        (
+            "commenter:@me",
+            vec![
+                summary("Octo/App", 1, "2026-04-10T00:00:00Z"),
+                summary("octo/web", 3, "2026-04-12T00:00:00Z"),
+            ],
+        ),
+    ]);
+    let request = ReviewQueueRequest {
+        repositories: vec!["octo/app".to_owned(), "octo/web".to_owned()],
+        search: None,
+    };
+
+    let entries = ReviewQueueService::new(&gateway)
+        .fetch(&request)
+        .await
+        .expect("fetch should succeed");
+
+    let queued = entries
+        .iter()
+        .map(|entry| (entry.pull_request.number, entry.reasons.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        queued,
+        vec![
+            (
+                1,
+                vec![
+                    ReviewQueueReason::ReviewRequested,
+                    ReviewQueueReason::Commented
+                ]
+            ),
+            (3, vec![ReviewQueueReason::Commented]),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn fetch_runs_the_extra_search_and_propagates_errors() {
+    let mut gateway = MockRepositoryGateway::new();
+    gateway
+        .expect_search_pull_requests()
+        .times(3)
+        .returning(|params| {
+            if params.search_query().contains("label:urgent") {
+                return Err(IntakeError::RateLimitExceeded {
+                    rate_limit: None,
+                    message: "search rate limit".to_owned(),
+                });
+            }
+            Ok(listing(Vec::new()))
+        });
+    let request = ReviewQueueRequest {
+        repositories: Vec::new(),
+        search: Some("label:urgent".to_owned()),
+    };
+
+    let result = ReviewQueueService::new(&gateway).fetch(&request).await;
+
+    assert!(
+        matches!(result, Err(IntakeError::RateLimitExceeded { .. })),
+        "unexpected result: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn fetch_limits_every_search_to_configured_repositories() {
+    let mut gateway = MockRepositoryGateway::new();
+    gateway
+        .expect_search_pull_requests()
+        .times(3)
+        .withf(|params| {
+            params
+                .search_query()
+                .ends_with(" repo:octo/app repo:octo/web")
+        })
+        .returning(|_| Ok(listing(Vec::new())));
+    let request = ReviewQueueRequest {
+        repositories: vec!["octo/app".to_owned(), "octo/web".to_owned()],
+        search: Some(" label:urgent ".to_owned()),
+    };
+
+    let entries = ReviewQueueService::new(&gateway)
+        .fetch(&request)
+        .await
+        .expect("fetch should succeed");
+
+    assert!(entries.is_empty());
+}
+
+#[tokio::test]
+async fn fetch_follows_every_result_page() {
+    let mut gateway = MockRepositoryGateway::new();
+    gateway.expect_search_pull_requests().returning(|params| {
+        if !params.search_query().contains("review-requested:@me") {
+            return Ok(listing(Vec::new()));
+        }
+        let page = params.page.unwrap_or(1);
+        Ok(PaginatedPullRequests {
+            page_info: PageInfo::builder(page, 100).has_next(page < 3).build(),
+            ..listing(vec![summary(
+                "octo/app",
+                u64::from(page),
+                "2026-04-10T00:00:00Z",
+            )])
+        })
+    });
+
+    let entries = ReviewQueueService::new(&gateway)
+        .fetch(&ReviewQueueRequest::default())
+        .await
+        .expect("fetch should succeed");
+
+    let numbers = entries
+        .iter()
+        .map(|entry| entry.pull_request.number)
+        .collect::<Vec<_>>();
+    assert_eq!(numbers, vec![1, 2, 3]);
+}
+
+#[rstest]
+fn searches_split_many_repositories_across_queries_github_accepts() {
+    let repositories = (0..40)
+        .map(|index| format!("octo-organisation/repository-{index:02}"))
+        .collect::<Vec<_>>();
+    let request = ReviewQueueRequest {
+        repositories: repositories.clone(),
+        search: None,
+    };
+
+    let searches = request.searches();
+
+    assert!(searches.len() > 2, "searches: {searches:?}");
+    for reason in [
+        ReviewQueueReason::ReviewRequested,
+        ReviewQueueReason::Commented,
+    ] {
+        let queries = searches
+            .iter()
+            .filter(|(searched, _)| *searched == reason)
+            .map(|(_, query)| SearchPullRequestsParams::new(query, SearchScope::All).search_query())
+            .collect::<Vec<_>>();
+        assert!(
+            queries.iter().all(|query| query.len() <= 256),
+            "{queries:?}"
+        );
+        let searched = queries
+            .iter()
+            .flat_map(|query| {
+                query
+                    .split(' ')
+                    .filter_map(|term| term.strip_prefix("repo:"))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(searched, repositories);
+    }
+}
+
+#[rstest]
+#[case::never_seen(None, Some("2026-04-10T00:00:00Z"), true)]
+#[case::updated_since(Some("2026-04-09T00:00:00Z"), Some("2026-04-10T00:00:00Z"), true)]
+#[case::seen(Some("2026-04-10T00:00:00Z"), Some("2026-04-10T00:00:00Z"), false)]
+#[case::no_timestamp(Some("2026-04-10T00:00:00Z"), None, false)]
+fn unread_activity_compares_updates_with_the_last_visit(
+    #[case] last_seen: Option<&str>,
+    #[case] updated_at: Option<&str>,
+    #[case] expected: bool,
+) {
+    let queued = entry(
+        PullRequestSummary {
+            updated_at: updated_at.map(str::to_owned),
+            ..PullRequestSummary::default()
+        },
+        &[ReviewQueueReason::Commented],
+        last_seen,
+    );
+
+    assert_eq!(queued.has_unread_activity(), expected);
+}
+
+#[rstest]
+fn idle_for_measures_time_since_the_last_update() {
+    let queued = entry(
+        summary("octo/app", 1, "2026-04-10T00:00:00Z"),
+        &[ReviewQueueReason::ReviewRequested],
+        None,
+    );
+    let now = Utc
+        .with_ymd_and_hms(2026, 4, 13, 6, 0, 0)
+        .single()
+        .expect("timestamp should be valid");
+
+    assert_eq!(queued.idle_for(now).map(|idle| idle.num_hours()), Some(78));
+}
+
+#[rstest]
+fn rank_puts_unread_then_requested_then_stalest_first() {
+    let seen = "2026-04-20T00:00:00Z";
+    let mut entries = vec![
+        entry(
+            summary("octo/app", 1, "2026-04-15T00:00:00Z"),
+            &[ReviewQueueReason::ReviewRequested],
+            Some(seen),
+        ),
+        entry(
+            summary("octo/app", 2, "2026-04-12T00:00:00Z"),
+            &[ReviewQueueReason::Commented],
+            None,
+        ),
+        entry(
+            summary("octo/app", 3, "2026-04-14T00:00:00Z"),
+            &[ReviewQueueReason::ReviewRequested],
+            None,
+        ),
+        entry(
+            summary("octo/app", 4, "2026-04-11T00:00:00Z"),
+            &[ReviewQueueReason::ReviewRequested],
+            None,
+        ),
+    ];
+
+    rank_review_queue(&mut entries);
+
+    let order = entries
+        .iter()
+        .map(|queued| queued.pull_request.number)
+        .collect::<Vec<_>>();
+    assert_eq!(order, vec![4, 3, 2, 1]);
+}
+
+#[rstest]
+#[case::requested(ReviewQueueReason::ReviewRequested)]
+#[case::commented(ReviewQueueReason::Commented)]
+#[case::matched(ReviewQueueReason::Matched)]
+fn reason_keys_round_trip(#[case] reason: ReviewQueueReason) {
+    assert_eq!(ReviewQueueReason::from_key(reason.key()), Some(reason));
+}
@@ -0,0 +1,384 @@
+//! Pull request review submission types.
+//!
+//! A review bundles a summary body, an event (approve, request changes, or
+//! comment), and any number of inline comments into a single GitHub review.
+//! [`ReviewSubmission`] is the transport-neutral description of that review;
+//! gateways translate it into the REST payload.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::error::IntakeError;
+
+/// Verdict attached to a submitted review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReviewEvent {
+    /// Leave general feedback without explicit approval.
+    #[default]
+    Comment,
+    /// Approve the pull request.
+    Approve,
+    /// Request changes before the pull request can be merged.
+    RequestChanges,
+}
+
+impl ReviewEvent {
+    /// Returns the GitHub API value for this event.
+    #[must_use]
+    pub const fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Comment => "COMMENT",
+            Self::Approve => "APPROVE",
+            Self::RequestChanges => "REQUEST_CHANGES",
+        }
+    }
+
+    /// Returns the next event in display order, wrapping at the end.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Comment => Self::Approve,
+            Self::Approve => Self::RequestChanges,
+            Self::RequestChanges => Self::Comment,
+        }
+    }
+}
+
+impl fmt::Display for ReviewEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Comment => "Comment",
+            Self::Approve => "Approve",
+            Self::RequestChanges => "Request changes",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Side of a diff that an inline comment is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffSide {
+    /// The base version (deleted or unchanged lines).
+    Left,
+    /// The head version (added or unchanged lines).
+    #[default]
+    Right,
+}
+
+impl DiffSide {
+    /// Returns the GitHub API value for this side.
+    #[must_use]
+    pub const fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+        }
+    }
+
+    /// Parses a GitHub API side value (`LEFT` or `RIGHT`).
+    #[must_use]
+    pub fn from_api_str(value: &str) -> Option<Self> {
+        match value {
+            "LEFT" => Some(Self::Left),
+            "RIGHT" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Inline comment composed locally and submitted as part of a review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingReviewComment {
+    /// Repository-relative file path.
+    pub path: String,
+    /// Line number in the file on the chosen diff side.
+    pub line: u32,
+    /// Diff side the line refers to.
+    pub side: DiffSide,
+    /// Comment body.
+    pub body: String,
+}
+
+impl PendingReviewComment {
+    /// Creates an inline comment on the head side of the diff.
+    #[must_use]
+    pub fn new(path: impl Into<String>, line: u32, body: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            line,
+            side: DiffSide::Right,
+            body: body.into(),
+        }
+    }
+
+    /// Sets the diff side the comment is anchored to.
+    #[must_use]
+    pub const fn with_side(mut self, side: DiffSide) -> Self {
+        self.side = side;
+        self
+    }
+}
+
+/// A complete pull request review ready to submit.
+///
+/// # Example
+///
+/// ```
+/// use frankie::github::{PendingReviewComment, ReviewEvent, ReviewSubmission};
+///
+/// let submission = ReviewSubmission::new(ReviewEvent::RequestChanges, "Needs tests")
+///     .with_comment(PendingReviewComment::new("src/lib.rs", 12, "Cover this branch"));
+///
+/// assert!(submission.validate().is_ok());
+/// assert_eq!(submission.comments().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewSubmission {
+    event: ReviewEvent,
+    body: String,
+    comments: Vec<PendingReviewComment>,
+    commit_id: Option<String>,
+}
+
+impl ReviewSubmission {
+    /// Creates a review with the given event and summary body.
+    #[must_use]
+    pub fn new(event: ReviewEvent, body: impl Into<String>) -> Self {
+        Self {
+            event,
+            body: body.into(),
+            comments: Vec::new(),
+            commit_id: None,
+        }
+    }
+
+    /// Adds an inline comment to the review.
+    #[must_use]
+    pub fn with_comment(mut self, comment: PendingReviewComment) -> Self {
+        self.comments.push(comment);
+        self
+    }
+
+    /// Adds several inline comments to the review.
+    #[must_use]
+    pub fn with_comments(
+        mut self,
+        comments: impl IntoIterator<Item = PendingReviewComment>,
+    ) -> Self {
+        self.comments.extend(comments);
+        self
+    }
+
+    /// Pins the review to a specific commit.
+    ///
+    /// When unset, GitHub uses the pull request's latest commit.
+    #[must_use]
+    pub fn with_commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// Returns the review event.
+    #[must_use]
+    pub const fn event(&self) -> ReviewEvent {
+        self.event
+    }
+
+    /// Returns the summary body.
+    #[must_use]
+    pub const fn body(&self) -> &str {
+        self.body.as_str()
+    }
+
+    /// Returns the inline comments.
+    #[must_use]
+    pub fn comments(&self) -> &[PendingReviewComment] {
+        &self.comments
+    }
+
+    /// Returns the commit the review is pinned to, if any.
+    #[must_use]
+    pub fn commit_id(&self) -> Option<&str> {
+        self.commit_id.as_deref()
+    }
+
+    /// Checks the review against GitHub's submission rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when a request-changes review
+    /// has no body, a comment review has neither body nor inline comments, or
+    /// an inline comment has an empty path, zero line, or empty body.
+    pub fn validate(&self) -> Result<(), IntakeError> {
+        let has_body = !self.body.trim().is_empty();
+        match self.event {
+            ReviewEvent::RequestChanges if !has_body => {
+                return Err(invalid("requesting changes requires a review summary"));
+            }
+            ReviewEvent::Comment if !has_body && self.comments.is_empty() => {
+                return Err(invalid(
+                    "a comment review requires a summary or at least one inline comment",
+                ));
+            }
+            _ => {}
+        }
+
+        self.comments.iter().try_for_each(validate_comment)
+    }
+
+    pub(crate) fn to_payload(&self) -> ReviewSubmissionPayload<'_> {
+        ReviewSubmissionPayload {
+            commit_id: self.commit_id.as_deref(),
+            body: self.body.as_str(),
+            event: self.event.as_api_str(),
+            comments: self
+                .comments
+                .iter()
+                .map(|comment| ReviewCommentPayload {
+                    path: comment.path.as_str(),
+                    line: comment.line,
+                    side: comment.side.as_api_str(),
+                    body: comment.body.as_str(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Review created on GitHub by a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmittedReview {
+    /// Review identifier.
+    pub id: u64,
+    /// Review state reported by GitHub (for example, `APPROVED`).
+    pub state: Option<String>,
+    /// Browser URL of the review.
+    pub html_url: Option<String>,
+}
+
+/// REST request body for creating a review.
+#[derive(Debug, Serialize)]
+pub(crate) struct ReviewSubmissionPayload<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<&'a str>,
+    body: &'a str,
+    event: &'static str,
+    comments: Vec<ReviewCommentPayload<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewCommentPayload<'a> {
+    path: &'a str,
+    line: u32,
+    side: &'static str,
+    body: &'a str,
+}
+
+fn validate_comment(comment: &PendingReviewComment) -> Result<(), IntakeError> {
+    if comment.path.trim().is_empty() {
+        return Err(invalid("inline review comments require a file path"));
+    }
+    if comment.line == 0 {
+        return Err(invalid(&format!(
+            "inline review comment on {} requires a line number",
+            comment.path
+        )));
+    }
+    if comment.body.trim().is_empty() {
+        return Err(invalid(&format!(
+            "inline review comment on {}:{} is empty",
+            comment.path, comment.line
+        )));
+    }
+    Ok(())
+}
+
+fn invalid(message: &str) -> IntakeError {
+    IntakeError::Configuration {
+        message: message.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for review submission validation and payloads.
+
+    use rstest::rstest;
+
+    use super::{DiffSide, PendingReviewComment, ReviewEvent, ReviewSubmission};
+
+    #[rstest]
+    #[case(ReviewEvent::Approve, "", 0, true)]
+    #[case(ReviewEvent::Comment, "", 0, false)]
+    #[case(ReviewEvent::Comment, "", 1, true)]
+    #[case(ReviewEvent::RequestChanges, "   ", 1, false)]
+    #[case(ReviewEvent::RequestChanges, "Please add tests", 0, true)]
+    fn validate_applies_event_rules(
+        #[case] event: ReviewEvent,
+        #[case] body: &str,
+        #[case] comment_count: usize,
+        #[case] expected_valid: bool,
+    ) {
+        let submission = ReviewSubmission::new(event, body).with_comments(
+            (0..comment_count).map(|_| PendingReviewComment::new("src/lib.rs", 3, "nit")),
+        );
+
+        assert_eq!(submission.validate().is_ok(), expected_valid);
+    }
+
+    #[rstest]
+    #[case(PendingReviewComment::new("", 3, "nit"), "file path")]
+    #[case(PendingReviewComment::new("src/lib.rs", 0, "nit"), "line number")]
+    #[case(PendingReviewComment::new("src/lib.rs", 3, " "), "is empty")]
+    fn validate_rejects_incomplete_inline_comments(
+        #[case] comment: PendingReviewComment,
+        #[case] expected_fragment: &str,
+    ) {
+        let submission = ReviewSubmission::new(ReviewEvent::Approve, "").with_comment(comment);
+
+        let error = submission
+            .validate()
+            .expect_err("comment should be rejected");
+
+        assert!(error.to_string().contains(expected_fragment), "{error}");
+    }
+
+    #[rstest]
+    fn payload_serializes_event_and_sides() {
+        let submission = ReviewSubmission::new(ReviewEvent::RequestChanges, "Needs work")
+            .with_commit_id("abc123")
+            .with_comment(
+                PendingReviewComment::new("src/lib.rs", 7, "Removed too early")
+                    .with_side(DiffSide::Left),
+            );
+
+        let payload =
+            serde_json::to_value(submission.to_payload()).expect("payload should serialize");
+
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "commit_id": "abc123",
+                "body": "Needs work",
+                "event": "REQUEST_CHANGES",
+                "comments": [{
+                    "path": "src/lib.rs",
+                    "line": 7,
+                    "side": "LEFT",
+                    "body": "Removed too early"
+                }]
+            })
+        );
+    }
+
+    #[rstest]
+    fn event_cycles_through_all_values() {
+        let start = ReviewEvent::default();
+
+        assert_eq!(start, ReviewEvent::Comment);
+        assert_eq!(start.next(), ReviewEvent::Approve);
+        assert_eq!(start.next().next(), ReviewEvent::RequestChanges);
+        assert_eq!(start.next().next().next(), ReviewEvent::Comment);
+    }
+}
@@ -0,0 +1,335 @@
+//! Incremental review comment sync.
+//!
+//! [`ReviewSyncService`] brings a host's copy of a pull request's review
+//! comments up to date without downloading every comment again. It keeps a
+//! per-pull-request checkpoint (the latest `updated_at` seen) and asks GitHub
+//! only for comments edited since then. GitHub's `since` filter never reports
+//! deletions, so the service compares the pull request's review comment count
+//! with the merged result and falls back to a full listing when they differ.
+//! Either way the caller receives a [`ReviewSyncDelta`] describing exactly
+//! what changed.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::persistence::SyncCheckpointStore;
+
+use super::error::IntakeError;
+use super::gateway::{ReviewCommentGateway, apply_thread_states, log_thread_state_failure};
+use super::locator::PullRequestLocator;
+use super::models::ReviewComment;
+
+/// Resource name under which review comment checkpoints are stored.
+const CHECKPOINT_RESOURCE: &str = "review_comments";
+
+/// Position up to which a pull request's review comments have been synced.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReviewSyncCheckpoint {
+    updated_at: String,
+}
+
+impl ReviewSyncCheckpoint {
+    /// Creates a checkpoint at the given ISO 8601 `updated_at` timestamp.
+    #[must_use]
+    pub fn new(updated_at: impl Into<String>) -> Self {
+        Self {
+            updated_at: updated_at.into(),
+        }
+    }
+
+    /// Returns the latest comment `updated_at` covered by the checkpoint.
+    #[must_use]
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// Returns a checkpoint at the newest `updated_at` among `comments`.
+    ///
+    /// Returns `None` when no comment carries an `updated_at` timestamp.
+    #[must_use]
+    pub fn latest(comments: &[ReviewComment]) -> Option<Self> {
+        comments
+            .iter()
+            .filter_map(|comment| comment.updated_at.as_deref())
+            .max()
+            .map(Self::new)
+    }
+}
+
+/// Changes to a pull request's review comments since the last sync.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewSyncDelta {
+    /// Comments that were not known before, sorted by ID.
+    pub added: Vec<ReviewComment>,
+    /// Known comments whose content or thread state changed, sorted by ID.
+    pub updated: Vec<ReviewComment>,
+    /// IDs of known comments that no longer exist, sorted ascending.
+    pub removed: Vec<u64>,
+    /// Checkpoint to resume from on the next sync, if any comment has an
+    /// `updated_at` timestamp.
+    pub checkpoint: Option<ReviewSyncCheckpoint>,
+}
+
+impl ReviewSyncDelta {
+    /// Computes the delta that turns `known` into `current`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use frankie::github::ReviewSyncDelta;
+    /// use frankie::github::models::ReviewComment;
+    ///
+    /// let known = vec![
+    ///     ReviewComment { id: 1, body: Some("Old".into()), ..Default::default() },
+    ///     ReviewComment { id: 2, ..Default::default() },
+    /// ];
+    /// let current = vec![
+    ///     ReviewComment { id: 1, body: Some("Edited".into()), ..Default::default() },
+    ///     ReviewComment { id: 3, ..Default::default() },
+    /// ];
+    ///
+    /// let delta = ReviewSyncDelta::between(&known, current.clone());
+    /// assert_eq!(delta.added.len(), 1);
+    /// assert_eq!(delta.updated.len(), 1);
+    /// assert_eq!(delta.removed, vec![2]);
+    /// assert_eq!(delta.apply(&known), current);
+    /// ```
+    #[must_use]
+    pub fn between(known: &[ReviewComment], current: Vec<ReviewComment>) -> Self {
+        let known_by_id: HashMap<u64, &ReviewComment> =
+            known.iter().map(|comment| (comment.id, comment)).collect();
+        let current_ids: HashSet<u64> = current.iter().map(|comment| comment.id).collect();
+        let checkpoint = ReviewSyncCheckpoint::latest(&current);
+
+        let mut delta = Self {
+            checkpoint,
+            ..Self::default()
+        };
+        for comment in current {
+            match known_by_id.get(&comment.id) {
+                None => delta.added.push(comment),
+                Some(previous) if **previous != comment => delta.updated.push(comment),
+                Some(_) => {}
+            }
+        }
+        delta.added.sort_by_key(|comment| comment.id);
+        delta.updated.sort_by_key(|comment| comment.id);
+        delta.removed = known
+            .iter()
+            .map(|comment| comment.id)
+            .filter(|id| !current_ids.contains(id))
+            .collect();
+        delta.removed.sort_unstable();
+        delta
+    }
+
+    /// Returns true when nothing was added, updated, or removed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    /// Applies the delta to `known`, returning the comments sorted by ID.
+    #[must_use]
+    pub fn apply(&self, known: &[ReviewComment]) -> Vec<ReviewComment> {
+        let mut comments: BTreeMap<u64, ReviewComment> = known
+            .iter()
+            .map(|comment| (comment.id, comment.clone()))
+            .collect();
+        for id in &self.removed {
+            comments.remove(id);
+        }
+        for comment in self.added.iter().chain(&self.updated) {
+            comments.insert(comment.id, comment.clone());
+        }
+        comments.into_values().collect()
+    }
+}
+
+/// Syncs review comments incrementally through a [`ReviewCommentGateway`].
+pub struct ReviewSyncService<'client, Gateway>
+where
+    Gateway: ReviewCommentGateway,
+{
+    gateway: &'client Gateway,
+    checkpoints: Option<SyncCheckpointStore>,
+}
+
+impl<'client, Gateway> ReviewSyncService<'client, Gateway>
+where
+    Gateway: ReviewCommentGateway,
+{
+    /// Creates a sync service using the provided gateway.
+    #[must_use]
+    pub const fn new(gateway: &'client Gateway) -> Self {
+        Self {
+            gateway,
+            checkpoints: None,
+        }
+    }
+
+    /// Persists checkpoints in `store` so they survive restarts.
+    ///
+    /// Without a store the checkpoint is derived from the known comments on
+    /// every sync.
+    #[must_use]
+    pub fn with_checkpoint_store(mut self, store: SyncCheckpointStore) -> Self {
+        self.checkpoints = Some(store);
+        self
+    }
+
+    /// Syncs the pull request's review comments against `known`.
+    ///
+    /// With no known comments, or none carrying an `updated_at` timestamp,
+    /// every comment is listed. Otherwise only comments updated since the
+    /// checkpoint are fetched, and a full listing is used only when the
+    /// comment count shows that comments were deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] when a GitHub request fails. Checkpoint store
+    /// and thread state failures are logged and do not fail the sync.
+    pub async fn sync(
+        &self,
+        locator: &PullRequestLocator,
+        known: &[ReviewComment],
+    ) -> Result<ReviewSyncDelta, IntakeError> {
+        let delta = match self.resume_point(locator, known) {
+            Some(checkpoint) => self.incremental_sync(locator, known, &checkpoint).await?,
+            None => self.full_sync(locator, known).await?,
+        };
+        self.save_checkpoint(locator, delta.checkpoint.as_ref());
+        Ok(delta)
+    }
+
+    /// Syncs the pull request's review comments against `known` from a full
+    /// listing.
+    ///
+    /// Reactions and other changes that leave a comment's `updated_at`
+    /// untouched are invisible to [`Self::sync`]; hosts call this now and
+    /// then to pick them up. With a page cache attached to the gateway,
+    /// unchanged pages cost no rate-limit quota.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError`] when a GitHub request fails. Checkpoint store
+    /// failures are logged and do not fail the sync.
+    pub async fn sync_all(
+        &self,
+        locator: &PullRequestLocator,
+        known: &[ReviewComment],
+    ) -> Result<ReviewSyncDelta, IntakeError> {
+        let delta = self.full_sync(locator, known).await?;
+        self.save_checkpoint(locator, delta.checkpoint.as_ref());
+        Ok(delta)
+    }
+
+    async fn full_sync(
+        &self,
+        locator: &PullRequestLocator,
+        known: &[ReviewComment],
+    ) -> Result<ReviewSyncDelta, IntakeError> {
+        let current = self.gateway.list_review_comments(locator).await?;
+        Ok(ReviewSyncDelta::between(known, current))
+    }
+
+    async fn incremental_sync(
+        &self,
+        locator: &PullRequestLocator,
+        known: &[ReviewComment],
+        checkpoint: &ReviewSyncCheckpoint,
+    ) -> Result<ReviewSyncDelta, IntakeError> {
+        let changed = self
+            .gateway
+            .review_comments_updated_since(locator, checkpoint.updated_at())
+            .await?;
+        let remote_count = self.gateway.review_comment_count(locator).await?;
+
+        let mut comments = merge_changed(known, changed);
+        if u64::try_from(comments.len()).ok() != Some(remote_count) {
+            return self.full_sync(locator, known).await;
+        }
+
+        self.refresh_thread_states(locator, &mut comments).await;
+        Ok(ReviewSyncDelta::between(known, comments))
+    }
+
+    /// Replaces thread state on every comment, since resolving a thread does
+    /// not change its comments' `updated_at`.
+    ///
+    /// Best-effort: on failure the previous thread states are kept.
+    async fn refresh_thread_states(
+        &self,
+        locator: &PullRequestLocator,
+        comments: &mut [ReviewComment],
+    ) {
+        match self.gateway.review_thread_states(locator).await {
+            Ok(states) => apply_thread_states(comments, &states),
+            Err(error) => log_thread_state_failure(&error),
+        }
+    }
+
+    /// Picks the checkpoint to resume from.
+    ///
+    /// The stored checkpoint is used only when it is not newer than the
+    /// known comments, so a host holding older data never skips changes.
+    fn resume_point(
+        &self,
+        locator: &PullRequestLocator,
+        known: &[ReviewComment],
+    ) -> Option<ReviewSyncCheckpoint> {
+        let derived = ReviewSyncCheckpoint::latest(known)?;
+        let stored = self.checkpoints.as_ref().and_then(|store| {
+            match store.load(locator, CHECKPOINT_RESOURCE) {
+                Ok(value) => value.map(ReviewSyncCheckpoint::new),
+                Err(error) => {
+                    tracing::warn!("review sync checkpoint unavailable: {error}");
+                    None
+                }
+            }
+        });
+        match stored {
+            Some(checkpoint) => Some(checkpoint.min(derived)),
+            None => Some(derived),
+        }
+    }
+
+    fn save_checkpoint(
+        &self,
+        locator: &PullRequestLocator,
+        checkpoint: Option<&ReviewSyncCheckpoint>,
+    ) {
+        let (Some(store), Some(value)) = (self.checkpoints.as_ref(), checkpoint) else {
+            return;
+        };
+        if let Err(error) = store.save(locator, CHECKPOINT_RESOURCE, value.updated_at()) {
+            tracing::warn!("review sync checkpoint not saved: {error}");
+        }
+    }
+}
+
+/// Overlays `changed` comments onto `known`, returning them sorted by ID.
+///
+/// REST listings carry no thread state, so a changed comment keeps the state
+/// of the version it replaces until thread states are refreshed.
+fn merge_changed(known: &[ReviewComment], changed: Vec<ReviewComment>) -> Vec<ReviewComment> {
+    let mut merged: BTreeMap<u64, ReviewComment> = known
+        .iter()
+        .map(|comment| (comment.id, comment.clone()))
+        .collect();
+    for comment in changed {
+        let thread_state = merged
+            .get(&comment.id)
+            .and_then(|previous| previous.thread_state.clone());
+        merged.insert(
+            comment.id,
+            ReviewComment {
+                thread_state,
+                ..comment
+            },
+        );
+    }
+    merged.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests;
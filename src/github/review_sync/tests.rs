@@ -0,0 +1,241 @@
+//! Tests for incremental review comment sync.
+
+use std::collections::HashMap;
+
+use mockall::predicate::{always, eq};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::{ReviewSyncCheckpoint, ReviewSyncDelta, ReviewSyncService};
+use crate::github::gateway::MockReviewCommentGateway;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{ReactionContent, ReactionCount, ReviewComment, ReviewThreadState};
+use crate::persistence::{SyncCheckpointStore, migrate_database};
+use crate::telemetry::NoopTelemetrySink;
+
+fn locator() -> PullRequestLocator {
+    PullRequestLocator::parse("https://github.com/owner/repo/pull/42")
+        .expect("locator should parse")
+}
+
+fn comment(id: u64, body: &str, updated_at: &str) -> ReviewComment {
+    ReviewComment {
+        id,
+        body: Some(body.to_owned()),
+        updated_at: Some(updated_at.to_owned()),
+        ..Default::default()
+    }
+}
+
+fn thread_state(resolved: bool) -> ReviewThreadState {
+    ReviewThreadState {
+        thread_id: "thread-1".to_owned(),
+        is_resolved: resolved,
+        is_outdated: false,
+        resolved_by: None,
+    }
+}
+
+fn known_comments() -> Vec<ReviewComment> {
+    vec![
+        comment(1, "First", "2025-01-01T00:00:00Z"),
+        comment(2, "Second", "2025-01-02T00:00:00Z"),
+    ]
+}
+
+#[rstest]
+fn delta_classifies_added_updated_and_removed() {
+    let known = known_comments();
+    let current = vec![
+        comment(1, "First (edited)", "2025-01-03T00:00:00Z"),
+        comment(3, "Third", "2025-01-04T00:00:00Z"),
+    ];
+
+    let delta = ReviewSyncDelta::between(&known, current.clone());
+
+    assert_eq!(
+        delta.added,
+        vec![comment(3, "Third", "2025-01-04T00:00:00Z")]
+    );
+    assert_eq!(
+        delta.updated,
+        vec![comment(1, "First (edited)", "2025-01-03T00:00:00Z")]
+    );
+    assert_eq!(delta.removed, vec![2]);
+    assert_eq!(
+        delta.checkpoint,
+        Some(ReviewSyncCheckpoint::new("2025-01-04T00:00:00Z"))
+    );
+    assert_eq!(delta.apply(&known), current);
+}
+
+#[rstest]
+fn delta_ignores_unchanged_comments() {
+    let known = known_comments();
+
+    let delta = ReviewSyncDelta::between(&known, known.clone());
+
+    assert!(delta.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn sync_without_known_comments_lists_everything() {
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_list_review_comments()
+        .with(always())
+        .times(1)
+        .returning(|_| Ok(known_comments()));
+
+    let delta = ReviewSyncService::new(&gateway)
+        .sync(&locator(), &[])
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(delta.added, known_comments());
+    assert!(delta.updated.is_empty() && delta.removed.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn sync_fetches_only_comments_updated_since_checkpoint() {
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_review_comments_updated_since()
+        .with(always(), eq("2025-01-02T00:00:00Z"))
+        .times(1)
+        .returning(|_, _| {
+            Ok(vec![
+                comment(2, "Second", "2025-01-02T00:00:00Z"),
+                comment(3, "Third", "2025-01-05T00:00:00Z"),
+            ])
+        });
+    gateway
+        .expect_review_comment_count()
+        .times(1)
+        .returning(|_| Ok(3));
+    gateway
+        .expect_review_thread_states()
+        .times(1)
+        .returning(|_| Ok(HashMap::from([(1, thread_state(true))])));
+    gateway.expect_list_review_comments().never();
+
+    let delta = ReviewSyncService::new(&gateway)
+        .sync(&locator(), &known_comments())
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(
+        delta.added,
+        vec![comment(3, "Third", "2025-01-05T00:00:00Z")]
+    );
+    assert_eq!(
+        delta.updated,
+        vec![ReviewComment {
+            thread_state: Some(thread_state(true)),
+            ..comment(1, "First", "2025-01-01T00:00:00Z")
+        }]
+    );
+    assert!(delta.removed.is_empty());
+    assert_eq!(
+        delta.checkpoint,
+        Some(ReviewSyncCheckpoint::new("2025-01-05T00:00:00Z"))
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn sync_detects_deletions_from_the_comment_count() {
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_review_comments_updated_since()
+        .times(1)
+        .returning(|_, _| Ok(Vec::new()));
+    gateway
+        .expect_review_comment_count()
+        .times(1)
+        .returning(|_| Ok(1));
+    gateway
+        .expect_list_review_comments()
+        .times(1)
+        .returning(|_| Ok(vec![comment(2, "Second", "2025-01-02T00:00:00Z")]));
+    gateway.expect_review_thread_states().never();
+
+    let delta = ReviewSyncService::new(&gateway)
+        .sync(&locator(), &known_comments())
+        .await
+        .expect("sync should succeed");
+
+    assert!(delta.added.is_empty() && delta.updated.is_empty());
+    assert_eq!(delta.removed, vec![1]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn sync_persists_checkpoint_and_never_resumes_past_known_comments() {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let db_path = temp_dir.path().join("frankie.sqlite");
+    let database_url = db_path.to_string_lossy();
+    migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+    let store = SyncCheckpointStore::new(database_url.as_ref()).expect("store should be created");
+    store
+        .save(&locator(), "review_comments", "2024-12-31T00:00:00Z")
+        .expect("seed checkpoint should save");
+
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway
+        .expect_review_comments_updated_since()
+        .with(always(), eq("2024-12-31T00:00:00Z"))
+        .times(1)
+        .returning(|_, _| Ok(vec![comment(3, "Third", "2025-01-05T00:00:00Z")]));
+    gateway
+        .expect_review_comment_count()
+        .times(1)
+        .returning(|_| Ok(3));
+    gateway
+        .expect_review_thread_states()
+        .times(1)
+        .returning(|_| Ok(HashMap::new()));
+
+    ReviewSyncService::new(&gateway)
+        .with_checkpoint_store(store.clone())
+        .sync(&locator(), &known_comments())
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(
+        store
+            .load(&locator(), "review_comments")
+            .expect("checkpoint should load"),
+        Some("2025-01-05T00:00:00Z".to_owned())
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn sync_all_picks_up_reactions_without_an_updated_at_change() {
+    let mut reacted = comment(1, "First", "2025-01-01T00:00:00Z");
+    reacted.reactions = vec![ReactionCount {
+        content: ReactionContent::Heart,
+        count: 1,
+    }];
+    let listed = vec![
+        reacted.clone(),
+        comment(2, "Second", "2025-01-02T00:00:00Z"),
+    ];
+    let mut gateway = MockReviewCommentGateway::new();
+    gateway.expect_review_comments_updated_since().never();
+    gateway
+        .expect_list_review_comments()
+        .times(1)
+        .returning(move |_| Ok(listed.clone()));
+
+    let delta = ReviewSyncService::new(&gateway)
+        .sync_all(&locator(), &known_comments())
+        .await
+        .expect("sync should succeed");
+
+    assert_eq!(delta.updated, vec![reacted]);
+    assert!(delta.added.is_empty() && delta.removed.is_empty());
+}
@@ -46,6 +46,45 @@ fn parses_enterprise_url() {
     );
 }
 
+#[rstest]
+#[case::github_com(
+    "https://github.com/octo/repo/pull/1",
+    "github.com",
+    "https://api.github.com"
+)]
+#[case::enterprise(
+    "https://ghe.example.com/corp/app/pull/1",
+    "ghe.example.com",
+    "https://ghe.example.com/api"
+)]
+#[case::enterprise_with_port(
+    "https://ghe.example.com:8443/corp/app/pull/1",
+    "ghe.example.com",
+    "https://ghe.example.com:8443/api"
+)]
+fn derives_host_and_graphql_base(
+    #[case] url: &str,
+    #[case] expected_host: &str,
+    #[case] expected_graphql_base: &str,
+) {
+    let locator = PullRequestLocator::parse(url).expect("should parse pull request URL");
+
+    assert_eq!(locator.host(), expected_host);
+    assert_eq!(locator.graphql_base(), expected_graphql_base);
+}
+
+#[rstest]
+#[case::github_com("https://api.github.com", "https://api.github.com")]
+#[case::trailing_slash("https://api.github.com/", "https://api.github.com")]
+#[case::enterprise("https://ghe.example.com/api/v3", "https://ghe.example.com/api")]
+#[case::enterprise_trailing_slash("https://ghe.example.com/api/v3/", "https://ghe.example.com/api")]
+fn graphql_base_for_api_base_maps_rest_base(#[case] api_base: &str, #[case] expected: &str) {
+    assert_eq!(
+        super::locator::graphql_base_for_api_base(api_base),
+        expected
+    );
+}
+
 #[rstest]
 fn rejects_missing_number() {
     let result = PullRequestLocator::parse("https://github.com/octo/repo/pull/");
@@ -124,6 +163,7 @@ fn setup_pull_request_gateway() -> MockPullRequestGateway {
                 state: Some(String::from("open")),
                 html_url: None,
                 author: Some(String::from("octocat")),
+                ..PullRequestMetadata::default()
             })
         });
 
@@ -137,11 +177,15 @@ fn setup_pull_request_gateway() -> MockPullRequestGateway {
                     id: 1,
                     body: Some(String::from("first")),
                     author: Some(String::from("a")),
+                    created_at: None,
+                    html_url: None,
                 },
                 PullRequestComment {
                     id: 2,
                     body: Some(String::from("second")),
                     author: Some(String::from("b")),
+                    created_at: None,
+                    html_url: None,
                 },
             ])
         });
@@ -257,6 +301,7 @@ fn setup_repository_gateway() -> MockRepositoryGateway {
                         author: Some(String::from("alice")),
                         created_at: None,
                         updated_at: None,
+                        ..PullRequestSummary::default()
                     },
                     PullRequestSummary {
                         number: 2,
@@ -265,6 +310,7 @@ fn setup_repository_gateway() -> MockRepositoryGateway {
                         author: Some(String::from("bob")),
                         created_at: None,
                         updated_at: None,
+                        ..PullRequestSummary::default()
                     },
                 ],
                 page_info: PageInfo::builder(1, 30).total_pages(Some(1)).build(),
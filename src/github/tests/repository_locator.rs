@@ -3,6 +3,7 @@
 use rstest::rstest;
 
 use crate::github::{IntakeError, RepositoryLocator};
+use crate::local::GitHubOrigin;
 
 #[rstest]
 fn parses_repository_url() {
@@ -70,3 +71,41 @@ fn rejects_empty_segment(#[case] owner: &str, #[case] repo: &str) {
         "expected MissingPathSegments, got {result:?}"
     );
 }
+
+#[rstest]
+#[case::github_com(
+    GitHubOrigin::GitHubCom { owner: "octo".to_owned(), repository: "repo".to_owned() },
+    "https://api.github.com/",
+    "github.com"
+)]
+#[case::enterprise(
+    GitHubOrigin::Enterprise {
+        host: "ghe.example.com".to_owned(),
+        port: None,
+        owner: "corp".to_owned(),
+        repository: "app".to_owned(),
+    },
+    "https://ghe.example.com/api/v3",
+    "ghe.example.com"
+)]
+#[case::enterprise_with_port(
+    GitHubOrigin::Enterprise {
+        host: "ghe.example.com".to_owned(),
+        port: Some(8443),
+        owner: "corp".to_owned(),
+        repository: "app".to_owned(),
+    },
+    "https://ghe.example.com:8443/api/v3",
+    "ghe.example.com"
+)]
+fn from_github_origin_derives_api_base(
+    #[case] origin: GitHubOrigin,
+    #[case] expected_api_base: &str,
+    #[case] expected_host: &str,
+) {
+    let locator = RepositoryLocator::from_github_origin(&origin).expect("should create locator");
+
+    assert_eq!(locator.api_base().as_str(), expected_api_base);
+    assert_eq!(locator.host(), expected_host);
+    assert_eq!(locator.owner().as_str(), origin.owner());
+}
@@ -10,24 +10,39 @@ pub mod export;
 pub mod github;
 pub mod local;
 pub mod persistence;
+pub mod redaction;
 pub mod reply_template;
+pub mod suggestion;
 pub mod telemetry;
 pub mod time;
 pub mod time_travel;
 pub mod tui;
 pub mod verification;
 
-pub use config::{DEFAULT_COMMIT_HISTORY_LIMIT, FrankieConfig, OperationMode};
+pub use config::{
+    AuthAction, CredentialStore, CredentialTarget, DEFAULT_COMMIT_HISTORY_LIMIT, FrankieConfig,
+    OperationMode, OutboxAction, ResolvedToken, TokenSource,
+};
 pub use export::{
-    ExportFormat, ExportedComment, PrUrl, sort_comments, write_jsonl, write_markdown,
-    write_template,
+    ExportFormat, ExportedComment, ExportedCommentKind, PrUrl, sort_comments, timeline_comments,
+    write_jsonl, write_markdown, write_template,
 };
 pub use github::{
-    IntakeError, ListPullRequestsParams, OctocrabCachingGateway, OctocrabGateway,
-    OctocrabRepositoryGateway, OctocrabReviewCommentGateway, PageInfo, PaginatedPullRequests,
-    PersonalAccessToken, PullRequestDetails, PullRequestIntake, PullRequestLocator,
-    PullRequestState, PullRequestSummary, RateLimitInfo, RepositoryIntake, RepositoryLocator,
-    ReviewComment, ReviewCommentGateway,
+    BackoffReason, ChangedFile, ChangedFileStatus, CiCheck, CiCheckKind, CiOutcome, CiStatus,
+    CiStatusGateway, DiffSide, GitHubAppInstallation, GitHubCredentials, IntakeError,
+    ListPullRequestsParams, OctocrabCachingGateway, OctocrabCiStatusGateway, OctocrabGateway,
+    OctocrabPullRequestDiffGateway, OctocrabPullRequestReviewGateway, OctocrabRepositoryGateway,
+    OctocrabReviewCommentGateway, OutboxReplayReport, OutboxReplayService, PageInfo,
+    PaginatedPullRequests, PendingBackoff, PendingReviewComment, PersonalAccessToken,
+    PullRequestDetails, PullRequestDiff, PullRequestDiffGateway, PullRequestIntake,
+    PullRequestLocator, PullRequestReview, PullRequestReviewGateway, PullRequestState,
+    PullRequestSummary, RateLimitInfo, ReactionContent, ReactionCount, ReactionToggle,
+    ReplayOutcome, RepositoryIntake, RepositoryLocator, ReviewComment, ReviewCommentGateway,
+    ReviewCommentPageSender, ReviewEvent, ReviewQueueEntry, ReviewQueueReason, ReviewQueueRequest,
+    ReviewQueueService, ReviewSubmission, ReviewSyncCheckpoint, ReviewSyncDelta, ReviewSyncService,
+    ReviewThreadState, SearchPullRequestsParams, SearchScope, SubmittedReview, TimelineEntry,
+    TimelineEntryKind, TimelineThread, WriteIntent, build_timeline, pending_backoff,
+    rank_review_queue,
 };
 pub use local::{GitHubOrigin, LocalDiscoveryError, LocalRepository, discover_repository};
 pub use reply_template::{
@@ -179,7 +179,11 @@ fn try_parse_url_style(url: &str) -> Option<GitHubOrigin> {
     let parsed = url::Url::parse(url).ok()?;
 
     let host = parsed.host_str()?;
-    let port = parsed.port();
+    // SSH and git-protocol ports say nothing about where the web interface
+    // and API are served, so only HTTP(S) ports are kept.
+    let port = parsed
+        .port()
+        .filter(|_| matches!(parsed.scheme(), "http" | "https"));
     // Path should start with /
     let path_stripped = parsed.path().strip_prefix('/')?;
 
@@ -51,6 +51,10 @@ struct EnterpriseExpected {
     "https://ghe.example.com:8443/owner/repo.git",
     EnterpriseExpected { host: "ghe.example.com", port: Some(8443), owner: "owner", repo: "repo" }
 )]
+#[case::ssh_with_port(
+    "ssh://git@ghe.example.com:2222/owner/repo.git",
+    EnterpriseExpected { host: "ghe.example.com", port: None, owner: "owner", repo: "repo" }
+)]
 fn parse_enterprise_origins(#[case] input: &str, #[case] expected: EnterpriseExpected) {
     let result = parse_github_remote(input).expect("should parse successfully");
 
@@ -1,10 +1,14 @@
 //! Frankie CLI entrypoint for pull request intake.
 
 use std::ffi::OsString;
+use std::future::Future;
 use std::io::{self, Write};
+use std::pin::Pin;
 use std::process::ExitCode;
 
+use frankie::redaction::RedactingMakeWriter;
 use frankie::{FrankieConfig, IntakeError, OperationMode};
+use tracing_subscriber::EnvFilter;
 
 mod cli;
 
@@ -12,6 +16,8 @@ mod cli;
 // macro expansion calls `expect` on runtime construction, which the
 // `no_expect_outside_tests` lint rejects in binary code.
 fn main() -> ExitCode {
+    init_tracing();
+
     let runtime = match tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
@@ -39,22 +45,69 @@ fn main() -> ExitCode {
     })
 }
 
+/// Installs a stderr tracing subscriber whose output is redacted.
+///
+/// Logging is off unless `FRANKIE_LOG` holds a filter such as `debug` or
+/// `frankie=trace`.
+fn init_tracing() {
+    let filter = EnvFilter::try_from_env("FRANKIE_LOG").unwrap_or_else(|_| EnvFilter::new("off"));
+    // A subscriber can only fail to install when one is already set.
+    drop(
+        tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(RedactingMakeWriter::new(io::stderr))
+            .try_init(),
+    );
+}
+
 async fn run() -> Result<(), IntakeError> {
     let config = load_config()?;
+    config.register_secrets();
 
     if config.migrate_db {
         return cli::migrations::run(&config);
     }
 
+    dispatch(&config).await
+}
+
+/// Future running the CLI handler for one operation mode.
+type ModeRun<'a> = Pin<Box<dyn Future<Output = Result<(), IntakeError>> + 'a>>;
+
+/// Runs the CLI handler for the configured operation mode.
+///
+/// Rate-limit waits are announced on stderr unless a TUI is running, which
+/// shows them in its status bar instead.
+async fn dispatch(config: &FrankieConfig) -> Result<(), IntakeError> {
+    let _notices = (!runs_tui(config)).then(cli::backoff_notice::BackoffNotices::start);
+    mode_run(config).await
+}
+
+/// Returns whether the configured mode runs a TUI.
+fn runs_tui(config: &FrankieConfig) -> bool {
+    config.tui || config.operation_mode() == OperationMode::ReviewTui
+}
+
+/// Selects the CLI handler for the configured operation mode.
+///
+/// Every mode has exactly one arm, so adding a mode without routing it
+/// fails to compile.
+fn mode_run(config: &FrankieConfig) -> ModeRun<'_> {
     match config.operation_mode() {
-        OperationMode::SinglePullRequest => cli::single_pr::run(&config).await,
-        OperationMode::RepositoryListing => cli::repository_listing::run(&config).await,
-        OperationMode::Interactive => cli::interactive::run(&config).await,
-        OperationMode::ReviewTui => cli::review_tui::run(&config).await,
-        OperationMode::ExportComments => cli::export_comments::run(&config).await,
-        OperationMode::AiRewrite => cli::ai_rewrite::run(&config),
-        OperationMode::VerifyResolutions => cli::verify_resolutions::run(&config).await,
-        OperationMode::SummarizeDiscussions => cli::summarize_discussions::run(&config).await,
+        OperationMode::SinglePullRequest => Box::pin(cli::single_pr::run(config)),
+        OperationMode::RepositoryListing => Box::pin(cli::repository_listing::run(config)),
+        OperationMode::SearchPullRequests => Box::pin(cli::search_pull_requests::run(config)),
+        OperationMode::Dashboard => Box::pin(cli::dashboard::run(config)),
+        OperationMode::Interactive => Box::pin(cli::interactive::run(config)),
+        OperationMode::AiRewrite => Box::pin(async { cli::ai_rewrite::run(config) }),
+        OperationMode::Auth => Box::pin(async { cli::auth::run(config) }),
+        OperationMode::ReviewTui => Box::pin(cli::review_tui::run(config)),
+        OperationMode::ExportComments => Box::pin(cli::export_comments::run(config)),
+        OperationMode::VerifyResolutions => Box::pin(cli::verify_resolutions::run(config)),
+        OperationMode::SummarizeDiscussions => Box::pin(cli::summarize_discussions::run(config)),
+        OperationMode::PostReply => Box::pin(cli::post_reply::run(config)),
+        OperationMode::ResolveThreads => Box::pin(cli::resolve_threads::run(config)),
+        OperationMode::Outbox => Box::pin(cli::outbox::run(config)),
     }
 }
 
@@ -181,7 +234,7 @@ fn is_flag_token(arg: &str) -> bool {
 ///
 /// `--flag=value` is self-contained; `--flag value` needs a skip.
 fn is_flag_requiring_value(flag: &str) -> bool {
-    !flag.contains('=') && FrankieConfig::VALUE_FLAGS.contains(&flag)
+    FrankieConfig::is_value_flag(flag)
 }
 
 /// Loads configuration from CLI, environment, and files.
@@ -0,0 +1,229 @@
+//! Comment listing page cache backed by `SQLite`.
+//!
+//! Comment listings are paginated, and the TUI refreshes them every few
+//! seconds. Storing each page body with its `ETag` lets gateways send
+//! conditional requests: a `304 Not Modified` response costs no rate-limit
+//! quota and the cached body is reused as if it had been downloaded again.
+
+use diesel::OptionalExtension;
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Bool, Nullable, Text};
+
+use crate::github::PullRequestLocator;
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+const COMMENT_PAGE_CACHE_TABLE: &str = "comment_page_cache";
+
+/// Comment listing a cached page belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentListing {
+    /// Inline review comments from `/pulls/{number}/comments`.
+    ReviewComments,
+    /// Inline review comments updated since the sync checkpoint, from
+    /// `/pulls/{number}/comments?since=...`.
+    ReviewCommentsSince,
+    /// Conversation comments from `/issues/{number}/comments`.
+    IssueComments,
+    /// Top-level reviews from `/pulls/{number}/reviews`.
+    Reviews,
+    /// The pull request resource itself, stored as a single page.
+    PullRequest,
+}
+
+impl CommentListing {
+    /// Returns the identifier stored in the `listing` column.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ReviewComments => "review_comments",
+            Self::ReviewCommentsSince => "review_comments_since",
+            Self::IssueComments => "issue_comments",
+            Self::Reviews => "reviews",
+            Self::PullRequest => "pull_request",
+        }
+    }
+}
+
+/// One cached page of a comment listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedCommentPage {
+    /// `ETag` returned with the page, if any.
+    pub etag: Option<String>,
+    /// Raw JSON body of the page.
+    pub body: String,
+    /// Whether GitHub advertised a following page.
+    pub has_next_page: bool,
+    /// Unix timestamp when the page body was downloaded.
+    pub fetched_at_unix: i64,
+}
+
+/// Parameters for writing one comment listing page.
+#[derive(Debug, Clone, Copy)]
+pub struct CommentPageCacheWrite<'a> {
+    /// Listing the page belongs to.
+    pub listing: CommentListing,
+    /// One-based page number.
+    pub page: u32,
+    /// `ETag` returned with the page, if any.
+    pub etag: Option<&'a str>,
+    /// Raw JSON body of the page.
+    pub body: &'a str,
+    /// Whether GitHub advertised a following page.
+    pub has_next_page: bool,
+    /// Unix timestamp when the page body was downloaded.
+    pub fetched_at_unix: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+struct PageRow {
+    #[diesel(sql_type = Nullable<Text>)]
+    etag: Option<String>,
+    #[diesel(sql_type = Text)]
+    body: String,
+    #[diesel(sql_type = Bool)]
+    has_next_page: bool,
+    #[diesel(sql_type = BigInt)]
+    fetched_at_unix: i64,
+}
+
+/// SQLite-backed cache for comment listing pages and their `ETag`s.
+#[derive(Debug, Clone)]
+pub struct CommentPageCache {
+    database_url: String,
+}
+
+impl CommentPageCache {
+    /// Create a cache wrapper targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Fetches one cached page of a comment listing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// cache schema is missing, or the query fails.
+    pub fn get_page(
+        &self,
+        locator: &PullRequestLocator,
+        listing: CommentListing,
+        page: u32,
+    ) -> Result<Option<CachedCommentPage>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let row: Option<PageRow> = sql_query(
+            "SELECT etag, body, has_next_page, fetched_at_unix \
+             FROM comment_page_cache \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
+               AND listing = ? AND page = ? \
+             LIMIT 1;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<Text, _>(listing.as_str())
+        .bind::<BigInt, _>(i64::from(page))
+        .get_result(&mut connection)
+        .optional()
+        .map_err(|error| map_query_error(&mut connection, COMMENT_PAGE_CACHE_TABLE, &error))?;
+
+        Ok(row.map(|page_row| CachedCommentPage {
+            etag: page_row.etag,
+            body: page_row.body,
+            has_next_page: page_row.has_next_page,
+            fetched_at_unix: page_row.fetched_at_unix,
+        }))
+    }
+
+    /// Inserts or replaces one page of a comment listing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// cache schema is missing, or the write fails.
+    pub fn upsert_page(
+        &self,
+        locator: &PullRequestLocator,
+        write: &CommentPageCacheWrite<'_>,
+    ) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        sql_query(
+            "INSERT INTO comment_page_cache \
+             (api_base, owner, repo, pr_number, listing, page, etag, body, has_next_page, \
+              fetched_at_unix) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(api_base, owner, repo, pr_number, listing, page) DO UPDATE SET \
+               etag = excluded.etag, \
+               body = excluded.body, \
+               has_next_page = excluded.has_next_page, \
+               fetched_at_unix = excluded.fetched_at_unix, \
+               updated_at = CURRENT_TIMESTAMP;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<Text, _>(write.listing.as_str())
+        .bind::<BigInt, _>(i64::from(write.page))
+        .bind::<Nullable<Text>, _>(write.etag)
+        .bind::<Text, _>(write.body)
+        .bind::<Bool, _>(write.has_next_page)
+        .bind::<BigInt, _>(write.fetched_at_unix)
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| map_write_error(&mut connection, COMMENT_PAGE_CACHE_TABLE, &error))
+    }
+
+    /// Removes cached pages numbered after `last_page`.
+    ///
+    /// Called once a listing has been walked to its end, so pages left over
+    /// from a longer listing are not reused when comments are deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// cache schema is missing, or the delete fails.
+    pub fn prune_pages_after(
+        &self,
+        locator: &PullRequestLocator,
+        listing: CommentListing,
+        last_page: u32,
+    ) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        sql_query(
+            "DELETE FROM comment_page_cache \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
+               AND listing = ? AND page > ?;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<Text, _>(listing.as_str())
+        .bind::<BigInt, _>(i64::from(last_page))
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| map_write_error(&mut connection, COMMENT_PAGE_CACHE_TABLE, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests;
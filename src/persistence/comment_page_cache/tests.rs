@@ -0,0 +1,124 @@
+//! Tests for the comment listing page cache.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::{CachedCommentPage, CommentListing, CommentPageCache, CommentPageCacheWrite};
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
+
+#[fixture]
+fn migrated_cache() -> FixtureResult<(TempDir, CommentPageCache)> {
+    migrated(CommentPageCache::new)
+}
+
+fn page_write<'a>(page: u32, etag: &'a str, body: &'a str) -> CommentPageCacheWrite<'a> {
+    CommentPageCacheWrite {
+        listing: CommentListing::ReviewComments,
+        page,
+        etag: Some(etag),
+        body,
+        has_next_page: false,
+        fetched_at_unix: 10,
+    }
+}
+
+#[rstest]
+fn cache_round_trips_page_with_etag(migrated_cache: FixtureResult<(TempDir, CommentPageCache)>) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    cache
+        .upsert_page(
+            &locator,
+            &CommentPageCacheWrite {
+                has_next_page: true,
+                ..page_write(1, "\"abc\"", "[{\"id\":1}]")
+            },
+        )
+        .expect("upsert should succeed");
+    let cached = cache
+        .get_page(&locator, CommentListing::ReviewComments, 1)
+        .expect("cache get should succeed");
+
+    assert_eq!(
+        cached,
+        Some(CachedCommentPage {
+            etag: Some("\"abc\"".to_owned()),
+            body: "[{\"id\":1}]".to_owned(),
+            has_next_page: true,
+            fetched_at_unix: 10,
+        })
+    );
+    assert_eq!(
+        cache
+            .get_page(&locator, CommentListing::IssueComments, 1)
+            .expect("cache get should succeed"),
+        None
+    );
+}
+
+#[rstest]
+fn upsert_replaces_existing_page(migrated_cache: FixtureResult<(TempDir, CommentPageCache)>) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    cache
+        .upsert_page(&locator, &page_write(1, "\"old\"", "[1]"))
+        .expect("first upsert should succeed");
+    cache
+        .upsert_page(&locator, &page_write(1, "\"new\"", "[2]"))
+        .expect("second upsert should succeed");
+    let cached = cache
+        .get_page(&locator, CommentListing::ReviewComments, 1)
+        .expect("cache get should succeed")
+        .expect("page should exist");
+
+    assert_eq!(cached.etag.as_deref(), Some("\"new\""));
+    assert_eq!(cached.body, "[2]");
+}
+
+#[rstest]
+fn prune_removes_only_later_pages(migrated_cache: FixtureResult<(TempDir, CommentPageCache)>) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+    for page in 1..=3 {
+        cache
+            .upsert_page(&locator, &page_write(page, "\"etag\"", "[]"))
+            .expect("upsert should succeed");
+    }
+
+    cache
+        .prune_pages_after(&locator, CommentListing::ReviewComments, 1)
+        .expect("prune should succeed");
+
+    let present: Vec<_> = (1..=3)
+        .map(|page| {
+            cache
+                .get_page(&locator, CommentListing::ReviewComments, page)
+                .expect("cache get should succeed")
+                .is_some()
+        })
+        .collect();
+    assert_eq!(present, vec![true, false, false]);
+}
+
+#[rstest]
+fn cache_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let cache = CommentPageCache::new(database_url).expect("cache should be created");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    let error = cache
+        .upsert_page(&locator, &page_write(1, "\"etag\"", "[]"))
+        .expect_err("write should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
+
+#[rstest]
+fn blank_database_url_is_rejected() {
+    let result = CommentPageCache::new(" ");
+
+    assert!(matches!(result, Err(PersistenceError::BlankDatabaseUrl)));
+}
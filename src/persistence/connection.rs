@@ -0,0 +1,97 @@
+//! Connection and error helpers shared by the persistence stores.
+//!
+//! Every store opens a short-lived connection per operation with foreign
+//! keys enforced, and reports a failure against a missing table as
+//! [`PersistenceError::SchemaNotInitialised`] so callers can suggest
+//! running migrations.
+
+use diesel::prelude::*;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Text};
+use diesel::sqlite::SqliteConnection;
+
+use super::PersistenceError;
+
+/// Opens a connection to `database_url` with foreign keys enforced.
+pub(super) fn establish_connection(
+    database_url: &str,
+) -> Result<SqliteConnection, PersistenceError> {
+    let mut connection = SqliteConnection::establish(database_url).map_err(|error| {
+        PersistenceError::ConnectionFailed {
+            message: error.to_string(),
+        }
+    })?;
+
+    sql_query("PRAGMA foreign_keys = ON;")
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| PersistenceError::ForeignKeysEnableFailed {
+            message: error.to_string(),
+        })?;
+
+    Ok(connection)
+}
+
+/// Converts a pull request number for binding as `BigInt`.
+///
+/// PR numbers are `u64` but Diesel's `BigInt` binding uses `i64`; saturate
+/// defensively.
+pub(super) fn pr_number_to_i64(number: u64) -> i64 {
+    i64::try_from(number).unwrap_or(i64::MAX)
+}
+
+/// Returns whether `table` exists in the connected database.
+pub(super) fn table_exists(
+    connection: &mut SqliteConnection,
+    table: &str,
+) -> Result<bool, diesel::result::Error> {
+    #[derive(Debug, QueryableByName)]
+    struct Row {
+        #[diesel(sql_type = BigInt)]
+        count: i64,
+    }
+
+    let row: Row =
+        sql_query("SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' AND name = ?;")
+            .bind::<Text, _>(table)
+            .get_result(connection)?;
+
+    Ok(row.count > 0)
+}
+
+/// Maps a failed read against `table`.
+pub(super) fn map_query_error(
+    connection: &mut SqliteConnection,
+    table: &str,
+    error: &diesel::result::Error,
+) -> PersistenceError {
+    map_error_with_schema_check(connection, table, error, |message| {
+        PersistenceError::QueryFailed { message }
+    })
+}
+
+/// Maps a failed write against `table`.
+pub(super) fn map_write_error(
+    connection: &mut SqliteConnection,
+    table: &str,
+    error: &diesel::result::Error,
+) -> PersistenceError {
+    map_error_with_schema_check(connection, table, error, |message| {
+        PersistenceError::WriteFailed { message }
+    })
+}
+
+fn map_error_with_schema_check(
+    connection: &mut SqliteConnection,
+    table: &str,
+    error: &diesel::result::Error,
+    create_error: impl FnOnce(String) -> PersistenceError,
+) -> PersistenceError {
+    match table_exists(connection, table) {
+        Ok(false) => PersistenceError::SchemaNotInitialised,
+        Ok(true) => create_error(error.to_string()),
+        Err(check_error) => create_error(format!(
+            "schema presence check failed: {check_error}; original error: {error}"
+        )),
+    }
+}
@@ -5,19 +5,39 @@
 //! managed with Diesel migrations so the database can be created and upgraded
 //! consistently across machines.
 
+mod comment_page_cache;
+mod connection;
 mod error;
 mod migrator;
+mod pending_review_comments;
 mod pr_metadata_cache;
+mod pr_review_cache;
+mod repositories;
+mod review_comment_archive;
 mod review_comment_verification_cache;
+mod review_queue;
+mod sync_checkpoints;
+#[cfg(test)]
+mod test_support;
+mod write_outbox;
 
+pub use comment_page_cache::{
+    CachedCommentPage, CommentListing, CommentPageCache, CommentPageCacheWrite,
+};
 pub use error::PersistenceError;
 pub use migrator::{
     CURRENT_SCHEMA_VERSION, INITIAL_SCHEMA_VERSION, SchemaVersion, migrate_database,
 };
+pub use pending_review_comments::{PendingReviewCommentStore, StoredPendingReviewComment};
 pub use pr_metadata_cache::{
     CachedPullRequestMetadata, PullRequestMetadataCache, PullRequestMetadataCacheWrite,
 };
+pub use pr_review_cache::{CachedPullRequestReviews, PullRequestReviewCache};
+pub use review_comment_archive::ReviewCommentArchive;
 pub use review_comment_verification_cache::{
     CachedReviewCommentVerification, ReviewCommentVerificationCache,
     ReviewCommentVerificationCacheWrite,
 };
+pub use review_queue::{CachedReviewQueue, ReviewQueueCache};
+pub use sync_checkpoints::SyncCheckpointStore;
+pub use write_outbox::{QueuedWrite, WriteOutbox};
@@ -0,0 +1,184 @@
+//! Pending inline review comment drafts backed by `SQLite`.
+//!
+//! Comments started from the full-screen diff context are anchored to a
+//! `(path, line, side)` triple at a specific commit. They are stored locally
+//! so a review can be composed across sessions and are removed once the
+//! review containing them has been submitted to GitHub.
+
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Text};
+
+use crate::github::PullRequestLocator;
+use crate::github::review_submission::{DiffSide, PendingReviewComment};
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+const PENDING_REVIEW_COMMENTS_TABLE: &str = "pending_review_comments";
+
+#[derive(Debug, QueryableByName)]
+struct PendingCommentRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = Text)]
+    commit_sha: String,
+    #[diesel(sql_type = Text)]
+    path: String,
+    #[diesel(sql_type = BigInt)]
+    line: i64,
+    #[diesel(sql_type = Text)]
+    side: String,
+    #[diesel(sql_type = Text)]
+    body: String,
+}
+
+/// Pending inline comment draft loaded from the local database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredPendingReviewComment {
+    /// Local row identifier used to delete the draft after submission.
+    pub id: i64,
+    /// Commit SHA the comment is anchored to.
+    pub commit_sha: String,
+    /// The anchored comment.
+    pub comment: PendingReviewComment,
+}
+
+/// SQLite-backed store for pending inline review comment drafts.
+#[derive(Debug, Clone)]
+pub struct PendingReviewCommentStore {
+    database_url: String,
+}
+
+impl PendingReviewCommentStore {
+    /// Creates a store targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Stores a draft for the pull request and returns its row identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn insert(
+        &self,
+        locator: &PullRequestLocator,
+        commit_sha: &str,
+        comment: &PendingReviewComment,
+    ) -> Result<i64, PersistenceError> {
+        #[derive(Debug, QueryableByName)]
+        struct InsertedRow {
+            #[diesel(sql_type = BigInt)]
+            id: i64,
+        }
+
+        let mut connection = establish_connection(&self.database_url)?;
+
+        sql_query(
+            "INSERT INTO pending_review_comments \
+             (api_base, owner, repo, pr_number, commit_sha, path, line, side, body) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<Text, _>(commit_sha)
+        .bind::<Text, _>(comment.path.as_str())
+        .bind::<BigInt, _>(i64::from(comment.line))
+        .bind::<Text, _>(comment.side.as_api_str())
+        .bind::<Text, _>(comment.body.as_str())
+        .execute(&mut connection)
+        .map_err(|error| map_write_error(&mut connection, PENDING_REVIEW_COMMENTS_TABLE, &error))?;
+
+        let inserted: InsertedRow = sql_query("SELECT last_insert_rowid() AS id;")
+            .get_result(&mut connection)
+            .map_err(|error| {
+                map_query_error(&mut connection, PENDING_REVIEW_COMMENTS_TABLE, &error)
+            })?;
+
+        Ok(inserted.id)
+    }
+
+    /// Lists drafts stored for the pull request in creation order.
+    ///
+    /// Rows with an unrecognised side or an out-of-range line are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the query
+    /// fails.
+    pub fn list_for_pull_request(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<StoredPendingReviewComment>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let rows: Vec<PendingCommentRow> = sql_query(
+            "SELECT id, commit_sha, path, line, side, body \
+             FROM pending_review_comments \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
+             ORDER BY id ASC;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, PENDING_REVIEW_COMMENTS_TABLE, &error))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_stored).collect())
+    }
+
+    /// Deletes the drafts with the given row identifiers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn delete(&self, ids: &[i64]) -> Result<(), PersistenceError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = establish_connection(&self.database_url)?;
+        for id in ids {
+            sql_query("DELETE FROM pending_review_comments WHERE id = ?;")
+                .bind::<BigInt, _>(*id)
+                .execute(&mut connection)
+                .map(drop)
+                .map_err(|error| {
+                    map_write_error(&mut connection, PENDING_REVIEW_COMMENTS_TABLE, &error)
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn row_to_stored(row: PendingCommentRow) -> Option<StoredPendingReviewComment> {
+        let line = u32::try_from(row.line).ok()?;
+        let side = DiffSide::from_api_str(&row.side)?;
+        Some(StoredPendingReviewComment {
+            id: row.id,
+            commit_sha: row.commit_sha,
+            comment: PendingReviewComment::new(row.path, line, row.body).with_side(side),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
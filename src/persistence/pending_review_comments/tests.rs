@@ -0,0 +1,108 @@
+//! Tests for the pending review comment store.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::PendingReviewCommentStore;
+use crate::github::review_submission::{DiffSide, PendingReviewComment};
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
+
+#[fixture]
+fn migrated_store() -> FixtureResult<(TempDir, PendingReviewCommentStore)> {
+    migrated(PendingReviewCommentStore::new)
+}
+
+#[rstest]
+#[expect(
+    clippy::panic_in_result_fn,
+    reason = "Fixture-based test returns Result and still uses assertions for state checks."
+)]
+fn store_round_trips_anchored_comments(
+    migrated_store: FixtureResult<(TempDir, PendingReviewCommentStore)>,
+) -> FixtureResult<()> {
+    let (_temp_dir, store) = migrated_store?;
+    let locator = parse_locator(42)?;
+    let left =
+        PendingReviewComment::new("src/lib.rs", 7, "Why remove this?").with_side(DiffSide::Left);
+    let right = PendingReviewComment::new("src/main.rs", 12, "Nice");
+
+    let first_id = store.insert(&locator, "abc123", &left)?;
+    let second_id = store.insert(&locator, "abc123", &right)?;
+    let loaded = store.list_for_pull_request(&locator)?;
+
+    assert_ne!(first_id, second_id);
+    assert_eq!(loaded.len(), 2);
+    let first = loaded
+        .first()
+        .ok_or_else(|| std::io::Error::other("first draft should exist"))?;
+    assert_eq!(first.id, first_id);
+    assert_eq!(first.commit_sha, "abc123");
+    assert_eq!(first.comment, left);
+    assert_eq!(loaded.get(1).map(|stored| &stored.comment), Some(&right));
+    Ok(())
+}
+
+#[rstest]
+#[expect(
+    clippy::panic_in_result_fn,
+    reason = "Fixture-based test returns Result and still uses assertions for state checks."
+)]
+fn store_scopes_drafts_to_pull_request(
+    migrated_store: FixtureResult<(TempDir, PendingReviewCommentStore)>,
+) -> FixtureResult<()> {
+    let (_temp_dir, store) = migrated_store?;
+    let comment = PendingReviewComment::new("src/lib.rs", 1, "nit");
+    store.insert(&parse_locator(1)?, "abc123", &comment)?;
+
+    let other = store.list_for_pull_request(&parse_locator(2)?)?;
+
+    assert!(other.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[expect(
+    clippy::panic_in_result_fn,
+    reason = "Fixture-based test returns Result and still uses assertions for state checks."
+)]
+fn delete_removes_only_requested_drafts(
+    migrated_store: FixtureResult<(TempDir, PendingReviewCommentStore)>,
+) -> FixtureResult<()> {
+    let (_temp_dir, store) = migrated_store?;
+    let locator = parse_locator(42)?;
+    let kept = PendingReviewComment::new("a.rs", 1, "keep");
+    let removed_id = store.insert(
+        &locator,
+        "abc123",
+        &PendingReviewComment::new("b.rs", 2, "drop"),
+    )?;
+    store.insert(&locator, "abc123", &kept)?;
+
+    store.delete(&[removed_id])?;
+    let loaded = store.list_for_pull_request(&locator)?;
+
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded.first().map(|stored| &stored.comment), Some(&kept));
+    Ok(())
+}
+
+#[rstest]
+fn store_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let store = PendingReviewCommentStore::new(database_url).expect("store should be created");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    let error = store
+        .list_for_pull_request(&locator)
+        .expect_err("query should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
+
+#[rstest]
+fn blank_database_url_is_rejected() {
+    let result = PendingReviewCommentStore::new("  ");
+
+    assert!(matches!(result, Err(PersistenceError::BlankDatabaseUrl)));
+}
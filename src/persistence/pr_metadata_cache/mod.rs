@@ -8,18 +8,17 @@
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use diesel::Connection;
 use diesel::OptionalExtension;
 use diesel::QueryableByName;
 use diesel::RunQueryDsl;
 use diesel::sql_query;
-use diesel::sql_types::{BigInt, Nullable, Text};
-use diesel::sqlite::SqliteConnection;
+use diesel::sql_types::{BigInt, Bool, Nullable, Text};
 
 use crate::github::PullRequestLocator;
-use crate::github::models::PullRequestMetadata;
+use crate::github::models::{PullRequestBranch, PullRequestMetadata};
 
 use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
 
 const PR_METADATA_CACHE_TABLE: &str = "pr_metadata_cache";
 
@@ -46,6 +45,100 @@ impl CachedPullRequestMetadata {
     }
 }
 
+#[derive(Debug, QueryableByName)]
+struct MetadataRow {
+    #[diesel(sql_type = Nullable<Text>)]
+    title: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    state: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    html_url: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    author: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    body: Option<String>,
+    #[diesel(sql_type = Bool)]
+    draft: bool,
+    #[diesel(sql_type = Nullable<Text>)]
+    base_ref: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    base_sha: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    head_ref: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    head_sha: Option<String>,
+    #[diesel(sql_type = Text)]
+    labels: String,
+    #[diesel(sql_type = Text)]
+    requested_reviewers: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    mergeable_state: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    additions: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    deletions: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    changed_files: Option<i64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    etag: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    last_modified: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    fetched_at_unix: i64,
+    #[diesel(sql_type = BigInt)]
+    expires_at_unix: i64,
+}
+
+impl MetadataRow {
+    fn into_cached(self, number: u64) -> CachedPullRequestMetadata {
+        CachedPullRequestMetadata {
+            metadata: PullRequestMetadata {
+                number,
+                title: self.title,
+                state: self.state,
+                html_url: self.html_url,
+                author: self.author,
+                body: self.body,
+                draft: self.draft,
+                base: PullRequestBranch {
+                    name: self.base_ref,
+                    sha: self.base_sha,
+                },
+                head: PullRequestBranch {
+                    name: self.head_ref,
+                    sha: self.head_sha,
+                },
+                labels: decode_names(&self.labels),
+                requested_reviewers: decode_names(&self.requested_reviewers),
+                mergeable_state: self.mergeable_state,
+                additions: self.additions.and_then(|count| u64::try_from(count).ok()),
+                deletions: self.deletions.and_then(|count| u64::try_from(count).ok()),
+                changed_files: self
+                    .changed_files
+                    .and_then(|count| u64::try_from(count).ok()),
+            },
+            etag: self.etag,
+            last_modified: self.last_modified,
+            fetched_at_unix: self.fetched_at_unix,
+            expires_at_unix: self.expires_at_unix,
+        }
+    }
+}
+
+/// Encodes labels or reviewers as a JSON array of strings.
+fn encode_names(names: &[String]) -> String {
+    serde_json::to_string(names).unwrap_or_else(|_| "[]".to_owned())
+}
+
+/// Decodes a JSON array of strings, treating malformed values as empty.
+fn decode_names(encoded: &str) -> Vec<String> {
+    serde_json::from_str(encoded).unwrap_or_default()
+}
+
+fn count_to_i64(count: u64) -> i64 {
+    i64::try_from(count).unwrap_or(i64::MAX)
+}
+
 /// SQLite-backed cache for pull request metadata.
 #[derive(Debug, Clone)]
 pub struct PullRequestMetadataCache {
@@ -93,31 +186,13 @@ impl PullRequestMetadataCache {
         &self,
         locator: &PullRequestLocator,
     ) -> Result<Option<CachedPullRequestMetadata>, PersistenceError> {
-        #[derive(Debug, QueryableByName)]
-        struct Row {
-            #[diesel(sql_type = Nullable<Text>)]
-            title: Option<String>,
-            #[diesel(sql_type = Nullable<Text>)]
-            state: Option<String>,
-            #[diesel(sql_type = Nullable<Text>)]
-            html_url: Option<String>,
-            #[diesel(sql_type = Nullable<Text>)]
-            author: Option<String>,
-            #[diesel(sql_type = Nullable<Text>)]
-            etag: Option<String>,
-            #[diesel(sql_type = Nullable<Text>)]
-            last_modified: Option<String>,
-            #[diesel(sql_type = BigInt)]
-            fetched_at_unix: i64,
-            #[diesel(sql_type = BigInt)]
-            expires_at_unix: i64,
-        }
-
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
 
-        let result: Option<Row> = sql_query(
-            "SELECT title, state, html_url, author, etag, last_modified, fetched_at_unix, \
-             expires_at_unix \
+        let result: Option<MetadataRow> = sql_query(
+            "SELECT title, state, html_url, author, body, draft, base_ref, base_sha, head_ref, \
+                    head_sha, labels, requested_reviewers, mergeable_state, additions, \
+                    deletions, changed_files, etag, last_modified, fetched_at_unix, \
+                    expires_at_unix \
              FROM pr_metadata_cache \
              WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
              LIMIT 1;",
@@ -125,24 +200,12 @@ impl PullRequestMetadataCache {
         .bind::<Text, _>(locator.api_base().as_str())
         .bind::<Text, _>(locator.owner().as_str())
         .bind::<Text, _>(locator.repository().as_str())
-        .bind::<BigInt, _>(Self::pr_number_to_i64(locator))
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
         .get_result(&mut connection)
         .optional()
-        .map_err(|error| Self::map_query_error(&mut connection, &error))?;
+        .map_err(|error| map_query_error(&mut connection, PR_METADATA_CACHE_TABLE, &error))?;
 
-        Ok(result.map(|row| CachedPullRequestMetadata {
-            metadata: PullRequestMetadata {
-                number: locator.number().get(),
-                title: row.title,
-                state: row.state,
-                html_url: row.html_url,
-                author: row.author,
-            },
-            etag: row.etag,
-            last_modified: row.last_modified,
-            fetched_at_unix: row.fetched_at_unix,
-            expires_at_unix: row.expires_at_unix,
-        }))
+        Ok(result.map(|row| row.into_cached(locator.number().get())))
     }
 
     /// Inserts or updates a cache entry.
@@ -156,20 +219,34 @@ impl PullRequestMetadataCache {
         locator: &PullRequestLocator,
         write: PullRequestMetadataCacheWrite<'_>,
     ) -> Result<(), PersistenceError> {
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
 
         let metadata = write.metadata;
 
         sql_query(
             "INSERT INTO pr_metadata_cache \
-             (api_base, owner, repo, pr_number, title, state, html_url, author, etag, \
-              last_modified, fetched_at_unix, expires_at_unix) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             (api_base, owner, repo, pr_number, title, state, html_url, author, body, draft, \
+              base_ref, base_sha, head_ref, head_sha, labels, requested_reviewers, \
+              mergeable_state, additions, deletions, changed_files, etag, last_modified, \
+              fetched_at_unix, expires_at_unix) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
              ON CONFLICT(api_base, owner, repo, pr_number) DO UPDATE SET \
                title = excluded.title, \
                state = excluded.state, \
                html_url = excluded.html_url, \
                author = excluded.author, \
+               body = excluded.body, \
+               draft = excluded.draft, \
+               base_ref = excluded.base_ref, \
+               base_sha = excluded.base_sha, \
+               head_ref = excluded.head_ref, \
+               head_sha = excluded.head_sha, \
+               labels = excluded.labels, \
+               requested_reviewers = excluded.requested_reviewers, \
+               mergeable_state = excluded.mergeable_state, \
+               additions = excluded.additions, \
+               deletions = excluded.deletions, \
+               changed_files = excluded.changed_files, \
                etag = excluded.etag, \
                last_modified = excluded.last_modified, \
                fetched_at_unix = excluded.fetched_at_unix, \
@@ -179,18 +256,30 @@ impl PullRequestMetadataCache {
         .bind::<Text, _>(locator.api_base().as_str())
         .bind::<Text, _>(locator.owner().as_str())
         .bind::<Text, _>(locator.repository().as_str())
-        .bind::<BigInt, _>(Self::pr_number_to_i64(locator))
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
         .bind::<Nullable<Text>, _>(metadata.title.as_deref())
         .bind::<Nullable<Text>, _>(metadata.state.as_deref())
         .bind::<Nullable<Text>, _>(metadata.html_url.as_deref())
         .bind::<Nullable<Text>, _>(metadata.author.as_deref())
+        .bind::<Nullable<Text>, _>(metadata.body.as_deref())
+        .bind::<Bool, _>(metadata.draft)
+        .bind::<Nullable<Text>, _>(metadata.base.name.as_deref())
+        .bind::<Nullable<Text>, _>(metadata.base.sha.as_deref())
+        .bind::<Nullable<Text>, _>(metadata.head.name.as_deref())
+        .bind::<Nullable<Text>, _>(metadata.head.sha.as_deref())
+        .bind::<Text, _>(encode_names(&metadata.labels))
+        .bind::<Text, _>(encode_names(&metadata.requested_reviewers))
+        .bind::<Nullable<Text>, _>(metadata.mergeable_state.as_deref())
+        .bind::<Nullable<BigInt>, _>(metadata.additions.map(count_to_i64))
+        .bind::<Nullable<BigInt>, _>(metadata.deletions.map(count_to_i64))
+        .bind::<Nullable<BigInt>, _>(metadata.changed_files.map(count_to_i64))
         .bind::<Nullable<Text>, _>(write.etag)
         .bind::<Nullable<Text>, _>(write.last_modified)
         .bind::<BigInt, _>(write.fetched_at_unix)
         .bind::<BigInt, _>(write.expires_at_unix)
         .execute(&mut connection)
         .map(drop)
-        .map_err(|error| Self::map_write_error(&mut connection, &error))
+        .map_err(|error| map_write_error(&mut connection, PR_METADATA_CACHE_TABLE, &error))
     }
 
     /// Updates the expiry for an existing cache entry (for a 304 response).
@@ -205,7 +294,7 @@ impl PullRequestMetadataCache {
         fetched_at_unix: i64,
         expires_at_unix: i64,
     ) -> Result<(), PersistenceError> {
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
 
         let affected = sql_query(
             "UPDATE pr_metadata_cache \
@@ -217,9 +306,9 @@ impl PullRequestMetadataCache {
         .bind::<Text, _>(locator.api_base().as_str())
         .bind::<Text, _>(locator.owner().as_str())
         .bind::<Text, _>(locator.repository().as_str())
-        .bind::<BigInt, _>(Self::pr_number_to_i64(locator))
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
         .execute(&mut connection)
-        .map_err(|error| Self::map_write_error(&mut connection, &error))?;
+        .map_err(|error| map_write_error(&mut connection, PR_METADATA_CACHE_TABLE, &error))?;
 
         if affected == 0 {
             return Err(PersistenceError::WriteFailed {
@@ -251,81 +340,6 @@ impl PullRequestMetadataCache {
                 },
             )
     }
-
-    fn establish_connection(&self) -> Result<SqliteConnection, PersistenceError> {
-        let mut connection = SqliteConnection::establish(&self.database_url).map_err(|error| {
-            PersistenceError::ConnectionFailed {
-                message: error.to_string(),
-            }
-        })?;
-
-        sql_query("PRAGMA foreign_keys = ON;")
-            .execute(&mut connection)
-            .map(drop)
-            .map_err(|error| PersistenceError::ForeignKeysEnableFailed {
-                message: error.to_string(),
-            })?;
-
-        Ok(connection)
-    }
-
-    fn pr_number_to_i64(locator: &PullRequestLocator) -> i64 {
-        // PR numbers are `u64` but Diesel's `BigInt` binding uses `i64`; saturate defensively.
-        i64::try_from(locator.number().get()).unwrap_or(i64::MAX)
-    }
-
-    fn cache_table_exists(
-        connection: &mut SqliteConnection,
-    ) -> Result<bool, diesel::result::Error> {
-        #[derive(Debug, QueryableByName)]
-        struct Row {
-            #[diesel(sql_type = BigInt)]
-            count: i64,
-        }
-
-        let row: Row = sql_query(
-            "SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' AND name = ?;",
-        )
-        .bind::<Text, _>(PR_METADATA_CACHE_TABLE)
-        .get_result(connection)?;
-
-        Ok(row.count > 0)
-    }
-
-    fn map_error_with_schema_check<F>(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-        create_error: F,
-    ) -> PersistenceError
-    where
-        F: Fn(String) -> PersistenceError,
-    {
-        match Self::cache_table_exists(connection) {
-            Ok(false) => PersistenceError::SchemaNotInitialised,
-            Ok(true) => create_error(error.to_string()),
-            Err(check_error) => create_error(format!(
-                "schema presence check failed: {check_error}; original error: {error}"
-            )),
-        }
-    }
-
-    fn map_query_error(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-    ) -> PersistenceError {
-        Self::map_error_with_schema_check(connection, error, |message| {
-            PersistenceError::QueryFailed { message }
-        })
-    }
-
-    fn map_write_error(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-    ) -> PersistenceError {
-        Self::map_error_with_schema_check(connection, error, |message| {
-            PersistenceError::WriteFailed { message }
-        })
-    }
 }
 
 #[cfg(test)]
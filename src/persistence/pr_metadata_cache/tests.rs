@@ -1,7 +1,5 @@
 //! Tests for the pull request metadata cache.
 
-type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
-
 use diesel::Connection;
 use diesel::RunQueryDsl;
 use diesel::sql_query;
@@ -10,31 +8,13 @@ use rstest::{fixture, rstest};
 use tempfile::TempDir;
 
 use super::{CachedPullRequestMetadata, PullRequestMetadataCache, PullRequestMetadataCacheWrite};
-use crate::github::{PullRequestLocator, PullRequestMetadata};
-use crate::persistence::{PersistenceError, migrate_database};
-use crate::telemetry::NoopTelemetrySink;
-
-#[fixture]
-fn temp_db() -> FixtureResult<(TempDir, String)> {
-    let temp_dir = TempDir::new()?;
-    let db_path = temp_dir.path().join("frankie.sqlite");
-    Ok((temp_dir, db_path.to_string_lossy().to_string()))
-}
+use crate::github::{PullRequestBranch, PullRequestMetadata};
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
 
 #[fixture]
-fn migrated_cache(
-    temp_db: FixtureResult<(TempDir, String)>,
-) -> FixtureResult<(TempDir, PullRequestMetadataCache)> {
-    let (temp_dir, database_url) = temp_db?;
-    migrate_database(&database_url, &NoopTelemetrySink)?;
-
-    let cache = PullRequestMetadataCache::new(database_url)?;
-    Ok((temp_dir, cache))
-}
-
-fn parse_locator(pr_number: u64) -> FixtureResult<PullRequestLocator> {
-    let url = format!("https://github.com/owner/repo/pull/{pr_number}");
-    Ok(PullRequestLocator::parse(&url)?)
+fn migrated_cache() -> FixtureResult<(TempDir, PullRequestMetadataCache)> {
+    migrated(PullRequestMetadataCache::new)
 }
 
 #[rstest]
@@ -48,6 +28,22 @@ fn cache_round_trips_metadata(migrated_cache: FixtureResult<(TempDir, PullReques
         state: Some("open".to_owned()),
         html_url: Some("https://example.invalid".to_owned()),
         author: Some("octocat".to_owned()),
+        body: Some("Adds a metadata cache.".to_owned()),
+        draft: true,
+        base: PullRequestBranch {
+            name: Some("main".to_owned()),
+            sha: Some("aaa111".to_owned()),
+        },
+        head: PullRequestBranch {
+            name: Some("feature/cache".to_owned()),
+            sha: Some("bbb222".to_owned()),
+        },
+        labels: vec!["enhancement".to_owned(), "needs review".to_owned()],
+        requested_reviewers: vec!["hubot".to_owned(), "core-team".to_owned()],
+        mergeable_state: Some("clean".to_owned()),
+        additions: Some(120),
+        deletions: Some(8),
+        changed_files: Some(4),
     };
 
     let fetched_at = 10;
@@ -92,6 +88,7 @@ fn cache_touch_updates_expiry(migrated_cache: FixtureResult<(TempDir, PullReques
         state: None,
         html_url: None,
         author: None,
+        ..PullRequestMetadata::default()
     };
 
     cache
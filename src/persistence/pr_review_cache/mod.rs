@@ -0,0 +1,267 @@
+//! Pull request review cache backed by `SQLite`.
+//!
+//! Top-level pull request reviews (verdicts and summary bodies) can be cached
+//! in the local `SQLite` database so repeated sessions do not page through the
+//! reviews endpoint on every start. Each pull request has a single cache entry
+//! with a TTL; the reviews themselves are stored in API order and replaced as
+//! a whole whenever the list is refetched.
+
+use diesel::OptionalExtension;
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::github::PullRequestLocator;
+use crate::github::models::PullRequestReview;
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+const PR_REVIEW_CACHE_TABLE: &str = "pr_review_cache";
+
+/// Cached pull request reviews along with their expiry window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedPullRequestReviews {
+    /// Cached reviews in the order GitHub returned them.
+    pub reviews: Vec<PullRequestReview>,
+    /// Unix timestamp when the reviews were fetched.
+    pub fetched_at_unix: i64,
+    /// Unix timestamp when the cache entry should be treated as stale.
+    pub expires_at_unix: i64,
+}
+
+impl CachedPullRequestReviews {
+    /// Returns true if the entry is expired at the supplied `now_unix`.
+    #[must_use]
+    pub const fn is_expired(&self, now_unix: i64) -> bool {
+        now_unix >= self.expires_at_unix
+    }
+}
+
+#[derive(Debug, QueryableByName)]
+struct ReviewRow {
+    #[diesel(sql_type = BigInt)]
+    review_id: i64,
+    #[diesel(sql_type = Nullable<Text>)]
+    author: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    state: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    body: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    submitted_at: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    commit_sha: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    html_url: Option<String>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct EntryRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+    #[diesel(sql_type = BigInt)]
+    fetched_at_unix: i64,
+    #[diesel(sql_type = BigInt)]
+    expires_at_unix: i64,
+}
+
+/// SQLite-backed cache for top-level pull request reviews.
+#[derive(Debug, Clone)]
+pub struct PullRequestReviewCache {
+    database_url: String,
+}
+
+impl PullRequestReviewCache {
+    /// Create a cache wrapper targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Fetches the cached reviews for the given locator.
+    ///
+    /// Rows whose review identifier cannot be represented as `u64` are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// cache schema is missing, or the query fails.
+    pub fn get(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Option<CachedPullRequestReviews>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let entry: Option<EntryRow> = sql_query(
+            "SELECT id, fetched_at_unix, expires_at_unix \
+             FROM pr_review_cache \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
+             LIMIT 1;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .get_result(&mut connection)
+        .optional()
+        .map_err(|error| map_query_error(&mut connection, PR_REVIEW_CACHE_TABLE, &error))?;
+
+        let Some(entry_row) = entry else {
+            return Ok(None);
+        };
+
+        let rows: Vec<ReviewRow> = sql_query(
+            "SELECT review_id, author, state, body, submitted_at, commit_sha, html_url \
+             FROM pr_review_cache_reviews \
+             WHERE cache_id = ? \
+             ORDER BY position ASC;",
+        )
+        .bind::<BigInt, _>(entry_row.id)
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, PR_REVIEW_CACHE_TABLE, &error))?;
+
+        Ok(Some(CachedPullRequestReviews {
+            reviews: rows.into_iter().filter_map(Self::row_to_review).collect(),
+            fetched_at_unix: entry_row.fetched_at_unix,
+            expires_at_unix: entry_row.expires_at_unix,
+        }))
+    }
+
+    /// Replaces the cached reviews for the given locator.
+    ///
+    /// The expiry window is given as `(fetched_at_unix, expires_at_unix)`.
+    /// The entry and its reviews are written in a single transaction, so a
+    /// failed write leaves the previous cache entry intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when a review identifier exceeds the
+    /// `i64` range, the schema is missing, or the write fails.
+    pub fn replace(
+        &self,
+        locator: &PullRequestLocator,
+        reviews: &[PullRequestReview],
+        (fetched_at_unix, expires_at_unix): (i64, i64),
+    ) -> Result<(), PersistenceError> {
+        let review_ids = reviews
+            .iter()
+            .map(|review| {
+                i64::try_from(review.id).map_err(|_| PersistenceError::WriteFailed {
+                    message: format!("review id {} exceeds i64 range", review.id),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut connection = establish_connection(&self.database_url)?;
+        let result = connection.immediate_transaction(|transaction| {
+            let cache_id =
+                Self::upsert_entry(transaction, locator, (fetched_at_unix, expires_at_unix))?;
+            sql_query("DELETE FROM pr_review_cache_reviews WHERE cache_id = ?;")
+                .bind::<BigInt, _>(cache_id)
+                .execute(transaction)?;
+            for (position, (review, review_id)) in reviews.iter().zip(review_ids).enumerate() {
+                Self::insert_review(transaction, cache_id, (position, review_id), review)?;
+            }
+            Ok::<(), diesel::result::Error>(())
+        });
+
+        result.map_err(|error| map_write_error(&mut connection, PR_REVIEW_CACHE_TABLE, &error))
+    }
+
+    fn upsert_entry(
+        connection: &mut SqliteConnection,
+        locator: &PullRequestLocator,
+        (fetched_at_unix, expires_at_unix): (i64, i64),
+    ) -> Result<i64, diesel::result::Error> {
+        #[derive(Debug, QueryableByName)]
+        struct IdRow {
+            #[diesel(sql_type = BigInt)]
+            id: i64,
+        }
+
+        sql_query(
+            "INSERT INTO pr_review_cache \
+             (api_base, owner, repo, pr_number, fetched_at_unix, expires_at_unix) \
+             VALUES (?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(api_base, owner, repo, pr_number) DO UPDATE SET \
+               fetched_at_unix = excluded.fetched_at_unix, \
+               expires_at_unix = excluded.expires_at_unix, \
+               updated_at = CURRENT_TIMESTAMP;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<BigInt, _>(fetched_at_unix)
+        .bind::<BigInt, _>(expires_at_unix)
+        .execute(connection)?;
+
+        let row: IdRow = sql_query(
+            "SELECT id FROM pr_review_cache \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ?;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .get_result(connection)?;
+
+        Ok(row.id)
+    }
+
+    fn insert_review(
+        connection: &mut SqliteConnection,
+        cache_id: i64,
+        (position, review_id): (usize, i64),
+        review: &PullRequestReview,
+    ) -> Result<(), diesel::result::Error> {
+        // Positions are bounded by the number of reviews held in memory.
+        let position_i64 = i64::try_from(position).unwrap_or(i64::MAX);
+        sql_query(
+            "INSERT INTO pr_review_cache_reviews \
+             (cache_id, position, review_id, author, state, body, submitted_at, commit_sha, \
+              html_url) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);",
+        )
+        .bind::<BigInt, _>(cache_id)
+        .bind::<BigInt, _>(position_i64)
+        .bind::<BigInt, _>(review_id)
+        .bind::<Nullable<Text>, _>(review.author.as_deref())
+        .bind::<Nullable<Text>, _>(review.state.as_deref())
+        .bind::<Nullable<Text>, _>(review.body.as_deref())
+        .bind::<Nullable<Text>, _>(review.submitted_at.as_deref())
+        .bind::<Nullable<Text>, _>(review.commit_sha.as_deref())
+        .bind::<Nullable<Text>, _>(review.html_url.as_deref())
+        .execute(connection)
+        .map(drop)
+    }
+
+    fn row_to_review(row: ReviewRow) -> Option<PullRequestReview> {
+        let id = u64::try_from(row.review_id).ok()?;
+        Some(PullRequestReview {
+            id,
+            author: row.author,
+            state: row.state,
+            body: row.body,
+            submitted_at: row.submitted_at,
+            commit_sha: row.commit_sha,
+            html_url: row.html_url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+//! Tests for the pull request review cache.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::{CachedPullRequestReviews, PullRequestReviewCache};
+use crate::github::PullRequestReview;
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
+
+#[fixture]
+fn migrated_cache() -> FixtureResult<(TempDir, PullRequestReviewCache)> {
+    migrated(PullRequestReviewCache::new)
+}
+
+fn review(id: u64, state: &str) -> PullRequestReview {
+    PullRequestReview {
+        id,
+        author: Some("reviewer".to_owned()),
+        state: Some(state.to_owned()),
+        body: Some(format!("Review {id}")),
+        submitted_at: Some("2025-01-01T00:00:00Z".to_owned()),
+        commit_sha: Some("abc123".to_owned()),
+        html_url: None,
+    }
+}
+
+#[rstest]
+fn cache_round_trips_reviews_in_order(
+    migrated_cache: FixtureResult<(TempDir, PullRequestReviewCache)>,
+) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+    let reviews = vec![review(9, "COMMENTED"), review(3, "APPROVED")];
+
+    cache
+        .replace(&locator, &reviews, (10, 20))
+        .expect("replace should succeed");
+    let cached = cache.get(&locator).expect("cache get should succeed");
+
+    assert_eq!(
+        cached,
+        Some(CachedPullRequestReviews {
+            reviews,
+            fetched_at_unix: 10,
+            expires_at_unix: 20,
+        })
+    );
+}
+
+#[rstest]
+fn replace_discards_previous_reviews(
+    migrated_cache: FixtureResult<(TempDir, PullRequestReviewCache)>,
+) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+    cache
+        .replace(
+            &locator,
+            &[review(1, "COMMENTED"), review(2, "COMMENTED")],
+            (10, 20),
+        )
+        .expect("first replace should succeed");
+
+    cache
+        .replace(&locator, &[review(3, "APPROVED")], (30, 40))
+        .expect("second replace should succeed");
+    let cached = cache
+        .get(&locator)
+        .expect("cache get should succeed")
+        .expect("entry should exist");
+
+    assert_eq!(cached.reviews, vec![review(3, "APPROVED")]);
+    assert_eq!(cached.fetched_at_unix, 30);
+    assert!(!cached.is_expired(39));
+    assert!(cached.is_expired(40));
+}
+
+#[rstest]
+fn empty_review_list_is_cached(migrated_cache: FixtureResult<(TempDir, PullRequestReviewCache)>) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let locator = parse_locator(7).expect("locator should parse");
+
+    cache
+        .replace(&locator, &[], (10, 20))
+        .expect("replace should succeed");
+    let cached = cache.get(&locator).expect("cache get should succeed");
+
+    assert_eq!(cached.map(|entry| entry.reviews), Some(Vec::new()));
+    assert_eq!(
+        cache
+            .get(&parse_locator(8).expect("locator should parse"))
+            .expect("cache get should succeed"),
+        None
+    );
+}
+
+#[rstest]
+fn cache_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let cache = PullRequestReviewCache::new(database_url).expect("cache should be created");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    let error = cache
+        .replace(&locator, &[review(1, "APPROVED")], (10, 20))
+        .expect_err("write should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
+
+#[rstest]
+fn blank_database_url_is_rejected() {
+    let result = PullRequestReviewCache::new(" ");
+
+    assert!(matches!(result, Err(PersistenceError::BlankDatabaseUrl)));
+}
@@ -0,0 +1,52 @@
+//! Shared helpers for rows in the `repositories` table.
+//!
+//! Stores that hang data off the initial schema's repository graph (sync
+//! checkpoints, archived review comments) create the repository row on first
+//! use through [`upsert_repository`]. Repositories are keyed by host as well
+//! as owner and name, so a github.com repository and a GitHub Enterprise
+//! repository with the same slug never share rows.
+
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::github::PullRequestLocator;
+
+#[derive(Debug, QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+}
+
+/// Inserts the pull request's repository if missing and returns its row ID.
+pub(super) fn upsert_repository(
+    connection: &mut SqliteConnection,
+    locator: &PullRequestLocator,
+) -> Result<i64, diesel::result::Error> {
+    let remote_url = format!(
+        "https://{}/{}/{}",
+        locator.host(),
+        locator.owner().as_str(),
+        locator.repository().as_str()
+    );
+    sql_query(
+        "INSERT INTO repositories (host, owner, name, remote_url) VALUES (?, ?, ?, ?) \
+         ON CONFLICT(host, owner, name) DO UPDATE SET updated_at = CURRENT_TIMESTAMP;",
+    )
+    .bind::<Text, _>(locator.host())
+    .bind::<Text, _>(locator.owner().as_str())
+    .bind::<Text, _>(locator.repository().as_str())
+    .bind::<Text, _>(remote_url)
+    .execute(connection)?;
+
+    let row: IdRow =
+        sql_query("SELECT id FROM repositories WHERE host = ? AND owner = ? AND name = ?;")
+            .bind::<Text, _>(locator.host())
+            .bind::<Text, _>(locator.owner().as_str())
+            .bind::<Text, _>(locator.repository().as_str())
+            .get_result(connection)?;
+
+    Ok(row.id)
+}
@@ -0,0 +1,272 @@
+//! Archived review comments backed by `SQLite`.
+//!
+//! Every review comment fetched from GitHub can be written to the initial
+//! schema's `review_comments` table, keyed by `github_comment_id` within its
+//! pull request and linked to its `pull_requests` and `repositories` rows.
+//! Repositories are keyed by host, so comments from different GitHub hosts
+//! never collide. The archive holds enough of
+//! each comment to rebuild a [`ReviewComment`] without network access, which
+//! is what offline mode reads from.
+//!
+//! Thread state is stored alongside each comment. Writes that carry no thread
+//! state (REST listings without the GraphQL overlay) keep whatever thread
+//! state was stored before, mirroring how incremental sync merges comments.
+
+use std::collections::HashMap;
+
+use diesel::OptionalExtension;
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Bool, Nullable, Text};
+
+use crate::github::PullRequestLocator;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+mod writes;
+
+use writes::row_to_comment;
+
+const REVIEW_COMMENTS_TABLE: &str = "review_comments";
+
+const STATUS_RESOLVED: &str = "resolved";
+const STATUS_OUTDATED: &str = "outdated";
+const STATUS_UNRESOLVED: &str = "unresolved";
+
+#[derive(Debug, QueryableByName)]
+struct CommentRow {
+    #[diesel(sql_type = BigInt)]
+    github_comment_id: i64,
+    #[diesel(sql_type = Nullable<Text>)]
+    body: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    author: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    file_path: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    line_number: Option<i64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    side: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    start_line_number: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    original_line_number: Option<i64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    diff_hunk: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    commit_sha: Option<String>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    in_reply_to_id: Option<i64>,
+    #[diesel(sql_type = Nullable<Text>)]
+    github_created_at: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    github_updated_at: Option<String>,
+    #[diesel(sql_type = Text)]
+    resolution_status: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    thread_id: Option<String>,
+    #[diesel(sql_type = Bool)]
+    thread_is_outdated: bool,
+    #[diesel(sql_type = Nullable<Text>)]
+    thread_resolved_by: Option<String>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct ThreadLinkRow {
+    #[diesel(sql_type = BigInt)]
+    github_comment_id: i64,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    in_reply_to_id: Option<i64>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct IdRow {
+    #[diesel(sql_type = BigInt)]
+    id: i64,
+}
+
+/// SQLite-backed archive of pull request review comments.
+#[derive(Debug, Clone)]
+pub struct ReviewCommentArchive {
+    database_url: String,
+}
+
+impl ReviewCommentArchive {
+    /// Creates an archive targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Loads the archived review comments for the pull request, sorted by ID.
+    ///
+    /// Returns `None` when the pull request has never been archived, so
+    /// callers can tell "no comments" apart from "never fetched".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// schema is missing, or the query fails.
+    pub fn load_comments(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Option<Vec<ReviewComment>>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let pull_request: Option<IdRow> = sql_query(
+            "SELECT pull_requests.id AS id \
+             FROM pull_requests \
+             JOIN repositories ON repositories.id = pull_requests.repository_id \
+             WHERE repositories.host = ? AND repositories.owner = ? \
+               AND repositories.name = ? AND pull_requests.pr_number = ? \
+               AND pull_requests.last_synced IS NOT NULL \
+             LIMIT 1;",
+        )
+        .bind::<Text, _>(locator.host())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .get_result(&mut connection)
+        .optional()
+        .map_err(|error| map_query_error(&mut connection, REVIEW_COMMENTS_TABLE, &error))?;
+
+        let Some(pull_request_row) = pull_request else {
+            return Ok(None);
+        };
+
+        let rows: Vec<CommentRow> = sql_query(
+            "SELECT github_comment_id, body, author, file_path, line_number, side, \
+                    start_line_number, original_line_number, diff_hunk, commit_sha, \
+                    in_reply_to_id, github_created_at, github_updated_at, resolution_status, \
+                    thread_id, thread_is_outdated, thread_resolved_by \
+             FROM review_comments \
+             WHERE pull_request_id = ? \
+             ORDER BY github_comment_id ASC;",
+        )
+        .bind::<BigInt, _>(pull_request_row.id)
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, REVIEW_COMMENTS_TABLE, &error))?;
+
+        Ok(Some(rows.into_iter().filter_map(row_to_comment).collect()))
+    }
+
+    /// Replaces the archived comments for the pull request with `comments`.
+    ///
+    /// Comments missing from `comments` are deleted, so the archive mirrors
+    /// a complete listing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when a comment ID exceeds the `i64`
+    /// range, the schema is missing, or the write fails.
+    pub fn replace_comments(
+        &self,
+        locator: &PullRequestLocator,
+        comments: &[ReviewComment],
+    ) -> Result<(), PersistenceError> {
+        self.write_comments(locator, comments, true)
+    }
+
+    /// Inserts or updates `comments` without touching other archived comments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when a comment ID exceeds the `i64`
+    /// range, the schema is missing, or the write fails.
+    pub fn upsert_comments(
+        &self,
+        locator: &PullRequestLocator,
+        comments: &[ReviewComment],
+    ) -> Result<(), PersistenceError> {
+        self.write_comments(locator, comments, false)
+    }
+
+    /// Removes the archived comment `comment_id` from the pull request, if
+    /// present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the comment ID exceeds the `i64`
+    /// range, the database cannot be opened, the schema is missing, or the
+    /// delete fails.
+    pub fn delete_comment(
+        &self,
+        locator: &PullRequestLocator,
+        comment_id: u64,
+    ) -> Result<(), PersistenceError> {
+        let stored_id = i64::try_from(comment_id).map_err(|_| PersistenceError::WriteFailed {
+            message: format!("review comment id {comment_id} exceeds i64 range"),
+        })?;
+        let mut connection = establish_connection(&self.database_url)?;
+
+        sql_query(
+            "DELETE FROM review_comments \
+             WHERE github_comment_id = ? AND pull_request_id IN ( \
+               SELECT pull_requests.id FROM pull_requests \
+               JOIN repositories ON repositories.id = pull_requests.repository_id \
+               WHERE repositories.host = ? AND repositories.owner = ? \
+                 AND repositories.name = ? AND pull_requests.pr_number = ?);",
+        )
+        .bind::<BigInt, _>(stored_id)
+        .bind::<Text, _>(locator.host())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| map_write_error(&mut connection, REVIEW_COMMENTS_TABLE, &error))
+    }
+
+    /// Replaces the thread state of every archived comment on the pull
+    /// request, using `states` keyed by thread root comment ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// schema is missing, or the write fails.
+    pub fn update_thread_states(
+        &self,
+        locator: &PullRequestLocator,
+        states: &HashMap<u64, ReviewThreadState>,
+    ) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let result = connection.immediate_transaction(|transaction| {
+            let pull_request_id = Self::upsert_pull_request(transaction, locator)?;
+            let links: Vec<ThreadLinkRow> = sql_query(
+                "SELECT github_comment_id, in_reply_to_id FROM review_comments \
+                 WHERE pull_request_id = ?;",
+            )
+            .bind::<BigInt, _>(pull_request_id)
+            .load(transaction)?;
+
+            for link in links {
+                let root_id = link.in_reply_to_id.unwrap_or(link.github_comment_id);
+                let state = u64::try_from(root_id).ok().and_then(|id| states.get(&id));
+                Self::write_thread_state(
+                    transaction,
+                    (pull_request_id, link.github_comment_id),
+                    state,
+                )?;
+            }
+            Ok::<(), diesel::result::Error>(())
+        });
+
+        result.map_err(|error| map_write_error(&mut connection, REVIEW_COMMENTS_TABLE, &error))
+    }
+}
+
+#[cfg(test)]
+mod tests;
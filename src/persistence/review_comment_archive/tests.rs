@@ -0,0 +1,238 @@
+//! Tests for the review comment archive.
+
+use std::collections::HashMap;
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::ReviewCommentArchive;
+use crate::github::PullRequestLocator;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::github::review_submission::DiffSide;
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, temp_db};
+
+#[fixture]
+fn migrated_archive() -> FixtureResult<(TempDir, ReviewCommentArchive)> {
+    migrated(ReviewCommentArchive::new)
+}
+
+fn locator() -> PullRequestLocator {
+    PullRequestLocator::parse("https://github.com/owner/repo/pull/42")
+        .expect("locator should parse")
+}
+
+fn thread(resolved: bool) -> ReviewThreadState {
+    ReviewThreadState {
+        thread_id: "PRRT_1".to_owned(),
+        is_resolved: resolved,
+        is_outdated: false,
+        resolved_by: resolved.then(|| "alice".to_owned()),
+    }
+}
+
+fn full_comment() -> ReviewComment {
+    ReviewComment {
+        id: 1,
+        body: Some("Please rename this".to_owned()),
+        author: Some("alice".to_owned()),
+        file_path: Some("src/lib.rs".to_owned()),
+        line_number: Some(10),
+        side: Some(DiffSide::Right),
+        start_line_number: Some(8),
+        original_line_number: Some(9),
+        diff_hunk: Some("@@ -1 +1 @@".to_owned()),
+        commit_sha: Some("abc123".to_owned()),
+        in_reply_to_id: None,
+        created_at: Some("2025-01-01T00:00:00Z".to_owned()),
+        updated_at: Some("2025-01-02T00:00:00Z".to_owned()),
+        thread_state: Some(thread(true)),
+        reactions: Vec::new(),
+    }
+}
+
+fn reply(id: u64) -> ReviewComment {
+    ReviewComment {
+        id,
+        body: None,
+        in_reply_to_id: Some(1),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+fn comments_round_trip_with_every_field(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    assert_eq!(
+        archive
+            .load_comments(&locator())
+            .expect("load should succeed"),
+        None
+    );
+
+    let comments = vec![full_comment(), reply(2)];
+    archive
+        .replace_comments(&locator(), &comments)
+        .expect("replace should succeed");
+
+    assert_eq!(
+        archive
+            .load_comments(&locator())
+            .expect("load should succeed"),
+        Some(comments)
+    );
+}
+
+#[rstest]
+fn replace_deletes_comments_missing_from_the_listing(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    archive
+        .replace_comments(&locator(), &[full_comment(), reply(2)])
+        .expect("first replace should succeed");
+
+    archive
+        .replace_comments(&locator(), &[full_comment()])
+        .expect("second replace should succeed");
+
+    assert_eq!(
+        archive
+            .load_comments(&locator())
+            .expect("load should succeed"),
+        Some(vec![full_comment()])
+    );
+}
+
+#[rstest]
+fn delete_removes_only_the_given_comment(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    archive
+        .replace_comments(&locator(), &[full_comment(), reply(2)])
+        .expect("replace should succeed");
+
+    archive
+        .delete_comment(&locator(), 2)
+        .expect("delete should succeed");
+    archive
+        .delete_comment(&locator(), 404)
+        .expect("deleting an unknown comment should succeed");
+
+    assert_eq!(
+        archive
+            .load_comments(&locator())
+            .expect("load should succeed"),
+        Some(vec![full_comment()])
+    );
+}
+
+#[rstest]
+fn same_repository_on_another_host_is_archived_separately(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    let enterprise = PullRequestLocator::parse("https://ghe.example.com/owner/repo/pull/42")
+        .expect("enterprise locator should parse");
+    let enterprise_comment = ReviewComment {
+        body: Some("Enterprise comment".to_owned()),
+        thread_state: None,
+        ..full_comment()
+    };
+
+    archive
+        .replace_comments(&locator(), &[full_comment(), reply(2)])
+        .expect("github.com replace should succeed");
+    archive
+        .replace_comments(&enterprise, std::slice::from_ref(&enterprise_comment))
+        .expect("enterprise replace should succeed");
+    archive
+        .delete_comment(&enterprise, 2)
+        .expect("enterprise delete should succeed");
+
+    assert_eq!(
+        archive
+            .load_comments(&locator())
+            .expect("github.com load should succeed"),
+        Some(vec![full_comment(), reply(2)])
+    );
+    assert_eq!(
+        archive
+            .load_comments(&enterprise)
+            .expect("enterprise load should succeed"),
+        Some(vec![enterprise_comment])
+    );
+}
+
+#[rstest]
+fn upsert_without_thread_state_keeps_the_stored_state(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    archive
+        .replace_comments(&locator(), &[full_comment()])
+        .expect("replace should succeed");
+
+    let edited = ReviewComment {
+        body: Some("Edited".to_owned()),
+        thread_state: None,
+        ..full_comment()
+    };
+    archive
+        .upsert_comments(&locator(), &[edited.clone(), reply(3)])
+        .expect("upsert should succeed");
+
+    let loaded = archive
+        .load_comments(&locator())
+        .expect("load should succeed")
+        .expect("pull request should be archived");
+    assert_eq!(
+        loaded,
+        vec![
+            ReviewComment {
+                thread_state: Some(thread(true)),
+                ..edited
+            },
+            reply(3),
+        ]
+    );
+}
+
+#[rstest]
+fn thread_states_apply_to_every_comment_in_the_thread(
+    migrated_archive: FixtureResult<(TempDir, ReviewCommentArchive)>,
+) {
+    let (_temp_dir, archive) = migrated_archive.expect("fixture should succeed");
+    archive
+        .replace_comments(&locator(), &[full_comment(), reply(2)])
+        .expect("replace should succeed");
+
+    archive
+        .update_thread_states(&locator(), &HashMap::from([(1, thread(false))]))
+        .expect("thread update should succeed");
+
+    let loaded = archive
+        .load_comments(&locator())
+        .expect("load should succeed")
+        .expect("pull request should be archived");
+    assert!(
+        loaded
+            .iter()
+            .all(|comment| comment.thread_state == Some(thread(false)))
+    );
+}
+
+#[rstest]
+fn archive_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let archive = ReviewCommentArchive::new(database_url).expect("archive should be created");
+
+    let error = archive
+        .replace_comments(&locator(), &[full_comment()])
+        .expect_err("replace should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
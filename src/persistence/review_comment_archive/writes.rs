@@ -0,0 +1,233 @@
+//! Write paths for the review comment archive.
+//!
+//! Comments are upserted inside one immediate transaction per pull request,
+//! and thread state is mapped onto the schema's `resolution_status` column.
+
+use std::collections::HashSet;
+
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Bool, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::github::PullRequestLocator;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::github::review_submission::DiffSide;
+
+use crate::persistence::PersistenceError;
+use crate::persistence::connection::{establish_connection, map_write_error, pr_number_to_i64};
+use crate::persistence::repositories::upsert_repository;
+
+use super::{
+    CommentRow, IdRow, REVIEW_COMMENTS_TABLE, ReviewCommentArchive, STATUS_OUTDATED,
+    STATUS_RESOLVED, STATUS_UNRESOLVED, ThreadLinkRow,
+};
+
+impl ReviewCommentArchive {
+    pub(super) fn write_comments(
+        &self,
+        locator: &PullRequestLocator,
+        comments: &[ReviewComment],
+        delete_missing: bool,
+    ) -> Result<(), PersistenceError> {
+        let comment_ids = comments
+            .iter()
+            .map(|comment| {
+                i64::try_from(comment.id).map_err(|_| PersistenceError::WriteFailed {
+                    message: format!("review comment id {} exceeds i64 range", comment.id),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut connection = establish_connection(&self.database_url)?;
+        let result = connection.immediate_transaction(|transaction| {
+            let pull_request_id = Self::upsert_pull_request(transaction, locator)?;
+            for (comment, comment_id) in comments.iter().zip(&comment_ids) {
+                Self::upsert_comment(transaction, (pull_request_id, *comment_id), comment)?;
+            }
+            if delete_missing {
+                Self::delete_comments_except(transaction, pull_request_id, &comment_ids)?;
+            }
+            Ok::<(), diesel::result::Error>(())
+        });
+
+        result.map_err(|error| map_write_error(&mut connection, REVIEW_COMMENTS_TABLE, &error))
+    }
+
+    /// Creates the pull request row on first use and marks it as synced.
+    ///
+    /// Rows created here carry placeholder `title` and `state` values, since
+    /// the comment listing does not include pull request metadata.
+    pub(super) fn upsert_pull_request(
+        connection: &mut SqliteConnection,
+        locator: &PullRequestLocator,
+    ) -> Result<i64, diesel::result::Error> {
+        let repository_id = upsert_repository(connection, locator)?;
+        sql_query(
+            "INSERT INTO pull_requests (repository_id, pr_number, title, state, last_synced) \
+             VALUES (?, ?, '', 'open', CURRENT_TIMESTAMP) \
+             ON CONFLICT(repository_id, pr_number) DO UPDATE SET \
+               last_synced = CURRENT_TIMESTAMP, \
+               updated_at = CURRENT_TIMESTAMP;",
+        )
+        .bind::<BigInt, _>(repository_id)
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .execute(connection)?;
+
+        let row: IdRow =
+            sql_query("SELECT id FROM pull_requests WHERE repository_id = ? AND pr_number = ?;")
+                .bind::<BigInt, _>(repository_id)
+                .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+                .get_result(connection)?;
+
+        Ok(row.id)
+    }
+
+    fn upsert_comment(
+        connection: &mut SqliteConnection,
+        (pull_request_id, comment_id): (i64, i64),
+        comment: &ReviewComment,
+    ) -> Result<(), diesel::result::Error> {
+        let thread = comment.thread_state.as_ref();
+        sql_query(
+            "INSERT INTO review_comments \
+             (pull_request_id, github_comment_id, body, author, file_path, line_number, \
+              side, start_line_number, original_line_number, diff_hunk, commit_sha, \
+              in_reply_to_id, github_created_at, github_updated_at, resolution_status, \
+              thread_id, thread_is_outdated, thread_resolved_by) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(pull_request_id, github_comment_id) DO UPDATE SET \
+               body = excluded.body, \
+               author = excluded.author, \
+               file_path = excluded.file_path, \
+               line_number = excluded.line_number, \
+               side = excluded.side, \
+               start_line_number = excluded.start_line_number, \
+               original_line_number = excluded.original_line_number, \
+               diff_hunk = excluded.diff_hunk, \
+               commit_sha = excluded.commit_sha, \
+               in_reply_to_id = excluded.in_reply_to_id, \
+               github_created_at = excluded.github_created_at, \
+               github_updated_at = excluded.github_updated_at, \
+               resolution_status = CASE WHEN excluded.thread_id IS NULL \
+                 THEN review_comments.resolution_status ELSE excluded.resolution_status END, \
+               thread_is_outdated = CASE WHEN excluded.thread_id IS NULL \
+                 THEN review_comments.thread_is_outdated ELSE excluded.thread_is_outdated END, \
+               thread_resolved_by = CASE WHEN excluded.thread_id IS NULL \
+                 THEN review_comments.thread_resolved_by ELSE excluded.thread_resolved_by END, \
+               thread_id = COALESCE(excluded.thread_id, review_comments.thread_id), \
+               updated_at = CURRENT_TIMESTAMP;",
+        )
+        .bind::<BigInt, _>(pull_request_id)
+        .bind::<BigInt, _>(comment_id)
+        .bind::<Nullable<Text>, _>(comment.body.as_deref())
+        .bind::<Nullable<Text>, _>(comment.author.as_deref())
+        .bind::<Nullable<Text>, _>(comment.file_path.as_deref())
+        .bind::<Nullable<BigInt>, _>(comment.line_number.map(i64::from))
+        .bind::<Nullable<Text>, _>(comment.side.map(DiffSide::as_api_str))
+        .bind::<Nullable<BigInt>, _>(comment.start_line_number.map(i64::from))
+        .bind::<Nullable<BigInt>, _>(comment.original_line_number.map(i64::from))
+        .bind::<Nullable<Text>, _>(comment.diff_hunk.as_deref())
+        .bind::<Nullable<Text>, _>(comment.commit_sha.as_deref())
+        .bind::<Nullable<BigInt>, _>(comment.in_reply_to_id.and_then(|id| i64::try_from(id).ok()))
+        .bind::<Nullable<Text>, _>(comment.created_at.as_deref())
+        .bind::<Nullable<Text>, _>(comment.updated_at.as_deref())
+        .bind::<Text, _>(resolution_status(thread))
+        .bind::<Nullable<Text>, _>(thread.map(|state| state.thread_id.as_str()))
+        .bind::<Bool, _>(thread.is_some_and(|state| state.is_outdated))
+        .bind::<Nullable<Text>, _>(thread.and_then(|state| state.resolved_by.as_deref()))
+        .execute(connection)
+        .map(drop)
+    }
+
+    pub(super) fn write_thread_state(
+        connection: &mut SqliteConnection,
+        (pull_request_id, comment_id): (i64, i64),
+        thread: Option<&ReviewThreadState>,
+    ) -> Result<(), diesel::result::Error> {
+        sql_query(
+            "UPDATE review_comments SET \
+               resolution_status = ?, thread_id = ?, thread_is_outdated = ?, \
+               thread_resolved_by = ?, updated_at = CURRENT_TIMESTAMP \
+             WHERE pull_request_id = ? AND github_comment_id = ?;",
+        )
+        .bind::<Text, _>(resolution_status(thread))
+        .bind::<Nullable<Text>, _>(thread.map(|state| state.thread_id.as_str()))
+        .bind::<Bool, _>(thread.is_some_and(|state| state.is_outdated))
+        .bind::<Nullable<Text>, _>(thread.and_then(|state| state.resolved_by.as_deref()))
+        .bind::<BigInt, _>(pull_request_id)
+        .bind::<BigInt, _>(comment_id)
+        .execute(connection)
+        .map(drop)
+    }
+
+    fn delete_comments_except(
+        connection: &mut SqliteConnection,
+        pull_request_id: i64,
+        keep_ids: &[i64],
+    ) -> Result<(), diesel::result::Error> {
+        let keep: HashSet<i64> = keep_ids.iter().copied().collect();
+        let stored: Vec<ThreadLinkRow> = sql_query(
+            "SELECT github_comment_id, in_reply_to_id FROM review_comments \
+             WHERE pull_request_id = ?;",
+        )
+        .bind::<BigInt, _>(pull_request_id)
+        .load(connection)?;
+
+        for row in stored {
+            if keep.contains(&row.github_comment_id) {
+                continue;
+            }
+            sql_query(
+                "DELETE FROM review_comments WHERE pull_request_id = ? AND github_comment_id = ?;",
+            )
+            .bind::<BigInt, _>(pull_request_id)
+            .bind::<BigInt, _>(row.github_comment_id)
+            .execute(connection)?;
+        }
+        Ok(())
+    }
+}
+
+/// Maps thread state onto the schema's `resolution_status` vocabulary.
+const fn resolution_status(thread: Option<&ReviewThreadState>) -> &'static str {
+    match thread {
+        Some(state) if state.is_resolved => STATUS_RESOLVED,
+        Some(state) if state.is_outdated => STATUS_OUTDATED,
+        _ => STATUS_UNRESOLVED,
+    }
+}
+
+pub(super) fn row_to_comment(row: CommentRow) -> Option<ReviewComment> {
+    let id = u64::try_from(row.github_comment_id).ok()?;
+    let thread_state = row.thread_id.map(|thread_id| ReviewThreadState {
+        thread_id,
+        is_resolved: row.resolution_status == STATUS_RESOLVED,
+        is_outdated: row.thread_is_outdated,
+        resolved_by: row.thread_resolved_by,
+    });
+    Some(ReviewComment {
+        id,
+        body: row.body,
+        author: row.author,
+        file_path: row.file_path,
+        line_number: row.line_number.and_then(|line| u32::try_from(line).ok()),
+        side: row.side.as_deref().and_then(DiffSide::from_api_str),
+        start_line_number: row
+            .start_line_number
+            .and_then(|line| u32::try_from(line).ok()),
+        original_line_number: row
+            .original_line_number
+            .and_then(|line| u32::try_from(line).ok()),
+        diff_hunk: row.diff_hunk,
+        commit_sha: row.commit_sha,
+        in_reply_to_id: row
+            .in_reply_to_id
+            .and_then(|parent_id| u64::try_from(parent_id).ok()),
+        created_at: row.github_created_at,
+        updated_at: row.github_updated_at,
+        thread_state,
+        // Reactions change too often to be worth archiving.
+        reactions: Vec::new(),
+    })
+}
@@ -7,13 +7,13 @@
 
 use std::collections::{HashMap, HashSet};
 
-use diesel::Connection;
 use diesel::QueryableByName;
 use diesel::RunQueryDsl;
 use diesel::sql_query;
 use diesel::sql_types::{BigInt, Nullable, Text};
 use diesel::sqlite::SqliteConnection;
 
+use super::connection::{establish_connection, map_query_error, map_write_error};
 use crate::persistence::PersistenceError;
 use crate::verification::{
     CommentVerificationEvidenceKind, CommentVerificationResult, CommentVerificationStatus,
@@ -106,7 +106,7 @@ impl ReviewCommentVerificationCache {
             .map(|id| Self::try_query_comment_id((*id).into()))
             .collect::<Result<_, _>>()?;
 
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
 
         let query = format!(
             concat!(
@@ -124,7 +124,9 @@ impl ReviewCommentVerificationCache {
                 .bind::<Text, _>(target_sha)
                 .bind::<BigInt, _>(github_comment_id)
                 .load(&mut connection)
-                .map_err(|error| Self::map_query_error(&mut connection, &error))?;
+                .map_err(|error| {
+                    map_query_error(&mut connection, REVIEW_COMMENT_VERIFICATIONS_TABLE, &error)
+                })?;
             rows.append(&mut comment_rows);
         }
 
@@ -169,7 +171,7 @@ impl ReviewCommentVerificationCache {
         &self,
         write: ReviewCommentVerificationCacheWrite<'_>,
     ) -> Result<(), PersistenceError> {
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
         Self::upsert_with_connection(&mut connection, write)
     }
 
@@ -205,7 +207,9 @@ impl ReviewCommentVerificationCache {
             .bind::<BigInt, _>(write.verified_at_unix)
             .execute(connection)
             .map(drop)
-            .map_err(|error| Self::map_write_error(connection, &error))
+            .map_err(|error| {
+                map_write_error(connection, REVIEW_COMMENT_VERIFICATIONS_TABLE, &error)
+            })
     }
 
     /// Inserts or updates multiple cached verification results.
@@ -218,11 +222,13 @@ impl ReviewCommentVerificationCache {
         results: &[CommentVerificationResult],
         verified_at_unix: i64,
     ) -> Result<(), PersistenceError> {
-        let mut connection = self.establish_connection()?;
+        let mut connection = establish_connection(&self.database_url)?;
         sql_query("BEGIN IMMEDIATE TRANSACTION;")
             .execute(&mut connection)
             .map(drop)
-            .map_err(|error| Self::map_write_error(&mut connection, &error))?;
+            .map_err(|error| {
+                map_write_error(&mut connection, REVIEW_COMMENT_VERIFICATIONS_TABLE, &error)
+            })?;
 
         for result in results {
             if let Err(write_error) = Self::upsert_with_connection(
@@ -247,7 +253,9 @@ impl ReviewCommentVerificationCache {
         sql_query("COMMIT;")
             .execute(&mut connection)
             .map(drop)
-            .map_err(|error| Self::map_write_error(&mut connection, &error))
+            .map_err(|error| {
+                map_write_error(&mut connection, REVIEW_COMMENT_VERIFICATIONS_TABLE, &error)
+            })
     }
 
     fn try_query_comment_id(github_comment_id: GithubCommentId) -> Result<i64, PersistenceError> {
@@ -267,73 +275,6 @@ impl ReviewCommentVerificationCache {
             ),
         })
     }
-
-    fn establish_connection(&self) -> Result<SqliteConnection, PersistenceError> {
-        let mut connection = SqliteConnection::establish(&self.database_url).map_err(|error| {
-            PersistenceError::ConnectionFailed {
-                message: error.to_string(),
-            }
-        })?;
-
-        sql_query("PRAGMA foreign_keys = ON;")
-            .execute(&mut connection)
-            .map(drop)
-            .map_err(|error| PersistenceError::ForeignKeysEnableFailed {
-                message: error.to_string(),
-            })?;
-
-        Ok(connection)
-    }
-
-    fn map_diesel_error<F>(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-        error_constructor: F,
-    ) -> PersistenceError
-    where
-        F: FnOnce(String) -> PersistenceError,
-    {
-        match Self::cache_table_exists(connection) {
-            Ok(false) => PersistenceError::SchemaNotInitialised,
-            Ok(true) | Err(_) => error_constructor(error.to_string()),
-        }
-    }
-
-    fn map_query_error(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-    ) -> PersistenceError {
-        Self::map_diesel_error(connection, error, |message| PersistenceError::QueryFailed {
-            message,
-        })
-    }
-
-    fn map_write_error(
-        connection: &mut SqliteConnection,
-        error: &diesel::result::Error,
-    ) -> PersistenceError {
-        Self::map_diesel_error(connection, error, |message| PersistenceError::WriteFailed {
-            message,
-        })
-    }
-
-    fn cache_table_exists(
-        connection: &mut SqliteConnection,
-    ) -> Result<bool, diesel::result::Error> {
-        #[derive(Debug, QueryableByName)]
-        struct Count {
-            #[diesel(sql_type = BigInt)]
-            count: i64,
-        }
-
-        let count: Count = sql_query(
-            "SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' AND name = ?;",
-        )
-        .bind::<Text, _>(REVIEW_COMMENT_VERIFICATIONS_TABLE)
-        .get_result(connection)?;
-
-        Ok(count.count > 0)
-    }
 }
 
 #[cfg(test)]
@@ -5,8 +5,8 @@ use std::collections::HashMap;
 use rstest::{fixture, rstest};
 use tempfile::TempDir;
 
-use crate::persistence::{PersistenceError, migrate_database};
-use crate::telemetry::NoopTelemetrySink;
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, temp_db};
 use crate::verification::{
     CommentVerificationEvidence, CommentVerificationEvidenceKind, CommentVerificationResult,
     CommentVerificationStatus, GithubCommentId,
@@ -14,24 +14,9 @@ use crate::verification::{
 
 use super::{ReviewCommentVerificationCache, ReviewCommentVerificationCacheWrite};
 
-type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
-
-#[fixture]
-fn temp_db() -> FixtureResult<(TempDir, String)> {
-    let temp_dir = TempDir::new()?;
-    let db_path = temp_dir.path().join("frankie.sqlite");
-    let database_url = db_path.to_string_lossy().to_string();
-    Ok((temp_dir, database_url))
-}
-
 #[fixture]
-fn migrated_cache(
-    temp_db: FixtureResult<(TempDir, String)>,
-) -> FixtureResult<(TempDir, ReviewCommentVerificationCache)> {
-    let (temp_dir, database_url) = temp_db?;
-    migrate_database(&database_url, &NoopTelemetrySink)?;
-    let cache = ReviewCommentVerificationCache::new(database_url)?;
-    Ok((temp_dir, cache))
+fn migrated_cache() -> FixtureResult<(TempDir, ReviewCommentVerificationCache)> {
+    migrated(ReviewCommentVerificationCache::new)
 }
 
 fn sample_result(
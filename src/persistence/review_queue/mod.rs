@@ -0,0 +1,255 @@
+//! Review queue cache backed by `SQLite`.
+//!
+//! The dashboard's aggregated review queue is stored in the `review_queue`
+//! table so it can be shown offline and so each pull request remembers when
+//! the user last opened it. A refresh replaces the queued pull requests as a
+//! whole but keeps `last_seen_updated_at` for those still queued.
+//!
+//! Each cache is scoped to the GitHub host the queue was gathered from, so
+//! queues for github.com and a GitHub Enterprise host never overwrite each
+//! other.
+
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::github::models::PullRequestSummary;
+use crate::github::review_queue::{ReviewQueueEntry, ReviewQueueReason};
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+const REVIEW_QUEUE_TABLE: &str = "review_queue";
+
+/// The cached review queue and when it was fetched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CachedReviewQueue {
+    /// Queued pull requests, unranked.
+    pub entries: Vec<ReviewQueueEntry>,
+    /// Unix timestamp of the last refresh, or `None` if never refreshed.
+    pub fetched_at_unix: Option<i64>,
+}
+
+#[derive(Debug, QueryableByName)]
+struct QueueRow {
+    #[diesel(sql_type = Text)]
+    repository: String,
+    #[diesel(sql_type = BigInt)]
+    pr_number: i64,
+    #[diesel(sql_type = Nullable<Text>)]
+    title: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    author: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    state: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    html_url: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    created_at: Option<String>,
+    #[diesel(sql_type = Nullable<Text>)]
+    updated_at: Option<String>,
+    #[diesel(sql_type = Text)]
+    reasons: String,
+    #[diesel(sql_type = Nullable<Text>)]
+    last_seen_updated_at: Option<String>,
+    #[diesel(sql_type = BigInt)]
+    fetched_at_unix: i64,
+}
+
+/// SQLite-backed cache for the review queue dashboard on one GitHub host.
+#[derive(Debug, Clone)]
+pub struct ReviewQueueCache {
+    database_url: String,
+    host: String,
+}
+
+impl ReviewQueueCache {
+    /// Create a cache wrapper targeting the configured `database_url` for
+    /// the queue gathered from `host`, such as `github.com`.
+    ///
+    /// Hosts are matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>, host: &str) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+            host: host.to_ascii_lowercase(),
+        })
+    }
+
+    /// Loads the cached queue.
+    ///
+    /// Rows with an unrepresentable number are skipped, as are unknown
+    /// reason keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// cache schema is missing, or the query fails.
+    pub fn load(&self) -> Result<CachedReviewQueue, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+        let rows: Vec<QueueRow> = sql_query(
+            "SELECT repository, pr_number, title, author, state, html_url, created_at, \
+                    updated_at, reasons, last_seen_updated_at, fetched_at_unix \
+             FROM review_queue \
+             WHERE host = ? \
+             ORDER BY repository ASC, pr_number ASC;",
+        )
+        .bind::<Text, _>(&self.host)
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, REVIEW_QUEUE_TABLE, &error))?;
+
+        let fetched_at_unix = rows.iter().map(|row| row.fetched_at_unix).max();
+        Ok(CachedReviewQueue {
+            entries: rows.into_iter().filter_map(Self::row_to_entry).collect(),
+            fetched_at_unix,
+        })
+    }
+
+    /// Replaces the queued pull requests with `entries`.
+    ///
+    /// Pull requests no longer queued are removed; those still queued keep
+    /// their `last_seen_updated_at`. The replacement runs in a single
+    /// transaction, so a failed write leaves the previous queue intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when a pull request has no repository
+    /// name, the schema is missing, or the write fails.
+    pub fn replace(
+        &self,
+        entries: &[ReviewQueueEntry],
+        fetched_at_unix: i64,
+    ) -> Result<(), PersistenceError> {
+        if let Some(unnamed) = entries
+            .iter()
+            .find(|entry| entry.pull_request.repository.is_none())
+        {
+            return Err(PersistenceError::WriteFailed {
+                message: format!(
+                    "queued pull request #{} has no repository",
+                    unnamed.pull_request.number
+                ),
+            });
+        }
+
+        let mut connection = establish_connection(&self.database_url)?;
+        let result = connection.immediate_transaction(|transaction| {
+            // Mark every row stale; rows still queued are refreshed by the
+            // upserts and the rest are deleted.
+            sql_query("UPDATE review_queue SET fetched_at_unix = -1 WHERE host = ?;")
+                .bind::<Text, _>(&self.host)
+                .execute(transaction)?;
+            for entry in entries {
+                self.upsert_entry(transaction, entry, fetched_at_unix)?;
+            }
+            sql_query("DELETE FROM review_queue WHERE host = ? AND fetched_at_unix = -1;")
+                .bind::<Text, _>(&self.host)
+                .execute(transaction)
+                .map(drop)
+        });
+
+        result.map_err(|error| map_write_error(&mut connection, REVIEW_QUEUE_TABLE, &error))
+    }
+
+    /// Records that the user opened `pull_request` at its current
+    /// `updated_at`, clearing its unread activity.
+    ///
+    /// Does nothing when the pull request is not queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn mark_seen(&self, pull_request: &PullRequestSummary) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+        sql_query(
+            "UPDATE review_queue SET last_seen_updated_at = ? \
+             WHERE host = ? AND repository = ? COLLATE NOCASE AND pr_number = ?;",
+        )
+        .bind::<Nullable<Text>, _>(pull_request.updated_at.as_deref())
+        .bind::<Text, _>(&self.host)
+        .bind::<Text, _>(pull_request.repository.as_deref().unwrap_or_default())
+        .bind::<BigInt, _>(pr_number_to_i64(pull_request.number))
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| map_write_error(&mut connection, REVIEW_QUEUE_TABLE, &error))
+    }
+
+    fn upsert_entry(
+        &self,
+        connection: &mut SqliteConnection,
+        entry: &ReviewQueueEntry,
+        fetched_at_unix: i64,
+    ) -> Result<(), diesel::result::Error> {
+        let pull_request = &entry.pull_request;
+        let reasons = entry
+            .reasons
+            .iter()
+            .map(|reason| reason.key())
+            .collect::<Vec<_>>()
+            .join(",");
+        sql_query(
+            "INSERT INTO review_queue \
+             (host, repository, pr_number, title, author, state, html_url, created_at, \
+              updated_at, reasons, fetched_at_unix) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(host, repository, pr_number) DO UPDATE SET \
+               title = excluded.title, \
+               author = excluded.author, \
+               state = excluded.state, \
+               html_url = excluded.html_url, \
+               created_at = excluded.created_at, \
+               updated_at = excluded.updated_at, \
+               reasons = excluded.reasons, \
+               fetched_at_unix = excluded.fetched_at_unix;",
+        )
+        .bind::<Text, _>(&self.host)
+        .bind::<Text, _>(pull_request.repository.as_deref().unwrap_or_default())
+        .bind::<BigInt, _>(pr_number_to_i64(pull_request.number))
+        .bind::<Nullable<Text>, _>(pull_request.title.as_deref())
+        .bind::<Nullable<Text>, _>(pull_request.author.as_deref())
+        .bind::<Nullable<Text>, _>(pull_request.state.as_deref())
+        .bind::<Nullable<Text>, _>(pull_request.html_url.as_deref())
+        .bind::<Nullable<Text>, _>(pull_request.created_at.as_deref())
+        .bind::<Nullable<Text>, _>(pull_request.updated_at.as_deref())
+        .bind::<Text, _>(reasons)
+        .bind::<BigInt, _>(fetched_at_unix)
+        .execute(connection)
+        .map(drop)
+    }
+
+    fn row_to_entry(row: QueueRow) -> Option<ReviewQueueEntry> {
+        let number = u64::try_from(row.pr_number).ok()?;
+        let reasons = row
+            .reasons
+            .split(',')
+            .filter_map(ReviewQueueReason::from_key)
+            .collect();
+        Some(ReviewQueueEntry {
+            pull_request: PullRequestSummary {
+                number,
+                repository: Some(row.repository),
+                title: row.title,
+                state: row.state,
+                author: row.author,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+                html_url: row.html_url,
+            },
+            reasons,
+            last_seen_updated_at: row.last_seen_updated_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
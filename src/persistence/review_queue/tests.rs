@@ -0,0 +1,157 @@
+//! Tests for the review queue cache.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::ReviewQueueCache;
+use crate::github::models::PullRequestSummary;
+use crate::github::review_queue::{ReviewQueueEntry, ReviewQueueReason};
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, temp_db};
+
+#[fixture]
+fn migrated_cache() -> FixtureResult<(TempDir, ReviewQueueCache)> {
+    migrated(|database_url| ReviewQueueCache::new(database_url, "github.com"))
+}
+
+fn entry(number: u64, updated_at: &str, reasons: &[ReviewQueueReason]) -> ReviewQueueEntry {
+    ReviewQueueEntry {
+        pull_request: PullRequestSummary {
+            number,
+            repository: Some("octo/app".to_owned()),
+            title: Some(format!("Change {number}")),
+            state: Some("open".to_owned()),
+            author: Some("octocat".to_owned()),
+            updated_at: Some(updated_at.to_owned()),
+            html_url: Some(format!("https://github.com/octo/app/pull/{number}")),
+            ..PullRequestSummary::default()
+        },
+        reasons: reasons.to_vec(),
+        last_seen_updated_at: None,
+    }
+}
+
+#[rstest]
+fn replace_round_trips_entries(migrated_cache: FixtureResult<(TempDir, ReviewQueueCache)>) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let entries = vec![
+        entry(
+            1,
+            "2026-04-10T00:00:00Z",
+            &[
+                ReviewQueueReason::ReviewRequested,
+                ReviewQueueReason::Commented,
+            ],
+        ),
+        entry(2, "2026-04-11T00:00:00Z", &[ReviewQueueReason::Matched]),
+    ];
+
+    cache
+        .replace(&entries, 100)
+        .expect("replace should succeed");
+    let cached = cache.load().expect("load should succeed");
+
+    assert_eq!(cached.entries, entries);
+    assert_eq!(cached.fetched_at_unix, Some(100));
+}
+
+#[rstest]
+fn replace_keeps_last_seen_and_drops_unqueued_entries(
+    migrated_cache: FixtureResult<(TempDir, ReviewQueueCache)>,
+) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let first = entry(1, "2026-04-10T00:00:00Z", &[ReviewQueueReason::Commented]);
+    cache
+        .replace(
+            &[
+                first.clone(),
+                entry(2, "2026-04-11T00:00:00Z", &[ReviewQueueReason::Commented]),
+            ],
+            100,
+        )
+        .expect("replace should succeed");
+    cache
+        .mark_seen(&first.pull_request)
+        .expect("mark_seen should succeed");
+
+    let refreshed = entry(1, "2026-04-12T00:00:00Z", &[ReviewQueueReason::Commented]);
+    cache
+        .replace(std::slice::from_ref(&refreshed), 200)
+        .expect("replace should succeed");
+    let cached = cache.load().expect("load should succeed");
+
+    assert_eq!(
+        cached.entries,
+        vec![ReviewQueueEntry {
+            last_seen_updated_at: Some("2026-04-10T00:00:00Z".to_owned()),
+            ..refreshed
+        }]
+    );
+    assert!(
+        cached
+            .entries
+            .iter()
+            .all(ReviewQueueEntry::has_unread_activity)
+    );
+}
+
+#[rstest]
+fn queues_on_different_hosts_do_not_overwrite_each_other(
+    migrated_cache: FixtureResult<(TempDir, ReviewQueueCache)>,
+) {
+    let (temp_dir, public_cache) = migrated_cache.expect("fixture should succeed");
+    let database_url = temp_dir.path().join("frankie.sqlite");
+    let enterprise_cache = ReviewQueueCache::new(
+        database_url.to_string_lossy().to_string(),
+        "GHE.example.com",
+    )
+    .expect("cache should be created");
+    let public = entry(1, "2026-04-10T00:00:00Z", &[ReviewQueueReason::Commented]);
+    let enterprise = entry(1, "2026-04-11T00:00:00Z", &[ReviewQueueReason::Matched]);
+
+    public_cache
+        .replace(std::slice::from_ref(&public), 100)
+        .expect("replace should succeed");
+    enterprise_cache
+        .replace(std::slice::from_ref(&enterprise), 200)
+        .expect("replace should succeed");
+    enterprise_cache
+        .mark_seen(&enterprise.pull_request)
+        .expect("mark_seen should succeed");
+
+    let public_cached = public_cache.load().expect("load should succeed");
+    let enterprise_cached = enterprise_cache.load().expect("load should succeed");
+    assert_eq!(public_cached.entries, vec![public]);
+    assert_eq!(
+        enterprise_cached.entries,
+        vec![ReviewQueueEntry {
+            last_seen_updated_at: Some("2026-04-11T00:00:00Z".to_owned()),
+            ..enterprise
+        }]
+    );
+}
+
+#[rstest]
+fn replace_rejects_entries_without_a_repository(
+    migrated_cache: FixtureResult<(TempDir, ReviewQueueCache)>,
+) {
+    let (_temp_dir, cache) = migrated_cache.expect("fixture should succeed");
+    let mut unnamed = entry(1, "2026-04-10T00:00:00Z", &[ReviewQueueReason::Matched]);
+    unnamed.pull_request.repository = None;
+
+    assert!(matches!(
+        cache.replace(&[unnamed], 100),
+        Err(PersistenceError::WriteFailed { .. })
+    ));
+}
+
+#[rstest]
+fn load_reports_a_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let cache = ReviewQueueCache::new(database_url, "github.com").expect("cache should be created");
+
+    assert!(matches!(
+        cache.load(),
+        Err(PersistenceError::SchemaNotInitialised)
+    ));
+}
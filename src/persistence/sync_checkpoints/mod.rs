@@ -0,0 +1,129 @@
+//! Per-pull-request sync checkpoints backed by `SQLite`.
+//!
+//! The initial schema ships a `sync_checkpoints` table keyed by repository
+//! and resource name. Checkpoints here are scoped to a single pull request by
+//! prefixing the resource with the pull request number, so one repository row
+//! can carry checkpoints for many pull requests. The checkpoint value is an
+//! opaque string chosen by the sync service (for example the latest
+//! `updated_at` timestamp seen, or a pagination cursor).
+
+use diesel::OptionalExtension;
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+
+use crate::github::PullRequestLocator;
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error};
+use super::repositories::upsert_repository;
+
+const SYNC_CHECKPOINTS_TABLE: &str = "sync_checkpoints";
+
+#[derive(Debug, QueryableByName)]
+struct CheckpointRow {
+    #[diesel(sql_type = Nullable<Text>)]
+    checkpoint: Option<String>,
+}
+
+/// SQLite-backed store for per-pull-request sync checkpoints.
+#[derive(Debug, Clone)]
+pub struct SyncCheckpointStore {
+    database_url: String,
+}
+
+impl SyncCheckpointStore {
+    /// Creates a store targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Loads the checkpoint stored for `resource` on the pull request.
+    ///
+    /// Returns `None` when no checkpoint has been saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// schema is missing, or the query fails.
+    pub fn load(
+        &self,
+        locator: &PullRequestLocator,
+        resource: &str,
+    ) -> Result<Option<String>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let row: Option<CheckpointRow> = sql_query(
+            "SELECT sync_checkpoints.checkpoint AS checkpoint \
+             FROM sync_checkpoints \
+             JOIN repositories ON repositories.id = sync_checkpoints.repository_id \
+             WHERE repositories.host = ? AND repositories.owner = ? \
+               AND repositories.name = ? AND sync_checkpoints.resource = ? \
+             LIMIT 1;",
+        )
+        .bind::<Text, _>(locator.host())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<Text, _>(Self::scoped_resource(locator, resource))
+        .get_result(&mut connection)
+        .optional()
+        .map_err(|error| map_query_error(&mut connection, SYNC_CHECKPOINTS_TABLE, &error))?;
+
+        Ok(row.and_then(|checkpoint_row| checkpoint_row.checkpoint))
+    }
+
+    /// Saves the checkpoint for `resource` on the pull request.
+    ///
+    /// Creates the repository row on first use. The repository and checkpoint
+    /// rows are written in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the database cannot be opened, the
+    /// schema is missing, or the write fails.
+    pub fn save(
+        &self,
+        locator: &PullRequestLocator,
+        resource: &str,
+        checkpoint: &str,
+    ) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+        let scoped_resource = Self::scoped_resource(locator, resource);
+
+        let result = connection.immediate_transaction(|transaction| {
+            let repository_id = upsert_repository(transaction, locator)?;
+            sql_query(
+                "INSERT INTO sync_checkpoints (repository_id, resource, checkpoint) \
+                 VALUES (?, ?, ?) \
+                 ON CONFLICT(repository_id, resource) DO UPDATE SET \
+                   checkpoint = excluded.checkpoint, \
+                   updated_at = CURRENT_TIMESTAMP;",
+            )
+            .bind::<BigInt, _>(repository_id)
+            .bind::<Text, _>(scoped_resource.as_str())
+            .bind::<Text, _>(checkpoint)
+            .execute(transaction)
+            .map(drop)
+        });
+
+        result.map_err(|error| map_write_error(&mut connection, SYNC_CHECKPOINTS_TABLE, &error))
+    }
+
+    fn scoped_resource(locator: &PullRequestLocator, resource: &str) -> String {
+        format!("pull/{}/{resource}", locator.number().get())
+    }
+}
+
+#[cfg(test)]
+mod tests;
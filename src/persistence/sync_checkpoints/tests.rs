@@ -0,0 +1,78 @@
+//! Tests for the sync checkpoint store.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::SyncCheckpointStore;
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
+
+const RESOURCE: &str = "review_comments";
+
+#[fixture]
+fn migrated_store() -> FixtureResult<(TempDir, SyncCheckpointStore)> {
+    migrated(SyncCheckpointStore::new)
+}
+
+#[rstest]
+fn checkpoints_round_trip_per_pull_request(
+    migrated_store: FixtureResult<(TempDir, SyncCheckpointStore)>,
+) {
+    let (_temp_dir, store) = migrated_store.expect("fixture should succeed");
+    let first = parse_locator(1).expect("locator should parse");
+    let second = parse_locator(2).expect("locator should parse");
+
+    assert_eq!(
+        store.load(&first, RESOURCE).expect("load should succeed"),
+        None
+    );
+
+    store
+        .save(&first, RESOURCE, "2025-01-01T00:00:00Z")
+        .expect("save should succeed");
+    store
+        .save(&second, RESOURCE, "2025-02-01T00:00:00Z")
+        .expect("save should succeed");
+
+    assert_eq!(
+        store.load(&first, RESOURCE).expect("load should succeed"),
+        Some("2025-01-01T00:00:00Z".to_owned())
+    );
+    assert_eq!(
+        store.load(&second, RESOURCE).expect("load should succeed"),
+        Some("2025-02-01T00:00:00Z".to_owned())
+    );
+}
+
+#[rstest]
+fn save_overwrites_previous_checkpoint(
+    migrated_store: FixtureResult<(TempDir, SyncCheckpointStore)>,
+) {
+    let (_temp_dir, store) = migrated_store.expect("fixture should succeed");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    store
+        .save(&locator, RESOURCE, "2025-01-01T00:00:00Z")
+        .expect("first save should succeed");
+    store
+        .save(&locator, RESOURCE, "2025-03-01T00:00:00Z")
+        .expect("second save should succeed");
+
+    assert_eq!(
+        store.load(&locator, RESOURCE).expect("load should succeed"),
+        Some("2025-03-01T00:00:00Z".to_owned())
+    );
+}
+
+#[rstest]
+fn store_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let store = SyncCheckpointStore::new(database_url).expect("store should be created");
+    let locator = parse_locator(42).expect("locator should parse");
+
+    let error = store
+        .save(&locator, RESOURCE, "2025-01-01T00:00:00Z")
+        .expect_err("save should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
@@ -0,0 +1,46 @@
+//! Test fixtures shared by the persistence stores.
+//!
+//! Every store is exercised against a fresh `SQLite` file in a temporary
+//! directory, either migrated to the current schema or left empty to check
+//! how a missing schema is reported.
+
+use rstest::fixture;
+use tempfile::TempDir;
+
+use crate::github::PullRequestLocator;
+use crate::persistence::migrate_database;
+use crate::telemetry::NoopTelemetrySink;
+
+/// Result type returned by persistence fixtures and tests.
+pub(crate) type FixtureResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// Creates a temporary directory and the URL of an unmigrated database in it.
+///
+/// The directory must outlive the database, so it is returned alongside the
+/// URL.
+#[fixture]
+pub(crate) fn temp_db() -> FixtureResult<(TempDir, String)> {
+    let temp_dir = TempDir::new()?;
+    let db_path = temp_dir.path().join("frankie.sqlite");
+    Ok((temp_dir, db_path.to_string_lossy().to_string()))
+}
+
+/// Migrates a fresh temporary database and opens a store on it with `open`.
+pub(crate) fn migrated<S, E>(
+    open: impl FnOnce(String) -> Result<S, E>,
+) -> FixtureResult<(TempDir, S)>
+where
+    E: std::error::Error + 'static,
+{
+    let (temp_dir, database_url) = temp_db()?;
+    migrate_database(&database_url, &NoopTelemetrySink)?;
+    let store = open(database_url)?;
+    Ok((temp_dir, store))
+}
+
+/// Parses the locator of pull request `pr_number` in `owner/repo` on
+/// github.com.
+pub(crate) fn parse_locator(pr_number: u64) -> FixtureResult<PullRequestLocator> {
+    let url = format!("https://github.com/owner/repo/pull/{pr_number}");
+    Ok(PullRequestLocator::parse(&url)?)
+}
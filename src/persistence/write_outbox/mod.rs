@@ -0,0 +1,282 @@
+//! Durable outbox of review writes backed by `SQLite`.
+//!
+//! Replies and thread resolutions that cannot reach GitHub are queued here as
+//! [`WriteIntent`]s so they survive restarts and flaky connections. Each
+//! queued write carries a stable intent ID, the number of delivery attempts
+//! made so far, and the last error seen. Rows are removed once the write has
+//! been applied on GitHub or discarded by the user.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use diesel::OptionalExtension;
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+
+use crate::github::PullRequestLocator;
+use crate::github::outbox::WriteIntent;
+
+use super::PersistenceError;
+use super::connection::{establish_connection, map_query_error, map_write_error, pr_number_to_i64};
+
+const WRITE_OUTBOX_TABLE: &str = "write_outbox";
+
+/// Distinguishes intent IDs generated within the same millisecond.
+static INTENT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, QueryableByName)]
+struct QueuedWriteRow {
+    #[diesel(sql_type = Text)]
+    intent_id: String,
+    #[diesel(sql_type = Text)]
+    api_base: String,
+    #[diesel(sql_type = Text)]
+    owner: String,
+    #[diesel(sql_type = Text)]
+    repo: String,
+    #[diesel(sql_type = BigInt)]
+    pr_number: i64,
+    #[diesel(sql_type = Text)]
+    payload: String,
+    #[diesel(sql_type = BigInt)]
+    attempt_count: i64,
+    #[diesel(sql_type = Nullable<Text>)]
+    last_error: Option<String>,
+    #[diesel(sql_type = Text)]
+    created_at: String,
+}
+
+/// Write intent waiting in the outbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedWrite {
+    /// Stable identifier of the intent, kept across retries and restarts.
+    pub intent_id: String,
+    /// Pull request the write applies to.
+    pub locator: PullRequestLocator,
+    /// The write to apply.
+    pub intent: WriteIntent,
+    /// Number of delivery attempts that have failed so far.
+    pub attempt_count: u32,
+    /// Error from the most recent failed attempt, if any.
+    pub last_error: Option<String>,
+    /// When the write was queued (`YYYY-MM-DD HH:MM:SS`, UTC).
+    pub queued_at: String,
+}
+
+/// SQLite-backed outbox of review writes waiting to reach GitHub.
+#[derive(Debug, Clone)]
+pub struct WriteOutbox {
+    database_url: String,
+}
+
+impl WriteOutbox {
+    /// Creates an outbox targeting the configured `database_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError::BlankDatabaseUrl`] when the URL is blank.
+    pub fn new(database_url: impl Into<String>) -> Result<Self, PersistenceError> {
+        let database_url_string = database_url.into();
+        if database_url_string.trim().is_empty() {
+            return Err(PersistenceError::BlankDatabaseUrl);
+        }
+        Ok(Self {
+            database_url: database_url_string,
+        })
+    }
+
+    /// Queues `intent` for the pull request and returns the queued write.
+    ///
+    /// A queued thread resolution replaces any earlier one for the same
+    /// thread, so only the latest requested state is replayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn enqueue(
+        &self,
+        locator: &PullRequestLocator,
+        intent: &WriteIntent,
+    ) -> Result<QueuedWrite, PersistenceError> {
+        let payload =
+            serde_json::to_string(intent).map_err(|error| PersistenceError::WriteFailed {
+                message: format!("serialise write intent: {error}"),
+            })?;
+        let intent_id = new_intent_id();
+
+        for superseded in self
+            .list_for_pull_request(locator)?
+            .iter()
+            .filter(|queued| intent.supersedes(&queued.intent))
+        {
+            self.remove(&superseded.intent_id)?;
+        }
+
+        let mut connection = establish_connection(&self.database_url)?;
+        sql_query(
+            "INSERT INTO write_outbox \
+             (intent_id, api_base, owner, repo, pr_number, payload) \
+             VALUES (?, ?, ?, ?, ?, ?);",
+        )
+        .bind::<Text, _>(intent_id.as_str())
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .bind::<Text, _>(payload.as_str())
+        .execute(&mut connection)
+        .map_err(|error| map_write_error(&mut connection, WRITE_OUTBOX_TABLE, &error))?;
+
+        self.get(&intent_id)?
+            .ok_or_else(|| PersistenceError::QueryFailed {
+                message: format!("queued write {intent_id} could not be read back"),
+            })
+    }
+
+    /// Lists every queued write in queue order.
+    ///
+    /// Rows whose payload or pull request cannot be decoded are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the query
+    /// fails.
+    pub fn list(&self) -> Result<Vec<QueuedWrite>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let rows: Vec<QueuedWriteRow> = sql_query(
+            "SELECT intent_id, api_base, owner, repo, pr_number, payload, \
+                    attempt_count, last_error, created_at \
+             FROM write_outbox ORDER BY id ASC;",
+        )
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, WRITE_OUTBOX_TABLE, &error))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_queued).collect())
+    }
+
+    /// Lists the writes queued for the pull request in queue order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the query
+    /// fails.
+    pub fn list_for_pull_request(
+        &self,
+        locator: &PullRequestLocator,
+    ) -> Result<Vec<QueuedWrite>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let rows: Vec<QueuedWriteRow> = sql_query(
+            "SELECT intent_id, api_base, owner, repo, pr_number, payload, \
+                    attempt_count, last_error, created_at \
+             FROM write_outbox \
+             WHERE api_base = ? AND owner = ? AND repo = ? AND pr_number = ? \
+             ORDER BY id ASC;",
+        )
+        .bind::<Text, _>(locator.api_base().as_str())
+        .bind::<Text, _>(locator.owner().as_str())
+        .bind::<Text, _>(locator.repository().as_str())
+        .bind::<BigInt, _>(pr_number_to_i64(locator.number().get()))
+        .load(&mut connection)
+        .map_err(|error| map_query_error(&mut connection, WRITE_OUTBOX_TABLE, &error))?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_queued).collect())
+    }
+
+    /// Loads the queued write with the given intent ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the query
+    /// fails.
+    pub fn get(&self, intent_id: &str) -> Result<Option<QueuedWrite>, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let row: Option<QueuedWriteRow> = sql_query(
+            "SELECT intent_id, api_base, owner, repo, pr_number, payload, \
+                    attempt_count, last_error, created_at \
+             FROM write_outbox WHERE intent_id = ? LIMIT 1;",
+        )
+        .bind::<Text, _>(intent_id)
+        .get_result(&mut connection)
+        .optional()
+        .map_err(|error| map_query_error(&mut connection, WRITE_OUTBOX_TABLE, &error))?;
+
+        Ok(row.and_then(Self::row_to_queued))
+    }
+
+    /// Records a failed delivery attempt and its error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn record_failure(&self, intent_id: &str, message: &str) -> Result<(), PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        sql_query(
+            "UPDATE write_outbox \
+             SET attempt_count = attempt_count + 1, last_error = ?, \
+                 updated_at = CURRENT_TIMESTAMP \
+             WHERE intent_id = ?;",
+        )
+        .bind::<Text, _>(message)
+        .bind::<Text, _>(intent_id)
+        .execute(&mut connection)
+        .map(drop)
+        .map_err(|error| map_write_error(&mut connection, WRITE_OUTBOX_TABLE, &error))
+    }
+
+    /// Removes the queued write, returning whether it existed.
+    ///
+    /// Used both when a write has been applied and when it is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PersistenceError`] when the schema is missing or the write
+    /// fails.
+    pub fn remove(&self, intent_id: &str) -> Result<bool, PersistenceError> {
+        let mut connection = establish_connection(&self.database_url)?;
+
+        let removed = sql_query("DELETE FROM write_outbox WHERE intent_id = ?;")
+            .bind::<Text, _>(intent_id)
+            .execute(&mut connection)
+            .map_err(|error| map_write_error(&mut connection, WRITE_OUTBOX_TABLE, &error))?;
+
+        Ok(removed > 0)
+    }
+
+    fn row_to_queued(row: QueuedWriteRow) -> Option<QueuedWrite> {
+        let number = u64::try_from(row.pr_number).ok()?;
+        let locator =
+            PullRequestLocator::from_parts(&row.api_base, &row.owner, &row.repo, number).ok()?;
+        let intent = serde_json::from_str(&row.payload).ok()?;
+        Some(QueuedWrite {
+            intent_id: row.intent_id,
+            locator,
+            intent,
+            attempt_count: u32::try_from(row.attempt_count).unwrap_or(u32::MAX),
+            last_error: row.last_error,
+            queued_at: row.created_at,
+        })
+    }
+}
+
+/// Generates a new intent ID from the current time and a process-wide
+/// sequence number.
+fn new_intent_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let sequence = INTENT_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    format!("w{millis:x}{sequence:04x}")
+}
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
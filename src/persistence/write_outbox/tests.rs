@@ -0,0 +1,153 @@
+//! Tests for the write outbox.
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::WriteOutbox;
+use crate::github::outbox::WriteIntent;
+use crate::persistence::PersistenceError;
+use crate::persistence::test_support::{FixtureResult, migrated, parse_locator, temp_db};
+
+#[fixture]
+fn migrated_outbox() -> FixtureResult<(TempDir, WriteOutbox)> {
+    migrated(WriteOutbox::new)
+}
+
+fn reply(body: &str) -> WriteIntent {
+    WriteIntent::Reply {
+        comment_id: 7,
+        body: body.to_owned(),
+    }
+}
+
+fn resolution(thread_id: &str, resolved: bool) -> WriteIntent {
+    WriteIntent::ThreadResolution {
+        thread_id: thread_id.to_owned(),
+        resolved,
+    }
+}
+
+#[rstest]
+fn queued_writes_round_trip_per_pull_request(
+    migrated_outbox: FixtureResult<(TempDir, WriteOutbox)>,
+) {
+    let (_temp_dir, outbox) = migrated_outbox.expect("fixture should succeed");
+    let first = parse_locator(1).expect("locator should parse");
+    let second = parse_locator(2).expect("locator should parse");
+
+    let queued = outbox
+        .enqueue(&first, &reply("Thanks"))
+        .expect("enqueue should succeed");
+    outbox
+        .enqueue(&second, &resolution("PRRT_1", true))
+        .expect("enqueue should succeed");
+
+    assert_eq!(queued.locator, first);
+    assert_eq!(queued.intent, reply("Thanks"));
+    assert_eq!(queued.attempt_count, 0);
+    assert_eq!(queued.last_error, None);
+    assert_eq!(
+        outbox
+            .list_for_pull_request(&first)
+            .expect("list should succeed"),
+        vec![queued.clone()]
+    );
+    assert_eq!(outbox.list().expect("list should succeed").len(), 2);
+    assert_eq!(
+        outbox.get(&queued.intent_id).expect("get should succeed"),
+        Some(queued)
+    );
+}
+
+#[rstest]
+fn intent_ids_are_unique(migrated_outbox: FixtureResult<(TempDir, WriteOutbox)>) {
+    let (_temp_dir, outbox) = migrated_outbox.expect("fixture should succeed");
+    let locator = parse_locator(1).expect("locator should parse");
+
+    let first = outbox
+        .enqueue(&locator, &reply("Same"))
+        .expect("enqueue should succeed");
+    let second = outbox
+        .enqueue(&locator, &reply("Same"))
+        .expect("enqueue should succeed");
+
+    assert_ne!(first.intent_id, second.intent_id);
+}
+
+#[rstest]
+fn failures_are_counted_and_writes_can_be_removed(
+    migrated_outbox: FixtureResult<(TempDir, WriteOutbox)>,
+) {
+    let (_temp_dir, outbox) = migrated_outbox.expect("fixture should succeed");
+    let locator = parse_locator(1).expect("locator should parse");
+    let queued = outbox
+        .enqueue(&locator, &reply("Thanks"))
+        .expect("enqueue should succeed");
+
+    outbox
+        .record_failure(&queued.intent_id, "timed out")
+        .expect("first failure should record");
+    outbox
+        .record_failure(&queued.intent_id, "connection reset")
+        .expect("second failure should record");
+
+    let stored = outbox
+        .get(&queued.intent_id)
+        .expect("get should succeed")
+        .expect("write should still be queued");
+    assert_eq!(stored.attempt_count, 2);
+    assert_eq!(stored.last_error.as_deref(), Some("connection reset"));
+
+    assert!(
+        outbox
+            .remove(&queued.intent_id)
+            .expect("remove should succeed")
+    );
+    assert!(
+        !outbox
+            .remove(&queued.intent_id)
+            .expect("remove should succeed")
+    );
+    assert!(outbox.list().expect("list should succeed").is_empty());
+}
+
+#[rstest]
+fn thread_resolution_replaces_earlier_request_for_the_thread(
+    migrated_outbox: FixtureResult<(TempDir, WriteOutbox)>,
+) {
+    let (_temp_dir, outbox) = migrated_outbox.expect("fixture should succeed");
+    let locator = parse_locator(1).expect("locator should parse");
+
+    outbox
+        .enqueue(&locator, &resolution("PRRT_1", true))
+        .expect("enqueue should succeed");
+    outbox
+        .enqueue(&locator, &resolution("PRRT_2", true))
+        .expect("enqueue should succeed");
+    outbox
+        .enqueue(&locator, &resolution("PRRT_1", false))
+        .expect("enqueue should succeed");
+
+    let intents: Vec<WriteIntent> = outbox
+        .list()
+        .expect("list should succeed")
+        .into_iter()
+        .map(|queued| queued.intent)
+        .collect();
+    assert_eq!(
+        intents,
+        vec![resolution("PRRT_2", true), resolution("PRRT_1", false)]
+    );
+}
+
+#[rstest]
+fn outbox_reports_missing_schema(temp_db: FixtureResult<(TempDir, String)>) {
+    let (_temp_dir, database_url) = temp_db.expect("fixture should succeed");
+    let outbox = WriteOutbox::new(database_url).expect("outbox should be created");
+
+    let error = outbox
+        .list()
+        .expect_err("list should fail without migrations");
+
+    assert_eq!(error, PersistenceError::SchemaNotInitialised);
+}
@@ -0,0 +1,209 @@
+//! Applying suggested changes to the working tree.
+//!
+//! The target lines are checked against the suggestion's original lines
+//! first, so a conflict is reported line by line. The change is then
+//! written as a zero-context unified diff and applied to the working tree
+//! with git2, leaving the index untouched.
+
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use cap_std::ambient_authority;
+use cap_std::fs::Dir;
+use git2::{ApplyLocation, Diff, Repository};
+
+use super::error::{SuggestionConflict, SuggestionError};
+use super::model::SuggestedChange;
+
+/// Applies suggested changes to a local checkout.
+///
+/// This trait enables dependency injection, allowing tests to use stub
+/// implementations without real repositories.
+pub trait SuggestionApplier: Send + Sync + Debug {
+    /// Replaces the suggestion's target lines in the working tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SuggestionError::Conflict`] when the target lines no longer
+    /// match the suggestion's original lines, and other variants when the
+    /// file cannot be read or the patch cannot be applied.
+    fn apply_suggestion(&self, change: &SuggestedChange) -> Result<(), SuggestionError>;
+}
+
+/// Git2-based implementation of [`SuggestionApplier`].
+///
+/// Uses a `Mutex` to wrap the `Repository` because `git2::Repository` is not
+/// `Sync`.
+pub struct Git2SuggestionApplier {
+    repo: Mutex<Repository>,
+}
+
+impl Debug for Git2SuggestionApplier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Git2SuggestionApplier")
+            .field("repo", &"<git2::Repository>")
+            .finish()
+    }
+}
+
+impl Git2SuggestionApplier {
+    /// Opens the repository at `repo_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not a Git repository with a working
+    /// tree.
+    pub fn open(repo_path: &Path) -> Result<Self, SuggestionError> {
+        let repo = Repository::open(repo_path).map_err(|error| {
+            SuggestionError::RepositoryNotAvailable {
+                message: error.message().to_owned(),
+            }
+        })?;
+        if repo.workdir().is_none() {
+            return Err(SuggestionError::RepositoryNotAvailable {
+                message: "repository has no working tree".to_owned(),
+            });
+        }
+        Ok(Self {
+            repo: Mutex::new(repo),
+        })
+    }
+
+    fn lock_repo(&self) -> Result<MutexGuard<'_, Repository>, SuggestionError> {
+        self.repo
+            .lock()
+            .map_err(|_| SuggestionError::RepositoryNotAvailable {
+                message: "Git repository mutex poisoned".to_owned(),
+            })
+    }
+}
+
+impl SuggestionApplier for Git2SuggestionApplier {
+    fn apply_suggestion(&self, change: &SuggestedChange) -> Result<(), SuggestionError> {
+        let expected =
+            change
+                .original
+                .as_deref()
+                .ok_or_else(|| SuggestionError::OriginalUnknown {
+                    location: change.location(),
+                })?;
+        let repo = self.lock_repo()?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| SuggestionError::RepositoryNotAvailable {
+                message: "repository has no working tree".to_owned(),
+            })?;
+        let content = read_worktree_file(workdir, &change.file_path)?;
+
+        let target = target_lines(&content, change);
+        if !lines_match(&target, expected) {
+            return Err(SuggestionError::Conflict(SuggestionConflict {
+                location: change.location(),
+                expected: expected.to_vec(),
+                found: target
+                    .iter()
+                    .map(|line| strip_eol(line).to_owned())
+                    .collect(),
+            }));
+        }
+
+        let patch = build_patch(change, &target, line_ending(&content));
+        Diff::from_buffer(patch.as_bytes())
+            .and_then(|diff| repo.apply(&diff, ApplyLocation::WorkDir, None))
+            .map_err(|error| SuggestionError::ApplyFailed {
+                location: change.location(),
+                message: error.message().to_owned(),
+            })
+    }
+}
+
+/// Reads `path` from the working tree, refusing paths that escape it.
+fn read_worktree_file(workdir: &Path, path: &str) -> Result<String, SuggestionError> {
+    let read_failed = |error: std::io::Error| SuggestionError::ReadFailed {
+        path: path.to_owned(),
+        message: error.to_string(),
+    };
+    let dir = Dir::open_ambient_dir(workdir, ambient_authority()).map_err(read_failed)?;
+    dir.read_to_string(path).map_err(read_failed)
+}
+
+/// Returns the lines the suggestion replaces, each with its line ending.
+///
+/// Fewer lines than the range covers are returned when the file ends early.
+fn target_lines<'a>(content: &'a str, change: &SuggestedChange) -> Vec<&'a str> {
+    let skip = usize::try_from(change.start_line.saturating_sub(1)).unwrap_or(usize::MAX);
+    let take = usize::try_from(change.line_count()).unwrap_or(usize::MAX);
+    content
+        .split_inclusive('\n')
+        .skip(skip)
+        .take(take)
+        .collect()
+}
+
+/// Returns the file's line ending, judged by its first line.
+fn line_ending(content: &str) -> &'static str {
+    if content
+        .split_once('\n')
+        .is_some_and(|(first, _)| first.ends_with('\r'))
+    {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+fn lines_match(target: &[&str], expected: &[String]) -> bool {
+    target.len() == expected.len()
+        && target
+            .iter()
+            .zip(expected)
+            .all(|(line, expected_line)| strip_eol(line) == expected_line)
+}
+
+fn strip_eol(line: &str) -> &str {
+    line.strip_suffix('\n').map_or(line, |stripped| {
+        stripped.strip_suffix('\r').unwrap_or(stripped)
+    })
+}
+
+/// Builds a zero-context unified diff replacing `target` with the
+/// suggestion's replacement lines.
+///
+/// Replacement lines end with `eol`, and keep a missing final newline when
+/// the target ran to the end of the file.
+fn build_patch(change: &SuggestedChange, target: &[&str], eol: &str) -> String {
+    let file = &change.file_path;
+    let at_eof_without_newline = target.last().is_some_and(|line| !line.ends_with('\n'));
+
+    // libgit2 locates a hunk by its new start line, so a deletion keeps the
+    // start line rather than pointing at the line before the range as
+    // `git diff` would.
+    let mut patch = format!(
+        "diff --git a/{file} b/{file}\n--- a/{file}\n+++ b/{file}\n@@ -{start},{} +{start},{} @@\n",
+        target.len(),
+        change.replacement.len(),
+        start = change.start_line,
+    );
+    for line in target {
+        patch.push('-');
+        patch.push_str(line);
+        if !line.ends_with('\n') {
+            patch.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+    for (index, line) in change.replacement.iter().enumerate() {
+        patch.push('+');
+        patch.push_str(line);
+        patch.push_str(eol);
+        if at_eof_without_newline && index + 1 == change.replacement.len() {
+            patch.truncate(patch.len() - eol.len());
+            patch.push_str("\n\\ No newline at end of file\n");
+        }
+    }
+    patch
+}
+
+#[cfg(test)]
+#[path = "apply_tests.rs"]
+mod tests;
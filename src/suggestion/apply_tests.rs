@@ -0,0 +1,210 @@
+//! Tests for applying suggested changes with git2.
+//!
+//! These tests use real Git repositories created via `tempfile`.
+
+#![expect(
+    clippy::panic_in_result_fn,
+    reason = "Test assertions are expected to panic on failure"
+)]
+
+use cap_std::ambient_authority;
+use cap_std::fs::Dir;
+use git2::Repository;
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use super::{Git2SuggestionApplier, SuggestionApplier};
+use crate::suggestion::{SuggestedChange, SuggestionConflict, SuggestionError};
+
+/// Error type for test fixtures and helpers.
+type TestError = Box<dyn std::error::Error>;
+
+const FILE: &str = "src/config.rs";
+const CONTENT: &str = "fn config() {\n    let retries = 3;\n    let delay = 5;\n}\n";
+
+/// Temporary repository with `CONTENT` committed at `FILE`.
+struct Checkout {
+    dir: TempDir,
+    applier: Git2SuggestionApplier,
+}
+
+impl Checkout {
+    fn write(&self, content: &str) -> Result<(), TestError> {
+        let dir = Dir::open_ambient_dir(self.dir.path(), ambient_authority())?;
+        dir.write(FILE, content)?;
+        Ok(())
+    }
+
+    fn read(&self) -> Result<String, TestError> {
+        let dir = Dir::open_ambient_dir(self.dir.path(), ambient_authority())?;
+        Ok(dir.read_to_string(FILE)?)
+    }
+}
+
+#[fixture]
+fn checkout() -> Checkout {
+    let dir = TempDir::new().expect("temp dir should be created");
+    let repo = Repository::init(dir.path()).expect("repository should initialise");
+    let root = Dir::open_ambient_dir(dir.path(), ambient_authority()).expect("dir should open");
+    root.create_dir_all("src").expect("src should be created");
+    root.write(FILE, CONTENT).expect("file should be written");
+
+    let mut index = repo.index().expect("index should open");
+    index
+        .add_path(std::path::Path::new(FILE))
+        .expect("file should be staged");
+    let tree_id = index.write_tree().expect("tree should be written");
+    let tree = repo.find_tree(tree_id).expect("tree should exist");
+    let signature =
+        git2::Signature::now("Test User", "test@example.com").expect("signature should build");
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .expect("commit should succeed");
+
+    let applier = Git2SuggestionApplier::open(dir.path()).expect("applier should open");
+    Checkout { dir, applier }
+}
+
+fn change(
+    start_line: u32,
+    end_line: u32,
+    original: &[&str],
+    replacement: &[&str],
+) -> SuggestedChange {
+    SuggestedChange {
+        file_path: FILE.to_owned(),
+        start_line,
+        end_line,
+        original: Some(original.iter().map(|line| (*line).to_owned()).collect()),
+        replacement: replacement.iter().map(|line| (*line).to_owned()).collect(),
+    }
+}
+
+#[rstest]
+#[case::single_line(
+    change(3, 3, &["    let delay = 5;"], &["    let delay = 1;"]),
+    "fn config() {\n    let retries = 3;\n    let delay = 1;\n}\n"
+)]
+#[case::multi_line_grows(
+    change(2, 3, &["    let retries = 3;", "    let delay = 5;"], &[
+        "    let retries = 4;",
+        "    let delay = 5;",
+        "    let jitter = 2;",
+    ]),
+    "fn config() {\n    let retries = 4;\n    let delay = 5;\n    let jitter = 2;\n}\n"
+)]
+#[case::deletion(
+    change(2, 2, &["    let retries = 3;"], &[]),
+    "fn config() {\n    let delay = 5;\n}\n"
+)]
+fn applies_suggestion_to_working_tree(
+    checkout: Checkout,
+    #[case] suggestion: SuggestedChange,
+    #[case] expected: &str,
+) -> Result<(), TestError> {
+    checkout.applier.apply_suggestion(&suggestion)?;
+
+    assert_eq!(checkout.read()?, expected);
+    Ok(())
+}
+
+#[rstest]
+fn applies_on_top_of_uncommitted_edits(checkout: Checkout) -> Result<(), TestError> {
+    checkout.write("// edited\nfn config() {\n    let retries = 3;\n}\n")?;
+    let suggestion = change(3, 3, &["    let retries = 3;"], &["    let retries = 9;"]);
+
+    checkout.applier.apply_suggestion(&suggestion)?;
+
+    assert_eq!(
+        checkout.read()?,
+        "// edited\nfn config() {\n    let retries = 9;\n}\n"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn keeps_crlf_and_missing_final_newline(checkout: Checkout) -> Result<(), TestError> {
+    checkout.write("fn config() {\r\n    let delay = 5;\r\n}")?;
+    let suggestion = change(3, 3, &["}"], &["    connect();", "}"]);
+
+    checkout.applier.apply_suggestion(&suggestion)?;
+
+    assert_eq!(
+        checkout.read()?,
+        "fn config() {\r\n    let delay = 5;\r\n    connect();\r\n}"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn reports_conflict_when_lines_changed(checkout: Checkout) -> Result<(), TestError> {
+    checkout.write("fn config() {\n    let retries = 7;\n    let delay = 5;\n}\n")?;
+    let suggestion = change(2, 2, &["    let retries = 3;"], &["    let retries = 4;"]);
+
+    let error = checkout
+        .applier
+        .apply_suggestion(&suggestion)
+        .expect_err("changed lines should conflict");
+
+    assert_eq!(
+        error,
+        SuggestionError::Conflict(SuggestionConflict {
+            location: "src/config.rs:2".to_owned(),
+            expected: vec!["    let retries = 3;".to_owned()],
+            found: vec!["    let retries = 7;".to_owned()],
+        })
+    );
+    assert_eq!(
+        error.to_string(),
+        "src/config.rs:2 no longer matches the lines the suggestion replaces.\n\
+         Expected:\n      let retries = 3;\nFound:\n      let retries = 7;"
+    );
+    assert_eq!(
+        checkout.read()?,
+        "fn config() {\n    let retries = 7;\n    let delay = 5;\n}\n"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn reports_conflict_past_end_of_file(checkout: Checkout) {
+    let suggestion = change(4, 5, &["}", "// trailer"], &["}"]);
+
+    let error = checkout
+        .applier
+        .apply_suggestion(&suggestion)
+        .expect_err("short file should conflict");
+
+    assert!(
+        error.to_string().ends_with("Found:\n  }\n  (end of file)"),
+        "unexpected report: {error}"
+    );
+}
+
+#[rstest]
+fn refuses_suggestion_without_original_lines(checkout: Checkout) {
+    let suggestion = SuggestedChange {
+        original: None,
+        ..change(2, 2, &[], &["    let retries = 4;"])
+    };
+
+    let error = checkout.applier.apply_suggestion(&suggestion);
+
+    assert!(matches!(
+        error,
+        Err(SuggestionError::OriginalUnknown { location }) if location == "src/config.rs:2"
+    ));
+}
+
+#[rstest]
+#[case::missing("src/missing.rs")]
+#[case::escaping("../outside.rs")]
+fn reports_unreadable_files(checkout: Checkout, #[case] path: &str) {
+    let suggestion = SuggestedChange {
+        file_path: path.to_owned(),
+        ..change(1, 1, &["fn main() {}"], &["fn main() { run(); }"])
+    };
+
+    let error = checkout.applier.apply_suggestion(&suggestion);
+
+    assert!(matches!(error, Err(SuggestionError::ReadFailed { .. })));
+}
@@ -0,0 +1,83 @@
+//! Error types for applying suggested changes.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Report of target lines that no longer match a suggestion's original
+/// lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestionConflict {
+    /// `path:line` or `path:start-end` label of the target lines.
+    pub location: String,
+    /// Lines the reviewer saw when writing the suggestion.
+    pub expected: Vec<String>,
+    /// Lines currently in the working tree at the same position.
+    ///
+    /// Shorter than `expected` when the file now ends before the range
+    /// does.
+    pub found: Vec<String>,
+}
+
+impl fmt::Display for SuggestionConflict {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            formatter,
+            "{} no longer matches the lines the suggestion replaces.",
+            self.location
+        )?;
+        writeln!(formatter, "Expected:")?;
+        for line in &self.expected {
+            writeln!(formatter, "  {line}")?;
+        }
+        write!(formatter, "Found:")?;
+        for line in &self.found {
+            write!(formatter, "\n  {line}")?;
+        }
+        if self.found.len() < self.expected.len() {
+            write!(formatter, "\n  (end of file)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors that may occur while applying a suggested change.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum SuggestionError {
+    /// The comment's diff hunk does not show the lines being replaced, so
+    /// they cannot be checked before applying.
+    #[error("the lines at {location} are not in the comment's diff hunk")]
+    OriginalUnknown {
+        /// `path:line` or `path:start-end` label of the target lines.
+        location: String,
+    },
+
+    /// The working tree no longer matches the suggestion's original lines.
+    #[error("{0}")]
+    Conflict(SuggestionConflict),
+
+    /// The repository or its working tree is not available.
+    #[error("repository not available: {message}")]
+    RepositoryNotAvailable {
+        /// Error details.
+        message: String,
+    },
+
+    /// The target file could not be read from the working tree.
+    #[error("failed to read '{path}': {message}")]
+    ReadFailed {
+        /// Repository-relative path of the file.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// Git rejected the patch built from the suggestion.
+    #[error("failed to apply suggestion to {location}: {message}")]
+    ApplyFailed {
+        /// `path:line` or `path:start-end` label of the target lines.
+        location: String,
+        /// Error detail from the git2 library.
+        message: String,
+    },
+}
@@ -0,0 +1,32 @@
+//! Suggested changes left in review comments.
+//!
+//! Reviewers propose edits by fencing replacement text in
+//! ```` ```suggestion ```` blocks. This module parses those blocks into
+//! [`SuggestedChange`] values tied to the comment's line range, and applies
+//! them to the local working tree through git2 once the target lines are
+//! confirmed to still match what the reviewer saw.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use frankie::ReviewComment;
+//! use frankie::suggestion::{Git2SuggestionApplier, SuggestionApplier, parse_suggestions};
+//!
+//! # fn example(comment: &ReviewComment) -> Result<(), frankie::suggestion::SuggestionError> {
+//! let applier = Git2SuggestionApplier::open(Path::new("."))?;
+//! for suggestion in parse_suggestions(comment) {
+//!     applier.apply_suggestion(&suggestion)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod apply;
+mod error;
+mod model;
+
+pub use apply::{Git2SuggestionApplier, SuggestionApplier};
+pub use error::{SuggestionConflict, SuggestionError};
+pub use model::{SuggestedChange, parse_suggestions};
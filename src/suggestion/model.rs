@@ -0,0 +1,318 @@
+//! Suggested-change blocks parsed from review comment bodies.
+//!
+//! A reviewer proposes a change by fencing replacement text in a
+//! ```` ```suggestion ```` block. The replacement stands in for every line
+//! the comment covers, from its start line (or its only line) to its end
+//! line on the new side of the diff.
+
+use crate::github::models::ReviewComment;
+use crate::github::review_submission::DiffSide;
+
+/// Info string that marks a fenced code block as a suggested change.
+const SUGGESTION_INFO: &str = "suggestion";
+
+/// A suggested change proposed by a review comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedChange {
+    /// Repository-relative path of the file the suggestion targets.
+    pub file_path: String,
+    /// First line replaced by the suggestion (1-based, inclusive).
+    pub start_line: u32,
+    /// Last line replaced by the suggestion (1-based, inclusive).
+    pub end_line: u32,
+    /// Lines the reviewer saw, read from the comment's diff hunk.
+    ///
+    /// `None` when the hunk does not cover every replaced line.
+    pub original: Option<Vec<String>>,
+    /// Replacement lines; empty when the suggestion deletes the lines.
+    pub replacement: Vec<String>,
+}
+
+impl SuggestedChange {
+    /// Returns a `path:line` or `path:start-end` label for the replaced
+    /// lines.
+    #[must_use]
+    pub fn location(&self) -> String {
+        if self.start_line == self.end_line {
+            format!("{}:{}", self.file_path, self.end_line)
+        } else {
+            format!("{}:{}-{}", self.file_path, self.start_line, self.end_line)
+        }
+    }
+
+    /// Returns the number of lines the suggestion replaces.
+    #[must_use]
+    pub const fn line_count(&self) -> u32 {
+        self.end_line - self.start_line + 1
+    }
+}
+
+/// Parses every suggested-change block in `comment`.
+///
+/// Comments without a body, a file path, or a current line (such as
+/// outdated comments) yield no suggestions, because there is nothing in the
+/// working tree they can be applied to. Neither do comments on the left side
+/// of the diff, whose lines no longer exist in the head version.
+///
+/// # Examples
+///
+/// ```
+/// use frankie::ReviewComment;
+/// use frankie::suggestion::parse_suggestions;
+///
+/// let comment = ReviewComment {
+///     body: Some("Prefer a constant:\n```suggestion\nconst LIMIT: usize = 10;\n```".to_owned()),
+///     file_path: Some("src/lib.rs".to_owned()),
+///     line_number: Some(3),
+///     diff_hunk: Some("@@ -1,2 +1,3 @@\n fn main() {}\n \n+let limit = 10;".to_owned()),
+///     ..ReviewComment::default()
+/// };
+///
+/// let suggestions = parse_suggestions(&comment);
+/// assert_eq!(suggestions.len(), 1);
+/// assert_eq!(suggestions[0].replacement, vec!["const LIMIT: usize = 10;"]);
+/// assert_eq!(suggestions[0].original, Some(vec!["let limit = 10;".to_owned()]));
+/// ```
+#[must_use]
+pub fn parse_suggestions(comment: &ReviewComment) -> Vec<SuggestedChange> {
+    let (Some(body), Some(file_path), Some(end_line)) = (
+        comment.body.as_deref(),
+        comment.file_path.as_deref(),
+        comment.line_number,
+    ) else {
+        return Vec::new();
+    };
+    if comment.side == Some(DiffSide::Left) {
+        return Vec::new();
+    }
+    let start_line = comment
+        .start_line_number
+        .filter(|start| *start <= end_line)
+        .unwrap_or(end_line);
+    let original = comment
+        .diff_hunk
+        .as_deref()
+        .and_then(|hunk| new_side_lines(hunk, start_line, end_line));
+
+    suggestion_blocks(body)
+        .into_iter()
+        .map(|replacement| SuggestedChange {
+            file_path: file_path.to_owned(),
+            start_line,
+            end_line,
+            original: original.clone(),
+            replacement,
+        })
+        .collect()
+}
+
+/// Extracts the contents of every suggestion fence in `body`.
+///
+/// Like GitHub's Markdown renderer, an unclosed fence runs to the end of
+/// the body.
+fn suggestion_blocks(body: &str) -> Vec<Vec<String>> {
+    let mut blocks = Vec::new();
+    let mut lines = body.lines();
+    while let Some(line) = lines.next() {
+        let Some(fence_len) = suggestion_fence_len(line) else {
+            continue;
+        };
+        let block = lines
+            .by_ref()
+            .take_while(|block_line| !closes_fence(block_line, fence_len))
+            .map(str::to_owned)
+            .collect();
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Returns the backtick count of a line opening a suggestion fence.
+fn suggestion_fence_len(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start();
+    let fence_len = trimmed.chars().take_while(|ch| *ch == '`').count();
+    if fence_len < 3 {
+        return None;
+    }
+    let info = trimmed.get(fence_len..)?;
+    (info.split_whitespace().next() == Some(SUGGESTION_INFO)).then_some(fence_len)
+}
+
+/// Whether `line` closes a fence opened with `fence_len` backticks.
+fn closes_fence(line: &str, fence_len: usize) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= fence_len && trimmed.chars().all(|ch| ch == '`')
+}
+
+/// Reads lines `start..=end` of the new side of a `@@ -a,b +c,d @@` hunk.
+///
+/// Returns `None` when the header cannot be parsed or the hunk does not
+/// cover the whole range.
+fn new_side_lines(hunk: &str, start: u32, end: u32) -> Option<Vec<String>> {
+    let mut lines = hunk.lines();
+    let mut new_line = parse_new_start(lines.next()?)?;
+    let mut found = Vec::new();
+    for line in lines {
+        if line.starts_with('-') || line.starts_with('\\') {
+            continue;
+        }
+        if (start..=end).contains(&new_line) {
+            found.push(line.get(1..).unwrap_or_default().to_owned());
+        }
+        new_line = new_line.saturating_add(1);
+    }
+    let expected = usize::try_from(end - start + 1).ok()?;
+    (found.len() == expected).then_some(found)
+}
+
+/// Parses the new-side start line from a hunk header.
+fn parse_new_start(header: &str) -> Option<u32> {
+    let (ranges, _) = header.strip_prefix("@@ ")?.split_once(" @@")?;
+    let (_, new_side) = ranges.split_once(' ')?;
+    let new_range = new_side.strip_prefix('+')?;
+    new_range
+        .split_once(',')
+        .map_or(new_range, |(start, _)| start)
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for suggestion parsing.
+
+    use rstest::{fixture, rstest};
+
+    use super::{SuggestedChange, parse_suggestions};
+    use crate::github::models::ReviewComment;
+    use crate::github::review_submission::DiffSide;
+
+    const HUNK: &str = "@@ -10,4 +10,5 @@ fn config() {\n \
+                        let retries = 3;\n\
+                        -let delay = 1;\n\
+                        +let delay = 5;\n\
+                        +let jitter = 2;\n \
+                        connect(retries, delay);\n \
+                        }";
+
+    #[fixture]
+    fn base_comment() -> ReviewComment {
+        ReviewComment {
+            id: 1,
+            file_path: Some("src/config.rs".to_owned()),
+            line_number: Some(12),
+            diff_hunk: Some(HUNK.to_owned()),
+            ..ReviewComment::default()
+        }
+    }
+
+    fn with_body(mut comment: ReviewComment, body: &str) -> ReviewComment {
+        comment.body = Some(body.to_owned());
+        comment
+    }
+
+    #[rstest]
+    fn parses_single_line_suggestion(base_comment: ReviewComment) {
+        let comment = with_body(
+            base_comment,
+            "Drop the jitter:\n```suggestion\nlet jitter = 0;\n```\nThanks!",
+        );
+
+        let suggestions = parse_suggestions(&comment);
+
+        assert_eq!(
+            suggestions,
+            vec![SuggestedChange {
+                file_path: "src/config.rs".to_owned(),
+                start_line: 12,
+                end_line: 12,
+                original: Some(vec!["let jitter = 2;".to_owned()]),
+                replacement: vec!["let jitter = 0;".to_owned()],
+            }]
+        );
+    }
+
+    #[rstest]
+    fn multi_line_suggestion_covers_comment_range(mut base_comment: ReviewComment) {
+        base_comment.start_line_number = Some(10);
+        let comment = with_body(base_comment, "```suggestion\nlet retries = 3;\n```");
+
+        let suggestions = parse_suggestions(&comment);
+
+        let suggestion = suggestions.first().expect("suggestion should parse");
+        assert_eq!(suggestion.location(), "src/config.rs:10-12");
+        assert_eq!(suggestion.line_count(), 3);
+        assert_eq!(
+            suggestion.original,
+            Some(vec![
+                "let retries = 3;".to_owned(),
+                "let delay = 5;".to_owned(),
+                "let jitter = 2;".to_owned(),
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::empty_block("```suggestion\n```", vec![])]
+    #[case::unclosed("```suggestion\nlet jitter = 1;", vec!["let jitter = 1;"])]
+    #[case::longer_fence(
+        "````suggestion\n```rust\n````",
+        vec!["```rust"]
+    )]
+    #[case::crlf("```suggestion\r\nlet jitter = 1;\r\n```\r\n", vec!["let jitter = 1;"])]
+    fn parses_block_contents(
+        base_comment: ReviewComment,
+        #[case] body: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let comment = with_body(base_comment, body);
+
+        let suggestions = parse_suggestions(&comment);
+
+        let replacement = suggestions
+            .first()
+            .map(|suggestion| suggestion.replacement.clone());
+        assert_eq!(
+            replacement,
+            Some(expected.into_iter().map(str::to_owned).collect())
+        );
+    }
+
+    #[rstest]
+    #[case::plain_code("```rust\nlet jitter = 1;\n```")]
+    #[case::no_fence("Consider a constant here.")]
+    fn ignores_other_blocks(base_comment: ReviewComment, #[case] body: &str) {
+        let comment = with_body(base_comment, body);
+
+        assert!(parse_suggestions(&comment).is_empty());
+    }
+
+    #[rstest]
+    fn outdated_comment_has_no_suggestions(mut base_comment: ReviewComment) {
+        base_comment.line_number = None;
+        let comment = with_body(base_comment, "```suggestion\nlet jitter = 1;\n```");
+
+        assert!(parse_suggestions(&comment).is_empty());
+    }
+
+    #[rstest]
+    fn left_side_comment_has_no_suggestions(mut base_comment: ReviewComment) {
+        base_comment.side = Some(DiffSide::Left);
+        let comment = with_body(base_comment, "```suggestion\nlet delay = 2;\n```");
+
+        assert!(parse_suggestions(&comment).is_empty());
+    }
+
+    #[rstest]
+    fn original_is_unknown_outside_hunk(mut base_comment: ReviewComment) {
+        base_comment.start_line_number = Some(2);
+        let comment = with_body(base_comment, "```suggestion\nlet jitter = 1;\n```");
+
+        let suggestions = parse_suggestions(&comment);
+
+        assert_eq!(
+            suggestions.first().map(|suggestion| &suggestion.original),
+            Some(&None)
+        );
+    }
+}
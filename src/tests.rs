@@ -33,6 +33,41 @@ fn args(values: &[&str]) -> Vec<OsString> {
     Some("42"),
     &["frankie", "-t", "abc"],
 )]
+#[case::short_resolve_threads_flag(
+    &["frankie", "-S", "1", "https://github.com/o/r/pull/1"],
+    Some("https://github.com/o/r/pull/1"),
+    &["frankie", "-S", "1"],
+)]
+#[case::short_unresolve_threads_flag(
+    &["frankie", "-N", "1", "7"],
+    Some("7"),
+    &["frankie", "-N", "1"],
+)]
+#[case::short_reply_flags(
+    &["frankie", "-L", "5", "-y", "hi", "42"],
+    Some("42"),
+    &["frankie", "-L", "5", "-y", "hi"],
+)]
+#[case::short_host_tokens_flag(
+    &["frankie", "-H", "ghe.example.com=tok", "42"],
+    Some("42"),
+    &["frankie", "-H", "ghe.example.com=tok"],
+)]
+#[case::short_outbox_flags(
+    &["frankie", "-B", "1", "-x", "2", "42"],
+    Some("42"),
+    &["frankie", "-B", "1", "-x", "2"],
+)]
+#[case::short_app_auth_flags(
+    &["frankie", "-D", "12", "-K", "key.pem", "42"],
+    Some("42"),
+    &["frankie", "-D", "12", "-K", "key.pem"],
+)]
+#[case::short_credential_flags(
+    &["frankie", "-f", "creds.enc", "-g", "github.com", "-G", "ghe.example.com", "42"],
+    Some("42"),
+    &["frankie", "-f", "creds.enc", "-g", "github.com", "-G", "ghe.example.com"],
+)]
 #[case::equals_syntax_does_not_skip_value(
     &["frankie", "--token=abc", "99"],
     Some("99"),
@@ -7,12 +7,18 @@ use std::sync::Arc;
 
 use crate::ai::{CodexExecutionService, CommentRewriteService, PrDiscussionSummaryService};
 use crate::config::DEFAULT_COMMIT_HISTORY_LIMIT;
+use crate::github::{
+    CiStatus, PullRequestComment, PullRequestLocator, PullRequestMetadata, PullRequestReview,
+};
 use crate::local::GitOperations;
-use crate::persistence::ReviewCommentVerificationCache;
-use crate::tui::ReplyDraftConfig;
+use crate::persistence::{PendingReviewCommentStore, ReviewCommentVerificationCache, WriteOutbox};
+use crate::suggestion::SuggestionApplier;
+use crate::tui::{ReplyDraftConfig, ReviewCommentStream};
 use crate::verification::ResolutionVerificationService;
 
-use super::ReviewApp;
+use super::diff_comment_handlers::PendingCommentDrafts;
+use super::outbox_handlers::{OutboxTarget, QueuedWrites};
+use super::{DataSource, ReviewApp};
 
 impl ReviewApp {
     /// Sets the git operations for time-travel navigation.
@@ -26,6 +32,22 @@ impl ReviewApp {
         self
     }
 
+    /// Sets the applier used to apply suggested changes to the local
+    /// checkout.
+    #[must_use]
+    pub fn with_suggestion_applier(mut self, applier: Arc<dyn SuggestionApplier>) -> Self {
+        self.suggestion_applier = Some(applier);
+        self
+    }
+
+    /// Sets the authenticated user's login, enabling editing and deleting
+    /// the comments they wrote.
+    #[must_use]
+    pub fn with_authenticated_login(mut self, login: impl Into<String>) -> Self {
+        self.authenticated_login = Some(login.into());
+        self
+    }
+
     /// Sets the maximum number of commits to load in time-travel history.
     #[must_use]
     pub const fn with_commit_history_limit(mut self, limit: usize) -> Self {
@@ -33,6 +55,13 @@ impl ReviewApp {
         self
     }
 
+    /// Marks the app as offline, disabling background sync and refresh.
+    #[must_use]
+    pub const fn with_offline_mode(mut self) -> Self {
+        self.data_source = DataSource::Offline;
+        self
+    }
+
     /// Sets the Codex execution service used by this app instance.
     #[must_use]
     pub fn with_codex_service(mut self, codex_service: Arc<dyn CodexExecutionService>) -> Self {
@@ -84,6 +113,72 @@ impl ReviewApp {
         self
     }
 
+    /// Sets the store used to persist pending comment drafts for `locator`.
+    #[must_use]
+    pub fn with_pending_review_comment_store(
+        mut self,
+        store: Arc<PendingReviewCommentStore>,
+        locator: PullRequestLocator,
+    ) -> Self {
+        self.pending_comment_drafts = Some(PendingCommentDrafts { store, locator });
+        self
+    }
+
+    /// Sets the outbox used to queue writes for `locator` that cannot reach
+    /// GitHub.
+    #[must_use]
+    pub fn with_write_outbox(
+        mut self,
+        outbox: Arc<WriteOutbox>,
+        locator: PullRequestLocator,
+    ) -> Self {
+        self.outbox = Some(OutboxTarget { outbox, locator });
+        self.queued = QueuedWrites::default();
+        self
+    }
+
+    /// Sets the top-level pull request reviews shown in the reviews view.
+    #[must_use]
+    pub fn with_pull_request_reviews(mut self, reviews: Vec<PullRequestReview>) -> Self {
+        self.pull_request_reviews = reviews;
+        self.selected_pull_request_review = 0;
+        self
+    }
+
+    /// Sets the conversation comments shown in the timeline view.
+    #[must_use]
+    pub fn with_issue_comments(mut self, comments: Vec<PullRequestComment>) -> Self {
+        self.issue_comments = comments;
+        self.selected_timeline_entry = 0;
+        self
+    }
+
+    /// Sets the pull request details shown in the header.
+    #[must_use]
+    pub fn with_pull_request_metadata(mut self, metadata: Option<PullRequestMetadata>) -> Self {
+        self.pull_request = metadata;
+        self
+    }
+
+    /// Sets the CI results shown in the header badge and CI checks view.
+    #[must_use]
+    pub fn with_ci_status(mut self, ci_status: Option<CiStatus>) -> Self {
+        self.ci_status = ci_status;
+        self.selected_ci_check = 0;
+        self
+    }
+
+    /// Sets the review comment listing whose remaining pages are merged as
+    /// they arrive.
+    ///
+    /// The app shows a loading state until the listing finishes.
+    #[must_use]
+    pub fn with_review_stream(mut self, stream: ReviewCommentStream) -> Self {
+        self.review_stream = Some(stream);
+        self.loading = true;
+        self
+    }
+
     /// Sets the PR-discussion summary service for this app instance.
     #[must_use]
     pub fn with_pr_discussion_summary_service(
@@ -0,0 +1,59 @@
+//! Handlers for the full-screen CI checks view.
+//!
+//! The view drills into the header CI badge, listing the failing checks on
+//! the pull request head. List navigation moves the selection between them.
+
+use bubbletea_rs::Cmd;
+
+use crate::github::CiStatus;
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+impl ReviewApp {
+    /// Handles opening and closing the CI checks view.
+    pub(super) fn handle_ci_checks_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowCiChecks => {
+                self.view_mode = ViewMode::CiChecks;
+                self.error = None;
+            }
+            AppMsg::HideCiChecks => {
+                self.view_mode = ViewMode::ReviewList;
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Replaces the CI status, keeping the failing-check selection within the
+    /// new failing checks.
+    pub(super) fn apply_ci_status(&mut self, ci_status: &CiStatus) {
+        let last_index = ci_status.failing_checks().len().saturating_sub(1);
+        self.selected_ci_check = self.selected_ci_check.min(last_index);
+        self.ci_status = Some(ci_status.clone());
+    }
+
+    /// Moves the failing-check selection for list navigation messages.
+    pub(super) fn handle_ci_checks_navigation(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        let failing_count = self
+            .ci_status
+            .as_ref()
+            .map_or(0, |ci| ci.failing_checks().len());
+        let last_index = failing_count.saturating_sub(1);
+        let selected = self.selected_ci_check;
+        self.selected_ci_check = match msg {
+            AppMsg::CursorUp => selected.saturating_sub(1),
+            AppMsg::CursorDown => selected.saturating_add(1).min(last_index),
+            AppMsg::Home => 0,
+            AppMsg::End => last_index,
+            _ => selected,
+        };
+        None
+    }
+}
+
+#[cfg(test)]
+#[path = "ci_checks_handlers_tests.rs"]
+mod tests;
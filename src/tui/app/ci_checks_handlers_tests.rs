@@ -0,0 +1,96 @@
+//! Tests for the CI checks view handlers.
+
+use bubbletea_rs::Model;
+use rstest::{fixture, rstest};
+
+use crate::github::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+fn check(name: &str, outcome: CiOutcome) -> CiCheck {
+    CiCheck {
+        name: name.to_owned(),
+        kind: CiCheckKind::CheckRun,
+        outcome,
+        state: None,
+        summary: Some(format!("{name} output")),
+        details_url: None,
+    }
+}
+
+#[fixture]
+fn app() -> ReviewApp {
+    ReviewApp::with_dimensions(Vec::new(), 80, 24).with_ci_status(Some(CiStatus {
+        head_sha: "abc1234def".to_owned(),
+        checks: vec![
+            check("build", CiOutcome::Failing),
+            check("lint", CiOutcome::Passing),
+            check("e2e", CiOutcome::Failing),
+        ],
+    }))
+}
+
+#[rstest]
+fn header_shows_ci_badge(app: ReviewApp) {
+    let output = app.view();
+
+    assert!(
+        output.starts_with("Frankie - Review Comments  [CI: 2/3 failing]"),
+        "{output}"
+    );
+}
+
+#[rstest]
+fn checks_view_lists_failing_checks(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowCiChecks);
+
+    let output = app.view();
+
+    assert_eq!(app.view_mode, ViewMode::CiChecks);
+    assert!(output.contains("Failing checks (2):"), "{output}");
+    assert!(output.contains("> build  failing"), "{output}");
+    assert!(output.contains("e2e output"), "{output}");
+}
+
+#[rstest]
+fn navigation_moves_selection_within_bounds(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowCiChecks);
+
+    app.handle_message(&AppMsg::CursorDown);
+    app.handle_message(&AppMsg::CursorDown);
+    assert_eq!(app.selected_ci_check, 1);
+    assert!(app.view().contains("> e2e"));
+
+    app.handle_message(&AppMsg::Home);
+    assert_eq!(app.selected_ci_check, 0);
+}
+
+#[rstest]
+fn escape_returns_to_review_list(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowCiChecks);
+
+    app.handle_message(&AppMsg::EscapePressed);
+
+    assert_eq!(app.view_mode, ViewMode::ReviewList);
+}
+
+#[rstest]
+fn refreshed_status_replaces_badge_and_clamps_selection(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowCiChecks);
+    app.handle_message(&AppMsg::End);
+    assert_eq!(app.selected_ci_check, 1);
+
+    app.handle_message(&AppMsg::CiStatusRefreshed(CiStatus {
+        head_sha: "fedcba9876".to_owned(),
+        checks: vec![
+            check("build", CiOutcome::Passing),
+            check("e2e", CiOutcome::Failing),
+        ],
+    }));
+
+    assert_eq!(app.selected_ci_check, 0);
+    let output = app.view();
+    assert!(output.contains("> e2e"), "{output}");
+    assert!(!output.contains("build"), "{output}");
+}
@@ -0,0 +1,253 @@
+//! Handlers for editing and deleting the authenticated user's own comments.
+//!
+//! Editing reuses the inline reply draft, pre-filled with the comment's
+//! body; sending the draft replaces the body on GitHub. Deleting asks for
+//! confirmation first. Both outcomes are merged into the review list so it
+//! updates without waiting for the next refresh.
+
+use std::any::Any;
+
+use bubbletea_rs::Cmd;
+
+use crate::github::models::ReviewComment;
+use crate::tui::messages::AppMsg;
+use crate::tui::state::ReplyDraftState;
+
+use super::ReviewApp;
+
+impl ReviewApp {
+    /// Handles comment edit and delete messages.
+    pub(super) fn handle_comment_edit_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::StartCommentEdit => {
+                self.start_comment_edit();
+                None
+            }
+            AppMsg::CommentEdited { comment } => {
+                self.handle_comment_edited(comment);
+                None
+            }
+            AppMsg::CommentEditFailed {
+                comment_id,
+                message,
+            } => {
+                if self.in_flight_reply_send == Some(*comment_id) {
+                    self.in_flight_reply_send = None;
+                }
+                self.error = Some(format!(
+                    "Failed to edit comment: {message}. Draft kept; press Enter to retry."
+                ));
+                None
+            }
+            _ => self.handle_comment_delete_msg(msg),
+        }
+    }
+
+    fn handle_comment_delete_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::RequestCommentDelete => {
+                self.request_comment_delete();
+                None
+            }
+            AppMsg::ConfirmCommentDelete => self.confirm_comment_delete(),
+            AppMsg::CancelCommentDelete => {
+                self.comment_delete_target = None;
+                None
+            }
+            AppMsg::CommentDeleted { comment_id } => {
+                self.handle_comment_deleted(*comment_id);
+                None
+            }
+            AppMsg::CommentDeleteFailed {
+                comment_id,
+                message,
+            } => {
+                if self.in_flight_comment_delete == Some(*comment_id) {
+                    self.in_flight_comment_delete = None;
+                }
+                self.error = Some(format!("Failed to delete comment: {message}"));
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the authenticated user wrote `comment`.
+    ///
+    /// GitHub logins are case-insensitive. Always `false` when the login is
+    /// unknown, such as offline or when authenticated as a GitHub App.
+    pub(super) fn is_own_comment(&self, comment: &ReviewComment) -> bool {
+        self.authenticated_login
+            .as_deref()
+            .zip(comment.author.as_deref())
+            .is_some_and(|(login, author)| login.eq_ignore_ascii_case(author))
+    }
+
+    /// Returns the selected comment when the authenticated user may change
+    /// it, recording why not otherwise.
+    fn selected_own_comment(&mut self, action: &str) -> Option<ReviewComment> {
+        if self.is_offline() {
+            self.error = Some(format!("{action} comments is unavailable offline."));
+            return None;
+        }
+        let Some(comment) = self.selected_comment() else {
+            self.error = Some(format!("{action} requires a selected comment"));
+            return None;
+        };
+        if !self.is_own_comment(comment) {
+            self.error = Some(format!("{action} is only available on your own comments"));
+            return None;
+        }
+        Some(comment.clone())
+    }
+
+    fn start_comment_edit(&mut self) {
+        let Some(comment) = self.selected_own_comment("Editing") else {
+            return;
+        };
+
+        let body = comment.body.as_deref().unwrap_or_default();
+        match ReplyDraftState::for_edit(comment.id, body, self.reply_draft_config.max_length) {
+            Ok(draft) => {
+                self.reply_draft = Some(draft);
+                self.reply_draft_ai_preview = None;
+                self.in_flight_ai_rewrite_request_id = None;
+                self.error = None;
+            }
+            Err(error) => {
+                self.error = Some(format!("Cannot edit comment: {error}"));
+            }
+        }
+    }
+
+    /// Replaces the edited comment in the review list and clears its draft.
+    ///
+    /// The edited comment keeps its local thread state, which the edit
+    /// response does not include.
+    fn handle_comment_edited(&mut self, comment: &ReviewComment) {
+        if self.in_flight_reply_send == Some(comment.id) {
+            self.in_flight_reply_send = None;
+        }
+
+        let mut edited = comment.clone();
+        let merged: Vec<ReviewComment> = self
+            .reviews
+            .iter()
+            .map(|review| {
+                if review.id != edited.id {
+                    return review.clone();
+                }
+                if edited.thread_state.is_none() {
+                    edited.thread_state.clone_from(&review.thread_state);
+                }
+                edited.clone()
+            })
+            .collect();
+        self.replace_reviews_in_place(&merged);
+
+        if self
+            .reply_draft
+            .as_ref()
+            .is_some_and(|draft| draft.comment_id() == comment.id)
+        {
+            self.reply_draft = None;
+            self.reply_draft_ai_preview = None;
+        }
+        self.error = None;
+    }
+
+    fn request_comment_delete(&mut self) {
+        let Some(comment) = self.selected_own_comment("Deleting") else {
+            return;
+        };
+        self.error = None;
+        self.comment_delete_target = Some(comment.id);
+    }
+
+    fn confirm_comment_delete(&mut self) -> Option<Cmd> {
+        let comment_id = self.comment_delete_target.take()?;
+        if self.in_flight_comment_delete.is_some() {
+            self.error =
+                Some("A comment is already being deleted; wait for it to finish.".to_owned());
+            return None;
+        }
+
+        self.in_flight_comment_delete = Some(comment_id);
+        self.error = None;
+        Some(spawn_comment_delete(comment_id))
+    }
+
+    /// Removes the deleted comment from the review list, along with any
+    /// draft or suggestion report attached to it.
+    fn handle_comment_deleted(&mut self, comment_id: u64) {
+        if self.in_flight_comment_delete == Some(comment_id) {
+            self.in_flight_comment_delete = None;
+        }
+
+        let remaining: Vec<ReviewComment> = self
+            .reviews
+            .iter()
+            .filter(|review| review.id != comment_id)
+            .cloned()
+            .collect();
+        self.replace_reviews_in_place(&remaining);
+
+        if self
+            .reply_draft
+            .as_ref()
+            .is_some_and(|draft| draft.comment_id() == comment_id)
+        {
+            self.reply_draft = None;
+            self.reply_draft_ai_preview = None;
+        }
+        if self
+            .suggestion_report
+            .as_ref()
+            .is_some_and(|(report_comment_id, _)| *report_comment_id == comment_id)
+        {
+            self.suggestion_report = None;
+        }
+        self.error = None;
+    }
+
+    /// Applies `reviews` without disturbing an in-progress refresh.
+    pub(super) fn replace_reviews_in_place(&mut self, reviews: &[ReviewComment]) {
+        let was_loading = self.loading;
+        self.apply_new_reviews(reviews);
+        self.loading = was_loading;
+    }
+}
+
+/// Spawns an async task replacing the comment's body on GitHub.
+pub(super) fn spawn_comment_edit(comment_id: u64, body: String) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::edit_review_comment(comment_id, body).await {
+            Ok(comment) => AppMsg::CommentEdited {
+                comment: Box::new(comment),
+            },
+            Err(error) => AppMsg::CommentEditFailed {
+                comment_id,
+                message: error.to_string(),
+            },
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+/// Spawns an async task deleting the comment on GitHub.
+fn spawn_comment_delete(comment_id: u64) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::delete_review_comment(comment_id).await {
+            Ok(()) => AppMsg::CommentDeleted { comment_id },
+            Err(error) => AppMsg::CommentDeleteFailed {
+                comment_id,
+                message: error.to_string(),
+            },
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+#[cfg(test)]
+#[path = "comment_edit_handlers_tests.rs"]
+mod tests;
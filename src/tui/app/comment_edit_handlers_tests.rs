@@ -0,0 +1,209 @@
+//! Tests for editing and deleting the authenticated user's own comments.
+
+use rstest::{fixture, rstest};
+
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::tui::input::InputContext;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+fn thread() -> ReviewThreadState {
+    ReviewThreadState {
+        thread_id: "PRRT_1".to_owned(),
+        is_resolved: false,
+        is_outdated: false,
+        resolved_by: None,
+    }
+}
+
+#[fixture]
+fn reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            id: 1,
+            body: Some("Fixed in teh latest push.".to_owned()),
+            author: Some("Octocat".to_owned()),
+            thread_state: Some(thread()),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 2,
+            body: Some("Looks good".to_owned()),
+            author: Some("alice".to_owned()),
+            ..ReviewComment::default()
+        },
+    ]
+}
+
+#[rstest]
+fn edit_prefills_draft_with_own_comment_body(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+
+    app.handle_message(&AppMsg::StartCommentEdit);
+
+    let draft = app.reply_draft.as_ref().expect("edit draft should open");
+    assert!(draft.edits_comment());
+    assert_eq!(draft.text(), "Fixed in teh latest push.");
+    assert_eq!(app.input_context(), InputContext::ReplyDraft);
+    assert!(app.render_status_bar().starts_with("Editing comment:"));
+}
+
+#[rstest]
+#[case::other_author(Some("alice"), "only available on your own comments")]
+#[case::unknown_login(None, "only available on your own comments")]
+fn edit_and_delete_refuse_other_comments(
+    reviews: Vec<ReviewComment>,
+    #[case] login: Option<&str>,
+    #[case] expected_error: &str,
+) {
+    let mut app = ReviewApp::new(reviews);
+    if let Some(name) = login {
+        app = app.with_authenticated_login(name);
+    }
+
+    app.handle_message(&AppMsg::StartCommentEdit);
+    assert!(app.reply_draft.is_none());
+    let edit_error = app.error_message().unwrap_or_default();
+    assert!(
+        edit_error.contains(expected_error),
+        "unexpected: {edit_error}"
+    );
+
+    app.handle_message(&AppMsg::RequestCommentDelete);
+    assert!(app.comment_delete_target.is_none());
+    let delete_error = app.error_message().unwrap_or_default();
+    assert!(
+        delete_error.contains(expected_error),
+        "unexpected: {delete_error}"
+    );
+}
+
+#[rstest]
+fn edit_is_unavailable_offline(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews)
+        .with_authenticated_login("octocat")
+        .with_offline_mode();
+
+    app.handle_message(&AppMsg::StartCommentEdit);
+
+    assert!(app.reply_draft.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("unavailable offline"), "unexpected: {error}");
+}
+
+#[rstest]
+fn saved_edit_replaces_comment_in_list(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+    app.handle_message(&AppMsg::StartCommentEdit);
+
+    let cmd = app.handle_message(&AppMsg::ReplyDraftRequestSend);
+    assert!(cmd.is_some(), "saving should spawn an edit request");
+    assert_eq!(app.in_flight_reply_send, Some(1));
+
+    app.handle_message(&AppMsg::CommentEdited {
+        comment: Box::new(ReviewComment {
+            id: 1,
+            body: Some("Fixed in the latest push.".to_owned()),
+            author: Some("Octocat".to_owned()),
+            ..ReviewComment::default()
+        }),
+    });
+
+    assert!(app.reply_draft.is_none());
+    assert!(app.in_flight_reply_send.is_none());
+    let edited = app
+        .reviews
+        .iter()
+        .find(|comment| comment.id == 1)
+        .expect("edited comment should remain");
+    assert_eq!(edited.body.as_deref(), Some("Fixed in the latest push."));
+    assert_eq!(edited.thread_state, Some(thread()));
+    assert_eq!(app.reviews.len(), 2);
+}
+
+#[rstest]
+fn failed_edit_keeps_draft(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+    app.handle_message(&AppMsg::StartCommentEdit);
+    app.handle_message(&AppMsg::ReplyDraftRequestSend);
+
+    app.handle_message(&AppMsg::CommentEditFailed {
+        comment_id: 1,
+        message: "Not Found".to_owned(),
+    });
+
+    assert!(app.in_flight_reply_send.is_none());
+    assert!(app.reply_draft.is_some());
+    let error = app.error_message().unwrap_or_default();
+    assert!(
+        error.starts_with("Failed to edit comment: Not Found"),
+        "unexpected: {error}"
+    );
+}
+
+#[rstest]
+fn edit_draft_cannot_be_queued_on_review(mut reviews: Vec<ReviewComment>) {
+    let own = reviews.first_mut().expect("fixture has comments");
+    own.file_path = Some("src/lib.rs".to_owned());
+    own.line_number = Some(3);
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+    app.handle_message(&AppMsg::StartCommentEdit);
+
+    app.handle_message(&AppMsg::QueueReplyDraftForReview);
+
+    assert!(app.reply_draft.is_some());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("cannot be queued"), "unexpected: {error}");
+}
+
+#[rstest]
+fn delete_waits_for_confirmation(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+
+    app.handle_message(&AppMsg::RequestCommentDelete);
+    assert_eq!(app.input_context(), InputContext::CommentDeleteConfirm);
+    assert!(app.render_status_bar().starts_with("Delete this comment"));
+
+    let cmd = app.handle_message(&AppMsg::CancelCommentDelete);
+    assert!(cmd.is_none());
+    assert_eq!(app.input_context(), InputContext::ReviewList);
+    assert!(app.in_flight_comment_delete.is_none());
+}
+
+#[rstest]
+fn confirmed_delete_removes_comment_from_list(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+    app.handle_message(&AppMsg::RequestCommentDelete);
+
+    let cmd = app.handle_message(&AppMsg::ConfirmCommentDelete);
+    assert!(cmd.is_some(), "confirming should spawn a delete request");
+    assert_eq!(app.in_flight_comment_delete, Some(1));
+
+    app.handle_message(&AppMsg::CommentDeleted { comment_id: 1 });
+
+    assert!(app.in_flight_comment_delete.is_none());
+    let remaining: Vec<u64> = app.reviews.iter().map(|comment| comment.id).collect();
+    assert_eq!(remaining, vec![2]);
+    assert_eq!(app.selected_comment().map(|comment| comment.id), Some(2));
+}
+
+#[rstest]
+fn failed_delete_keeps_comment(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_authenticated_login("octocat");
+    app.handle_message(&AppMsg::RequestCommentDelete);
+    app.handle_message(&AppMsg::ConfirmCommentDelete);
+
+    app.handle_message(&AppMsg::CommentDeleteFailed {
+        comment_id: 1,
+        message: "Forbidden".to_owned(),
+    });
+
+    assert!(app.in_flight_comment_delete.is_none());
+    assert_eq!(app.reviews.len(), 2);
+    let error = app.error_message().unwrap_or_default();
+    assert!(
+        error.contains("Failed to delete comment: Forbidden"),
+        "unexpected: {error}"
+    );
+}
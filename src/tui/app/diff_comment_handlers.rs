@@ -0,0 +1,174 @@
+//! New inline comment handlers for the full-screen diff context view.
+//!
+//! The line cursor in the diff context view selects a `(path, line, side)`
+//! anchor. Pressing `a` starts a draft for that anchor; saving it stores the
+//! draft in the local database (when configured) and queues it on the
+//! pending review, which posts it with the hunk's commit SHA.
+
+use std::sync::Arc;
+
+use crate::github::PullRequestLocator;
+use crate::github::review_submission::PendingReviewComment;
+use crate::persistence::PendingReviewCommentStore;
+use crate::tui::messages::AppMsg;
+use crate::tui::state::DiffCommentDraft;
+
+use super::ReviewApp;
+
+/// Local store and pull request used to persist pending comment drafts.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingCommentDrafts {
+    /// Store holding the drafts.
+    pub(crate) store: Arc<PendingReviewCommentStore>,
+    /// Pull request the drafts belong to.
+    pub(crate) locator: PullRequestLocator,
+}
+
+impl ReviewApp {
+    /// Handles line cursor and new-comment draft messages in diff context.
+    pub(super) fn handle_diff_comment_msg(&mut self, msg: &AppMsg) {
+        match msg {
+            AppMsg::DiffLineDown => self.diff_context_state.move_line_down(),
+            AppMsg::DiffLineUp => self.diff_context_state.move_line_up(),
+            AppMsg::StartDiffCommentDraft => self.start_diff_comment_draft(),
+            AppMsg::DiffCommentInsertChar(character) => {
+                if let Some(draft) = self.diff_context_state.comment_draft_mut() {
+                    draft.push_char(*character);
+                }
+            }
+            AppMsg::DiffCommentBackspace => {
+                if let Some(draft) = self.diff_context_state.comment_draft_mut() {
+                    draft.backspace();
+                }
+            }
+            AppMsg::DiffCommentSave => self.save_diff_comment_draft(),
+            AppMsg::DiffCommentCancel => {
+                let _ = self.diff_context_state.take_comment_draft();
+                self.error = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn start_diff_comment_draft(&mut self) {
+        if self.review_composer.is_submitting() {
+            self.error = Some("The review is being submitted; wait for it to finish.".to_owned());
+            return;
+        }
+        let Some((hunk, anchor)) = self.diff_context_state.selected_line() else {
+            self.error = Some("The selected diff line cannot anchor a comment.".to_owned());
+            return;
+        };
+        let (Some(path), Some(commit_sha)) = (hunk.file_path.clone(), hunk.commit_sha.clone())
+        else {
+            self.error = Some(
+                "This hunk has no file path or commit; it cannot anchor a comment.".to_owned(),
+            );
+            return;
+        };
+        if let Err(error) = self.review_composer.check_commit(&commit_sha) {
+            self.error = Some(error.to_string());
+            return;
+        }
+
+        self.diff_context_state
+            .start_comment_draft(DiffCommentDraft::new(path, commit_sha, anchor));
+        self.error = None;
+    }
+
+    /// Persists the active draft and queues it on the pending review.
+    ///
+    /// The draft is kept open when it is empty or cannot be stored so the
+    /// text is not lost.
+    fn save_diff_comment_draft(&mut self) {
+        let Some(draft) = self.diff_context_state.comment_draft() else {
+            return;
+        };
+        if draft.body().trim().is_empty() {
+            self.error = Some("Comment is empty; type text before saving it.".to_owned());
+            return;
+        }
+        let commit_sha = draft.commit_sha().to_owned();
+        let comment = draft.to_pending_comment();
+        if let Err(error) = self.review_composer.check_commit(&commit_sha) {
+            self.error = Some(error.to_string());
+            return;
+        }
+
+        let draft_id = match self.persist_comment_draft(&commit_sha, &comment) {
+            Ok(draft_id) => draft_id,
+            Err(message) => {
+                self.error = Some(message);
+                return;
+            }
+        };
+
+        if let Err(error) =
+            self.review_composer
+                .queue_anchored_comment(&commit_sha, comment, draft_id)
+        {
+            self.error = Some(error.to_string());
+            return;
+        }
+        let _ = self.diff_context_state.take_comment_draft();
+        self.error = None;
+    }
+
+    /// Stores a draft when a local database is configured.
+    fn persist_comment_draft(
+        &self,
+        commit_sha: &str,
+        comment: &PendingReviewComment,
+    ) -> Result<Option<i64>, String> {
+        let Some(drafts) = self.pending_comment_drafts.as_ref() else {
+            return Ok(None);
+        };
+        drafts
+            .store
+            .insert(&drafts.locator, commit_sha, comment)
+            .map(Some)
+            .map_err(|error| format!("Failed to save comment draft: {error}"))
+    }
+
+    /// Queues drafts stored for this pull request on the pending review.
+    ///
+    /// Returns an error message when the drafts cannot be loaded.
+    pub(super) fn load_pending_comment_drafts(&mut self) -> Option<String> {
+        let drafts = self.pending_comment_drafts.clone()?;
+        let stored = match drafts.store.list_for_pull_request(&drafts.locator) {
+            Ok(stored) => stored,
+            Err(error) => return Some(format!("Failed to load comment drafts: {error}")),
+        };
+
+        let mut skipped = 0_usize;
+        for draft in stored {
+            if self
+                .review_composer
+                .queue_anchored_comment(&draft.commit_sha, draft.comment, Some(draft.id))
+                .is_err()
+            {
+                skipped += 1;
+            }
+        }
+
+        (skipped > 0).then(|| {
+            format!("{skipped} stored comment draft(s) target another commit and were not loaded")
+        })
+    }
+
+    /// Deletes stored drafts once they are dropped or submitted.
+    ///
+    /// Returns an error message when the drafts cannot be deleted.
+    pub(super) fn delete_pending_comment_drafts(&self, ids: &[i64]) -> Option<String> {
+        let drafts = self.pending_comment_drafts.as_ref()?;
+        drafts
+            .store
+            .delete(ids)
+            .err()
+            .map(|error| format!("Failed to delete comment drafts: {error}"))
+    }
+}
+
+#[cfg(test)]
+#[path = "diff_comment_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,182 @@
+//! Tests for new inline comments written in the diff context view.
+
+use std::sync::Arc;
+
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use crate::github::PullRequestLocator;
+use crate::github::models::ReviewComment;
+use crate::github::review_submission::{DiffSide, SubmittedReview};
+use crate::persistence::{PendingReviewCommentStore, migrate_database};
+use crate::telemetry::NoopTelemetrySink;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+const HUNK: &str = "@@ -10,3 +10,3 @@\n keep\n-old\n+new\n tail";
+
+fn commented_review(id: u64, commit_sha: &str) -> ReviewComment {
+    ReviewComment {
+        id,
+        body: Some("Existing".to_owned()),
+        file_path: Some("src/lib.rs".to_owned()),
+        line_number: Some(12),
+        diff_hunk: Some(HUNK.to_owned()),
+        commit_sha: Some(commit_sha.to_owned()),
+        ..ReviewComment::default()
+    }
+}
+
+#[fixture]
+fn app() -> ReviewApp {
+    let mut app = ReviewApp::with_dimensions(vec![commented_review(1, "abc123")], 80, 24);
+    app.handle_message(&AppMsg::ShowDiffContext);
+    app
+}
+
+struct StoreFixture {
+    _temp_dir: TempDir,
+    store: Arc<PendingReviewCommentStore>,
+    locator: PullRequestLocator,
+}
+
+#[fixture]
+fn store() -> StoreFixture {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let database_url = temp_dir
+        .path()
+        .join("frankie.sqlite")
+        .to_string_lossy()
+        .to_string();
+    migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+    let store = PendingReviewCommentStore::new(database_url).expect("store should be created");
+    let locator = PullRequestLocator::parse("https://github.com/owner/repo/pull/42")
+        .expect("locator should parse");
+    StoreFixture {
+        _temp_dir: temp_dir,
+        store: Arc::new(store),
+        locator,
+    }
+}
+
+fn write_comment(app: &mut ReviewApp, text: &str) {
+    app.handle_message(&AppMsg::StartDiffCommentDraft);
+    for character in text.chars() {
+        app.handle_message(&AppMsg::DiffCommentInsertChar(character));
+    }
+    app.handle_message(&AppMsg::DiffCommentSave);
+}
+
+#[rstest]
+fn comment_is_anchored_to_selected_line_and_commit(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::DiffLineUp);
+    app.handle_message(&AppMsg::DiffLineUp);
+
+    write_comment(&mut app, "Why drop this?");
+
+    assert!(app.diff_context_state.comment_draft().is_none());
+    let queued = app
+        .review_composer
+        .pending_comments()
+        .first()
+        .expect("comment should be queued");
+    assert_eq!(queued.comment.path, "src/lib.rs");
+    assert_eq!(queued.comment.line, 11);
+    assert_eq!(queued.comment.side, DiffSide::Left);
+    assert_eq!(queued.comment.body, "Why drop this?");
+    assert_eq!(
+        app.review_composer.to_submission().commit_id(),
+        Some("abc123")
+    );
+}
+
+#[rstest]
+fn empty_comment_is_kept_open(mut app: ReviewApp) {
+    write_comment(&mut app, "  ");
+
+    assert!(app.diff_context_state.comment_draft().is_some());
+    assert!(app.review_composer.pending_comments().is_empty());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Comment is empty"), "{error}");
+}
+
+#[rstest]
+fn comment_on_another_commit_is_rejected() {
+    let reviews = vec![
+        commented_review(1, "abc123"),
+        ReviewComment {
+            file_path: Some("src/main.rs".to_owned()),
+            ..commented_review(2, "def456")
+        },
+    ];
+    let mut app = ReviewApp::with_dimensions(reviews, 80, 24);
+    app.handle_message(&AppMsg::ShowDiffContext);
+    write_comment(&mut app, "first");
+    app.handle_message(&AppMsg::NextHunk);
+
+    app.handle_message(&AppMsg::StartDiffCommentDraft);
+
+    assert!(app.diff_context_state.comment_draft().is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("already targets commit abc123"), "{error}");
+}
+
+#[rstest]
+fn leaving_diff_context_discards_unsaved_comment(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::StartDiffCommentDraft);
+    app.handle_message(&AppMsg::DiffCommentInsertChar('x'));
+
+    app.handle_message(&AppMsg::HideDiffContext);
+
+    assert!(app.diff_context_state.comment_draft().is_none());
+    assert!(app.review_composer.pending_comments().is_empty());
+}
+
+#[rstest]
+fn saved_comments_persist_until_review_is_submitted(mut app: ReviewApp, store: StoreFixture) {
+    app = app.with_pending_review_comment_store(Arc::clone(&store.store), store.locator.clone());
+    write_comment(&mut app, "Stored");
+    let stored = store
+        .store
+        .list_for_pull_request(&store.locator)
+        .expect("drafts should load");
+    assert_eq!(stored.len(), 1);
+
+    let mut restarted = ReviewApp::with_dimensions(Vec::new(), 80, 24)
+        .with_pending_review_comment_store(Arc::clone(&store.store), store.locator.clone());
+    assert_eq!(restarted.load_pending_comment_drafts(), None);
+    assert_eq!(restarted.review_composer.pending_comments().len(), 1);
+    assert_eq!(restarted.review_composer.commit_id(), Some("abc123"));
+
+    restarted.handle_message(&AppMsg::ReviewSubmitted {
+        review: SubmittedReview {
+            id: 5,
+            state: Some("COMMENTED".to_owned()),
+            html_url: None,
+        },
+    });
+
+    let remaining = store
+        .store
+        .list_for_pull_request(&store.locator)
+        .expect("drafts should load");
+    assert!(remaining.is_empty(), "submitted drafts should be deleted");
+}
+
+#[rstest]
+fn dropping_queued_comment_deletes_stored_draft(mut app: ReviewApp, store: StoreFixture) {
+    app = app.with_pending_review_comment_store(Arc::clone(&store.store), store.locator.clone());
+    write_comment(&mut app, "Temporary");
+    app.handle_message(&AppMsg::HideDiffContext);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+
+    app.handle_message(&AppMsg::ReviewComposerDropLastComment);
+
+    let remaining = store
+        .store
+        .list_for_pull_request(&store.locator)
+        .expect("drafts should load");
+    assert!(remaining.is_empty());
+    assert!(app.review_composer.pending_comments().is_empty());
+}
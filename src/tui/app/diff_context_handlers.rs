@@ -1,7 +1,8 @@
 //! Diff context view handlers for the review TUI.
 //!
 //! This module manages the full-screen diff context view, including entering
-//! and exiting the view and handling hunk navigation messages.
+//! and exiting the view and handling hunk navigation messages. Line cursor
+//! and new-comment messages are delegated to `diff_comment_handlers`.
 
 use bubbletea_rs::Cmd;
 
@@ -27,8 +28,10 @@ impl ReviewApp {
         self.view_mode = ViewMode::DiffContext;
     }
 
-    /// Exits the full-screen diff context view.
-    pub(super) const fn exit_diff_context(&mut self) {
+    /// Exits the full-screen diff context view, discarding any unsaved
+    /// new-comment draft.
+    pub(super) fn exit_diff_context(&mut self) {
+        let _ = self.diff_context_state.take_comment_draft();
         self.view_mode = ViewMode::ReviewList;
     }
 
@@ -52,7 +55,7 @@ impl ReviewApp {
                 None
             }
             _ => {
-                // Unreachable: caller filters to diff-context messages.
+                self.handle_diff_comment_msg(msg);
                 None
             }
         }
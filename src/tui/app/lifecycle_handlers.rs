@@ -30,14 +30,20 @@ impl ReviewApp {
     ///
     /// `Initialized` is intended as a one-shot event emitted during startup.
     /// Subsequent `Initialized` messages are ignored to avoid re-arming the
-    /// sync timer unintentionally.
+    /// sync timer unintentionally. While review comment pages are still
+    /// loading, polling for them starts alongside the sync timer.
     fn handle_initialized(&mut self) -> Option<Cmd> {
         if self.has_initialized {
             return None;
         }
 
         self.has_initialized = true;
-        Some(Self::arm_sync_timer())
+        let sync_timer = Self::arm_sync_timer();
+        Some(if self.review_stream.is_some() {
+            bubbletea_rs::batch(vec![sync_timer, Self::arm_review_stream_poll_timer()])
+        } else {
+            sync_timer
+        })
     }
 
     fn handle_resize(&mut self, width: u16, height: u16) -> Option<Cmd> {
@@ -6,17 +6,27 @@
 //!
 //! # Module Structure
 //!
+//! - `ci_checks_handlers`: CI checks drill-down view
 //! - `codex_handlers`: Codex execution trigger and stream polling
+//! - `comment_edit_handlers`: Editing and deleting the user's own comments
+//! - `diff_comment_handlers`: New inline comments anchored to diff lines
 //! - `diff_context_handlers`: Full-screen diff context view management
 //! - `filter_handlers`: Review filter application and cycling
 //! - `layout`: Shared layout and scroll calculations
 //! - `lifecycle_handlers`: Startup, quit, help toggle, and resize handling
 //! - `model_impl`: `bubbletea_rs::Model` trait implementation
 //! - `navigation`: Cursor and page navigation handlers
+//! - `outbox_handlers`: Queued review writes and their replay
+//! - `pull_request_reviews_handlers`: Top-level pull request reviews view
+//! - `reaction_handlers`: Reaction picker and review comment reactions
 //! - `rendering`: View rendering methods for terminal output
+//! - `review_stream_handlers`: Merging review comment pages loaded after startup
+//! - `review_submission_handlers`: Pending review composition and submission
 //! - `routing`: Mode-aware message routing and category dispatch
+//! - `suggestion_handlers`: Applying suggested changes to the working tree
 //! - `sync_handlers`: Background sync and refresh handling
 //! - `time_travel_handlers`: Time-travel navigation handlers
+//! - `timeline_handlers`: Unified conversation timeline view
 //! - `verification_state`: Verification service/cache/result state
 
 use std::sync::Arc;
@@ -27,39 +37,71 @@ use crate::ai::{
     CodexExecutionHandle, CodexExecutionService, CommentRewriteMode, CommentRewriteService,
     PrDiscussionSummaryService, SessionState, SideBySideDiffPreview, SystemCodexExecutionService,
 };
-use crate::github::models::ReviewComment;
+use crate::github::models::{
+    PullRequestComment, PullRequestMetadata, PullRequestReview, ReviewComment,
+};
+use crate::github::{CiStatus, PendingBackoff};
 use crate::local::GitOperations;
+use crate::suggestion::SuggestionApplier;
 use crate::time_travel::TimeTravelState;
-use crate::tui::ReplyDraftConfig;
+use crate::tui::{ReplyDraftConfig, ReviewCommentStream};
 
 use super::components::{CommentDetailComponent, DiffContextComponent, ReviewListComponent};
 use super::messages::AppMsg;
-use super::state::{DiffContextState, FilterState, ReplyDraftState, ReviewFilter};
+use super::state::{
+    DiffContextState, FilterState, ReplyDraftState, ReviewComposerState, ReviewFilter,
+};
+use diff_comment_handlers::PendingCommentDrafts;
+use outbox_handlers::{OutboxTarget, QueuedWrites};
+use reaction_handlers::ReactionState;
 use verification_state::VerificationState;
 
 mod builder;
+mod ci_checks_handlers;
 mod codex_handlers;
+mod comment_edit_handlers;
+mod diff_comment_handlers;
 mod diff_context_handlers;
 mod filter_handlers;
 mod layout;
 mod lifecycle_handlers;
 mod model_impl;
 mod navigation;
+mod outbox_handlers;
 mod pr_discussion_summary_handlers;
 mod pr_discussion_summary_state;
+mod pull_request_reviews_handlers;
+mod reaction_handlers;
 mod rendering;
+mod reply_draft_ai_handlers;
 mod reply_draft_handlers;
+mod review_stream_handlers;
+mod review_submission_handlers;
 mod routing;
+mod suggestion_handlers;
 mod sync_handlers;
+mod thread_resolution_handlers;
 mod time_travel_handlers;
+mod timeline_handlers;
 mod verification_handlers;
 mod verification_state;
 mod view_mode;
+mod viewport;
 
 pub(crate) use pr_discussion_summary_state::PrDiscussionSummaryViewState;
 use routing::MessageRouting;
 pub(crate) use view_mode::{CHROME_HEIGHT, MIN_DETAIL_HEIGHT, MIN_LIST_HEIGHT, ViewMode};
 
+/// Where the app's reviews come from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum DataSource {
+    /// Reviews are fetched from GitHub and kept current by background sync.
+    #[default]
+    GitHub,
+    /// Reviews were loaded from the local database; GitHub is never called.
+    Offline,
+}
+
 /// Main application model for the review listing TUI.
 #[derive(Debug)]
 pub struct ReviewApp {
@@ -72,6 +114,12 @@ pub struct ReviewApp {
     pub(crate) filter_state: FilterState,
     /// Whether data is currently loading.
     pub(crate) loading: bool,
+    /// Whether reviews come from GitHub or the local database.
+    data_source: DataSource,
+    /// Rate-limit backoff the in-flight sync is waiting out, if any.
+    rate_limit_backoff: Option<PendingBackoff>,
+    /// Incremental syncs started since the last full listing.
+    incremental_syncs: u32,
     /// Current error message, if any.
     pub(crate) error: Option<String>,
     /// Terminal dimensions.
@@ -111,6 +159,8 @@ pub struct ReviewApp {
     codex_status: Option<String>,
     /// Poll interval for draining Codex progress events.
     codex_poll_interval: std::time::Duration,
+    /// Review comment listing still delivering pages after startup.
+    review_stream: Option<ReviewCommentStream>,
     /// Tracks whether startup initialization has been handled.
     has_initialized: bool,
     /// Interrupted session awaiting user confirmation to resume.
@@ -123,6 +173,48 @@ pub struct ReviewApp {
     next_ai_rewrite_request_id: u64,
     /// Most recent in-flight rewrite request ID, if one is pending.
     in_flight_ai_rewrite_request_id: Option<u64>,
+    /// Comment ID of the reply or comment edit currently being sent to
+    /// GitHub, if any.
+    in_flight_reply_send: Option<u64>,
+    /// GraphQL ID of the thread whose resolution is being changed, if any.
+    in_flight_thread_resolution: Option<String>,
+    /// Reaction picker target, in-flight change, and confirmed reactions.
+    reactions: ReactionState,
+    /// Login of the authenticated user, used to offer editing and deleting
+    /// their own comments.
+    authenticated_login: Option<String>,
+    /// Comment ID awaiting deletion confirmation, if any.
+    comment_delete_target: Option<u64>,
+    /// Comment ID currently being deleted on GitHub, if any.
+    in_flight_comment_delete: Option<u64>,
+    /// Applies suggested changes to the local checkout, when one is
+    /// available.
+    suggestion_applier: Option<Arc<dyn SuggestionApplier>>,
+    /// Comment ID and outcome of the last suggestion applied from it.
+    suggestion_report: Option<(u64, String)>,
+    /// Pending review composed from queued inline comments.
+    review_composer: ReviewComposerState,
+    /// Local store for pending comment drafts, when a database is configured.
+    pending_comment_drafts: Option<PendingCommentDrafts>,
+    /// Top-level reviews submitted on the pull request.
+    pull_request_reviews: Vec<PullRequestReview>,
+    /// Index of the selected review in the pull request reviews view.
+    selected_pull_request_review: usize,
+    /// Conversation comments on the pull request, shown in the timeline.
+    issue_comments: Vec<PullRequestComment>,
+    /// Index of the selected entry in the conversation timeline view.
+    selected_timeline_entry: usize,
+    /// Pull request details shown in the header, when loaded.
+    pull_request: Option<PullRequestMetadata>,
+    /// CI results for the pull request head, when loaded.
+    ci_status: Option<CiStatus>,
+    /// Index of the selected failing check in the CI checks view.
+    selected_ci_check: usize,
+    /// Local outbox for writes GitHub could not accept, when a database is
+    /// configured.
+    outbox: Option<OutboxTarget>,
+    /// Queued writes shown in the header badge and outbox view.
+    queued: QueuedWrites,
     /// Reply-drafting templates and max-length configuration.
     reply_draft_config: ReplyDraftConfig,
     /// Service used to perform AI rewrite requests.
@@ -182,6 +274,9 @@ impl ReviewApp {
             filtered_indices,
             filter_state: FilterState::new(),
             loading: false,
+            data_source: DataSource::GitHub,
+            rate_limit_backoff: None,
+            incremental_syncs: 0,
             error: None,
             width,
             height,
@@ -202,12 +297,32 @@ impl ReviewApp {
             codex_handle: None,
             codex_status: None,
             codex_poll_interval: std::time::Duration::from_millis(150),
+            review_stream: None,
             has_initialized: false,
             resume_prompt: None,
             reply_draft: None,
             reply_draft_ai_preview: None,
             next_ai_rewrite_request_id: 1,
             in_flight_ai_rewrite_request_id: None,
+            in_flight_reply_send: None,
+            in_flight_thread_resolution: None,
+            reactions: ReactionState::default(),
+            authenticated_login: None,
+            comment_delete_target: None,
+            in_flight_comment_delete: None,
+            suggestion_applier: None,
+            suggestion_report: None,
+            review_composer: ReviewComposerState::default(),
+            pending_comment_drafts: None,
+            pull_request_reviews: Vec::new(),
+            selected_pull_request_review: 0,
+            issue_comments: Vec::new(),
+            selected_timeline_entry: 0,
+            pull_request: None,
+            ci_status: None,
+            selected_ci_check: 0,
+            outbox: None,
+            queued: QueuedWrites::default(),
             reply_draft_config: super::get_reply_draft_config(),
             comment_rewrite_service: super::get_comment_rewrite_service(),
             verification: VerificationState::default(),
@@ -226,6 +341,12 @@ impl ReviewApp {
         Self::new(Vec::new())
     }
 
+    /// Returns whether reviews were loaded offline from the local database.
+    #[must_use]
+    pub(super) const fn is_offline(&self) -> bool {
+        matches!(self.data_source, DataSource::Offline)
+    }
+
     /// Returns whether a Codex execution run is currently active.
     #[must_use]
     pub(super) const fn is_codex_running(&self) -> bool {
@@ -6,7 +6,6 @@
 use std::any::Any;
 
 use bubbletea_rs::{Cmd, Model};
-use unicode_width::UnicodeWidthChar;
 
 use super::ReviewApp;
 use crate::tui::app::ViewMode;
@@ -18,11 +17,50 @@ use crate::tui::input::{InputContext, map_key_to_message_with_context};
 use crate::tui::messages::AppMsg;
 use crate::verification::DiffReplayResolutionVerifier;
 
+impl ReviewApp {
+    /// Attaches the pending draft store and write outbox configured for the
+    /// refresh pull request, loading what they hold.
+    fn with_local_stores(mut self) -> Self {
+        let Some(locator) = crate::tui::get_refresh_locator() else {
+            return self;
+        };
+
+        if let Some(store) = crate::tui::get_pending_review_comment_store() {
+            self = self.with_pending_review_comment_store(store, locator.clone());
+            if let Some(error) = self.load_pending_comment_drafts() {
+                self.error = Some(error);
+            }
+        }
+
+        if let Some(outbox) = crate::tui::get_write_outbox() {
+            self = self.with_write_outbox(outbox, locator);
+            if let Some(error) = self.reload_queued_writes() {
+                self.error = Some(error);
+            }
+        }
+
+        self
+    }
+
+    /// Attaches the review comment listing left running at startup, if any.
+    fn with_stored_review_stream(self) -> Self {
+        match crate::tui::take_review_comment_stream() {
+            Some(stream) => self.with_review_stream(stream),
+            None => self,
+        }
+    }
+}
+
 impl Model for ReviewApp {
     fn init() -> (Self, Option<Cmd>) {
         // Retrieve initial data from module-level storage
         let reviews = crate::tui::get_initial_reviews();
-        let mut model = Self::new(reviews);
+        let mut model = Self::new(reviews)
+            .with_pull_request_reviews(crate::tui::get_initial_pull_request_reviews())
+            .with_issue_comments(crate::tui::get_initial_issue_comments())
+            .with_pull_request_metadata(crate::tui::get_initial_pull_request_metadata())
+            .with_ci_status(crate::tui::get_initial_ci_status());
+        model.error = crate::tui::get_initial_error();
 
         // Wire up git operations for time-travel if available
         if let Some((git_ops, head_sha)) = crate::tui::get_git_ops_context() {
@@ -34,6 +72,18 @@ impl Model for ReviewApp {
                 .with_resolution_verification_service(verifier);
         }
 
+        if let Some(applier) = crate::tui::get_suggestion_applier() {
+            model = model.with_suggestion_applier(applier);
+        }
+
+        if let Some(login) = crate::tui::get_authenticated_login() {
+            model = model.with_authenticated_login(login);
+        }
+
+        if crate::tui::is_offline() {
+            model = model.with_offline_mode();
+        }
+
         if let Some(limit) = crate::tui::get_commit_history_limit() {
             model = model.with_commit_history_limit(limit);
         }
@@ -42,6 +92,8 @@ impl Model for ReviewApp {
             model = model.with_review_comment_verification_cache(cache);
         }
 
+        model = model.with_local_stores().with_stored_review_stream();
+
         if let Some(head_sha) = model.head_sha.clone() {
             let comment_ids: Vec<u64> = model.reviews.iter().map(|comment| comment.id).collect();
             if let Some(error) = model
@@ -105,6 +157,21 @@ impl Model for ReviewApp {
         if self.view_mode == ViewMode::PrDiscussionSummary {
             return self.normalise_viewport(&self.render_pr_discussion_summary_view());
         }
+        if self.view_mode == ViewMode::ReviewSubmission {
+            return self.normalise_viewport(&self.render_review_submission_view());
+        }
+        if self.view_mode == ViewMode::PullRequestReviews {
+            return self.normalise_viewport(&self.render_pull_request_reviews_view());
+        }
+        if self.view_mode == ViewMode::Timeline {
+            return self.normalise_viewport(&self.render_timeline_view());
+        }
+        if self.view_mode == ViewMode::CiChecks {
+            return self.normalise_viewport(&self.render_ci_checks_view());
+        }
+        if self.view_mode == ViewMode::Outbox {
+            return self.normalise_viewport(&self.render_outbox_view());
+        }
 
         // Render main ReviewList view
         let mut output = String::new();
@@ -138,6 +205,8 @@ impl Model for ReviewApp {
                 .and_then(|comment| self.reply_draft_ai_preview_for_comment(comment.id));
             let verification = selected_comment
                 .and_then(|comment| self.verification.verification_for_comment(comment.id));
+            let suggestion_report =
+                selected_comment.and_then(|comment| self.suggestion_report_for_comment(comment.id));
             let detail_ctx = CommentDetailViewContext {
                 selected_comment,
                 max_width: safe_terminal_width,
@@ -145,6 +214,7 @@ impl Model for ReviewApp {
                 reply_draft,
                 reply_draft_ai_preview,
                 verification,
+                suggestion_report,
             };
             output.push_str(&self.comment_detail.view(&detail_ctx));
         }
@@ -162,15 +232,36 @@ impl ReviewApp {
         }
         match self.view_mode {
             ViewMode::ReviewList => {
-                if self.has_reply_draft_for_current_selection() {
+                if self.reactions.picker_target.is_some() {
+                    InputContext::ReactionPicker
+                } else if self.comment_delete_target.is_some() {
+                    InputContext::CommentDeleteConfirm
+                } else if self.has_reply_draft_for_current_selection() {
                     InputContext::ReplyDraft
                 } else {
                     InputContext::ReviewList
                 }
             }
-            ViewMode::DiffContext => InputContext::DiffContext,
+            ViewMode::DiffContext => {
+                if self.diff_context_state.comment_draft().is_some() {
+                    InputContext::DiffCommentDraft
+                } else {
+                    InputContext::DiffContext
+                }
+            }
             ViewMode::TimeTravel => InputContext::TimeTravel,
             ViewMode::PrDiscussionSummary => InputContext::PrDiscussionSummary,
+            ViewMode::PullRequestReviews => InputContext::PullRequestReviews,
+            ViewMode::Timeline => InputContext::Timeline,
+            ViewMode::CiChecks => InputContext::CiChecks,
+            ViewMode::Outbox => InputContext::Outbox,
+            ViewMode::ReviewSubmission => {
+                if self.review_composer.is_confirming() {
+                    InputContext::ReviewSubmissionConfirm
+                } else {
+                    InputContext::ReviewSubmission
+                }
+            }
         }
     }
 
@@ -197,6 +288,7 @@ impl ReviewApp {
             max_length: draft.max_length().as_usize(),
             ready_to_send: draft.is_ready_to_send(),
             origin_label: draft.origin_label(),
+            edits_comment: draft.edits_comment(),
         })
     }
 
@@ -217,183 +309,4 @@ impl ReviewApp {
             has_changes: preview.side_by_side_preview.has_changes,
         })
     }
-
-    /// Normalizes the rendered frame to terminal dimensions.
-    ///
-    /// The output stream from components can leave stale trailing cells behind
-    /// when rows are shorter than previous frames, especially after resize.
-    /// We clamp rows to one column less than terminal width to avoid autowrap
-    /// behaviour, while still padding with spaces to clear stale trailing
-    /// cells after resize.
-    fn normalise_viewport(&self, output: &str) -> String {
-        let width = self.width.max(1) as usize;
-        let safe_width = width.saturating_sub(1).max(1);
-        let height = self.height.max(1) as usize;
-
-        let lines: Vec<String> = output
-            .lines()
-            .map(|line| pad_or_truncate_line(line, safe_width))
-            .collect();
-
-        normalise_lines_to_height(lines, height, safe_width)
-    }
-}
-
-fn normalise_lines_to_height(mut lines: Vec<String>, height: usize, width: usize) -> String {
-    lines.truncate(height);
-
-    let missing = height.saturating_sub(lines.len());
-    let blank = " ".repeat(width);
-    lines.extend(std::iter::repeat_with(|| blank.clone()).take(missing));
-
-    let mut normalised = lines.join("\n");
-    normalised.push('\n');
-    normalised
-}
-
-fn pad_or_truncate_line(line: &str, width: usize) -> String {
-    truncate_ansi_line(line, width)
-}
-
-fn truncate_ansi_line(line: &str, width: usize) -> String {
-    if width == 0 {
-        return String::new();
-    }
-
-    let mut output = String::new();
-    let mut visible_chars = 0usize;
-    let mut in_escape = false;
-    let mut had_ansi = false;
-    let mut ended_with_reset = false;
-    let mut escape_buffer = String::new();
-
-    for ch in line.chars() {
-        if in_escape {
-            output.push(ch);
-            escape_buffer.push(ch);
-            if ch.is_ascii_alphabetic() {
-                in_escape = false;
-                update_reset_tracking(&escape_buffer, &mut ended_with_reset);
-            }
-            continue;
-        }
-
-        if ch == '\x1b' {
-            in_escape = true;
-            had_ansi = true;
-            output.push(ch);
-            escape_buffer.clear();
-            escape_buffer.push(ch);
-            continue;
-        }
-
-        let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
-        if char_width == 0 {
-            output.push(ch);
-            continue;
-        }
-
-        if visible_chars.saturating_add(char_width) > width {
-            break;
-        }
-
-        output.push(ch);
-        visible_chars = visible_chars.saturating_add(char_width);
-    }
-    add_padding(&mut output, visible_chars, width);
-    add_ansi_reset_if_needed(&mut output, had_ansi, ended_with_reset);
-
-    output
-}
-
-fn add_padding(output: &mut String, visible_chars: usize, width: usize) {
-    if visible_chars < width {
-        output.push_str(&" ".repeat(width - visible_chars));
-    }
-}
-
-fn add_ansi_reset_if_needed(output: &mut String, had_ansi: bool, ended_with_reset: bool) {
-    if had_ansi && !ended_with_reset {
-        // Add a defensive reset when styles may still be active.
-        output.push_str("\x1b[0m");
-    }
-}
-
-fn update_reset_tracking(escape_sequence: &str, ended_with_reset: &mut bool) {
-    if let Some(is_reset_sequence) = sgr_sequence_resets_styles(escape_sequence) {
-        *ended_with_reset = is_reset_sequence;
-    }
-}
-
-fn sgr_sequence_resets_styles(escape_sequence: &str) -> Option<bool> {
-    let params = escape_sequence.strip_prefix("\x1b[")?.strip_suffix('m')?;
-    if params.is_empty() {
-        return Some(true);
-    }
-
-    Some(
-        params
-            .split(';')
-            .all(|param| param.is_empty() || param == "0"),
-    )
-}
-
-#[cfg(test)]
-mod tests {
-    //! Unit tests for the `model_impl` module.
-
-    use super::{normalise_lines_to_height, pad_or_truncate_line, sgr_sequence_resets_styles};
-
-    #[test]
-    fn pad_or_truncate_line_resets_ansi_without_explicit_reset() {
-        let line = "\u{1b}[31mred";
-        let result = pad_or_truncate_line(line, 3);
-
-        assert_eq!(result, "\u{1b}[31mred\u{1b}[0m");
-    }
-
-    #[test]
-    fn pad_or_truncate_line_avoids_duplicate_reset_when_line_is_already_reset() {
-        let line = "\u{1b}[31mred\u{1b}[0m";
-        let result = pad_or_truncate_line(line, 3);
-
-        assert_eq!(result, line);
-    }
-
-    #[test]
-    fn pad_or_truncate_line_adds_reset_after_non_reset_sgr_with_zero_prefix() {
-        let line = "\u{1b}[0;31mred";
-        let result = pad_or_truncate_line(line, 3);
-
-        assert_eq!(result, "\u{1b}[0;31mred\u{1b}[0m");
-    }
-
-    #[test]
-    fn pad_or_truncate_line_handles_wide_characters() {
-        let result = pad_or_truncate_line("你好世界", 5);
-        assert_eq!(result, "你好 ");
-    }
-
-    #[test]
-    fn sgr_sequence_resets_styles_only_for_true_reset_sequences() {
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[m"), Some(true));
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[0m"), Some(true));
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[0;0m"), Some(true));
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[31m"), Some(false));
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[0;31m"), Some(false));
-        assert_eq!(sgr_sequence_resets_styles("\u{1b}[K"), None);
-    }
-
-    #[test]
-    fn normalise_lines_to_height_pads_missing_rows() {
-        let result = normalise_lines_to_height(vec!["abcd".to_owned()], 3, 4);
-        assert_eq!(result, "abcd\n    \n    \n");
-    }
-
-    #[test]
-    fn normalise_lines_to_height_truncates_extra_rows() {
-        let lines = vec!["1111".to_owned(), "2222".to_owned(), "3333".to_owned()];
-        let result = normalise_lines_to_height(lines, 2, 4);
-        assert_eq!(result, "1111\n2222\n");
-    }
 }
@@ -0,0 +1,278 @@
+//! Handlers for the write outbox and its full-screen view.
+//!
+//! Replies and thread resolutions that cannot reach GitHub, because the TUI
+//! runs offline or the request failed transiently, are queued in the local
+//! write outbox. The view lists the queued writes so they can be retried or
+//! discarded, and queued writes are replayed after each successful sync.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use bubbletea_rs::Cmd;
+
+use crate::github::PullRequestLocator;
+use crate::github::error::IntakeError;
+use crate::github::outbox::{OutboxReplayReport, ReplayOutcome, WriteIntent};
+use crate::persistence::{PersistenceError, QueuedWrite, WriteOutbox};
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+/// Status message shown when a replay is requested offline.
+const OFFLINE_RETRY_MESSAGE: &str =
+    "Offline mode: queued writes are sent when Frankie runs without --offline";
+
+/// Local outbox and the pull request whose writes it queues.
+#[derive(Debug, Clone)]
+pub(crate) struct OutboxTarget {
+    /// Outbox holding the queued writes.
+    pub(crate) outbox: Arc<WriteOutbox>,
+    /// Pull request the writes apply to.
+    pub(crate) locator: PullRequestLocator,
+}
+
+/// Writes queued for the pull request and the outbox view selection.
+#[derive(Debug, Default)]
+pub(crate) struct QueuedWrites {
+    /// Queued writes, as last read from the outbox.
+    pub(crate) writes: Vec<QueuedWrite>,
+    /// Index of the selected write in the outbox view.
+    pub(crate) selected: usize,
+    /// Whether queued writes are currently being replayed.
+    pub(crate) replay_in_flight: bool,
+}
+
+impl OutboxTarget {
+    /// Queues `intent` for the pull request, returning its intent ID.
+    fn enqueue(&self, intent: &WriteIntent) -> Result<String, PersistenceError> {
+        self.outbox
+            .enqueue(&self.locator, intent)
+            .map(|queued| queued.intent_id)
+    }
+
+    /// Queues `intent` when `error` is transient, returning its intent ID.
+    ///
+    /// Returns `None` for other errors, or when queueing itself fails, so the
+    /// caller reports the original error instead.
+    pub(super) fn queue_after_failure(
+        &self,
+        intent: &WriteIntent,
+        error: &IntakeError,
+    ) -> Option<String> {
+        if !error.is_transient() {
+            return None;
+        }
+        self.enqueue(intent)
+            .inspect_err(|queue_error| log_queue_failure(intent, queue_error))
+            .ok()
+    }
+}
+
+fn log_queue_failure(intent: &WriteIntent, error: &PersistenceError) {
+    tracing::warn!("could not queue {}: {error}", intent.summary());
+}
+
+impl ReviewApp {
+    /// Handles opening the outbox view and acting on queued writes.
+    pub(super) fn handle_outbox_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowOutbox => {
+                self.view_mode = ViewMode::Outbox;
+                self.error = self.reload_queued_writes();
+                None
+            }
+            AppMsg::HideOutbox => {
+                self.view_mode = ViewMode::ReviewList;
+                self.error = None;
+                None
+            }
+            AppMsg::RetryQueuedWrite => {
+                let selected = self.queued.writes.get(self.queued.selected).cloned();
+                self.request_outbox_replay(selected.into_iter().collect())
+            }
+            AppMsg::RetryAllQueuedWrites => self.request_outbox_replay(self.queued.writes.clone()),
+            AppMsg::DiscardQueuedWrite => {
+                self.discard_selected_queued_write();
+                None
+            }
+            AppMsg::OutboxReplayed { report } => {
+                self.handle_outbox_replayed(report);
+                None
+            }
+            AppMsg::OutboxReplayFailed { message } => {
+                self.queued.replay_in_flight = false;
+                self.error = Some(format!("Failed to send queued writes: {message}"));
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the queued-write selection for list navigation messages.
+    pub(super) fn handle_outbox_navigation(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        let last_index = self.queued.writes.len().saturating_sub(1);
+        let selected = self.queued.selected;
+        self.queued.selected = match msg {
+            AppMsg::CursorUp => selected.saturating_sub(1),
+            AppMsg::CursorDown => selected.saturating_add(1).min(last_index),
+            AppMsg::Home => 0,
+            AppMsg::End => last_index,
+            _ => selected,
+        };
+        None
+    }
+
+    /// Queues `intent` directly when the TUI runs offline.
+    ///
+    /// Returns `None` when the app is online or no outbox is configured, in
+    /// which case the write should be sent to GitHub instead. Otherwise
+    /// returns the intent ID, or the message explaining why queueing failed.
+    pub(super) fn queue_offline_write(
+        &self,
+        intent: &WriteIntent,
+    ) -> Option<Result<String, String>> {
+        if !self.is_offline() {
+            return None;
+        }
+        let target = self.outbox.as_ref()?;
+        Some(
+            target
+                .enqueue(intent)
+                .map_err(|error| format!("Failed to queue {}: {error}", intent.summary())),
+        )
+    }
+
+    /// Reports that a write was queued instead of sent.
+    ///
+    /// `label` names the kind of write, such as "Reply".
+    pub(super) fn note_queued_write(&mut self, label: &str, intent_id: &str) {
+        self.error = self.reload_queued_writes().or_else(|| {
+            Some(format!(
+                "{label} queued as {intent_id}; it will be sent when GitHub is reachable. Press w to inspect."
+            ))
+        });
+    }
+
+    /// Replays every queued write once GitHub has proven reachable.
+    ///
+    /// Called after a successful sync. Does nothing offline, while a replay
+    /// is already running, or when nothing is queued.
+    pub(super) fn replay_queued_writes_after_sync(&mut self) -> Option<Cmd> {
+        if self.is_offline() || self.queued.replay_in_flight || self.outbox.is_none() {
+            return None;
+        }
+        if let Some(error) = self.reload_queued_writes() {
+            log_reload_failure(&error);
+            return None;
+        }
+        if self.queued.writes.is_empty() {
+            return None;
+        }
+        self.queued.replay_in_flight = true;
+        Some(spawn_outbox_replay(self.queued.writes.clone()))
+    }
+
+    /// Re-reads the writes queued for the pull request.
+    ///
+    /// Returns an error message when the outbox cannot be read.
+    pub(super) fn reload_queued_writes(&mut self) -> Option<String> {
+        let target = self.outbox.as_ref()?;
+        match target.outbox.list_for_pull_request(&target.locator) {
+            Ok(writes) => {
+                self.queued.selected = self.queued.selected.min(writes.len().saturating_sub(1));
+                self.queued.writes = writes;
+                None
+            }
+            Err(error) => Some(format!("Failed to load queued writes: {error}")),
+        }
+    }
+
+    fn request_outbox_replay(&mut self, writes: Vec<QueuedWrite>) -> Option<Cmd> {
+        if self.outbox.is_none() {
+            self.error = Some("Queued writes require --database-url".to_owned());
+            return None;
+        }
+        if self.is_offline() {
+            self.error = Some(OFFLINE_RETRY_MESSAGE.to_owned());
+            return None;
+        }
+        if self.queued.replay_in_flight {
+            self.error = Some("Queued writes are already being sent.".to_owned());
+            return None;
+        }
+        if writes.is_empty() {
+            self.error = Some("No queued writes to send.".to_owned());
+            return None;
+        }
+
+        self.queued.replay_in_flight = true;
+        self.error = None;
+        Some(spawn_outbox_replay(writes))
+    }
+
+    fn discard_selected_queued_write(&mut self) {
+        let Some(target) = self.outbox.clone() else {
+            self.error = Some("Queued writes require --database-url".to_owned());
+            return;
+        };
+        let Some(write) = self.queued.writes.get(self.queued.selected) else {
+            self.error = Some("No queued write selected.".to_owned());
+            return;
+        };
+
+        let intent_id = write.intent_id.clone();
+        self.error = match target.outbox.remove(&intent_id) {
+            Ok(_) => self.reload_queued_writes(),
+            Err(error) => Some(format!(
+                "Failed to discard queued write {intent_id}: {error}"
+            )),
+        };
+    }
+
+    fn handle_outbox_replayed(&mut self, report: &OutboxReplayReport) {
+        self.queued.replay_in_flight = false;
+        self.error = self
+            .reload_queued_writes()
+            .or_else(|| describe_unsent(report));
+    }
+}
+
+fn log_reload_failure(message: &str) {
+    tracing::warn!("{message}");
+}
+
+/// Describes the writes a replay left queued, if any.
+fn describe_unsent(report: &OutboxReplayReport) -> Option<String> {
+    let remaining = report.remaining_count();
+    if remaining == 0 {
+        return None;
+    }
+    let reason = report
+        .outcomes
+        .iter()
+        .find_map(|(_, outcome)| match outcome {
+            ReplayOutcome::Failed(error) => Some(format!(": {error}")),
+            _ => None,
+        })
+        .unwrap_or_default();
+    Some(format!(
+        "{remaining} queued write(s) not sent{reason}. Press w to inspect."
+    ))
+}
+
+/// Spawns an async task replaying `writes` against GitHub.
+fn spawn_outbox_replay(writes: Vec<QueuedWrite>) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::replay_queued_writes(writes).await {
+            Ok(report) => AppMsg::OutboxReplayed { report },
+            Err(error) => AppMsg::OutboxReplayFailed {
+                message: error.to_string(),
+            },
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+#[cfg(test)]
+#[path = "outbox_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,275 @@
+//! Tests for the write outbox handlers.
+
+use std::sync::Arc;
+
+use bubbletea_rs::Model;
+use rstest::{fixture, rstest};
+use tempfile::TempDir;
+
+use crate::github::models::{ReactionContent, ReviewComment, ReviewThreadState};
+use crate::github::outbox::{OutboxReplayReport, ReplayOutcome, WriteIntent};
+use crate::github::{IntakeError, PullRequestLocator};
+use crate::persistence::{WriteOutbox, migrate_database};
+use crate::telemetry::NoopTelemetrySink;
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+/// Outbox in a migrated temporary database, kept alive with its directory.
+struct OutboxFixture {
+    _temp_dir: TempDir,
+    outbox: Arc<WriteOutbox>,
+    locator: PullRequestLocator,
+}
+
+impl OutboxFixture {
+    fn enqueue(&self, intent: &WriteIntent) -> String {
+        self.outbox
+            .enqueue(&self.locator, intent)
+            .expect("enqueue should succeed")
+            .intent_id
+    }
+
+    fn queued_intents(&self) -> Vec<WriteIntent> {
+        self.outbox
+            .list()
+            .expect("list should succeed")
+            .into_iter()
+            .map(|queued| queued.intent)
+            .collect()
+    }
+
+    fn app(&self, reviews: Vec<ReviewComment>) -> ReviewApp {
+        let mut app = ReviewApp::with_dimensions(reviews, 100, 24)
+            .with_write_outbox(Arc::clone(&self.outbox), self.locator.clone());
+        assert_eq!(app.reload_queued_writes(), None);
+        app
+    }
+}
+
+#[fixture]
+fn fixture() -> OutboxFixture {
+    let temp_dir = TempDir::new().expect("temp dir should be created");
+    let database_url = temp_dir
+        .path()
+        .join("frankie.sqlite")
+        .to_string_lossy()
+        .to_string();
+    migrate_database(&database_url, &NoopTelemetrySink).expect("migrations should run");
+    OutboxFixture {
+        _temp_dir: temp_dir,
+        outbox: Arc::new(WriteOutbox::new(database_url).expect("outbox should be created")),
+        locator: PullRequestLocator::parse("https://github.com/owner/repo/pull/5")
+            .expect("locator should parse"),
+    }
+}
+
+fn reply(body: &str) -> WriteIntent {
+    WriteIntent::Reply {
+        comment_id: 1,
+        body: body.to_owned(),
+    }
+}
+
+fn threaded_comment() -> ReviewComment {
+    ReviewComment {
+        id: 1,
+        body: Some("Please split this helper".to_owned()),
+        thread_state: Some(ReviewThreadState {
+            thread_id: "PRRT_one".to_owned(),
+            ..ReviewThreadState::default()
+        }),
+        ..ReviewComment::default()
+    }
+}
+
+#[rstest]
+fn outbox_view_lists_queued_writes_and_header_counts_them(fixture: OutboxFixture) {
+    fixture.enqueue(&reply("Fixed"));
+    fixture.enqueue(&reply("Also renamed it"));
+    let mut app = fixture.app(Vec::new());
+
+    assert!(app.view().contains("[Queued: 2]"));
+
+    app.handle_message(&AppMsg::ShowOutbox);
+    app.handle_message(&AppMsg::CursorDown);
+    let output = app.view();
+
+    assert_eq!(app.view_mode, ViewMode::Outbox);
+    assert!(output.contains("Queued writes (2):"), "{output}");
+    assert!(output.contains("reply to #1: Also renamed it"), "{output}");
+    assert_eq!(app.queued.selected, 1);
+
+    app.handle_message(&AppMsg::EscapePressed);
+    assert_eq!(app.view_mode, ViewMode::ReviewList);
+}
+
+#[rstest]
+fn discard_removes_the_selected_write(fixture: OutboxFixture) {
+    fixture.enqueue(&reply("Fixed"));
+    fixture.enqueue(&reply("Also renamed it"));
+    let mut app = fixture.app(Vec::new());
+    app.handle_message(&AppMsg::ShowOutbox);
+
+    app.handle_message(&AppMsg::End);
+    app.handle_message(&AppMsg::DiscardQueuedWrite);
+
+    assert_eq!(fixture.queued_intents(), vec![reply("Fixed")]);
+    assert_eq!(app.queued.writes.len(), 1);
+    assert_eq!(app.queued.selected, 0);
+    assert_eq!(app.error, None);
+}
+
+#[rstest]
+fn offline_reply_is_queued_and_the_draft_cleared(fixture: OutboxFixture) {
+    let mut app = fixture.app(vec![threaded_comment()]).with_offline_mode();
+    app.handle_message(&AppMsg::StartReplyDraft);
+    for character in "Done".chars() {
+        app.handle_message(&AppMsg::ReplyDraftInsertChar(character));
+    }
+
+    let cmd = app.handle_message(&AppMsg::ReplyDraftRequestSend);
+
+    assert!(cmd.is_none(), "offline replies should not reach GitHub");
+    assert!(app.reply_draft.is_none());
+    assert_eq!(app.in_flight_reply_send, None);
+    assert_eq!(fixture.queued_intents(), vec![reply("Done")]);
+    let status = app.error.clone().unwrap_or_default();
+    assert!(status.starts_with("Reply queued as w"), "{status}");
+}
+
+#[rstest]
+fn offline_thread_resolution_is_queued(fixture: OutboxFixture) {
+    let mut app = fixture.app(vec![threaded_comment()]).with_offline_mode();
+
+    let cmd = app.handle_message(&AppMsg::ToggleThreadResolution);
+
+    assert!(cmd.is_none());
+    assert_eq!(app.in_flight_thread_resolution, None);
+    assert_eq!(
+        fixture.queued_intents(),
+        vec![WriteIntent::ThreadResolution {
+            thread_id: "PRRT_one".to_owned(),
+            resolved: true,
+        }]
+    );
+}
+
+#[rstest]
+fn queued_send_result_clears_the_in_flight_reply(fixture: OutboxFixture) {
+    let mut app = fixture.app(vec![threaded_comment()]);
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('x'));
+    app.in_flight_reply_send = Some(1);
+    let intent_id = fixture.enqueue(&reply("x"));
+
+    app.handle_message(&AppMsg::ReplyDraftQueued {
+        comment_id: 1,
+        intent_id,
+    });
+
+    assert_eq!(app.in_flight_reply_send, None);
+    assert!(app.reply_draft.is_none());
+    assert_eq!(app.queued.writes.len(), 1);
+}
+
+#[rstest]
+fn queued_reaction_result_clears_the_in_flight_reaction(fixture: OutboxFixture) {
+    let mut app = fixture.app(vec![threaded_comment()]);
+    app.reactions.in_flight = Some(1);
+    let intent_id = fixture.enqueue(&WriteIntent::Reaction {
+        comment_id: 1,
+        content: ReactionContent::Heart,
+        added: true,
+    });
+
+    app.handle_message(&AppMsg::ReactionQueued {
+        comment_id: 1,
+        intent_id,
+    });
+
+    assert_eq!(app.reactions.in_flight, None);
+    assert_eq!(app.queued.writes.len(), 1);
+}
+
+#[rstest]
+fn retry_is_refused_offline(fixture: OutboxFixture) {
+    fixture.enqueue(&reply("Fixed"));
+    let mut app = fixture.app(Vec::new()).with_offline_mode();
+    app.handle_message(&AppMsg::ShowOutbox);
+
+    let cmd = app.handle_message(&AppMsg::RetryAllQueuedWrites);
+
+    assert!(cmd.is_none());
+    assert!(!app.queued.replay_in_flight);
+    let status = app.error.clone().unwrap_or_default();
+    assert!(status.starts_with("Offline mode"), "{status}");
+}
+
+#[rstest]
+fn retry_starts_one_replay_at_a_time(fixture: OutboxFixture) {
+    fixture.enqueue(&reply("Fixed"));
+    let mut app = fixture.app(Vec::new());
+    app.handle_message(&AppMsg::ShowOutbox);
+
+    assert!(app.handle_message(&AppMsg::RetryQueuedWrite).is_some());
+    assert!(app.queued.replay_in_flight);
+    assert!(app.view().contains("sending to GitHub"));
+    assert!(app.handle_message(&AppMsg::RetryQueuedWrite).is_none());
+    assert_eq!(
+        app.error.as_deref(),
+        Some("Queued writes are already being sent.")
+    );
+}
+
+#[rstest]
+fn replay_report_refreshes_the_queue_and_reports_unsent_writes(fixture: OutboxFixture) {
+    let intent_id = fixture.enqueue(&reply("Fixed"));
+    let mut app = fixture.app(Vec::new());
+    app.queued.replay_in_flight = true;
+    let report = OutboxReplayReport {
+        outcomes: vec![(
+            intent_id,
+            ReplayOutcome::Failed(IntakeError::Network {
+                message: "connection reset".to_owned(),
+            }),
+        )],
+    };
+
+    app.handle_message(&AppMsg::OutboxReplayed { report });
+
+    assert!(!app.queued.replay_in_flight);
+    let status = app.error.clone().unwrap_or_default();
+    assert!(
+        status.starts_with("1 queued write(s) not sent: network error"),
+        "{status}"
+    );
+}
+
+#[rstest]
+fn replay_report_clears_the_badge_once_everything_is_sent(fixture: OutboxFixture) {
+    let intent_id = fixture.enqueue(&reply("Fixed"));
+    let mut app = fixture.app(Vec::new());
+    fixture
+        .outbox
+        .remove(&intent_id)
+        .expect("remove should succeed");
+
+    app.handle_message(&AppMsg::OutboxReplayed {
+        report: OutboxReplayReport {
+            outcomes: vec![(intent_id, ReplayOutcome::Applied)],
+        },
+    });
+
+    assert_eq!(app.error, None);
+    assert!(!app.view().contains("[Queued:"));
+}
+
+#[rstest]
+fn sync_completion_replays_queued_writes_once(fixture: OutboxFixture) {
+    fixture.enqueue(&reply("Fixed"));
+    let mut app = fixture.app(Vec::new());
+
+    assert!(app.replay_queued_writes_after_sync().is_some());
+    assert!(app.replay_queued_writes_after_sync().is_none());
+}
@@ -94,12 +94,15 @@ impl ReviewApp {
         self.in_flight_pr_discussion_summary_request_id = Some(request_id);
         self.error = None;
 
-        let request = PrDiscussionSummaryRequest::new(
+        let mut request = PrDiscussionSummaryRequest::new(
             pr_number,
             crate::tui::get_refresh_pr_title(),
             self.reviews.clone(),
         )
         .with_verification_results(self.verification.results.clone());
+        if let Some(ci_status) = &self.ci_status {
+            request = request.with_ci_status(ci_status.clone());
+        }
 
         Some(spawn_pr_discussion_summary(SummaryTaskParams {
             request_id,
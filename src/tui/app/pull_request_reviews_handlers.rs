@@ -0,0 +1,46 @@
+//! Handlers for the full-screen pull request reviews view.
+//!
+//! The view lists top-level reviews (verdicts and summary bodies) loaded at
+//! startup. List navigation moves the selection between reviews.
+
+use bubbletea_rs::Cmd;
+
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+impl ReviewApp {
+    /// Handles opening and closing the pull request reviews view.
+    pub(super) fn handle_pull_request_reviews_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowPullRequestReviews => {
+                self.view_mode = ViewMode::PullRequestReviews;
+                self.error = None;
+            }
+            AppMsg::HidePullRequestReviews => {
+                self.view_mode = ViewMode::ReviewList;
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Moves the review selection for list navigation messages.
+    pub(super) fn handle_pull_request_reviews_navigation(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        let last_index = self.pull_request_reviews.len().saturating_sub(1);
+        let selected = self.selected_pull_request_review;
+        self.selected_pull_request_review = match msg {
+            AppMsg::CursorUp => selected.saturating_sub(1),
+            AppMsg::CursorDown => selected.saturating_add(1).min(last_index),
+            AppMsg::Home => 0,
+            AppMsg::End => last_index,
+            _ => selected,
+        };
+        None
+    }
+}
+
+#[cfg(test)]
+#[path = "pull_request_reviews_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+//! Tests for the pull request reviews view handlers.
+
+use bubbletea_rs::Model;
+use rstest::{fixture, rstest};
+
+use crate::github::models::PullRequestReview;
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+fn review(id: u64, author: &str, state: &str) -> PullRequestReview {
+    PullRequestReview {
+        id,
+        author: Some(author.to_owned()),
+        state: Some(state.to_owned()),
+        body: Some(format!("Summary from {author}")),
+        ..PullRequestReview::default()
+    }
+}
+
+#[fixture]
+fn app() -> ReviewApp {
+    ReviewApp::with_dimensions(Vec::new(), 80, 24).with_pull_request_reviews(vec![
+        review(1, "alice", "APPROVED"),
+        review(2, "bot", "CHANGES_REQUESTED"),
+    ])
+}
+
+#[rstest]
+fn reviews_view_lists_verdicts(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowPullRequestReviews);
+
+    let output = app.view();
+
+    assert_eq!(app.view_mode, ViewMode::PullRequestReviews);
+    assert!(output.contains("Pull request reviews (2):"), "{output}");
+    assert!(output.contains("> alice  Approved"), "{output}");
+    assert!(output.contains("  bot  Changes requested"), "{output}");
+    assert!(output.contains("Summary from bot"), "{output}");
+}
+
+#[rstest]
+fn navigation_moves_selection_within_bounds(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowPullRequestReviews);
+
+    app.handle_message(&AppMsg::CursorDown);
+    app.handle_message(&AppMsg::CursorDown);
+    assert_eq!(app.selected_pull_request_review, 1);
+    assert!(app.view().contains("> bot"));
+
+    app.handle_message(&AppMsg::Home);
+    assert_eq!(app.selected_pull_request_review, 0);
+}
+
+#[rstest]
+fn escape_returns_to_review_list(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowPullRequestReviews);
+
+    app.handle_message(&AppMsg::EscapePressed);
+
+    assert_eq!(app.view_mode, ViewMode::ReviewList);
+}
+
+#[rstest]
+fn review_list_actions_are_blocked_while_open(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowPullRequestReviews);
+
+    app.handle_message(&AppMsg::ShowDiffContext);
+
+    assert_eq!(app.view_mode, ViewMode::PullRequestReviews);
+}
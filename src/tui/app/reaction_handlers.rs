@@ -0,0 +1,189 @@
+//! Review comment reaction handlers.
+//!
+//! Opens the reaction picker for the selected comment, toggles the chosen
+//! reaction on GitHub, and applies the outcome to the local reaction counts
+//! so the list and detail pane update without waiting for the next refresh.
+//!
+//! A toggle that fails transiently is queued in the write outbox as a
+//! [`WriteIntent::Reaction`]. Queued reactions record the intended end
+//! state, which is a removal only when GitHub confirmed the reaction earlier
+//! in the session.
+
+use std::any::Any;
+use std::collections::HashSet;
+
+use bubbletea_rs::Cmd;
+
+use crate::github::models::{ReactionContent, ReactionToggle, apply_reaction_toggle};
+use crate::github::outbox::WriteIntent;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+use super::outbox_handlers::OutboxTarget;
+
+/// Reaction picker and reaction change state.
+#[derive(Debug, Default)]
+pub(crate) struct ReactionState {
+    /// Comment ID the open reaction picker targets, if the picker is open.
+    pub(crate) picker_target: Option<u64>,
+    /// Comment ID whose reaction is being changed, if any.
+    pub(crate) in_flight: Option<u64>,
+    /// Reactions GitHub has confirmed the authenticated user left this
+    /// session, used to tell a queued addition from a removal.
+    pub(crate) viewer_reactions: HashSet<(u64, ReactionContent)>,
+}
+
+impl ReviewApp {
+    /// Handles reaction picker and reaction toggle messages.
+    pub(super) fn handle_reaction_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowReactionPicker => {
+                self.show_reaction_picker();
+                None
+            }
+            AppMsg::HideReactionPicker => {
+                self.reactions.picker_target = None;
+                None
+            }
+            AppMsg::ToggleReaction(content) => self.request_reaction_toggle(*content),
+            AppMsg::ReactionToggled {
+                comment_id,
+                content,
+                toggle,
+            } => {
+                self.handle_reaction_toggled(*comment_id, *content, *toggle);
+                None
+            }
+            AppMsg::ReactionFailed {
+                comment_id,
+                message,
+            } => {
+                if self.reactions.in_flight == Some(*comment_id) {
+                    self.reactions.in_flight = None;
+                }
+                self.error = Some(format!("Failed to update reaction: {message}"));
+                None
+            }
+            AppMsg::ReactionQueued {
+                comment_id,
+                intent_id,
+            } => {
+                if self.reactions.in_flight == Some(*comment_id) {
+                    self.reactions.in_flight = None;
+                }
+                self.note_queued_write("Reaction", intent_id);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn show_reaction_picker(&mut self) {
+        if self.is_offline() {
+            self.error = Some("Reactions are unavailable offline.".to_owned());
+            return;
+        }
+        let Some(comment_id) = self.selected_comment().map(|comment| comment.id) else {
+            self.error = Some("Reacting requires a selected comment".to_owned());
+            return;
+        };
+        self.error = None;
+        self.reactions.picker_target = Some(comment_id);
+    }
+
+    fn request_reaction_toggle(&mut self, content: ReactionContent) -> Option<Cmd> {
+        let Some(comment_id) = self.reactions.picker_target.take() else {
+            self.error = Some("Reacting requires a selected comment".to_owned());
+            return None;
+        };
+        if self.reactions.in_flight.is_some() {
+            self.error = Some("A reaction change is already in progress.".to_owned());
+            return None;
+        }
+
+        let added = !self
+            .reactions
+            .viewer_reactions
+            .contains(&(comment_id, content));
+        self.reactions.in_flight = Some(comment_id);
+        self.error = None;
+        Some(spawn_reaction_toggle(
+            (comment_id, content),
+            added,
+            self.outbox.clone(),
+        ))
+    }
+
+    fn handle_reaction_toggled(
+        &mut self,
+        comment_id: u64,
+        content: ReactionContent,
+        toggle: ReactionToggle,
+    ) {
+        if self.reactions.in_flight == Some(comment_id) {
+            self.reactions.in_flight = None;
+        }
+        match toggle {
+            ReactionToggle::Added => self
+                .reactions
+                .viewer_reactions
+                .insert((comment_id, content)),
+            ReactionToggle::Removed => self
+                .reactions
+                .viewer_reactions
+                .remove(&(comment_id, content)),
+        };
+        if let Some(comment) = self
+            .reviews
+            .iter_mut()
+            .find(|comment| comment.id == comment_id)
+        {
+            apply_reaction_toggle(&mut comment.reactions, content, toggle);
+        }
+    }
+}
+
+/// Spawns an async task toggling the reaction on GitHub.
+///
+/// When the toggle fails transiently and an outbox is configured, the
+/// change is queued for replay, as an addition when `added` is set, instead
+/// of being reported as failed.
+fn spawn_reaction_toggle(
+    (comment_id, content): (u64, ReactionContent),
+    added: bool,
+    outbox: Option<OutboxTarget>,
+) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::toggle_review_comment_reaction(comment_id, content).await {
+            Ok(toggle) => AppMsg::ReactionToggled {
+                comment_id,
+                content,
+                toggle,
+            },
+            Err(error) => {
+                let intent = WriteIntent::Reaction {
+                    comment_id,
+                    content,
+                    added,
+                };
+                outbox
+                    .and_then(|target| target.queue_after_failure(&intent, &error))
+                    .map_or_else(
+                        || AppMsg::ReactionFailed {
+                            comment_id,
+                            message: error.to_string(),
+                        },
+                        |intent_id| AppMsg::ReactionQueued {
+                            comment_id,
+                            intent_id,
+                        },
+                    )
+            }
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+#[cfg(test)]
+#[path = "reaction_handlers_tests.rs"]
+mod tests;
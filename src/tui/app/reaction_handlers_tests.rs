@@ -0,0 +1,126 @@
+//! Tests for review comment reaction handlers.
+
+use rstest::{fixture, rstest};
+
+use crate::github::models::{ReactionContent, ReactionCount, ReactionToggle, ReviewComment};
+use crate::tui::input::InputContext;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+#[fixture]
+fn reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            id: 1,
+            body: Some("nit: rename".to_owned()),
+            reactions: vec![ReactionCount {
+                content: ReactionContent::ThumbsUp,
+                count: 1,
+            }],
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 2,
+            body: Some("Looks good".to_owned()),
+            ..ReviewComment::default()
+        },
+    ]
+}
+
+#[rstest]
+fn picker_captures_keys_until_closed(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews);
+
+    app.handle_message(&AppMsg::ShowReactionPicker);
+    assert_eq!(app.input_context(), InputContext::ReactionPicker);
+    assert!(app.render_status_bar().starts_with("React: 1:👍"));
+
+    app.handle_message(&AppMsg::HideReactionPicker);
+    assert_eq!(app.input_context(), InputContext::ReviewList);
+}
+
+#[rstest]
+fn picker_is_unavailable_offline(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews).with_offline_mode();
+
+    app.handle_message(&AppMsg::ShowReactionPicker);
+
+    assert!(app.reactions.picker_target.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("unavailable offline"), "unexpected: {error}");
+}
+
+#[rstest]
+fn toggle_spawns_request_and_rejects_duplicates(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews);
+
+    app.handle_message(&AppMsg::ShowReactionPicker);
+    let first = app.handle_message(&AppMsg::ToggleReaction(ReactionContent::Eyes));
+    app.handle_message(&AppMsg::ShowReactionPicker);
+    let second = app.handle_message(&AppMsg::ToggleReaction(ReactionContent::Eyes));
+
+    assert!(first.is_some(), "first toggle should spawn a request");
+    assert!(second.is_none(), "second toggle should be rejected");
+    assert!(
+        app.reactions.picker_target.is_none(),
+        "picking should close the picker"
+    );
+    assert_eq!(app.reactions.in_flight, Some(1));
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("already in progress"));
+}
+
+#[rstest]
+fn toggle_without_open_picker_is_ignored(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews);
+
+    let cmd = app.handle_message(&AppMsg::ToggleReaction(ReactionContent::Eyes));
+
+    assert!(cmd.is_none());
+    assert!(app.reactions.in_flight.is_none());
+}
+
+#[rstest]
+#[case::added(ReactionToggle::Added, 2)]
+#[case::removed(ReactionToggle::Removed, 0)]
+fn toggle_result_updates_counts(
+    reviews: Vec<ReviewComment>,
+    #[case] toggle: ReactionToggle,
+    #[case] expected: u64,
+) {
+    let mut app = ReviewApp::new(reviews);
+
+    app.handle_message(&AppMsg::ShowReactionPicker);
+    app.handle_message(&AppMsg::ToggleReaction(ReactionContent::ThumbsUp));
+    app.handle_message(&AppMsg::ReactionToggled {
+        comment_id: 1,
+        content: ReactionContent::ThumbsUp,
+        toggle,
+    });
+
+    assert!(app.reactions.in_flight.is_none());
+    let count = app
+        .reviews
+        .first()
+        .and_then(|comment| comment.reactions.first())
+        .map_or(0, |reaction| reaction.count);
+    assert_eq!(count, expected);
+}
+
+#[rstest]
+fn toggle_failure_reports_error(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews);
+
+    app.handle_message(&AppMsg::ShowReactionPicker);
+    app.handle_message(&AppMsg::ToggleReaction(ReactionContent::Hooray));
+    app.handle_message(&AppMsg::ReactionFailed {
+        comment_id: 1,
+        message: "Resource not accessible by integration".to_owned(),
+    });
+
+    assert!(app.reactions.in_flight.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Failed to update reaction"));
+    assert!(error.contains("Resource not accessible"));
+}
@@ -4,11 +4,116 @@
 //! for display in the terminal. These are pure query methods that read state
 //! without modification.
 
+use unicode_width::UnicodeWidthStr;
+
 use super::ReviewApp;
+use crate::github::models::{PullRequestMetadata, ReactionContent};
 use crate::tui::components::{
-    DiffContextComponent, DiffContextViewContext, PrDiscussionSummaryComponent,
-    PrDiscussionSummaryViewContext, TimeTravelViewComponent, TimeTravelViewContext,
+    CiChecksComponent, CiChecksViewContext, DiffContextComponent, DiffContextViewContext,
+    OutboxComponent, OutboxViewContext, PrDiscussionSummaryComponent,
+    PrDiscussionSummaryViewContext, PullRequestReviewsComponent, PullRequestReviewsViewContext,
+    ReviewSubmissionComponent, ReviewSubmissionViewContext, TimeTravelViewComponent,
+    TimeTravelViewContext, TimelineComponent, TimelineViewContext,
+    truncate_to_display_width_with_ellipsis,
 };
+use crate::tui::state::{ReplyDraftState, ReviewFilter};
+
+/// Keyboard shortcut reference shown by the help overlay.
+const HELP_TEXT: &str = r"
+=== Keyboard Shortcuts ===
+
+Navigation:
+  j, Down    Move cursor down
+  k, Up      Move cursor up
+  PgDn       Page down
+  PgUp       Page up
+  Home, g    Go to first item
+  End, G     Go to last item
+
+Filtering:
+  f          Cycle filter (All/Unresolved)
+  Esc        Clear filter
+
+Other:
+  r          Refresh from GitHub
+  c          View full-screen context
+  t          Time-travel to comment's commit
+  v          Verify selected comment
+  V          Verify filtered comments
+  s          Generate PR discussion summary
+  a          Start inline reply draft
+  R          Resolve/unresolve selected thread
+  e          Edit your selected comment (Enter saves)
+  D          Delete your selected comment (y confirms)
+  +          React to selected comment (1-8 picks, Esc cancels)
+  A          Apply suggested change to working tree
+  S          Compose and submit a pull request review
+  o          Show pull request reviews and verdicts
+  T          Show conversation timeline
+  C          Show CI checks for the pull request head
+  w          Show queued writes (outbox)
+  x          Run Codex using filtered comments
+  ?          Toggle this help
+  q          Quit
+
+Diff context:
+  [          Previous hunk
+  ]          Next hunk
+  j, k       Move the line cursor
+  a          Comment on the selected line (Enter adds it to the review)
+  Esc        Return to review list
+
+Time-travel:
+  h          Previous (older) commit
+  l          Next (more recent) commit
+  Esc        Return to review list
+
+Reply draft:
+  1-9        Insert template
+  E          AI expand draft
+  W          AI reword draft
+  Y          Apply AI preview
+  N          Discard AI preview
+  text keys  Edit draft text
+  Backspace  Delete one character
+  Enter      Send reply (or save edit) to GitHub
+  Tab        Queue draft as inline comment on the pending review
+  Esc        Discard draft and return
+
+Review composer:
+  text keys  Edit review summary
+  Tab        Cycle event (Comment/Approve/Request changes)
+  Delete     Drop the last queued inline comment
+  Enter      Confirm, then y to submit or n to keep editing
+  Esc        Return to the review list (review is kept)
+
+Pull request reviews:
+  j, k       Move between reviews
+  Esc        Return to the review list
+
+Conversation timeline:
+  j, k       Move between entries
+  f          Cycle filter (All/Unresolved)
+  Esc        Return to the review list
+
+CI checks:
+  j, k       Move between failing checks
+  Esc        Return to the review list
+
+Queued writes:
+  j, k       Move between queued writes
+  r          Retry the selected write
+  R          Retry every queued write
+  d          Discard the selected write
+  Esc        Return to the review list
+
+PR discussion summary:
+  j, k       Move between summary items
+  Enter      Jump to the linked comment detail view
+  Esc        Return to the review list
+
+Press any key to close this help.
+";
 
 impl ReviewApp {
     fn render_chrome_with_body<F>(&self, render_body: F) -> String
@@ -30,10 +135,33 @@ impl ReviewApp {
     }
 
     /// Renders the header bar.
+    ///
+    /// When pull request details are loaded, the title is replaced by a
+    /// summary of them, truncated so the header and its badges fit on one
+    /// line.
     pub(super) fn render_header(&self) -> String {
-        let title = "Frankie - Review Comments";
         let loading_indicator = if self.loading { " [Loading...]" } else { "" };
-        format!("{title}{loading_indicator}\n")
+        let offline_indicator = if self.is_offline() { " [Offline]" } else { "" };
+        let ci_badge = self
+            .ci_status
+            .as_ref()
+            .map_or_else(String::new, |ci| format!("  [{}]", ci.badge()));
+        let queued_badge = if self.queued.writes.is_empty() {
+            String::new()
+        } else {
+            format!("  [Queued: {}]", self.queued.writes.len())
+        };
+        let badges = format!("{loading_indicator}{offline_indicator}{ci_badge}{queued_badge}");
+        let title = self.pull_request.as_ref().map_or_else(
+            || "Frankie - Review Comments".to_owned(),
+            |metadata| {
+                truncate_to_display_width_with_ellipsis(
+                    &format!("Frankie - {}", pull_request_header(metadata)),
+                    (self.width as usize).saturating_sub(badges.width()),
+                )
+            },
+        );
+        format!("{title}{badges}\n")
     }
 
     /// Renders the filter bar showing active filter.
@@ -55,21 +183,45 @@ impl ReviewApp {
             return format!("Error: {error}\n");
         }
 
+        if let Some(backoff) = &self.rate_limit_backoff {
+            return format!(
+                "GitHub {}: retrying in {}s (attempt {}/{})\n",
+                backoff.reason().label(),
+                backoff.seconds_remaining(),
+                backoff.attempt(),
+                backoff.max_attempts()
+            );
+        }
+
         if let Some(codex_status) = &self.codex_status {
             return self.render_codex_status(codex_status);
         }
 
+        if self.reactions.picker_target.is_some() {
+            return render_reaction_picker_status();
+        }
+
+        if self.comment_delete_target.is_some() {
+            return "Delete this comment on GitHub? y:delete  n:keep\n".to_owned();
+        }
+
         if self.has_reply_draft() {
             return self.render_reply_draft_status();
         }
 
         let hints = match self.view_mode {
             super::ViewMode::ReviewList => self.review_list_status_hints(),
-            super::ViewMode::DiffContext => "[/]:hunks  Esc:back  ?:help  q:quit",
+            super::ViewMode::DiffContext => self.diff_context_status_hints(),
             super::ViewMode::TimeTravel => "h/l:commits  Esc:back  ?:help  q:quit",
             super::ViewMode::PrDiscussionSummary => {
                 "j/k:move  Enter:open  Esc:back  ?:help  q:quit"
             }
+            super::ViewMode::ReviewSubmission => self.review_submission_status_hints(),
+            super::ViewMode::PullRequestReviews | super::ViewMode::CiChecks => {
+                "j/k:move  Esc:back  ?:help  q:quit"
+            }
+            super::ViewMode::Timeline => "j/k:move  f:filter  Esc:back  ?:help  q:quit",
+            super::ViewMode::Outbox => self.outbox_status_hints(),
         };
         format!("{hints}\n")
     }
@@ -84,76 +236,59 @@ impl ReviewApp {
     }
 
     fn render_reply_draft_status(&self) -> String {
-        if self.has_reply_draft_ai_preview() {
-            "Reply draft: Y:apply  N:discard  text:edit  Enter:ready  Esc:cancel\n".to_owned()
+        if self
+            .reply_draft
+            .as_ref()
+            .is_some_and(ReplyDraftState::edits_comment)
+        {
+            return self.render_comment_edit_status();
+        }
+        if self.in_flight_reply_send.is_some() {
+            "Reply draft: sending reply to GitHub...\n".to_owned()
+        } else if self.has_reply_draft_ai_preview() {
+            "Reply draft: Y:apply  N:discard  text:edit  Enter:send  Esc:cancel\n".to_owned()
+        } else {
+            "Reply draft: 1-9:template  E:expand  W:reword  text:edit  Backspace:delete  Enter:send  Esc:cancel\n"
+                .to_owned()
+        }
+    }
+
+    fn render_comment_edit_status(&self) -> String {
+        if self.in_flight_reply_send.is_some() {
+            "Editing comment: saving edit to GitHub...\n".to_owned()
+        } else if self.has_reply_draft_ai_preview() {
+            "Editing comment: Y:apply  N:discard  text:edit  Enter:save  Esc:cancel\n".to_owned()
         } else {
-            "Reply draft: 1-9:template  E:expand  W:reword  text:edit  Backspace:delete  Enter:ready  Esc:cancel\n"
+            "Editing comment: 1-9:template  E:expand  W:reword  text:edit  Backspace:delete  Enter:save  Esc:cancel\n"
                 .to_owned()
         }
     }
 
+    const fn diff_context_status_hints(&self) -> &'static str {
+        if self.diff_context_state.comment_draft().is_some() {
+            "Comment: text:edit  Backspace:delete  Enter:add to review  Esc:cancel"
+        } else {
+            "j/k:line  a:comment  [/]:hunks  Esc:back  ?:help  q:quit"
+        }
+    }
+
+    const fn review_submission_status_hints(&self) -> &'static str {
+        if self.review_composer.is_submitting() {
+            "Review: submitting to GitHub..."
+        } else if self.review_composer.is_confirming() {
+            "Review: y:submit  n:keep editing"
+        } else {
+            "Review: text:summary  Tab:event  Del:drop comment  Enter:submit  Esc:back"
+        }
+    }
+
     /// Renders the help overlay if visible.
     pub(super) fn render_help_overlay(&self) -> String {
         if !self.show_help {
             return String::new();
         }
 
-        let help_text = r"
-=== Keyboard Shortcuts ===
-
-Navigation:
-  j, Down    Move cursor down
-  k, Up      Move cursor up
-  PgDn       Page down
-  PgUp       Page up
-  Home, g    Go to first item
-  End, G     Go to last item
-
-Filtering:
-  f          Cycle filter (All/Unresolved)
-  Esc        Clear filter
-
-Other:
-  r          Refresh from GitHub
-  c          View full-screen context
-  t          Time-travel to comment's commit
-  v          Verify selected comment
-  V          Verify filtered comments
-  s          Generate PR discussion summary
-  a          Start inline reply draft
-  x          Run Codex using filtered comments
-  ?          Toggle this help
-  q          Quit
-
-Diff context:
-  [          Previous hunk
-  ]          Next hunk
-  Esc        Return to review list
-
-Time-travel:
-  h          Previous (older) commit
-  l          Next (more recent) commit
-  Esc        Return to review list
-
-Reply draft:
-  1-9        Insert template
-  E          AI expand draft
-  W          AI reword draft
-  Y          Apply AI preview
-  N          Discard AI preview
-  text keys  Edit draft text
-  Backspace  Delete one character
-  Enter      Mark draft ready to send
-  Esc        Discard draft and return
-
-PR discussion summary:
-  j, k       Move between summary items
-  Enter      Jump to the linked comment detail view
-  Esc        Return to the review list
-
-Press any key to close this help.
-";
-        help_text.to_owned()
+        HELP_TEXT.to_owned()
     }
 
     /// Renders the full-screen diff context view.
@@ -162,6 +297,9 @@ Press any key to close this help.
             let ctx = DiffContextViewContext {
                 hunks: self.diff_context_state.hunks(),
                 current_index: self.diff_context_state.current_index(),
+                line_cursor: self.diff_context_state.line_cursor(),
+                comment_draft: self.diff_context_state.comment_draft(),
+                max_width: self.width as usize,
                 max_height: body_height,
             };
 
@@ -195,11 +333,136 @@ Press any key to close this help.
         })
     }
 
-    const fn review_list_status_hints(&self) -> &'static str {
-        if self.width <= 80 {
-            "q:quit  ?:help  j/k:move  s:summary  v/V:verify  a:reply  x:codex"
+    /// Renders the full-screen pending review composer.
+    pub(super) fn render_review_submission_view(&self) -> String {
+        self.render_chrome_with_body(|body_height| {
+            let ctx = ReviewSubmissionViewContext {
+                composer: &self.review_composer,
+                max_width: self.width as usize,
+                max_height: body_height,
+            };
+
+            ReviewSubmissionComponent::view(&ctx)
+        })
+    }
+
+    /// Renders the full-screen pull request reviews view.
+    pub(super) fn render_pull_request_reviews_view(&self) -> String {
+        self.render_chrome_with_body(|body_height| {
+            let ctx = PullRequestReviewsViewContext {
+                reviews: &self.pull_request_reviews,
+                selected_index: self.selected_pull_request_review,
+                max_width: self.width as usize,
+                max_height: body_height,
+            };
+
+            PullRequestReviewsComponent::view(&ctx)
+        })
+    }
+
+    /// Renders the full-screen conversation timeline view.
+    pub(super) fn render_timeline_view(&self) -> String {
+        let entries = self.timeline_entries();
+        let filter = &self.filter_state.active_filter;
+        self.render_chrome_with_body(|body_height| {
+            let ctx = TimelineViewContext {
+                entries: &entries,
+                selected_index: self.selected_timeline_entry,
+                filter_label: (*filter != ReviewFilter::All).then(|| filter.label()),
+                max_width: self.width as usize,
+                max_height: body_height,
+            };
+
+            TimelineComponent::view(&ctx)
+        })
+    }
+
+    /// Renders the full-screen CI checks view.
+    pub(super) fn render_ci_checks_view(&self) -> String {
+        self.render_chrome_with_body(|body_height| {
+            let ctx = CiChecksViewContext {
+                ci_status: self.ci_status.as_ref(),
+                selected_index: self.selected_ci_check,
+                max_width: self.width as usize,
+                max_height: body_height,
+            };
+
+            CiChecksComponent::view(&ctx)
+        })
+    }
+
+    /// Renders the full-screen queued writes view.
+    pub(super) fn render_outbox_view(&self) -> String {
+        self.render_chrome_with_body(|body_height| {
+            let ctx = OutboxViewContext {
+                writes: self.outbox.as_ref().map(|_| self.queued.writes.as_slice()),
+                selected_index: self.queued.selected,
+                max_width: self.width as usize,
+                max_height: body_height,
+            };
+
+            OutboxComponent::view(&ctx)
+        })
+    }
+
+    const fn outbox_status_hints(&self) -> &'static str {
+        if self.queued.replay_in_flight {
+            "Queued writes: sending to GitHub..."
         } else {
-            "j/k:move  f:filter  s:summary  c:context  t:travel  v/V:verify  a:reply  x:codex  r:refresh  ?:help  q:quit"
+            "j/k:move  r:retry  R:retry all  d:discard  Esc:back  ?:help  q:quit"
         }
     }
+
+    /// Returns review list key hints, offering edit and delete when the
+    /// selected comment belongs to the authenticated user.
+    fn review_list_status_hints(&self) -> &'static str {
+        let own_comment = self
+            .selected_comment()
+            .is_some_and(|comment| self.is_own_comment(comment));
+        match (self.width <= 80, own_comment) {
+            (true, false) => "q:quit  ?:help  j/k:move  s:summary  v/V:verify  a:reply  x:codex",
+            (true, true) => "q:quit  ?:help  j/k:move  a:reply  e:edit  D:delete  x:codex",
+            (false, false) => {
+                "j/k:move  f:filter  s:summary  c:context  t:travel  v/V:verify  a:reply  x:codex  r:refresh  ?:help  q:quit"
+            }
+            (false, true) => {
+                "j/k:move  f:filter  s:summary  c:context  t:travel  v/V:verify  a:reply  e:edit  D:delete  x:codex  r:refresh  ?:help  q:quit"
+            }
+        }
+    }
+}
+
+/// Lists the reactions the picker offers, numbered by their key.
+fn render_reaction_picker_status() -> String {
+    let choices = ReactionContent::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, content)| format!("{}:{}", index + 1, content.emoji()))
+        .collect::<Vec<_>>()
+        .join("  ");
+    format!("React: {choices}  Esc:cancel\n")
+}
+
+/// Summarises pull request details for the header, such as
+/// `#42 Add widgets [draft]  feature -> main  +120 -8 in 4 files  blocked`,
+/// followed by labels and requested reviewers when present.
+fn pull_request_header(metadata: &PullRequestMetadata) -> String {
+    let title = metadata.title.as_deref().unwrap_or("untitled pull request");
+    let draft = if metadata.draft { " [draft]" } else { "" };
+    let labels =
+        (!metadata.labels.is_empty()).then(|| format!("labels: {}", metadata.labels.join(", ")));
+    let reviewers = (!metadata.requested_reviewers.is_empty())
+        .then(|| format!("reviewers: {}", metadata.requested_reviewers.join(", ")));
+    [
+        Some(format!("#{} {title}{draft}", metadata.number)),
+        metadata.branch_summary(),
+        metadata.change_summary(),
+        metadata.mergeable_state.clone(),
+        labels,
+        reviewers,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("  ")
 }
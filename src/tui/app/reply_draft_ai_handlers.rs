@@ -0,0 +1,185 @@
+//! AI rewrite handlers for reply drafts in the review TUI.
+//!
+//! This module requests AI rewrites of the active reply draft, keeps the
+//! generated text as a side-by-side preview, and applies or discards that
+//! preview on request.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use bubbletea_rs::Cmd;
+
+use crate::ai::{
+    CommentRewriteContext, CommentRewriteMode, CommentRewriteOutcome, CommentRewriteRequest,
+    CommentRewriteService, build_side_by_side_diff_preview, rewrite_with_fallback,
+};
+use crate::tui::messages::AppMsg;
+
+use super::{ReplyDraftAiPreview, ReviewApp};
+
+impl ReviewApp {
+    /// Handles AI rewrite messages for the active reply draft.
+    pub(super) fn handle_reply_draft_ai_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ReplyDraftRequestAiRewrite { mode } => self.request_ai_rewrite(*mode),
+            AppMsg::ReplyDraftAiRewriteReady {
+                request_id,
+                mode,
+                outcome,
+            } => {
+                self.handle_ai_rewrite_ready(*request_id, *mode, outcome);
+                None
+            }
+            AppMsg::ReplyDraftAiApply => {
+                self.apply_ai_rewrite_preview();
+                None
+            }
+            AppMsg::ReplyDraftAiDiscard => {
+                self.discard_ai_rewrite_preview();
+                None
+            }
+            _ => self.handle_reply_send_result_msg(msg),
+        }
+    }
+
+    fn request_ai_rewrite(&mut self, mode: CommentRewriteMode) -> Option<Cmd> {
+        let Some(comment) = self.selected_comment().cloned() else {
+            self.error = Some("Reply drafting requires a selected comment".to_owned());
+            return None;
+        };
+
+        if !self.ensure_active_reply_draft_matches_comment(comment.id) {
+            return None;
+        }
+
+        let Some(draft) = self.active_reply_draft_mut(comment.id) else {
+            self.error = Some("No active reply draft. Press 'a' to start drafting.".to_owned());
+            return None;
+        };
+
+        let source_text = draft.text().to_owned();
+        if source_text.trim().is_empty() {
+            self.error = Some("Reply draft is empty; type text before AI rewrite.".to_owned());
+            return None;
+        }
+
+        let request =
+            CommentRewriteRequest::new(mode, source_text, CommentRewriteContext::from(&comment));
+        let request_id = self.next_ai_rewrite_request_id;
+        self.next_ai_rewrite_request_id = self.next_ai_rewrite_request_id.saturating_add(1);
+
+        self.reply_draft_ai_preview = None;
+        self.in_flight_ai_rewrite_request_id = Some(request_id);
+        self.error = None;
+        Some(spawn_ai_rewrite_request(
+            Arc::clone(&self.comment_rewrite_service),
+            request,
+            mode,
+            request_id,
+        ))
+    }
+
+    fn handle_ai_rewrite_ready(
+        &mut self,
+        request_id: u64,
+        mode: CommentRewriteMode,
+        outcome: &CommentRewriteOutcome,
+    ) {
+        if self.in_flight_ai_rewrite_request_id != Some(request_id) {
+            return;
+        }
+        self.in_flight_ai_rewrite_request_id = None;
+
+        match outcome {
+            CommentRewriteOutcome::Generated(generated) => {
+                let Some(comment_id) = self.selected_comment().map(|comment| comment.id) else {
+                    self.error = Some("Reply drafting requires a selected comment".to_owned());
+                    return;
+                };
+
+                if !self.ensure_active_reply_draft_matches_comment(comment_id) {
+                    return;
+                }
+
+                let Some(draft) = self.active_reply_draft_mut(comment_id) else {
+                    self.error =
+                        Some("No active reply draft. Press 'a' to start drafting.".to_owned());
+                    return;
+                };
+
+                let preview = build_side_by_side_diff_preview(
+                    draft.text(),
+                    generated.rewritten_text.as_str(),
+                );
+                self.reply_draft_ai_preview = Some(ReplyDraftAiPreview {
+                    mode,
+                    rewritten_text: generated.rewritten_text.clone(),
+                    origin_label: generated.origin_label.clone(),
+                    side_by_side_preview: preview,
+                });
+                self.error = None;
+            }
+            CommentRewriteOutcome::Fallback(fallback) => {
+                self.reply_draft_ai_preview = None;
+                self.error = Some(fallback.reason.clone());
+            }
+        }
+    }
+
+    fn apply_ai_rewrite_preview(&mut self) {
+        let Some(preview) = self.reply_draft_ai_preview.clone() else {
+            self.error = Some("No AI rewrite preview to apply.".to_owned());
+            return;
+        };
+
+        match self.get_active_draft_for_editing() {
+            Ok(draft) => {
+                if let Err(error) =
+                    draft.replace_text(preview.rewritten_text.as_str(), Some(preview.origin_label))
+                {
+                    self.error = Some(error.to_string());
+                    return;
+                }
+
+                self.reply_draft_ai_preview = None;
+                self.error = None;
+            }
+            Err(error) => {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    fn discard_ai_rewrite_preview(&mut self) {
+        self.reply_draft_ai_preview = None;
+        self.error = None;
+    }
+}
+
+fn spawn_ai_rewrite_request(
+    service: Arc<dyn CommentRewriteService>,
+    request: CommentRewriteRequest,
+    mode: CommentRewriteMode,
+    request_id: u64,
+) -> Cmd {
+    let original_text = request.source_text().to_owned();
+    Box::pin(async move {
+        let outcome = match tokio::task::spawn_blocking(move || {
+            rewrite_with_fallback(service.as_ref(), &request)
+        })
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(error) => CommentRewriteOutcome::fallback(
+                original_text,
+                format!("AI rewrite task failed: {error}"),
+            ),
+        };
+
+        Some(Box::new(AppMsg::ReplyDraftAiRewriteReady {
+            request_id,
+            mode,
+            outcome,
+        }) as Box<dyn Any + Send>)
+    })
+}
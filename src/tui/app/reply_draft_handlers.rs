@@ -1,22 +1,23 @@
 //! Reply drafting handlers for the review TUI.
 //!
 //! This module implements keyboard-driven template insertion and inline reply
-//! editing while enforcing configured length limits.
+//! editing while enforcing configured length limits, and posts validated
+//! drafts to GitHub as threaded review replies, or as the new body of a
+//! comment being edited.
 
 use std::any::Any;
-use std::sync::Arc;
 
 use bubbletea_rs::Cmd;
 
-use crate::ai::{
-    CommentRewriteContext, CommentRewriteMode, CommentRewriteOutcome, CommentRewriteRequest,
-    CommentRewriteService, build_side_by_side_diff_preview, rewrite_with_fallback,
-};
+use crate::github::models::ReviewComment;
+use crate::github::outbox::WriteIntent;
 use crate::reply_template::{ReplyTemplateContext, ReplyTemplateError, render_reply_template};
 use crate::tui::messages::AppMsg;
 use crate::tui::state::ReplyDraftState;
 
-use super::{ReplyDraftAiPreview, ReviewApp};
+use super::ReviewApp;
+use super::comment_edit_handlers::spawn_comment_edit;
+use super::outbox_handlers::OutboxTarget;
 
 impl ReviewApp {
     /// Handles reply-drafting messages.
@@ -38,10 +39,7 @@ impl ReviewApp {
                 self.backspace_reply_draft();
                 None
             }
-            AppMsg::ReplyDraftRequestSend => {
-                self.request_reply_send();
-                None
-            }
+            AppMsg::ReplyDraftRequestSend => self.request_reply_send(),
             AppMsg::ReplyDraftCancel => {
                 self.cancel_reply_draft();
                 None
@@ -50,23 +48,24 @@ impl ReviewApp {
         }
     }
 
-    fn handle_reply_draft_ai_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+    pub(super) fn handle_reply_send_result_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
         match msg {
-            AppMsg::ReplyDraftRequestAiRewrite { mode } => self.request_ai_rewrite(*mode),
-            AppMsg::ReplyDraftAiRewriteReady {
-                request_id,
-                mode,
-                outcome,
-            } => {
-                self.handle_ai_rewrite_ready(*request_id, *mode, outcome);
+            AppMsg::ReplyDraftSent { comment_id, reply } => {
+                self.handle_reply_sent(*comment_id, reply);
                 None
             }
-            AppMsg::ReplyDraftAiApply => {
-                self.apply_ai_rewrite_preview();
+            AppMsg::ReplyDraftSendFailed {
+                comment_id,
+                message,
+            } => {
+                self.handle_reply_send_failed(*comment_id, message);
                 None
             }
-            AppMsg::ReplyDraftAiDiscard => {
-                self.discard_ai_rewrite_preview();
+            AppMsg::ReplyDraftQueued {
+                comment_id,
+                intent_id,
+            } => {
+                self.handle_reply_queued(*comment_id, intent_id);
                 None
             }
             _ => None,
@@ -159,8 +158,110 @@ impl ReviewApp {
         });
     }
 
-    fn request_reply_send(&mut self) {
+    fn request_reply_send(&mut self) -> Option<Cmd> {
+        if self.in_flight_reply_send.is_some() {
+            self.error = Some("A reply is already being sent; wait for it to finish.".to_owned());
+            return None;
+        }
+
         self.with_active_draft_operation(ReplyDraftState::request_send);
+        if self.error.is_some() {
+            return None;
+        }
+
+        let draft = self.reply_draft.as_ref()?;
+        let comment_id = draft.comment_id();
+        let body = draft.text().to_owned();
+        if draft.edits_comment() {
+            self.in_flight_reply_send = Some(comment_id);
+            return Some(spawn_comment_edit(comment_id, body));
+        }
+        let intent = WriteIntent::Reply {
+            comment_id,
+            body: body.clone(),
+        };
+        match self.queue_offline_write(&intent) {
+            Some(Ok(intent_id)) => {
+                self.handle_reply_queued(comment_id, &intent_id);
+                None
+            }
+            Some(Err(message)) => {
+                self.error = Some(message);
+                None
+            }
+            None => {
+                self.in_flight_reply_send = Some(comment_id);
+                Some(spawn_reply_send(comment_id, body, self.outbox.clone()))
+            }
+        }
+    }
+
+    /// Merges a posted reply into the review list and clears its draft.
+    ///
+    /// The reply inherits the parent's thread state so that thread-aware
+    /// filters keep grouping it correctly until the next refresh.
+    fn handle_reply_sent(&mut self, comment_id: u64, reply: &ReviewComment) {
+        if self.in_flight_reply_send == Some(comment_id) {
+            self.in_flight_reply_send = None;
+        }
+
+        let mut posted = reply.clone();
+        if posted.thread_state.is_none() {
+            posted.thread_state = self
+                .reviews
+                .iter()
+                .find(|review| review.id == comment_id)
+                .and_then(|parent| parent.thread_state.clone());
+        }
+        self.merge_posted_reply(posted);
+
+        if self
+            .reply_draft
+            .as_ref()
+            .is_some_and(|draft| draft.comment_id() == comment_id)
+        {
+            self.reply_draft = None;
+            self.reply_draft_ai_preview = None;
+        }
+        self.error = None;
+    }
+
+    fn merge_posted_reply(&mut self, reply: ReviewComment) {
+        let was_loading = self.loading;
+        let mut merged: Vec<ReviewComment> = self
+            .reviews
+            .iter()
+            .filter(|review| review.id != reply.id)
+            .cloned()
+            .collect();
+        merged.push(reply);
+        self.apply_new_reviews(&merged);
+        self.loading = was_loading;
+    }
+
+    /// Clears the draft of a reply that was queued in the write outbox.
+    fn handle_reply_queued(&mut self, comment_id: u64, intent_id: &str) {
+        if self.in_flight_reply_send == Some(comment_id) {
+            self.in_flight_reply_send = None;
+        }
+        if self
+            .reply_draft
+            .as_ref()
+            .is_some_and(|draft| draft.comment_id() == comment_id)
+        {
+            self.reply_draft = None;
+            self.reply_draft_ai_preview = None;
+        }
+        self.note_queued_write("Reply", intent_id);
+    }
+
+    fn handle_reply_send_failed(&mut self, comment_id: u64, message: &str) {
+        if self.in_flight_reply_send == Some(comment_id) {
+            self.in_flight_reply_send = None;
+        }
+        self.error = Some(format!(
+            "Failed to send reply: {message}. Draft kept; press Enter to retry."
+        ));
     }
 
     fn cancel_reply_draft(&mut self) {
@@ -199,7 +300,7 @@ impl ReviewApp {
     /// - No comment is selected
     /// - No reply draft is active
     /// - The active draft doesn't match the selected comment
-    fn get_active_draft_for_editing(&mut self) -> Result<&mut ReplyDraftState, String> {
+    pub(super) fn get_active_draft_for_editing(&mut self) -> Result<&mut ReplyDraftState, String> {
         let selected_id = self
             .selected_comment()
             .map(|comment| comment.id)
@@ -215,7 +316,10 @@ impl ReviewApp {
         })
     }
 
-    fn active_reply_draft_mut(&mut self, selected_comment_id: u64) -> Option<&mut ReplyDraftState> {
+    pub(super) fn active_reply_draft_mut(
+        &mut self,
+        selected_comment_id: u64,
+    ) -> Option<&mut ReplyDraftState> {
         let draft = self.reply_draft.as_mut()?;
 
         if draft.comment_id() != selected_comment_id {
@@ -225,7 +329,7 @@ impl ReviewApp {
         Some(draft)
     }
 
-    fn ensure_active_reply_draft_matches_comment(&mut self, comment_id: u64) -> bool {
+    pub(super) fn ensure_active_reply_draft_matches_comment(&mut self, comment_id: u64) -> bool {
         if self.reply_draft.is_none() {
             self.error = Some("No active reply draft. Press 'a' to start drafting.".to_owned());
             return false;
@@ -245,146 +349,36 @@ impl ReviewApp {
 
         true
     }
-
-    fn request_ai_rewrite(&mut self, mode: CommentRewriteMode) -> Option<Cmd> {
-        let Some(comment) = self.selected_comment().cloned() else {
-            self.error = Some("Reply drafting requires a selected comment".to_owned());
-            return None;
-        };
-
-        if !self.ensure_active_reply_draft_matches_comment(comment.id) {
-            return None;
-        }
-
-        let Some(draft) = self.active_reply_draft_mut(comment.id) else {
-            self.error = Some("No active reply draft. Press 'a' to start drafting.".to_owned());
-            return None;
-        };
-
-        let source_text = draft.text().to_owned();
-        if source_text.trim().is_empty() {
-            self.error = Some("Reply draft is empty; type text before AI rewrite.".to_owned());
-            return None;
-        }
-
-        let request =
-            CommentRewriteRequest::new(mode, source_text, CommentRewriteContext::from(&comment));
-        let request_id = self.next_ai_rewrite_request_id;
-        self.next_ai_rewrite_request_id = self.next_ai_rewrite_request_id.saturating_add(1);
-
-        self.reply_draft_ai_preview = None;
-        self.in_flight_ai_rewrite_request_id = Some(request_id);
-        self.error = None;
-        Some(spawn_ai_rewrite_request(
-            Arc::clone(&self.comment_rewrite_service),
-            request,
-            mode,
-            request_id,
-        ))
-    }
-
-    fn handle_ai_rewrite_ready(
-        &mut self,
-        request_id: u64,
-        mode: CommentRewriteMode,
-        outcome: &CommentRewriteOutcome,
-    ) {
-        if self.in_flight_ai_rewrite_request_id != Some(request_id) {
-            return;
-        }
-        self.in_flight_ai_rewrite_request_id = None;
-
-        match outcome {
-            CommentRewriteOutcome::Generated(generated) => {
-                let Some(comment_id) = self.selected_comment().map(|comment| comment.id) else {
-                    self.error = Some("Reply drafting requires a selected comment".to_owned());
-                    return;
-                };
-
-                if !self.ensure_active_reply_draft_matches_comment(comment_id) {
-                    return;
-                }
-
-                let Some(draft) = self.active_reply_draft_mut(comment_id) else {
-                    self.error =
-                        Some("No active reply draft. Press 'a' to start drafting.".to_owned());
-                    return;
-                };
-
-                let preview = build_side_by_side_diff_preview(
-                    draft.text(),
-                    generated.rewritten_text.as_str(),
-                );
-                self.reply_draft_ai_preview = Some(ReplyDraftAiPreview {
-                    mode,
-                    rewritten_text: generated.rewritten_text.clone(),
-                    origin_label: generated.origin_label.clone(),
-                    side_by_side_preview: preview,
-                });
-                self.error = None;
-            }
-            CommentRewriteOutcome::Fallback(fallback) => {
-                self.reply_draft_ai_preview = None;
-                self.error = Some(fallback.reason.clone());
-            }
-        }
-    }
-
-    fn apply_ai_rewrite_preview(&mut self) {
-        let Some(preview) = self.reply_draft_ai_preview.clone() else {
-            self.error = Some("No AI rewrite preview to apply.".to_owned());
-            return;
-        };
-
-        match self.get_active_draft_for_editing() {
-            Ok(draft) => {
-                if let Err(error) =
-                    draft.replace_text(preview.rewritten_text.as_str(), Some(preview.origin_label))
-                {
-                    self.error = Some(error.to_string());
-                    return;
-                }
-
-                self.reply_draft_ai_preview = None;
-                self.error = None;
-            }
-            Err(error) => {
-                self.error = Some(error);
-            }
-        }
-    }
-
-    fn discard_ai_rewrite_preview(&mut self) {
-        self.reply_draft_ai_preview = None;
-        self.error = None;
-    }
 }
 
-fn spawn_ai_rewrite_request(
-    service: Arc<dyn CommentRewriteService>,
-    request: CommentRewriteRequest,
-    mode: CommentRewriteMode,
-    request_id: u64,
-) -> Cmd {
-    let original_text = request.source_text().to_owned();
+/// Spawns an async task posting the reply.
+///
+/// When posting fails transiently and an outbox is configured, the reply is
+/// queued for replay instead of being reported as failed.
+fn spawn_reply_send(comment_id: u64, body: String, outbox: Option<OutboxTarget>) -> Cmd {
     Box::pin(async move {
-        let outcome = match tokio::task::spawn_blocking(move || {
-            rewrite_with_fallback(service.as_ref(), &request)
-        })
-        .await
-        {
-            Ok(outcome) => outcome,
-            Err(error) => CommentRewriteOutcome::fallback(
-                original_text,
-                format!("AI rewrite task failed: {error}"),
-            ),
+        let msg = match crate::tui::post_review_reply(comment_id, body.clone()).await {
+            Ok(reply) => AppMsg::ReplyDraftSent {
+                comment_id,
+                reply: Box::new(reply),
+            },
+            Err(error) => {
+                let intent = WriteIntent::Reply { comment_id, body };
+                outbox
+                    .and_then(|target| target.queue_after_failure(&intent, &error))
+                    .map_or_else(
+                        || AppMsg::ReplyDraftSendFailed {
+                            comment_id,
+                            message: error.to_string(),
+                        },
+                        |intent_id| AppMsg::ReplyDraftQueued {
+                            comment_id,
+                            intent_id,
+                        },
+                    )
+            }
         };
-
-        Some(Box::new(AppMsg::ReplyDraftAiRewriteReady {
-            request_id,
-            mode,
-            outcome,
-        }) as Box<dyn Any + Send>)
+        Some(Box::new(msg) as Box<dyn Any + Send>)
     })
 }
 
@@ -9,6 +9,7 @@ use crate::ai::CommentRewriteMode;
 use crate::ai::comment_rewrite::test_support::StubCommentRewriteService;
 use crate::github::IntakeError;
 use crate::github::models::ReviewComment;
+use crate::tui::app::ViewMode;
 use crate::tui::messages::AppMsg;
 use crate::tui::{ReplyDraftConfig, ReplyDraftMaxLength};
 
@@ -234,5 +235,106 @@ fn cancel_reply_draft_discards_state(sample_reviews: Vec<ReviewComment>) {
     assert!(app.error_message().is_none());
 }
 
+#[rstest]
+fn request_send_returns_post_command(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('o'));
+    let cmd = app.handle_message(&AppMsg::ReplyDraftRequestSend);
+
+    assert!(cmd.is_some(), "valid draft should spawn a send command");
+    assert_eq!(app.in_flight_reply_send, Some(1));
+}
+
+#[rstest]
+fn request_send_rejects_duplicate_while_in_flight(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('o'));
+    app.handle_message(&AppMsg::ReplyDraftRequestSend);
+    let second = app.handle_message(&AppMsg::ReplyDraftRequestSend);
+
+    assert!(second.is_none(), "second send should not spawn a command");
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("already being sent"));
+}
+
+#[rstest]
+fn reply_sent_merges_reply_and_clears_draft(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('o'));
+    app.handle_message(&AppMsg::ReplyDraftRequestSend);
+    app.handle_message(&AppMsg::ReplyDraftSent {
+        comment_id: 1,
+        reply: Box::new(ReviewComment {
+            id: 10,
+            body: Some("o".to_owned()),
+            in_reply_to_id: Some(1),
+            ..ReviewComment::default()
+        }),
+    });
+
+    assert!(app.reply_draft.is_none());
+    assert!(app.in_flight_reply_send.is_none());
+    assert!(app.error_message().is_none());
+    assert!(
+        app.reviews
+            .iter()
+            .any(|review| review.id == 10 && review.in_reply_to_id == Some(1))
+    );
+}
+
+#[rstest]
+fn reply_sent_is_delivered_in_summary_view(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('o'));
+    app.handle_message(&AppMsg::ReplyDraftRequestSend);
+    app.view_mode = ViewMode::PrDiscussionSummary;
+    app.handle_message(&AppMsg::ReplyDraftSent {
+        comment_id: 1,
+        reply: Box::new(ReviewComment {
+            id: 10,
+            in_reply_to_id: Some(1),
+            ..ReviewComment::default()
+        }),
+    });
+
+    assert!(app.in_flight_reply_send.is_none());
+    assert!(app.reply_draft.is_none());
+}
+
+#[rstest]
+fn reply_send_failure_keeps_draft(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::StartReplyDraft);
+    app.handle_message(&AppMsg::ReplyDraftInsertChar('o'));
+    app.handle_message(&AppMsg::ReplyDraftRequestSend);
+    app.handle_message(&AppMsg::ReplyDraftSendFailed {
+        comment_id: 1,
+        message: IntakeError::Network {
+            message: "connection reset".to_owned(),
+        }
+        .to_string(),
+    });
+
+    assert_eq!(
+        app.reply_draft
+            .as_ref()
+            .map(|draft| draft.text().to_owned()),
+        Some("o".to_owned())
+    );
+    assert!(app.in_flight_reply_send.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Failed to send reply"));
+    assert!(error.contains("connection reset"));
+}
+
 #[path = "reply_draft_handlers_tests_ai.rs"]
 mod ai_rewrite;
@@ -0,0 +1,84 @@
+//! Handlers merging review comment pages that arrive after startup.
+//!
+//! Large pull requests open with their first page of comments; the rest are
+//! polled from a [`ReviewCommentStream`](crate::tui::ReviewCommentStream)
+//! and merged as they arrive, so the list fills in progressively.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bubbletea_rs::Cmd;
+
+use super::ReviewApp;
+use crate::github::error::IntakeError;
+use crate::github::models::ReviewComment;
+use crate::tui::ReviewStreamUpdate;
+use crate::tui::messages::AppMsg;
+
+/// Interval between polls for review comment pages.
+const REVIEW_STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+impl ReviewApp {
+    /// Merges every review comment page received since the last poll.
+    ///
+    /// Keeps polling until the listing finishes, then applies the complete
+    /// listing, which also carries thread state.
+    pub(super) fn handle_review_stream_poll_tick(&mut self) -> Option<Cmd> {
+        let mut stream = self.review_stream.take()?;
+        while let Some(update) = stream.try_next_update() {
+            match update {
+                ReviewStreamUpdate::Page(page) => self.merge_review_page(page),
+                ReviewStreamUpdate::Finished(result) => {
+                    self.finish_review_stream(result);
+                    return None;
+                }
+            }
+        }
+
+        self.review_stream = Some(stream);
+        Some(Self::arm_review_stream_poll_timer())
+    }
+
+    /// Updates comments already shown from `page` and appends the rest.
+    fn merge_review_page(&mut self, page: Vec<ReviewComment>) {
+        let mut reviews = self.reviews.clone();
+        let positions: HashMap<u64, usize> = reviews
+            .iter()
+            .enumerate()
+            .map(|(index, comment)| (comment.id, index))
+            .collect();
+        for comment in page {
+            match positions
+                .get(&comment.id)
+                .and_then(|&index| reviews.get_mut(index))
+            {
+                Some(known) => *known = comment,
+                None => reviews.push(comment),
+            }
+        }
+        self.replace_reviews_in_place(&reviews);
+    }
+
+    fn finish_review_stream(&mut self, result: Result<Vec<ReviewComment>, IntakeError>) {
+        match result {
+            Ok(reviews) => self.apply_new_reviews(&reviews),
+            Err(error) => {
+                self.loading = false;
+                self.error = Some(format!("Failed to load all review comments: {error}"));
+            }
+        }
+    }
+
+    /// Creates a command that polls for review comment pages shortly.
+    pub(super) fn arm_review_stream_poll_timer() -> Cmd {
+        Box::pin(async {
+            tokio::time::sleep(REVIEW_STREAM_POLL_INTERVAL).await;
+            Some(Box::new(AppMsg::ReviewStreamPollTick) as Box<dyn Any + Send>)
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "review_stream_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,137 @@
+//! Tests for merging review comment pages loaded after startup.
+
+use rstest::{fixture, rstest};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+use crate::github::error::IntakeError;
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::tui::messages::AppMsg;
+use crate::tui::{ReviewCommentStream, ReviewStreamUpdate};
+
+use super::ReviewApp;
+
+fn comment(id: u64, body: &str) -> ReviewComment {
+    ReviewComment {
+        id,
+        body: Some(body.to_owned()),
+        ..ReviewComment::default()
+    }
+}
+
+fn ids(app: &ReviewApp) -> Vec<u64> {
+    app.reviews.iter().map(|review| review.id).collect()
+}
+
+#[fixture]
+fn streaming_app() -> (ReviewApp, UnboundedSender<ReviewStreamUpdate>) {
+    let (sender, receiver) = unbounded_channel();
+    let app = ReviewApp::with_dimensions(vec![comment(1, "First")], 80, 24)
+        .with_review_stream(ReviewCommentStream::new(receiver));
+    (app, sender)
+}
+
+#[rstest]
+fn pages_are_merged_while_loading(streaming_app: (ReviewApp, UnboundedSender<ReviewStreamUpdate>)) {
+    let (mut app, sender) = streaming_app;
+    assert!(app.loading);
+
+    sender
+        .send(ReviewStreamUpdate::Page(vec![comment(2, "Second")]))
+        .expect("stream should be open");
+    sender
+        .send(ReviewStreamUpdate::Page(vec![
+            comment(1, "First, edited"),
+            comment(3, "Third"),
+        ]))
+        .expect("stream should be open");
+    let cmd = app.handle_message(&AppMsg::ReviewStreamPollTick);
+
+    assert!(
+        cmd.is_some(),
+        "polling should continue until the listing ends"
+    );
+    assert!(app.loading);
+    assert_eq!(ids(&app), vec![1, 2, 3]);
+    assert_eq!(
+        app.reviews
+            .first()
+            .and_then(|review| review.body.as_deref()),
+        Some("First, edited")
+    );
+    assert_eq!(app.selected_comment_id, Some(1));
+}
+
+#[rstest]
+fn finished_listing_replaces_pages_and_stops_polling(
+    streaming_app: (ReviewApp, UnboundedSender<ReviewStreamUpdate>),
+) {
+    let (mut app, sender) = streaming_app;
+    let mut resolved = comment(1, "First");
+    resolved.thread_state = Some(ReviewThreadState {
+        thread_id: "PRRT_1".to_owned(),
+        is_resolved: true,
+        is_outdated: false,
+        resolved_by: None,
+    });
+
+    sender
+        .send(ReviewStreamUpdate::Finished(Ok(vec![
+            resolved,
+            comment(2, "Second"),
+        ])))
+        .expect("stream should be open");
+    let cmd = app.handle_message(&AppMsg::ReviewStreamPollTick);
+
+    assert!(cmd.is_none());
+    assert!(!app.loading);
+    assert_eq!(ids(&app), vec![1, 2]);
+    assert!(
+        app.reviews
+            .first()
+            .is_some_and(|review| review.thread_state.is_some())
+    );
+    assert!(
+        app.handle_message(&AppMsg::ReviewStreamPollTick).is_none(),
+        "no stream should remain to poll"
+    );
+}
+
+#[rstest]
+fn failed_listing_keeps_loaded_pages_and_reports_error(
+    streaming_app: (ReviewApp, UnboundedSender<ReviewStreamUpdate>),
+) {
+    let (mut app, sender) = streaming_app;
+
+    sender
+        .send(ReviewStreamUpdate::Finished(Err(IntakeError::Api {
+            message: "boom".to_owned(),
+        })))
+        .expect("stream should be open");
+    app.handle_message(&AppMsg::ReviewStreamPollTick);
+
+    assert!(!app.loading);
+    assert_eq!(ids(&app), vec![1]);
+    let error = app.error.as_deref().expect("error should be shown");
+    assert!(
+        error.starts_with("Failed to load all review comments:"),
+        "{error}"
+    );
+}
+
+#[rstest]
+fn dropped_listing_is_reported_as_failed(
+    streaming_app: (ReviewApp, UnboundedSender<ReviewStreamUpdate>),
+) {
+    let (mut app, sender) = streaming_app;
+    drop(sender);
+
+    let cmd = app.handle_message(&AppMsg::ReviewStreamPollTick);
+
+    assert!(cmd.is_none());
+    assert!(!app.loading);
+    assert!(
+        app.error
+            .as_deref()
+            .is_some_and(|error| error.contains("stopped unexpectedly"))
+    );
+}
@@ -0,0 +1,184 @@
+//! Pending review composition and submission handlers.
+//!
+//! Reply drafts and comments written in the diff context view can be queued
+//! as inline comments on a pending review. The composer view collects those comments with a summary body and review
+//! event, asks for confirmation, and submits everything to GitHub as a
+//! single review.
+
+use std::any::Any;
+
+use bubbletea_rs::Cmd;
+
+use crate::github::review_submission::{PendingReviewComment, ReviewSubmission};
+use crate::tui::messages::AppMsg;
+use crate::tui::state::ReviewComposerState;
+
+use super::{ReviewApp, ViewMode};
+
+impl ReviewApp {
+    /// Handles pending review composition and submission messages.
+    pub(super) fn handle_review_submission_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowReviewComposer => {
+                self.show_review_composer();
+                None
+            }
+            AppMsg::HideReviewComposer => {
+                self.hide_review_composer();
+                None
+            }
+            AppMsg::QueueReplyDraftForReview => {
+                self.queue_reply_draft_for_review();
+                None
+            }
+            AppMsg::ReviewComposerConfirmSubmit => self.confirm_review_submission(),
+            AppMsg::ReviewSubmitted { .. } => self.handle_review_submitted(),
+            AppMsg::ReviewSubmissionFailed { message } => {
+                self.handle_review_submission_failed(message);
+                None
+            }
+            _ => {
+                self.edit_review_composer(msg);
+                None
+            }
+        }
+    }
+
+    fn show_review_composer(&mut self) {
+        self.view_mode = ViewMode::ReviewSubmission;
+        self.error = None;
+    }
+
+    fn hide_review_composer(&mut self) {
+        self.review_composer.cancel_confirm();
+        self.view_mode = ViewMode::ReviewList;
+        self.error = None;
+    }
+
+    fn edit_review_composer(&mut self, msg: &AppMsg) {
+        if self.review_composer.is_submitting() {
+            self.error = Some("The review is being submitted; wait for it to finish.".to_owned());
+            return;
+        }
+
+        match msg {
+            AppMsg::ReviewComposerInsertChar(character) => {
+                self.review_composer.push_char(*character);
+            }
+            AppMsg::ReviewComposerBackspace => self.review_composer.backspace(),
+            AppMsg::ReviewComposerCycleEvent => self.review_composer.cycle_event(),
+            AppMsg::ReviewComposerDropLastComment => {
+                let dropped = self.review_composer.drop_last_comment();
+                if let Some(draft_id) = dropped.and_then(|queued| queued.draft_id) {
+                    self.error = self.delete_pending_comment_drafts(&[draft_id]);
+                    return;
+                }
+            }
+            AppMsg::ReviewComposerRequestSubmit => {
+                if let Err(error) = self.review_composer.request_confirm() {
+                    self.error = Some(error.to_string());
+                    return;
+                }
+            }
+            AppMsg::ReviewComposerCancelConfirm => self.review_composer.cancel_confirm(),
+            _ => {}
+        }
+        self.error = None;
+    }
+
+    /// Moves the active reply draft into the pending review as an inline
+    /// comment anchored to the selected comment's file and line.
+    fn queue_reply_draft_for_review(&mut self) {
+        let Some(comment) = self.selected_comment() else {
+            self.error = Some("Queueing a review comment requires a selected comment".to_owned());
+            return;
+        };
+        let (Some(path), Some(line)) = (comment.file_path.clone(), comment.line_number) else {
+            self.error = Some(
+                "Selected comment has no file line; it cannot anchor an inline review comment."
+                    .to_owned(),
+            );
+            return;
+        };
+        let comment_id = comment.id;
+        let side = comment.side.unwrap_or_default();
+
+        let Some(draft) = self
+            .reply_draft
+            .as_ref()
+            .filter(|draft| draft.comment_id() == comment_id)
+        else {
+            self.error = Some("No active reply draft. Press 'a' to start drafting.".to_owned());
+            return;
+        };
+        if draft.edits_comment() {
+            self.error =
+                Some("An edit cannot be queued on the review; press Enter to save it.".to_owned());
+            return;
+        }
+        if draft.text().trim().is_empty() {
+            self.error = Some("Reply draft is empty; type text before queueing it.".to_owned());
+            return;
+        }
+
+        let pending = PendingReviewComment::new(path, line, draft.text().trim()).with_side(side);
+        self.review_composer.queue_comment(pending);
+        self.reply_draft = None;
+        self.reply_draft_ai_preview = None;
+        self.in_flight_ai_rewrite_request_id = None;
+        self.error = None;
+    }
+
+    fn confirm_review_submission(&mut self) -> Option<Cmd> {
+        if self.review_composer.is_submitting() {
+            self.error = Some("The review is already being submitted.".to_owned());
+            return None;
+        }
+        if !self.review_composer.is_confirming() {
+            self.error = Some("Press Enter to review the submission before confirming.".to_owned());
+            return None;
+        }
+
+        self.review_composer.set_submitting(true);
+        self.error = None;
+        Some(spawn_review_submission(
+            self.review_composer.to_submission(),
+        ))
+    }
+
+    /// Clears the composer and its stored drafts after a successful
+    /// submission and refreshes the review list so the new review's comments
+    /// appear.
+    fn handle_review_submitted(&mut self) -> Option<Cmd> {
+        let saved_draft_ids = self.review_composer.saved_draft_ids();
+        self.review_composer = ReviewComposerState::default();
+        if self.view_mode == ViewMode::ReviewSubmission {
+            self.view_mode = ViewMode::ReviewList;
+        }
+        self.error = self.delete_pending_comment_drafts(&saved_draft_ids);
+        self.handle_refresh_requested()
+    }
+
+    fn handle_review_submission_failed(&mut self, message: &str) {
+        self.review_composer.set_submitting(false);
+        self.error = Some(format!(
+            "Failed to submit review: {message}. Review kept; press Enter to retry."
+        ));
+    }
+}
+
+fn spawn_review_submission(submission: ReviewSubmission) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::submit_pull_request_review(submission).await {
+            Ok(review) => AppMsg::ReviewSubmitted { review },
+            Err(error) => AppMsg::ReviewSubmissionFailed {
+                message: error.to_string(),
+            },
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+#[cfg(test)]
+#[path = "review_submission_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,175 @@
+//! Tests for pending review composition and submission handlers.
+
+use rstest::{fixture, rstest};
+
+use crate::github::models::ReviewComment;
+use crate::github::review_submission::{DiffSide, ReviewEvent, SubmittedReview};
+use crate::tui::app::ViewMode;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+#[fixture]
+fn inline_reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            id: 1,
+            body: Some("Consider extracting this".to_owned()),
+            file_path: Some("src/lib.rs".to_owned()),
+            line_number: Some(14),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 2,
+            body: Some("General remark".to_owned()),
+            ..ReviewComment::default()
+        },
+    ]
+}
+
+fn type_text(app: &mut ReviewApp, text: &str, to_msg: fn(char) -> AppMsg) {
+    for character in text.chars() {
+        app.handle_message(&to_msg(character));
+    }
+}
+
+#[rstest]
+fn reply_draft_is_queued_as_inline_review_comment(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::StartReplyDraft);
+    type_text(&mut app, "Extract it", AppMsg::ReplyDraftInsertChar);
+
+    app.handle_message(&AppMsg::QueueReplyDraftForReview);
+
+    assert!(app.reply_draft.is_none(), "draft should move to the review");
+    let pending = app.review_composer.pending_comments();
+    assert_eq!(pending.len(), 1);
+    let queued = pending.first().expect("comment should be queued");
+    assert_eq!(queued.comment.path, "src/lib.rs");
+    assert_eq!(queued.comment.line, 14);
+    assert_eq!(queued.comment.body, "Extract it");
+}
+
+#[rstest]
+#[case::left(Some(DiffSide::Left), DiffSide::Left)]
+#[case::right(Some(DiffSide::Right), DiffSide::Right)]
+#[case::unknown(None, DiffSide::Right)]
+fn queued_comment_keeps_the_source_comment_side(
+    mut inline_reviews: Vec<ReviewComment>,
+    #[case] side: Option<DiffSide>,
+    #[case] expected: DiffSide,
+) {
+    if let Some(first) = inline_reviews.first_mut() {
+        first.side = side;
+    }
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::StartReplyDraft);
+    type_text(&mut app, "Restore it", AppMsg::ReplyDraftInsertChar);
+
+    app.handle_message(&AppMsg::QueueReplyDraftForReview);
+
+    let queued = app
+        .review_composer
+        .pending_comments()
+        .first()
+        .expect("comment should be queued");
+    assert_eq!(queued.comment.side, expected);
+}
+
+#[rstest]
+fn queueing_requires_a_file_line(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::CursorDown);
+    app.handle_message(&AppMsg::StartReplyDraft);
+    type_text(&mut app, "Note", AppMsg::ReplyDraftInsertChar);
+
+    app.handle_message(&AppMsg::QueueReplyDraftForReview);
+
+    assert!(app.reply_draft.is_some(), "draft should be kept");
+    assert!(app.review_composer.pending_comments().is_empty());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("no file line"), "unexpected: {error}");
+}
+
+#[rstest]
+fn submit_requires_confirmation_before_spawning(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+    app.handle_message(&AppMsg::ReviewComposerCycleEvent);
+    assert_eq!(app.review_composer.event(), ReviewEvent::Approve);
+
+    let early = app.handle_message(&AppMsg::ReviewComposerConfirmSubmit);
+    assert!(early.is_none(), "confirming before Enter should not submit");
+
+    app.handle_message(&AppMsg::ReviewComposerRequestSubmit);
+    assert!(app.review_composer.is_confirming());
+    let cmd = app.handle_message(&AppMsg::ReviewComposerConfirmSubmit);
+
+    assert!(cmd.is_some(), "confirmed review should be submitted");
+    assert!(app.review_composer.is_submitting());
+}
+
+#[rstest]
+fn request_changes_without_summary_is_rejected(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+    app.handle_message(&AppMsg::ReviewComposerCycleEvent);
+    app.handle_message(&AppMsg::ReviewComposerCycleEvent);
+
+    app.handle_message(&AppMsg::ReviewComposerRequestSubmit);
+
+    assert!(!app.review_composer.is_confirming());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("requires a review summary"), "{error}");
+}
+
+#[rstest]
+fn composer_consumes_navigation_keys(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+
+    app.handle_message(&AppMsg::CursorDown);
+
+    assert_eq!(app.cursor_position(), 0);
+    assert_eq!(app.view_mode, ViewMode::ReviewSubmission);
+}
+
+#[rstest]
+fn submitted_review_clears_composer_and_returns_to_list(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+    type_text(&mut app, "LGTM", AppMsg::ReviewComposerInsertChar);
+    app.handle_message(&AppMsg::ReviewComposerRequestSubmit);
+    app.handle_message(&AppMsg::ReviewComposerConfirmSubmit);
+
+    app.handle_message(&AppMsg::ReviewSubmitted {
+        review: SubmittedReview {
+            id: 80,
+            state: Some("COMMENTED".to_owned()),
+            html_url: None,
+        },
+    });
+
+    assert!(!app.review_composer.is_submitting());
+    assert_eq!(app.view_mode, ViewMode::ReviewList);
+    assert!(app.review_composer.body().is_empty());
+}
+
+#[rstest]
+fn failed_submission_keeps_composer(inline_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(inline_reviews);
+    app.handle_message(&AppMsg::ShowReviewComposer);
+    type_text(&mut app, "LGTM", AppMsg::ReviewComposerInsertChar);
+    app.handle_message(&AppMsg::ReviewComposerRequestSubmit);
+    app.handle_message(&AppMsg::ReviewComposerConfirmSubmit);
+
+    app.handle_message(&AppMsg::ReviewSubmissionFailed {
+        message: "Validation Failed".to_owned(),
+    });
+
+    assert!(!app.review_composer.is_submitting());
+    assert_eq!(app.view_mode, ViewMode::ReviewSubmission);
+    assert_eq!(app.review_composer.body(), "LGTM");
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Failed to submit review"), "{error}");
+}
@@ -56,14 +56,28 @@ impl ReviewApp {
     /// Checks if a message should be blocked when in PR discussion summary mode.
     ///
     /// PR discussion summary mode blocks filter, diff-context, time-travel,
-    /// reply-draft, and verification messages to prevent interference with
-    /// the summary view state.
+    /// reply-draft, verification, thread-resolution, reaction, suggestion,
+    /// comment edit and delete, and review-composer requests to prevent
+    /// interference with the summary view state. Reply send, reaction, comment
+    /// edit and delete, and review submission results are still delivered so
+    /// that in-flight requests started before opening the summary complete.
     pub(super) const fn is_blocked_in_pr_discussion_summary(msg: &AppMsg) -> bool {
         msg.is_filter()
             || msg.is_diff_context()
             || msg.is_time_travel()
-            || msg.is_reply_draft()
+            || (msg.is_reply_draft() && !is_reply_send_result(msg))
             || msg.is_verification()
+            || msg.is_suggestion()
+            || matches!(
+                msg,
+                AppMsg::ToggleThreadResolution
+                    | AppMsg::ShowReactionPicker
+                    | AppMsg::ToggleReaction(_)
+                    | AppMsg::StartCommentEdit
+                    | AppMsg::RequestCommentDelete
+                    | AppMsg::ConfirmCommentDelete
+            )
+            || (msg.is_review_submission() && !is_review_submission_result(msg))
     }
 
     /// Returns `true` when the current view is `ReviewList` and the message is
@@ -107,6 +121,26 @@ impl ReviewApp {
             return MessageRouting::Handled(result);
         }
 
+        if let MessageRouting::Handled(result) = self.try_handle_in_review_submission_mode(msg) {
+            return MessageRouting::Handled(result);
+        }
+
+        if let MessageRouting::Handled(result) = self.try_handle_in_pull_request_reviews_mode(msg) {
+            return MessageRouting::Handled(result);
+        }
+
+        if let MessageRouting::Handled(result) = self.try_handle_in_timeline_mode(msg) {
+            return MessageRouting::Handled(result);
+        }
+
+        if let MessageRouting::Handled(result) = self.try_handle_in_ci_checks_mode(msg) {
+            return MessageRouting::Handled(result);
+        }
+
+        if let MessageRouting::Handled(result) = self.try_handle_in_outbox_mode(msg) {
+            return MessageRouting::Handled(result);
+        }
+
         // Route TimeTravel mode messages first (takes priority)
         if let MessageRouting::Handled(result) = self.try_handle_in_time_travel_mode(msg) {
             return MessageRouting::Handled(result);
@@ -139,6 +173,15 @@ impl ReviewApp {
             MessageCategory::Codex => self.handle_codex_msg(msg),
             MessageCategory::ReplyDraft => self.handle_reply_draft_msg(msg),
             MessageCategory::Verification => self.handle_verification_msg(msg),
+            MessageCategory::ThreadResolution => self.handle_thread_resolution_msg(msg),
+            MessageCategory::Reactions => self.handle_reaction_msg(msg),
+            MessageCategory::Suggestions => self.handle_suggestion_msg(msg),
+            MessageCategory::CommentEdits => self.handle_comment_edit_msg(msg),
+            MessageCategory::ReviewSubmission => self.handle_review_submission_msg(msg),
+            MessageCategory::PullRequestReviews => self.handle_pull_request_reviews_msg(msg),
+            MessageCategory::Timeline => self.handle_timeline_msg(msg),
+            MessageCategory::CiChecks => self.handle_ci_checks_msg(msg),
+            MessageCategory::Outbox => self.handle_outbox_msg(msg),
             MessageCategory::Data => self.handle_data_msg(msg),
             MessageCategory::Lifecycle => self.handle_lifecycle_msg(msg),
         }
@@ -167,4 +210,162 @@ impl ReviewApp {
 
         MessageRouting::Fallthrough
     }
+
+    /// Routes messages when in `ReviewSubmission` mode.
+    ///
+    /// The composer owns the keyboard, so list navigation and the actions
+    /// blocked in the summary view are ignored until it is closed.
+    pub(super) fn try_handle_in_review_submission_mode(&mut self, msg: &AppMsg) -> MessageRouting {
+        if self.view_mode != ViewMode::ReviewSubmission {
+            return MessageRouting::Fallthrough;
+        }
+
+        if msg.is_review_submission() {
+            return MessageRouting::Handled(self.handle_review_submission_msg(msg));
+        }
+
+        if msg.is_navigation() || Self::is_blocked_in_pr_discussion_summary(msg) {
+            return MessageRouting::Handled(None);
+        }
+
+        MessageRouting::Fallthrough
+    }
+
+    /// Routes messages when in `PullRequestReviews` mode.
+    ///
+    /// List navigation moves between reviews; the actions blocked in the
+    /// summary view are ignored until the view is closed.
+    pub(super) fn try_handle_in_pull_request_reviews_mode(
+        &mut self,
+        msg: &AppMsg,
+    ) -> MessageRouting {
+        if self.view_mode != ViewMode::PullRequestReviews {
+            return MessageRouting::Fallthrough;
+        }
+
+        if msg.is_pull_request_reviews() {
+            return MessageRouting::Handled(self.handle_pull_request_reviews_msg(msg));
+        }
+
+        if matches!(msg, AppMsg::EscapePressed) {
+            return MessageRouting::Handled(
+                self.handle_pull_request_reviews_msg(&AppMsg::HidePullRequestReviews),
+            );
+        }
+
+        if msg.is_navigation() {
+            return MessageRouting::Handled(self.handle_pull_request_reviews_navigation(msg));
+        }
+
+        if Self::is_blocked_in_pr_discussion_summary(msg) {
+            return MessageRouting::Handled(None);
+        }
+
+        MessageRouting::Fallthrough
+    }
+
+    /// Routes messages when in `Timeline` mode.
+    ///
+    /// List navigation moves between timeline entries and filter messages
+    /// change which entries are shown; the other actions blocked in the
+    /// summary view are ignored until the view is closed.
+    pub(super) fn try_handle_in_timeline_mode(&mut self, msg: &AppMsg) -> MessageRouting {
+        if self.view_mode != ViewMode::Timeline {
+            return MessageRouting::Fallthrough;
+        }
+
+        if msg.is_timeline() {
+            return MessageRouting::Handled(self.handle_timeline_msg(msg));
+        }
+
+        if matches!(msg, AppMsg::EscapePressed) {
+            return MessageRouting::Handled(self.handle_timeline_msg(&AppMsg::HideTimeline));
+        }
+
+        if msg.is_navigation() {
+            return MessageRouting::Handled(self.handle_timeline_navigation(msg));
+        }
+
+        if msg.is_filter() {
+            return MessageRouting::Handled(self.handle_timeline_filter(msg));
+        }
+
+        if Self::is_blocked_in_pr_discussion_summary(msg) {
+            return MessageRouting::Handled(None);
+        }
+
+        MessageRouting::Fallthrough
+    }
+
+    /// Routes messages when in `CiChecks` mode.
+    ///
+    /// List navigation moves between failing checks; the actions blocked in
+    /// the summary view are ignored until the view is closed.
+    pub(super) fn try_handle_in_ci_checks_mode(&mut self, msg: &AppMsg) -> MessageRouting {
+        if self.view_mode != ViewMode::CiChecks {
+            return MessageRouting::Fallthrough;
+        }
+
+        if msg.is_ci_checks() {
+            return MessageRouting::Handled(self.handle_ci_checks_msg(msg));
+        }
+
+        if matches!(msg, AppMsg::EscapePressed) {
+            return MessageRouting::Handled(self.handle_ci_checks_msg(&AppMsg::HideCiChecks));
+        }
+
+        if msg.is_navigation() {
+            return MessageRouting::Handled(self.handle_ci_checks_navigation(msg));
+        }
+
+        if Self::is_blocked_in_pr_discussion_summary(msg) {
+            return MessageRouting::Handled(None);
+        }
+
+        MessageRouting::Fallthrough
+    }
+
+    /// Routes messages when in `Outbox` mode.
+    ///
+    /// List navigation moves between queued writes; the actions blocked in
+    /// the summary view are ignored until the view is closed.
+    pub(super) fn try_handle_in_outbox_mode(&mut self, msg: &AppMsg) -> MessageRouting {
+        if self.view_mode != ViewMode::Outbox {
+            return MessageRouting::Fallthrough;
+        }
+
+        if msg.is_outbox() {
+            return MessageRouting::Handled(self.handle_outbox_msg(msg));
+        }
+
+        if matches!(msg, AppMsg::EscapePressed) {
+            return MessageRouting::Handled(self.handle_outbox_msg(&AppMsg::HideOutbox));
+        }
+
+        if msg.is_navigation() {
+            return MessageRouting::Handled(self.handle_outbox_navigation(msg));
+        }
+
+        if Self::is_blocked_in_pr_discussion_summary(msg) {
+            return MessageRouting::Handled(None);
+        }
+
+        MessageRouting::Fallthrough
+    }
+}
+
+const fn is_reply_send_result(msg: &AppMsg) -> bool {
+    matches!(
+        msg,
+        AppMsg::ReplyDraftSent { .. }
+            | AppMsg::ReplyDraftSendFailed { .. }
+            | AppMsg::ReplyDraftQueued { .. }
+    )
+}
+
+const fn is_review_submission_result(msg: &AppMsg) -> bool {
+    matches!(
+        msg,
+        AppMsg::ReviewSubmitted { .. } | AppMsg::ReviewSubmissionFailed { .. }
+    )
 }
@@ -0,0 +1,74 @@
+//! Suggested-change handlers.
+//!
+//! Applies the selected comment's suggested change to the local working tree
+//! and keeps the outcome, including any conflict report, for the comment
+//! detail pane.
+
+use bubbletea_rs::Cmd;
+
+use crate::suggestion::{SuggestionError, parse_suggestions};
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+impl ReviewApp {
+    /// Handles suggested-change messages.
+    pub(super) fn handle_suggestion_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        if matches!(msg, AppMsg::ApplySuggestion) {
+            self.apply_selected_suggestion();
+        }
+        None
+    }
+
+    /// Returns the outcome of the last suggestion applied from `comment_id`.
+    pub(super) fn suggestion_report_for_comment(&self, comment_id: u64) -> Option<&str> {
+        self.suggestion_report
+            .as_ref()
+            .filter(|(report_comment_id, _)| *report_comment_id == comment_id)
+            .map(|(_, report)| report.as_str())
+    }
+
+    /// Applies the first suggested change in the selected comment.
+    fn apply_selected_suggestion(&mut self) {
+        let Some(comment) = self.selected_comment() else {
+            self.error = Some("Applying a suggestion requires a selected comment".to_owned());
+            return;
+        };
+        let comment_id = comment.id;
+        let Some(suggestion) = parse_suggestions(comment).into_iter().next() else {
+            self.error = Some("The selected comment has no suggested change".to_owned());
+            return;
+        };
+        let Some(applier) = self.suggestion_applier.as_ref() else {
+            self.error = Some(
+                "Applying suggestions requires a local repository (no git operations available)"
+                    .to_owned(),
+            );
+            return;
+        };
+
+        let report = match applier.apply_suggestion(&suggestion) {
+            Ok(()) => {
+                self.error = None;
+                format!("Applied to {}.", suggestion.location())
+            }
+            Err(error @ SuggestionError::Conflict(_)) => {
+                self.error = Some(format!(
+                    "Suggestion not applied: {} has changed",
+                    suggestion.location()
+                ));
+                error.to_string()
+            }
+            Err(error) => {
+                self.error = Some(format!("Failed to apply suggestion: {error}"));
+                self.suggestion_report = None;
+                return;
+            }
+        };
+        self.suggestion_report = Some((comment_id, report));
+    }
+}
+
+#[cfg(test)]
+#[path = "suggestion_handlers_tests.rs"]
+mod tests;
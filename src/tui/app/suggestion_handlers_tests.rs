@@ -0,0 +1,133 @@
+//! Tests for suggested-change handlers.
+
+use std::sync::{Arc, Mutex};
+
+use rstest::{fixture, rstest};
+
+use crate::github::models::ReviewComment;
+use crate::suggestion::{SuggestedChange, SuggestionApplier, SuggestionConflict, SuggestionError};
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+
+/// Applier that records suggestions and returns a scripted result.
+#[derive(Debug)]
+struct StubApplier {
+    result: Result<(), SuggestionError>,
+    applied: Mutex<Vec<SuggestedChange>>,
+}
+
+impl StubApplier {
+    fn returning(result: Result<(), SuggestionError>) -> Arc<Self> {
+        Arc::new(Self {
+            result,
+            applied: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn applied(&self) -> Vec<SuggestedChange> {
+        self.applied.lock().expect("lock should succeed").clone()
+    }
+}
+
+impl SuggestionApplier for StubApplier {
+    fn apply_suggestion(&self, change: &SuggestedChange) -> Result<(), SuggestionError> {
+        self.applied
+            .lock()
+            .expect("lock should succeed")
+            .push(change.clone());
+        self.result.clone()
+    }
+}
+
+#[fixture]
+fn reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            id: 1,
+            body: Some("```suggestion\nlet retries = 4;\n```".to_owned()),
+            file_path: Some("src/config.rs".to_owned()),
+            line_number: Some(2),
+            diff_hunk: Some("@@ -1,2 +1,2 @@\n fn config() {\n+let retries = 3;".to_owned()),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 2,
+            body: Some("Looks good".to_owned()),
+            file_path: Some("src/config.rs".to_owned()),
+            line_number: Some(1),
+            ..ReviewComment::default()
+        },
+    ]
+}
+
+#[rstest]
+fn applies_selected_suggestion(reviews: Vec<ReviewComment>) {
+    let applier = StubApplier::returning(Ok(()));
+    let mut app = ReviewApp::new(reviews).with_suggestion_applier(applier.clone());
+
+    app.handle_message(&AppMsg::ApplySuggestion);
+
+    let applied = applier.applied();
+    assert_eq!(applied.len(), 1);
+    assert_eq!(
+        applied.first().map(|change| change.replacement.clone()),
+        Some(vec!["let retries = 4;".to_owned()])
+    );
+    assert!(app.error_message().is_none());
+    assert_eq!(
+        app.suggestion_report_for_comment(1),
+        Some("Applied to src/config.rs:2.")
+    );
+    assert!(app.suggestion_report_for_comment(2).is_none());
+}
+
+#[rstest]
+fn conflict_keeps_report_for_detail_pane(reviews: Vec<ReviewComment>) {
+    let conflict = SuggestionConflict {
+        location: "src/config.rs:2".to_owned(),
+        expected: vec!["let retries = 3;".to_owned()],
+        found: vec!["let retries = 5;".to_owned()],
+    };
+    let applier = StubApplier::returning(Err(SuggestionError::Conflict(conflict.clone())));
+    let mut app = ReviewApp::new(reviews).with_suggestion_applier(applier);
+
+    app.handle_message(&AppMsg::ApplySuggestion);
+
+    let error = app.error_message().unwrap_or_default();
+    assert!(
+        error.contains("src/config.rs:2 has changed"),
+        "unexpected: {error}"
+    );
+    let expected_report = conflict.to_string();
+    assert_eq!(
+        app.suggestion_report_for_comment(1),
+        Some(expected_report.as_str())
+    );
+}
+
+#[rstest]
+fn comment_without_suggestion_is_rejected(reviews: Vec<ReviewComment>) {
+    let applier = StubApplier::returning(Ok(()));
+    let mut app = ReviewApp::new(reviews).with_suggestion_applier(applier.clone());
+
+    app.handle_message(&AppMsg::CursorDown);
+    app.handle_message(&AppMsg::ApplySuggestion);
+
+    assert!(applier.applied().is_empty());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("no suggested change"), "unexpected: {error}");
+}
+
+#[rstest]
+fn requires_local_repository(reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(reviews);
+
+    app.handle_message(&AppMsg::ApplySuggestion);
+
+    let error = app.error_message().unwrap_or_default();
+    assert!(
+        error.contains("requires a local repository"),
+        "unexpected: {error}"
+    );
+}
@@ -10,13 +10,28 @@ use std::time::Duration;
 use bubbletea_rs::Cmd;
 
 use super::ReviewApp;
-use crate::github::models::ReviewComment;
+use crate::github::models::{PullRequestComment, PullRequestReview, ReviewComment};
+use crate::github::pending_backoff;
+use crate::github::review_sync::ReviewSyncDelta;
 use crate::tui::app::ViewMode;
 use crate::tui::messages::AppMsg;
 
 /// Default interval between background syncs.
 pub(super) const SYNC_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Status message shown when a refresh is requested offline.
+const OFFLINE_REFRESH_MESSAGE: &str =
+    "Offline mode: showing stored comments; restart without --offline to refresh";
+
+/// Number of incremental syncs between full listings.
+///
+/// Reacting to a comment does not change its `updated_at`, so incremental
+/// syncs miss reactions; a periodic full listing picks them up.
+pub(super) const FULL_LISTING_INTERVAL: u32 = 10;
+
+/// Interval between rate-limit backoff polls while a sync is in flight.
+const BACKOFF_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 impl ReviewApp {
     /// Dispatches data loading and sync messages to their handlers.
     pub(super) fn handle_data_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
@@ -25,10 +40,29 @@ impl ReviewApp {
             AppMsg::RefreshComplete(new_reviews) => self.handle_refresh_complete(new_reviews),
             AppMsg::RefreshFailed(error_msg) => self.handle_refresh_failed(error_msg),
             AppMsg::SyncTick => self.handle_sync_tick(),
+            AppMsg::BackoffPollTick => self.handle_backoff_poll_tick(),
+            AppMsg::ReviewStreamPollTick => self.handle_review_stream_poll_tick(),
             AppMsg::SyncComplete {
                 reviews,
                 latency_ms,
             } => self.handle_sync_complete(reviews, *latency_ms),
+            AppMsg::SyncDeltaReceived { delta, latency_ms } => {
+                self.handle_sync_delta(delta, *latency_ms)
+            }
+            AppMsg::ConversationRefreshed { reviews, comments } => {
+                self.apply_conversation(reviews, comments);
+                None
+            }
+            AppMsg::CiStatusRefreshed(ci_status) => {
+                self.apply_ci_status(ci_status);
+                None
+            }
+            AppMsg::ConversationRefreshFailed(error_msg) => {
+                self.error = Some(format!(
+                    "Failed to refresh pull request reviews: {error_msg}"
+                ));
+                None
+            }
             _ => {
                 // Unreachable: caller filters to data messages.
                 None
@@ -39,10 +73,16 @@ impl ReviewApp {
     /// Handles a manual refresh request by delegating to sync logic.
     ///
     /// This ensures consistent behaviour between manual refresh and
-    /// background sync, including selection preservation.
+    /// background sync, including selection preservation. A manual refresh
+    /// always lists every comment so reaction counts are current. Offline,
+    /// the request is refused with a status message.
     pub(super) fn handle_refresh_requested(&mut self) -> Option<Cmd> {
-        // Delegate to sync tick for consistent behaviour
-        self.handle_sync_tick()
+        if self.is_offline() {
+            self.error = Some(OFFLINE_REFRESH_MESSAGE.to_owned());
+            return None;
+        }
+
+        Some(self.start_sync(true))
     }
 
     /// Applies new reviews with incremental merge and selection preservation.
@@ -83,6 +123,7 @@ impl ReviewApp {
 
         self.loading = false;
         self.error = None;
+        self.rate_limit_backoff = None;
     }
 
     /// Handles legacy refresh complete (for backward compatibility).
@@ -97,6 +138,7 @@ impl ReviewApp {
     )]
     pub(super) fn handle_refresh_failed(&mut self, error_msg: &str) -> Option<Cmd> {
         self.loading = false;
+        self.rate_limit_backoff = None;
         self.error = Some(error_msg.to_owned());
         // Re-arm the sync timer so that transient failures don't stop periodic sync
         Some(Self::arm_sync_timer())
@@ -104,46 +146,123 @@ impl ReviewApp {
 
     /// Handles a background sync timer tick.
     ///
-    /// Skips the sync if already loading to prevent duplicate requests.
-    /// Returns a command that fetches reviews and records timing.
-    #[expect(
-        clippy::unnecessary_wraps,
-        reason = "Returns Option<Cmd> for consistency with other message handlers"
-    )]
+    /// Skips the sync if already loading to prevent duplicate requests, and
+    /// stops the sync timer altogether when offline. Returns a command that
+    /// syncs reviews incrementally against the ones already loaded and
+    /// records timing, batched with reloads of the top-level reviews,
+    /// conversation comments, and CI status, and with a backoff poll so
+    /// rate-limit waits show up in the status bar.
+    ///
+    /// Once every [`FULL_LISTING_INTERVAL`] syncs every comment is listed
+    /// instead, picking up reactions that incremental syncs cannot see.
     pub(super) fn handle_sync_tick(&mut self) -> Option<Cmd> {
+        if self.is_offline() {
+            return None;
+        }
+
+        Some(self.start_sync(self.incremental_syncs.saturating_add(1) >= FULL_LISTING_INTERVAL))
+    }
+
+    /// Starts a sync unless one is already in flight.
+    fn start_sync(&mut self, full_listing: bool) -> Cmd {
         // Don't start new sync if already loading
         if self.loading {
-            return Some(Self::arm_sync_timer());
+            return Self::arm_sync_timer();
         }
 
         self.loading = true;
         self.error = None;
+        self.incremental_syncs = if full_listing {
+            0
+        } else {
+            self.incremental_syncs.saturating_add(1)
+        };
 
-        Some(Box::pin(async {
+        let known = self.reviews.clone();
+        let fetch: Cmd = Box::pin(async move {
             let start = std::time::Instant::now();
-            match crate::tui::fetch_reviews().await {
-                Ok(reviews) => {
+            match crate::tui::sync_reviews(&known, full_listing).await {
+                Ok(delta) => {
                     #[expect(
                         clippy::cast_possible_truncation,
                         reason = "Latency over u64::MAX milliseconds is unrealistic"
                     )]
                     let latency_ms = start.elapsed().as_millis() as u64;
-                    Some(Box::new(AppMsg::SyncComplete {
-                        reviews,
-                        latency_ms,
-                    }) as Box<dyn Any + Send>)
+                    Some(Box::new(AppMsg::SyncDeltaReceived { delta, latency_ms })
+                        as Box<dyn Any + Send>)
                 }
                 Err(error) => {
                     Some(Box::new(AppMsg::RefreshFailed(error.to_string())) as Box<dyn Any + Send>)
                 }
             }
-        }))
+        });
+        bubbletea_rs::batch(vec![
+            fetch,
+            Self::refresh_conversation(),
+            Self::refresh_ci_status(),
+            Self::arm_backoff_poll_timer(),
+        ])
+    }
+
+    /// Creates a command that reloads the top-level reviews and conversation
+    /// comments.
+    fn refresh_conversation() -> Cmd {
+        Box::pin(async {
+            let msg = match crate::tui::fetch_pull_request_conversation().await {
+                Ok((reviews, comments)) => AppMsg::ConversationRefreshed { reviews, comments },
+                Err(error) => AppMsg::ConversationRefreshFailed(error.to_string()),
+            };
+            Some(Box::new(msg) as Box<dyn Any + Send>)
+        })
+    }
+
+    /// Creates a command that reloads CI results for the pull request head.
+    ///
+    /// Failures are ignored: the header keeps the last known CI status.
+    fn refresh_ci_status() -> Cmd {
+        Box::pin(async {
+            crate::tui::fetch_ci_status().await.ok().map(|ci_status| {
+                Box::new(AppMsg::CiStatusRefreshed(ci_status)) as Box<dyn Any + Send>
+            })
+        })
+    }
+
+    /// Replaces the top-level reviews and conversation comments, keeping the
+    /// selections of their views within the new listings.
+    pub(super) fn apply_conversation(
+        &mut self,
+        reviews: &[PullRequestReview],
+        comments: &[PullRequestComment],
+    ) {
+        self.pull_request_reviews = reviews.to_vec();
+        self.issue_comments = comments.to_vec();
+        self.selected_pull_request_review = self
+            .selected_pull_request_review
+            .min(self.pull_request_reviews.len().saturating_sub(1));
+        self.selected_timeline_entry = self
+            .selected_timeline_entry
+            .min(self.timeline_entries().len().saturating_sub(1));
+    }
+
+    /// Refreshes the rate-limit backoff shown while a sync is in flight.
+    ///
+    /// Keeps polling until the sync completes or fails, so the status bar
+    /// countdown ticks down once per second.
+    pub(super) fn handle_backoff_poll_tick(&mut self) -> Option<Cmd> {
+        if !self.loading {
+            self.rate_limit_backoff = None;
+            return None;
+        }
+
+        self.rate_limit_backoff = pending_backoff();
+        Some(Self::arm_backoff_poll_timer())
     }
 
     /// Handles successful sync completion with incremental merge.
     ///
     /// Delegates to `apply_new_reviews` for the merge/selection logic,
-    /// then records telemetry and re-arms the sync timer.
+    /// then records telemetry and re-arms the sync timer. Writes queued in
+    /// the outbox are replayed now that GitHub has answered.
     #[expect(
         clippy::unnecessary_wraps,
         reason = "Returns Option<Cmd> for consistency with other message handlers"
@@ -158,8 +277,26 @@ impl ReviewApp {
         // Log telemetry
         crate::tui::record_sync_telemetry(latency_ms, self.reviews.len(), true);
 
-        // Re-arm sync timer
-        Some(Self::arm_sync_timer())
+        // Re-arm sync timer, replaying queued writes alongside
+        let timer = Self::arm_sync_timer();
+        Some(match self.replay_queued_writes_after_sync() {
+            Some(replay) => bubbletea_rs::batch(vec![timer, replay]),
+            None => timer,
+        })
+    }
+
+    /// Handles an incremental sync result.
+    ///
+    /// Applies the delta to the reviews currently held, so comments posted
+    /// locally while the sync was in flight are kept, then completes the
+    /// sync as for a full listing.
+    pub(super) fn handle_sync_delta(
+        &mut self,
+        delta: &ReviewSyncDelta,
+        latency_ms: u64,
+    ) -> Option<Cmd> {
+        let reviews = delta.apply(&self.reviews);
+        self.handle_sync_complete(&reviews, latency_ms)
     }
 
     /// Creates a command that triggers a sync tick after the sync interval.
@@ -170,6 +307,14 @@ impl ReviewApp {
         })
     }
 
+    /// Creates a command that polls for a rate-limit backoff shortly.
+    fn arm_backoff_poll_timer() -> Cmd {
+        Box::pin(async {
+            tokio::time::sleep(BACKOFF_POLL_INTERVAL).await;
+            Some(Box::new(AppMsg::BackoffPollTick) as Box<dyn Any + Send>)
+        })
+    }
+
     /// Creates a command that emits `Initialized` immediately.
     ///
     /// This synthetic startup event triggers the first render cycle without
@@ -309,3 +309,62 @@ fn escape_exits_diff_context(reviews_without_hunks: Vec<ReviewComment>) {
 
 #[path = "tests_sync.rs"]
 mod sync;
+
+fn detailed_metadata() -> PullRequestMetadata {
+    PullRequestMetadata {
+        number: 42,
+        title: Some("Add widgets".to_owned()),
+        draft: true,
+        base: crate::github::PullRequestBranch {
+            name: Some("main".to_owned()),
+            sha: Some("aaa111".to_owned()),
+        },
+        head: crate::github::PullRequestBranch {
+            name: Some("feature/widgets".to_owned()),
+            sha: Some("bbb222".to_owned()),
+        },
+        labels: vec!["enhancement".to_owned()],
+        requested_reviewers: vec!["hubot".to_owned()],
+        mergeable_state: Some("blocked".to_owned()),
+        additions: Some(120),
+        deletions: Some(8),
+        changed_files: Some(4),
+        ..PullRequestMetadata::default()
+    }
+}
+
+#[rstest]
+fn header_summarises_pull_request_metadata() {
+    let app = ReviewApp::with_dimensions(Vec::new(), 200, 24)
+        .with_pull_request_metadata(Some(detailed_metadata()));
+
+    let header = app.render_header();
+
+    assert_eq!(
+        header,
+        concat!(
+            "Frankie - #42 Add widgets [draft]  feature/widgets -> main  ",
+            "+120 -8 in 4 files  blocked  labels: enhancement  reviewers: hubot\n"
+        )
+    );
+}
+
+#[rstest]
+fn header_truncates_metadata_to_keep_badges_visible() {
+    let app = ReviewApp::with_dimensions(Vec::new(), 60, 24)
+        .with_pull_request_metadata(Some(detailed_metadata()))
+        .with_ci_status(Some(CiStatus {
+            head_sha: "bbb222".to_owned(),
+            checks: Vec::new(),
+        }));
+
+    let header = app.render_header();
+    let line = header.trim_end_matches('\n');
+
+    assert!(
+        line.starts_with("Frankie - #42 Add widgets [draft]"),
+        "{line}"
+    );
+    assert!(line.contains("...  [CI: "), "{line}");
+    assert_eq!(line.width(), 60, "{line}");
+}
@@ -2,7 +2,9 @@
 
 use rstest::rstest;
 
+use super::sync_handlers::FULL_LISTING_INTERVAL;
 use super::*;
+use crate::github::ReviewSyncDelta;
 
 #[rstest]
 fn sync_complete_preserves_selection_by_id(sample_reviews: Vec<ReviewComment>) {
@@ -64,6 +66,27 @@ fn sync_complete_adds_new_comments(sample_reviews: Vec<ReviewComment>) {
     assert_eq!(app.filtered_count(), 3);
 }
 
+#[rstest]
+fn sync_delta_is_applied_to_loaded_reviews(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+    app.handle_message(&AppMsg::CursorDown);
+    assert_eq!(app.current_selected_id(), Some(2));
+
+    let cmd = app.handle_message(&AppMsg::SyncDeltaReceived {
+        delta: ReviewSyncDelta {
+            added: vec![minimal_review(3, "Third comment", "charlie")],
+            removed: vec![1],
+            ..ReviewSyncDelta::default()
+        },
+        latency_ms: 40,
+    });
+
+    let ids: Vec<_> = app.reviews.iter().map(|review| review.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+    assert_eq!(app.current_selected_id(), Some(2));
+    assert!(cmd.is_some(), "sync timer should be re-armed");
+}
+
 /// Tests that navigation commands correctly update `selected_comment_id`.
 #[rstest]
 #[case::cursor_down(AppMsg::CursorDown, 0, Some(2))]
@@ -152,6 +175,43 @@ fn sync_tick_clears_error_state() {
     assert!(app.loading);
 }
 
+#[test]
+fn backoff_poll_shows_countdown_while_loading() {
+    use crate::github::{BackoffReason, PendingBackoff};
+
+    let mut app = ReviewApp::empty();
+    app.loading = true;
+
+    let cmd = app.handle_message(&AppMsg::BackoffPollTick);
+    assert!(cmd.is_some(), "poll should re-arm while loading");
+
+    app.rate_limit_backoff = Some(PendingBackoff::new(
+        BackoffReason::SecondaryRateLimit,
+        2,
+        3,
+        u64::MAX,
+    ));
+    let status = app.render_status_bar();
+    assert!(
+        status.starts_with("GitHub secondary rate limit: retrying in "),
+        "{status}"
+    );
+    assert!(status.ends_with("s (attempt 2/3)\n"), "{status}");
+}
+
+#[test]
+fn backoff_poll_stops_once_sync_finishes() {
+    use crate::github::{BackoffReason, PendingBackoff};
+
+    let mut app = ReviewApp::empty();
+    app.rate_limit_backoff = Some(PendingBackoff::new(BackoffReason::RetryAfter, 1, 3, 0));
+
+    let cmd = app.handle_message(&AppMsg::BackoffPollTick);
+
+    assert!(cmd.is_none());
+    assert!(app.rate_limit_backoff.is_none());
+}
+
 // Tests for find_filtered_index_by_id
 
 #[rstest]
@@ -225,3 +285,90 @@ async fn arm_sync_timer_schedules_sync_tick_after_interval() {
         "arm_sync_timer should schedule a SyncTick message"
     );
 }
+
+#[rstest]
+fn offline_app_refuses_refresh_and_stops_sync(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews).with_offline_mode();
+
+    let refresh_cmd = app.handle_message(&AppMsg::RefreshRequested);
+    let tick_cmd = app.handle_message(&AppMsg::SyncTick);
+
+    assert!(refresh_cmd.is_none() && tick_cmd.is_none());
+    assert!(!app.loading);
+    assert!(
+        app.error
+            .as_deref()
+            .is_some_and(|error| error.contains("Offline"))
+    );
+    assert!(app.view().contains("[Offline]"));
+}
+
+#[rstest]
+fn conversation_refresh_replaces_reviews_and_clamps_selection(sample_reviews: Vec<ReviewComment>) {
+    let review = |id| PullRequestReview {
+        id,
+        ..PullRequestReview::default()
+    };
+    let mut app = ReviewApp::new(sample_reviews).with_pull_request_reviews(vec![
+        review(1),
+        review(2),
+        review(3),
+    ]);
+    app.handle_message(&AppMsg::ShowPullRequestReviews);
+    app.handle_message(&AppMsg::End);
+    assert_eq!(app.selected_pull_request_review, 2);
+
+    let comment = PullRequestComment {
+        id: 7,
+        ..PullRequestComment::default()
+    };
+    let cmd = app.handle_message(&AppMsg::ConversationRefreshed {
+        reviews: vec![review(1)],
+        comments: vec![comment.clone()],
+    });
+
+    assert!(cmd.is_none());
+    assert_eq!(app.pull_request_reviews, vec![review(1)]);
+    assert_eq!(app.issue_comments, vec![comment]);
+    assert_eq!(app.selected_pull_request_review, 0);
+}
+
+#[rstest]
+fn conversation_refresh_failure_is_reported(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    app.handle_message(&AppMsg::ConversationRefreshFailed("boom".to_owned()));
+
+    assert_eq!(
+        app.error.as_deref(),
+        Some("Failed to refresh pull request reviews: boom")
+    );
+}
+
+#[rstest]
+fn periodic_sync_lists_every_comment(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+
+    for expected in 1..FULL_LISTING_INTERVAL {
+        app.handle_message(&AppMsg::SyncTick);
+        assert_eq!(app.incremental_syncs, expected);
+        app.loading = false;
+    }
+    app.handle_message(&AppMsg::SyncTick);
+
+    assert_eq!(
+        app.incremental_syncs, 0,
+        "full listing should reset the count"
+    );
+}
+
+#[rstest]
+fn manual_refresh_lists_every_comment(sample_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(sample_reviews);
+    app.handle_message(&AppMsg::SyncTick);
+    app.loading = false;
+
+    app.handle_message(&AppMsg::RefreshRequested);
+
+    assert_eq!(app.incremental_syncs, 0);
+}
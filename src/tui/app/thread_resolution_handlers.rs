@@ -0,0 +1,156 @@
+//! Review thread resolution handlers.
+//!
+//! Resolves or unresolves the selected comment's GitHub review thread and
+//! applies the returned state locally so that thread-aware filters update
+//! without waiting for the next refresh.
+
+use std::any::Any;
+
+use bubbletea_rs::Cmd;
+
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::github::outbox::WriteIntent;
+use crate::tui::messages::AppMsg;
+
+use super::ReviewApp;
+use super::outbox_handlers::OutboxTarget;
+
+impl ReviewApp {
+    /// Handles thread-resolution messages.
+    pub(super) fn handle_thread_resolution_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ToggleThreadResolution => self.request_thread_resolution_toggle(),
+            AppMsg::ThreadResolutionChanged { state } => {
+                self.handle_thread_resolution_changed(state);
+                None
+            }
+            AppMsg::ThreadResolutionFailed { thread_id, message } => {
+                self.handle_thread_resolution_failed(thread_id, message);
+                None
+            }
+            AppMsg::ThreadResolutionQueued {
+                thread_id,
+                intent_id,
+            } => {
+                if self.in_flight_thread_resolution.as_deref() == Some(thread_id.as_str()) {
+                    self.in_flight_thread_resolution = None;
+                }
+                self.note_queued_write("Thread resolution", intent_id);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn request_thread_resolution_toggle(&mut self) -> Option<Cmd> {
+        if self.in_flight_thread_resolution.is_some() {
+            self.error = Some("A thread resolution change is already in progress.".to_owned());
+            return None;
+        }
+
+        let Some(comment) = self.selected_comment() else {
+            self.error = Some("Thread resolution requires a selected comment".to_owned());
+            return None;
+        };
+
+        let Some(state) = comment.thread_state.as_ref() else {
+            self.error = Some(
+                "Thread state is unavailable for this comment; press 'r' to refresh.".to_owned(),
+            );
+            return None;
+        };
+
+        let thread_id = state.thread_id.clone();
+        let resolved = !state.is_resolved;
+        let intent = WriteIntent::ThreadResolution {
+            thread_id: thread_id.clone(),
+            resolved,
+        };
+        match self.queue_offline_write(&intent) {
+            Some(Ok(intent_id)) => {
+                self.note_queued_write("Thread resolution", &intent_id);
+                None
+            }
+            Some(Err(message)) => {
+                self.error = Some(message);
+                None
+            }
+            None => {
+                self.in_flight_thread_resolution = Some(thread_id.clone());
+                self.error = None;
+                Some(spawn_thread_resolution(
+                    thread_id,
+                    resolved,
+                    self.outbox.clone(),
+                ))
+            }
+        }
+    }
+
+    fn handle_thread_resolution_changed(&mut self, state: &ReviewThreadState) {
+        if self.in_flight_thread_resolution.as_deref() == Some(state.thread_id.as_str()) {
+            self.in_flight_thread_resolution = None;
+        }
+
+        let updated: Vec<ReviewComment> = self
+            .reviews
+            .iter()
+            .cloned()
+            .map(|mut review| {
+                if review
+                    .thread_state
+                    .as_ref()
+                    .is_some_and(|current| current.thread_id == state.thread_id)
+                {
+                    review.thread_state = Some(state.clone());
+                }
+                review
+            })
+            .collect();
+
+        let was_loading = self.loading;
+        self.apply_new_reviews(&updated);
+        self.loading = was_loading;
+    }
+
+    fn handle_thread_resolution_failed(&mut self, thread_id: &str, message: &str) {
+        if self.in_flight_thread_resolution.as_deref() == Some(thread_id) {
+            self.in_flight_thread_resolution = None;
+        }
+        self.error = Some(format!("Failed to update thread resolution: {message}"));
+    }
+}
+
+/// Spawns an async task applying the thread resolution change.
+///
+/// When the change fails transiently and an outbox is configured, it is
+/// queued for replay instead of being reported as failed.
+fn spawn_thread_resolution(thread_id: String, resolved: bool, outbox: Option<OutboxTarget>) -> Cmd {
+    Box::pin(async move {
+        let msg = match crate::tui::set_review_thread_resolution(thread_id.clone(), resolved).await
+        {
+            Ok(state) => AppMsg::ThreadResolutionChanged { state },
+            Err(error) => {
+                let intent = WriteIntent::ThreadResolution {
+                    thread_id: thread_id.clone(),
+                    resolved,
+                };
+                match outbox.and_then(|target| target.queue_after_failure(&intent, &error)) {
+                    Some(intent_id) => AppMsg::ThreadResolutionQueued {
+                        thread_id,
+                        intent_id,
+                    },
+                    None => AppMsg::ThreadResolutionFailed {
+                        thread_id,
+                        message: error.to_string(),
+                    },
+                }
+            }
+        };
+        Some(Box::new(msg) as Box<dyn Any + Send>)
+    })
+}
+
+#[cfg(test)]
+#[path = "thread_resolution_handlers_tests.rs"]
+mod tests;
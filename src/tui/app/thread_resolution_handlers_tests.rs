@@ -0,0 +1,123 @@
+//! Tests for review thread resolution handlers.
+
+use rstest::{fixture, rstest};
+
+use crate::github::models::{ReviewComment, ReviewThreadState};
+use crate::tui::messages::AppMsg;
+use crate::tui::state::ReviewFilter;
+
+use super::ReviewApp;
+
+fn open_thread(thread_id: &str) -> ReviewThreadState {
+    ReviewThreadState {
+        thread_id: thread_id.to_owned(),
+        ..ReviewThreadState::default()
+    }
+}
+
+#[fixture]
+fn threaded_reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            id: 1,
+            body: Some("Please split this helper".to_owned()),
+            thread_state: Some(open_thread("PRRT_one")),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 2,
+            body: Some("Done".to_owned()),
+            in_reply_to_id: Some(1),
+            thread_state: Some(open_thread("PRRT_one")),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 3,
+            body: Some("Rename this".to_owned()),
+            thread_state: Some(open_thread("PRRT_two")),
+            ..ReviewComment::default()
+        },
+    ]
+}
+
+#[rstest]
+fn toggle_requires_thread_state() {
+    let mut app = ReviewApp::new(vec![ReviewComment {
+        id: 1,
+        ..ReviewComment::default()
+    }]);
+
+    let cmd = app.handle_message(&AppMsg::ToggleThreadResolution);
+
+    assert!(cmd.is_none());
+    assert!(app.in_flight_thread_resolution.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Thread state is unavailable"));
+}
+
+#[rstest]
+fn toggle_spawns_request_and_rejects_duplicates(threaded_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(threaded_reviews);
+
+    let first = app.handle_message(&AppMsg::ToggleThreadResolution);
+    let second = app.handle_message(&AppMsg::ToggleThreadResolution);
+
+    assert!(first.is_some(), "first toggle should spawn a request");
+    assert!(second.is_none(), "second toggle should be rejected");
+    assert_eq!(app.in_flight_thread_resolution.as_deref(), Some("PRRT_one"));
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("already in progress"));
+}
+
+#[rstest]
+fn resolution_change_updates_whole_thread_and_filter(threaded_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(threaded_reviews);
+    app.handle_message(&AppMsg::SetFilter(ReviewFilter::Unresolved));
+    assert_eq!(app.filtered_count(), 2);
+
+    app.handle_message(&AppMsg::ToggleThreadResolution);
+    app.handle_message(&AppMsg::ThreadResolutionChanged {
+        state: ReviewThreadState {
+            thread_id: "PRRT_one".to_owned(),
+            is_resolved: true,
+            is_outdated: false,
+            resolved_by: Some("alice".to_owned()),
+        },
+    });
+
+    assert!(app.in_flight_thread_resolution.is_none());
+    let resolved: Vec<u64> = app
+        .reviews
+        .iter()
+        .filter(|review| {
+            review
+                .thread_state
+                .as_ref()
+                .is_some_and(|state| state.is_resolved)
+        })
+        .map(|review| review.id)
+        .collect();
+    assert_eq!(resolved, vec![1, 2]);
+    assert_eq!(
+        app.filtered_count(),
+        1,
+        "resolved thread should leave filter"
+    );
+    assert_eq!(app.selected_comment().map(|comment| comment.id), Some(3));
+}
+
+#[rstest]
+fn resolution_failure_reports_error(threaded_reviews: Vec<ReviewComment>) {
+    let mut app = ReviewApp::new(threaded_reviews);
+
+    app.handle_message(&AppMsg::ToggleThreadResolution);
+    app.handle_message(&AppMsg::ThreadResolutionFailed {
+        thread_id: "PRRT_one".to_owned(),
+        message: "Resource not accessible by integration".to_owned(),
+    });
+
+    assert!(app.in_flight_thread_resolution.is_none());
+    let error = app.error_message().unwrap_or_default();
+    assert!(error.contains("Failed to update thread resolution"));
+    assert!(error.contains("Resource not accessible"));
+}
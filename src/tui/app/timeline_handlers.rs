@@ -0,0 +1,70 @@
+//! Handlers for the full-screen conversation timeline view.
+//!
+//! The timeline merges conversation comments, submitted reviews, and review
+//! threads into one chronological list. It is rebuilt from the loaded data
+//! on demand, so it always reflects the latest sync and the active filter.
+
+use bubbletea_rs::Cmd;
+
+use crate::github::models::{TimelineEntry, build_timeline};
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+impl ReviewApp {
+    /// Handles opening and closing the conversation timeline view.
+    pub(super) fn handle_timeline_msg(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        match msg {
+            AppMsg::ShowTimeline => {
+                self.view_mode = ViewMode::Timeline;
+                self.selected_timeline_entry = 0;
+                self.error = None;
+            }
+            AppMsg::HideTimeline => {
+                self.view_mode = ViewMode::ReviewList;
+                self.error = None;
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Moves the entry selection for list navigation messages.
+    pub(super) fn handle_timeline_navigation(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        let last_index = self.timeline_entries().len().saturating_sub(1);
+        let selected = self.selected_timeline_entry.min(last_index);
+        self.selected_timeline_entry = match msg {
+            AppMsg::CursorUp => selected.saturating_sub(1),
+            AppMsg::CursorDown => selected.saturating_add(1).min(last_index),
+            AppMsg::Home => 0,
+            AppMsg::End => last_index,
+            _ => selected,
+        };
+        None
+    }
+
+    /// Applies a filter message and moves the selection back to the first
+    /// entry, since the visible entries may have changed.
+    pub(super) fn handle_timeline_filter(&mut self, msg: &AppMsg) -> Option<Cmd> {
+        let cmd = self.handle_filter_msg(msg);
+        self.selected_timeline_entry = 0;
+        cmd
+    }
+
+    /// Returns the timeline entries that match the active filter.
+    pub(super) fn timeline_entries(&self) -> Vec<TimelineEntry<'_>> {
+        let filter = &self.filter_state.active_filter;
+        build_timeline(
+            &self.issue_comments,
+            &self.pull_request_reviews,
+            &self.reviews,
+        )
+        .into_iter()
+        .filter(|entry| filter.matches_timeline_entry(entry, &self.reviews))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "timeline_handlers_tests.rs"]
+mod tests;
@@ -0,0 +1,121 @@
+//! Tests for the conversation timeline view handlers.
+
+use bubbletea_rs::Model;
+use rstest::{fixture, rstest};
+
+use crate::github::models::{
+    PullRequestComment, PullRequestReview, ReviewComment, ReviewThreadState,
+};
+use crate::tui::messages::AppMsg;
+
+use super::{ReviewApp, ViewMode};
+
+#[fixture]
+fn app() -> ReviewApp {
+    let threads = vec![
+        ReviewComment {
+            id: 10,
+            author: Some("bob".to_owned()),
+            body: Some("Rename this".to_owned()),
+            file_path: Some("src/lib.rs".to_owned()),
+            line_number: Some(4),
+            created_at: Some("2025-01-02T00:00:00Z".to_owned()),
+            thread_state: Some(ReviewThreadState {
+                thread_id: "PRRT_1".to_owned(),
+                is_resolved: false,
+                is_outdated: false,
+                resolved_by: None,
+            }),
+            ..ReviewComment::default()
+        },
+        ReviewComment {
+            id: 11,
+            author: Some("alice".to_owned()),
+            body: Some("Done".to_owned()),
+            in_reply_to_id: Some(10),
+            created_at: Some("2025-01-04T00:00:00Z".to_owned()),
+            ..ReviewComment::default()
+        },
+    ];
+    ReviewApp::with_dimensions(threads, 80, 30)
+        .with_issue_comments(vec![PullRequestComment {
+            id: 1,
+            author: Some("carol".to_owned()),
+            body: Some("Any update?".to_owned()),
+            created_at: Some("2025-01-01T00:00:00Z".to_owned()),
+            ..PullRequestComment::default()
+        }])
+        .with_pull_request_reviews(vec![PullRequestReview {
+            id: 2,
+            author: Some("alice".to_owned()),
+            state: Some("APPROVED".to_owned()),
+            body: Some("Ship it".to_owned()),
+            submitted_at: Some("2025-01-03T00:00:00Z".to_owned()),
+            ..PullRequestReview::default()
+        }])
+}
+
+#[rstest]
+fn timeline_view_lists_entries_in_order(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowTimeline);
+
+    let output = app.view();
+
+    assert_eq!(app.view_mode, ViewMode::Timeline);
+    assert!(output.contains("Conversation timeline (3):"), "{output}");
+    let comment = output.find("> [comment]  carol").expect("comment entry");
+    let thread = output.find("  [thread]  bob").expect("thread entry");
+    let review = output
+        .find("  [review]  alice  Approved")
+        .expect("review entry");
+    assert!(comment < thread && thread < review, "{output}");
+    assert!(output.contains("alice replied"), "{output}");
+}
+
+#[rstest]
+fn navigation_moves_selection_within_bounds(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowTimeline);
+
+    app.handle_message(&AppMsg::End);
+    app.handle_message(&AppMsg::CursorDown);
+    assert_eq!(app.selected_timeline_entry, 2);
+    assert!(app.view().contains("> [review]"));
+
+    app.handle_message(&AppMsg::Home);
+    assert_eq!(app.selected_timeline_entry, 0);
+}
+
+#[rstest]
+fn filter_narrows_entries_and_resets_selection(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowTimeline);
+    app.handle_message(&AppMsg::End);
+
+    app.handle_message(&AppMsg::CycleFilter);
+
+    assert_eq!(app.view_mode, ViewMode::Timeline);
+    assert_eq!(app.selected_timeline_entry, 0);
+    let output = app.view();
+    assert!(
+        output.contains("Conversation timeline (1, filter: Unresolved):"),
+        "{output}"
+    );
+    assert!(output.contains("> [thread]  bob"), "{output}");
+}
+
+#[rstest]
+fn escape_returns_to_review_list(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowTimeline);
+
+    app.handle_message(&AppMsg::EscapePressed);
+
+    assert_eq!(app.view_mode, ViewMode::ReviewList);
+}
+
+#[rstest]
+fn review_list_actions_are_blocked_while_open(mut app: ReviewApp) {
+    app.handle_message(&AppMsg::ShowTimeline);
+
+    app.handle_message(&AppMsg::ShowDiffContext);
+
+    assert_eq!(app.view_mode, ViewMode::Timeline);
+}
@@ -15,4 +15,9 @@ pub(crate) enum ViewMode {
     DiffContext,
     TimeTravel,
     PrDiscussionSummary,
+    ReviewSubmission,
+    PullRequestReviews,
+    Timeline,
+    CiChecks,
+    Outbox,
 }
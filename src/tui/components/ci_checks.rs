@@ -0,0 +1,183 @@
+//! Full-screen CI checks view for the review TUI.
+//!
+//! Drills into the header badge by listing the failing checks on the pull
+//! request head with their summaries and details links, so CI failures can
+//! be triaged without leaving the terminal.
+
+use crate::github::{CiOutcome, CiStatus};
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+use crate::tui::components::text_wrap::wrap_text;
+
+/// Indentation applied to check summary lines.
+const SUMMARY_INDENT: &str = "    ";
+
+/// Number of SHA characters shown in the view heading.
+const SHORT_SHA_LEN: usize = 7;
+
+/// Context for rendering the CI checks view.
+#[derive(Debug, Clone)]
+pub(crate) struct CiChecksViewContext<'a> {
+    /// CI results for the pull request head, if loaded.
+    pub ci_status: Option<&'a CiStatus>,
+    /// Index of the selected failing check.
+    pub selected_index: usize,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the CI checks view.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct CiChecksComponent;
+
+impl CiChecksComponent {
+    /// Renders the failing checks, scrolled so the selected check is visible.
+    #[must_use]
+    pub fn view(ctx: &CiChecksViewContext<'_>) -> String {
+        let (lines, selected_row) = ctx.ci_status.map_or_else(
+            || (vec![NOT_LOADED.to_owned()], 0),
+            |ci| render_lines(ctx, ci),
+        );
+        let max_height = ctx.max_height.max(1);
+        let offset = if selected_row < max_height {
+            0
+        } else {
+            selected_row
+        };
+
+        let mut output = String::new();
+        for line in lines.iter().skip(offset).take(max_height) {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line.as_str(),
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+const NOT_LOADED: &str = "CI status has not been loaded for this pull request.";
+
+/// Builds the view lines and returns the row of the selected check header.
+fn render_lines(ctx: &CiChecksViewContext<'_>, ci: &CiStatus) -> (Vec<String>, usize) {
+    let short_sha = ci.head_sha.get(..SHORT_SHA_LEN).unwrap_or(&ci.head_sha);
+    let failing = ci.failing_checks();
+    let mut lines = vec![
+        format!("CI checks for {short_sha}: {}", ci.badge()),
+        format!(
+            "  {} passing, {} pending, {} failing",
+            ci.count(CiOutcome::Passing),
+            ci.count(CiOutcome::Pending),
+            failing.len()
+        ),
+        String::new(),
+        format!("Failing checks ({}):", failing.len()),
+    ];
+    if failing.is_empty() {
+        lines.push("  (no failing checks)".to_owned());
+        return (lines, 0);
+    }
+
+    let summary_width = ctx.max_width.saturating_sub(SUMMARY_INDENT.len()).max(1);
+    let mut selected_row = 0;
+    for (index, check) in failing.iter().enumerate() {
+        lines.push(String::new());
+        let marker = if index == ctx.selected_index {
+            selected_row = lines.len();
+            '>'
+        } else {
+            ' '
+        };
+        let state = check.state.as_deref().unwrap_or("failing");
+        lines.push(format!("{marker} {}  {state}", check.name));
+
+        if let Some(summary) = check.summary.as_deref() {
+            lines.extend(
+                wrap_text(summary, summary_width)
+                    .lines()
+                    .map(|line| format!("{SUMMARY_INDENT}{line}")),
+            );
+        }
+        if let Some(url) = check.details_url.as_deref() {
+            lines.push(format!("{SUMMARY_INDENT}{url}"));
+        }
+    }
+
+    (lines, selected_row)
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `ci_checks` module.
+
+    use rstest::rstest;
+
+    use super::{CiChecksComponent, CiChecksViewContext};
+    use crate::github::{CiCheck, CiCheckKind, CiOutcome, CiStatus};
+
+    fn check(name: &str, outcome: CiOutcome, summary: Option<&str>) -> CiCheck {
+        CiCheck {
+            name: name.to_owned(),
+            kind: CiCheckKind::CheckRun,
+            outcome,
+            state: (outcome == CiOutcome::Failing).then(|| "failure".to_owned()),
+            summary: summary.map(str::to_owned),
+            details_url: None,
+        }
+    }
+
+    fn render(ci_status: Option<&CiStatus>, selected_index: usize) -> String {
+        CiChecksComponent::view(&CiChecksViewContext {
+            ci_status,
+            selected_index,
+            max_width: 80,
+            max_height: 20,
+        })
+    }
+
+    #[rstest]
+    fn view_lists_failing_checks_with_summaries() {
+        let ci = CiStatus {
+            head_sha: "0123456789abcdef".to_owned(),
+            checks: vec![
+                check("build", CiOutcome::Failing, Some("2 tests failed")),
+                check("lint", CiOutcome::Passing, None),
+                check("e2e", CiOutcome::Failing, None),
+            ],
+        };
+
+        let output = render(Some(&ci), 1);
+
+        assert!(
+            output.contains("CI checks for 0123456: CI: 2/3 failing"),
+            "{output}"
+        );
+        assert!(output.contains("Failing checks (2):"), "{output}");
+        assert!(output.contains("  build  failure"), "{output}");
+        assert!(output.contains("    2 tests failed"), "{output}");
+        assert!(output.contains("> e2e  failure"), "{output}");
+        assert!(!output.contains("lint"), "{output}");
+    }
+
+    #[rstest]
+    fn view_reports_when_nothing_fails() {
+        let ci = CiStatus {
+            head_sha: "abc".to_owned(),
+            checks: vec![check("build", CiOutcome::Passing, None)],
+        };
+
+        let output = render(Some(&ci), 0);
+
+        assert!(output.contains("(no failing checks)"), "{output}");
+    }
+
+    #[rstest]
+    fn view_explains_missing_status() {
+        let output = render(None, 0);
+
+        assert!(output.contains("has not been loaded"), "{output}");
+    }
+}
@@ -6,7 +6,8 @@
 //! maximum width (typically 80 columns or the terminal width if narrower).
 
 use crate::ai::{CommentRewriteMode, SideBySideLine};
-use crate::github::models::ReviewComment;
+use crate::github::models::{ReviewComment, format_reactions};
+use crate::suggestion::{SuggestedChange, parse_suggestions};
 use crate::verification::CommentVerificationResult;
 
 use super::code_highlight::CodeHighlighter;
@@ -37,6 +38,8 @@ pub struct CommentDetailViewContext<'a> {
     pub reply_draft_ai_preview: Option<ReplyDraftAiPreviewRenderContext<'a>>,
     /// Cached verification result for the selected comment, if available.
     pub verification: Option<&'a CommentVerificationResult>,
+    /// Outcome of the last suggestion applied from the selected comment.
+    pub suggestion_report: Option<&'a str>,
 }
 
 /// Render-only reply-draft context for the comment detail view.
@@ -52,6 +55,8 @@ pub struct ReplyDraftRenderContext<'a> {
     pub ready_to_send: bool,
     /// Provenance label for the current draft text.
     pub origin_label: Option<&'a str>,
+    /// Whether the draft edits the selected comment instead of replying.
+    pub edits_comment: bool,
 }
 
 /// Render-only AI preview context for reply-draft rewrite suggestions.
@@ -97,7 +102,9 @@ impl CommentDetailComponent {
     /// Returns a formatted string containing:
     /// - A separator line
     /// - Comment header (author, file, line number)
+    /// - Reaction counts (if any)
     /// - Comment body text
+    /// - Suggested changes as before/after diffs (if any)
     /// - Code context with syntax highlighting (if available)
     ///
     /// If no comment is selected, returns a placeholder message.
@@ -118,6 +125,12 @@ impl CommentDetailComponent {
         output.push_str(&Self::render_header(comment));
         output.push('\n');
 
+        if let Some(reactions) = format_reactions(&comment.reactions) {
+            output.push_str("Reactions: ");
+            output.push_str(&reactions);
+            output.push('\n');
+        }
+
         if let Some(verification) = ctx.verification {
             let verification_text = Self::render_verification(verification);
             output.push_str(&wrap_text(&verification_text, ctx.max_width));
@@ -128,6 +141,12 @@ impl CommentDetailComponent {
         output.push_str(&Self::render_body(comment, ctx.max_width));
         output.push('\n');
 
+        output.push_str(&Self::render_suggestions(
+            comment,
+            ctx.suggestion_report,
+            ctx.max_width,
+        ));
+
         // Code context
         output.push_str(&self.render_code_context(comment, ctx.max_width));
 
@@ -172,6 +191,51 @@ impl CommentDetailComponent {
         wrap_text(body, max_width)
     }
 
+    /// Renders each suggested change as removed and added lines, followed
+    /// by the outcome of the last attempt to apply it.
+    fn render_suggestions(
+        comment: &ReviewComment,
+        report: Option<&str>,
+        max_width: usize,
+    ) -> String {
+        let suggestions = parse_suggestions(comment);
+        if suggestions.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        for (index, suggestion) in suggestions.iter().enumerate() {
+            let hint = if index == 0 { " (A applies)" } else { "" };
+            output.push_str("\nSuggested change for ");
+            output.push_str(&suggestion.location());
+            output.push_str(hint);
+            output.push_str(":\n");
+            output.push_str(&Self::render_suggestion_diff(suggestion, max_width));
+        }
+        if let Some(text) = report {
+            output.push_str(&wrap_text(text, max_width));
+            output.push('\n');
+        }
+        output
+    }
+
+    fn render_suggestion_diff(suggestion: &SuggestedChange, max_width: usize) -> String {
+        let mut output = String::new();
+        let mut push_line = |marker: char, line: &str| {
+            output.push_str(&wrap_text(&format!("{marker} {line}"), max_width));
+            output.push('\n');
+        };
+        match suggestion.original.as_deref() {
+            Some(original) => original.iter().for_each(|line| push_line('-', line)),
+            None => push_line('?', "(original lines are not in the diff hunk)"),
+        }
+        suggestion
+            .replacement
+            .iter()
+            .for_each(|line| push_line('+', line));
+        output
+    }
+
     fn render_verification(result: &CommentVerificationResult) -> String {
         let status = result.status();
         let evidence = result.evidence();
@@ -216,7 +280,11 @@ impl CommentDetailComponent {
 
     /// Renders inline reply-draft content and length metadata.
     fn render_reply_draft(reply_draft: &ReplyDraftRenderContext<'_>, max_width: usize) -> String {
-        let mut output = String::from("\nReply draft:\n");
+        let mut output = String::from(if reply_draft.edits_comment {
+            "\nEditing comment:\n"
+        } else {
+            "\nReply draft:\n"
+        });
 
         if reply_draft.text.is_empty() {
             output.push_str("(empty)\n");
@@ -243,7 +311,11 @@ impl CommentDetailComponent {
         output.push_str(&reply_draft.max_length.to_string());
         output.push_str(readiness_suffix);
         output.push('\n');
-        output.push_str("Templates: 1-9  E:expand  W:reword  Enter:ready  Esc:cancel\n");
+        output.push_str(if reply_draft.edits_comment {
+            "Templates: 1-9  E:expand  W:reword  Enter:save  Esc:cancel\n"
+        } else {
+            "Templates: 1-9  E:expand  W:reword  Enter:send  Esc:cancel\n"
+        });
         output
     }
 
@@ -22,6 +22,7 @@ fn make_preview_ctx<'a>(
             has_changes,
         }),
         verification: None,
+        suggestion_report: None,
     }
 }
 
@@ -19,6 +19,7 @@ fn render_comment_detail(comment: Option<&ReviewComment>) -> String {
         reply_draft: None,
         reply_draft_ai_preview: None,
         verification: None,
+        suggestion_report: None,
     };
     component.view(&ctx)
 }
@@ -47,9 +48,11 @@ fn render_comment_with_reply_draft(
             max_length: 120,
             ready_to_send,
             origin_label,
+            edits_comment: false,
         }),
         reply_draft_ai_preview: None,
         verification: None,
+        suggestion_report: None,
     };
     component.view(&ctx)
 }
@@ -341,6 +344,37 @@ fn view_renders_inline_reply_draft_without_ready_suffix() {
     assert!(!output.contains("Origin:"));
 }
 
+#[test]
+fn view_labels_draft_that_edits_the_comment() {
+    let component = CommentDetailComponent::new();
+    let comment = ReviewCommentBuilder::new(1)
+        .author("alice")
+        .body("Fixed in teh latest push.")
+        .build();
+    let ctx = CommentDetailViewContext {
+        selected_comment: Some(&comment),
+        max_width: 80,
+        max_height: 0,
+        reply_draft: Some(ReplyDraftRenderContext {
+            text: "Fixed in the latest push.",
+            char_count: 25,
+            max_length: 120,
+            ready_to_send: false,
+            origin_label: None,
+            edits_comment: true,
+        }),
+        reply_draft_ai_preview: None,
+        verification: None,
+        suggestion_report: None,
+    };
+
+    let output = component.view(&ctx);
+
+    assert!(output.contains("Editing comment:"));
+    assert!(!output.contains("Reply draft:"));
+    assert!(output.contains("Enter:save"));
+}
+
 #[test]
 fn view_renders_ai_origin_label_for_reply_draft() {
     let output = render_comment_with_reply_draft("AI suggestion", 13, false, Some("AI-originated"));
@@ -349,3 +383,75 @@ fn view_renders_ai_origin_label_for_reply_draft() {
     assert!(output.contains("AI suggestion"));
     assert!(output.contains("13"));
 }
+
+#[rstest]
+fn view_renders_reaction_counts_below_header(mut sample_comment: ReviewComment) {
+    use crate::github::models::{ReactionContent, ReactionCount};
+
+    sample_comment.reactions = vec![
+        ReactionCount {
+            content: ReactionContent::ThumbsUp,
+            count: 3,
+        },
+        ReactionCount {
+            content: ReactionContent::Confused,
+            count: 1,
+        },
+    ];
+
+    let output = render_comment_detail(Some(&sample_comment));
+
+    assert!(output.contains("[alice] src/main.rs:42\nReactions: 👍 3  😕 1\n"));
+}
+
+#[rstest]
+fn view_omits_reactions_line_without_reactions(sample_comment: ReviewComment) {
+    let output = render_comment_detail(Some(&sample_comment));
+
+    assert!(!output.contains("Reactions:"));
+}
+
+fn suggestion_comment() -> ReviewComment {
+    ReviewCommentBuilder::new(7)
+        .author("bob")
+        .file_path("src/config.rs")
+        .line_number(2)
+        .body("Bump it:\n```suggestion\nlet retries = 4;\n```")
+        .diff_hunk("@@ -1,2 +1,2 @@\n fn config() {\n-let retries = 2;\n+let retries = 3;")
+        .build()
+}
+
+#[rstest]
+fn view_renders_suggested_change_as_diff() {
+    let comment = suggestion_comment();
+
+    let output = render_comment_detail(Some(&comment));
+
+    assert!(
+        output.contains(
+            "Suggested change for src/config.rs:2 (A applies):\n\
+             - let retries = 3;\n\
+             + let retries = 4;\n"
+        ),
+        "unexpected output: {output}"
+    );
+}
+
+#[rstest]
+fn view_renders_suggestion_report() {
+    let comment = suggestion_comment();
+    let component = CommentDetailComponent::new();
+    let ctx = CommentDetailViewContext {
+        selected_comment: Some(&comment),
+        max_width: 80,
+        max_height: 0,
+        reply_draft: None,
+        reply_draft_ai_preview: None,
+        verification: None,
+        suggestion_report: Some("Applied to src/config.rs:2."),
+    };
+
+    let output = component.view(&ctx);
+
+    assert!(output.contains("+ let retries = 4;\nApplied to src/config.rs:2.\n"));
+}
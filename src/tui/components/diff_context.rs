@@ -1,16 +1,27 @@
 //! Full-screen diff context component.
 //!
-//! Renders a single diff hunk in a full-screen view with syntax highlighting
-//! and a header that includes the file path and hunk position.
+//! Renders a single diff hunk in a full-screen view with syntax highlighting,
+//! a line cursor gutter, and a header that includes the file path and hunk
+//! position. A new-comment draft for the selected line is shown below the
+//! hunk while it is being written.
 
-use crate::tui::state::{DiffHunk, RenderedDiffHunk, clamp_hunk_index};
+use crate::tui::state::{
+    DiffCommentDraft, DiffHunk, RenderedDiffHunk, clamp_hunk_index, diff_line_anchors,
+};
 
 use super::code_highlight::CodeHighlighter;
-use super::text_truncate::truncate_to_height;
+use super::text_truncate::{truncate_to_display_width_with_ellipsis, truncate_to_height};
+use super::text_wrap::wrap_to_width;
 
 /// Placeholder shown when no diff hunks are available.
 const NO_CONTEXT_PLACEHOLDER: &str = "(No diff context available for this comment)";
 
+/// Gutter marking the line cursor.
+const CURSOR_GUTTER: &str = "> ";
+
+/// Gutter for every other row.
+const EMPTY_GUTTER: &str = "  ";
+
 /// Context for rendering the full-screen diff view.
 #[derive(Debug, Clone)]
 pub(crate) struct DiffContextViewContext<'a> {
@@ -18,6 +29,12 @@ pub(crate) struct DiffContextViewContext<'a> {
     pub hunks: &'a [RenderedDiffHunk],
     /// Current hunk index.
     pub current_index: usize,
+    /// Selected line within the current hunk.
+    pub line_cursor: usize,
+    /// New-comment draft being written for the selected line, if any.
+    pub comment_draft: Option<&'a DiffCommentDraft>,
+    /// Maximum width in columns, used for the draft line (0 = unlimited).
+    pub max_width: usize,
     /// Maximum height in lines (0 = unlimited).
     pub max_height: usize,
 }
@@ -54,6 +71,9 @@ impl DiffContextComponent {
 
     /// Pre-renders diff hunks with syntax highlighting and wrapping.
     ///
+    /// Code is wrapped to `max_width` minus the cursor gutter so that each
+    /// raw hunk line maps to a known number of rendered rows.
+    ///
     /// # Examples
     ///
     /// ```rust,ignore
@@ -64,6 +84,7 @@ impl DiffContextComponent {
     /// let hunks = vec![DiffHunk {
     ///     file_path: Some("src/main.rs".to_owned()),
     ///     line_number: Some(1),
+    ///     commit_sha: None,
     ///     text: "@@ -1 +1 @@\n+fn main() {}".to_owned(),
     /// }];
     ///
@@ -76,6 +97,12 @@ impl DiffContextComponent {
         hunks: &[DiffHunk],
         max_width: usize,
     ) -> Vec<RenderedDiffHunk> {
+        let code_width = if max_width > CURSOR_GUTTER.len() {
+            max_width - CURSOR_GUTTER.len()
+        } else {
+            max_width
+        };
+
         hunks
             .iter()
             .map(|hunk| RenderedDiffHunk {
@@ -83,8 +110,14 @@ impl DiffContextComponent {
                 rendered: ensure_trailing_newline(self.highlighter.highlight_or_plain(
                     &hunk.text,
                     hunk.file_path.as_deref(),
-                    max_width,
+                    code_width,
                 )),
+                anchors: diff_line_anchors(&hunk.text),
+                line_rows: hunk
+                    .text
+                    .lines()
+                    .map(|line| wrap_to_width(line, code_width).lines().count().max(1))
+                    .collect(),
             })
             .collect()
     }
@@ -101,6 +134,9 @@ impl DiffContextComponent {
     /// let ctx = DiffContextViewContext {
     ///     hunks: &[],
     ///     current_index: 0,
+    ///     line_cursor: 0,
+    ///     comment_draft: None,
+    ///     max_width: 80,
     ///     max_height: 0,
     /// };
     /// let output = DiffContextComponent::view(&ctx);
@@ -119,14 +155,26 @@ impl DiffContextComponent {
         };
 
         let header = render_header(&current.hunk, current_index, total);
-        let mut body = current.rendered.clone();
+        let draft_line = ctx
+            .comment_draft
+            .map(|draft| render_draft_line(draft, ctx.max_width));
+        let draft_height = usize::from(draft_line.is_some());
 
-        if ctx.max_height > 0 {
-            let body_height = ctx.max_height.saturating_sub(1);
+        let mut body = if ctx.max_height > 0 {
+            let body_height = ctx.max_height.saturating_sub(1 + draft_height);
             if body_height == 0 {
                 return format!("{header}\n");
             }
+            let mut body = render_body(current, ctx.line_cursor, body_height);
             truncate_to_height(&mut body, body_height);
+            body
+        } else {
+            render_body(current, ctx.line_cursor, 0)
+        };
+
+        if let Some(line) = draft_line {
+            body.push_str(&line);
+            body.push('\n');
         }
 
         let mut output = String::new();
@@ -137,6 +185,66 @@ impl DiffContextComponent {
     }
 }
 
+/// Renders the hunk rows with a cursor gutter.
+///
+/// When `body_height` is non-zero, leading rows are skipped so the selected
+/// line stays visible above the truncation marker.
+fn render_body(current: &RenderedDiffHunk, line_cursor: usize, body_height: usize) -> String {
+    let cursor_start: usize = current.line_rows.iter().take(line_cursor).sum();
+    let cursor_rows = current.line_rows.get(line_cursor).copied().unwrap_or(1);
+    let total_rows = current.rendered.lines().count();
+    let skip = if body_height > 0 && total_rows > body_height {
+        (cursor_start + cursor_rows + 1).saturating_sub(body_height)
+    } else {
+        0
+    };
+    let gutter_rows: usize = current.line_rows.iter().sum();
+
+    let mut body = String::new();
+    for (row, line) in current.rendered.lines().enumerate().skip(skip) {
+        if row == cursor_start {
+            body.push_str(CURSOR_GUTTER);
+        } else if row < gutter_rows {
+            body.push_str(EMPTY_GUTTER);
+        }
+        body.push_str(line);
+        body.push('\n');
+    }
+    body
+}
+
+/// Renders the draft line, keeping the end of a long body visible.
+fn render_draft_line(draft: &DiffCommentDraft, max_width: usize) -> String {
+    let anchor = draft.anchor();
+    let prefix = format!(
+        "New comment on {}:{} [{}]: ",
+        draft.path(),
+        anchor.line,
+        anchor.side.as_api_str()
+    );
+    let text = format!("{}_", draft.body());
+    let available = max_width.saturating_sub(prefix.chars().count());
+    let text_width = text.chars().count();
+
+    if max_width == 0 || text_width <= available {
+        return truncate_or_keep(format!("{prefix}{text}"), max_width);
+    }
+
+    let tail: String = text
+        .chars()
+        .skip(text_width - available.saturating_sub(3))
+        .collect();
+    truncate_or_keep(format!("{prefix}...{tail}"), max_width)
+}
+
+fn truncate_or_keep(line: String, max_width: usize) -> String {
+    if max_width == 0 {
+        line
+    } else {
+        truncate_to_display_width_with_ellipsis(&line, max_width)
+    }
+}
+
 fn render_header(hunk: &DiffHunk, current_index: usize, total: usize) -> String {
     let file = hunk.file_path.as_deref().unwrap_or("(no file)");
     let line_suffix = hunk
@@ -171,10 +279,26 @@ mod tests {
         DiffHunk {
             file_path: Some("src/main.rs".to_owned()),
             line_number: Some(1),
+            commit_sha: Some("abc123".to_owned()),
             text: "@@ -1 +1 @@\n+fn main() {}".to_owned(),
         }
     }
 
+    fn view_context(
+        hunks: &[RenderedDiffHunk],
+        line_cursor: usize,
+        max_height: usize,
+    ) -> DiffContextViewContext<'_> {
+        DiffContextViewContext {
+            hunks,
+            current_index: 0,
+            line_cursor,
+            comment_draft: None,
+            max_width: 80,
+            max_height,
+        }
+    }
+
     #[rstest]
     fn render_hunks_returns_rendered_output(sample_hunk: DiffHunk) {
         let component = DiffContextComponent::new();
@@ -190,11 +314,7 @@ mod tests {
     fn view_includes_header(sample_hunk: DiffHunk) {
         let component = DiffContextComponent::new();
         let rendered = component.render_hunks(&[sample_hunk], 80);
-        let ctx = DiffContextViewContext {
-            hunks: &rendered,
-            current_index: 0,
-            max_height: 0,
-        };
+        let ctx = view_context(&rendered, 1, 0);
 
         let output = DiffContextComponent::view(&ctx);
 
@@ -204,11 +324,7 @@ mod tests {
 
     #[test]
     fn view_shows_placeholder_when_empty() {
-        let ctx = DiffContextViewContext {
-            hunks: &[],
-            current_index: 0,
-            max_height: 0,
-        };
+        let ctx = view_context(&[], 0, 0);
 
         let output = DiffContextComponent::view(&ctx);
 
@@ -219,11 +335,7 @@ mod tests {
     fn view_keeps_header_when_height_is_one(sample_hunk: DiffHunk) {
         let component = DiffContextComponent::new();
         let rendered = component.render_hunks(&[sample_hunk], 80);
-        let ctx = DiffContextViewContext {
-            hunks: &rendered,
-            current_index: 0,
-            max_height: 1,
-        };
+        let ctx = view_context(&rendered, 1, 1);
 
         let output = DiffContextComponent::view(&ctx);
         let stripped = strip_ansi_codes(&output);
@@ -231,4 +343,61 @@ mod tests {
         assert!(stripped.contains("File: src/main.rs:1"));
         assert!(!stripped.contains("fn main"));
     }
+
+    #[rstest]
+    fn view_marks_line_cursor() {
+        let hunk = DiffHunk {
+            file_path: Some("notes.unknown_ext_xyz".to_owned()),
+            line_number: Some(2),
+            commit_sha: None,
+            text: "@@ -1,2 +1,2 @@\n first\n second".to_owned(),
+        };
+        let rendered = DiffContextComponent::new().render_hunks(&[hunk], 80);
+
+        let output = DiffContextComponent::view(&view_context(&rendered, 1, 0));
+        let stripped = strip_ansi_codes(&output);
+
+        assert!(stripped.contains(">  first"), "{stripped}");
+        assert!(stripped.contains("   second"), "{stripped}");
+    }
+
+    #[rstest]
+    fn view_scrolls_to_keep_cursor_visible() {
+        let body: Vec<String> = (1..=20).map(|line| format!(" line {line}")).collect();
+        let hunk = DiffHunk {
+            file_path: Some("notes.unknown_ext_xyz".to_owned()),
+            line_number: Some(20),
+            commit_sha: None,
+            text: format!("@@ -1,20 +1,20 @@\n{}", body.join("\n")),
+        };
+        let rendered = DiffContextComponent::new().render_hunks(&[hunk], 80);
+
+        let output = DiffContextComponent::view(&view_context(&rendered, 20, 6));
+        let stripped = strip_ansi_codes(&output);
+
+        assert!(stripped.contains(">  line 20"), "{stripped}");
+        assert!(!stripped.contains("line 1\n"), "{stripped}");
+    }
+
+    #[rstest]
+    fn view_shows_comment_draft(sample_hunk: DiffHunk) {
+        let rendered = DiffContextComponent::new().render_hunks(&[sample_hunk], 80);
+        let anchor = rendered
+            .first()
+            .and_then(|hunk| hunk.anchors.get(1).copied().flatten())
+            .expect("added line should be commentable");
+        let mut draft = DiffCommentDraft::new("src/main.rs", "abc123", anchor);
+        draft.push_char('?');
+        let ctx = DiffContextViewContext {
+            comment_draft: Some(&draft),
+            ..view_context(&rendered, 1, 0)
+        };
+
+        let output = DiffContextComponent::view(&ctx);
+
+        assert!(
+            output.contains("New comment on src/main.rs:1 [RIGHT]: ?_"),
+            "{output}"
+        );
+    }
 }
@@ -3,26 +3,39 @@
 //! This module provides reusable UI components following the bubbletea-rs
 //! Model-View pattern. Each component manages its own state and rendering.
 
+mod ci_checks;
 mod code_highlight;
 mod comment_detail;
 mod diff_context;
+mod outbox;
 mod pr_discussion_summary;
+mod pull_request_picker;
+mod pull_request_reviews;
 mod review_list;
+mod review_submission;
 mod text_truncate;
 mod text_wrap;
 mod time_travel_view;
+mod timeline;
 
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_utils;
 
+pub(crate) use ci_checks::{CiChecksComponent, CiChecksViewContext};
 pub use code_highlight::CodeHighlighter;
 pub use comment_detail::{
     CommentDetailComponent, CommentDetailViewContext, ReplyDraftAiPreviewRenderContext,
     ReplyDraftRenderContext,
 };
 pub(crate) use diff_context::{DiffContextComponent, DiffContextViewContext};
+pub(crate) use outbox::{OutboxComponent, OutboxViewContext};
 pub(crate) use pr_discussion_summary::{
     PrDiscussionSummaryComponent, PrDiscussionSummaryViewContext,
 };
+pub(crate) use pull_request_picker::{PullRequestPickerComponent, PullRequestPickerViewContext};
+pub(crate) use pull_request_reviews::{PullRequestReviewsComponent, PullRequestReviewsViewContext};
 pub use review_list::{ReviewListComponent, ReviewListViewContext};
+pub(crate) use review_submission::{ReviewSubmissionComponent, ReviewSubmissionViewContext};
+pub(crate) use text_truncate::truncate_to_display_width_with_ellipsis;
 pub(crate) use time_travel_view::{TimeTravelViewComponent, TimeTravelViewContext};
+pub(crate) use timeline::{TimelineComponent, TimelineViewContext};
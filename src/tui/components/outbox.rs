@@ -0,0 +1,173 @@
+//! Full-screen queued writes view for the review TUI.
+//!
+//! Lists the replies and thread resolutions waiting in the write outbox with
+//! their intent IDs, delivery attempts, and last errors, so stuck writes can
+//! be retried or discarded.
+
+use crate::persistence::QueuedWrite;
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+
+/// Indentation applied to queued write detail lines.
+const DETAIL_INDENT: &str = "    ";
+
+/// Context for rendering the queued writes view.
+#[derive(Debug, Clone)]
+pub(crate) struct OutboxViewContext<'a> {
+    /// Writes queued for the pull request, or `None` without an outbox.
+    pub writes: Option<&'a [QueuedWrite]>,
+    /// Index of the selected write.
+    pub selected_index: usize,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the queued writes view.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct OutboxComponent;
+
+impl OutboxComponent {
+    /// Renders the queued writes, scrolled so the selected write is visible.
+    #[must_use]
+    pub fn view(ctx: &OutboxViewContext<'_>) -> String {
+        let (lines, selected_row) = ctx.writes.map_or_else(
+            || (vec![NOT_CONFIGURED.to_owned()], 0),
+            |writes| render_lines(writes, ctx.selected_index),
+        );
+        let max_height = ctx.max_height.max(1);
+        let offset = if selected_row < max_height {
+            0
+        } else {
+            selected_row
+        };
+
+        let mut output = String::new();
+        for line in lines.iter().skip(offset).take(max_height) {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line.as_str(),
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+const NOT_CONFIGURED: &str =
+    "Writes are not queued without a local database; pass --database-url to enable the outbox.";
+
+/// Builds the view lines and returns the row of the selected write.
+fn render_lines(writes: &[QueuedWrite], selected_index: usize) -> (Vec<String>, usize) {
+    let mut lines = vec![format!("Queued writes ({}):", writes.len())];
+    if writes.is_empty() {
+        lines.push("  (nothing queued)".to_owned());
+        return (lines, 0);
+    }
+
+    let mut selected_row = 0;
+    for (index, write) in writes.iter().enumerate() {
+        lines.push(String::new());
+        let marker = if index == selected_index {
+            selected_row = lines.len();
+            '>'
+        } else {
+            ' '
+        };
+        lines.push(format!(
+            "{marker} {}  {}",
+            write.intent_id,
+            write.intent.summary()
+        ));
+        lines.push(format!(
+            "{DETAIL_INDENT}queued {} UTC, {} failed attempt(s)",
+            write.queued_at, write.attempt_count
+        ));
+        if let Some(error) = write.last_error.as_deref() {
+            lines.push(format!("{DETAIL_INDENT}last error: {error}"));
+        }
+    }
+
+    (lines, selected_row)
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `outbox` module.
+
+    use rstest::rstest;
+
+    use super::{OutboxComponent, OutboxViewContext};
+    use crate::github::PullRequestLocator;
+    use crate::github::outbox::WriteIntent;
+    use crate::persistence::QueuedWrite;
+
+    fn queued(intent_id: &str, intent: WriteIntent, last_error: Option<&str>) -> QueuedWrite {
+        QueuedWrite {
+            intent_id: intent_id.to_owned(),
+            locator: PullRequestLocator::parse("https://github.com/owner/repo/pull/1")
+                .expect("locator should parse"),
+            intent,
+            attempt_count: u32::from(last_error.is_some()),
+            last_error: last_error.map(str::to_owned),
+            queued_at: "2026-04-17 09:30:00".to_owned(),
+        }
+    }
+
+    fn render(writes: Option<&[QueuedWrite]>, selected_index: usize) -> String {
+        OutboxComponent::view(&OutboxViewContext {
+            writes,
+            selected_index,
+            max_width: 100,
+            max_height: 20,
+        })
+    }
+
+    #[rstest]
+    fn view_lists_queued_writes_with_attempts_and_errors() {
+        let writes = vec![
+            queued(
+                "w1",
+                WriteIntent::Reply {
+                    comment_id: 7,
+                    body: "Fixed".to_owned(),
+                },
+                Some("network error: connection reset"),
+            ),
+            queued(
+                "w2",
+                WriteIntent::ThreadResolution {
+                    thread_id: "PRRT_1".to_owned(),
+                    resolved: true,
+                },
+                None,
+            ),
+        ];
+
+        let output = render(Some(&writes), 1);
+
+        assert!(output.contains("Queued writes (2):"), "{output}");
+        assert!(output.contains("  w1  reply to #7: Fixed"), "{output}");
+        assert!(
+            output.contains("last error: network error: connection reset"),
+            "{output}"
+        );
+        assert!(output.contains("1 failed attempt(s)"), "{output}");
+        assert!(output.contains("> w2  resolve thread PRRT_1"), "{output}");
+    }
+
+    #[rstest]
+    fn view_reports_an_empty_outbox() {
+        let output = render(Some(&[]), 0);
+
+        assert!(output.contains("(nothing queued)"), "{output}");
+    }
+
+    #[rstest]
+    fn view_explains_missing_outbox() {
+        let output = render(None, 0);
+
+        assert!(output.contains("--database-url"), "{output}");
+    }
+}
@@ -0,0 +1,97 @@
+//! Pull request list rendered by the pull request picker.
+//!
+//! Shows one page of pull requests with the repository, state, title,
+//! author, and last update date, scrolled so the cursor stays visible.
+
+use crate::github::models::PullRequestSummary;
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+
+/// Rows used by the heading, status line, and key hints.
+const CHROME_ROWS: usize = 5;
+
+const KEY_HINTS: &str = "j/k: move  Enter: open in review TUI  q/Esc: quit";
+
+/// Context for rendering the pull request picker.
+#[derive(Debug, Clone)]
+pub(crate) struct PullRequestPickerViewContext<'a> {
+    /// Title line, such as the search query.
+    pub heading: &'a str,
+    /// Line describing how much of the listing is shown.
+    pub status: &'a str,
+    /// Pull requests to choose from.
+    pub items: &'a [PullRequestSummary],
+    /// Notes shown after the pull request at the same index.
+    pub notes: &'a [String],
+    /// Index of the highlighted pull request.
+    pub cursor: usize,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the pull request picker.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct PullRequestPickerComponent;
+
+impl PullRequestPickerComponent {
+    /// Renders the picker, scrolled so the highlighted pull request is
+    /// visible.
+    #[must_use]
+    pub fn view(ctx: &PullRequestPickerViewContext<'_>) -> String {
+        let mut lines = vec![ctx.heading.to_owned(), ctx.status.to_owned(), String::new()];
+        lines.extend(list_rows(ctx));
+        lines.push(String::new());
+        lines.push(KEY_HINTS.to_owned());
+
+        let mut output = String::new();
+        for line in &lines {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line,
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Builds the visible list rows.
+fn list_rows(ctx: &PullRequestPickerViewContext<'_>) -> Vec<String> {
+    if ctx.items.is_empty() {
+        return vec!["  (no pull requests match)".to_owned()];
+    }
+    let visible = ctx.max_height.saturating_sub(CHROME_ROWS).max(1);
+    let offset = ctx.cursor.saturating_sub(visible - 1);
+    ctx.items
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(index, item)| {
+            let marker = if index == ctx.cursor { '>' } else { ' ' };
+            let line = format!("{marker} {}", summary_line(item));
+            ctx.notes
+                .get(index)
+                .map_or_else(|| line.clone(), |note| format!("{line} - {note}"))
+        })
+        .collect()
+}
+
+/// Formats one pull request as
+/// `octo/repo#42 [open] Title (@author, updated 2026-04-17)`.
+fn summary_line(item: &PullRequestSummary) -> String {
+    let updated = item
+        .updated_at
+        .as_deref()
+        .and_then(|timestamp| timestamp.get(..10))
+        .map(|date| format!(", updated {date}"))
+        .unwrap_or_default();
+    format!(
+        "{} [{}] {} (@{}{updated})",
+        item.reference(),
+        item.state.as_deref().unwrap_or("unknown"),
+        item.title.as_deref().unwrap_or("(no title)"),
+        item.author.as_deref().unwrap_or("unknown"),
+    )
+}
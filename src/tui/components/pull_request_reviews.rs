@@ -0,0 +1,180 @@
+//! Full-screen pull request reviews view for the review TUI.
+//!
+//! Lists the top-level reviews submitted on the pull request with their
+//! verdicts and summary bodies, so approvals and change requests are visible
+//! alongside the inline review threads.
+
+use crate::github::models::PullRequestReview;
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+use crate::tui::components::text_wrap::wrap_text;
+
+/// Indentation applied to review body lines.
+const BODY_INDENT: &str = "    ";
+
+/// Context for rendering the pull request reviews view.
+#[derive(Debug, Clone)]
+pub(crate) struct PullRequestReviewsViewContext<'a> {
+    /// Reviews to render, in submission order.
+    pub reviews: &'a [PullRequestReview],
+    /// Index of the selected review.
+    pub selected_index: usize,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the pull request reviews view.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct PullRequestReviewsComponent;
+
+impl PullRequestReviewsComponent {
+    /// Renders the review list, scrolled so the selected review is visible.
+    #[must_use]
+    pub fn view(ctx: &PullRequestReviewsViewContext<'_>) -> String {
+        let (lines, selected_row) = render_lines(ctx);
+        let max_height = ctx.max_height.max(1);
+        let offset = if selected_row < max_height {
+            0
+        } else {
+            selected_row
+        };
+
+        let mut output = String::new();
+        for line in lines.iter().skip(offset).take(max_height) {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line.as_str(),
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// Builds the view lines and returns the row of the selected review header.
+fn render_lines(ctx: &PullRequestReviewsViewContext<'_>) -> (Vec<String>, usize) {
+    let mut lines = vec![format!("Pull request reviews ({}):", ctx.reviews.len())];
+    if ctx.reviews.is_empty() {
+        lines.push("  (no reviews have been submitted)".to_owned());
+        return (lines, 0);
+    }
+
+    let body_width = ctx.max_width.saturating_sub(BODY_INDENT.len()).max(1);
+    let mut selected_row = 0;
+    for (index, review) in ctx.reviews.iter().enumerate() {
+        lines.push(String::new());
+        let marker = if index == ctx.selected_index {
+            selected_row = lines.len();
+            '>'
+        } else {
+            ' '
+        };
+        lines.push(format!("{marker} {}", review_header(review)));
+
+        match review.body.as_deref() {
+            Some(body) => lines.extend(
+                wrap_text(body, body_width)
+                    .lines()
+                    .map(|line| format!("{BODY_INDENT}{line}")),
+            ),
+            None => lines.push(format!("{BODY_INDENT}(no summary)")),
+        }
+    }
+
+    (lines, selected_row)
+}
+
+fn review_header(review: &PullRequestReview) -> String {
+    let author = review.author.as_deref().unwrap_or("(unknown)");
+    let verdict = state_label(review.state.as_deref());
+    review.submitted_at.as_deref().map_or_else(
+        || format!("{author}  {verdict}"),
+        |submitted_at| format!("{author}  {verdict}  {submitted_at}"),
+    )
+}
+
+/// Maps a GitHub review state to a human-readable verdict.
+pub(super) fn state_label(state: Option<&str>) -> &str {
+    match state {
+        Some("APPROVED") => "Approved",
+        Some("CHANGES_REQUESTED") => "Changes requested",
+        Some("COMMENTED") => "Commented",
+        Some("DISMISSED") => "Dismissed",
+        Some("PENDING") => "Pending",
+        Some(other) => other,
+        None => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `pull_request_reviews` module.
+
+    use rstest::rstest;
+
+    use super::{PullRequestReviewsComponent, PullRequestReviewsViewContext};
+    use crate::github::models::PullRequestReview;
+
+    fn review(id: u64, author: &str, state: &str, body: Option<&str>) -> PullRequestReview {
+        PullRequestReview {
+            id,
+            author: Some(author.to_owned()),
+            state: Some(state.to_owned()),
+            body: body.map(str::to_owned),
+            submitted_at: Some("2025-01-01T00:00:00Z".to_owned()),
+            ..PullRequestReview::default()
+        }
+    }
+
+    fn render(reviews: &[PullRequestReview], selected_index: usize, max_height: usize) -> String {
+        PullRequestReviewsComponent::view(&PullRequestReviewsViewContext {
+            reviews,
+            selected_index,
+            max_width: 80,
+            max_height,
+        })
+    }
+
+    #[rstest]
+    fn view_lists_verdicts_and_bodies() {
+        let reviews = vec![
+            review(1, "alice", "APPROVED", Some("Looks good")),
+            review(2, "bot", "CHANGES_REQUESTED", None),
+        ];
+
+        let output = render(&reviews, 0, 20);
+
+        assert!(output.contains("Pull request reviews (2):"), "{output}");
+        assert!(
+            output.contains("> alice  Approved  2025-01-01T00:00:00Z"),
+            "{output}"
+        );
+        assert!(output.contains("    Looks good"), "{output}");
+        assert!(output.contains("  bot  Changes requested"), "{output}");
+        assert!(output.contains("    (no summary)"), "{output}");
+    }
+
+    #[rstest]
+    fn view_shows_placeholder_without_reviews() {
+        let output = render(&[], 0, 20);
+
+        assert!(
+            output.contains("(no reviews have been submitted)"),
+            "{output}"
+        );
+    }
+
+    #[rstest]
+    fn view_scrolls_to_selected_review() {
+        let reviews: Vec<_> = (1..=5)
+            .map(|id| review(id, &format!("user{id}"), "COMMENTED", Some("Body")))
+            .collect();
+
+        let output = render(&reviews, 4, 4);
+
+        assert!(output.starts_with("> user5"), "{output}");
+        assert_eq!(output.lines().count(), 2, "{output}");
+    }
+}
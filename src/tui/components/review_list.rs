@@ -3,7 +3,7 @@
 //! This component renders a scrollable list of review comments with cursor
 //! highlighting and displays relevant metadata for each comment.
 
-use crate::github::models::ReviewComment;
+use crate::github::models::{ReviewComment, format_reactions};
 use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
 use crate::verification::{CommentVerificationResult, GithubCommentId};
 
@@ -144,8 +144,12 @@ impl ReviewListComponent {
             .map(|line| truncate_to_display_width_with_ellipsis(line, BODY_PREVIEW_WIDTH))
             .unwrap_or_default();
 
-        let line =
-            format!("{prefix}{verification_symbol} [{author}] {file}{line_num}: {body_preview}");
+        let reactions = format_reactions(&review.reactions)
+            .map_or_else(String::new, |summary| format!("  {summary}"));
+
+        let line = format!(
+            "{prefix}{verification_symbol} [{author}] {file}{line_num}: {body_preview}{reactions}"
+        );
         truncate_to_display_width_with_ellipsis(&line, max_width)
     }
 }
@@ -186,12 +190,16 @@ mod tests {
                 author: self.author,
                 file_path: self.file_path,
                 line_number: self.line_number,
+                side: None,
                 original_line_number: None,
                 diff_hunk: None,
                 commit_sha: None,
                 in_reply_to_id: None,
                 created_at: None,
                 updated_at: None,
+                thread_state: None,
+                start_line_number: None,
+                reactions: Vec::new(),
             }
         }
     }
@@ -301,6 +309,18 @@ mod tests {
         assert!(line.contains("Consider refactoring"));
     }
 
+    #[rstest]
+    fn format_review_line_appends_reaction_counts(mut sample_review: ReviewComment) {
+        sample_review.reactions = vec![crate::github::models::ReactionCount {
+            content: crate::github::models::ReactionContent::Eyes,
+            count: 2,
+        }];
+
+        let line = ReviewListComponent::format_review_line(&sample_review, " ", None, 100);
+
+        assert!(line.ends_with("  👀 2"), "unexpected line: {line}");
+    }
+
     #[rstest]
     fn format_review_line_includes_verification_marker(sample_review: ReviewComment) {
         let line = verified_line(&sample_review, 80);
@@ -0,0 +1,135 @@
+//! Full-screen pending review composer view for the review TUI.
+
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+use crate::tui::state::ReviewComposerState;
+
+/// Context for rendering the pending review composer.
+#[derive(Debug, Clone)]
+pub(crate) struct ReviewSubmissionViewContext<'a> {
+    /// Composer state to render.
+    pub composer: &'a ReviewComposerState,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the pending review composer.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ReviewSubmissionComponent;
+
+impl ReviewSubmissionComponent {
+    /// Renders the pending review composer.
+    #[must_use]
+    pub fn view(ctx: &ReviewSubmissionViewContext<'_>) -> String {
+        let mut output = String::new();
+
+        for line in render_lines(ctx.composer)
+            .iter()
+            .take(ctx.max_height.max(1))
+        {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line.as_str(),
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+fn render_lines(composer: &ReviewComposerState) -> Vec<String> {
+    let mut lines = vec![
+        format!("Review: {}", composer.event()),
+        "Summary:".to_owned(),
+    ];
+
+    if composer.body().is_empty() {
+        lines.push("  (empty)".to_owned());
+    } else {
+        lines.extend(composer.body().lines().map(|line| format!("  {line}")));
+    }
+
+    let pending = composer.pending_comments();
+    lines.push(String::new());
+    lines.push(format!("Inline comments ({}):", pending.len()));
+    if pending.is_empty() {
+        lines.push(
+            "  (none; press Tab in a reply draft or a in diff context to add one)".to_owned(),
+        );
+    }
+    for comment in pending.iter().map(|queued| &queued.comment) {
+        lines.push(format!(
+            "  {}:{} [{}] {}",
+            comment.path,
+            comment.line,
+            comment.side.as_api_str(),
+            comment.body.replace('\n', " ")
+        ));
+    }
+
+    if composer.is_confirming() {
+        lines.push(String::new());
+        lines.push(format!(
+            "Submit {} review with {} inline comment(s)? [y/n]",
+            composer.event(),
+            pending.len()
+        ));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `review_submission` module.
+
+    use rstest::rstest;
+
+    use super::{ReviewSubmissionComponent, ReviewSubmissionViewContext};
+    use crate::github::review_submission::PendingReviewComment;
+    use crate::tui::state::ReviewComposerState;
+
+    fn render(composer: &ReviewComposerState) -> String {
+        ReviewSubmissionComponent::view(&ReviewSubmissionViewContext {
+            composer,
+            max_width: 120,
+            max_height: 20,
+        })
+    }
+
+    #[rstest]
+    fn view_lists_event_summary_and_pending_comments() {
+        let mut composer = ReviewComposerState::default();
+        composer.cycle_event();
+        for character in "Ship it".chars() {
+            composer.push_char(character);
+        }
+        composer.queue_comment(PendingReviewComment::new("src/main.rs", 12, "Nice"));
+
+        let output = render(&composer);
+
+        assert!(output.contains("Review: Approve"), "{output}");
+        assert!(output.contains("  Ship it"), "{output}");
+        assert!(output.contains("Inline comments (1):"), "{output}");
+        assert!(output.contains("src/main.rs:12 [RIGHT] Nice"), "{output}");
+        assert!(!output.contains("[y/n]"), "{output}");
+    }
+
+    #[rstest]
+    fn view_shows_confirmation_prompt() {
+        let mut composer = ReviewComposerState::default();
+        composer.cycle_event();
+        composer
+            .request_confirm()
+            .expect("approval should be valid");
+
+        let output = render(&composer);
+
+        assert!(
+            output.contains("Submit Approve review with 0 inline comment(s)? [y/n]"),
+            "{output}"
+        );
+    }
+}
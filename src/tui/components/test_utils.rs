@@ -98,12 +98,16 @@ impl ReviewCommentBuilder {
             author: self.author,
             file_path: self.file_path,
             line_number: self.line_number,
+            side: None,
             original_line_number: None,
             diff_hunk: self.diff_hunk,
             commit_sha: self.commit_sha,
             in_reply_to_id: None,
             created_at: None,
             updated_at: None,
+            thread_state: None,
+            start_line_number: None,
+            reactions: Vec::new(),
         }
     }
 }
@@ -0,0 +1,259 @@
+//! Full-screen conversation timeline view for the review TUI.
+//!
+//! Shows conversation comments, submitted reviews, and review threads in
+//! one chronological list, each badged with its kind, so the whole pull
+//! request discussion reads top to bottom.
+
+use crate::github::models::{ReviewComment, TimelineEntry};
+use crate::tui::components::text_truncate::truncate_to_display_width_with_ellipsis;
+use crate::tui::components::text_wrap::wrap_text;
+
+use super::pull_request_reviews::state_label;
+
+/// Indentation applied to entry body lines.
+const BODY_INDENT: &str = "    ";
+
+/// Indentation applied to thread reply headers.
+const REPLY_INDENT: &str = "      ";
+
+/// Indentation applied to thread reply body lines.
+const REPLY_BODY_INDENT: &str = "        ";
+
+/// Context for rendering the conversation timeline view.
+#[derive(Debug, Clone)]
+pub(crate) struct TimelineViewContext<'a> {
+    /// Entries to render, oldest first.
+    pub entries: &'a [TimelineEntry<'a>],
+    /// Index of the selected entry.
+    pub selected_index: usize,
+    /// Label of the active filter, or `None` when everything is shown.
+    pub filter_label: Option<String>,
+    /// Maximum visible width in display columns.
+    pub max_width: usize,
+    /// Maximum visible height in rows.
+    pub max_height: usize,
+}
+
+/// Stateless component rendering the conversation timeline view.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TimelineComponent;
+
+impl TimelineComponent {
+    /// Renders the timeline, scrolled so the selected entry is visible.
+    #[must_use]
+    pub fn view(ctx: &TimelineViewContext<'_>) -> String {
+        let (lines, selected_row) = render_lines(ctx);
+        let max_height = ctx.max_height.max(1);
+        let offset = if selected_row < max_height {
+            0
+        } else {
+            selected_row
+        };
+
+        let mut output = String::new();
+        for line in lines.iter().skip(offset).take(max_height) {
+            output.push_str(&truncate_to_display_width_with_ellipsis(
+                line.as_str(),
+                ctx.max_width,
+            ));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+/// Builds the view lines and returns the row of the selected entry header.
+fn render_lines(ctx: &TimelineViewContext<'_>) -> (Vec<String>, usize) {
+    let count = ctx.entries.len();
+    let title = ctx.filter_label.as_deref().map_or_else(
+        || format!("Conversation timeline ({count}):"),
+        |label| format!("Conversation timeline ({count}, filter: {label}):"),
+    );
+    let mut lines = vec![title];
+    if ctx.entries.is_empty() {
+        let placeholder = if ctx.filter_label.is_some() {
+            "  (no entries match the active filter)"
+        } else {
+            "  (no conversation yet)"
+        };
+        lines.push(placeholder.to_owned());
+        return (lines, 0);
+    }
+
+    let mut selected_row = 0;
+    for (index, entry) in ctx.entries.iter().enumerate() {
+        lines.push(String::new());
+        let marker = if index == ctx.selected_index {
+            selected_row = lines.len();
+            '>'
+        } else {
+            ' '
+        };
+        lines.push(format!("{marker} {}", entry_header(entry)));
+        push_body(&mut lines, entry.body(), BODY_INDENT, ctx.max_width);
+
+        if let TimelineEntry::ReviewThread(thread) = entry {
+            for reply in &thread.replies {
+                lines.push(format!("{REPLY_INDENT}{}", reply_header(reply)));
+                push_body(
+                    &mut lines,
+                    reply.body.as_deref(),
+                    REPLY_BODY_INDENT,
+                    ctx.max_width,
+                );
+            }
+        }
+    }
+
+    (lines, selected_row)
+}
+
+/// Appends `body` wrapped to the available width and indented.
+fn push_body(lines: &mut Vec<String>, body: Option<&str>, indent: &str, max_width: usize) {
+    let width = max_width.saturating_sub(indent.len()).max(1);
+    match body.filter(|text| !text.trim().is_empty()) {
+        Some(text) => lines.extend(
+            wrap_text(text, width)
+                .lines()
+                .map(|line| format!("{indent}{line}")),
+        ),
+        None => lines.push(format!("{indent}(no text)")),
+    }
+}
+
+fn entry_header(entry: &TimelineEntry<'_>) -> String {
+    let mut parts = vec![
+        format!("[{}]", entry.kind().badge()),
+        entry.author().unwrap_or("(unknown)").to_owned(),
+    ];
+    match entry {
+        TimelineEntry::IssueComment(_) => {}
+        TimelineEntry::Review(review) => {
+            parts.push(state_label(review.state.as_deref()).to_owned());
+        }
+        TimelineEntry::ReviewThread(thread) => parts.push(thread_location(thread.root)),
+    }
+    parts.extend(entry.timestamp().map(str::to_owned));
+    parts.join("  ")
+}
+
+fn reply_header(reply: &ReviewComment) -> String {
+    let author = reply.author.as_deref().unwrap_or("(unknown)");
+    reply.created_at.as_deref().map_or_else(
+        || format!("{author} replied"),
+        |created_at| format!("{author} replied  {created_at}"),
+    )
+}
+
+/// Describes where a thread is anchored and whether it is resolved.
+fn thread_location(root: &ReviewComment) -> String {
+    let path = root.file_path.as_deref().unwrap_or("(unknown file)");
+    let location = root
+        .line_number
+        .map_or_else(|| path.to_owned(), |line| format!("{path}:{line}"));
+    if root
+        .thread_state
+        .as_ref()
+        .is_some_and(|state| state.is_resolved)
+    {
+        format!("{location} (resolved)")
+    } else {
+        location
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `timeline` module.
+
+    use rstest::rstest;
+
+    use super::{TimelineComponent, TimelineViewContext};
+    use crate::github::models::{
+        PullRequestComment, PullRequestReview, ReviewComment, TimelineEntry, TimelineThread,
+    };
+
+    fn render(entries: &[TimelineEntry<'_>], filter_label: Option<&str>) -> String {
+        TimelineComponent::view(&TimelineViewContext {
+            entries,
+            selected_index: 0,
+            filter_label: filter_label.map(str::to_owned),
+            max_width: 80,
+            max_height: 30,
+        })
+    }
+
+    #[rstest]
+    fn view_badges_each_kind_of_entry() {
+        let comment = PullRequestComment {
+            id: 1,
+            author: Some("carol".to_owned()),
+            body: Some("Any update?".to_owned()),
+            created_at: Some("2025-01-01T00:00:00Z".to_owned()),
+            ..PullRequestComment::default()
+        };
+        let review = PullRequestReview {
+            id: 2,
+            author: Some("alice".to_owned()),
+            state: Some("APPROVED".to_owned()),
+            body: None,
+            ..PullRequestReview::default()
+        };
+        let root = ReviewComment {
+            id: 3,
+            author: Some("bob".to_owned()),
+            body: Some("Rename this".to_owned()),
+            file_path: Some("src/lib.rs".to_owned()),
+            line_number: Some(7),
+            ..ReviewComment::default()
+        };
+        let reply = ReviewComment {
+            id: 4,
+            author: Some("carol".to_owned()),
+            body: Some("Done".to_owned()),
+            in_reply_to_id: Some(3),
+            ..ReviewComment::default()
+        };
+        let entries = [
+            TimelineEntry::IssueComment(&comment),
+            TimelineEntry::Review(&review),
+            TimelineEntry::ReviewThread(TimelineThread {
+                root: &root,
+                replies: vec![&reply],
+            }),
+        ];
+
+        let output = render(&entries, None);
+
+        assert!(output.contains("Conversation timeline (3):"), "{output}");
+        assert!(
+            output.contains("> [comment]  carol  2025-01-01T00:00:00Z"),
+            "{output}"
+        );
+        assert!(output.contains("    Any update?"), "{output}");
+        assert!(output.contains("  [review]  alice  Approved"), "{output}");
+        assert!(output.contains("    (no text)"), "{output}");
+        assert!(output.contains("  [thread]  bob  src/lib.rs:7"), "{output}");
+        assert!(output.contains("      carol replied\n"), "{output}");
+        assert!(output.contains("        Done"), "{output}");
+    }
+
+    #[rstest]
+    #[case::unfiltered(None, "Conversation timeline (0):", "(no conversation yet)")]
+    #[case::filtered(
+        Some("Unresolved"),
+        "Conversation timeline (0, filter: Unresolved):",
+        "(no entries match the active filter)"
+    )]
+    fn view_shows_placeholder_without_entries(
+        #[case] filter_label: Option<&str>,
+        #[case] title: &str,
+        #[case] placeholder: &str,
+    ) {
+        let output = render(&[], filter_label);
+
+        assert!(output.contains(title), "{output}");
+        assert!(output.contains(placeholder), "{output}");
+    }
+}
@@ -1,377 +0,0 @@
-//! Input handling for the TUI application.
-//!
-//! This module provides key-to-message mapping for translating terminal key
-//! events into application messages.
-
-use super::messages::AppMsg;
-use crate::ai::CommentRewriteMode;
-
-/// View mode for context-aware key mapping.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum InputContext {
-    /// Default review list view.
-    #[default]
-    ReviewList,
-    /// Full-screen diff context view.
-    DiffContext,
-    /// Time-travel navigation view.
-    TimeTravel,
-    /// Session resume prompt (y/n/Esc).
-    ResumePrompt,
-    /// Inline reply drafting mode.
-    ReplyDraft,
-    /// Full-screen PR discussion summary view.
-    PrDiscussionSummary,
-}
-
-/// Maps a key event to an application message.
-///
-/// Returns `None` for unrecognised key events, allowing them to be ignored.
-/// This is a convenience wrapper that assumes the `ReviewList` context.
-/// For context-aware key mapping, use `map_key_to_message_with_context`.
-#[must_use]
-pub fn map_key_to_message(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    map_key_to_message_with_context(key, InputContext::ReviewList)
-}
-
-const fn shared_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    // Default/shared mappings
-    match key.key {
-        KeyCode::Char('q') => Some(AppMsg::Quit),
-        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
-        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
-        KeyCode::PageDown => Some(AppMsg::PageDown),
-        KeyCode::PageUp => Some(AppMsg::PageUp),
-        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
-        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
-        KeyCode::Char('f') => Some(AppMsg::CycleFilter),
-        KeyCode::Esc => Some(AppMsg::EscapePressed),
-        KeyCode::Char('r') => Some(AppMsg::RefreshRequested),
-        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
-        KeyCode::Char('c') => Some(AppMsg::ShowDiffContext),
-        KeyCode::Char('t') => Some(AppMsg::EnterTimeTravel),
-        KeyCode::Char('[') => Some(AppMsg::PreviousHunk),
-        KeyCode::Char(']') => Some(AppMsg::NextHunk),
-        _ => None,
-    }
-}
-
-/// Key bindings for full-screen overlay views that navigate between items
-/// and exit back to the review list.
-struct OverlayNavigationBindings {
-    previous_key: char,
-    next_key: char,
-    previous_msg: AppMsg,
-    next_msg: AppMsg,
-    exit_msg: AppMsg,
-}
-
-/// Maps keys for overlay views sharing the previous/next/exit shape, such
-/// as time travel and the full-screen diff context.
-fn overlay_navigation_keys(
-    key: &bubbletea_rs::event::KeyMsg,
-    bindings: OverlayNavigationBindings,
-) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    match key.key {
-        KeyCode::Char(character) if character == bindings.previous_key => {
-            Some(bindings.previous_msg)
-        }
-        KeyCode::Char(character) if character == bindings.next_key => Some(bindings.next_msg),
-        KeyCode::Esc => Some(bindings.exit_msg),
-        KeyCode::Char('q') => Some(AppMsg::Quit),
-        _ => shared_keys(key),
-    }
-}
-
-/// Bindings for the time-travel overlay (`h`/`l` navigate commits).
-const TIME_TRAVEL_BINDINGS: OverlayNavigationBindings = OverlayNavigationBindings {
-    previous_key: 'h',
-    next_key: 'l',
-    previous_msg: AppMsg::PreviousCommit,
-    next_msg: AppMsg::NextCommit,
-    exit_msg: AppMsg::ExitTimeTravel,
-};
-
-/// Bindings for the diff-context overlay (`[`/`]` navigate hunks).
-const DIFF_CONTEXT_BINDINGS: OverlayNavigationBindings = OverlayNavigationBindings {
-    previous_key: '[',
-    next_key: ']',
-    previous_msg: AppMsg::PreviousHunk,
-    next_msg: AppMsg::NextHunk,
-    exit_msg: AppMsg::HideDiffContext,
-};
-
-const fn resume_prompt_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    match key.key {
-        KeyCode::Char('y') => Some(AppMsg::ResumeAccepted),
-        KeyCode::Char('n') | KeyCode::Esc => Some(AppMsg::ResumeDeclined),
-        KeyCode::Char('q') => Some(AppMsg::Quit),
-        _ => None,
-    }
-}
-
-const fn review_list_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    match key.key {
-        KeyCode::Char('x') => Some(AppMsg::StartCodexExecution),
-        KeyCode::Char('s') => Some(AppMsg::GeneratePrDiscussionSummary),
-        KeyCode::Char('a') => Some(AppMsg::StartReplyDraft),
-        KeyCode::Char('v') => Some(AppMsg::VerifySelectedComment),
-        KeyCode::Char('V') => Some(AppMsg::VerifyFilteredComments),
-        _ => shared_keys(key),
-    }
-}
-
-const fn reply_draft_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    match key.key {
-        KeyCode::Enter => Some(AppMsg::ReplyDraftRequestSend),
-        KeyCode::Backspace => Some(AppMsg::ReplyDraftBackspace),
-        KeyCode::Esc => Some(AppMsg::ReplyDraftCancel),
-        KeyCode::Char('E') => Some(AppMsg::ReplyDraftRequestAiRewrite {
-            mode: CommentRewriteMode::Expand,
-        }),
-        KeyCode::Char('W') => Some(AppMsg::ReplyDraftRequestAiRewrite {
-            mode: CommentRewriteMode::Reword,
-        }),
-        KeyCode::Char('Y') => Some(AppMsg::ReplyDraftAiApply),
-        KeyCode::Char('N') => Some(AppMsg::ReplyDraftAiDiscard),
-        KeyCode::Char(character @ '1'..='9') => {
-            let template_index = character as usize - '1' as usize;
-            Some(AppMsg::ReplyDraftInsertTemplate { template_index })
-        }
-        KeyCode::Char(character) => Some(AppMsg::ReplyDraftInsertChar(character)),
-        _ => None,
-    }
-}
-
-const fn pr_discussion_summary_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
-    use crossterm::event::KeyCode;
-
-    match key.key {
-        KeyCode::Char('q') => Some(AppMsg::Quit),
-        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
-        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
-        KeyCode::PageDown => Some(AppMsg::PageDown),
-        KeyCode::PageUp => Some(AppMsg::PageUp),
-        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
-        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
-        KeyCode::Enter => Some(AppMsg::OpenSelectedPrDiscussionSummaryLink),
-        KeyCode::Esc => Some(AppMsg::HidePrDiscussionSummary),
-        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
-        _ => None,
-    }
-}
-
-/// Maps a key event to an application message with view context.
-///
-/// Different view modes may interpret the same key differently. For example,
-/// `h` and `l` are navigation keys in time-travel mode but have no function
-/// in the review list.
-#[must_use]
-#[doc(hidden)]
-pub fn map_key_to_message_with_context(
-    key: &bubbletea_rs::event::KeyMsg,
-    context: InputContext,
-) -> Option<AppMsg> {
-    match context {
-        InputContext::TimeTravel => overlay_navigation_keys(key, TIME_TRAVEL_BINDINGS),
-        InputContext::DiffContext => overlay_navigation_keys(key, DIFF_CONTEXT_BINDINGS),
-        InputContext::ResumePrompt => resume_prompt_keys(key),
-        InputContext::ReviewList => review_list_keys(key),
-        InputContext::ReplyDraft => reply_draft_keys(key),
-        InputContext::PrDiscussionSummary => pr_discussion_summary_keys(key),
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    //! Unit tests for the `input` module.
-
-    use super::*;
-    use bubbletea_rs::event::KeyMsg;
-    use crossterm::event::{KeyCode, KeyModifiers};
-    use rstest::rstest;
-
-    fn key_msg(key: KeyCode) -> KeyMsg {
-        KeyMsg {
-            key,
-            modifiers: KeyModifiers::empty(),
-        }
-    }
-
-    #[rstest]
-    #[case::time_travel_h_previous(
-        KeyCode::Char('h'),
-        Some(InputContext::TimeTravel),
-        Some(AppMsg::PreviousCommit)
-    )]
-    #[case::time_travel_l_next(
-        KeyCode::Char('l'),
-        Some(InputContext::TimeTravel),
-        Some(AppMsg::NextCommit)
-    )]
-    #[case::time_travel_esc_exit(
-        KeyCode::Esc,
-        Some(InputContext::TimeTravel),
-        Some(AppMsg::ExitTimeTravel)
-    )]
-    #[case::review_list_t_enter(
-        KeyCode::Char('t'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::EnterTimeTravel)
-    )]
-    #[case::review_list_x_start_codex(
-        KeyCode::Char('x'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::StartCodexExecution)
-    )]
-    #[case::review_list_a_start_reply_draft(
-        KeyCode::Char('a'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::StartReplyDraft)
-    )]
-    #[case::review_list_s_generate_summary(
-        KeyCode::Char('s'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::GeneratePrDiscussionSummary)
-    )]
-    #[case::review_list_v_verify_selected(
-        KeyCode::Char('v'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::VerifySelectedComment)
-    )]
-    #[case::review_list_shift_v_verify_filtered(
-        KeyCode::Char('V'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::VerifyFilteredComments)
-    )]
-    #[case::time_travel_x_unmapped(KeyCode::Char('x'), Some(InputContext::TimeTravel), None)]
-    #[case::diff_context_esc_hide(
-        KeyCode::Esc,
-        Some(InputContext::DiffContext),
-        Some(AppMsg::HideDiffContext)
-    )]
-    #[case::review_list_j_down(
-        KeyCode::Char('j'),
-        Some(InputContext::ReviewList),
-        Some(AppMsg::CursorDown)
-    )]
-    #[case::resume_prompt_y_accepted(
-        KeyCode::Char('y'),
-        Some(InputContext::ResumePrompt),
-        Some(AppMsg::ResumeAccepted)
-    )]
-    #[case::resume_prompt_n_declined(
-        KeyCode::Char('n'),
-        Some(InputContext::ResumePrompt),
-        Some(AppMsg::ResumeDeclined)
-    )]
-    #[case::resume_prompt_esc_declined(
-        KeyCode::Esc,
-        Some(InputContext::ResumePrompt),
-        Some(AppMsg::ResumeDeclined)
-    )]
-    #[case::resume_prompt_j_unmapped(KeyCode::Char('j'), Some(InputContext::ResumePrompt), None)]
-    #[case::reply_draft_insert_template(
-        KeyCode::Char('2'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftInsertTemplate { template_index: 1 })
-    )]
-    #[case::reply_draft_ai_expand(
-        KeyCode::Char('E'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftRequestAiRewrite { mode: CommentRewriteMode::Expand })
-    )]
-    #[case::reply_draft_ai_reword(
-        KeyCode::Char('W'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftRequestAiRewrite { mode: CommentRewriteMode::Reword })
-    )]
-    #[case::reply_draft_ai_apply(
-        KeyCode::Char('Y'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftAiApply)
-    )]
-    #[case::reply_draft_ai_discard(
-        KeyCode::Char('N'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftAiDiscard)
-    )]
-    #[case::reply_draft_insert_char(
-        KeyCode::Char('q'),
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftInsertChar('q'))
-    )]
-    #[case::reply_draft_backspace(
-        KeyCode::Backspace,
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftBackspace)
-    )]
-    #[case::reply_draft_enter_send(
-        KeyCode::Enter,
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftRequestSend)
-    )]
-    #[case::reply_draft_esc_cancel(
-        KeyCode::Esc,
-        Some(InputContext::ReplyDraft),
-        Some(AppMsg::ReplyDraftCancel)
-    )]
-    #[case::summary_enter_open(
-        KeyCode::Enter,
-        Some(InputContext::PrDiscussionSummary),
-        Some(AppMsg::OpenSelectedPrDiscussionSummaryLink)
-    )]
-    #[case::summary_esc_close(
-        KeyCode::Esc,
-        Some(InputContext::PrDiscussionSummary),
-        Some(AppMsg::HidePrDiscussionSummary)
-    )]
-    #[case::summary_j_down(
-        KeyCode::Char('j'),
-        Some(InputContext::PrDiscussionSummary),
-        Some(AppMsg::CursorDown)
-    )]
-    #[case::default_context_j_down(KeyCode::Char('j'), None, Some(AppMsg::CursorDown))]
-    fn key_mapping(
-        #[case] key: KeyCode,
-        #[case] ctx: Option<InputContext>,
-        #[case] expected: Option<AppMsg>,
-    ) {
-        let result = ctx.map_or_else(
-            || map_key_to_message(&key_msg(key)),
-            |context| map_key_to_message_with_context(&key_msg(key), context),
-        );
-
-        // Compare enum variants using discriminant
-        match (result, expected) {
-            (Some(r), Some(e)) => {
-                assert_eq!(std::mem::discriminant(&r), std::mem::discriminant(&e));
-            }
-            (None, None) => {}
-            (r, e) => {
-                panic!("Some/None mismatch: result={r:?}, expected={e:?}");
-            }
-        }
-    }
-
-    #[test]
-    fn reply_draft_template_index_maps_digit_to_zero_based_slot() {
-        let result =
-            map_key_to_message_with_context(&key_msg(KeyCode::Char('9')), InputContext::ReplyDraft);
-
-        assert!(matches!(
-            result,
-            Some(AppMsg::ReplyDraftInsertTemplate { template_index: 8 })
-        ));
-    }
-}
@@ -0,0 +1,67 @@
+//! Key maps for composing replies, inline comments, and reviews.
+
+use crate::ai::CommentRewriteMode;
+use crate::tui::messages::AppMsg;
+
+pub(super) const fn reply_draft_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Enter => Some(AppMsg::ReplyDraftRequestSend),
+        KeyCode::Tab => Some(AppMsg::QueueReplyDraftForReview),
+        KeyCode::Backspace => Some(AppMsg::ReplyDraftBackspace),
+        KeyCode::Esc => Some(AppMsg::ReplyDraftCancel),
+        KeyCode::Char('E') => Some(AppMsg::ReplyDraftRequestAiRewrite {
+            mode: CommentRewriteMode::Expand,
+        }),
+        KeyCode::Char('W') => Some(AppMsg::ReplyDraftRequestAiRewrite {
+            mode: CommentRewriteMode::Reword,
+        }),
+        KeyCode::Char('Y') => Some(AppMsg::ReplyDraftAiApply),
+        KeyCode::Char('N') => Some(AppMsg::ReplyDraftAiDiscard),
+        KeyCode::Char(character @ '1'..='9') => {
+            let template_index = character as usize - '1' as usize;
+            Some(AppMsg::ReplyDraftInsertTemplate { template_index })
+        }
+        KeyCode::Char(character) => Some(AppMsg::ReplyDraftInsertChar(character)),
+        _ => None,
+    }
+}
+
+pub(super) const fn diff_comment_draft_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Enter => Some(AppMsg::DiffCommentSave),
+        KeyCode::Backspace => Some(AppMsg::DiffCommentBackspace),
+        KeyCode::Esc => Some(AppMsg::DiffCommentCancel),
+        KeyCode::Char(character) => Some(AppMsg::DiffCommentInsertChar(character)),
+        _ => None,
+    }
+}
+
+pub(super) const fn review_submission_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Enter => Some(AppMsg::ReviewComposerRequestSubmit),
+        KeyCode::Tab => Some(AppMsg::ReviewComposerCycleEvent),
+        KeyCode::Backspace => Some(AppMsg::ReviewComposerBackspace),
+        KeyCode::Delete => Some(AppMsg::ReviewComposerDropLastComment),
+        KeyCode::Esc => Some(AppMsg::HideReviewComposer),
+        KeyCode::Char(character) => Some(AppMsg::ReviewComposerInsertChar(character)),
+        _ => None,
+    }
+}
+
+pub(super) const fn review_submission_confirm_keys(
+    key: &bubbletea_rs::event::KeyMsg,
+) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('y') => Some(AppMsg::ReviewComposerConfirmSubmit),
+        KeyCode::Char('n') | KeyCode::Esc => Some(AppMsg::ReviewComposerCancelConfirm),
+        _ => None,
+    }
+}
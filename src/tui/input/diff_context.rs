@@ -0,0 +1,69 @@
+//! Key maps for the diff context and time-travel overlays.
+
+use super::shared_keys;
+use crate::tui::messages::AppMsg;
+
+/// Key bindings for full-screen overlay views that navigate between items
+/// and exit back to the review list.
+struct OverlayNavigationBindings {
+    previous_key: char,
+    next_key: char,
+    previous_msg: AppMsg,
+    next_msg: AppMsg,
+    exit_msg: AppMsg,
+}
+
+/// Maps keys for overlay views sharing the previous/next/exit shape, such
+/// as time travel and the full-screen diff context.
+fn overlay_navigation_keys(
+    key: &bubbletea_rs::event::KeyMsg,
+    bindings: OverlayNavigationBindings,
+) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char(character) if character == bindings.previous_key => {
+            Some(bindings.previous_msg)
+        }
+        KeyCode::Char(character) if character == bindings.next_key => Some(bindings.next_msg),
+        KeyCode::Esc => Some(bindings.exit_msg),
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        _ => shared_keys(key),
+    }
+}
+
+/// Bindings for the time-travel overlay (`h`/`l` navigate commits).
+const TIME_TRAVEL_BINDINGS: OverlayNavigationBindings = OverlayNavigationBindings {
+    previous_key: 'h',
+    next_key: 'l',
+    previous_msg: AppMsg::PreviousCommit,
+    next_msg: AppMsg::NextCommit,
+    exit_msg: AppMsg::ExitTimeTravel,
+};
+
+/// Bindings for the diff-context overlay (`[`/`]` navigate hunks).
+const DIFF_CONTEXT_BINDINGS: OverlayNavigationBindings = OverlayNavigationBindings {
+    previous_key: '[',
+    next_key: ']',
+    previous_msg: AppMsg::PreviousHunk,
+    next_msg: AppMsg::NextHunk,
+    exit_msg: AppMsg::HideDiffContext,
+};
+
+/// Maps keys for the diff-context overlay, adding the line cursor and new
+/// comment keys ahead of the shared overlay bindings.
+pub(super) fn diff_context_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::DiffLineDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::DiffLineUp),
+        KeyCode::Char('a') => Some(AppMsg::StartDiffCommentDraft),
+        _ => overlay_navigation_keys(key, DIFF_CONTEXT_BINDINGS),
+    }
+}
+
+/// Maps keys for the time-travel overlay.
+pub(super) fn time_travel_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    overlay_navigation_keys(key, TIME_TRAVEL_BINDINGS)
+}
@@ -0,0 +1,128 @@
+//! Input handling for the TUI application.
+//!
+//! This module provides key-to-message mapping for translating terminal key
+//! events into application messages.
+
+use super::messages::AppMsg;
+
+mod composer;
+mod diff_context;
+mod outbox;
+mod review_list;
+mod timeline;
+
+use composer::{
+    diff_comment_draft_keys, reply_draft_keys, review_submission_confirm_keys,
+    review_submission_keys,
+};
+use diff_context::{diff_context_keys, time_travel_keys};
+use outbox::outbox_keys;
+use review_list::{
+    comment_delete_confirm_keys, reaction_picker_keys, resume_prompt_keys, review_list_keys,
+};
+use timeline::{
+    ci_checks_keys, pr_discussion_summary_keys, pull_request_reviews_keys, timeline_keys,
+};
+
+/// View mode for context-aware key mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputContext {
+    /// Default review list view.
+    #[default]
+    ReviewList,
+    /// Full-screen diff context view.
+    DiffContext,
+    /// New inline comment drafting in the diff context view.
+    DiffCommentDraft,
+    /// Time-travel navigation view.
+    TimeTravel,
+    /// Session resume prompt (y/n/Esc).
+    ResumePrompt,
+    /// Inline reply drafting mode.
+    ReplyDraft,
+    /// Reaction picker for the selected comment (1-8/Esc).
+    ReactionPicker,
+    /// Comment deletion confirmation (y/n/Esc).
+    CommentDeleteConfirm,
+    /// Full-screen PR discussion summary view.
+    PrDiscussionSummary,
+    /// Pending review composer view.
+    ReviewSubmission,
+    /// Pending review submit confirmation (y/n/Esc).
+    ReviewSubmissionConfirm,
+    /// Full-screen pull request reviews view.
+    PullRequestReviews,
+    /// Full-screen conversation timeline view.
+    Timeline,
+    /// Full-screen CI checks view.
+    CiChecks,
+    /// Full-screen queued writes view.
+    Outbox,
+}
+
+/// Maps a key event to an application message.
+///
+/// Returns `None` for unrecognised key events, allowing them to be ignored.
+/// This is a convenience wrapper that assumes the `ReviewList` context.
+/// For context-aware key mapping, use `map_key_to_message_with_context`.
+#[must_use]
+pub fn map_key_to_message(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    map_key_to_message_with_context(key, InputContext::ReviewList)
+}
+
+const fn shared_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    // Default/shared mappings
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::PageDown => Some(AppMsg::PageDown),
+        KeyCode::PageUp => Some(AppMsg::PageUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Char('f') => Some(AppMsg::CycleFilter),
+        KeyCode::Esc => Some(AppMsg::EscapePressed),
+        KeyCode::Char('r') => Some(AppMsg::RefreshRequested),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        KeyCode::Char('c') => Some(AppMsg::ShowDiffContext),
+        KeyCode::Char('t') => Some(AppMsg::EnterTimeTravel),
+        KeyCode::Char('[') => Some(AppMsg::PreviousHunk),
+        KeyCode::Char(']') => Some(AppMsg::NextHunk),
+        _ => None,
+    }
+}
+
+/// Maps a key event to an application message with view context.
+///
+/// Different view modes may interpret the same key differently. For example,
+/// `h` and `l` are navigation keys in time-travel mode but have no function
+/// in the review list.
+#[must_use]
+#[doc(hidden)]
+pub fn map_key_to_message_with_context(
+    key: &bubbletea_rs::event::KeyMsg,
+    context: InputContext,
+) -> Option<AppMsg> {
+    match context {
+        InputContext::TimeTravel => time_travel_keys(key),
+        InputContext::DiffContext => diff_context_keys(key),
+        InputContext::DiffCommentDraft => diff_comment_draft_keys(key),
+        InputContext::ResumePrompt => resume_prompt_keys(key),
+        InputContext::ReviewList => review_list_keys(key),
+        InputContext::ReplyDraft => reply_draft_keys(key),
+        InputContext::ReactionPicker => reaction_picker_keys(key),
+        InputContext::CommentDeleteConfirm => comment_delete_confirm_keys(key),
+        InputContext::PrDiscussionSummary => pr_discussion_summary_keys(key),
+        InputContext::ReviewSubmission => review_submission_keys(key),
+        InputContext::ReviewSubmissionConfirm => review_submission_confirm_keys(key),
+        InputContext::PullRequestReviews => pull_request_reviews_keys(key),
+        InputContext::Timeline => timeline_keys(key),
+        InputContext::CiChecks => ci_checks_keys(key),
+        InputContext::Outbox => outbox_keys(key),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,21 @@
+//! Key maps for the queued writes view.
+
+use crate::tui::messages::AppMsg;
+
+pub(super) const fn outbox_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Char('r') => Some(AppMsg::RetryQueuedWrite),
+        KeyCode::Char('R') => Some(AppMsg::RetryAllQueuedWrites),
+        KeyCode::Char('d') => Some(AppMsg::DiscardQueuedWrite),
+        KeyCode::Esc => Some(AppMsg::HideOutbox),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        _ => None,
+    }
+}
@@ -0,0 +1,70 @@
+//! Key maps for the review list and the prompts it opens.
+
+use super::shared_keys;
+use crate::github::models::ReactionContent;
+use crate::tui::messages::AppMsg;
+
+pub(super) const fn review_list_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('x') => Some(AppMsg::StartCodexExecution),
+        KeyCode::Char('s') => Some(AppMsg::GeneratePrDiscussionSummary),
+        KeyCode::Char('a') => Some(AppMsg::StartReplyDraft),
+        KeyCode::Char('v') => Some(AppMsg::VerifySelectedComment),
+        KeyCode::Char('V') => Some(AppMsg::VerifyFilteredComments),
+        KeyCode::Char('R') => Some(AppMsg::ToggleThreadResolution),
+        KeyCode::Char('S') => Some(AppMsg::ShowReviewComposer),
+        KeyCode::Char('o') => Some(AppMsg::ShowPullRequestReviews),
+        KeyCode::Char('T') => Some(AppMsg::ShowTimeline),
+        KeyCode::Char('C') => Some(AppMsg::ShowCiChecks),
+        KeyCode::Char('w') => Some(AppMsg::ShowOutbox),
+        KeyCode::Char('+') => Some(AppMsg::ShowReactionPicker),
+        KeyCode::Char('A') => Some(AppMsg::ApplySuggestion),
+        KeyCode::Char('e') => Some(AppMsg::StartCommentEdit),
+        KeyCode::Char('D') => Some(AppMsg::RequestCommentDelete),
+        _ => shared_keys(key),
+    }
+}
+
+/// Maps keys for the reaction picker: `1`-`8` pick a reaction in
+/// [`ReactionContent::ALL`] order.
+pub(super) fn reaction_picker_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char(character @ '1'..='8') => {
+            let index = character as usize - '1' as usize;
+            ReactionContent::ALL
+                .get(index)
+                .copied()
+                .map(AppMsg::ToggleReaction)
+        }
+        KeyCode::Esc => Some(AppMsg::HideReactionPicker),
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        _ => None,
+    }
+}
+
+pub(super) const fn comment_delete_confirm_keys(
+    key: &bubbletea_rs::event::KeyMsg,
+) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('y') => Some(AppMsg::ConfirmCommentDelete),
+        KeyCode::Char('n') | KeyCode::Esc => Some(AppMsg::CancelCommentDelete),
+        _ => None,
+    }
+}
+
+pub(super) const fn resume_prompt_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('y') => Some(AppMsg::ResumeAccepted),
+        KeyCode::Char('n') | KeyCode::Esc => Some(AppMsg::ResumeDeclined),
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        _ => None,
+    }
+}
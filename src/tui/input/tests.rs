@@ -0,0 +1,368 @@
+//! Unit tests for the `input` module.
+
+use super::*;
+use bubbletea_rs::event::KeyMsg;
+use crossterm::event::{KeyCode, KeyModifiers};
+use rstest::rstest;
+
+use crate::ai::CommentRewriteMode;
+use crate::github::models::ReactionContent;
+
+fn key_msg(key: KeyCode) -> KeyMsg {
+    KeyMsg {
+        key,
+        modifiers: KeyModifiers::empty(),
+    }
+}
+
+#[rstest]
+#[case::time_travel_h_previous(
+    KeyCode::Char('h'),
+    Some(InputContext::TimeTravel),
+    Some(AppMsg::PreviousCommit)
+)]
+#[case::time_travel_l_next(
+    KeyCode::Char('l'),
+    Some(InputContext::TimeTravel),
+    Some(AppMsg::NextCommit)
+)]
+#[case::time_travel_esc_exit(
+    KeyCode::Esc,
+    Some(InputContext::TimeTravel),
+    Some(AppMsg::ExitTimeTravel)
+)]
+#[case::review_list_t_enter(
+    KeyCode::Char('t'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::EnterTimeTravel)
+)]
+#[case::review_list_x_start_codex(
+    KeyCode::Char('x'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::StartCodexExecution)
+)]
+#[case::review_list_a_start_reply_draft(
+    KeyCode::Char('a'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::StartReplyDraft)
+)]
+#[case::review_list_s_generate_summary(
+    KeyCode::Char('s'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::GeneratePrDiscussionSummary)
+)]
+#[case::review_list_v_verify_selected(
+    KeyCode::Char('v'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::VerifySelectedComment)
+)]
+#[case::review_list_shift_v_verify_filtered(
+    KeyCode::Char('V'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::VerifyFilteredComments)
+)]
+#[case::review_list_shift_r_toggle_resolution(
+    KeyCode::Char('R'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ToggleThreadResolution)
+)]
+#[case::time_travel_x_unmapped(KeyCode::Char('x'), Some(InputContext::TimeTravel), None)]
+#[case::diff_context_esc_hide(
+    KeyCode::Esc,
+    Some(InputContext::DiffContext),
+    Some(AppMsg::HideDiffContext)
+)]
+#[case::diff_context_j_line_down(
+    KeyCode::Char('j'),
+    Some(InputContext::DiffContext),
+    Some(AppMsg::DiffLineDown)
+)]
+#[case::diff_context_up_line_up(
+    KeyCode::Up,
+    Some(InputContext::DiffContext),
+    Some(AppMsg::DiffLineUp)
+)]
+#[case::diff_context_a_start_comment(
+    KeyCode::Char('a'),
+    Some(InputContext::DiffContext),
+    Some(AppMsg::StartDiffCommentDraft)
+)]
+#[case::diff_context_next_hunk(
+    KeyCode::Char(']'),
+    Some(InputContext::DiffContext),
+    Some(AppMsg::NextHunk)
+)]
+#[case::diff_comment_draft_q_inserts_char(
+    KeyCode::Char('q'),
+    Some(InputContext::DiffCommentDraft),
+    Some(AppMsg::DiffCommentInsertChar('q'))
+)]
+#[case::diff_comment_draft_enter_save(
+    KeyCode::Enter,
+    Some(InputContext::DiffCommentDraft),
+    Some(AppMsg::DiffCommentSave)
+)]
+#[case::diff_comment_draft_esc_cancel(
+    KeyCode::Esc,
+    Some(InputContext::DiffCommentDraft),
+    Some(AppMsg::DiffCommentCancel)
+)]
+#[case::review_list_j_down(
+    KeyCode::Char('j'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::CursorDown)
+)]
+#[case::resume_prompt_y_accepted(
+    KeyCode::Char('y'),
+    Some(InputContext::ResumePrompt),
+    Some(AppMsg::ResumeAccepted)
+)]
+#[case::resume_prompt_n_declined(
+    KeyCode::Char('n'),
+    Some(InputContext::ResumePrompt),
+    Some(AppMsg::ResumeDeclined)
+)]
+#[case::resume_prompt_esc_declined(
+    KeyCode::Esc,
+    Some(InputContext::ResumePrompt),
+    Some(AppMsg::ResumeDeclined)
+)]
+#[case::resume_prompt_j_unmapped(KeyCode::Char('j'), Some(InputContext::ResumePrompt), None)]
+#[case::reply_draft_insert_template(
+    KeyCode::Char('2'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftInsertTemplate { template_index: 1 })
+)]
+#[case::reply_draft_ai_expand(
+    KeyCode::Char('E'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftRequestAiRewrite { mode: CommentRewriteMode::Expand })
+)]
+#[case::reply_draft_ai_reword(
+    KeyCode::Char('W'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftRequestAiRewrite { mode: CommentRewriteMode::Reword })
+)]
+#[case::reply_draft_ai_apply(
+    KeyCode::Char('Y'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftAiApply)
+)]
+#[case::reply_draft_ai_discard(
+    KeyCode::Char('N'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftAiDiscard)
+)]
+#[case::reply_draft_insert_char(
+    KeyCode::Char('q'),
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftInsertChar('q'))
+)]
+#[case::reply_draft_backspace(
+    KeyCode::Backspace,
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftBackspace)
+)]
+#[case::reply_draft_enter_send(
+    KeyCode::Enter,
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftRequestSend)
+)]
+#[case::reply_draft_esc_cancel(
+    KeyCode::Esc,
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::ReplyDraftCancel)
+)]
+#[case::review_list_shift_s_show_composer(
+    KeyCode::Char('S'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowReviewComposer)
+)]
+#[case::review_list_o_show_pull_request_reviews(
+    KeyCode::Char('o'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowPullRequestReviews)
+)]
+#[case::pull_request_reviews_j_cursor_down(
+    KeyCode::Char('j'),
+    Some(InputContext::PullRequestReviews),
+    Some(AppMsg::CursorDown)
+)]
+#[case::pull_request_reviews_esc_hide(
+    KeyCode::Esc,
+    Some(InputContext::PullRequestReviews),
+    Some(AppMsg::HidePullRequestReviews)
+)]
+#[case::review_list_shift_t_show_timeline(
+    KeyCode::Char('T'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowTimeline)
+)]
+#[case::timeline_f_cycle_filter(
+    KeyCode::Char('f'),
+    Some(InputContext::Timeline),
+    Some(AppMsg::CycleFilter)
+)]
+#[case::timeline_esc_hide(KeyCode::Esc, Some(InputContext::Timeline), Some(AppMsg::HideTimeline))]
+#[case::review_list_shift_c_show_ci_checks(
+    KeyCode::Char('C'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowCiChecks)
+)]
+#[case::ci_checks_esc_hide(KeyCode::Esc, Some(InputContext::CiChecks), Some(AppMsg::HideCiChecks))]
+#[case::review_list_w_show_outbox(
+    KeyCode::Char('w'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowOutbox)
+)]
+#[case::outbox_r_retry_selected(
+    KeyCode::Char('r'),
+    Some(InputContext::Outbox),
+    Some(AppMsg::RetryQueuedWrite)
+)]
+#[case::outbox_shift_r_retry_all(
+    KeyCode::Char('R'),
+    Some(InputContext::Outbox),
+    Some(AppMsg::RetryAllQueuedWrites)
+)]
+#[case::outbox_d_discard(
+    KeyCode::Char('d'),
+    Some(InputContext::Outbox),
+    Some(AppMsg::DiscardQueuedWrite)
+)]
+#[case::outbox_esc_hide(KeyCode::Esc, Some(InputContext::Outbox), Some(AppMsg::HideOutbox))]
+#[case::review_list_shift_a_apply_suggestion(
+    KeyCode::Char('A'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ApplySuggestion)
+)]
+#[case::review_list_e_start_comment_edit(
+    KeyCode::Char('e'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::StartCommentEdit)
+)]
+#[case::review_list_shift_d_request_comment_delete(
+    KeyCode::Char('D'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::RequestCommentDelete)
+)]
+#[case::comment_delete_confirm_y(
+    KeyCode::Char('y'),
+    Some(InputContext::CommentDeleteConfirm),
+    Some(AppMsg::ConfirmCommentDelete)
+)]
+#[case::comment_delete_confirm_esc_cancel(
+    KeyCode::Esc,
+    Some(InputContext::CommentDeleteConfirm),
+    Some(AppMsg::CancelCommentDelete)
+)]
+#[case::comment_delete_confirm_q_ignored(
+    KeyCode::Char('q'),
+    Some(InputContext::CommentDeleteConfirm),
+    None
+)]
+#[case::review_list_plus_show_reaction_picker(
+    KeyCode::Char('+'),
+    Some(InputContext::ReviewList),
+    Some(AppMsg::ShowReactionPicker)
+)]
+#[case::reaction_picker_esc_hide(
+    KeyCode::Esc,
+    Some(InputContext::ReactionPicker),
+    Some(AppMsg::HideReactionPicker)
+)]
+#[case::reaction_picker_9_ignored(KeyCode::Char('9'), Some(InputContext::ReactionPicker), None)]
+#[case::reply_draft_tab_queue_for_review(
+    KeyCode::Tab,
+    Some(InputContext::ReplyDraft),
+    Some(AppMsg::QueueReplyDraftForReview)
+)]
+#[case::review_submission_tab_cycle_event(
+    KeyCode::Tab,
+    Some(InputContext::ReviewSubmission),
+    Some(AppMsg::ReviewComposerCycleEvent)
+)]
+#[case::review_submission_q_inserts_char(
+    KeyCode::Char('q'),
+    Some(InputContext::ReviewSubmission),
+    Some(AppMsg::ReviewComposerInsertChar('q'))
+)]
+#[case::review_submission_enter_request_submit(
+    KeyCode::Enter,
+    Some(InputContext::ReviewSubmission),
+    Some(AppMsg::ReviewComposerRequestSubmit)
+)]
+#[case::review_submission_confirm_y(
+    KeyCode::Char('y'),
+    Some(InputContext::ReviewSubmissionConfirm),
+    Some(AppMsg::ReviewComposerConfirmSubmit)
+)]
+#[case::review_submission_confirm_esc_cancel(
+    KeyCode::Esc,
+    Some(InputContext::ReviewSubmissionConfirm),
+    Some(AppMsg::ReviewComposerCancelConfirm)
+)]
+#[case::summary_enter_open(
+    KeyCode::Enter,
+    Some(InputContext::PrDiscussionSummary),
+    Some(AppMsg::OpenSelectedPrDiscussionSummaryLink)
+)]
+#[case::summary_esc_close(
+    KeyCode::Esc,
+    Some(InputContext::PrDiscussionSummary),
+    Some(AppMsg::HidePrDiscussionSummary)
+)]
+#[case::summary_j_down(
+    KeyCode::Char('j'),
+    Some(InputContext::PrDiscussionSummary),
+    Some(AppMsg::CursorDown)
+)]
+#[case::default_context_j_down(KeyCode::Char('j'), None, Some(AppMsg::CursorDown))]
+fn key_mapping(
+    #[case] key: KeyCode,
+    #[case] ctx: Option<InputContext>,
+    #[case] expected: Option<AppMsg>,
+) {
+    let result = ctx.map_or_else(
+        || map_key_to_message(&key_msg(key)),
+        |context| map_key_to_message_with_context(&key_msg(key), context),
+    );
+
+    // Compare enum variants using discriminant
+    match (result, expected) {
+        (Some(r), Some(e)) => {
+            assert_eq!(std::mem::discriminant(&r), std::mem::discriminant(&e));
+        }
+        (None, None) => {}
+        (r, e) => {
+            panic!("Some/None mismatch: result={r:?}, expected={e:?}");
+        }
+    }
+}
+
+#[rstest]
+#[case::thumbs_up('1', ReactionContent::ThumbsUp)]
+#[case::hooray('4', ReactionContent::Hooray)]
+#[case::eyes('8', ReactionContent::Eyes)]
+fn reaction_picker_digits_pick_reactions(#[case] digit: char, #[case] expected: ReactionContent) {
+    let result = map_key_to_message_with_context(
+        &key_msg(KeyCode::Char(digit)),
+        InputContext::ReactionPicker,
+    );
+
+    assert!(
+        matches!(result, Some(AppMsg::ToggleReaction(content)) if content == expected),
+        "unexpected: {result:?}"
+    );
+}
+
+#[test]
+fn reply_draft_template_index_maps_digit_to_zero_based_slot() {
+    let result =
+        map_key_to_message_with_context(&key_msg(KeyCode::Char('9')), InputContext::ReplyDraft);
+
+    assert!(matches!(
+        result,
+        Some(AppMsg::ReplyDraftInsertTemplate { template_index: 8 })
+    ));
+}
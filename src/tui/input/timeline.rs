@@ -0,0 +1,69 @@
+//! Key maps for the timeline and the other full-screen pull request views.
+
+use crate::tui::messages::AppMsg;
+
+pub(super) const fn timeline_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Char('f') => Some(AppMsg::CycleFilter),
+        KeyCode::Esc => Some(AppMsg::HideTimeline),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        _ => None,
+    }
+}
+
+pub(super) const fn pull_request_reviews_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Esc => Some(AppMsg::HidePullRequestReviews),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        _ => None,
+    }
+}
+
+pub(super) const fn ci_checks_keys(key: &bubbletea_rs::event::KeyMsg) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Esc => Some(AppMsg::HideCiChecks),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        _ => None,
+    }
+}
+
+pub(super) const fn pr_discussion_summary_keys(
+    key: &bubbletea_rs::event::KeyMsg,
+) -> Option<AppMsg> {
+    use crossterm::event::KeyCode;
+
+    match key.key {
+        KeyCode::Char('q') => Some(AppMsg::Quit),
+        KeyCode::Char('j') | KeyCode::Down => Some(AppMsg::CursorDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(AppMsg::CursorUp),
+        KeyCode::PageDown => Some(AppMsg::PageDown),
+        KeyCode::PageUp => Some(AppMsg::PageUp),
+        KeyCode::Home | KeyCode::Char('g') => Some(AppMsg::Home),
+        KeyCode::End | KeyCode::Char('G') => Some(AppMsg::End),
+        KeyCode::Enter => Some(AppMsg::OpenSelectedPrDiscussionSummaryLink),
+        KeyCode::Esc => Some(AppMsg::HidePrDiscussionSummary),
+        KeyCode::Char('?') => Some(AppMsg::ToggleHelp),
+        _ => None,
+    }
+}
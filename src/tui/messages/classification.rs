@@ -21,6 +21,24 @@ pub enum MessageCategory {
     ReplyDraft,
     /// Automated resolution verification actions.
     Verification,
+    /// GitHub review thread resolve and unresolve actions.
+    ThreadResolution,
+    /// Review comment reaction picker and toggle actions.
+    Reactions,
+    /// Suggested-change application actions.
+    Suggestions,
+    /// Editing and deleting the authenticated user's own comments.
+    CommentEdits,
+    /// Pending review composition and submission actions.
+    ReviewSubmission,
+    /// Top-level pull request review listing actions.
+    PullRequestReviews,
+    /// Conversation timeline actions.
+    Timeline,
+    /// CI check drill-down actions.
+    CiChecks,
+    /// Write outbox inspection and replay actions.
+    Outbox,
     /// PR discussion summary actions.
     PrDiscussionSummary,
     /// Data refresh and background sync actions.
@@ -48,9 +66,17 @@ impl AppMsg {
             | Self::Home
             | Self::End => MessageCategory::Navigation,
             Self::SetFilter(_) | Self::ClearFilter | Self::CycleFilter => MessageCategory::Filter,
-            Self::ShowDiffContext | Self::HideDiffContext | Self::NextHunk | Self::PreviousHunk => {
-                MessageCategory::DiffContext
-            }
+            Self::ShowDiffContext
+            | Self::HideDiffContext
+            | Self::NextHunk
+            | Self::PreviousHunk
+            | Self::DiffLineDown
+            | Self::DiffLineUp
+            | Self::StartDiffCommentDraft
+            | Self::DiffCommentInsertChar(_)
+            | Self::DiffCommentBackspace
+            | Self::DiffCommentSave
+            | Self::DiffCommentCancel => MessageCategory::DiffContext,
             Self::EnterTimeTravel
             | Self::ExitTimeTravel
             | Self::TimeTravelLoaded { .. }
@@ -65,11 +91,40 @@ impl AppMsg {
             | Self::ResumePromptShown(_)
             | Self::ResumeAccepted
             | Self::ResumeDeclined => MessageCategory::Codex,
+            Self::RefreshRequested
+            | Self::RefreshComplete(_)
+            | Self::RefreshFailed(_)
+            | Self::SyncTick
+            | Self::BackoffPollTick
+            | Self::ReviewStreamPollTick
+            | Self::SyncComplete { .. }
+            | Self::SyncDeltaReceived { .. }
+            | Self::ConversationRefreshed { .. }
+            | Self::ConversationRefreshFailed(_)
+            | Self::CiStatusRefreshed(_) => MessageCategory::Data,
+            Self::EscapePressed
+            | Self::Initialized
+            | Self::Quit
+            | Self::ToggleHelp
+            | Self::WindowResized { .. } => MessageCategory::Lifecycle,
+            _ => self.review_action_category(),
+        }
+    }
+
+    /// Returns the dispatch category for review interaction messages.
+    ///
+    /// Split from [`Self::category`] to keep each match readable; messages
+    /// classified there never reach this function.
+    const fn review_action_category(&self) -> MessageCategory {
+        match self {
             Self::StartReplyDraft
             | Self::ReplyDraftInsertTemplate { .. }
             | Self::ReplyDraftInsertChar(_)
             | Self::ReplyDraftBackspace
             | Self::ReplyDraftRequestSend
+            | Self::ReplyDraftSent { .. }
+            | Self::ReplyDraftSendFailed { .. }
+            | Self::ReplyDraftQueued { .. }
             | Self::ReplyDraftCancel
             | Self::ReplyDraftRequestAiRewrite { .. }
             | Self::ReplyDraftAiRewriteReady { .. }
@@ -79,21 +134,55 @@ impl AppMsg {
             | Self::VerifyFilteredComments
             | Self::VerificationReady { .. }
             | Self::VerificationFailed { .. } => MessageCategory::Verification,
+            Self::ToggleThreadResolution
+            | Self::ThreadResolutionChanged { .. }
+            | Self::ThreadResolutionFailed { .. }
+            | Self::ThreadResolutionQueued { .. } => MessageCategory::ThreadResolution,
+            Self::ShowReactionPicker
+            | Self::HideReactionPicker
+            | Self::ToggleReaction(_)
+            | Self::ReactionToggled { .. }
+            | Self::ReactionFailed { .. }
+            | Self::ReactionQueued { .. } => MessageCategory::Reactions,
+            Self::ApplySuggestion => MessageCategory::Suggestions,
+            Self::StartCommentEdit
+            | Self::CommentEdited { .. }
+            | Self::CommentEditFailed { .. }
+            | Self::RequestCommentDelete
+            | Self::ConfirmCommentDelete
+            | Self::CancelCommentDelete
+            | Self::CommentDeleted { .. }
+            | Self::CommentDeleteFailed { .. } => MessageCategory::CommentEdits,
+            Self::ShowReviewComposer
+            | Self::HideReviewComposer
+            | Self::QueueReplyDraftForReview
+            | Self::ReviewComposerInsertChar(_)
+            | Self::ReviewComposerBackspace
+            | Self::ReviewComposerCycleEvent
+            | Self::ReviewComposerDropLastComment
+            | Self::ReviewComposerRequestSubmit
+            | Self::ReviewComposerConfirmSubmit
+            | Self::ReviewComposerCancelConfirm
+            | Self::ReviewSubmitted { .. }
+            | Self::ReviewSubmissionFailed { .. } => MessageCategory::ReviewSubmission,
+            Self::ShowPullRequestReviews | Self::HidePullRequestReviews => {
+                MessageCategory::PullRequestReviews
+            }
+            Self::ShowTimeline | Self::HideTimeline => MessageCategory::Timeline,
+            Self::ShowCiChecks | Self::HideCiChecks => MessageCategory::CiChecks,
+            Self::ShowOutbox
+            | Self::HideOutbox
+            | Self::RetryQueuedWrite
+            | Self::RetryAllQueuedWrites
+            | Self::DiscardQueuedWrite
+            | Self::OutboxReplayed { .. }
+            | Self::OutboxReplayFailed { .. } => MessageCategory::Outbox,
             Self::GeneratePrDiscussionSummary
             | Self::PrDiscussionSummaryReady { .. }
             | Self::PrDiscussionSummaryFailed { .. }
             | Self::OpenSelectedPrDiscussionSummaryLink
             | Self::HidePrDiscussionSummary => MessageCategory::PrDiscussionSummary,
-            Self::RefreshRequested
-            | Self::RefreshComplete(_)
-            | Self::RefreshFailed(_)
-            | Self::SyncTick
-            | Self::SyncComplete { .. } => MessageCategory::Data,
-            Self::EscapePressed
-            | Self::Initialized
-            | Self::Quit
-            | Self::ToggleHelp
-            | Self::WindowResized { .. } => MessageCategory::Lifecycle,
+            _ => MessageCategory::Lifecycle,
         }
     }
 
@@ -129,7 +218,13 @@ impl AppMsg {
                 | Self::RefreshComplete(_)
                 | Self::RefreshFailed(_)
                 | Self::SyncTick
+                | Self::BackoffPollTick
+                | Self::ReviewStreamPollTick
                 | Self::SyncComplete { .. }
+                | Self::SyncDeltaReceived { .. }
+                | Self::ConversationRefreshed { .. }
+                | Self::ConversationRefreshFailed(_)
+                | Self::CiStatusRefreshed(_)
         )
     }
 
@@ -145,6 +240,111 @@ impl AppMsg {
         )
     }
 
+    /// Returns `true` if this is a review thread resolution message.
+    #[must_use]
+    pub const fn is_thread_resolution(&self) -> bool {
+        matches!(
+            self,
+            Self::ToggleThreadResolution
+                | Self::ThreadResolutionChanged { .. }
+                | Self::ThreadResolutionFailed { .. }
+                | Self::ThreadResolutionQueued { .. }
+        )
+    }
+
+    /// Returns `true` if this is a review comment reaction message.
+    #[must_use]
+    pub const fn is_reaction(&self) -> bool {
+        matches!(
+            self,
+            Self::ShowReactionPicker
+                | Self::HideReactionPicker
+                | Self::ToggleReaction(_)
+                | Self::ReactionToggled { .. }
+                | Self::ReactionFailed { .. }
+                | Self::ReactionQueued { .. }
+        )
+    }
+
+    /// Returns `true` if this is a suggested-change message.
+    #[must_use]
+    pub const fn is_suggestion(&self) -> bool {
+        matches!(self, Self::ApplySuggestion)
+    }
+
+    /// Returns `true` if this is a comment edit or delete message.
+    #[must_use]
+    pub const fn is_comment_edit(&self) -> bool {
+        matches!(
+            self,
+            Self::StartCommentEdit
+                | Self::CommentEdited { .. }
+                | Self::CommentEditFailed { .. }
+                | Self::RequestCommentDelete
+                | Self::ConfirmCommentDelete
+                | Self::CancelCommentDelete
+                | Self::CommentDeleted { .. }
+                | Self::CommentDeleteFailed { .. }
+        )
+    }
+
+    /// Returns `true` if this is a pending review composition or submission
+    /// message.
+    #[must_use]
+    pub const fn is_review_submission(&self) -> bool {
+        matches!(
+            self,
+            Self::ShowReviewComposer
+                | Self::HideReviewComposer
+                | Self::QueueReplyDraftForReview
+                | Self::ReviewComposerInsertChar(_)
+                | Self::ReviewComposerBackspace
+                | Self::ReviewComposerCycleEvent
+                | Self::ReviewComposerDropLastComment
+                | Self::ReviewComposerRequestSubmit
+                | Self::ReviewComposerConfirmSubmit
+                | Self::ReviewComposerCancelConfirm
+                | Self::ReviewSubmitted { .. }
+                | Self::ReviewSubmissionFailed { .. }
+        )
+    }
+
+    /// Returns `true` if this is a pull request reviews view message.
+    #[must_use]
+    pub const fn is_pull_request_reviews(&self) -> bool {
+        matches!(
+            self,
+            Self::ShowPullRequestReviews | Self::HidePullRequestReviews
+        )
+    }
+
+    /// Returns `true` if this is a conversation timeline message.
+    #[must_use]
+    pub const fn is_timeline(&self) -> bool {
+        matches!(self, Self::ShowTimeline | Self::HideTimeline)
+    }
+
+    /// Returns `true` if this is a CI checks view message.
+    #[must_use]
+    pub const fn is_ci_checks(&self) -> bool {
+        matches!(self, Self::ShowCiChecks | Self::HideCiChecks)
+    }
+
+    /// Returns `true` if this is a write outbox message.
+    #[must_use]
+    pub const fn is_outbox(&self) -> bool {
+        matches!(
+            self,
+            Self::ShowOutbox
+                | Self::HideOutbox
+                | Self::RetryQueuedWrite
+                | Self::RetryAllQueuedWrites
+                | Self::DiscardQueuedWrite
+                | Self::OutboxReplayed { .. }
+                | Self::OutboxReplayFailed { .. }
+        )
+    }
+
     /// Returns `true` if this is a PR discussion summary message.
     #[must_use]
     pub const fn is_pr_discussion_summary(&self) -> bool {
@@ -183,6 +383,9 @@ impl AppMsg {
                 | Self::ReplyDraftInsertChar(_)
                 | Self::ReplyDraftBackspace
                 | Self::ReplyDraftRequestSend
+                | Self::ReplyDraftSent { .. }
+                | Self::ReplyDraftSendFailed { .. }
+                | Self::ReplyDraftQueued { .. }
                 | Self::ReplyDraftCancel
                 | Self::ReplyDraftRequestAiRewrite { .. }
                 | Self::ReplyDraftAiRewriteReady { .. }
@@ -191,12 +394,23 @@ impl AppMsg {
         )
     }
 
-    /// Returns `true` if this is a diff context navigation message.
+    /// Returns `true` if this is a diff context navigation or new-comment
+    /// message.
     #[must_use]
     pub const fn is_diff_context(&self) -> bool {
         matches!(
             self,
-            Self::ShowDiffContext | Self::HideDiffContext | Self::NextHunk | Self::PreviousHunk
+            Self::ShowDiffContext
+                | Self::HideDiffContext
+                | Self::NextHunk
+                | Self::PreviousHunk
+                | Self::DiffLineDown
+                | Self::DiffLineUp
+                | Self::StartDiffCommentDraft
+                | Self::DiffCommentInsertChar(_)
+                | Self::DiffCommentBackspace
+                | Self::DiffCommentSave
+                | Self::DiffCommentCancel
         )
     }
 
@@ -10,7 +10,14 @@ use crate::ai::{
     CodexExecutionOutcome, CodexProgressEvent, CommentRewriteMode, CommentRewriteOutcome,
     PrDiscussionSummary, SessionState,
 };
-use crate::github::models::ReviewComment;
+use crate::github::CiStatus;
+use crate::github::models::{
+    PullRequestComment, PullRequestReview, ReactionContent, ReactionToggle, ReviewComment,
+    ReviewThreadState,
+};
+use crate::github::outbox::OutboxReplayReport;
+use crate::github::review_submission::SubmittedReview;
+use crate::github::review_sync::ReviewSyncDelta;
 use crate::time_travel::TimeTravelState;
 use crate::verification::CommentVerificationResult;
 
@@ -53,6 +60,20 @@ pub enum AppMsg {
     NextHunk,
     /// Move to the previous diff hunk.
     PreviousHunk,
+    /// Move the line cursor down within the current hunk.
+    DiffLineDown,
+    /// Move the line cursor up within the current hunk.
+    DiffLineUp,
+    /// Start a new inline comment on the selected diff line.
+    StartDiffCommentDraft,
+    /// Insert a character into the new inline comment.
+    DiffCommentInsertChar(char),
+    /// Delete the last character of the new inline comment.
+    DiffCommentBackspace,
+    /// Save the new inline comment to the pending review.
+    DiffCommentSave,
+    /// Discard the new inline comment.
+    DiffCommentCancel,
     /// Escape key pressed (context-aware handling).
     EscapePressed,
 
@@ -119,8 +140,30 @@ pub enum AppMsg {
     ReplyDraftInsertChar(char),
     /// Remove the final character from the active reply draft.
     ReplyDraftBackspace,
-    /// Validate the active reply draft and mark it ready to send.
+    /// Validate the active reply draft and post it to GitHub.
     ReplyDraftRequestSend,
+    /// A reply draft was posted to GitHub successfully.
+    ReplyDraftSent {
+        /// Comment the draft replied to.
+        comment_id: u64,
+        /// Newly created reply returned by GitHub.
+        reply: Box<ReviewComment>,
+    },
+    /// Posting a reply draft to GitHub failed; the draft is kept.
+    ReplyDraftSendFailed {
+        /// Comment the draft replied to.
+        comment_id: u64,
+        /// User-readable failure message.
+        message: String,
+    },
+    /// Posting a reply draft was not possible, so the reply was queued in
+    /// the write outbox and the draft cleared.
+    ReplyDraftQueued {
+        /// Comment the draft replied to.
+        comment_id: u64,
+        /// Intent ID of the queued reply.
+        intent_id: String,
+    },
     /// Cancel and discard the active reply draft.
     ReplyDraftCancel,
     /// Request an AI rewrite for the active reply draft.
@@ -164,6 +207,173 @@ pub enum AppMsg {
         message: String,
     },
 
+    // Thread resolution
+    /// Resolve or unresolve the selected comment's review thread on GitHub.
+    ToggleThreadResolution,
+    /// GitHub accepted a thread resolution change.
+    ThreadResolutionChanged {
+        /// Thread state reported by GitHub after the change.
+        state: ReviewThreadState,
+    },
+    /// Changing a thread's resolution state failed.
+    ThreadResolutionFailed {
+        /// GraphQL identifier of the thread that was being updated.
+        thread_id: String,
+        /// User-readable failure message.
+        message: String,
+    },
+    /// Changing a thread's resolution state was not possible, so the change
+    /// was queued in the write outbox.
+    ThreadResolutionQueued {
+        /// GraphQL identifier of the thread that was being updated.
+        thread_id: String,
+        /// Intent ID of the queued change.
+        intent_id: String,
+    },
+
+    // Reactions
+    /// Open the reaction picker for the selected comment.
+    ShowReactionPicker,
+    /// Close the reaction picker without reacting.
+    HideReactionPicker,
+    /// Add or remove a reaction on the selected comment.
+    ToggleReaction(ReactionContent),
+    /// GitHub accepted a reaction change.
+    ReactionToggled {
+        /// Comment the reaction was changed on.
+        comment_id: u64,
+        /// Reaction that was changed.
+        content: ReactionContent,
+        /// Whether the reaction was added or removed.
+        toggle: ReactionToggle,
+    },
+    /// Changing a reaction failed.
+    ReactionFailed {
+        /// Comment the reaction was being changed on.
+        comment_id: u64,
+        /// User-readable failure message.
+        message: String,
+    },
+    /// Changing a reaction was not possible, so the change was queued in
+    /// the write outbox.
+    ReactionQueued {
+        /// Comment the reaction was being changed on.
+        comment_id: u64,
+        /// Intent ID of the queued change.
+        intent_id: String,
+    },
+
+    // Suggested changes
+    /// Apply the selected comment's suggested change to the working tree.
+    ApplySuggestion,
+
+    // Editing and deleting own comments
+    /// Open the reply draft editor pre-filled with the selected comment's
+    /// body.
+    StartCommentEdit,
+    /// GitHub accepted an edited comment body.
+    CommentEdited {
+        /// Updated comment returned by GitHub.
+        comment: Box<ReviewComment>,
+    },
+    /// Editing a comment failed; the draft is kept.
+    CommentEditFailed {
+        /// Comment that was being edited.
+        comment_id: u64,
+        /// User-readable failure message.
+        message: String,
+    },
+    /// Ask for confirmation before deleting the selected comment.
+    RequestCommentDelete,
+    /// Confirm and delete the comment awaiting confirmation.
+    ConfirmCommentDelete,
+    /// Keep the comment awaiting deletion confirmation.
+    CancelCommentDelete,
+    /// GitHub deleted a comment.
+    CommentDeleted {
+        /// Comment that was deleted.
+        comment_id: u64,
+    },
+    /// Deleting a comment failed.
+    CommentDeleteFailed {
+        /// Comment that was being deleted.
+        comment_id: u64,
+        /// User-readable failure message.
+        message: String,
+    },
+
+    // Review submission
+    /// Open the pending review composer.
+    ShowReviewComposer,
+    /// Close the pending review composer, keeping its contents.
+    HideReviewComposer,
+    /// Queue the active reply draft as an inline comment on the pending review.
+    QueueReplyDraftForReview,
+    /// Insert one typed character into the review summary body.
+    ReviewComposerInsertChar(char),
+    /// Remove the final character from the review summary body.
+    ReviewComposerBackspace,
+    /// Cycle the review event (comment, approve, request changes).
+    ReviewComposerCycleEvent,
+    /// Drop the most recently queued inline comment.
+    ReviewComposerDropLastComment,
+    /// Validate the pending review and ask for confirmation.
+    ReviewComposerRequestSubmit,
+    /// Confirm and submit the pending review to GitHub.
+    ReviewComposerConfirmSubmit,
+    /// Return from the confirmation step without submitting.
+    ReviewComposerCancelConfirm,
+    /// GitHub accepted the submitted review.
+    ReviewSubmitted {
+        /// Review created by GitHub.
+        review: SubmittedReview,
+    },
+    /// Submitting the pending review failed; the composer is kept.
+    ReviewSubmissionFailed {
+        /// User-readable failure message.
+        message: String,
+    },
+
+    // Pull request reviews
+    /// Open the pull request reviews view.
+    ShowPullRequestReviews,
+    /// Close the pull request reviews view.
+    HidePullRequestReviews,
+
+    // Conversation timeline
+    /// Open the conversation timeline view.
+    ShowTimeline,
+    /// Close the conversation timeline view.
+    HideTimeline,
+
+    // CI checks
+    /// Open the CI checks view for the pull request head.
+    ShowCiChecks,
+    /// Close the CI checks view.
+    HideCiChecks,
+
+    // Write outbox
+    /// Open the queued writes view.
+    ShowOutbox,
+    /// Close the queued writes view.
+    HideOutbox,
+    /// Replay the selected queued write.
+    RetryQueuedWrite,
+    /// Replay every queued write for the pull request.
+    RetryAllQueuedWrites,
+    /// Remove the selected queued write without sending it.
+    DiscardQueuedWrite,
+    /// Replaying queued writes finished.
+    OutboxReplayed {
+        /// Outcome of each replayed write.
+        report: OutboxReplayReport,
+    },
+    /// Replaying queued writes could not start.
+    OutboxReplayFailed {
+        /// User-readable failure message.
+        message: String,
+    },
+
     // PR discussion summary
     /// Request generation of a PR-level discussion summary.
     GeneratePrDiscussionSummary,
@@ -197,6 +407,10 @@ pub enum AppMsg {
     // Background sync
     /// Timer tick for background sync.
     SyncTick,
+    /// Poll for a rate-limit backoff while a sync is in flight.
+    BackoffPollTick,
+    /// Poll for review comment pages still loading after startup.
+    ReviewStreamPollTick,
     /// Incremental sync completed successfully with new data and timing.
     SyncComplete {
         /// Fresh reviews from the API.
@@ -204,6 +418,24 @@ pub enum AppMsg {
         /// Duration of the sync operation in milliseconds.
         latency_ms: u64,
     },
+    /// Incremental sync completed with the changes since the last sync.
+    SyncDeltaReceived {
+        /// Comments added, updated, and removed since the last sync.
+        delta: ReviewSyncDelta,
+        /// Duration of the sync operation in milliseconds.
+        latency_ms: u64,
+    },
+    /// Top-level reviews and conversation comments reloaded during a sync.
+    ConversationRefreshed {
+        /// Fresh top-level reviews from the API.
+        reviews: Vec<PullRequestReview>,
+        /// Fresh conversation comments from the API.
+        comments: Vec<PullRequestComment>,
+    },
+    /// Reloading reviews and conversation comments failed.
+    ConversationRefreshFailed(String),
+    /// CI results for the pull request head reloaded during a sync.
+    CiStatusRefreshed(CiStatus),
 
     // Application lifecycle
     /// Synthetic startup event emitted immediately after launch.
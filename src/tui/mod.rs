@@ -1,24 +1,34 @@
 //! Terminal User Interface for review listing and filtering.
 //!
 //! Provides an interactive TUI for navigating and filtering pull request
-//! review comments using the bubbletea-rs Model-View-Update (MVU) pattern.
+//! review comments using the bubbletea-rs Model-View-Update (MVU) pattern,
+//! and a picker for choosing a pull request from a listing.
 
 pub mod app;
 pub mod components;
 pub mod input;
 pub mod messages;
+pub mod picker;
 mod reply_draft_config;
+mod review_stream;
 pub mod state;
 mod storage;
 pub mod sync;
 
 pub use app::ReviewApp;
+pub use picker::{PullRequestPages, PullRequestPicker};
 pub use reply_draft_config::{ReplyDraftConfig, ReplyDraftMaxLength, set_reply_draft_config};
+pub use review_stream::{ReviewCommentStream, ReviewStreamUpdate};
 pub use storage::{
-    TimeTravelContext, set_comment_rewrite_service, set_commit_history_limit, set_git_ops_context,
-    set_initial_reviews, set_initial_terminal_size, set_pr_discussion_summary_service,
-    set_refresh_context, set_review_comment_verification_cache, set_telemetry_sink,
-    set_time_travel_context,
+    TimeTravelContext, set_authenticated_login, set_comment_page_cache,
+    set_comment_rewrite_service, set_commit_history_limit, set_git_ops_context,
+    set_initial_ci_status, set_initial_error, set_initial_issue_comments,
+    set_initial_pull_request_metadata, set_initial_pull_request_reviews, set_initial_reviews,
+    set_initial_terminal_size, set_offline_context, set_pending_review_comment_store,
+    set_pr_discussion_summary_service, set_pull_request_picker, set_refresh_context,
+    set_review_comment_archive, set_review_comment_stream, set_review_comment_verification_cache,
+    set_suggestion_applier, set_sync_checkpoint_store, set_telemetry_sink, set_time_travel_context,
+    set_write_outbox,
 };
 
 #[cfg(feature = "test-support")]
@@ -26,10 +36,16 @@ pub use storage::get_refresh_context_for_tests;
 
 pub(crate) use reply_draft_config::get_reply_draft_config;
 pub(crate) use storage::{
-    fetch_reviews, get_comment_rewrite_service, get_commit_history_limit, get_git_ops_context,
-    get_initial_reviews, get_initial_terminal_size, get_pr_discussion_summary_service,
-    get_refresh_locator, get_refresh_pr_title, get_review_comment_verification_cache,
-    get_time_travel_context, record_sync_telemetry,
+    delete_review_comment, edit_review_comment, fetch_ci_status, fetch_pull_request_conversation,
+    get_authenticated_login, get_comment_rewrite_service, get_commit_history_limit,
+    get_git_ops_context, get_initial_ci_status, get_initial_error, get_initial_issue_comments,
+    get_initial_pull_request_metadata, get_initial_pull_request_reviews, get_initial_reviews,
+    get_initial_terminal_size, get_pending_review_comment_store, get_pr_discussion_summary_service,
+    get_pull_request_picker, get_refresh_locator, get_refresh_pr_title,
+    get_review_comment_verification_cache, get_suggestion_applier, get_time_travel_context,
+    get_write_outbox, is_offline, post_review_reply, record_sync_telemetry, replay_queued_writes,
+    set_review_thread_resolution, submit_pull_request_review, sync_reviews,
+    take_review_comment_stream, toggle_review_comment_reaction,
 };
 
 #[cfg(test)]
@@ -0,0 +1,225 @@
+//! Pull request picker shown before the review TUI.
+//!
+//! Lists pull requests, such as search results or the review queue, and lets
+//! the user choose one with the keyboard. A listing with a
+//! [`PullRequestPages`] source loads further pages on demand. The CLI reads
+//! the choice from the model returned by the finished program and opens the
+//! review TUI for it.
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bubbletea_rs::event::{KeyMsg, WindowSizeMsg};
+use bubbletea_rs::{Cmd, Model};
+use crossterm::event::KeyCode;
+
+use crate::github::error::IntakeError;
+use crate::github::gateway::PaginatedPullRequests;
+use crate::github::models::PullRequestSummary;
+use crate::github::pagination::PageInfo;
+use crate::tui::components::{PullRequestPickerComponent, PullRequestPickerViewContext};
+
+/// Fetches further pages of the listing shown in the picker.
+#[async_trait]
+pub trait PullRequestPages: Send + Sync + fmt::Debug {
+    /// Fetches page `page` (1-based) of the listing.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the underlying request, such as an exhausted
+    /// search rate limit.
+    async fn fetch_page(&self, page: u32) -> Result<PaginatedPullRequests, IntakeError>;
+}
+
+/// Result of loading the next page of the listing.
+struct PageLoaded(Result<PaginatedPullRequests, IntakeError>);
+
+/// Model for choosing a pull request from a listing.
+#[derive(Debug, Clone)]
+pub struct PullRequestPicker {
+    heading: String,
+    items: Vec<PullRequestSummary>,
+    notes: Vec<String>,
+    page_info: PageInfo,
+    pages: Option<Arc<dyn PullRequestPages>>,
+    loading: bool,
+    load_error: Option<String>,
+    cursor: usize,
+    chosen: bool,
+    width: u16,
+    height: u16,
+}
+
+impl PullRequestPicker {
+    /// Creates a picker over `listing`, titled with `heading`.
+    #[must_use]
+    pub fn new(heading: impl Into<String>, listing: PaginatedPullRequests) -> Self {
+        let (width, height) = crate::tui::get_initial_terminal_size();
+        Self {
+            heading: heading.into(),
+            items: listing.items,
+            notes: Vec::new(),
+            page_info: listing.page_info,
+            pages: None,
+            loading: false,
+            load_error: None,
+            cursor: 0,
+            chosen: false,
+            width,
+            height,
+        }
+    }
+
+    /// Adds a note after each pull request, in listing order.
+    #[must_use]
+    pub fn with_notes(mut self, notes: Vec<String>) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// Loads further pages from `pages` when the user asks for more.
+    #[must_use]
+    pub fn with_more_pages(mut self, pages: Arc<dyn PullRequestPages>) -> Self {
+        self.pages = Some(pages);
+        self
+    }
+
+    /// Returns the pull request chosen with Enter, if any.
+    #[must_use]
+    pub fn selected(&self) -> Option<&PullRequestSummary> {
+        self.items.get(self.cursor).filter(|_| self.chosen)
+    }
+
+    /// Returns the pull request under the cursor.
+    #[must_use]
+    pub fn highlighted(&self) -> Option<&PullRequestSummary> {
+        self.items.get(self.cursor)
+    }
+
+    /// Applies a key press and returns the command to run.
+    pub fn handle_key(&mut self, key: &KeyMsg) -> Option<Cmd> {
+        match key.key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                if self.cursor.saturating_add(1) >= self.items.len() {
+                    return self.load_next_page();
+                }
+                self.move_cursor_to(self.cursor.saturating_add(1));
+            }
+            KeyCode::Char('m') => return self.load_next_page(),
+            KeyCode::Char('k') | KeyCode::Up => self.move_cursor_to(self.cursor.saturating_sub(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.move_cursor_to(0),
+            KeyCode::End | KeyCode::Char('G') => self.move_cursor_to(usize::MAX),
+            KeyCode::Enter if !self.items.is_empty() => {
+                self.chosen = true;
+                return Some(bubbletea_rs::quit());
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return Some(bubbletea_rs::quit()),
+            _ => {}
+        }
+        None
+    }
+
+    fn move_cursor_to(&mut self, index: usize) {
+        self.cursor = index.min(self.items.len().saturating_sub(1));
+    }
+
+    /// Starts loading the page after the last one loaded, unless a load is
+    /// already running or there are no more pages.
+    fn load_next_page(&mut self) -> Option<Cmd> {
+        if self.loading || !self.page_info.has_next() {
+            return None;
+        }
+        let pages = Arc::clone(self.pages.as_ref()?);
+        let next_page = self.page_info.current_page().saturating_add(1);
+        self.loading = true;
+        self.load_error = None;
+        Some(Box::pin(async move {
+            let loaded = pages.fetch_page(next_page).await;
+            Some(Box::new(PageLoaded(loaded)) as Box<dyn Any + Send>)
+        }))
+    }
+
+    /// Appends a loaded page, or records why it could not be loaded.
+    fn handle_page_loaded(&mut self, loaded: PageLoaded) {
+        self.loading = false;
+        match loaded.0 {
+            Ok(listing) => {
+                self.items.extend(listing.items);
+                self.page_info = listing.page_info;
+            }
+            Err(error) => self.load_error = Some(error.to_string()),
+        }
+    }
+
+    /// Describes how much of the listing is shown and whether more can be
+    /// loaded.
+    fn status_line(&self) -> String {
+        let shown = format!(
+            "{} shown, page {} of {}",
+            self.items.len(),
+            self.page_info.current_page(),
+            self.page_info.total_pages().unwrap_or(1)
+        );
+        if self.loading {
+            return format!("{shown}; loading more...");
+        }
+        if let Some(error) = &self.load_error {
+            return format!("{shown}; loading more failed: {error}");
+        }
+        if !self.page_info.has_next() {
+            return shown;
+        }
+        if self.pages.is_some() {
+            format!("{shown}; press m to load more")
+        } else {
+            format!("{shown}; refine the query to see more")
+        }
+    }
+}
+
+impl Model for PullRequestPicker {
+    fn init() -> (Self, Option<Cmd>) {
+        let picker = crate::tui::get_pull_request_picker().unwrap_or_else(|| {
+            Self::new(
+                "Pull requests",
+                PaginatedPullRequests {
+                    items: Vec::new(),
+                    page_info: PageInfo::default(),
+                    rate_limit: None,
+                },
+            )
+        });
+        (picker, None)
+    }
+
+    fn update(&mut self, msg: Box<dyn Any + Send>) -> Option<Cmd> {
+        if let Some(key) = msg.downcast_ref::<KeyMsg>() {
+            return self.handle_key(key);
+        }
+        if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+            self.width = size.width;
+            self.height = size.height;
+        }
+        if let Ok(loaded) = msg.downcast::<PageLoaded>() {
+            self.handle_page_loaded(*loaded);
+        }
+        None
+    }
+
+    fn view(&self) -> String {
+        PullRequestPickerComponent::view(&PullRequestPickerViewContext {
+            heading: &self.heading,
+            status: &self.status_line(),
+            items: &self.items,
+            notes: &self.notes,
+            cursor: self.cursor,
+            max_width: usize::from(self.width).saturating_sub(1).max(1),
+            max_height: usize::from(self.height),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,209 @@
+//! Unit tests for the `picker` module.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bubbletea_rs::Model;
+use bubbletea_rs::event::KeyMsg;
+use crossterm::event::{KeyCode, KeyModifiers};
+use rstest::rstest;
+
+use super::{PageLoaded, PullRequestPages, PullRequestPicker};
+use crate::github::error::IntakeError;
+use crate::github::gateway::PaginatedPullRequests;
+use crate::github::models::PullRequestSummary;
+use crate::github::pagination::PageInfo;
+
+fn summary(repository: &str, number: u64, title: &str) -> PullRequestSummary {
+    PullRequestSummary {
+        number,
+        repository: Some(repository.to_owned()),
+        title: Some(title.to_owned()),
+        state: Some("open".to_owned()),
+        author: Some("octocat".to_owned()),
+        updated_at: Some("2026-04-17T09:30:00Z".to_owned()),
+        ..PullRequestSummary::default()
+    }
+}
+
+fn picker() -> PullRequestPicker {
+    let mut picker = PullRequestPicker::new(
+        "Pull requests matching `is:pr review-requested:@me`",
+        PaginatedPullRequests {
+            items: vec![
+                summary("octo/app", 42, "Add search"),
+                summary("octo/lib", 7, "Fix flaky test"),
+            ],
+            page_info: PageInfo::builder(1, 50)
+                .total_pages(Some(2))
+                .has_next(true)
+                .build(),
+            rate_limit: None,
+        },
+    );
+    picker.width = 80;
+    picker.height = 12;
+    picker
+}
+
+/// Page source whose pages are delivered by the tests themselves.
+#[derive(Debug)]
+struct UnusedPages;
+
+#[async_trait]
+impl PullRequestPages for UnusedPages {
+    async fn fetch_page(&self, _page: u32) -> Result<PaginatedPullRequests, IntakeError> {
+        Err(IntakeError::Api {
+            message: "pages are delivered by the test".to_owned(),
+        })
+    }
+}
+
+fn second_page() -> PaginatedPullRequests {
+    PaginatedPullRequests {
+        items: vec![summary("octo/cli", 3, "Add flags")],
+        page_info: PageInfo::builder(2, 50)
+            .total_pages(Some(2))
+            .has_prev(true)
+            .build(),
+        rate_limit: None,
+    }
+}
+
+fn press(picker: &mut PullRequestPicker, key: KeyCode) -> bool {
+    picker
+        .handle_key(&KeyMsg {
+            key,
+            modifiers: KeyModifiers::NONE,
+        })
+        .is_some()
+}
+
+#[rstest]
+fn view_lists_pull_requests_with_the_cursor_marker() {
+    let mut picker = picker();
+    press(&mut picker, KeyCode::Char('j'));
+
+    insta::assert_snapshot!(bubbletea_rs::Model::view(&picker));
+}
+
+#[rstest]
+fn enter_selects_the_highlighted_pull_request_and_quits() {
+    let mut picker = picker();
+    press(&mut picker, KeyCode::Down);
+    press(&mut picker, KeyCode::Down);
+
+    assert!(press(&mut picker, KeyCode::Enter));
+    assert_eq!(
+        picker.selected().map(PullRequestSummary::reference),
+        Some("octo/lib#7".to_owned())
+    );
+}
+
+#[rstest]
+#[case::quit(KeyCode::Char('q'))]
+#[case::escape(KeyCode::Esc)]
+fn quitting_leaves_nothing_selected(#[case] key: KeyCode) {
+    let mut picker = picker();
+
+    assert!(press(&mut picker, key));
+    assert_eq!(picker.selected(), None);
+    assert!(picker.highlighted().is_some());
+}
+
+#[rstest]
+fn notes_follow_their_pull_requests() {
+    let picker = picker().with_notes(vec!["unread".to_owned()]);
+
+    let view = bubbletea_rs::Model::view(&picker);
+
+    assert!(
+        view.contains("Add search (@octocat, updated 2026-04-17) - unread"),
+        "missing note: {view}"
+    );
+    assert!(
+        view.contains("Fix flaky test (@octocat, updated 2026-04-17)\n"),
+        "second row should have no note: {view}"
+    );
+}
+
+#[rstest]
+fn enter_is_ignored_without_results() {
+    let mut picker = PullRequestPicker::new(
+        "Pull requests",
+        PaginatedPullRequests {
+            items: Vec::new(),
+            page_info: PageInfo::default(),
+            rate_limit: None,
+        },
+    );
+
+    assert!(!press(&mut picker, KeyCode::Enter));
+    assert!(
+        bubbletea_rs::Model::view(&picker).contains("(no pull requests match)"),
+        "empty picker should say so"
+    );
+}
+
+#[rstest]
+#[case::load_key(KeyCode::Char('m'))]
+#[case::moving_past_the_end(KeyCode::Down)]
+fn more_pages_load_on_demand(#[case] key: KeyCode) {
+    let mut picker = picker().with_more_pages(Arc::new(UnusedPages));
+    press(&mut picker, KeyCode::End);
+
+    assert!(press(&mut picker, key), "loading should start");
+    assert!(
+        picker
+            .view()
+            .contains("2 shown, page 1 of 2; loading more..."),
+        "missing loading status: {}",
+        picker.view()
+    );
+    assert!(!press(&mut picker, key), "only one load at a time");
+
+    picker.update(Box::new(PageLoaded(Ok(second_page()))));
+    press(&mut picker, KeyCode::End);
+
+    assert_eq!(
+        picker.highlighted().map(PullRequestSummary::reference),
+        Some("octo/cli#3".to_owned())
+    );
+    assert!(
+        picker.view().contains("3 shown, page 2 of 2\n"),
+        "missing status: {}",
+        picker.view()
+    );
+    assert!(!press(&mut picker, KeyCode::Char('m')), "no pages remain");
+}
+
+#[rstest]
+fn failed_loads_are_reported_and_can_be_retried() {
+    let mut picker = picker().with_more_pages(Arc::new(UnusedPages));
+    press(&mut picker, KeyCode::Char('m'));
+
+    picker.update(Box::new(PageLoaded(Err(IntakeError::Api {
+        message: "timed out".to_owned(),
+    }))));
+
+    assert!(
+        picker
+            .view()
+            .contains("loading more failed: GitHub API error: timed out"),
+        "missing error: {}",
+        picker.view()
+    );
+    assert!(press(&mut picker, KeyCode::Char('m')), "retry should start");
+}
+
+#[rstest]
+fn listings_without_a_page_source_do_not_load_more() {
+    let mut picker = picker();
+
+    assert!(!press(&mut picker, KeyCode::Char('m')));
+    assert!(
+        picker.view().contains("refine the query to see more"),
+        "missing hint: {}",
+        picker.view()
+    );
+}
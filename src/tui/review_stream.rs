@@ -0,0 +1,150 @@
+//! Progressive loading of review comments at TUI startup.
+//!
+//! Large pull requests can carry thousands of review comments. Rather than
+//! waiting for every page, startup shows the first page and the TUI polls a
+//! [`ReviewCommentStream`] for the rest, merging each page as it arrives.
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use crate::github::error::IntakeError;
+use crate::github::gateway::ReviewCommentGateway;
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::ReviewComment;
+
+/// Progress of a streamed review comment listing.
+#[derive(Debug)]
+pub enum ReviewStreamUpdate {
+    /// A page of comments, without thread state.
+    Page(Vec<ReviewComment>),
+    /// The listing ended: the complete comments with thread state, or the
+    /// failure that stopped it.
+    Finished(Result<Vec<ReviewComment>, IntakeError>),
+}
+
+/// Handle on a review comment listing running in the background.
+pub struct ReviewCommentStream {
+    receiver: UnboundedReceiver<ReviewStreamUpdate>,
+}
+
+impl std::fmt::Debug for ReviewCommentStream {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("ReviewCommentStream(..)")
+    }
+}
+
+impl ReviewCommentStream {
+    /// Creates a stream from a channel receiver.
+    #[must_use]
+    pub const fn new(receiver: UnboundedReceiver<ReviewStreamUpdate>) -> Self {
+        Self { receiver }
+    }
+
+    /// Starts listing the review comments of `locator` through `gateway` on
+    /// the current Tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called outside a Tokio runtime.
+    #[must_use]
+    pub fn spawn(gateway: Arc<dyn ReviewCommentGateway>, locator: PullRequestLocator) -> Self {
+        let (sender, receiver) = unbounded_channel();
+        tokio::spawn(async move {
+            let (pages, page_receiver) = unbounded_channel();
+            let listing = gateway.stream_review_comments(&locator, pages);
+            let (result, ()) = tokio::join!(listing, forward_pages(page_receiver, &sender));
+            drop(sender.send(ReviewStreamUpdate::Finished(result)));
+        });
+        Self::new(receiver)
+    }
+
+    /// Waits for the next update.
+    ///
+    /// Returns `None` once the listing has finished and every update has
+    /// been received.
+    pub async fn next_update(&mut self) -> Option<ReviewStreamUpdate> {
+        self.receiver.recv().await
+    }
+
+    /// Returns the next update if one is ready, without waiting.
+    ///
+    /// A listing that stopped without reporting its outcome is reported as
+    /// failed.
+    pub(crate) fn try_next_update(&mut self) -> Option<ReviewStreamUpdate> {
+        match self.receiver.try_recv() {
+            Ok(update) => Some(update),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                Some(ReviewStreamUpdate::Finished(Err(IntakeError::Api {
+                    message: "review comment listing stopped unexpectedly".to_owned(),
+                })))
+            }
+        }
+    }
+}
+
+/// Forwards pages from the gateway until it drops its sender.
+async fn forward_pages(
+    mut pages: UnboundedReceiver<Vec<ReviewComment>>,
+    sender: &UnboundedSender<ReviewStreamUpdate>,
+) {
+    while let Some(page) = pages.recv().await {
+        drop(sender.send(ReviewStreamUpdate::Page(page)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `review_stream` module.
+
+    use std::sync::Arc;
+
+    use super::{ReviewCommentStream, ReviewStreamUpdate};
+    use crate::github::gateway::MockReviewCommentGateway;
+    use crate::github::locator::PullRequestLocator;
+    use crate::github::models::ReviewComment;
+
+    fn comment(id: u64) -> ReviewComment {
+        ReviewComment {
+            id,
+            ..ReviewComment::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn spawned_stream_forwards_pages_then_finishes() {
+        let mut gateway = MockReviewCommentGateway::new();
+        gateway
+            .expect_stream_review_comments()
+            .times(1)
+            .returning(|_, pages| {
+                drop(pages.send(vec![comment(1)]));
+                drop(pages.send(vec![comment(2)]));
+                Ok(vec![comment(1), comment(2)])
+            });
+        let locator = PullRequestLocator::parse("https://github.com/owner/repo/pull/1")
+            .expect("locator should parse");
+
+        let mut stream = ReviewCommentStream::spawn(Arc::new(gateway), locator);
+        let mut updates = Vec::new();
+        while let Some(update) = stream.next_update().await {
+            updates.push(update);
+        }
+
+        assert!(
+            matches!(
+                updates.as_slice(),
+                [
+                    ReviewStreamUpdate::Page(first),
+                    ReviewStreamUpdate::Page(second),
+                    ReviewStreamUpdate::Finished(Ok(all)),
+                ] if first == &vec![comment(1)]
+                    && second == &vec![comment(2)]
+                    && all.len() == 2
+            ),
+            "{updates:?}"
+        );
+    }
+}
@@ -1,12 +1,14 @@
 //! Diff context state and helpers for full-screen diff navigation.
 //!
 //! This module provides data structures for collecting diff hunks from review
-//! comments and tracking the current hunk in a full-screen diff view.
+//! comments and tracking the current hunk and line in a full-screen diff view.
 
 use std::collections::HashSet;
 
 use crate::github::models::ReviewComment;
 
+use super::diff_line_anchor::{DiffCommentDraft, DiffLineAnchor};
+
 /// A single diff hunk extracted from a review comment.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct DiffHunk {
@@ -14,6 +16,8 @@ pub(crate) struct DiffHunk {
     pub(crate) file_path: Option<String>,
     /// Line number associated with the hunk, if available.
     pub(crate) line_number: Option<u32>,
+    /// Commit SHA the hunk was captured at, if known.
+    pub(crate) commit_sha: Option<String>,
     /// Raw diff hunk text.
     pub(crate) text: String,
 }
@@ -25,6 +29,24 @@ pub(crate) struct RenderedDiffHunk {
     pub(crate) hunk: DiffHunk,
     /// Pre-rendered diff body string.
     pub(crate) rendered: String,
+    /// Comment anchor for each raw hunk line.
+    pub(crate) anchors: Vec<Option<DiffLineAnchor>>,
+    /// Number of wrapped rows each raw hunk line occupies in `rendered`.
+    pub(crate) line_rows: Vec<usize>,
+}
+
+impl RenderedDiffHunk {
+    /// Returns the index of the last commentable line, or zero if none.
+    ///
+    /// Review comment hunks end at the commented line, so this is where the
+    /// line cursor starts.
+    #[must_use]
+    pub(crate) fn default_line(&self) -> usize {
+        self.anchors
+            .iter()
+            .rposition(Option::is_some)
+            .unwrap_or_default()
+    }
 }
 
 /// Clamps a hunk index to the valid bounds for the given length.
@@ -43,6 +65,8 @@ pub(crate) struct DiffContextState {
     hunks: Vec<RenderedDiffHunk>,
     current_index: usize,
     cached_width: usize,
+    line_cursor: usize,
+    comment_draft: Option<DiffCommentDraft>,
 }
 
 impl DiffContextState {
@@ -63,9 +87,16 @@ impl DiffContextState {
         cached_width: usize,
         preferred_index: usize,
     ) {
+        let previous_hunk = self.current_hunk().map(|current| current.hunk.clone());
         self.hunks = hunks;
         self.cached_width = cached_width;
         self.current_index = clamp_hunk_index(preferred_index, self.hunks.len());
+
+        let same_hunk = previous_hunk.is_some()
+            && self.current_hunk().map(|current| &current.hunk) == previous_hunk.as_ref();
+        if !same_hunk {
+            self.reset_line_cursor();
+        }
     }
 
     /// Returns the rendered hunks.
@@ -129,6 +160,7 @@ impl DiffContextState {
         }
         self.current_index =
             clamp_hunk_index(self.current_index.saturating_add(1), self.hunks.len());
+        self.reset_line_cursor();
     }
 
     /// Moves to the previous hunk, clamping at the first hunk.
@@ -141,11 +173,88 @@ impl DiffContextState {
     /// let mut state = DiffContextState::default();
     /// state.move_previous();
     /// ```
-    pub(crate) const fn move_previous(&mut self) {
+    pub(crate) fn move_previous(&mut self) {
         if self.hunks.is_empty() {
             return;
         }
         self.current_index = self.current_index.saturating_sub(1);
+        self.reset_line_cursor();
+    }
+
+    /// Returns the index of the selected line within the current hunk.
+    #[must_use]
+    pub(crate) const fn line_cursor(&self) -> usize {
+        self.line_cursor
+    }
+
+    /// Moves the line cursor to the next commentable line, if any.
+    pub(crate) fn move_line_down(&mut self) {
+        let Some(current) = self.current_hunk() else {
+            return;
+        };
+        let next = current
+            .anchors
+            .iter()
+            .enumerate()
+            .skip(self.line_cursor.saturating_add(1))
+            .find_map(|(index, anchor)| anchor.map(|_| index));
+        if let Some(index) = next {
+            self.line_cursor = index;
+        }
+    }
+
+    /// Moves the line cursor to the previous commentable line, if any.
+    pub(crate) fn move_line_up(&mut self) {
+        let Some(current) = self.current_hunk() else {
+            return;
+        };
+        let previous = current
+            .anchors
+            .iter()
+            .take(self.line_cursor)
+            .rposition(Option::is_some);
+        if let Some(index) = previous {
+            self.line_cursor = index;
+        }
+    }
+
+    /// Returns the current hunk and the anchor under the line cursor.
+    #[must_use]
+    pub(crate) fn selected_line(&self) -> Option<(&DiffHunk, DiffLineAnchor)> {
+        let current = self.current_hunk()?;
+        let anchor = current.anchors.get(self.line_cursor).copied().flatten()?;
+        Some((&current.hunk, anchor))
+    }
+
+    /// Returns the new-comment draft being written, if any.
+    #[must_use]
+    pub(crate) const fn comment_draft(&self) -> Option<&DiffCommentDraft> {
+        self.comment_draft.as_ref()
+    }
+
+    /// Returns the new-comment draft for editing, if any.
+    pub(crate) const fn comment_draft_mut(&mut self) -> Option<&mut DiffCommentDraft> {
+        self.comment_draft.as_mut()
+    }
+
+    /// Starts writing a new comment, replacing any existing draft.
+    pub(crate) fn start_comment_draft(&mut self, draft: DiffCommentDraft) {
+        self.comment_draft = Some(draft);
+    }
+
+    /// Removes and returns the new-comment draft, if any.
+    pub(crate) const fn take_comment_draft(&mut self) -> Option<DiffCommentDraft> {
+        self.comment_draft.take()
+    }
+
+    fn current_hunk(&self) -> Option<&RenderedDiffHunk> {
+        self.hunks.get(self.current_index)
+    }
+
+    fn reset_line_cursor(&mut self) {
+        self.line_cursor = self
+            .current_hunk()
+            .map_or(0, RenderedDiffHunk::default_line);
     }
 }
 
@@ -200,6 +309,7 @@ pub(crate) fn collect_diff_hunks(
             hunks.push(DiffHunk {
                 file_path: key.file_path,
                 line_number: comment.line_number,
+                commit_sha: comment.commit_sha.clone(),
                 text: key.text,
             });
         }
@@ -249,98 +359,4 @@ pub(crate) fn find_hunk_index(
 }
 
 #[cfg(test)]
-mod tests {
-    //! Unit tests for the `diff_context` module.
-
-    use rstest::{fixture, rstest};
-
-    use super::*;
-    use crate::github::models::test_support::minimal_review;
-
-    #[fixture]
-    fn reviews() -> Vec<ReviewComment> {
-        vec![
-            ReviewComment {
-                file_path: Some("src/lib.rs".to_owned()),
-                line_number: Some(10),
-                diff_hunk: Some("@@ -1 +1 @@\n+fn a() {}".to_owned()),
-                ..minimal_review(1, "First", "alice")
-            },
-            ReviewComment {
-                file_path: Some("src/lib.rs".to_owned()),
-                line_number: Some(20),
-                diff_hunk: Some("@@ -1 +1 @@\n+fn a() {}".to_owned()),
-                ..minimal_review(2, "Second", "bob")
-            },
-            ReviewComment {
-                file_path: Some("src/main.rs".to_owned()),
-                line_number: Some(5),
-                diff_hunk: Some("@@ -1 +1 @@\n+fn b() {}".to_owned()),
-                ..minimal_review(3, "Third", "cara")
-            },
-            ReviewComment {
-                file_path: None,
-                line_number: None,
-                diff_hunk: None,
-                ..minimal_review(4, "Fourth", "drew")
-            },
-        ]
-    }
-
-    #[rstest]
-    fn collect_diff_hunks_deduplicates(reviews: Vec<ReviewComment>) {
-        let indices = vec![0, 1, 2, 3];
-        let hunks = collect_diff_hunks(&reviews, &indices);
-
-        assert_eq!(hunks.len(), 2, "expected deduplicated hunks");
-    }
-
-    #[rstest]
-    fn collect_diff_hunks_sorts_by_path_then_line(reviews: Vec<ReviewComment>) {
-        let indices = vec![2, 0];
-        let hunks = collect_diff_hunks(&reviews, &indices);
-
-        let first = hunks.first().expect("expected at least one hunk");
-        let second = hunks.get(1).expect("expected two hunks");
-        assert_eq!(first.file_path.as_deref(), Some("src/lib.rs"));
-        assert_eq!(second.file_path.as_deref(), Some("src/main.rs"));
-    }
-
-    #[rstest]
-    fn find_hunk_index_uses_selected_comment(reviews: Vec<ReviewComment>) {
-        let indices = vec![0, 2];
-        let hunks = collect_diff_hunks(&reviews, &indices);
-        let selected = reviews.get(2);
-
-        let index = find_hunk_index(&hunks, selected);
-
-        assert_eq!(index, 1);
-    }
-
-    #[test]
-    fn rebuild_clamps_to_length() {
-        let hunks = vec![
-            RenderedDiffHunk {
-                hunk: DiffHunk {
-                    file_path: Some("src/lib.rs".to_owned()),
-                    line_number: Some(1),
-                    text: "@@ -1 +1 @@\n+fn a() {}".to_owned(),
-                },
-                rendered: "first\n".to_owned(),
-            },
-            RenderedDiffHunk {
-                hunk: DiffHunk {
-                    file_path: Some("src/main.rs".to_owned()),
-                    line_number: Some(2),
-                    text: "@@ -1 +1 @@\n+fn b() {}".to_owned(),
-                },
-                rendered: "second\n".to_owned(),
-            },
-        ];
-
-        let mut state = DiffContextState::default();
-        state.rebuild(hunks, 80, 5);
-
-        assert_eq!(state.current_index(), 1);
-    }
-}
+mod tests;
@@ -0,0 +1,130 @@
+//! Unit tests for the `diff_context` module.
+
+use rstest::{fixture, rstest};
+
+use super::*;
+use crate::github::models::test_support::minimal_review;
+use crate::github::review_submission::DiffSide;
+use crate::tui::state::diff_line_anchor::diff_line_anchors;
+
+#[fixture]
+fn reviews() -> Vec<ReviewComment> {
+    vec![
+        ReviewComment {
+            file_path: Some("src/lib.rs".to_owned()),
+            line_number: Some(10),
+            diff_hunk: Some("@@ -1 +1 @@\n+fn a() {}".to_owned()),
+            ..minimal_review(1, "First", "alice")
+        },
+        ReviewComment {
+            file_path: Some("src/lib.rs".to_owned()),
+            line_number: Some(20),
+            diff_hunk: Some("@@ -1 +1 @@\n+fn a() {}".to_owned()),
+            ..minimal_review(2, "Second", "bob")
+        },
+        ReviewComment {
+            file_path: Some("src/main.rs".to_owned()),
+            line_number: Some(5),
+            diff_hunk: Some("@@ -1 +1 @@\n+fn b() {}".to_owned()),
+            ..minimal_review(3, "Third", "cara")
+        },
+        ReviewComment {
+            file_path: None,
+            line_number: None,
+            diff_hunk: None,
+            ..minimal_review(4, "Fourth", "drew")
+        },
+    ]
+}
+
+#[rstest]
+fn collect_diff_hunks_deduplicates(reviews: Vec<ReviewComment>) {
+    let indices = vec![0, 1, 2, 3];
+    let hunks = collect_diff_hunks(&reviews, &indices);
+
+    assert_eq!(hunks.len(), 2, "expected deduplicated hunks");
+}
+
+#[rstest]
+fn collect_diff_hunks_sorts_by_path_then_line(reviews: Vec<ReviewComment>) {
+    let indices = vec![2, 0];
+    let hunks = collect_diff_hunks(&reviews, &indices);
+
+    let first = hunks.first().expect("expected at least one hunk");
+    let second = hunks.get(1).expect("expected two hunks");
+    assert_eq!(first.file_path.as_deref(), Some("src/lib.rs"));
+    assert_eq!(second.file_path.as_deref(), Some("src/main.rs"));
+}
+
+#[rstest]
+fn find_hunk_index_uses_selected_comment(reviews: Vec<ReviewComment>) {
+    let indices = vec![0, 2];
+    let hunks = collect_diff_hunks(&reviews, &indices);
+    let selected = reviews.get(2);
+
+    let index = find_hunk_index(&hunks, selected);
+
+    assert_eq!(index, 1);
+}
+
+fn rendered_hunk(path: &str, text: &str) -> RenderedDiffHunk {
+    let anchors = diff_line_anchors(text);
+    RenderedDiffHunk {
+        hunk: DiffHunk {
+            file_path: Some(path.to_owned()),
+            line_number: Some(1),
+            commit_sha: Some("abc123".to_owned()),
+            text: text.to_owned(),
+        },
+        rendered: format!("{text}\n"),
+        line_rows: vec![1; anchors.len()],
+        anchors,
+    }
+}
+
+#[test]
+fn rebuild_clamps_to_length() {
+    let hunks = vec![
+        rendered_hunk("src/lib.rs", "@@ -1 +1 @@\n+fn a() {}"),
+        rendered_hunk("src/main.rs", "@@ -1 +1 @@\n+fn b() {}"),
+    ];
+
+    let mut state = DiffContextState::default();
+    state.rebuild(hunks, 80, 5);
+
+    assert_eq!(state.current_index(), 1);
+}
+
+#[test]
+fn line_cursor_starts_on_last_line_and_skips_headers() {
+    let hunks = vec![rendered_hunk(
+        "src/lib.rs",
+        "@@ -4,2 +4,3 @@\n keep\n-old\n+new\n+added",
+    )];
+    let mut state = DiffContextState::default();
+    state.rebuild(hunks, 80, 0);
+
+    assert_eq!(state.line_cursor(), 4);
+    for _ in 0..5 {
+        state.move_line_up();
+    }
+    assert_eq!(state.line_cursor(), 1, "header should not be selectable");
+
+    state.move_line_down();
+    let (hunk, anchor) = state.selected_line().expect("line should be selected");
+    assert_eq!(hunk.file_path.as_deref(), Some("src/lib.rs"));
+    assert_eq!(anchor.line, 5);
+    assert_eq!(anchor.side, DiffSide::Left);
+}
+
+#[test]
+fn rebuild_keeps_line_cursor_on_same_hunk() {
+    let text = "@@ -1,2 +1,2 @@\n a\n b";
+    let mut state = DiffContextState::default();
+    state.rebuild(vec![rendered_hunk("src/lib.rs", text)], 80, 0);
+    state.move_line_up();
+
+    state.rebuild(vec![rendered_hunk("src/lib.rs", text)], 40, 0);
+
+    assert_eq!(state.line_cursor(), 1);
+}
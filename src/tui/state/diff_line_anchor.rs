@@ -0,0 +1,238 @@
+//! Diff line anchors and new-comment drafts for the diff context view.
+//!
+//! A review comment diff hunk starts with a `@@ -a,b +c,d @@` header followed
+//! by context (` `), added (`+`), and removed (`-`) lines. This module maps
+//! each hunk line to the file line and diff side GitHub expects when a new
+//! inline comment is anchored to it, and holds the draft being written for
+//! the selected line.
+
+use crate::github::review_submission::{DiffSide, PendingReviewComment};
+
+/// File line and diff side a new inline comment can be anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DiffLineAnchor {
+    /// Line number in the file on `side`.
+    pub(crate) line: u32,
+    /// Diff side the line belongs to.
+    pub(crate) side: DiffSide,
+}
+
+/// Maps each line of a diff hunk to its comment anchor.
+///
+/// The returned vector has one entry per line in `text`. Header lines,
+/// `\ No newline at end of file` markers, and every line of a hunk whose
+/// header cannot be parsed map to `None`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use frankie::tui::state::diff_line_anchors;
+///
+/// let anchors = diff_line_anchors("@@ -1 +1 @@\n+fn main() {}");
+/// assert_eq!(anchors.len(), 2);
+/// assert!(anchors[0].is_none());
+/// ```
+#[must_use]
+pub(crate) fn diff_line_anchors(text: &str) -> Vec<Option<DiffLineAnchor>> {
+    let mut lines = text.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let Some((mut old_line, mut new_line)) = parse_hunk_header(header) else {
+        return text.lines().map(|_| None).collect();
+    };
+
+    let mut anchors = vec![None];
+    for line in lines {
+        let anchor = match line.chars().next() {
+            Some('-') => {
+                let anchor = anchor_at(old_line, DiffSide::Left);
+                old_line = old_line.saturating_add(1);
+                anchor
+            }
+            Some('+') => {
+                let anchor = anchor_at(new_line, DiffSide::Right);
+                new_line = new_line.saturating_add(1);
+                anchor
+            }
+            Some('\\') => None,
+            _ => {
+                let anchor = anchor_at(new_line, DiffSide::Right);
+                old_line = old_line.saturating_add(1);
+                new_line = new_line.saturating_add(1);
+                anchor
+            }
+        };
+        anchors.push(anchor);
+    }
+
+    anchors
+}
+
+/// Returns an anchor unless `line` is zero (an empty side of the hunk).
+const fn anchor_at(line: u32, side: DiffSide) -> Option<DiffLineAnchor> {
+    if line == 0 {
+        None
+    } else {
+        Some(DiffLineAnchor { line, side })
+    }
+}
+
+/// Parses the old and new start lines from a `@@ -a,b +c,d @@` header.
+fn parse_hunk_header(header: &str) -> Option<(u32, u32)> {
+    let (ranges, _) = header.strip_prefix("@@ ")?.split_once(" @@")?;
+    let (old_range, new_range) = ranges.split_once(' ')?;
+    let old_start = parse_range_start(old_range.strip_prefix('-')?)?;
+    let new_start = parse_range_start(new_range.strip_prefix('+')?)?;
+    Some((old_start, new_start))
+}
+
+fn parse_range_start(range: &str) -> Option<u32> {
+    range
+        .split_once(',')
+        .map_or(range, |(start, _)| start)
+        .parse()
+        .ok()
+}
+
+/// New inline comment being written for a line in the diff context view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DiffCommentDraft {
+    path: String,
+    commit_sha: String,
+    anchor: DiffLineAnchor,
+    body: String,
+}
+
+impl DiffCommentDraft {
+    /// Starts an empty draft anchored to `anchor` in `path` at `commit_sha`.
+    #[must_use]
+    pub(crate) fn new(
+        path: impl Into<String>,
+        commit_sha: impl Into<String>,
+        anchor: DiffLineAnchor,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            commit_sha: commit_sha.into(),
+            anchor,
+            body: String::new(),
+        }
+    }
+
+    /// Returns the repository-relative file path.
+    #[must_use]
+    pub(crate) const fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// Returns the commit SHA the draft is anchored to.
+    #[must_use]
+    pub(crate) const fn commit_sha(&self) -> &str {
+        self.commit_sha.as_str()
+    }
+
+    /// Returns the anchored line and side.
+    #[must_use]
+    pub(crate) const fn anchor(&self) -> DiffLineAnchor {
+        self.anchor
+    }
+
+    /// Returns the draft body.
+    #[must_use]
+    pub(crate) const fn body(&self) -> &str {
+        self.body.as_str()
+    }
+
+    /// Appends one character to the body.
+    pub(crate) fn push_char(&mut self, character: char) {
+        self.body.push(character);
+    }
+
+    /// Removes the last character from the body, if present.
+    pub(crate) fn backspace(&mut self) {
+        let _ = self.body.pop();
+    }
+
+    /// Builds the pending review comment described by the draft.
+    #[must_use]
+    pub(crate) fn to_pending_comment(&self) -> PendingReviewComment {
+        PendingReviewComment::new(self.path.as_str(), self.anchor.line, self.body.trim())
+            .with_side(self.anchor.side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `diff_line_anchor` module.
+
+    use rstest::rstest;
+
+    use super::{DiffCommentDraft, DiffLineAnchor, diff_line_anchors};
+    use crate::github::review_submission::DiffSide;
+
+    const fn right(line: u32) -> DiffLineAnchor {
+        DiffLineAnchor {
+            line,
+            side: DiffSide::Right,
+        }
+    }
+
+    const fn left(line: u32) -> DiffLineAnchor {
+        DiffLineAnchor {
+            line,
+            side: DiffSide::Left,
+        }
+    }
+
+    #[rstest]
+    fn anchors_follow_old_and_new_line_counters() {
+        let hunk = "@@ -10,4 +20,4 @@ fn context()\n keep\n-old\n+new\n+added\n tail";
+
+        let anchors = diff_line_anchors(hunk);
+
+        assert_eq!(
+            anchors,
+            vec![
+                None,
+                Some(right(20)),
+                Some(left(11)),
+                Some(right(21)),
+                Some(right(22)),
+                Some(right(23))
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::no_newline_marker("@@ -1 +1 @@\n+a\n\\ No newline at end of file", vec![None, Some(right(1)), None])]
+    #[case::unparsable_header("not a header\n+a", vec![None, None])]
+    #[case::empty_new_side("@@ -3,1 +0,0 @@\n-gone", vec![None, Some(left(3))])]
+    fn anchors_skip_uncommentable_lines(
+        #[case] hunk: &str,
+        #[case] expected: Vec<Option<DiffLineAnchor>>,
+    ) {
+        assert_eq!(diff_line_anchors(hunk), expected);
+    }
+
+    #[rstest]
+    fn draft_builds_anchored_pending_comment() {
+        let anchor = DiffLineAnchor {
+            line: 7,
+            side: DiffSide::Left,
+        };
+        let mut draft = DiffCommentDraft::new("src/lib.rs", "abc123", anchor);
+        for character in " Why? ".chars() {
+            draft.push_char(character);
+        }
+        draft.backspace();
+
+        let comment = draft.to_pending_comment();
+
+        assert_eq!(comment.path, "src/lib.rs");
+        assert_eq!(comment.line, 7);
+        assert_eq!(comment.side, DiffSide::Left);
+        assert_eq!(comment.body, "Why?");
+        assert_eq!(draft.commit_sha(), "abc123");
+    }
+}
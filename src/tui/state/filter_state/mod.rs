@@ -0,0 +1,199 @@
+//! Filter and cursor state for review listing.
+//!
+//! This module provides types for managing which reviews are displayed and
+//! tracking the user's position within the filtered list. The design ensures
+//! that cursor position is retained when filters change (clamped to valid range).
+
+use crate::github::models::{ReviewComment, TimelineEntry};
+
+mod predicates;
+
+use predicates::{commit_in_range, conversation_item_matches, is_unresolved_thread_root};
+
+/// Filter criteria for the review listing.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ReviewFilter {
+    /// Show all review comments.
+    #[default]
+    All,
+    /// Show only unresolved review threads, one entry per thread root.
+    ///
+    /// When GitHub thread state has been loaded, a root comment is shown if
+    /// its thread has not been marked as resolved via "Resolve conversation".
+    ///
+    /// Without thread state the filter falls back to a heuristic: a root
+    /// comment (no `in_reply_to_id`) is unresolved when no other comment
+    /// replies to it. Comments that have received at least one reply are
+    /// considered addressed.
+    Unresolved,
+    /// Show only comments on a specific file path.
+    ByFile(String),
+    /// Show only comments from a specific reviewer.
+    ByReviewer(String),
+    /// Show only comments within a commit range.
+    ByCommitRange {
+        /// Starting commit SHA (exclusive).
+        from: String,
+        /// Ending commit SHA (inclusive).
+        to: String,
+    },
+}
+
+impl ReviewFilter {
+    /// Returns a human-readable label for display in the UI.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self {
+            Self::All => "All".to_owned(),
+            Self::Unresolved => "Unresolved".to_owned(),
+            Self::ByFile(path) => format!("File: {path}"),
+            Self::ByReviewer(name) => format!("Reviewer: {name}"),
+            Self::ByCommitRange { from, to } => {
+                let from_short = truncate_sha(from);
+                let to_short = truncate_sha(to);
+                format!("Commits: {from_short}..{to_short}")
+            }
+        }
+    }
+
+    /// Returns true if this filter matches the given review comment.
+    ///
+    /// The `all_reviews` parameter is required for filters that need thread
+    /// analysis (e.g., `Unresolved` checks if a comment has replies).
+    #[must_use]
+    pub fn matches(&self, review: &ReviewComment, all_reviews: &[ReviewComment]) -> bool {
+        match self {
+            Self::All => true,
+            Self::Unresolved => is_unresolved_thread_root(review, all_reviews),
+            Self::ByFile(path) => review.file_path.as_ref().is_some_and(|p| p == path),
+            Self::ByReviewer(name) => review.author.as_ref().is_some_and(|a| a == name),
+            Self::ByCommitRange { from, to } => {
+                commit_in_range(review.commit_sha.as_deref(), from, to)
+            }
+        }
+    }
+
+    /// Returns true if this filter matches the given conversation timeline
+    /// entry.
+    ///
+    /// A review thread matches when any of its comments does. Issue comments
+    /// and reviews belong to no file or thread, so only the reviewer filter
+    /// and, for reviews, the commit range filter can narrow them.
+    #[must_use]
+    pub fn matches_timeline_entry(
+        &self,
+        entry: &TimelineEntry<'_>,
+        all_reviews: &[ReviewComment],
+    ) -> bool {
+        match entry {
+            TimelineEntry::ReviewThread(thread) => thread
+                .comments()
+                .any(|comment| self.matches(comment, all_reviews)),
+            TimelineEntry::IssueComment(_) | TimelineEntry::Review(_) => {
+                conversation_item_matches(self, entry)
+            }
+        }
+    }
+}
+
+/// State managing the active filter and cursor position.
+#[derive(Debug, Clone, Default)]
+pub struct FilterState {
+    /// Currently active filter.
+    pub active_filter: ReviewFilter,
+    /// Current cursor position (0-indexed) within the filtered list.
+    pub cursor_position: usize,
+    /// Scroll offset for virtual scrolling (lines scrolled from top).
+    pub scroll_offset: usize,
+}
+
+impl FilterState {
+    /// Creates a new filter state with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the current filter to a list of reviews.
+    ///
+    /// Returns a vector of references to matching reviews.
+    #[must_use]
+    pub fn apply_filter<'a>(&self, reviews: &'a [ReviewComment]) -> Vec<&'a ReviewComment> {
+        reviews
+            .iter()
+            .filter(|review| self.active_filter.matches(review, reviews))
+            .collect()
+    }
+
+    /// Updates the filter and clamps the cursor to valid range.
+    ///
+    /// This method preserves the cursor position when possible, only adjusting
+    /// it if the new filtered list is shorter than the current position.
+    pub fn set_filter(&mut self, filter: ReviewFilter, new_count: usize) {
+        self.active_filter = filter;
+        self.clamp_cursor(new_count);
+    }
+
+    /// Clamps the cursor position to be within the valid range.
+    ///
+    /// If the list is empty, cursor is set to 0. If cursor exceeds the list
+    /// length, it is set to the last valid index.
+    pub const fn clamp_cursor(&mut self, count: usize) {
+        if count == 0 {
+            self.cursor_position = 0;
+            self.scroll_offset = 0;
+        } else if self.cursor_position >= count {
+            self.cursor_position = count.saturating_sub(1);
+        }
+    }
+
+    /// Moves the cursor up by one position if possible.
+    pub const fn cursor_up(&mut self) {
+        self.cursor_position = self.cursor_position.saturating_sub(1);
+    }
+
+    /// Moves the cursor down by one position if within bounds.
+    pub const fn cursor_down(&mut self, max_index: usize) {
+        if self.cursor_position < max_index {
+            self.cursor_position = self.cursor_position.saturating_add(1);
+        }
+    }
+
+    /// Moves the cursor up by a page (visible height).
+    pub const fn page_up(&mut self, page_size: usize) {
+        self.cursor_position = self.cursor_position.saturating_sub(page_size);
+    }
+
+    /// Moves the cursor down by a page (visible height).
+    pub const fn page_down(&mut self, page_size: usize, max_index: usize) {
+        let new_pos = self.cursor_position.saturating_add(page_size);
+        self.cursor_position = if new_pos < max_index {
+            new_pos
+        } else {
+            max_index
+        };
+    }
+
+    /// Moves the cursor to the first item.
+    pub const fn home(&mut self) {
+        self.cursor_position = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Moves the cursor to the last item.
+    pub const fn end(&mut self, max_index: usize) {
+        self.cursor_position = max_index;
+    }
+}
+
+/// Truncates a SHA to first 7 characters for display.
+///
+/// SHA strings are ASCII hex digits, but we use `get()` for safety in case
+/// the input contains unexpected characters.
+fn truncate_sha(sha: &str) -> &str {
+    const SHA_DISPLAY_LEN: usize = 7;
+    sha.get(..SHA_DISPLAY_LEN).unwrap_or(sha)
+}
+
+#[cfg(test)]
+mod tests;
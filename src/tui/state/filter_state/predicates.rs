@@ -0,0 +1,51 @@
+//! Match predicates shared by the review filters.
+
+use crate::github::models::{ReviewComment, TimelineEntry};
+
+use super::ReviewFilter;
+
+/// Returns true when `review` roots a thread that is still unresolved.
+///
+/// Only thread roots are listed; replies belong to their root. GitHub thread
+/// state wins when it has been loaded; otherwise a root counts as unresolved
+/// until another comment in `all_reviews` replies to it.
+pub(super) fn is_unresolved_thread_root(
+    review: &ReviewComment,
+    all_reviews: &[ReviewComment],
+) -> bool {
+    if review.in_reply_to_id.is_some() {
+        return false;
+    }
+    if let Some(thread_state) = &review.thread_state {
+        return !thread_state.is_resolved;
+    }
+    !all_reviews
+        .iter()
+        .any(|reply| reply.in_reply_to_id == Some(review.id))
+}
+
+/// Returns true when `commit_sha` matches either end of the commit range.
+///
+/// Full range checking requires commit ordering, which is deferred to a
+/// future implementation.
+pub(super) fn commit_in_range(commit_sha: Option<&str>, from: &str, to: &str) -> bool {
+    commit_sha.is_some_and(|sha| sha == from || sha == to)
+}
+
+/// Returns true when `filter` matches an issue comment or review entry.
+///
+/// These entries belong to no file or thread, so only the reviewer filter
+/// and, for reviews, the commit range filter can narrow them.
+pub(super) fn conversation_item_matches(filter: &ReviewFilter, entry: &TimelineEntry<'_>) -> bool {
+    match filter {
+        ReviewFilter::All => true,
+        ReviewFilter::Unresolved | ReviewFilter::ByFile(_) => false,
+        ReviewFilter::ByReviewer(name) => entry.author() == Some(name.as_str()),
+        ReviewFilter::ByCommitRange { from, to } => match entry {
+            TimelineEntry::Review(review) => {
+                commit_in_range(review.commit_sha.as_deref(), from, to)
+            }
+            _ => false,
+        },
+    }
+}
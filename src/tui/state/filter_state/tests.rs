@@ -0,0 +1,265 @@
+//! Unit tests for the `filter_state` module.
+
+use rstest::{fixture, rstest};
+
+use super::*;
+
+/// Creates a review comment with configurable author and file path.
+fn make_review(id: u64, author: Option<&str>, file: Option<&str>) -> ReviewComment {
+    ReviewComment {
+        id,
+        body: Some("Test comment".to_owned()),
+        author: author.map(ToOwned::to_owned),
+        file_path: file.map(ToOwned::to_owned),
+        line_number: Some(42),
+        side: None,
+        original_line_number: None,
+        diff_hunk: None,
+        commit_sha: None,
+        in_reply_to_id: None,
+        created_at: None,
+        updated_at: None,
+        thread_state: None,
+        start_line_number: None,
+        reactions: Vec::new(),
+    }
+}
+
+/// Attaches GitHub thread state to a review comment.
+fn with_thread_state(review: ReviewComment, is_resolved: bool) -> ReviewComment {
+    ReviewComment {
+        thread_state: Some(crate::github::ReviewThreadState {
+            thread_id: format!("PRRT_{}", review.id),
+            is_resolved,
+            is_outdated: false,
+            resolved_by: is_resolved.then(|| "carol".to_owned()),
+        }),
+        ..review
+    }
+}
+
+#[fixture]
+fn alice_main_review() -> ReviewComment {
+    make_review(1, Some("alice"), Some("src/main.rs"))
+}
+
+#[rstest]
+#[case::all(ReviewFilter::All, [true, true, true])]
+#[case::unresolved(ReviewFilter::Unresolved, [false, false, true])]
+#[case::by_file(ReviewFilter::ByFile("src/main.rs".to_owned()), [false, false, true])]
+#[case::by_reviewer(ReviewFilter::ByReviewer("bob".to_owned()), [true, false, false])]
+#[case::by_commit_range(
+    ReviewFilter::ByCommitRange { from: "abc".to_owned(), to: "def".to_owned() },
+    [false, true, false]
+)]
+fn filter_matches_timeline_entries(
+    alice_main_review: ReviewComment,
+    #[case] filter: ReviewFilter,
+    #[case] expected: [bool; 3],
+) {
+    use crate::github::models::{PullRequestComment, PullRequestReview, TimelineThread};
+
+    let issue_comment = PullRequestComment {
+        id: 10,
+        body: Some("CI report".to_owned()),
+        author: Some("bob".to_owned()),
+        created_at: None,
+        html_url: None,
+    };
+    let review = PullRequestReview {
+        id: 20,
+        author: Some("carol".to_owned()),
+        commit_sha: Some("def".to_owned()),
+        ..PullRequestReview::default()
+    };
+    let reviews = [alice_main_review.clone()];
+    let entries = [
+        TimelineEntry::IssueComment(&issue_comment),
+        TimelineEntry::Review(&review),
+        TimelineEntry::ReviewThread(TimelineThread {
+            root: &alice_main_review,
+            replies: Vec::new(),
+        }),
+    ];
+
+    let matched = entries
+        .each_ref()
+        .map(|entry| filter.matches_timeline_entry(entry, &reviews));
+
+    assert_eq!(matched, expected);
+}
+
+#[rstest]
+fn filter_all_matches_everything(alice_main_review: ReviewComment) {
+    let reviews = [alice_main_review.clone()];
+    assert!(ReviewFilter::All.matches(&alice_main_review, &reviews));
+}
+
+#[rstest]
+fn filter_by_file_matches_correct_path(alice_main_review: ReviewComment) {
+    let reviews = [alice_main_review.clone()];
+    assert!(ReviewFilter::ByFile("src/main.rs".to_owned()).matches(&alice_main_review, &reviews));
+    assert!(!ReviewFilter::ByFile("src/lib.rs".to_owned()).matches(&alice_main_review, &reviews));
+}
+
+#[rstest]
+fn filter_by_reviewer_matches_correct_author(alice_main_review: ReviewComment) {
+    let reviews = [alice_main_review.clone()];
+    assert!(ReviewFilter::ByReviewer("alice".to_owned()).matches(&alice_main_review, &reviews));
+    assert!(!ReviewFilter::ByReviewer("bob".to_owned()).matches(&alice_main_review, &reviews));
+}
+
+#[test]
+fn filter_unresolved_matches_unanswered_root_comments() {
+    let root_unanswered = make_review(1, Some("alice"), Some("src/main.rs"));
+    let root_answered = make_review(2, Some("alice"), Some("src/lib.rs"));
+    let reply_to_root2 = ReviewComment {
+        in_reply_to_id: Some(2),
+        ..make_review(3, Some("bob"), Some("src/lib.rs"))
+    };
+
+    let reviews = [
+        root_unanswered.clone(),
+        root_answered.clone(),
+        reply_to_root2.clone(),
+    ];
+
+    // Root comment with no replies is unresolved
+    assert!(ReviewFilter::Unresolved.matches(&root_unanswered, &reviews));
+    // Root comment with a reply is NOT unresolved (it has been answered)
+    assert!(!ReviewFilter::Unresolved.matches(&root_answered, &reviews));
+    // Reply comments are never considered unresolved
+    assert!(!ReviewFilter::Unresolved.matches(&reply_to_root2, &reviews));
+}
+
+#[test]
+fn filter_unresolved_prefers_github_thread_state() {
+    let resolved_unanswered =
+        with_thread_state(make_review(1, Some("alice"), Some("src/main.rs")), true);
+    let open_answered = with_thread_state(make_review(2, Some("alice"), Some("src/lib.rs")), false);
+    let reply_to_root2 = with_thread_state(
+        ReviewComment {
+            in_reply_to_id: Some(2),
+            ..make_review(3, Some("bob"), Some("src/lib.rs"))
+        },
+        false,
+    );
+
+    let reviews = [
+        resolved_unanswered.clone(),
+        open_answered.clone(),
+        reply_to_root2.clone(),
+    ];
+
+    // Resolved on GitHub even though nobody replied
+    assert!(!ReviewFilter::Unresolved.matches(&resolved_unanswered, &reviews));
+    // Still open on GitHub even though it has a reply
+    assert!(ReviewFilter::Unresolved.matches(&open_answered, &reviews));
+    // Replies stay folded into their root
+    assert!(!ReviewFilter::Unresolved.matches(&reply_to_root2, &reviews));
+}
+
+#[test]
+fn clamp_cursor_sets_to_zero_when_empty() {
+    let mut state = FilterState {
+        cursor_position: 5,
+        ..FilterState::default()
+    };
+    state.clamp_cursor(0);
+    assert_eq!(state.cursor_position, 0);
+}
+
+#[test]
+fn clamp_cursor_reduces_to_last_valid_index() {
+    let mut state = FilterState {
+        cursor_position: 10,
+        ..FilterState::default()
+    };
+    state.clamp_cursor(5);
+    assert_eq!(state.cursor_position, 4);
+}
+
+#[test]
+fn clamp_cursor_preserves_valid_position() {
+    let mut state = FilterState {
+        cursor_position: 3,
+        ..FilterState::default()
+    };
+    state.clamp_cursor(10);
+    assert_eq!(state.cursor_position, 3);
+}
+
+#[test]
+fn set_filter_changes_filter_and_clamps() {
+    let mut state = FilterState {
+        cursor_position: 10,
+        active_filter: ReviewFilter::All,
+        ..FilterState::default()
+    };
+    state.set_filter(ReviewFilter::Unresolved, 5);
+    assert_eq!(state.active_filter, ReviewFilter::Unresolved);
+    assert_eq!(state.cursor_position, 4);
+}
+
+#[test]
+fn apply_filter_returns_matching_reviews() {
+    let reviews = vec![
+        make_review(1, Some("alice"), Some("src/main.rs")),
+        make_review(2, Some("bob"), Some("src/lib.rs")),
+        make_review(3, Some("alice"), Some("src/lib.rs")),
+    ];
+
+    let state = FilterState {
+        active_filter: ReviewFilter::ByReviewer("alice".to_owned()),
+        ..FilterState::default()
+    };
+
+    let filtered = state.apply_filter(&reviews);
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.first().map(|r| r.id), Some(1));
+    assert_eq!(filtered.get(1).map(|r| r.id), Some(3));
+}
+
+#[test]
+fn cursor_navigation_respects_bounds() {
+    let mut state = FilterState {
+        cursor_position: 5,
+        ..FilterState::default()
+    };
+
+    state.cursor_up();
+    assert_eq!(state.cursor_position, 4);
+
+    state.cursor_position = 0;
+    state.cursor_up();
+    assert_eq!(state.cursor_position, 0); // Cannot go below 0
+
+    state.cursor_down(10);
+    assert_eq!(state.cursor_position, 1);
+
+    state.cursor_position = 10;
+    state.cursor_down(10);
+    assert_eq!(state.cursor_position, 10); // Cannot exceed max
+}
+
+#[test]
+fn filter_label_is_human_readable() {
+    assert_eq!(ReviewFilter::All.label(), "All");
+    assert_eq!(ReviewFilter::Unresolved.label(), "Unresolved");
+    assert_eq!(
+        ReviewFilter::ByFile("src/main.rs".to_owned()).label(),
+        "File: src/main.rs"
+    );
+    assert_eq!(
+        ReviewFilter::ByReviewer("alice".to_owned()).label(),
+        "Reviewer: alice"
+    );
+    assert_eq!(
+        ReviewFilter::ByCommitRange {
+            from: "abc123456789".to_owned(),
+            to: "def987654321".to_owned()
+        }
+        .label(),
+        "Commits: abc1234..def9876"
+    );
+}
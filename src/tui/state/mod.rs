@@ -1,16 +1,21 @@
 //! State management for the review listing TUI.
 //!
 //! This module provides the core state types for managing filter criteria,
-//! cursor position in the review list, and time-travel navigation state.
+//! cursor position in the review list, time-travel navigation state, and
+//! the pending review composer.
 
 mod diff_context;
+mod diff_line_anchor;
 mod filter_state;
 mod reply_draft;
+mod review_composer;
 
 pub use crate::time_travel::{TimeTravelInitParams, TimeTravelState};
 pub(crate) use diff_context::{
     DiffContextState, DiffHunk, RenderedDiffHunk, clamp_hunk_index, collect_diff_hunks,
     find_hunk_index,
 };
+pub(crate) use diff_line_anchor::{DiffCommentDraft, diff_line_anchors};
 pub use filter_state::{FilterState, ReviewFilter};
 pub use reply_draft::{ReplyDraftError, ReplyDraftState};
+pub use review_composer::{QueuedReviewComment, ReviewComposerState};
@@ -2,7 +2,8 @@
 //!
 //! This module encapsulates editable reply draft state tied to a selected
 //! review comment. It enforces a maximum character count and tracks
-//! send-readiness for inline replies.
+//! send-readiness for inline replies and for edits of the user's own
+//! comments.
 
 use thiserror::Error;
 
@@ -16,6 +17,7 @@ pub struct ReplyDraftState {
     origin_label: Option<String>,
     max_length: ReplyDraftMaxLength,
     ready_to_send: bool,
+    edits_comment: bool,
 }
 
 impl ReplyDraftState {
@@ -28,15 +30,41 @@ impl ReplyDraftState {
             origin_label: None,
             max_length,
             ready_to_send: false,
+            edits_comment: false,
         }
     }
 
+    /// Creates a draft that edits the given comment, pre-filled with its
+    /// current `body`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReplyDraftError::LengthExceeded`] when `body` exceeds the
+    /// configured maximum length.
+    pub fn for_edit(
+        comment_id: u64,
+        body: &str,
+        max_length: ReplyDraftMaxLength,
+    ) -> Result<Self, ReplyDraftError> {
+        let mut draft = Self::new(comment_id, max_length);
+        draft.append_text(body)?;
+        draft.edits_comment = true;
+        Ok(draft)
+    }
+
     /// Returns the selected comment ID associated with this draft.
     #[must_use]
     pub const fn comment_id(&self) -> u64 {
         self.comment_id
     }
 
+    /// Returns whether sending the draft replaces the comment's body rather
+    /// than posting a reply.
+    #[must_use]
+    pub const fn edits_comment(&self) -> bool {
+        self.edits_comment
+    }
+
     /// Returns the current draft text.
     #[must_use]
     pub const fn text(&self) -> &str {
@@ -210,6 +238,30 @@ mod tests {
         assert_eq!(draft.max_length().as_usize(), 60);
         assert_eq!(draft.char_count(), 0);
         assert!(!draft.is_ready_to_send());
+        assert!(!draft.edits_comment());
+    }
+
+    #[test]
+    fn edit_draft_starts_with_comment_body() {
+        let draft = ReplyDraftState::for_edit(42, "Fixed typo", ReplyDraftMaxLength::new(60))
+            .expect("body should fit");
+
+        assert_eq!(draft.text(), "Fixed typo");
+        assert!(draft.edits_comment());
+        assert!(!draft.is_ready_to_send());
+    }
+
+    #[test]
+    fn edit_draft_rejects_body_over_max_length() {
+        let result = ReplyDraftState::for_edit(42, "hello world", ReplyDraftMaxLength::new(10));
+
+        assert_eq!(
+            result,
+            Err(ReplyDraftError::LengthExceeded {
+                attempted: 11,
+                max_length: 10,
+            })
+        );
     }
 
     #[test]
@@ -0,0 +1,282 @@
+//! Pending review composer state for the review TUI.
+//!
+//! The composer collects inline comments queued from reply drafts and the
+//! diff context view together with a summary body and review event, and
+//! gates submission behind an explicit confirmation step.
+
+use crate::github::error::IntakeError;
+use crate::github::review_submission::{PendingReviewComment, ReviewEvent, ReviewSubmission};
+
+/// Inline comment queued on the pending review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedReviewComment {
+    /// The comment to submit.
+    pub comment: PendingReviewComment,
+    /// Local database row holding the comment, when it was persisted.
+    pub draft_id: Option<i64>,
+}
+
+/// Locally composed pull request review awaiting submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewComposerState {
+    pending: Vec<QueuedReviewComment>,
+    commit_id: Option<String>,
+    body: String,
+    event: ReviewEvent,
+    confirming: bool,
+    submitting: bool,
+}
+
+impl ReviewComposerState {
+    /// Returns the queued inline comments in the order they were added.
+    #[must_use]
+    pub fn pending_comments(&self) -> &[QueuedReviewComment] {
+        &self.pending
+    }
+
+    /// Returns the commit anchored comments were written against, if any.
+    #[must_use]
+    pub fn commit_id(&self) -> Option<&str> {
+        self.commit_id.as_deref()
+    }
+
+    /// Returns the local database rows backing the queued comments.
+    #[must_use]
+    pub fn saved_draft_ids(&self) -> Vec<i64> {
+        self.pending
+            .iter()
+            .filter_map(|queued| queued.draft_id)
+            .collect()
+    }
+
+    /// Returns the review summary body.
+    #[must_use]
+    pub const fn body(&self) -> &str {
+        self.body.as_str()
+    }
+
+    /// Returns the selected review event.
+    #[must_use]
+    pub const fn event(&self) -> ReviewEvent {
+        self.event
+    }
+
+    /// Returns whether the composer is waiting for submit confirmation.
+    #[must_use]
+    pub const fn is_confirming(&self) -> bool {
+        self.confirming
+    }
+
+    /// Returns whether the review is currently being submitted to GitHub.
+    #[must_use]
+    pub const fn is_submitting(&self) -> bool {
+        self.submitting
+    }
+
+    /// Records whether a submission request is in flight.
+    ///
+    /// Starting a submission also leaves the confirmation step.
+    pub const fn set_submitting(&mut self, submitting: bool) {
+        self.submitting = submitting;
+        self.confirming = false;
+    }
+
+    /// Queues an inline comment for the next submission.
+    pub fn queue_comment(&mut self, comment: PendingReviewComment) {
+        self.pending.push(QueuedReviewComment {
+            comment,
+            draft_id: None,
+        });
+        self.confirming = false;
+    }
+
+    /// Checks that a comment anchored at `commit_sha` can join this review.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the review already holds
+    /// comments anchored to a different commit.
+    pub fn check_commit(&self, commit_sha: &str) -> Result<(), IntakeError> {
+        match self.commit_id.as_deref() {
+            Some(existing) if existing != commit_sha => Err(IntakeError::Configuration {
+                message: format!(
+                    "pending review already targets commit {existing}; submit it before commenting on another commit"
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Queues an inline comment anchored to a diff line at `commit_sha`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the review already holds
+    /// comments anchored to a different commit.
+    pub fn queue_anchored_comment(
+        &mut self,
+        commit_sha: &str,
+        comment: PendingReviewComment,
+        draft_id: Option<i64>,
+    ) -> Result<(), IntakeError> {
+        self.check_commit(commit_sha)?;
+        self.commit_id = Some(commit_sha.to_owned());
+        self.pending.push(QueuedReviewComment { comment, draft_id });
+        self.confirming = false;
+        Ok(())
+    }
+
+    /// Removes the most recently queued inline comment, if any.
+    ///
+    /// The review's commit is forgotten once no comments remain.
+    pub fn drop_last_comment(&mut self) -> Option<QueuedReviewComment> {
+        self.confirming = false;
+        let dropped = self.pending.pop();
+        if self.pending.is_empty() {
+            self.commit_id = None;
+        }
+        dropped
+    }
+
+    /// Appends one character to the summary body.
+    pub fn push_char(&mut self, character: char) {
+        self.body.push(character);
+        self.confirming = false;
+    }
+
+    /// Removes the last character from the summary body, if present.
+    pub fn backspace(&mut self) {
+        let _ = self.body.pop();
+        self.confirming = false;
+    }
+
+    /// Advances to the next review event.
+    pub const fn cycle_event(&mut self) {
+        self.event = self.event.next();
+        self.confirming = false;
+    }
+
+    /// Validates the review and enters the confirmation step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntakeError::Configuration`] when the composed review would
+    /// be rejected by [`ReviewSubmission::validate`].
+    pub fn request_confirm(&mut self) -> Result<(), IntakeError> {
+        self.to_submission().validate()?;
+        self.confirming = true;
+        Ok(())
+    }
+
+    /// Leaves the confirmation step without submitting.
+    pub const fn cancel_confirm(&mut self) {
+        self.confirming = false;
+    }
+
+    /// Builds the submission described by the composer.
+    #[must_use]
+    pub fn to_submission(&self) -> ReviewSubmission {
+        let submission = ReviewSubmission::new(self.event, self.body.trim())
+            .with_comments(self.pending.iter().map(|queued| queued.comment.clone()));
+        match self.commit_id.as_deref() {
+            Some(commit_id) => submission.with_commit_id(commit_id),
+            None => submission,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Unit tests for the `review_composer` module.
+
+    use rstest::rstest;
+
+    use super::ReviewComposerState;
+    use crate::github::review_submission::{PendingReviewComment, ReviewEvent};
+
+    #[rstest]
+    fn request_confirm_rejects_empty_comment_review() {
+        let mut composer = ReviewComposerState::default();
+
+        let result = composer.request_confirm();
+
+        assert!(result.is_err());
+        assert!(!composer.is_confirming());
+    }
+
+    #[rstest]
+    fn request_confirm_accepts_queued_comments() {
+        let mut composer = ReviewComposerState::default();
+        composer.queue_comment(PendingReviewComment::new("src/lib.rs", 4, "nit"));
+
+        composer.request_confirm().expect("review should be valid");
+
+        assert!(composer.is_confirming());
+    }
+
+    #[rstest]
+    fn edits_leave_confirmation_step() {
+        let mut composer = ReviewComposerState::default();
+        composer.cycle_event();
+        composer
+            .request_confirm()
+            .expect("approval should be valid");
+
+        composer.push_char('!');
+
+        assert!(!composer.is_confirming());
+        assert_eq!(composer.event(), ReviewEvent::Approve);
+    }
+
+    #[rstest]
+    fn to_submission_trims_body_and_keeps_comment_order() {
+        let mut composer = ReviewComposerState::default();
+        for character in " ok ".chars() {
+            composer.push_char(character);
+        }
+        composer.queue_comment(PendingReviewComment::new("a.rs", 1, "first"));
+        composer.queue_comment(PendingReviewComment::new("b.rs", 2, "second"));
+        composer.drop_last_comment();
+
+        let submission = composer.to_submission();
+
+        assert_eq!(submission.body(), "ok");
+        assert_eq!(submission.comments().len(), 1);
+        assert_eq!(
+            submission.comments().first().map(|c| c.path.as_str()),
+            Some("a.rs")
+        );
+    }
+
+    #[rstest]
+    fn anchored_comments_set_commit_and_reject_other_commits() {
+        let mut composer = ReviewComposerState::default();
+        composer
+            .queue_anchored_comment("abc123", PendingReviewComment::new("a.rs", 1, "x"), Some(4))
+            .expect("first anchored comment should be queued");
+
+        let result = composer.queue_anchored_comment(
+            "def456",
+            PendingReviewComment::new("b.rs", 2, "y"),
+            Some(5),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(composer.pending_comments().len(), 1);
+        assert_eq!(composer.saved_draft_ids(), vec![4]);
+        assert_eq!(composer.to_submission().commit_id(), Some("abc123"));
+    }
+
+    #[rstest]
+    fn dropping_last_comment_forgets_commit() {
+        let mut composer = ReviewComposerState::default();
+        composer
+            .queue_anchored_comment("abc123", PendingReviewComment::new("a.rs", 1, "x"), None)
+            .expect("anchored comment should be queued");
+
+        composer.drop_last_comment();
+
+        assert_eq!(composer.commit_id(), None);
+        assert!(composer.check_commit("def456").is_ok());
+    }
+}
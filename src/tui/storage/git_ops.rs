@@ -0,0 +1,150 @@
+//! Local repository context for time travel, verification, and suggested
+//! changes.
+//!
+//! Set when the CLI finds a local checkout of the pull request's repository;
+//! the time-travel context is always set so failures can explain why the
+//! repository is unavailable.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::local::GitOperations;
+use crate::persistence::ReviewCommentVerificationCache;
+use crate::suggestion::SuggestionApplier;
+
+/// Global storage for Git operations context.
+///
+/// This is set before the TUI program starts when a valid local repository
+/// is discovered or configured. Enables time-travel navigation in the TUI.
+static GIT_OPS_CONTEXT: OnceLock<GitOpsContext> = OnceLock::new();
+
+/// Global storage for the suggested-change applier.
+///
+/// Set alongside the Git operations context when a local repository is
+/// available, so suggested changes can be applied to its working tree.
+static SUGGESTION_APPLIER: OnceLock<Arc<dyn SuggestionApplier>> = OnceLock::new();
+
+/// Global storage for review comment verification cache.
+///
+/// Set before TUI startup when `--database-url` is configured, enabling
+/// annotation of comments with persisted verified/unverified status.
+static REVIEW_COMMENT_VERIFICATION_CACHE: OnceLock<Arc<ReviewCommentVerificationCache>> =
+    OnceLock::new();
+
+/// Global storage for configurable commit history limit.
+///
+/// Set before TUI startup from `FrankieConfig::commit_history_limit`. When
+/// not set, `ReviewApp` falls back to `DEFAULT_COMMIT_HISTORY_LIMIT`.
+static COMMIT_HISTORY_LIMIT: OnceLock<usize> = OnceLock::new();
+
+/// Global storage for time-travel context (PR info and discovery status).
+/// Always set before TUI startup for time-travel error messages.
+static TIME_TRAVEL_CONTEXT: OnceLock<TimeTravelContext> = OnceLock::new();
+
+/// Git operations context for time-travel navigation.
+struct GitOpsContext {
+    git_ops: Arc<dyn GitOperations>,
+    head_sha: String,
+}
+
+/// Context describing the PR and any discovery failure for error messages.
+///
+/// Stored alongside git ops to provide contextual error messages when
+/// time-travel is attempted without a valid local repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeTravelContext {
+    /// PR host (e.g. "github.com" or "ghe.corp.com").
+    pub host: String,
+    /// PR owner (e.g. "octocat").
+    pub owner: String,
+    /// PR repository name (e.g. "hello-world").
+    pub repo: String,
+    /// PR number.
+    pub pr_number: u64,
+    /// Reason discovery failed, if applicable.
+    pub discovery_failure: Option<String>,
+}
+
+/// Sets the Git operations context for time-travel navigation.
+///
+/// This must be called before starting the bubbletea-rs program. When a
+/// valid local repository is available, this enables time-travel features.
+///
+/// # Arguments
+///
+/// * `git_ops` - The Git operations implementation.
+/// * `head_sha` - The HEAD commit SHA for line mapping verification.
+///
+/// # Returns
+///
+/// `true` if the context was set, `false` if it was already set.
+pub fn set_git_ops_context(git_ops: Arc<dyn GitOperations>, head_sha: String) -> bool {
+    GIT_OPS_CONTEXT
+        .set(GitOpsContext { git_ops, head_sha })
+        .is_ok()
+}
+
+/// Sets the applier used to apply suggested changes to the local checkout.
+///
+/// Returns `true` if the applier was set, `false` if it was already set.
+pub fn set_suggestion_applier(applier: Arc<dyn SuggestionApplier>) -> bool {
+    SUGGESTION_APPLIER.set(applier).is_ok()
+}
+
+/// Sets the verification cache used to persist verified/unverified status.
+pub fn set_review_comment_verification_cache(cache: Arc<ReviewCommentVerificationCache>) -> bool {
+    REVIEW_COMMENT_VERIFICATION_CACHE.set(cache).is_ok()
+}
+
+/// Sets the commit history limit for the TUI application.
+///
+/// Returns `true` if the limit was set, `false` if it was already set.
+pub fn set_commit_history_limit(limit: usize) -> bool {
+    COMMIT_HISTORY_LIMIT.set(limit).is_ok()
+}
+
+/// Sets the time-travel context (PR info and discovery status).
+///
+/// This must be called before starting the bubbletea-rs program. It stores
+/// PR metadata used to generate contextual error messages when time-travel
+/// is unavailable.
+///
+/// # Returns
+///
+/// `true` if the context was set, `false` if it was already set.
+pub fn set_time_travel_context(context: TimeTravelContext) -> bool {
+    TIME_TRAVEL_CONTEXT.set(context).is_ok()
+}
+
+/// Gets the Git operations context, if configured.
+///
+/// Called internally by `ReviewApp::init()`. Returns the stored git ops
+/// and HEAD SHA, or `None` if no local repository was configured.
+pub(crate) fn get_git_ops_context() -> Option<(Arc<dyn GitOperations>, String)> {
+    GIT_OPS_CONTEXT
+        .get()
+        .map(|ctx| (Arc::clone(&ctx.git_ops), ctx.head_sha.clone()))
+}
+
+/// Gets the configured suggested-change applier, if any.
+pub(crate) fn get_suggestion_applier() -> Option<Arc<dyn SuggestionApplier>> {
+    SUGGESTION_APPLIER.get().cloned()
+}
+
+/// Gets the configured verification cache, if any.
+pub(crate) fn get_review_comment_verification_cache() -> Option<Arc<ReviewCommentVerificationCache>>
+{
+    REVIEW_COMMENT_VERIFICATION_CACHE.get().cloned()
+}
+
+/// Gets the time-travel context, if configured.
+///
+/// Called internally by the time-travel error handler to generate
+/// contextual error messages.
+pub(crate) fn get_time_travel_context() -> Option<TimeTravelContext> {
+    TIME_TRAVEL_CONTEXT.get().cloned()
+}
+
+/// Gets the configured commit history limit, if set.
+pub(crate) fn get_commit_history_limit() -> Option<usize> {
+    COMMIT_HISTORY_LIMIT.get().copied()
+}
@@ -0,0 +1,167 @@
+//! Pull request data loaded before the review TUI starts.
+//!
+//! The CLI stores the review comments, reviews, conversation comments, CI
+//! results, and header details it fetched, and `ReviewApp::init()` reads
+//! them to populate the first frame.
+
+use std::sync::OnceLock;
+
+use crate::github::CiStatus;
+use crate::github::models::{
+    PullRequestComment, PullRequestMetadata, PullRequestReview, ReviewComment,
+};
+
+/// Global storage for initial review data.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::init()`.
+static INITIAL_REVIEWS: OnceLock<Vec<ReviewComment>> = OnceLock::new();
+
+/// Global storage for the top-level pull request reviews.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::init()`
+/// to populate the pull request reviews view.
+static INITIAL_PULL_REQUEST_REVIEWS: OnceLock<Vec<PullRequestReview>> = OnceLock::new();
+
+/// Global storage for the pull request's issue comments.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::init()`
+/// to populate the conversation timeline.
+static INITIAL_ISSUE_COMMENTS: OnceLock<Vec<PullRequestComment>> = OnceLock::new();
+
+/// Global storage for CI results on the pull request head.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::init()`
+/// to populate the header badge and CI checks view.
+static INITIAL_CI_STATUS: OnceLock<CiStatus> = OnceLock::new();
+
+/// Global storage for a startup failure shown in the status bar.
+///
+/// This is set before the TUI program starts when data that the TUI can do
+/// without failed to load, and read by `ReviewApp::init()`.
+static INITIAL_ERROR: OnceLock<String> = OnceLock::new();
+
+/// Global storage for the pull request details shown in the header.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::init()`.
+static INITIAL_PULL_REQUEST_METADATA: OnceLock<PullRequestMetadata> = OnceLock::new();
+
+/// Sets the initial reviews for the TUI application.
+///
+/// This must be called before starting the bubbletea-rs program. The reviews
+/// will be read by `ReviewApp::init()` when the program starts.
+///
+/// # Arguments
+///
+/// * `reviews` - The review comments to display initially.
+///
+/// # Returns
+///
+/// `true` if the reviews were set, `false` if they were already set.
+pub fn set_initial_reviews(reviews: Vec<ReviewComment>) -> bool {
+    INITIAL_REVIEWS.set(reviews).is_ok()
+}
+
+/// Sets the top-level pull request reviews for the TUI application.
+///
+/// This must be called before starting the bubbletea-rs program.
+///
+/// # Returns
+///
+/// `true` if the reviews were set, `false` if they were already set.
+pub fn set_initial_pull_request_reviews(reviews: Vec<PullRequestReview>) -> bool {
+    INITIAL_PULL_REQUEST_REVIEWS.set(reviews).is_ok()
+}
+
+/// Sets the pull request's issue comments for the TUI application.
+///
+/// This must be called before starting the bubbletea-rs program.
+///
+/// # Returns
+///
+/// `true` if the comments were set, `false` if they were already set.
+pub fn set_initial_issue_comments(comments: Vec<PullRequestComment>) -> bool {
+    INITIAL_ISSUE_COMMENTS.set(comments).is_ok()
+}
+
+/// Sets the CI results for the pull request head.
+///
+/// This must be called before starting the bubbletea-rs program.
+///
+/// # Returns
+///
+/// `true` if the status was set, `false` if it was already set.
+pub fn set_initial_ci_status(ci_status: CiStatus) -> bool {
+    INITIAL_CI_STATUS.set(ci_status).is_ok()
+}
+
+/// Sets a startup failure to show in the status bar.
+///
+/// This must be called before starting the bubbletea-rs program.
+///
+/// # Returns
+///
+/// `true` if the error was set, `false` if one was already set.
+pub fn set_initial_error(message: String) -> bool {
+    INITIAL_ERROR.set(message).is_ok()
+}
+
+/// Sets the pull request details shown in the header.
+///
+/// This must be called before starting the bubbletea-rs program.
+///
+/// # Returns
+///
+/// `true` if the details were set, `false` if they were already set.
+pub fn set_initial_pull_request_metadata(metadata: PullRequestMetadata) -> bool {
+    INITIAL_PULL_REQUEST_METADATA.set(metadata).is_ok()
+}
+
+/// Gets a clone of the initial reviews from storage.
+///
+/// Called internally by `ReviewApp::init()`. Returns the stored reviews or
+/// an empty vector if not set.
+///
+/// Note: This function clones the data because `OnceLock` does not support
+/// consuming (taking) the value. The name reflects that this is a read
+/// operation, not a destructive take.
+pub(crate) fn get_initial_reviews() -> Vec<ReviewComment> {
+    INITIAL_REVIEWS.get().cloned().unwrap_or_default()
+}
+
+/// Gets a clone of the top-level pull request reviews from storage.
+///
+/// Returns an empty vector if none were set.
+pub(crate) fn get_initial_pull_request_reviews() -> Vec<PullRequestReview> {
+    INITIAL_PULL_REQUEST_REVIEWS
+        .get()
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Gets a clone of the pull request's issue comments from storage.
+///
+/// Returns an empty vector if none were set.
+pub(crate) fn get_initial_issue_comments() -> Vec<PullRequestComment> {
+    INITIAL_ISSUE_COMMENTS.get().cloned().unwrap_or_default()
+}
+
+/// Gets a clone of the CI results for the pull request head.
+///
+/// Returns `None` if CI status was not loaded.
+pub(crate) fn get_initial_ci_status() -> Option<CiStatus> {
+    INITIAL_CI_STATUS.get().cloned()
+}
+
+/// Gets a clone of the startup failure to show in the status bar.
+///
+/// Returns `None` if startup loaded everything.
+pub(crate) fn get_initial_error() -> Option<String> {
+    INITIAL_ERROR.get().cloned()
+}
+
+/// Gets a clone of the pull request details shown in the header.
+///
+/// Returns `None` if the details were not loaded.
+pub(crate) fn get_initial_pull_request_metadata() -> Option<PullRequestMetadata> {
+    INITIAL_PULL_REQUEST_METADATA.get().cloned()
+}
@@ -0,0 +1,257 @@
+//! Startup context storage and refresh helpers for the review TUI.
+//!
+//! This module owns the global `OnceLock` values used during TUI bootstrapping
+//! and provides the setter/getter functions consumed by CLI wiring and app
+//! handlers. Each feature's context lives in its own submodule: pull request
+//! data loaded at startup in `metadata`, the refresh context and GitHub reads
+//! in `refresh`, GitHub writes in `writes`, queued writes in `outbox`, and the
+//! local repository in `git_ops`.
+
+mod git_ops;
+mod metadata;
+mod outbox;
+mod refresh;
+mod writes;
+
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crossterm::terminal;
+
+use crate::ai::{
+    CommentRewriteService, OpenAiCommentRewriteService, OpenAiPrDiscussionSummaryService,
+    PrDiscussionSummaryService,
+};
+use crate::telemetry::{NoopTelemetrySink, TelemetryEvent, TelemetrySink};
+use crate::tui::picker::PullRequestPicker;
+use crate::tui::review_stream::ReviewCommentStream;
+
+pub use git_ops::{
+    TimeTravelContext, set_commit_history_limit, set_git_ops_context,
+    set_review_comment_verification_cache, set_suggestion_applier, set_time_travel_context,
+};
+pub(crate) use git_ops::{
+    get_commit_history_limit, get_git_ops_context, get_review_comment_verification_cache,
+    get_suggestion_applier, get_time_travel_context,
+};
+pub(crate) use metadata::{
+    get_initial_ci_status, get_initial_error, get_initial_issue_comments,
+    get_initial_pull_request_metadata, get_initial_pull_request_reviews, get_initial_reviews,
+};
+pub use metadata::{
+    set_initial_ci_status, set_initial_error, set_initial_issue_comments,
+    set_initial_pull_request_metadata, set_initial_pull_request_reviews, set_initial_reviews,
+};
+pub(crate) use outbox::{get_pending_review_comment_store, get_write_outbox, replay_queued_writes};
+pub use outbox::{set_pending_review_comment_store, set_write_outbox};
+#[cfg(feature = "test-support")]
+pub use refresh::get_refresh_context_for_tests;
+pub(crate) use refresh::{
+    fetch_ci_status, fetch_pull_request_conversation, get_authenticated_login, get_refresh_locator,
+    get_refresh_pr_title, is_offline, sync_reviews,
+};
+pub use refresh::{
+    set_authenticated_login, set_comment_page_cache, set_offline_context, set_refresh_context,
+    set_review_comment_archive, set_sync_checkpoint_store,
+};
+pub(crate) use writes::{
+    delete_review_comment, edit_review_comment, post_review_reply, set_review_thread_resolution,
+    submit_pull_request_review, toggle_review_comment_reaction,
+};
+
+/// Global storage for initial terminal dimensions.
+///
+/// This is set before the TUI program starts and read by `ReviewApp::new()`
+/// so the first frame uses the actual terminal size.
+static INITIAL_TERMINAL_SIZE: OnceLock<(u16, u16)> = OnceLock::new();
+
+/// Global storage for telemetry sink.
+///
+/// This is set before the TUI program starts to enable sync latency metrics.
+static TELEMETRY_SINK: OnceLock<Arc<dyn TelemetrySink>> = OnceLock::new();
+
+/// Static fallback telemetry sink to avoid allocations on each call.
+///
+/// This is used by `get_telemetry_sink` when no sink has been configured,
+/// avoiding repeated `Arc::new` allocations.
+static DEFAULT_TELEMETRY_SINK: OnceLock<Arc<dyn TelemetrySink>> = OnceLock::new();
+
+/// Global storage for AI rewrite service used by reply-draft workflows.
+static COMMENT_REWRITE_SERVICE: OnceLock<Arc<dyn CommentRewriteService>> = OnceLock::new();
+
+/// Static fallback rewrite service for deployments without explicit AI config.
+static DEFAULT_COMMENT_REWRITE_SERVICE: OnceLock<Arc<dyn CommentRewriteService>> = OnceLock::new();
+
+/// Global storage for PR-discussion summary service used by the summary view.
+static PR_DISCUSSION_SUMMARY_SERVICE: OnceLock<Arc<dyn PrDiscussionSummaryService>> =
+    OnceLock::new();
+
+/// Static fallback summary service for deployments without explicit AI config.
+static DEFAULT_PR_DISCUSSION_SUMMARY_SERVICE: OnceLock<Arc<dyn PrDiscussionSummaryService>> =
+    OnceLock::new();
+
+/// Global storage for the pull request picker.
+///
+/// Set before the picker program starts and read by
+/// `PullRequestPicker::init()`.
+static PULL_REQUEST_PICKER: OnceLock<PullRequestPicker> = OnceLock::new();
+
+/// Global storage for the review comment listing still in progress.
+///
+/// Set when startup shows the first page before the listing finishes, and
+/// taken by `ReviewApp::init()` to merge the remaining pages.
+static REVIEW_COMMENT_STREAM: Mutex<Option<ReviewCommentStream>> = Mutex::new(None);
+#[cfg(test)]
+static STORAGE_TEST_GUARD: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(test)]
+pub fn storage_test_guard() -> &'static std::sync::Mutex<()> {
+    &STORAGE_TEST_GUARD
+}
+
+/// Sets the initial terminal dimensions for the TUI application.
+///
+/// This should be called before starting the bubbletea-rs program so the
+/// initial render can use the actual terminal size instead of fallbacks.
+///
+/// # Arguments
+///
+/// * `width` - Terminal width in columns.
+/// * `height` - Terminal height in rows.
+///
+/// # Returns
+///
+/// `true` if the dimensions were set, `false` if they were already set.
+pub fn set_initial_terminal_size(width: u16, height: u16) -> bool {
+    INITIAL_TERMINAL_SIZE.set((width, height)).is_ok()
+}
+
+/// Sets the telemetry sink for the TUI application.
+///
+/// This must be called before starting the bubbletea-rs program to enable
+/// sync latency metrics. Without this, a no-op sink is used.
+///
+/// # Arguments
+///
+/// * `sink` - The telemetry sink to use for recording events.
+///
+/// # Returns
+///
+/// `true` if the sink was set, `false` if it was already set.
+pub fn set_telemetry_sink(sink: Arc<dyn TelemetrySink>) -> bool {
+    TELEMETRY_SINK.set(sink).is_ok()
+}
+
+/// Sets the AI rewrite service for the TUI application.
+///
+/// Returns `true` if the service was set, or `false` when one already exists.
+pub fn set_comment_rewrite_service(service: Arc<dyn CommentRewriteService>) -> bool {
+    COMMENT_REWRITE_SERVICE.set(service).is_ok()
+}
+
+/// Sets the PR-discussion summary service for the TUI application.
+pub fn set_pr_discussion_summary_service(service: Arc<dyn PrDiscussionSummaryService>) -> bool {
+    PR_DISCUSSION_SUMMARY_SERVICE.set(service).is_ok()
+}
+
+/// Sets the pull request picker shown by the picker program.
+///
+/// Returns `true` if the picker was set, `false` if it was already set.
+pub fn set_pull_request_picker(picker: PullRequestPicker) -> bool {
+    PULL_REQUEST_PICKER.set(picker).is_ok()
+}
+
+/// Sets the review comment listing whose remaining pages the TUI merges
+/// as they arrive.
+///
+/// Returns `true` if the stream was set, `false` if one is already waiting.
+pub fn set_review_comment_stream(stream: ReviewCommentStream) -> bool {
+    let Ok(mut slot) = REVIEW_COMMENT_STREAM.lock() else {
+        return false;
+    };
+    if slot.is_some() {
+        return false;
+    }
+    *slot = Some(stream);
+    true
+}
+
+/// Gets the telemetry sink, returning a no-op sink if not configured.
+///
+/// Uses a static fallback sink to avoid allocating a new `Arc` on each call
+/// when no sink has been configured.
+fn get_telemetry_sink() -> Arc<dyn TelemetrySink> {
+    TELEMETRY_SINK.get().cloned().unwrap_or_else(|| {
+        Arc::clone(DEFAULT_TELEMETRY_SINK.get_or_init(|| Arc::new(NoopTelemetrySink)))
+    })
+}
+
+/// Gets the configured rewrite service or a fallback implementation.
+pub(crate) fn get_comment_rewrite_service() -> Arc<dyn CommentRewriteService> {
+    COMMENT_REWRITE_SERVICE.get().cloned().unwrap_or_else(|| {
+        Arc::clone(
+            DEFAULT_COMMENT_REWRITE_SERVICE
+                .get_or_init(|| Arc::new(OpenAiCommentRewriteService::default())),
+        )
+    })
+}
+
+/// Gets the configured PR-discussion summary service or a fallback implementation.
+pub(crate) fn get_pr_discussion_summary_service() -> Arc<dyn PrDiscussionSummaryService> {
+    PR_DISCUSSION_SUMMARY_SERVICE
+        .get()
+        .cloned()
+        .unwrap_or_else(|| {
+            Arc::clone(
+                DEFAULT_PR_DISCUSSION_SUMMARY_SERVICE
+                    .get_or_init(|| Arc::new(OpenAiPrDiscussionSummaryService::default())),
+            )
+        })
+}
+
+/// Records sync telemetry for a completed sync operation.
+///
+/// Called internally by the app after a successful sync.
+pub(crate) fn record_sync_telemetry(latency_ms: u64, comment_count: usize, incremental: bool) {
+    get_telemetry_sink().record(TelemetryEvent::SyncLatencyRecorded {
+        latency_ms,
+        comment_count,
+        incremental,
+    });
+}
+
+/// Gets a clone of the pull request picker.
+///
+/// Returns `None` if no picker was set.
+pub(crate) fn get_pull_request_picker() -> Option<PullRequestPicker> {
+    PULL_REQUEST_PICKER.get().cloned()
+}
+
+/// Takes the review comment listing still in progress, if any.
+///
+/// Called internally by `ReviewApp::init()`; later calls return `None`.
+pub(crate) fn take_review_comment_stream() -> Option<ReviewCommentStream> {
+    REVIEW_COMMENT_STREAM
+        .lock()
+        .ok()
+        .and_then(|mut slot| slot.take())
+}
+
+/// Gets the initial terminal dimensions from storage.
+///
+/// Called internally by `ReviewApp::new()`. Returns the stored dimensions or
+/// fallback dimensions if none were set.
+pub(crate) fn get_initial_terminal_size() -> (u16, u16) {
+    const DEFAULT_WIDTH: u16 = 80;
+    const DEFAULT_HEIGHT: u16 = 24;
+
+    INITIAL_TERMINAL_SIZE
+        .get()
+        .copied()
+        .filter(|(width, height)| *width > 0 && *height > 0)
+        .or_else(|| {
+            terminal::size()
+                .ok()
+                .filter(|(width, height)| *width > 0 && *height > 0)
+        })
+        .unwrap_or((DEFAULT_WIDTH, DEFAULT_HEIGHT))
+}
@@ -0,0 +1,66 @@
+//! Stores for review writes that have not reached GitHub yet.
+//!
+//! The write outbox queues replies, resolutions, and reactions GitHub could
+//! not accept, and the pending comment store keeps inline comment drafts
+//! until a review submits them.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::github::error::IntakeError;
+use crate::github::outbox::{OutboxReplayReport, OutboxReplayService};
+use crate::persistence::{PendingReviewCommentStore, QueuedWrite, WriteOutbox};
+
+use super::refresh::{online_context, review_comment_gateway};
+
+/// Global storage for the pending review comment draft store.
+///
+/// Set before TUI startup when `--database-url` is configured, so comments
+/// started from the diff context view survive restarts until submitted.
+static PENDING_REVIEW_COMMENT_STORE: OnceLock<Arc<PendingReviewCommentStore>> = OnceLock::new();
+
+/// Global storage for the write outbox.
+///
+/// Set before TUI startup when `--database-url` is configured, so replies
+/// and thread resolutions that cannot reach GitHub are queued and replayed
+/// later instead of being lost.
+static WRITE_OUTBOX: OnceLock<Arc<WriteOutbox>> = OnceLock::new();
+
+/// Sets the store used to persist pending inline comment drafts.
+pub fn set_pending_review_comment_store(store: Arc<PendingReviewCommentStore>) -> bool {
+    PENDING_REVIEW_COMMENT_STORE.set(store).is_ok()
+}
+
+/// Sets the outbox that queues review writes GitHub could not accept.
+pub fn set_write_outbox(outbox: Arc<WriteOutbox>) -> bool {
+    WRITE_OUTBOX.set(outbox).is_ok()
+}
+
+/// Gets the configured pending comment draft store, if any.
+pub(crate) fn get_pending_review_comment_store() -> Option<Arc<PendingReviewCommentStore>> {
+    PENDING_REVIEW_COMMENT_STORE.get().cloned()
+}
+
+/// Gets the configured write outbox, if any.
+pub(crate) fn get_write_outbox() -> Option<Arc<WriteOutbox>> {
+    WRITE_OUTBOX.get().cloned()
+}
+
+/// Replays the given queued writes against GitHub.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context) and the outbox
+/// set by [`set_write_outbox`]. Returns an error if either was not set or
+/// the TUI is offline; failures of individual writes are reported in the
+/// returned [`OutboxReplayReport`].
+pub(crate) async fn replay_queued_writes(
+    writes: Vec<QueuedWrite>,
+) -> Result<OutboxReplayReport, IntakeError> {
+    let outbox = WRITE_OUTBOX
+        .get()
+        .ok_or_else(|| IntakeError::Configuration {
+            message: "write outbox not configured".to_owned(),
+        })?;
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    let service = OutboxReplayService::new(&gateway, WriteOutbox::clone(outbox));
+    Ok(service.replay_writes(locator, &writes).await)
+}
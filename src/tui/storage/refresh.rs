@@ -0,0 +1,269 @@
+//! Refresh context and GitHub reads for the review TUI.
+//!
+//! Holds the pull request locator and credentials, or their absence in
+//! offline mode, together with the caches that make background syncs cheap,
+//! and performs the syncs and fetches that refresh the TUI.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::github::CiStatus;
+use crate::github::credentials::GitHubCredentials;
+use crate::github::error::IntakeError;
+use crate::github::gateway::{
+    CiStatusGateway, OctocrabCiStatusGateway, OctocrabGateway, OctocrabReviewCommentGateway,
+    PullRequestGateway,
+};
+use crate::github::locator::PullRequestLocator;
+use crate::github::models::{PullRequestComment, PullRequestReview, ReviewComment};
+use crate::github::review_sync::{ReviewSyncDelta, ReviewSyncService};
+use crate::persistence::{CommentPageCache, ReviewCommentArchive, SyncCheckpointStore};
+
+/// Global storage for refresh context (locator and credentials).
+///
+/// This is set before the TUI program starts to enable refresh functionality.
+/// In offline mode it holds the locator without credentials.
+static REFRESH_CONTEXT: OnceLock<RefreshContext> = OnceLock::new();
+
+/// Global storage for the authenticated user's login.
+///
+/// Set before the TUI program starts when GitHub reports the login, so the
+/// user's own comments can be offered for editing and deletion.
+static AUTHENTICATED_LOGIN: OnceLock<String> = OnceLock::new();
+
+/// Global storage for the review comment page cache.
+///
+/// Set before TUI startup when `--database-url` is configured, so background
+/// refreshes revalidate cached pages with their `ETag`s instead of
+/// downloading every review comment page again.
+static COMMENT_PAGE_CACHE: OnceLock<Arc<CommentPageCache>> = OnceLock::new();
+
+/// Global storage for the review sync checkpoint store.
+///
+/// Set before TUI startup when `--database-url` is configured, so the latest
+/// synced `updated_at` is recorded per pull request.
+static SYNC_CHECKPOINT_STORE: OnceLock<Arc<SyncCheckpointStore>> = OnceLock::new();
+
+/// Global storage for the review comment archive.
+///
+/// Set before TUI startup when `--database-url` is configured, so comments
+/// fetched by background syncs and posted replies are stored for offline
+/// reading.
+static REVIEW_COMMENT_ARCHIVE: OnceLock<Arc<ReviewCommentArchive>> = OnceLock::new();
+
+/// Context required to refresh review data from GitHub.
+///
+/// `credentials` is `None` in offline mode, where every GitHub request is
+/// refused.
+struct RefreshContext {
+    locator: PullRequestLocator,
+    credentials: Option<GitHubCredentials>,
+    pr_title: Option<String>,
+}
+
+/// Sets the refresh context for the TUI application.
+///
+/// This must be called before starting the bubbletea-rs program to enable
+/// the refresh feature. Without this context, refresh requests will fail
+/// with an error message.
+///
+/// # Arguments
+///
+/// * `locator` - The pull request locator for API calls.
+/// * `credentials` - The personal access token or GitHub App installation
+///   used for authentication.
+/// * `pr_title` - Optional pull-request title for summary prompt context.
+///
+/// # Returns
+///
+/// `true` if the context was set, `false` if it was already set.
+pub fn set_refresh_context(
+    locator: PullRequestLocator,
+    credentials: impl Into<GitHubCredentials>,
+    pr_title: Option<String>,
+) -> bool {
+    REFRESH_CONTEXT
+        .set(RefreshContext {
+            locator,
+            credentials: Some(credentials.into()),
+            pr_title,
+        })
+        .is_ok()
+}
+
+/// Sets an offline context for the TUI application.
+///
+/// Use this instead of [`set_refresh_context`] when comments were loaded
+/// from the local database. Locator-dependent features keep working, while
+/// refresh, replies, thread resolution, and review submission report that
+/// Frankie is offline.
+///
+/// # Returns
+///
+/// `true` if the context was set, `false` if a context was already set.
+pub fn set_offline_context(locator: PullRequestLocator, pr_title: Option<String>) -> bool {
+    REFRESH_CONTEXT
+        .set(RefreshContext {
+            locator,
+            credentials: None,
+            pr_title,
+        })
+        .is_ok()
+}
+
+/// Sets the authenticated user's login.
+///
+/// Returns `true` if the login was set, `false` if it was already set.
+pub fn set_authenticated_login(login: String) -> bool {
+    AUTHENTICATED_LOGIN.set(login).is_ok()
+}
+
+/// Sets the cache used for conditional review comment refreshes.
+pub fn set_comment_page_cache(cache: Arc<CommentPageCache>) -> bool {
+    COMMENT_PAGE_CACHE.set(cache).is_ok()
+}
+
+/// Sets the store used to persist review sync checkpoints.
+pub fn set_sync_checkpoint_store(store: Arc<SyncCheckpointStore>) -> bool {
+    SYNC_CHECKPOINT_STORE.set(store).is_ok()
+}
+
+/// Sets the archive that stores fetched and posted review comments.
+pub fn set_review_comment_archive(archive: Arc<ReviewCommentArchive>) -> bool {
+    REVIEW_COMMENT_ARCHIVE.set(archive).is_ok()
+}
+
+/// Gets the authenticated user's login, if known.
+pub(crate) fn get_authenticated_login() -> Option<String> {
+    AUTHENTICATED_LOGIN.get().cloned()
+}
+
+/// Returns the configured pull request locator for refresh-dependent features.
+#[must_use]
+pub(crate) fn get_refresh_locator() -> Option<PullRequestLocator> {
+    REFRESH_CONTEXT.get().map(|context| context.locator.clone())
+}
+
+/// Returns whether the TUI was started offline from the local database.
+#[must_use]
+pub(crate) fn is_offline() -> bool {
+    REFRESH_CONTEXT
+        .get()
+        .is_some_and(|context| context.credentials.is_none())
+}
+
+/// Returns the configured pull-request title for summary-dependent features.
+#[must_use]
+pub(crate) fn get_refresh_pr_title() -> Option<String> {
+    REFRESH_CONTEXT
+        .get()
+        .and_then(|context| context.pr_title.clone())
+}
+
+/// Returns the configured refresh locator and token for test assertions.
+///
+/// Contexts authenticated as a GitHub App installation have no fixed token
+/// and return `None`.
+#[cfg(feature = "test-support")]
+#[must_use]
+pub fn get_refresh_context_for_tests() -> Option<(PullRequestLocator, String)> {
+    REFRESH_CONTEXT
+        .get()
+        .and_then(|context| match context.credentials.as_ref() {
+            Some(GitHubCredentials::PersonalAccessToken(token)) => {
+                Some((context.locator.clone(), token.value().to_owned()))
+            }
+            Some(GitHubCredentials::AppInstallation(_)) | None => None,
+        })
+}
+
+/// Returns the locator and credentials for GitHub requests.
+///
+/// Fails when no context was set, or when the TUI runs offline.
+pub(super) fn online_context()
+-> Result<(&'static PullRequestLocator, &'static GitHubCredentials), IntakeError> {
+    let context = REFRESH_CONTEXT.get().ok_or_else(|| IntakeError::Api {
+        message: "Refresh context not configured".to_owned(),
+    })?;
+    let credentials = context
+        .credentials
+        .as_ref()
+        .ok_or_else(|| IntakeError::Api {
+            message: "Offline mode: GitHub requests are disabled".to_owned(),
+        })?;
+    Ok((&context.locator, credentials))
+}
+
+/// Builds a review comment gateway for the online context.
+///
+/// Attaches the page cache and archive when they were configured.
+pub(super) fn review_comment_gateway(
+    locator: &PullRequestLocator,
+    credentials: &GitHubCredentials,
+) -> Result<OctocrabReviewCommentGateway, IntakeError> {
+    let mut gateway = OctocrabReviewCommentGateway::for_token(credentials, locator)?;
+    if let Some(cache) = COMMENT_PAGE_CACHE.get() {
+        gateway = gateway.with_page_cache(CommentPageCache::clone(cache));
+    }
+    if let Some(archive) = REVIEW_COMMENT_ARCHIVE.get() {
+        gateway = gateway.with_archive(ReviewCommentArchive::clone(archive));
+    }
+    Ok(gateway)
+}
+
+/// Syncs review comments from GitHub against the `known` comments.
+///
+/// Uses the refresh context set by [`set_refresh_context`]. When a page
+/// cache was set with [`set_comment_page_cache`], unchanged pages are served
+/// from it after a conditional request; when a checkpoint store was set
+/// with [`set_sync_checkpoint_store`] the sync checkpoint is persisted; and
+/// when an archive was set with [`set_review_comment_archive`] the fetched
+/// comments are stored. With `full_listing` every comment is listed instead
+/// of only those updated since the checkpoint, which also picks up reaction
+/// changes. Returns an error if the context was not set, the TUI is offline,
+/// or the API call fails.
+pub(crate) async fn sync_reviews(
+    known: &[ReviewComment],
+    full_listing: bool,
+) -> Result<ReviewSyncDelta, IntakeError> {
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    let mut service = ReviewSyncService::new(&gateway);
+    if let Some(store) = SYNC_CHECKPOINT_STORE.get() {
+        service = service.with_checkpoint_store(SyncCheckpointStore::clone(store));
+    }
+    if full_listing {
+        service.sync_all(locator, known).await
+    } else {
+        service.sync(locator, known).await
+    }
+}
+
+/// Fetches the top-level reviews and conversation comments of the pull
+/// request.
+///
+/// Uses the refresh context set by [`set_refresh_context`]. When a page
+/// cache was set with [`set_comment_page_cache`], unchanged pages are served
+/// from it after a conditional request. Returns an error if the context was
+/// not set, the TUI is offline, or either API call fails.
+pub(crate) async fn fetch_pull_request_conversation()
+-> Result<(Vec<PullRequestReview>, Vec<PullRequestComment>), IntakeError> {
+    let (locator, credentials) = online_context()?;
+    let mut gateway = OctocrabGateway::for_token(credentials, locator)?;
+    if let Some(cache) = COMMENT_PAGE_CACHE.get() {
+        gateway = gateway.with_page_cache(CommentPageCache::clone(cache));
+    }
+    tokio::try_join!(
+        gateway.list_reviews(locator),
+        gateway.pull_request_comments(locator)
+    )
+}
+
+/// Fetches check runs and commit statuses for the pull request head.
+///
+/// Uses the refresh context set by [`set_refresh_context`]. Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn fetch_ci_status() -> Result<CiStatus, IntakeError> {
+    let (locator, credentials) = online_context()?;
+    let gateway = OctocrabCiStatusGateway::new(credentials, locator.api_base().as_str())?;
+    gateway.head_ci_status(locator).await
+}
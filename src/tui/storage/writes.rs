@@ -0,0 +1,105 @@
+//! GitHub writes made from the review TUI.
+//!
+//! Replies, edits, deletions, thread resolutions, reactions, and review
+//! submissions all go through the refresh context; each fails when the TUI
+//! runs offline.
+
+use crate::github::error::IntakeError;
+use crate::github::models::{ReactionContent, ReactionToggle, ReviewComment, ReviewThreadState};
+use crate::github::review_submission::{ReviewSubmission, SubmittedReview};
+
+use super::refresh::{online_context, review_comment_gateway};
+
+/// Posts a reply into the review thread containing `comment_id`.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn post_review_reply(
+    comment_id: u64,
+    body: String,
+) -> Result<ReviewComment, IntakeError> {
+    use crate::github::gateway::ReviewCommentGateway;
+
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    gateway
+        .reply_to_review_comment(locator, comment_id, body.as_str())
+        .await
+}
+
+/// Replaces the body of the review comment `comment_id`.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn edit_review_comment(
+    comment_id: u64,
+    body: String,
+) -> Result<ReviewComment, IntakeError> {
+    use crate::github::gateway::ReviewCommentGateway;
+
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    gateway
+        .edit_review_comment(locator, comment_id, body.as_str())
+        .await
+}
+
+/// Deletes the review comment `comment_id`.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn delete_review_comment(comment_id: u64) -> Result<(), IntakeError> {
+    use crate::github::gateway::ReviewCommentGateway;
+
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    gateway.delete_review_comment(locator, comment_id).await
+}
+
+/// Resolves or unresolves the review thread identified by `thread_id`.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn set_review_thread_resolution(
+    thread_id: String,
+    resolved: bool,
+) -> Result<ReviewThreadState, IntakeError> {
+    use crate::github::gateway::ReviewCommentGateway;
+
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    gateway
+        .set_review_thread_resolution(thread_id.as_str(), resolved)
+        .await
+}
+
+/// Adds or removes the authenticated user's reaction on `comment_id`.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn toggle_review_comment_reaction(
+    comment_id: u64,
+    content: ReactionContent,
+) -> Result<ReactionToggle, IntakeError> {
+    use crate::github::gateway::ReviewCommentGateway;
+
+    let (locator, credentials) = online_context()?;
+    let gateway = review_comment_gateway(locator, credentials)?;
+    gateway
+        .toggle_review_comment_reaction(locator, comment_id, content)
+        .await
+}
+
+/// Submits a complete pull request review.
+///
+/// Uses the refresh context set by [`set_refresh_context`](super::set_refresh_context). Returns an error
+/// if the context was not set, the TUI is offline, or the API call fails.
+pub(crate) async fn submit_pull_request_review(
+    submission: ReviewSubmission,
+) -> Result<SubmittedReview, IntakeError> {
+    use crate::github::gateway::{OctocrabPullRequestReviewGateway, PullRequestReviewGateway};
+
+    let (locator, credentials) = online_context()?;
+    let gateway = OctocrabPullRequestReviewGateway::new(credentials, locator.api_base().as_str())?;
+    gateway.submit_review(locator, &submission).await
+}
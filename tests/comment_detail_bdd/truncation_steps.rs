@@ -78,6 +78,7 @@ pub fn when_view_rendered_with_max_height(detail_state: &DetailState, height: us
         reply_draft: None,
         reply_draft_ai_preview: None,
         verification: None,
+        suggestion_report: None,
     };
     let view = component.view(&ctx);
     detail_state.rendered_view.set(view);
@@ -31,12 +31,16 @@ impl From<FixtureReviewComment> for ReviewComment {
             author: value.author,
             file_path: value.file_path,
             line_number: value.line_number,
+            side: None,
             original_line_number: value.original_line_number,
             diff_hunk: value.diff_hunk,
             commit_sha: value.commit_sha,
             in_reply_to_id: value.in_reply_to_id,
             created_at: value.created_at,
             updated_at: value.updated_at,
+            thread_state: None,
+            start_line_number: None,
+            reactions: Vec::new(),
         }
     }
 }
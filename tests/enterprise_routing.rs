@@ -0,0 +1,152 @@
+//! Integration tests for GitHub Enterprise API routing and per-host tokens.
+//!
+//! The mock server stands in for an Enterprise host: every endpoint is derived
+//! from a pull request URL on that host, never from a hand-written API base.
+
+use std::process::Command;
+
+use frankie::{
+    FrankieConfig, OctocrabReviewCommentGateway, PersonalAccessToken, PullRequestLocator,
+    ReviewCommentGateway,
+};
+use rstest::rstest;
+use serde_json::json;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ENTERPRISE_TOKEN: &str = "ghe-token";
+
+/// Returns the `host=token` entry giving the mock host its own token.
+#[expect(
+    clippy::expect_used,
+    reason = "integration test setup; allow-expect-in-tests does not cover integration tests"
+)]
+fn host_token_entry(server: &MockServer) -> String {
+    let uri = url::Url::parse(&server.uri()).expect("mock server URI should parse");
+    let host = uri.host_str().expect("mock server URI should have a host");
+    format!("{host}={ENTERPRISE_TOKEN}")
+}
+
+fn enterprise_auth() -> wiremock::matchers::HeaderExactMatcher {
+    header(
+        "authorization",
+        format!("Bearer {ENTERPRISE_TOKEN}").as_str(),
+    )
+}
+
+async fn mount_review_comments(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v3/repos/corp/app/pulls/7/comments"))
+        .and(enterprise_auth())
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "body": "Please rename", "in_reply_to_id": null }
+        ])))
+        .expect(1)
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(enterprise_auth())
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "pullRequest": { "reviewThreads": {
+                "pageInfo": { "hasNextPage": false, "endCursor": null },
+                "nodes": [{
+                    "id": "PRRT_enterprise",
+                    "isResolved": true,
+                    "isOutdated": false,
+                    "resolvedBy": { "login": "carol" },
+                    "comments": { "nodes": [{ "databaseId": 1 }] }
+                }]
+            } } } }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[rstest]
+fn review_comments_route_rest_and_graphql_to_the_enterprise_host() {
+    let runtime = Runtime::new().expect("runtime should start");
+    let server = runtime.block_on(MockServer::start());
+    runtime.block_on(mount_review_comments(&server));
+    let config = FrankieConfig {
+        token: Some("public-token".to_owned()),
+        host_tokens: vec![host_token_entry(&server)],
+        ..FrankieConfig::default()
+    };
+
+    let locator = PullRequestLocator::parse(&format!("{}/corp/app/pull/7", server.uri()))
+        .expect("enterprise pull request URL should parse");
+    let token = config
+        .resolve_token_for_host(locator.host())
+        .and_then(PersonalAccessToken::new)
+        .expect("host token should resolve");
+    let _guard = runtime.enter();
+    let gateway =
+        OctocrabReviewCommentGateway::for_token(&token, &locator).expect("gateway should build");
+    let comments = runtime
+        .block_on(gateway.list_review_comments(&locator))
+        .expect("comments should load");
+
+    let thread_ids: Vec<_> = comments
+        .iter()
+        .map(|comment| {
+            comment
+                .thread_state
+                .as_ref()
+                .map(|state| state.thread_id.as_str())
+        })
+        .collect();
+    assert_eq!(thread_ids, vec![Some("PRRT_enterprise")]);
+}
+
+/// Returns the path to the built binary.
+fn binary_path() -> std::io::Result<std::path::PathBuf> {
+    let mut path = std::env::current_exe()?;
+    path.pop();
+    path.pop();
+    path.push("frankie");
+    Ok(path)
+}
+
+#[rstest]
+fn reply_mode_uses_the_host_token_for_enterprise_pull_requests() {
+    let runtime = Runtime::new().expect("runtime should start");
+    let server = runtime.block_on(MockServer::start());
+    runtime.block_on(
+        Mock::given(method("POST"))
+            .and(path("/api/v3/repos/corp/app/pulls/7/comments/1/replies"))
+            .and(enterprise_auth())
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": 10,
+                "body": "Renamed",
+                "in_reply_to_id": 1
+            })))
+            .expect(1)
+            .mount(&server),
+    );
+    let output = Command::new(binary_path().expect("binary path should resolve"))
+        .args([
+            "--pr-url",
+            &format!("{}/corp/app/pull/7", server.uri()),
+            "--reply-to",
+            "1",
+            "--reply-text",
+            "Renamed",
+        ])
+        .env("FRANKIE_TOKEN", "public-token")
+        .env(
+            "FRANKIE_HOST_TOKENS",
+            format!("[\"{}\"]", host_token_entry(&server)),
+        )
+        .env_remove("FRANKIE_DATABASE_URL")
+        .env_remove("GITHUB_TOKEN")
+        .output()
+        .expect("binary should run");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert_eq!(stdout, "Posted reply 10 to review comment 1\n");
+}
@@ -56,3 +56,12 @@ Feature: Full-screen diff context
     And the diff context is closed
     And the view is rendered
     Then the second review comment remains selected
+
+  Scenario: Comment on a diff line joins the pending review
+    Given a TUI with review comments that contain diff hunks
+    When the full-screen diff context is opened
+    And a comment is written on the selected diff line
+    And the diff context is closed
+    And the review composer is opened
+    And the view is rendered
+    Then the pending review lists a comment on src/main.rs line 1
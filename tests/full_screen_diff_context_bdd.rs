@@ -52,6 +52,7 @@ fn given_review_comments_with_hunks(state: &DiffContextState) {
         .line_number(10)
         .body("Check main")
         .diff_hunk("@@ -1 +1 @@\n+fn main() {}")
+        .commit_sha("abc123")
         .build();
 
     let second = ReviewCommentBuilder::new(2)
@@ -131,6 +132,30 @@ fn when_filter_key_pressed_in_diff_context(state: &DiffContextState) -> StepResu
     Ok(())
 }
 
+#[when("a comment is written on the selected diff line")]
+fn when_comment_written_on_diff_line(state: &DiffContextState) -> StepResult {
+    state
+        .app
+        .with_mut(|app| {
+            app.handle_message(&AppMsg::StartDiffCommentDraft);
+            for character in "Nit".chars() {
+                app.handle_message(&AppMsg::DiffCommentInsertChar(character));
+            }
+            app.handle_message(&AppMsg::DiffCommentSave);
+        })
+        .ok_or("app should be initialised before writing a comment")?;
+    Ok(())
+}
+
+#[when("the review composer is opened")]
+fn when_review_composer_opened(state: &DiffContextState) -> StepResult {
+    state
+        .app
+        .with_mut(|app| app.handle_message(&AppMsg::ShowReviewComposer))
+        .ok_or("app should be initialised before opening the composer")?;
+    Ok(())
+}
+
 #[given("the second review comment is selected")]
 fn given_second_review_comment_selected(state: &DiffContextState) -> StepResult {
     state
@@ -200,6 +225,21 @@ fn then_second_review_comment_remains_selected(state: &DiffContextState) -> Step
     Ok(())
 }
 
+#[then("the pending review lists a comment on {path} line {line}")]
+fn then_pending_review_lists_comment(
+    state: &DiffContextState,
+    path: String,
+    line: String,
+) -> StepResult {
+    let view = state.view()?;
+    let stripped = strip_ansi_codes(&view);
+    assert!(
+        stripped.contains(&format!("{path}:{line} [RIGHT] Nit")),
+        "expected pending comment on {path}:{line} in view:\n{stripped}"
+    );
+    Ok(())
+}
+
 // Scenario bindings
 
 #[scenario(path = "tests/features/full_screen_diff_context.feature", index = 0)]
@@ -241,3 +281,8 @@ fn diff_context_exit_returns_to_list(state: DiffContextState) {
 fn diff_context_exit_preserves_selection(state: DiffContextState) {
     let _ = state;
 }
+
+#[scenario(path = "tests/features/full_screen_diff_context.feature", index = 8)]
+fn diff_context_comment_joins_pending_review(state: DiffContextState) {
+    let _ = state;
+}
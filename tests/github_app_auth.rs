@@ -0,0 +1,282 @@
+//! Integration tests for GitHub App installation authentication.
+//!
+//! The mock server stands in for GitHub's installation token endpoint: each
+//! test checks the app JWT it receives, hands out installation tokens, and
+//! expects pull request requests to carry them. The key pair under
+//! `tests/fixtures` is a throwaway key generated for these tests.
+
+use std::process::Command;
+
+use chrono::{Duration, Utc};
+use frankie::github::PullRequestGateway;
+use frankie::{GitHubAppInstallation, IntakeError, OctocrabGateway, PullRequestLocator};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use rstest::rstest;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+const APP_ID: u64 = 4242;
+const INSTALLATION_ID: u64 = 99;
+const TOKEN_PATH: &str = "/api/v3/app/installations/99/access_tokens";
+const PULL_REQUEST_PATH: &str = "/api/v3/repos/corp/app/pulls/7";
+const PRIVATE_KEY: &[u8] = include_bytes!("fixtures/github_app_test_key.pem");
+const PUBLIC_KEY: &[u8] = include_bytes!("fixtures/github_app_test_key.pub.pem");
+
+/// Claims GitHub requires in an app JWT.
+#[derive(Deserialize)]
+struct AppClaims {
+    iss: u64,
+}
+
+/// Matches requests signed with an app JWT for [`APP_ID`].
+struct SignedAppJwt;
+
+impl Match for SignedAppJwt {
+    fn matches(&self, request: &Request) -> bool {
+        let Some(jwt) = request
+            .headers
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return false;
+        };
+        let Ok(key) = DecodingKey::from_rsa_pem(PUBLIC_KEY) else {
+            return false;
+        };
+        jsonwebtoken::decode::<AppClaims>(jwt, &key, &Validation::new(Algorithm::RS256))
+            .is_ok_and(|data| data.claims.iss == APP_ID)
+    }
+}
+
+fn installation_token(token: &str, lifetime: Duration) -> ResponseTemplate {
+    ResponseTemplate::new(201).set_body_json(json!({
+        "token": token,
+        "expires_at": (Utc::now() + lifetime).to_rfc3339(),
+        "permissions": { "pull_requests": "write" }
+    }))
+}
+
+fn pull_request_body() -> serde_json::Value {
+    json!({
+        "number": 7,
+        "title": "Add widgets",
+        "state": "open",
+        "html_url": "http://example.invalid",
+        "user": { "login": "octocat" }
+    })
+}
+
+async fn mount_pull_request(server: &MockServer, token: &str, expected_calls: u64) {
+    Mock::given(method("GET"))
+        .and(path(PULL_REQUEST_PATH))
+        .and(header("authorization", format!("Bearer {token}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request_body()))
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
+/// Starts a mock server and returns it with a runtime and pull request
+/// locator on that server.
+#[expect(
+    clippy::expect_used,
+    reason = "integration test setup; allow-expect-in-tests does not cover integration tests"
+)]
+fn start_server() -> (Runtime, MockServer, PullRequestLocator) {
+    let runtime = Runtime::new().expect("runtime should start");
+    let server = runtime.block_on(MockServer::start());
+    let locator = PullRequestLocator::parse(&format!("{}/corp/app/pull/7", server.uri()))
+        .expect("pull request URL should parse");
+    (runtime, server, locator)
+}
+
+#[expect(
+    clippy::expect_used,
+    reason = "integration test setup; allow-expect-in-tests does not cover integration tests"
+)]
+fn installation_for(locator: &PullRequestLocator) -> GitHubAppInstallation {
+    GitHubAppInstallation::new(
+        APP_ID,
+        INSTALLATION_ID,
+        PRIVATE_KEY,
+        locator.api_base().as_str(),
+    )
+    .expect("installation credentials should build")
+}
+
+/// Loads the pull request title through a new gateway for `installation`.
+fn load_title(
+    runtime: &Runtime,
+    installation: &GitHubAppInstallation,
+    locator: &PullRequestLocator,
+) -> Result<Option<String>, IntakeError> {
+    let _guard = runtime.enter();
+    let gateway = OctocrabGateway::for_token(installation, locator)?;
+    runtime
+        .block_on(gateway.pull_request(locator))
+        .map(|metadata| metadata.title)
+}
+
+#[rstest]
+fn installation_token_is_minted_once_and_shared_between_gateways() {
+    let (runtime, server, locator) = start_server();
+    runtime.block_on(
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .and(SignedAppJwt)
+            .respond_with(installation_token("ghs_installation", Duration::hours(1)))
+            .expect(1)
+            .mount(&server),
+    );
+    runtime.block_on(mount_pull_request(&server, "ghs_installation", 2));
+    let installation = installation_for(&locator);
+
+    let first = load_title(&runtime, &installation, &locator);
+    let second = load_title(&runtime, &installation, &locator);
+
+    assert_eq!(first.ok().flatten().as_deref(), Some("Add widgets"));
+    assert_eq!(second.ok().flatten().as_deref(), Some("Add widgets"));
+}
+
+#[rstest]
+fn installation_token_close_to_expiry_is_refreshed() {
+    let (runtime, server, locator) = start_server();
+    runtime.block_on(
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .and(SignedAppJwt)
+            .respond_with(installation_token("ghs_expiring", Duration::seconds(30)))
+            .expect(2)
+            .mount(&server),
+    );
+    runtime.block_on(mount_pull_request(&server, "ghs_expiring", 2));
+    let installation = installation_for(&locator);
+
+    let first = load_title(&runtime, &installation, &locator);
+    let second = load_title(&runtime, &installation, &locator);
+
+    assert!(first.is_ok(), "first load failed: {first:?}");
+    assert!(second.is_ok(), "second load failed: {second:?}");
+}
+
+/// Mounts a pull request endpoint that rejects `token` `expected_calls`
+/// times.
+async fn mount_rejected_token(server: &MockServer, token: &str, expected_calls: u64) {
+    Mock::given(method("GET"))
+        .and(path(PULL_REQUEST_PATH))
+        .and(header("authorization", format!("Bearer {token}").as_str()))
+        .respond_with(
+            ResponseTemplate::new(401).set_body_json(json!({ "message": "Bad credentials" })),
+        )
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
+#[rstest]
+fn rejected_installation_token_is_refreshed_and_the_request_retried() {
+    let (runtime, server, locator) = start_server();
+    runtime.block_on(async {
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .respond_with(installation_token("ghs_revoked", Duration::hours(1)))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .respond_with(installation_token("ghs_fresh", Duration::hours(1)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_rejected_token(&server, "ghs_revoked", 1).await;
+        mount_pull_request(&server, "ghs_fresh", 2).await;
+    });
+    let installation = installation_for(&locator);
+
+    let retried = load_title(&runtime, &installation, &locator);
+    let reused = load_title(&runtime, &installation, &locator);
+
+    assert_eq!(retried.ok().flatten().as_deref(), Some("Add widgets"));
+    assert_eq!(reused.ok().flatten().as_deref(), Some("Add widgets"));
+}
+
+#[rstest]
+fn request_rejected_after_a_refresh_is_not_retried_again() {
+    let (runtime, server, locator) = start_server();
+    runtime.block_on(async {
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .respond_with(installation_token("ghs_unusable", Duration::hours(1)))
+            .expect(2)
+            .mount(&server)
+            .await;
+        mount_rejected_token(&server, "ghs_unusable", 2).await;
+    });
+    let installation = installation_for(&locator);
+
+    let rejected = load_title(&runtime, &installation, &locator);
+
+    assert!(
+        matches!(rejected, Err(IntakeError::Authentication { .. })),
+        "expected authentication error, got {rejected:?}"
+    );
+}
+
+/// Returns the path to the built binary.
+fn binary_path() -> std::io::Result<std::path::PathBuf> {
+    let mut path = std::env::current_exe()?;
+    path.pop();
+    path.pop();
+    path.push("frankie");
+    Ok(path)
+}
+
+#[rstest]
+fn single_pull_request_mode_authenticates_as_the_installation() {
+    let (runtime, server, _locator) = start_server();
+    runtime.block_on(async {
+        Mock::given(method("POST"))
+            .and(path(TOKEN_PATH))
+            .and(SignedAppJwt)
+            .respond_with(installation_token("ghs_cli", Duration::hours(1)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_pull_request(&server, "ghs_cli", 1).await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/corp/app/issues/7/comments"))
+            .and(header("authorization", "Bearer ghs_cli"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+    });
+    let key_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/github_app_test_key.pem"
+    );
+
+    let output = Command::new(binary_path().expect("binary path should resolve"))
+        .args(["--pr-url", &format!("{}/corp/app/pull/7", server.uri())])
+        .env("FRANKIE_APP_ID", APP_ID.to_string())
+        .env("FRANKIE_APP_INSTALLATION_ID", INSTALLATION_ID.to_string())
+        .env("FRANKIE_APP_PRIVATE_KEY_PATH", key_path)
+        .env_remove("FRANKIE_TOKEN")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("FRANKIE_DATABASE_URL")
+        .output()
+        .expect("binary should run");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("Add widgets"),
+        "stdout should include the pull request title"
+    );
+}
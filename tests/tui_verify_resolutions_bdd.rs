@@ -121,12 +121,16 @@ fn sample_comment() -> ReviewComment {
         author: Some("alice".to_owned()),
         file_path: Some("src/main.rs".to_owned()),
         line_number: Some(2),
+        side: None,
         original_line_number: Some(2),
         diff_hunk: None,
         commit_sha: Some("old".to_owned()),
         in_reply_to_id: None,
         created_at: None,
         updated_at: None,
+        thread_state: None,
+        start_line_number: None,
+        reactions: Vec::new(),
     }
 }
 